@@ -31,6 +31,34 @@ pub fn verify_admin(state: &PoolState, admin: Pubkey) -> Result<(), Infallible>
     Ok(())
 }
 
+pub fn verify_no_timelock(state: &PoolState) -> Result<(), Infallible> {
+    if state.timelock_delay != 0 {
+        eprintln!(
+            "Pool has a timelock delay of {}. Queue the change with --queue instead",
+            state.timelock_delay
+        );
+        std::process::exit(-1);
+    }
+    Ok(())
+}
+
+/// `slot_name` is the name of the PendingAuthorities field, used for error messages
+pub fn verify_pending_authority(
+    pending: Pubkey,
+    slot_name: &str,
+    signer: Pubkey,
+) -> Result<(), Infallible> {
+    if pending == Pubkey::default() {
+        eprintln!("No pending {slot_name} to accept");
+        std::process::exit(-1);
+    }
+    if pending != signer {
+        eprintln!("Wrong pending {slot_name}. Expected: {pending}. Got: {signer}");
+        std::process::exit(-1);
+    }
+    Ok(())
+}
+
 pub fn verify_disable_pool_authority(
     disable_pool_authority_list: &[Pubkey],
     authority: Pubkey,
@@ -95,6 +95,7 @@ impl DepositSolStakedex {
 
     pub fn deposit_sol_ix(
         &self,
+        dest_token_program: Pubkey,
         SwapParams {
             in_amount,
             destination_mint,
@@ -114,7 +115,7 @@ impl DepositSolStakedex {
                 dest_token_fee_token_account: find_fee_token_acc(destination_mint).0,
                 dest_token_mint: *destination_mint,
                 wsol_mint: native_mint::ID,
-                token_program: spl_token::ID, // TODO: support token-22 LSTs
+                token_program: dest_token_program,
                 system_program: system_program::ID,
             },
             StakeWrappedSolIxArgs { amount: *in_amount },
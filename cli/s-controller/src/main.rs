@@ -5,6 +5,7 @@ use clap::{
     Parser,
 };
 use s_cli_utils::{CONFIG_HELP, FEE_LIMIT_CB_HELP, TX_SEND_MODE_HELP};
+use s_controller_lib::PoolKey;
 use sanctum_solana_cli_utils::{ConfigWrapper, TxSendMode};
 use solana_sdk::pubkey::Pubkey;
 use subcmd::Subcmd;
@@ -15,6 +16,7 @@ mod deposit_sol;
 mod lst_amt_arg;
 mod lst_arg;
 mod pricing_prog_arg;
+mod queued_change_arg;
 mod rpc;
 mod subcmd;
 
@@ -48,6 +50,13 @@ pub struct Args {
     )]
     pub program: Pubkey,
 
+    #[arg(
+        long,
+        help = "LP token mint of the additional pool to operate on. Operates on the program's default pool if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub pool: Option<Pubkey>,
+
     #[arg(
         long,
         short,
@@ -60,6 +69,15 @@ pub struct Args {
     pub subcmd: Subcmd,
 }
 
+impl Args {
+    pub fn pool_key(&self) -> PoolKey {
+        match self.pool {
+            None => PoolKey::default_for_prog(self.program),
+            Some(lp_token_mint) => PoolKey::new_for_prog(self.program, lp_token_mint),
+        }
+    }
+}
+
 fn main() {
     let args = Args::parse();
     let rt = Runtime::new().unwrap();
@@ -0,0 +1,29 @@
+use std::error::Error;
+
+use crate::lst_arg::LstArg;
+
+const PRICING_PROG_IDENT: &str = "pricing-prog";
+const TIMELOCK_DELAY_IDENT: &str = "timelock-delay";
+
+/// Identifies a queued change PDA
+#[derive(Clone, Copy, Debug)]
+pub enum QueuedChangeArg {
+    PricingProg,
+    TimelockDelay,
+    SolValueCalculator(LstArg),
+}
+
+impl QueuedChangeArg {
+    pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        Ok(match arg {
+            PRICING_PROG_IDENT => Self::PricingProg,
+            TIMELOCK_DELAY_IDENT => Self::TimelockDelay,
+            _ => Self::SolValueCalculator(LstArg::parse_arg(arg)?),
+        })
+    }
+}
+
+pub const QUEUED_CHANGE_ARG_HELP: &str = "The queued change. One of:
+- pricing-prog
+- timelock-delay
+- the LST whose SOL value calculator change was queued. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'";
@@ -1,5 +1,5 @@
 use data_encoding::BASE64;
-use s_controller_lib::{find_disable_pool_authority_list_address, find_pool_state_address};
+use s_controller_lib::PoolKey;
 use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
 use solana_client::{
     nonblocking::rpc_client::RpcClient,
@@ -9,18 +9,43 @@ use solana_client::{
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 use solana_sdk::{account::Account, pubkey::Pubkey, transaction::VersionedTransaction};
 
-pub async fn fetch_pool_state(rpc: &RpcClient, program_id: Pubkey) -> Account {
-    rpc.get_account(&find_pool_state_address(program_id).0)
+use crate::queued_change_arg::QueuedChangeArg;
+
+pub async fn fetch_pool_state(rpc: &RpcClient, pool: &PoolKey) -> Account {
+    rpc.get_account(&pool.pool_state).await.unwrap()
+}
+
+pub async fn fetch_disable_pool_authority_list(rpc: &RpcClient, pool: &PoolKey) -> Account {
+    rpc.get_account(&pool.disable_pool_authority_list_pda().address)
         .await
         .unwrap()
 }
 
-pub async fn fetch_disable_pool_authority_list(rpc: &RpcClient, program_id: Pubkey) -> Account {
-    rpc.get_account(&find_disable_pool_authority_list_address(program_id).0)
+pub async fn fetch_pending_authorities(rpc: &RpcClient, pool: &PoolKey) -> Account {
+    rpc.get_account(&pool.pending_authorities_pda().address)
         .await
         .unwrap()
 }
 
+pub async fn fetch_queued_change(
+    rpc: &RpcClient,
+    pool: &PoolKey,
+    change: QueuedChangeArg,
+) -> (Pubkey, Account) {
+    let addr = match change {
+        QueuedChangeArg::PricingProg => pool.queued_pricing_program_pda().address,
+        QueuedChangeArg::TimelockDelay => pool.queued_timelock_delay_pda().address,
+        QueuedChangeArg::SolValueCalculator(lst) => {
+            pool.queued_sol_value_calculator_pda(lst.mint()).address
+        }
+    };
+    let acc = rpc.get_account(&addr).await.unwrap_or_else(|_e| {
+        eprintln!("No queued change at {addr}");
+        std::process::exit(-1);
+    });
+    (addr, acc)
+}
+
 // NB: this fn is currently not tested because our current BanksRpcServer setup doesn't really
 // allow simulation with post tx accounts results:
 // https://github.com/igneous-labs/sanctum-solana-utils/issues/40#issuecomment-1932036297
@@ -0,0 +1,64 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::accept_admin_ix_with_program_id;
+use s_controller_lib::{try_pending_authorities, AcceptAuthorityFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_pending_authority, rpc::fetch_pending_authorities};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Accepts the pending admin handoff proposed by set-admin, making the pending admin the program's admin authority."
+)]
+pub struct AcceptAdminArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pending admin signer. Defaults to config wallet if not set."
+    )]
+    pub pending_admin: Option<String>,
+}
+
+impl AcceptAdminArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { pending_admin } = match args.subcmd {
+            Subcmd::AcceptAdmin(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let pending_admin_signer = pending_admin.map(|s| parse_signer(&s).unwrap());
+        let pending_admin = pending_admin_signer.as_ref().unwrap_or(&payer);
+
+        let pending_authorities_acc = fetch_pending_authorities(&rpc, &pool).await;
+        let pending_authorities = try_pending_authorities(&pending_authorities_acc.data).unwrap();
+        verify_pending_authority(pending_authorities.admin, "admin", pending_admin.pubkey())
+            .unwrap();
+
+        let ix = accept_admin_ix_with_program_id(
+            program_id,
+            AcceptAuthorityFreeArgs {
+                pending_authorities: pending_authorities_acc,
+            }
+            .resolve_admin_for_pool(&pool)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), pending_admin.as_ref()],
+        )
+        .await;
+    }
+}
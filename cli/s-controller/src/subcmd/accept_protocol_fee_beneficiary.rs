@@ -0,0 +1,70 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::accept_protocol_fee_beneficiary_ix_with_program_id;
+use s_controller_lib::{try_pending_authorities, AcceptAuthorityFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_pending_authority, rpc::fetch_pending_authorities};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Accepts the pending protocol fee beneficiary handoff proposed by set-protocol-fee-beneficiary, making the pending beneficiary the pool's protocol fee beneficiary."
+)]
+pub struct AcceptProtocolFeeBeneficiaryArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pending protocol fee beneficiary signer. Defaults to config wallet if not set."
+    )]
+    pub pending_beneficiary: Option<String>,
+}
+
+impl AcceptProtocolFeeBeneficiaryArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            pending_beneficiary,
+        } = match args.subcmd {
+            Subcmd::AcceptProtocolFeeBeneficiary(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let pending_beneficiary_signer = pending_beneficiary.map(|s| parse_signer(&s).unwrap());
+        let pending_beneficiary = pending_beneficiary_signer.as_ref().unwrap_or(&payer);
+
+        let pending_authorities_acc = fetch_pending_authorities(&rpc, &pool).await;
+        let pending_authorities = try_pending_authorities(&pending_authorities_acc.data).unwrap();
+        verify_pending_authority(
+            pending_authorities.protocol_fee_beneficiary,
+            "protocol fee beneficiary",
+            pending_beneficiary.pubkey(),
+        )
+        .unwrap();
+
+        let ix = accept_protocol_fee_beneficiary_ix_with_program_id(
+            program_id,
+            AcceptAuthorityFreeArgs {
+                pending_authorities: pending_authorities_acc,
+            }
+            .resolve_protocol_fee_beneficiary_for_pool(&pool)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), pending_beneficiary.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,71 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::accept_rebalance_authority_ix_with_program_id;
+use s_controller_lib::{try_pending_authorities, AcceptAuthorityFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_pending_authority, rpc::fetch_pending_authorities};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Accepts the pending rebalance authority handoff proposed by set-rebalance-auth, making the pending rebalance authority the program's rebalance authority."
+)]
+pub struct AcceptRebalanceAuthArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pending rebalance authority signer. Defaults to config wallet if not set."
+    )]
+    pub pending_rebalance_auth: Option<String>,
+}
+
+impl AcceptRebalanceAuthArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            pending_rebalance_auth,
+        } = match args.subcmd {
+            Subcmd::AcceptRebalanceAuth(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let pending_rebalance_auth_signer =
+            pending_rebalance_auth.map(|s| parse_signer(&s).unwrap());
+        let pending_rebalance_auth = pending_rebalance_auth_signer.as_ref().unwrap_or(&payer);
+
+        let pending_authorities_acc = fetch_pending_authorities(&rpc, &pool).await;
+        let pending_authorities = try_pending_authorities(&pending_authorities_acc.data).unwrap();
+        verify_pending_authority(
+            pending_authorities.rebalance_authority,
+            "rebalance authority",
+            pending_rebalance_auth.pubkey(),
+        )
+        .unwrap();
+
+        let ix = accept_rebalance_authority_ix_with_program_id(
+            program_id,
+            AcceptAuthorityFreeArgs {
+                pending_authorities: pending_authorities_acc,
+            }
+            .resolve_rebalance_authority_for_pool(&pool)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), pending_rebalance_auth.as_ref()],
+        )
+        .await;
+    }
+}
@@ -6,7 +6,7 @@ use clap::{
 };
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::add_disable_pool_authority_ix_with_program_id;
-use s_controller_lib::{find_pool_state_address, try_pool_state, AddDisablePoolAuthorityFreeArgs};
+use s_controller_lib::{try_pool_state, AddDisablePoolAuthorityFreeArgs};
 use sanctum_solana_cli_utils::parse_signer;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::pubkey::Pubkey;
@@ -46,12 +46,13 @@ impl AddDisableAuthArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
 
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
@@ -62,11 +63,11 @@ impl AddDisableAuthArgs {
                 payer: payer.pubkey(),
                 new_authority,
                 pool_state_acc: KeyedAccount {
-                    pubkey: find_pool_state_address(program_id).0,
+                    pubkey: pool.pool_state,
                     account: pool_state_acc,
                 },
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(&pool)
             .unwrap(),
         )
         .unwrap();
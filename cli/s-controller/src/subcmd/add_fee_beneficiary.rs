@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    add_protocol_fee_beneficiary_ix_with_program_id, AddProtocolFeeBeneficiaryIxArgs,
+};
+use s_controller_lib::{try_pool_state, AddProtocolFeeBeneficiaryFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Add a beneficiary to the list of protocol fee beneficiaries.
+
+Each beneficiary receives its weight's share of a protocol fee accumulator's balance on every distribute-protocol-fees. Weights of all beneficiaries must not sum to more than 10000 bps."
+)]
+pub struct AddFeeBeneficiaryArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The beneficiary to add",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub new_beneficiary: Pubkey,
+
+    #[arg(help = "The beneficiary's share of protocol fees in bips")]
+    pub weight_bps: u16,
+}
+
+impl AddFeeBeneficiaryArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            new_beneficiary,
+            weight_bps,
+        } = match args.subcmd {
+            Subcmd::AddFeeBeneficiary(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = add_protocol_fee_beneficiary_ix_with_program_id(
+            program_id,
+            AddProtocolFeeBeneficiaryFreeArgs {
+                payer: payer.pubkey(),
+                new_beneficiary,
+                pool_state_acc: KeyedAccount {
+                    pubkey: pool.pool_state,
+                    account: pool_state_acc,
+                },
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            AddProtocolFeeBeneficiaryIxArgs { weight_bps },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
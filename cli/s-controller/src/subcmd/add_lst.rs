@@ -4,7 +4,7 @@ use clap::{
 };
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::add_lst_ix_with_program_id;
-use s_controller_lib::{find_pool_state_address, try_pool_state, AddLstFreeArgs};
+use s_controller_lib::{try_pool_state, AddLstFreeArgs};
 use sanctum_solana_cli_utils::parse_signer;
 use solana_readonly_account::{keyed::Keyed, ReadonlyAccountData};
 use solana_sdk::pubkey::Pubkey;
@@ -52,7 +52,8 @@ impl AddLstArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
@@ -62,7 +63,7 @@ impl AddLstArgs {
                 .expect("LST not found on list, --sol-val-calc must be provided")
         });
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, mint.mint()])
             .await
@@ -82,7 +83,7 @@ impl AddLstArgs {
                 account: lst_mint_acc,
             },
         }
-        .resolve_for_prog(program_id)
+        .resolve_for_pool(&pool)
         .unwrap();
         let ix = add_lst_ix_with_program_id(program_id, keys).unwrap();
 
@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    add_rebalance_authority_ix_with_program_id, AddRebalanceAuthorityIxArgs,
+};
+use s_controller_lib::{try_pool_state, AddRebalanceAuthorityFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Add a rebalance authority to the list of rebalance authorities.
+
+Each rebalance authority on the list can rebalance the pool, but the SOL value it withdraws with StartRebalance and AddRebalanceLeg must not exceed its budget in any single epoch. The pool's rebalance authority can also be added to the list to rate-limit it."
+)]
+pub struct AddRebalanceAuthArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The rebalance authority to add",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub new_rebalance_authority: Pubkey,
+
+    #[arg(
+        help = "Max SOL value, in lamports, the rebalance authority can withdraw from the pool each epoch. 0 disallows any withdrawal, 18446744073709551615 (u64::MAX) means no limit"
+    )]
+    pub epoch_budget: u64,
+}
+
+impl AddRebalanceAuthArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            new_rebalance_authority,
+            epoch_budget,
+        } = match args.subcmd {
+            Subcmd::AddRebalanceAuth(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = add_rebalance_authority_ix_with_program_id(
+            program_id,
+            AddRebalanceAuthorityFreeArgs {
+                payer: payer.pubkey(),
+                new_rebalance_authority,
+                pool_state_acc: KeyedAccount {
+                    pubkey: pool.pool_state,
+                    account: pool_state_acc,
+                },
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            AddRebalanceAuthorityIxArgs { epoch_budget },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,89 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    cancel_authority_handoff_ix_with_program_id, CancelAuthorityHandoffIxArgs,
+};
+use s_controller_lib::{try_pool_state, CancelAuthorityHandoffFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Cancels pending authority handoffs proposed by set-admin, set-rebalance-auth or set-protocol-fee-beneficiary.
+Cancels all pending handoffs if none of the flags are set."
+)]
+pub struct CancelAuthorityHandoffArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(long, help = "Cancel the pending admin handoff")]
+    pub cancel_admin: bool,
+
+    #[arg(long, help = "Cancel the pending rebalance authority handoff")]
+    pub cancel_rebalance_auth: bool,
+
+    #[arg(long, help = "Cancel the pending protocol fee beneficiary handoff")]
+    pub cancel_protocol_fee_beneficiary: bool,
+}
+
+impl CancelAuthorityHandoffArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            cancel_admin,
+            cancel_rebalance_auth,
+            cancel_protocol_fee_beneficiary,
+        } = match args.subcmd {
+            Subcmd::CancelAuthorityHandoff(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let cancel_all =
+            !cancel_admin && !cancel_rebalance_auth && !cancel_protocol_fee_beneficiary;
+
+        let ix = cancel_authority_handoff_ix_with_program_id(
+            program_id,
+            CancelAuthorityHandoffFreeArgs {
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            CancelAuthorityHandoffIxArgs {
+                cancel_admin: cancel_all || cancel_admin,
+                cancel_rebalance_authority: cancel_all || cancel_rebalance_auth,
+                cancel_protocol_fee_beneficiary: cancel_all || cancel_protocol_fee_beneficiary,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,92 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::cancel_queued_change_ix_with_program_id;
+use s_controller_lib::{
+    try_disable_pool_authority_list, try_pool_state, CancelQueuedChangeFreeArgs,
+};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{
+    common::verify_disable_pool_authority,
+    queued_change_arg::{QueuedChangeArg, QUEUED_CHANGE_ARG_HELP},
+    rpc::{fetch_disable_pool_authority_list, fetch_pool_state, fetch_queued_change},
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Cancels a change queued by set-pricing-prog --queue, set-sol-value-calculator --queue or set-timelock-delay.
+The queued change account's rent is refunded to whoever paid for it."
+)]
+pub struct CancelQueuedChangeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin or a disable pool authority signer. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+
+    #[arg(
+        help = QUEUED_CHANGE_ARG_HELP,
+        value_parser = StringValueParser::new().try_map(|s| QueuedChangeArg::parse_arg(&s)),
+    )]
+    pub change: QueuedChangeArg,
+}
+
+impl CancelQueuedChangeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { authority, change } = match args.subcmd {
+            Subcmd::CancelQueuedChange(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+
+        // check if authority is either the admin or a disable pool authority
+        if pool_state.admin != authority.pubkey() {
+            let disable_pool_authority_list_acc =
+                fetch_disable_pool_authority_list(&rpc, &pool).await;
+            let disable_pool_authority_list =
+                try_disable_pool_authority_list(&disable_pool_authority_list_acc.data).unwrap();
+
+            verify_disable_pool_authority(disable_pool_authority_list, authority.pubkey()).unwrap();
+        }
+
+        let (_queued_change_addr, queued_change_acc) =
+            fetch_queued_change(&rpc, &pool, change).await;
+
+        let ix = cancel_queued_change_ix_with_program_id(
+            program_id,
+            CancelQueuedChangeFreeArgs {
+                signer: authority.pubkey(),
+                queued_change: queued_change_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), authority.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,111 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    convert_protocol_fees_to_lp_ix_full_for_prog, ConvertProtocolFeesToLpByMintFreeArgs, SwapPdas,
+};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use std::str::FromStr;
+
+use crate::lst_arg::LstArg;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Convert the protocol fee accumulator balance of a LST into LP tokens",
+    long_about = "Convert the protocol fee accumulator balance of a LST into LP tokens.
+
+Moves the entire balance of the LST's protocol fee accumulator into the pool reserves and mints the equivalent LP tokens to the protocol fee LP token account.
+The pool must have protocol fees as LP enabled, see set-protocol-fees-as-lp."
+)]
+pub struct ConvertProtocolFeesToLpArgs {
+    #[arg(
+        help = "Mint of the LST to convert protocol fees for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub mint: LstArg,
+
+    #[arg(
+        long,
+        short,
+        help = "Account suffix slice to call LstToSol for the given LST, excluding the SOL value calculator program ID and mint. Required if mint is not on sanctum-lst-list. Ignore clap's help msg and put this after mint arg instead of before.",
+        value_delimiter = ' ',
+        num_args = 1..,
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub suffix: Vec<Pubkey>,
+}
+
+impl ConvertProtocolFeesToLpArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { mint, suffix } = match args.subcmd {
+            Subcmd::ConvertProtocolFeesToLp(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        // accounts suffix slice including lst_mint as first account
+        let suffix = mint.sol_value_calculator_accounts_of().unwrap_or_else(|| {
+            std::iter::once(AccountMeta {
+                pubkey: mint.mint(),
+                is_signer: false,
+                is_writable: false,
+            })
+            .chain(suffix.into_iter().map(|pk| AccountMeta {
+                pubkey: pk,
+                is_signer: false,
+                is_writable: false,
+            }))
+            .collect()
+        });
+
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let mint_addr = mint.mint();
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[mint_addr, lst_state_list_addr, pool_state_addr])
+            .await
+            .unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let mint_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let (keys, lst_index, sol_value_calculator_program_id) =
+            ConvertProtocolFeesToLpByMintFreeArgs {
+                pool_state: pool_state_acc,
+                lst_state_list: lst_state_list_acc,
+                lst_mint: Keyed {
+                    pubkey: mint_addr,
+                    account: &mint_acc,
+                },
+            }
+            .resolve_with_pdas(SwapPdas::find_for_program_id(program_id))
+            .unwrap();
+        let ix = convert_protocol_fees_to_lp_ix_full_for_prog(
+            program_id,
+            keys,
+            lst_index,
+            &suffix,
+            sol_value_calculator_program_id,
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
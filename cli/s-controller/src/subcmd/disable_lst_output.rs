@@ -0,0 +1,87 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{disable_lst_output_ix_with_program_id, DisableLstOutputIxArgs};
+use s_controller_lib::{try_pool_state, DisableEnableLstInputByMintFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Disables all output for a LST for a pool.")]
+pub struct DisableLstOutputArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to disable output of",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub mint: Pubkey,
+}
+
+impl DisableLstOutputArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { admin, mint } = match args.subcmd {
+            Subcmd::DisableLstOutput(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data()).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let (keys, index) = DisableEnableLstInputByMintFreeArgs {
+            lst_mint: mint,
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+        }
+        .resolve_disable_output_for_pool(&pool)
+        .unwrap();
+        let ix = disable_lst_output_ix_with_program_id(
+            program_id,
+            keys,
+            DisableLstOutputIxArgs {
+                index: index.try_into().unwrap(),
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -37,18 +37,19 @@ impl DisablePoolArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
 
         // check if authority is either the admin or a disable pool authority
         if pool_state.admin != authority.pubkey() {
             let disable_pool_authority_list_acc =
-                fetch_disable_pool_authority_list(&rpc, program_id).await;
+                fetch_disable_pool_authority_list(&rpc, &pool).await;
             let disable_pool_authority_list =
                 try_disable_pool_authority_list(&disable_pool_authority_list_acc.data).unwrap();
 
@@ -60,7 +61,7 @@ impl DisablePoolArgs {
             DisablePoolFreeArgs {
                 signer: authority.pubkey(),
             }
-            .resolve_for_prog(program_id),
+            .resolve_for_pool(&pool),
         )
         .unwrap();
 
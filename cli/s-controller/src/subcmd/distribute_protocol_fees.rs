@@ -0,0 +1,104 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    distribute_protocol_fees_ix_full_for_prog, protocol_fee_beneficiary_atas,
+    try_protocol_fee_beneficiary_list, DistributeProtocolFeesFreeArgs,
+};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::lst_arg::LstArg;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Distribute accumulated protocol fees for a given LST to the protocol fee beneficiaries by weight.
+
+Transfers are made to each beneficiary's associated token account, creating them if they do not exist.
+Any undistributed remainder stays in the accumulator for the protocol fee beneficiary to withdraw."
+)]
+pub struct DistributeProtocolFeesArgs {
+    #[arg(
+        help = "Mint of the LST to distribute protocol fees for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub mint: LstArg,
+
+    #[arg(
+        long,
+        short,
+        help = "The token program of the LST. Must be provided if mint is not on sanctum-lst-list."
+    )]
+    pub token_program: Option<Pubkey>,
+}
+
+impl DistributeProtocolFeesArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            mint,
+            token_program,
+        } = match args.subcmd {
+            Subcmd::DistributeProtocolFees(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let token_program = token_program.unwrap_or_else(|| {
+            mint.token_program()
+                .expect("Unknown mint, token program must be provided")
+        });
+        let lst_mint = mint.mint();
+
+        let list_acc = rpc
+            .get_account(&pool.protocol_fee_beneficiary_list_pda().address)
+            .await
+            .unwrap();
+        let list = try_protocol_fee_beneficiary_list(&list_acc.data).unwrap();
+        let beneficiary_atas = protocol_fee_beneficiary_atas(list, lst_mint, token_program);
+
+        let mut ixs: Vec<_> = list
+            .iter()
+            .map(|share| {
+                create_associated_token_account_idempotent(
+                    &payer.pubkey(),
+                    &share.beneficiary,
+                    &lst_mint,
+                    &token_program,
+                )
+            })
+            .collect();
+        ixs.push(
+            distribute_protocol_fees_ix_full_for_prog(
+                program_id,
+                DistributeProtocolFeesFreeArgs {
+                    lst_mint: MintWithTokenProgram {
+                        pubkey: lst_mint,
+                        token_program,
+                    },
+                }
+                .resolve_for_pool(&pool),
+                &beneficiary_atas,
+            )
+            .unwrap(),
+        );
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            ixs,
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
@@ -4,9 +4,7 @@ use clap::{
 };
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::{enable_lst_input_ix_with_program_id, EnableLstInputIxArgs};
-use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, DisableEnableLstInputByMintFreeArgs,
-};
+use s_controller_lib::DisableEnableLstInputByMintFreeArgs;
 use sanctum_solana_cli_utils::parse_signer;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
@@ -39,13 +37,14 @@ impl EnableLstInputArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
             .await
@@ -58,7 +57,7 @@ impl EnableLstInputArgs {
             pool_state: pool_state_acc,
             lst_state_list: lst_state_list_acc,
         }
-        .resolve_enable_for_prog(program_id)
+        .resolve_enable_for_pool(&pool)
         .unwrap();
         let ix = enable_lst_input_ix_with_program_id(
             program_id,
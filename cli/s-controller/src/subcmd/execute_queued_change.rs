@@ -0,0 +1,138 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    execute_set_pricing_program_ix_with_program_id, execute_set_timelock_delay_ix_with_program_id,
+};
+use s_controller_lib::{
+    execute_set_sol_value_calculator_ix_by_mint_full_with_program_id, try_queued_change,
+    ExecuteSetPricingProgramFreeArgs, ExecuteSetSolValueCalculatorByMintFreeArgs,
+    ExecuteSetTimelockDelayFreeArgs,
+};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+use crate::{
+    queued_change_arg::{QueuedChangeArg, QUEUED_CHANGE_ARG_HELP},
+    rpc::fetch_queued_change,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Executes a change queued by set-pricing-prog --queue, set-sol-value-calculator --queue or set-timelock-delay after its timelock delay has elapsed.
+Permissionless, the queued change account's rent is refunded to whoever paid for it."
+)]
+pub struct ExecuteQueuedChangeArgs {
+    #[arg(
+        help = QUEUED_CHANGE_ARG_HELP,
+        value_parser = StringValueParser::new().try_map(|s| QueuedChangeArg::parse_arg(&s)),
+    )]
+    pub change: QueuedChangeArg,
+
+    #[arg(
+        long = "account-suffix",
+        short = 'c',
+        help = "Account suffix slice to call the new SOL value calculator's LstToSol for the given LST, excluding the program ID and mint. Only used when executing a SOL value calculator change. Can be omitted for LSTs on sanctum-lst-list.",
+        num_args(1..),
+    )]
+    pub account_suffix: Vec<Pubkey>,
+}
+
+impl ExecuteQueuedChangeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            change,
+            account_suffix,
+        } = match args.subcmd {
+            Subcmd::ExecuteQueuedChange(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let (_queued_change_addr, queued_change_acc) =
+            fetch_queued_change(&rpc, &pool, change).await;
+        let queued_change = *try_queued_change(&queued_change_acc.data).unwrap();
+
+        let ix = match change {
+            QueuedChangeArg::PricingProg => execute_set_pricing_program_ix_with_program_id(
+                program_id,
+                ExecuteSetPricingProgramFreeArgs {
+                    queued_change: queued_change_acc,
+                }
+                .resolve_for_pool(&pool)
+                .unwrap(),
+            )
+            .unwrap(),
+            QueuedChangeArg::TimelockDelay => execute_set_timelock_delay_ix_with_program_id(
+                program_id,
+                ExecuteSetTimelockDelayFreeArgs {
+                    queued_change: queued_change_acc,
+                }
+                .resolve_for_pool(&pool)
+                .unwrap(),
+            )
+            .unwrap(),
+            QueuedChangeArg::SolValueCalculator(mint) => {
+                let lst_state_list_addr = pool.lst_state_list_pda().address;
+                let mut fetched = rpc
+                    .get_multiple_accounts(&[pool.pool_state, lst_state_list_addr, mint.mint()])
+                    .await
+                    .unwrap();
+                let lst_mint_acc = fetched.pop().unwrap().unwrap();
+                let lst_state_list_acc = fetched.pop().unwrap().unwrap();
+                let pool_state_acc = fetched.pop().unwrap().unwrap();
+
+                let sol_value_calculator_accounts: Vec<AccountMeta> = if account_suffix.is_empty()
+                    && mint.sol_val_calc_of() == Some(queued_change.new_program)
+                {
+                    mint.sol_value_calculator_accounts_of().unwrap()
+                } else {
+                    std::iter::once(AccountMeta {
+                        pubkey: mint.mint(),
+                        is_signer: false,
+                        is_writable: false,
+                    })
+                    .chain(account_suffix.into_iter().map(|pubkey| AccountMeta {
+                        pubkey,
+                        is_signer: false,
+                        is_writable: false,
+                    }))
+                    .collect()
+                };
+                execute_set_sol_value_calculator_ix_by_mint_full_with_program_id(
+                    program_id,
+                    &ExecuteSetSolValueCalculatorByMintFreeArgs {
+                        pool_state: pool_state_acc,
+                        lst_state_list: lst_state_list_acc,
+                        lst_mint: KeyedAccount {
+                            pubkey: mint.mint(),
+                            account: lst_mint_acc,
+                        },
+                        queued_change: queued_change_acc,
+                    },
+                    &sol_value_calculator_accounts,
+                    queued_change.new_program,
+                )
+                .unwrap()
+            }
+        };
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
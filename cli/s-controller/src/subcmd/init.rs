@@ -1,7 +1,7 @@
 use clap::Args;
 use s_cli_utils::handle_tx_full;
-use s_controller_interface::initialize_ix_with_program_id;
-use s_controller_lib::{InitializeFreeArgs, InitializeResolveForProg};
+use s_controller_interface::{initialize_ix_with_program_id, InitializeKeys};
+use s_controller_lib::{InitializeFreeArgs, InitializeResolveForProg, PoolKey};
 use sanctum_solana_cli_utils::{parse_signer, PubkeySrc};
 
 use super::Subcmd;
@@ -17,7 +17,10 @@ Prerequisites:
 The procedure will:
 - Initialize the pool state only. Use add-lst to initialize the lst list and add the first LST to the pool.
 - Transfer the mint authority of lp_token_mint to the program. Make sure token metadata is already set up if required.
-- Set pool manager and rebalance authority to the program's initial authority"
+- Set pool manager and rebalance authority to the program's initial authority
+
+Pass --additional to initialize an additional pool identified by lp_token_mint instead of the program's default pool.
+Use --pool <lp_token_mint> for subsequent commands operating on the additional pool."
 )]
 pub struct InitArgs {
     #[arg(
@@ -27,6 +30,12 @@ pub struct InitArgs {
     )]
     pub init_auth: Option<String>,
 
+    #[arg(
+        long,
+        help = "Initialize an additional pool identified by lp_token_mint instead of the program's default pool"
+    )]
+    pub additional: bool,
+
     #[arg(help = "The initialized lp_token_mint. Can be a pubkey or signer.")]
     pub lp_token_mint: String,
 }
@@ -35,6 +44,7 @@ impl InitArgs {
     pub async fn run(args: crate::Args) {
         let Self {
             init_auth,
+            additional,
             lp_token_mint,
         } = match args.subcmd {
             Subcmd::Init(a) => a,
@@ -49,17 +59,25 @@ impl InitArgs {
         let init_auth = init_auth_signer.as_ref().unwrap_or(&payer);
 
         let lp_token_mint = PubkeySrc::parse(&lp_token_mint).unwrap();
+        let pool = if additional {
+            PoolKey::new_for_prog(program_id, lp_token_mint.pubkey())
+        } else {
+            PoolKey::default_for_prog(program_id)
+        };
 
         let ix = initialize_ix_with_program_id(
             program_id,
-            InitializeFreeArgs {
-                payer: payer.pubkey(),
-                lp_token_mint: lp_token_mint.pubkey(),
-            }
-            .resolve_for_prog(InitializeResolveForProg {
-                program_id,
-                initial_authority: init_auth.pubkey(),
-            }),
+            InitializeKeys {
+                pool_state: pool.pool_state,
+                ..InitializeFreeArgs {
+                    payer: payer.pubkey(),
+                    lp_token_mint: lp_token_mint.pubkey(),
+                }
+                .resolve_for_prog(InitializeResolveForProg {
+                    program_id,
+                    initial_authority: init_auth.pubkey(),
+                })
+            },
         )
         .unwrap();
 
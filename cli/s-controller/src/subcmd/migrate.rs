@@ -0,0 +1,72 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::migrate_ix_with_program_id;
+use s_controller_lib::{try_pool_state, MigrateFreeArgs, MIGRATABLE_PROGRAM_VERS};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Migrates the pool's accounts to the current program version's layout.
+
+Reallocs the pool state and LST state list accounts, with the admin paying for the additional rent, and bumps the pool's version. All other instructions that use the LST state list fail until this is done."
+)]
+pub struct MigrateArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Pays for the additional rent. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+}
+
+impl MigrateArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { admin } = match args.subcmd {
+            Subcmd::Migrate(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+        if pool_state.version != MIGRATABLE_PROGRAM_VERS {
+            eprintln!(
+                "Pool is on version {}, can only migrate from version {MIGRATABLE_PROGRAM_VERS}",
+                pool_state.version
+            );
+            std::process::exit(-1);
+        }
+
+        let ix = migrate_ix_with_program_id(
+            program_id,
+            MigrateFreeArgs {
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
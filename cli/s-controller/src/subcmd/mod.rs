@@ -1,34 +1,80 @@
 use clap::Subcommand;
 
 use self::{
-    add_disable_auth::AddDisableAuthArgs, add_lst::AddLstArgs,
-    disable_lst_input::DisableLstInputArgs, disable_pool::DisablePoolArgs,
-    enable_lst_input::EnableLstInputArgs, enable_pool::EnablePoolArgs, init::InitArgs,
-    rebal_sol::RebalSolArgs, remove_disable_auth::RemoveDisableAuthArgs, remove_lst::RemoveLstArgs,
-    set_admin::SetAdminArgs, set_pricing_prog::SetPricingProgArgs,
-    set_protocol_fee::SetProtocolFeeArgs,
+    accept_admin::AcceptAdminArgs,
+    accept_protocol_fee_beneficiary::AcceptProtocolFeeBeneficiaryArgs,
+    accept_rebalance_auth::AcceptRebalanceAuthArgs, add_disable_auth::AddDisableAuthArgs,
+    add_fee_beneficiary::AddFeeBeneficiaryArgs, add_lst::AddLstArgs,
+    add_rebalance_auth::AddRebalanceAuthArgs, cancel_authority_handoff::CancelAuthorityHandoffArgs,
+    cancel_queued_change::CancelQueuedChangeArgs,
+    convert_protocol_fees_to_lp::ConvertProtocolFeesToLpArgs,
+    disable_lst_input::DisableLstInputArgs, disable_lst_output::DisableLstOutputArgs,
+    disable_pool::DisablePoolArgs, distribute_protocol_fees::DistributeProtocolFeesArgs,
+    enable_lst_input::EnableLstInputArgs, enable_lst_output::EnableLstOutputArgs,
+    enable_pool::EnablePoolArgs, execute_queued_change::ExecuteQueuedChangeArgs, init::InitArgs,
+    migrate::MigrateArgs, rebal_sol::RebalSolArgs, remove_disable_auth::RemoveDisableAuthArgs,
+    remove_fee_beneficiary::RemoveFeeBeneficiaryArgs, remove_lst::RemoveLstArgs,
+    remove_rebalance_auth::RemoveRebalanceAuthArgs, set_admin::SetAdminArgs,
+    set_fee_beneficiary_weight::SetFeeBeneficiaryWeightArgs,
+    set_lp_sync_requirement::SetLpSyncRequirementArgs,
+    set_lp_token_metadata::SetLpTokenMetadataArgs,
+    set_lst_circuit_breaker::SetLstCircuitBreakerArgs,
+    set_lst_flash_loan_fee::SetLstFlashLoanFeeArgs, set_lst_max_share::SetLstMaxShareArgs,
+    set_max_rebalance_loss::SetMaxRebalanceLossArgs,
+    set_pool_rebalance_auth_budget::SetPoolRebalanceAuthBudgetArgs,
+    set_pricing_prog::SetPricingProgArgs, set_protocol_fee::SetProtocolFeeArgs,
     set_protocol_fee_beneficiary::SetProtocolFeeBeneficiaryArgs,
-    set_rebalance_auth::SetRebalanceAuthArgs, set_sol_value_calculator::SetSolValueCalculatorArgs,
-    sync::SyncArgs, sync_all::SyncAllArgs, view::ViewArgs,
+    set_protocol_fees_as_lp::SetProtocolFeesAsLpArgs, set_rebalance_auth::SetRebalanceAuthArgs,
+    set_rebalance_auth_budget::SetRebalanceAuthBudgetArgs, set_referral_fee::SetReferralFeeArgs,
+    set_sol_value_calculator::SetSolValueCalculatorArgs, set_timelock_delay::SetTimelockDelayArgs,
+    swap::SwapArgs, sync::SyncArgs, sync_all::SyncAllArgs, view::ViewArgs,
     withdraw_protocol_fees::WithdrawProtocolFeesArgs,
 };
 
+mod accept_admin;
+mod accept_protocol_fee_beneficiary;
+mod accept_rebalance_auth;
 mod add_disable_auth;
+mod add_fee_beneficiary;
 mod add_lst;
+mod add_rebalance_auth;
+mod cancel_authority_handoff;
+mod cancel_queued_change;
+mod convert_protocol_fees_to_lp;
 mod disable_lst_input;
+mod disable_lst_output;
 mod disable_pool;
+mod distribute_protocol_fees;
 mod enable_lst_input;
+mod enable_lst_output;
 mod enable_pool;
+mod execute_queued_change;
 mod init;
+mod migrate;
 mod rebal_sol;
 mod remove_disable_auth;
+mod remove_fee_beneficiary;
 mod remove_lst;
+mod remove_rebalance_auth;
 mod set_admin;
+mod set_fee_beneficiary_weight;
+mod set_lp_sync_requirement;
+mod set_lp_token_metadata;
+mod set_lst_circuit_breaker;
+mod set_lst_flash_loan_fee;
+mod set_lst_max_share;
+mod set_max_rebalance_loss;
+mod set_pool_rebalance_auth_budget;
 mod set_pricing_prog;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
+mod set_protocol_fees_as_lp;
 mod set_rebalance_auth;
+mod set_rebalance_auth_budget;
+mod set_referral_fee;
 mod set_sol_value_calculator;
+mod set_timelock_delay;
+mod swap;
 mod sync;
 mod sync_all;
 mod view;
@@ -40,22 +86,50 @@ pub enum Subcmd {
     AddDisableAuth(AddDisableAuthArgs),
     RemoveDisableAuth(RemoveDisableAuthArgs),
     SetAdmin(SetAdminArgs),
+    AcceptAdmin(AcceptAdminArgs),
     SetProtocolFee(SetProtocolFeeArgs),
     AddLst(AddLstArgs),
     RemoveLst(RemoveLstArgs),
     DisableLstInput(DisableLstInputArgs),
     EnableLstInput(EnableLstInputArgs),
+    DisableLstOutput(DisableLstOutputArgs),
+    EnableLstOutput(EnableLstOutputArgs),
+    SetLstMaxShare(SetLstMaxShareArgs),
+    SetLstFlashLoanFee(SetLstFlashLoanFeeArgs),
     DisablePool(DisablePoolArgs),
     SetProtocolFeeBeneficiary(SetProtocolFeeBeneficiaryArgs),
+    AcceptProtocolFeeBeneficiary(AcceptProtocolFeeBeneficiaryArgs),
     EnablePool(EnablePoolArgs),
     SetPricingProg(SetPricingProgArgs),
     SetSolValueCalculator(SetSolValueCalculatorArgs),
+    SetTimelockDelay(SetTimelockDelayArgs),
+    ExecuteQueuedChange(ExecuteQueuedChangeArgs),
+    CancelQueuedChange(CancelQueuedChangeArgs),
     SetRebalanceAuth(SetRebalanceAuthArgs),
+    AcceptRebalanceAuth(AcceptRebalanceAuthArgs),
+    CancelAuthorityHandoff(CancelAuthorityHandoffArgs),
     Sync(SyncArgs),
     SyncAll(SyncAllArgs),
     WithdrawProtocolFees(WithdrawProtocolFeesArgs),
     View(ViewArgs),
     RebalSol(RebalSolArgs),
+    SetReferralFee(SetReferralFeeArgs),
+    Swap(SwapArgs),
+    AddFeeBeneficiary(AddFeeBeneficiaryArgs),
+    RemoveFeeBeneficiary(RemoveFeeBeneficiaryArgs),
+    SetFeeBeneficiaryWeight(SetFeeBeneficiaryWeightArgs),
+    DistributeProtocolFees(DistributeProtocolFeesArgs),
+    SetProtocolFeesAsLp(SetProtocolFeesAsLpArgs),
+    ConvertProtocolFeesToLp(ConvertProtocolFeesToLpArgs),
+    SetLstCircuitBreaker(SetLstCircuitBreakerArgs),
+    SetLpSyncRequirement(SetLpSyncRequirementArgs),
+    Migrate(MigrateArgs),
+    SetLpTokenMetadata(SetLpTokenMetadataArgs),
+    AddRebalanceAuth(AddRebalanceAuthArgs),
+    RemoveRebalanceAuth(RemoveRebalanceAuthArgs),
+    SetRebalanceAuthBudget(SetRebalanceAuthBudgetArgs),
+    SetPoolRebalanceAuthBudget(SetPoolRebalanceAuthBudgetArgs),
+    SetMaxRebalanceLoss(SetMaxRebalanceLossArgs),
 }
 
 impl Subcmd {
@@ -65,22 +139,52 @@ impl Subcmd {
             Self::AddDisableAuth(_) => AddDisableAuthArgs::run(args).await,
             Self::RemoveDisableAuth(_) => RemoveDisableAuthArgs::run(args).await,
             Self::SetAdmin(_) => SetAdminArgs::run(args).await,
+            Self::AcceptAdmin(_) => AcceptAdminArgs::run(args).await,
             Self::SetProtocolFee(_) => SetProtocolFeeArgs::run(args).await,
             Self::AddLst(_) => AddLstArgs::run(args).await,
             Self::RemoveLst(_) => RemoveLstArgs::run(args).await,
             Self::DisableLstInput(_) => DisableLstInputArgs::run(args).await,
             Self::EnableLstInput(_) => EnableLstInputArgs::run(args).await,
+            Self::DisableLstOutput(_) => DisableLstOutputArgs::run(args).await,
+            Self::EnableLstOutput(_) => EnableLstOutputArgs::run(args).await,
+            Self::SetLstMaxShare(_) => SetLstMaxShareArgs::run(args).await,
+            Self::SetLstFlashLoanFee(_) => SetLstFlashLoanFeeArgs::run(args).await,
             Self::DisablePool(_) => DisablePoolArgs::run(args).await,
             Self::SetProtocolFeeBeneficiary(_) => SetProtocolFeeBeneficiaryArgs::run(args).await,
+            Self::AcceptProtocolFeeBeneficiary(_) => {
+                AcceptProtocolFeeBeneficiaryArgs::run(args).await
+            }
             Self::EnablePool(_) => EnablePoolArgs::run(args).await,
             Self::SetPricingProg(_) => SetPricingProgArgs::run(args).await,
             Self::SetSolValueCalculator(_) => SetSolValueCalculatorArgs::run(args).await,
+            Self::SetTimelockDelay(_) => SetTimelockDelayArgs::run(args).await,
+            Self::ExecuteQueuedChange(_) => ExecuteQueuedChangeArgs::run(args).await,
+            Self::CancelQueuedChange(_) => CancelQueuedChangeArgs::run(args).await,
             Self::SetRebalanceAuth(_) => SetRebalanceAuthArgs::run(args).await,
+            Self::AcceptRebalanceAuth(_) => AcceptRebalanceAuthArgs::run(args).await,
+            Self::CancelAuthorityHandoff(_) => CancelAuthorityHandoffArgs::run(args).await,
             Self::Sync(_) => SyncArgs::run(args).await,
             Self::SyncAll(_) => SyncAllArgs::run(args).await,
             Self::WithdrawProtocolFees(_) => WithdrawProtocolFeesArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
             Self::RebalSol(_) => RebalSolArgs::run(args).await,
+            Self::SetReferralFee(_) => SetReferralFeeArgs::run(args).await,
+            Self::Swap(_) => SwapArgs::run(args).await,
+            Self::AddFeeBeneficiary(_) => AddFeeBeneficiaryArgs::run(args).await,
+            Self::RemoveFeeBeneficiary(_) => RemoveFeeBeneficiaryArgs::run(args).await,
+            Self::SetFeeBeneficiaryWeight(_) => SetFeeBeneficiaryWeightArgs::run(args).await,
+            Self::DistributeProtocolFees(_) => DistributeProtocolFeesArgs::run(args).await,
+            Self::SetProtocolFeesAsLp(_) => SetProtocolFeesAsLpArgs::run(args).await,
+            Self::ConvertProtocolFeesToLp(_) => ConvertProtocolFeesToLpArgs::run(args).await,
+            Self::SetLstCircuitBreaker(_) => SetLstCircuitBreakerArgs::run(args).await,
+            Self::SetLpSyncRequirement(_) => SetLpSyncRequirementArgs::run(args).await,
+            Self::Migrate(_) => MigrateArgs::run(args).await,
+            Self::SetLpTokenMetadata(_) => SetLpTokenMetadataArgs::run(args).await,
+            Self::AddRebalanceAuth(_) => AddRebalanceAuthArgs::run(args).await,
+            Self::RemoveRebalanceAuth(_) => RemoveRebalanceAuthArgs::run(args).await,
+            Self::SetRebalanceAuthBudget(_) => SetRebalanceAuthBudgetArgs::run(args).await,
+            Self::SetPoolRebalanceAuthBudget(_) => SetPoolRebalanceAuthBudgetArgs::run(args).await,
+            Self::SetMaxRebalanceLoss(_) => SetMaxRebalanceLossArgs::run(args).await,
         }
     }
 }
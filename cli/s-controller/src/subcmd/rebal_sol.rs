@@ -8,11 +8,12 @@ use inquire::Confirm;
 use jupiter_amm_interface::SwapParams;
 use s_cli_utils::handle_tx_full;
 use s_controller_lib::{
-    end_rebalance_ix_from_start_rebalance_ix, find_lst_state_list_address,
-    find_pool_reserves_address, find_pool_reserves_address_with_pool_state_id,
-    find_pool_state_address, start_rebalance_ix_by_mints_full_for_prog, try_pool_state,
-    FindLstPdaAtaKeys, SrcDstLstSolValueCalcAccountSuffixes, StartRebalanceByMintsFreeArgs,
-    StartRebalanceIxLstAmts,
+    calc_max_rebalance_sol_value_loss, end_rebalance_ix_from_start_rebalance_ix,
+    find_pool_reserves_address_with_pool_state_id, pool_rebalance_authority_budget_remaining,
+    rebalance_authority_budget_remaining, start_rebalance_ix_by_mints_full_for_pool,
+    try_find_rebalance_authority, try_pool_config, try_pool_state, try_rebalance_authority_list,
+    verify_rebalance_authority, FindLstPdaAtaKeys, SrcDstLstSolValueCalcAccountSuffixes,
+    StartRebalanceByMintsFreeArgs, StartRebalanceIxLstAmts,
 };
 use s_jup_interface::{LstData, SPool, SPoolInitAccounts};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
@@ -36,17 +37,20 @@ use crate::{
 
 use super::Subcmd;
 
+const START_REBALANCE_IX_REBALANCE_AUTHORITY_INDEX: usize = 0;
+
 #[derive(Args, Debug)]
 #[command(
     about = "Rebalance from SOL to another LST by staking the SOL to the LST's stake pool.",
     long_about = "Rebalance from SOL to another LST by staking the SOL to the LST's stake pool.
-May require the payer to subsidize some amount of LST to make up for the stake pool's SOL deposit fees"
+May require the payer to subsidize some amount of LST to make up for the stake pool's SOL deposit fees,
+less the SOL value the pool is allowed to lose set by --max-loss-lamports"
 )]
 pub struct RebalSolArgs {
     #[arg(
         long,
         short,
-        help = "The program's rebalance authority. Defaults to config wallet if not set."
+        help = "The pool's rebalance authority or a rebalance authority on its rebalance authority list. Defaults to config wallet if not set."
     )]
     pub rebalance_auth: Option<String>,
 
@@ -58,6 +62,13 @@ pub struct RebalSolArgs {
     )]
     pub yes: bool,
 
+    #[arg(
+        long,
+        default_value_t = 0,
+        help = "Max SOL value in lamports the pool may lose to the stake pool's SOL deposit fees instead of it being subsidized. Further capped by the pool's max_rebalance_loss_bps"
+    )]
+    pub max_loss_lamports: u64,
+
     #[arg(
         help = "Amount in SOL to rebalance",
         value_parser = StringValueParser::new().try_map(|s| LstAmtArg::parse_arg(&s)),
@@ -76,6 +87,7 @@ impl RebalSolArgs {
         let Self {
             rebalance_auth,
             yes,
+            max_loss_lamports,
             sol,
             lst,
         } = match args.subcmd {
@@ -85,7 +97,7 @@ impl RebalSolArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
 
         let sanctum_lst = match lst {
             LstArg::SanctumLst(s) => s,
@@ -94,8 +106,6 @@ impl RebalSolArgs {
             }
         };
         let symbol = &sanctum_lst.symbol;
-        let (pool_id, _) = find_pool_state_address(program_id);
-        let (lst_state_list_id, _) = find_lst_state_list_address(program_id);
 
         let rebalance_auth = rebalance_auth.map(|s| parse_signer(&s).unwrap());
         let rebalance_auth = rebalance_auth
@@ -105,24 +115,35 @@ impl RebalSolArgs {
         let lamports = match sol {
             LstAmtArg::Amt(v) => v,
             LstAmtArg::All => {
-                let (wsol_reserves, _) = find_pool_reserves_address(FindLstPdaAtaKeys {
-                    lst_mint: native_mint::ID,
-                    token_program: spl_token::ID,
-                });
+                let (wsol_reserves, _) = find_pool_reserves_address_with_pool_state_id(
+                    pool.pool_state,
+                    FindLstPdaAtaKeys {
+                        lst_mint: native_mint::ID,
+                        token_program: spl_token::ID,
+                    },
+                );
                 let fetched_reserves = rpc.get_account(&wsol_reserves).await.unwrap();
                 token_account_balance(fetched_reserves).unwrap()
             }
         };
 
         let mut fetched = rpc
-            .get_multiple_accounts(&[pool_id, lst_state_list_id])
+            .get_multiple_accounts(&[
+                pool.pool_state,
+                pool.lst_state_list_pda().address,
+                pool.rebalance_authority_list_pda().address,
+                pool.pool_config_pda().address,
+            ])
             .await
             .unwrap();
+        let pool_config_data = fetched.pop().unwrap().map_or_else(Vec::new, |acc| acc.data);
+        let rebalance_authority_list_data =
+            fetched.pop().unwrap().map_or_else(Vec::new, |acc| acc.data);
         let lst_state_list_acc = fetched.pop().unwrap().unwrap();
         let pool_acc = fetched.pop().unwrap().unwrap();
 
-        let mut spool = SPool::from_init_accounts(
-            program_id,
+        let mut spool = SPool::from_init_accounts_for_pool(
+            pool,
             SPoolInitAccounts {
                 lst_state_list: lst_state_list_acc,
                 pool_state: pool_acc,
@@ -153,7 +174,20 @@ impl RebalSolArgs {
 
         let (_state, LstData { sol_val_calc, .. }) =
             spool.find_ready_lst(sanctum_lst.mint).unwrap();
-        let required_lst_deposit = sol_val_calc.sol_to_lst(lamports).unwrap().get_max();
+        let pool_state_data = spool.pool_state_data().unwrap();
+        let pool_state = try_pool_state(&pool_state_data).unwrap();
+        let pool_config = try_pool_config(&pool_config_data).unwrap();
+        let max_sol_value_loss = calc_max_rebalance_sol_value_loss(
+            pool_state.total_sol_value,
+            pool_config.max_rebalance_loss_bps,
+            max_loss_lamports,
+        )
+        .unwrap();
+        // wSOL's SOL value is its amount
+        let required_lst_deposit = sol_val_calc
+            .sol_to_lst(lamports.saturating_sub(max_sol_value_loss))
+            .unwrap()
+            .get_max();
 
         let lst_minted = deposit_sol.quote_deposit_sol(lamports).unwrap();
 
@@ -178,15 +212,36 @@ impl RebalSolArgs {
             eprintln!("No subsidy required, proceeding");
         }
 
-        let expected_rebalance_auth = try_pool_state(&spool.pool_state_data().unwrap())
-            .unwrap()
-            .rebalance_authority;
-        if rebalance_auth.pubkey() != expected_rebalance_auth {
+        let rebalance_authority_list =
+            try_rebalance_authority_list(&rebalance_authority_list_data).unwrap();
+        if verify_rebalance_authority(
+            pool_state,
+            rebalance_authority_list,
+            rebalance_auth.pubkey(),
+        )
+        .is_err()
+        {
             panic!(
-                "Wrong rebalance auth. Expecting {expected_rebalance_auth}, got {}",
+                "Wrong rebalance auth. Expecting {} or one on the rebalance authority list, got {}",
+                pool_state.rebalance_authority,
                 rebalance_auth.pubkey()
             )
         }
+        let epoch = rpc.get_epoch_info().await.unwrap().epoch;
+        let remaining =
+            match try_find_rebalance_authority(rebalance_auth.pubkey(), rebalance_authority_list) {
+                Some((_i, budget)) => rebalance_authority_budget_remaining(budget, epoch),
+                None => pool_rebalance_authority_budget_remaining(&pool_config, epoch),
+            };
+        if let Some(remaining) = remaining {
+            // wSOL's SOL value is its amount
+            if lamports > remaining {
+                panic!(
+                    "Rebalance auth only has {} SOL of its budget left for this epoch",
+                    lamports_to_sol(remaining)
+                );
+            }
+        }
 
         // 2 CB ixs + create wsol ata + start + depositSol + transfer + end
         let mut ixs = Vec::with_capacity(7);
@@ -227,16 +282,16 @@ impl RebalSolArgs {
             ));
         }
 
-        let start_rebalance_ix = start_rebalance_ix_by_mints_full_for_prog(
-            program_id,
+        let mut start_rebalance_ix = start_rebalance_ix_by_mints_full_for_pool(
+            &pool,
             StartRebalanceByMintsFreeArgs {
                 withdraw_to: wsol_withdraw_to,
                 lst_state_list: Keyed {
-                    pubkey: lst_state_list_id,
+                    pubkey: pool.lst_state_list_pda().address,
                     account: &spool.lst_state_list_account,
                 },
                 pool_state: Keyed {
-                    pubkey: pool_id,
+                    pubkey: pool.pool_state,
                     account: &spool.pool_state_account.unwrap(),
                 },
                 src_lst_mint: MintWithTokenProgram {
@@ -253,6 +308,7 @@ impl RebalSolArgs {
                 // TODO: allow for slippage config
                 min_starting_src_lst: 0,
                 max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: max_sol_value_loss,
             },
             SrcDstLstSolValueCalcAccountSuffixes {
                 src_lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
@@ -260,11 +316,15 @@ impl RebalSolArgs {
             },
         )
         .unwrap();
+        // resolved to the pool's rebalance authority,
+        // replace with the one on the rebalance authority list if that's what's signing
+        start_rebalance_ix.accounts[START_REBALANCE_IX_REBALANCE_AUTHORITY_INDEX].pubkey =
+            rebalance_auth.pubkey();
         let end_rebalance_ix =
             end_rebalance_ix_from_start_rebalance_ix(&start_rebalance_ix).unwrap();
         ixs.push(start_rebalance_ix);
         let (lst_reserves, _) = find_pool_reserves_address_with_pool_state_id(
-            pool_id,
+            pool.pool_state,
             FindLstPdaAtaKeys {
                 lst_mint: sanctum_lst.mint,
                 token_program: sanctum_lst.token_program,
@@ -272,20 +332,23 @@ impl RebalSolArgs {
         );
         ixs.push(
             deposit_sol
-                .deposit_sol_ix(&SwapParams {
-                    in_amount: lamports,
-                    out_amount: lst_minted,
-                    source_mint: native_mint::ID,
-                    destination_mint: sanctum_lst.mint,
-                    source_token_account: wsol_withdraw_to,
-                    destination_token_account: lst_reserves,
-                    token_transfer_authority: payer.pubkey(),
-                    // dont cares
-                    open_order_address: None,
-                    quote_mint_to_referrer: None,
-                    jupiter_program_id: &Pubkey::default(),
-                    missing_dynamic_accounts_as_default: false,
-                })
+                .deposit_sol_ix(
+                    sanctum_lst.token_program,
+                    &SwapParams {
+                        in_amount: lamports,
+                        out_amount: lst_minted,
+                        source_mint: native_mint::ID,
+                        destination_mint: sanctum_lst.mint,
+                        source_token_account: wsol_withdraw_to,
+                        destination_token_account: lst_reserves,
+                        token_transfer_authority: payer.pubkey(),
+                        // dont cares
+                        open_order_address: None,
+                        quote_mint_to_referrer: None,
+                        jupiter_program_id: &Pubkey::default(),
+                        missing_dynamic_accounts_as_default: false,
+                    },
+                )
                 .unwrap(),
         );
         if subsidy_amt > 0 {
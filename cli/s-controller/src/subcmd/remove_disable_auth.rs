@@ -7,7 +7,6 @@ use clap::{
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::remove_disable_pool_authority_ix_with_program_id;
 use s_controller_lib::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
     try_disable_pool_authority_list, try_pool_state, RemoveDisablePoolAuthorityByPubkeyFreeArgs,
 };
 use sanctum_solana_cli_utils::parse_signer;
@@ -58,14 +57,15 @@ impl RemoveDisableAuthArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
         let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let disable_auth_list_addr = find_disable_pool_authority_list_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let disable_auth_list_addr = pool.disable_pool_authority_list_pda().address;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, disable_auth_list_addr])
             .await
@@ -93,7 +93,7 @@ impl RemoveDisableAuthArgs {
             pool_state_acc,
             disable_pool_authority_list: disable_auth_list_acc,
         }
-        .resolve_for_prog(program_id)
+        .resolve_for_pool(&pool)
         .unwrap();
 
         let ix =
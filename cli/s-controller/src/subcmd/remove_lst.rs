@@ -4,9 +4,7 @@ use clap::{
 };
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::remove_lst_ix_with_program_id;
-use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, RemoveLstByMintFreeArgs,
-};
+use s_controller_lib::RemoveLstByMintFreeArgs;
 use sanctum_solana_cli_utils::parse_signer;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::pubkey::Pubkey;
@@ -61,14 +59,15 @@ impl RemoveLstArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
         let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[mint, pool_state_addr, lst_state_list_addr])
             .await
@@ -86,7 +85,7 @@ impl RemoveLstArgs {
                 account: mint_acc,
             },
         }
-        .resolve_for_prog(program_id)
+        .resolve_for_pool(&pool)
         .unwrap();
         let ix = remove_lst_ix_with_program_id(program_id, keys, ix_args).unwrap();
 
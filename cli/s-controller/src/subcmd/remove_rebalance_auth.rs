@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::remove_rebalance_authority_ix_with_program_id;
+use s_controller_lib::{try_pool_state, RemoveRebalanceAuthorityByPubkeyFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Removes a rebalance authority from the list of rebalance authorities")]
+pub struct RemoveRebalanceAuthArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "The account to refund rent SOL to. Defaults to config wallet if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub refund_rent_to: Option<Pubkey>,
+
+    #[arg(
+        help = "The rebalance authority to remove",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub removing: Pubkey,
+}
+
+impl RemoveRebalanceAuthArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            refund_rent_to,
+            removing,
+        } = match args.subcmd {
+            Subcmd::RemoveRebalanceAuth(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+        let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool.pool_state, pool.rebalance_authority_list_pda().address])
+            .await
+            .unwrap();
+        let list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let (keys, ix_args) = RemoveRebalanceAuthorityByPubkeyFreeArgs {
+            refund_rent_to,
+            rebalance_authority: removing,
+            pool_state_acc,
+            rebalance_authority_list: list_acc,
+        }
+        .resolve_for_pool(&pool)
+        .unwrap();
+
+        let ix = remove_rebalance_authority_ix_with_program_id(program_id, keys, ix_args).unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -1,7 +1,7 @@
 use clap::Args;
 use s_cli_utils::handle_tx_full;
 use s_controller_interface::set_admin_ix_with_program_id;
-use s_controller_lib::{find_pool_state_address, try_pool_state, SetAdminFreeArgs};
+use s_controller_lib::{try_pool_state, SetAdminFreeArgs};
 use sanctum_solana_cli_utils::{parse_signer, PubkeySrc};
 use solana_readonly_account::sdk::KeyedAccount;
 
@@ -11,8 +11,9 @@ use super::Subcmd;
 
 #[derive(Args, Debug)]
 #[command(
-    about = "Sets the S controller program's admin authority.",
-    long_about = "Sets the S controller program's admin authority.
+    about = "Proposes a new admin authority for the S controller program.",
+    long_about = "Proposes a new admin authority for the S controller program.
+The new admin must then run accept-admin for the change to take effect.
 
 Prerequisites:
 - The program's pool state must be initialized prior to the invocation."
@@ -25,7 +26,7 @@ pub struct SetAdminArgs {
     )]
     pub curr_admin: Option<String>,
 
-    #[arg(help = "The new program's admin authority to set. Can be a pubkey or signer.")]
+    #[arg(help = "The new program's admin authority to propose. Can be a pubkey or signer.")]
     pub new_admin: String,
 }
 
@@ -41,26 +42,28 @@ impl SetAdminArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let curr_admin_signer = curr_admin.map(|s| parse_signer(&s).unwrap());
         let curr_admin = curr_admin_signer.as_ref().unwrap_or(&payer);
         let new_admin = PubkeySrc::parse(&new_admin).unwrap().pubkey();
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, curr_admin.pubkey()).unwrap();
 
         let ix = set_admin_ix_with_program_id(
             program_id,
             SetAdminFreeArgs {
+                payer: payer.pubkey(),
                 new_admin,
                 pool_state: KeyedAccount {
-                    pubkey: find_pool_state_address(program_id).0,
+                    pubkey: pool.pool_state,
                     account: pool_state_acc,
                 },
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(&pool)
             .unwrap(),
         )
         .unwrap();
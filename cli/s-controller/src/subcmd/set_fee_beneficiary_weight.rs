@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::set_protocol_fee_beneficiary_weight_ix_with_program_id;
+use s_controller_lib::{try_pool_state, SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Sets the share of protocol fees a beneficiary receives on distribution")]
+pub struct SetFeeBeneficiaryWeightArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The beneficiary to set the weight of",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub beneficiary: Pubkey,
+
+    #[arg(help = "The beneficiary's new share of protocol fees in bips")]
+    pub weight_bps: u16,
+}
+
+impl SetFeeBeneficiaryWeightArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            beneficiary,
+            weight_bps,
+        } = match args.subcmd {
+            Subcmd::SetFeeBeneficiaryWeight(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[
+                pool.pool_state,
+                pool.protocol_fee_beneficiary_list_pda().address,
+            ])
+            .await
+            .unwrap();
+        let list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let (keys, mut ix_args) = SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs {
+            beneficiary,
+            pool_state_acc,
+            protocol_fee_beneficiary_list: list_acc,
+        }
+        .resolve_for_pool(&pool)
+        .unwrap();
+        ix_args.weight_bps = weight_bps;
+
+        let ix = set_protocol_fee_beneficiary_weight_ix_with_program_id(program_id, keys, ix_args)
+            .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
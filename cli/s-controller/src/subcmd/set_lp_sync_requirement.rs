@@ -0,0 +1,91 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    set_lp_sync_requirement_ix_with_program_id, SetLpSyncRequirementIxArgs,
+};
+use s_controller_lib::{try_pool_state, SetLpSyncRequirementFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Enables or disables requiring LSTs to be synced this epoch for LP instructions",
+    long_about = "Enables or disables requiring LSTs to be synced this epoch for LP instructions.
+
+When enabled, AddLiquidity, AddLiquidityMulti, DepositStake, RemoveLiquidity, RemoveLiquidityProportional and RemoveLiquidityToStake fail unless every LST in the pool with a SOL value at or above the dust threshold has had SyncSolValue run on it in the current epoch."
+)]
+pub struct SetLpSyncRequirementArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        long,
+        help = "Disable the sync requirement instead of enabling it",
+        default_value_t = false
+    )]
+    pub disable: bool,
+
+    #[arg(
+        long,
+        help = "LSTs with a SOL value below this amount, in lamports, are exempt from the requirement",
+        default_value_t = 0
+    )]
+    pub dust_threshold: u64,
+}
+
+impl SetLpSyncRequirementArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            disable,
+            dust_threshold,
+        } = match args.subcmd {
+            Subcmd::SetLpSyncRequirement(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_lp_sync_requirement_ix_with_program_id(
+            program_id,
+            SetLpSyncRequirementFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            SetLpSyncRequirementIxArgs {
+                lp_requires_synced_lsts: !disable,
+                lp_synced_lsts_dust_threshold: dust_threshold,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
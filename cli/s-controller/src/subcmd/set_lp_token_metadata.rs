@@ -0,0 +1,81 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{set_lp_token_metadata_ix_with_program_id, SetLpTokenMetadataIxArgs};
+use s_controller_lib::{try_pool_state, SetLpTokenMetadataFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Creates or updates the LP token's name, symbol and URI.
+
+Token-2022 LP token mints store these in the token metadata extension on the mint, which must already have its metadata pointer set to itself. Tokenkeg LP token mints store these in a metaplex metadata account. The payer pays for any additional rent required.")]
+pub struct SetLpTokenMetadataArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "The LP token's name")]
+    pub name: String,
+
+    #[arg(help = "The LP token's symbol")]
+    pub symbol: String,
+
+    #[arg(help = "URI of the LP token's off-chain metadata JSON")]
+    pub uri: String,
+}
+
+impl SetLpTokenMetadataArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            name,
+            symbol,
+            uri,
+        } = match args.subcmd {
+            Subcmd::SetLpTokenMetadata(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+        let lp_token_mint_acc = rpc.get_account(&pool_state.lp_token_mint).await.unwrap();
+
+        let ix = set_lp_token_metadata_ix_with_program_id(
+            program_id,
+            SetLpTokenMetadataFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+                lp_token_mint: lp_token_mint_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            SetLpTokenMetadataIxArgs { name, symbol, uri },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,94 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    set_lst_circuit_breaker_ix_by_mint_full_for_pool, try_pool_state,
+    SetLstCircuitBreakerByMintFreeArgs,
+};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the SOL value rate circuit breaker threshold of a LST.
+
+If a sync sees the SOL value of 1 unit of the LST move by more than this threshold since the previous sync in the same epoch, input and output for the LST are disabled. Set to 0 to turn the circuit breaker off."
+)]
+pub struct SetLstCircuitBreakerArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to set the circuit breaker threshold of",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub mint: Pubkey,
+
+    #[arg(help = "The LST's new circuit breaker threshold in bips. 0 means off.")]
+    pub sol_value_rate_circuit_breaker_bps: u16,
+}
+
+impl SetLstCircuitBreakerArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            mint,
+            sol_value_rate_circuit_breaker_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstCircuitBreaker(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data()).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_lst_circuit_breaker_ix_by_mint_full_for_pool(
+            &pool,
+            &SetLstCircuitBreakerByMintFreeArgs {
+                lst_mint: mint,
+                pool_state: pool_state_acc,
+                lst_state_list: lst_state_list_acc,
+            },
+            sol_value_rate_circuit_breaker_bps,
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
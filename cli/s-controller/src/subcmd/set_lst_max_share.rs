@@ -0,0 +1,93 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_lib::{
+    set_lst_max_share_ix_by_mint_full_for_pool, try_pool_state, SetLstMaxShareByMintFreeArgs,
+};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the maximum share of the pool's total SOL value a LST may make up.
+
+Swaps and AddLiquidity that would push the LST over this share are rejected. Set to 0 to remove the cap."
+)]
+pub struct SetLstMaxShareArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to set the max share of",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub mint: Pubkey,
+
+    #[arg(help = "The LST's new max share in bips. 0 means uncapped.")]
+    pub max_share_bps: u16,
+}
+
+impl SetLstMaxShareArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            mint,
+            max_share_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstMaxShare(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data()).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_lst_max_share_ix_by_mint_full_for_pool(
+            &pool,
+            &SetLstMaxShareByMintFreeArgs {
+                lst_mint: mint,
+                pool_state: pool_state_acc,
+                lst_state_list: lst_state_list_acc,
+            },
+            max_share_bps,
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -0,0 +1,78 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    set_max_rebalance_loss_ix_with_program_id, SetMaxRebalanceLossIxArgs,
+};
+use s_controller_lib::{try_pool_state, SetMaxRebalanceLossFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Sets the max SOL value a rebalance is allowed to lose, in bps of the pool's total SOL value",
+    long_about = "Sets the max SOL value a rebalance is allowed to lose, in bps of the pool's total SOL value.
+
+Each rebalance is further capped by the max_loss_lamports passed to its StartRebalance. Set to 0 to require rebalances to not lose any SOL value."
+)]
+pub struct SetMaxRebalanceLossArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "The max rebalance loss to set, in bps")]
+    pub max_rebalance_loss_bps: u16,
+}
+
+impl SetMaxRebalanceLossArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            max_rebalance_loss_bps,
+        } = match args.subcmd {
+            Subcmd::SetMaxRebalanceLoss(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_max_rebalance_loss_ix_with_program_id(
+            program_id,
+            SetMaxRebalanceLossFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            SetMaxRebalanceLossIxArgs {
+                max_rebalance_loss_bps,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
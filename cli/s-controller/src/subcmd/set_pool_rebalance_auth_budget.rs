@@ -0,0 +1,78 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    set_pool_rebalance_authority_budget_ix_with_program_id, SetPoolRebalanceAuthorityBudgetIxArgs,
+};
+use s_controller_lib::{try_pool_state, SetPoolRebalanceAuthorityBudgetFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Sets the max SOL value the pool's rebalance authority can withdraw from the pool each epoch",
+    long_about = "Sets the max SOL value the pool's rebalance authority can withdraw from the pool each epoch.
+
+Does not apply if the pool's rebalance authority is also on the rebalance authority list, in which case its entry's budget applies instead. Set to 0 to not rate-limit the pool's rebalance authority."
+)]
+pub struct SetPoolRebalanceAuthBudgetArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The pool's rebalance authority's new budget in lamports of SOL value per epoch. 0 disallows any withdrawal, 18446744073709551615 (u64::MAX) means no limit"
+    )]
+    pub epoch_budget: u64,
+}
+
+impl SetPoolRebalanceAuthBudgetArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            epoch_budget,
+        } = match args.subcmd {
+            Subcmd::SetPoolRebalanceAuthBudget(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_pool_rebalance_authority_budget_ix_with_program_id(
+            program_id,
+            SetPoolRebalanceAuthorityBudgetFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            SetPoolRebalanceAuthorityBudgetIxArgs { epoch_budget },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
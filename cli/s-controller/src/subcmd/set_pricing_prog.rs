@@ -3,16 +3,24 @@ use clap::{
     Args,
 };
 use s_cli_utils::handle_tx_full;
-use s_controller_interface::set_pricing_program_ix_with_program_id;
-use s_controller_lib::{try_pool_state, SetPricingProgramFreeArgs};
+use s_controller_interface::{
+    queue_set_pricing_program_ix_with_program_id, set_pricing_program_ix_with_program_id,
+};
+use s_controller_lib::{try_pool_state, QueueSetPricingProgramFreeArgs, SetPricingProgramFreeArgs};
 use sanctum_solana_cli_utils::parse_signer;
 
-use crate::{common::verify_admin, pricing_prog_arg::PricingProgArg, rpc::fetch_pool_state};
+use crate::{
+    common::{verify_admin, verify_no_timelock},
+    pricing_prog_arg::PricingProgArg,
+    rpc::fetch_pool_state,
+};
 
 use super::Subcmd;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the S controller program's pricing program.")]
+#[command(long_about = "Sets the S controller program's pricing program.
+
+If the pool has a nonzero timelock delay, the change must be queued with --queue and then executed with execute-queued-change after the delay.")]
 pub struct SetPricingProgArgs {
     #[arg(
         long,
@@ -26,6 +34,13 @@ pub struct SetPricingProgArgs {
 - flat-fee",
     value_parser = StringValueParser::new().try_map(|s| PricingProgArg::parse_arg(&s)))]
     pub pricing_prog: PricingProgArg,
+
+    #[arg(
+        long,
+        short,
+        help = "Queue the change instead of setting it immediately. Rent for the queued change account is paid by the payer."
+    )]
+    pub queue: bool,
 }
 
 impl SetPricingProgArgs {
@@ -33,6 +48,7 @@ impl SetPricingProgArgs {
         let Self {
             admin,
             pricing_prog,
+            queue,
         } = match args.subcmd {
             Subcmd::SetPricingProg(a) => a,
             _ => unreachable!(),
@@ -40,25 +56,41 @@ impl SetPricingProgArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
 
-        let ix = set_pricing_program_ix_with_program_id(
-            program_id,
-            SetPricingProgramFreeArgs {
-                new_pricing_program: pricing_prog.program_id(),
-                pool_state_acc,
-            }
-            .resolve_for_prog(program_id)
-            .unwrap(),
-        )
-        .unwrap();
+        let ix = if queue {
+            queue_set_pricing_program_ix_with_program_id(
+                program_id,
+                QueueSetPricingProgramFreeArgs {
+                    payer: payer.pubkey(),
+                    new_pricing_program: pricing_prog.program_id(),
+                    pool_state_acc,
+                }
+                .resolve_for_pool(&pool)
+                .unwrap(),
+            )
+            .unwrap()
+        } else {
+            verify_no_timelock(pool_state).unwrap();
+            set_pricing_program_ix_with_program_id(
+                program_id,
+                SetPricingProgramFreeArgs {
+                    new_pricing_program: pricing_prog.program_id(),
+                    pool_state_acc,
+                }
+                .resolve_for_pool(&pool)
+                .unwrap(),
+            )
+            .unwrap()
+        };
 
         handle_tx_full(
             &rpc,
@@ -55,12 +55,13 @@ impl SetProtocolFeeArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
 
@@ -69,7 +70,7 @@ impl SetProtocolFeeArgs {
             SetProtocolFeeFreeArgs {
                 pool_state: pool_state_acc,
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(&pool)
             .unwrap(),
             SetProtocolFeeIxArgs {
                 new_trading_protocol_fee_bps,
@@ -15,7 +15,8 @@ use crate::{common::verify_protocol_fee_beneficiary, rpc::fetch_pool_state};
 use super::Subcmd;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the pool's protocol fee beneficiary.")]
+#[command(long_about = "Proposes a new protocol fee beneficiary for the pool.
+The new beneficiary must then run accept-protocol-fee-beneficiary for the change to take effect.")]
 pub struct SetProtocolFeeBeneficiaryArgs {
     #[arg(
         long,
@@ -25,7 +26,7 @@ pub struct SetProtocolFeeBeneficiaryArgs {
     pub curr_beneficiary: Option<String>,
 
     #[arg(
-        help = "The pool's new protocol fee beneficiary to set.",
+        help = "The pool's new protocol fee beneficiary to propose.",
         value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s))
     )]
     pub new_beneficiary: Pubkey,
@@ -43,22 +44,24 @@ impl SetProtocolFeeBeneficiaryArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let curr_beneficiary_signer = curr_beneficiary.map(|s| parse_signer(&s).unwrap());
         let curr_beneficiary = curr_beneficiary_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_protocol_fee_beneficiary(pool_state, curr_beneficiary.pubkey()).unwrap();
 
         let ix = set_protocol_fee_beneficiary_ix_with_program_id(
             program_id,
             SetProtocolFeeBeneficiaryFreeArgs {
+                payer: payer.pubkey(),
                 new_beneficiary,
                 pool_state: pool_state_acc,
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(&pool)
             .unwrap(),
         )
         .unwrap();
@@ -0,0 +1,93 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    set_protocol_fees_as_lp_ix_with_program_id, SetProtocolFeesAsLpIxArgs,
+};
+use s_controller_lib::{try_pool_state, SetProtocolFeesAsLpFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Enables or disables accruing protocol fees as LP tokens",
+    long_about = "Enables or disables accruing protocol fees as LP tokens.
+
+When enabled, the protocol fees of SwapExactIn, SwapExactOut, AddLiquidity and RemoveLiquidity stay in the pool reserves and the equivalent LP tokens are minted to the protocol fee PDA's LP token ATA, which is created if it does not exist.
+Existing protocol fee accumulator balances can be converted with convert-protocol-fees-to-lp."
+)]
+pub struct SetProtocolFeesAsLpArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        long,
+        help = "Disable accruing protocol fees as LP tokens instead of enabling it",
+        default_value_t = false
+    )]
+    pub disable: bool,
+}
+
+impl SetProtocolFeesAsLpArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { admin, disable } = match args.subcmd {
+            Subcmd::SetProtocolFeesAsLp(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+        let lp_token_mint = pool_state.lp_token_mint;
+
+        let mut ixs = Vec::with_capacity(2);
+        if !disable {
+            ixs.push(create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &pool.protocol_fee_pda().address,
+                &lp_token_mint,
+                &spl_token::ID,
+            ));
+        }
+        ixs.push(
+            set_protocol_fees_as_lp_ix_with_program_id(
+                program_id,
+                SetProtocolFeesAsLpFreeArgs {
+                    payer: payer.pubkey(),
+                    pool_state: pool_state_acc,
+                }
+                .resolve_for_pool(&pool)
+                .unwrap(),
+                SetProtocolFeesAsLpIxArgs {
+                    protocol_fees_as_lp: !disable,
+                },
+            )
+            .unwrap(),
+        );
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            ixs,
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
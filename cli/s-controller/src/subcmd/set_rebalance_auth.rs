@@ -9,7 +9,10 @@ use crate::rpc::fetch_pool_state;
 use super::Subcmd;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the S controller program's rebalance authority.")]
+#[command(
+    long_about = "Proposes a new rebalance authority for the S controller program.
+The new rebalance authority must then run accept-rebalance-auth for the change to take effect."
+)]
 pub struct SetRebalanceAuthArgs {
     #[arg(
         long,
@@ -18,7 +21,7 @@ pub struct SetRebalanceAuthArgs {
     )]
     pub authority: Option<String>,
 
-    #[arg(help = "The new rebalance authority to set to. Can be a pubkey or signer.")]
+    #[arg(help = "The new rebalance authority to propose. Can be a pubkey or signer.")]
     pub new_rebalance_auth: String,
 }
 
@@ -34,27 +37,30 @@ impl SetRebalanceAuthArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
         let new_rebalance_authority = PubkeySrc::parse(&new_rebalance_auth).unwrap().pubkey();
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         let keys = if pool_state.admin == authority.pubkey() {
             KnownAuthoritySetRebalanceAuthorityFreeArgs {
+                payer: payer.pubkey(),
                 new_rebalance_authority,
                 pool_state: pool_state_acc,
             }
-            .resolve_pool_admin_for_prog(program_id)
+            .resolve_pool_admin_for_pool(&pool)
             .unwrap()
         } else if pool_state.rebalance_authority == authority.pubkey() {
             KnownAuthoritySetRebalanceAuthorityFreeArgs {
+                payer: payer.pubkey(),
                 new_rebalance_authority,
                 pool_state: pool_state_acc,
             }
-            .resolve_current_rebalance_authority_for_prog(program_id)
+            .resolve_current_rebalance_authority_for_pool(&pool)
             .unwrap()
         } else {
             eprintln!(
@@ -0,0 +1,92 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::set_rebalance_authority_budget_ix_with_program_id;
+use s_controller_lib::{try_pool_state, SetRebalanceAuthorityBudgetByPubkeyFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::verify_admin;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the max SOL value a rebalance authority on the list can withdraw from the pool each epoch"
+)]
+pub struct SetRebalanceAuthBudgetArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The rebalance authority to set the budget of",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub rebalance_authority: Pubkey,
+
+    #[arg(
+        help = "The rebalance authority's new budget in lamports of SOL value per epoch. 0 disallows any withdrawal, 18446744073709551615 (u64::MAX) means no limit"
+    )]
+    pub epoch_budget: u64,
+}
+
+impl SetRebalanceAuthBudgetArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            rebalance_authority,
+            epoch_budget,
+        } = match args.subcmd {
+            Subcmd::SetRebalanceAuthBudget(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool.pool_state, pool.rebalance_authority_list_pda().address])
+            .await
+            .unwrap();
+        let list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let (keys, mut ix_args) = SetRebalanceAuthorityBudgetByPubkeyFreeArgs {
+            rebalance_authority,
+            pool_state_acc,
+            rebalance_authority_list: list_acc,
+        }
+        .resolve_for_pool(&pool)
+        .unwrap();
+        ix_args.epoch_budget = epoch_budget;
+
+        let ix =
+            set_rebalance_authority_budget_ix_with_program_id(program_id, keys, ix_args).unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
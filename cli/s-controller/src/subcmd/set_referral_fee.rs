@@ -0,0 +1,73 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{set_referral_fee_ix_with_program_id, SetReferralFeeIxArgs};
+use s_controller_lib::{try_pool_state, SetReferralFeeFreeArgs};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Sets the share of swap protocol fees paid to referrers.
+
+The referral fee is carved out of the protocol fees charged on a swap and transferred to the referrer's token account of the output LST. Swaps without a referrer are unaffected.")]
+pub struct SetReferralFeeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "The new referral fee in bips of the protocol fees")]
+    pub referral_fee_bps: u16,
+}
+
+impl SetReferralFeeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            referral_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetReferralFee(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_referral_fee_ix_with_program_id(
+            program_id,
+            SetReferralFeeFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            SetReferralFeeIxArgs {
+                new_fee_bps: referral_fee_bps,
+            },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
@@ -5,21 +5,28 @@ use clap::{
     Args,
 };
 use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    queue_set_sol_value_calculator_ix_with_program_id, QueueSetSolValueCalculatorIxArgs,
+};
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address,
-    set_sol_value_calculator_ix_by_mint_full_with_program_id, try_pool_state,
-    SetSolValueCalculatorByMintFreeArgs,
+    index_to_u32, set_sol_value_calculator_ix_by_mint_full_with_program_id, try_pool_state,
+    QueueSetSolValueCalculatorByMintFreeArgs, SetSolValueCalculatorByMintFreeArgs,
 };
 use sanctum_solana_cli_utils::parse_signer;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 
-use crate::{common::verify_admin, lst_arg::LstArg};
+use crate::{
+    common::{verify_admin, verify_no_timelock},
+    lst_arg::LstArg,
+};
 
 use super::Subcmd;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the SOL value calculator program for a LST.")]
+#[command(long_about = "Sets the SOL value calculator program for a LST.
+
+If the pool has a nonzero timelock delay, the change must be queued with --queue and then executed with execute-queued-change after the delay.")]
 pub struct SetSolValueCalculatorArgs {
     #[arg(
         long,
@@ -47,11 +54,18 @@ pub struct SetSolValueCalculatorArgs {
     #[arg(
         long = "account-suffix",
         short = 'c',
-        help = "Account suffix slice to call LstToSol for the given LST, excluding the program ID and mint.",
-        required = true,
+        help = "Account suffix slice to call LstToSol for the given LST, excluding the program ID and mint. Not required with --queue.",
+        required_unless_present = "queue",
         num_args(1..),
     )]
     pub account_suffix: Vec<Pubkey>,
+
+    #[arg(
+        long,
+        short,
+        help = "Queue the change instead of setting it immediately. Rent for the queued change account is paid by the payer."
+    )]
+    pub queue: bool,
 }
 
 impl SetSolValueCalculatorArgs {
@@ -61,6 +75,7 @@ impl SetSolValueCalculatorArgs {
             sol_val_calc,
             mint,
             account_suffix,
+            queue,
         } = match args.subcmd {
             Subcmd::SetSolValueCalculator(a) => a,
             _ => unreachable!(),
@@ -68,13 +83,14 @@ impl SetSolValueCalculatorArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
         let mut fetched = rpc
             .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr, mint.mint()])
             .await
@@ -86,6 +102,37 @@ impl SetSolValueCalculatorArgs {
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
 
+        if queue {
+            let (keys, lst_index) = QueueSetSolValueCalculatorByMintFreeArgs {
+                payer: payer.pubkey(),
+                lst_mint: mint.mint(),
+                new_sol_value_calculator: sol_val_calc,
+                pool_state: pool_state_acc,
+                lst_state_list: lst_state_list_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap();
+            let ix = queue_set_sol_value_calculator_ix_with_program_id(
+                program_id,
+                keys,
+                QueueSetSolValueCalculatorIxArgs {
+                    lst_index: index_to_u32(lst_index).unwrap(),
+                },
+            )
+            .unwrap();
+            handle_tx_full(
+                &rpc,
+                args.fee_limit_cb,
+                args.send_mode,
+                vec![ix],
+                &[],
+                &mut [payer.as_ref(), admin.as_ref()],
+            )
+            .await;
+            return;
+        }
+        verify_no_timelock(pool_state).unwrap();
+
         let sol_value_calculator_accounts: Vec<AccountMeta> = std::iter::once(AccountMeta {
             pubkey: mint.mint(),
             is_signer: false,
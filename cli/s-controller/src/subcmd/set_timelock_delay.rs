@@ -0,0 +1,82 @@
+use clap::Args;
+use s_cli_utils::handle_tx_full;
+use s_controller_interface::{
+    queue_set_timelock_delay_ix_with_program_id, QueueSetTimelockDelayIxArgs,
+};
+use s_controller_lib::{try_pool_state, QueueSetTimelockDelayFreeArgs, TIMELOCK_DELAY_UNIT_SLOTS};
+use sanctum_solana_cli_utils::parse_signer;
+
+use crate::{common::verify_admin, rpc::fetch_pool_state};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Queues a change to the pool's timelock delay. The change is subject to the current timelock delay and must be executed with `execute-queued-change timelock-delay` after it.
+
+While the timelock delay is nonzero, set-pricing-prog and set-sol-value-calculator changes must be queued."
+)]
+pub struct SetTimelockDelayArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "The new timelock delay, in units of TIMELOCK_DELAY_UNIT_SLOTS (9000 slots, ~1 hour). 0 to disable the timelock."
+    )]
+    pub timelock_delay: u8,
+}
+
+impl SetTimelockDelayArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            timelock_delay,
+        } = match args.subcmd {
+            Subcmd::SetTimelockDelay(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
+
+        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, &pool).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        eprintln!(
+            "Queueing timelock delay change to {timelock_delay} ({} slots)",
+            u64::from(timelock_delay) * TIMELOCK_DELAY_UNIT_SLOTS
+        );
+
+        let ix = queue_set_timelock_delay_ix_with_program_id(
+            program_id,
+            QueueSetTimelockDelayFreeArgs {
+                payer: payer.pubkey(),
+                pool_state_acc,
+            }
+            .resolve_for_pool(&pool)
+            .unwrap(),
+            QueueSetTimelockDelayIxArgs { timelock_delay },
+        )
+        .unwrap();
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            vec![ix],
+            &[],
+            &mut [payer.as_ref(), admin.as_ref()],
+        )
+        .await;
+    }
+}
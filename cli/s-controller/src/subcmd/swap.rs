@@ -0,0 +1,214 @@
+use std::{collections::HashMap, str::FromStr};
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use jupiter_amm_interface::{QuoteParams, SwapMode, SwapParams};
+use s_cli_utils::handle_tx_full;
+use s_jup_interface::{LstData, SPool, SPoolInitAccounts};
+use sanctum_token_lib::token_account_balance;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{common::SANCTUM_LST_LIST, lst_amt_arg::LstAmtArg, lst_arg::LstArg};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Swap an exact amount of one LST in the pool for another",
+    long_about = "Swap an exact amount of one LST in the pool for another.
+
+Swaps from the config wallet's ATA of the input LST to its ATA of the output LST, creating the output ATA if it does not exist.
+If a referrer is given, the pool's referral fee share of the protocol fees is paid to it in the output LST."
+)]
+pub struct SwapArgs {
+    #[arg(
+        long,
+        short,
+        help = "The referrer's token account of the output LST to pay referral fees to. No referral fees are paid if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub referrer: Option<Pubkey>,
+
+    #[arg(
+        long,
+        default_value_t = 50,
+        help = "Max slippage from the quoted output amount in bips"
+    )]
+    pub slippage_bps: u16,
+
+    #[arg(
+        help = "Amount of the input LST to swap in. Set to 'all' to swap the entire balance.",
+        value_parser = StringValueParser::new().try_map(|s| LstAmtArg::parse_arg(&s)),
+    )]
+    pub amt: LstAmtArg,
+
+    #[arg(
+        help = "The LST to swap from. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub input: LstArg,
+
+    #[arg(
+        help = "The LST to swap to. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub output: LstArg,
+}
+
+impl SwapArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            referrer,
+            slippage_bps,
+            amt,
+            input,
+            output,
+        } = match args.subcmd {
+            Subcmd::Swap(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let pool = args.pool_key();
+
+        let (input_mint, output_mint) = (input.mint(), output.mint());
+
+        let mut fetched = rpc
+            .get_multiple_accounts(&[pool.pool_state, pool.lst_state_list_pda().address])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched.pop().unwrap().unwrap();
+        let pool_acc = fetched.pop().unwrap().unwrap();
+
+        let mut spool = SPool::from_init_accounts_for_pool(
+            pool,
+            SPoolInitAccounts {
+                lst_state_list: lst_state_list_acc,
+                pool_state: pool_acc,
+            },
+            &SANCTUM_LST_LIST.sanctum_lst_list,
+        )
+        .unwrap();
+
+        let mut accounts_to_fetch: Vec<Pubkey> = spool
+            .get_accounts_to_update_base()
+            .into_iter()
+            .chain(spool.get_accounts_to_update_pricing_prog_for_lsts(
+                [input_mint, output_mint].into_iter(),
+            ))
+            .chain(spool.get_accounts_to_update_lsts_filtered(|state, _data| {
+                state.mint == input_mint || state.mint == output_mint
+            }))
+            .collect();
+        accounts_to_fetch.sort();
+        accounts_to_fetch.dedup();
+
+        let account_map: HashMap<Pubkey, Account> = rpc
+            .get_multiple_accounts(&accounts_to_fetch)
+            .await
+            .unwrap()
+            .into_iter()
+            .zip(accounts_to_fetch)
+            .filter_map(|(acc, pk)| acc.map(|acc| (pk, acc)))
+            .collect();
+        spool.update_full(&account_map).unwrap();
+
+        let [src_token_program, dst_token_program] = [input_mint, output_mint].map(|mint| {
+            let (_state, LstData { token_program, .. }) = spool.find_ready_lst(mint).unwrap();
+            *token_program
+        });
+        let [src_lst_acc, dst_lst_acc] = [
+            (input_mint, src_token_program),
+            (output_mint, dst_token_program),
+        ]
+        .map(|(mint, token_program)| {
+            get_associated_token_address_with_program_id(&payer.pubkey(), &mint, &token_program)
+        });
+
+        let mut fetched = rpc
+            .get_multiple_accounts(&[src_lst_acc, dst_lst_acc])
+            .await
+            .unwrap();
+        let dst_lst_acc_fetched = fetched.pop().unwrap();
+        let src_lst_acc_fetched = fetched
+            .pop()
+            .unwrap()
+            .unwrap_or_else(|| panic!("Payer does not have an ATA for {input_mint}"));
+
+        let in_amount = match amt {
+            LstAmtArg::Amt(v) => v,
+            LstAmtArg::All => token_account_balance(src_lst_acc_fetched).unwrap(),
+        };
+
+        let quote = spool
+            .quote_full(&QuoteParams {
+                amount: in_amount,
+                input_mint,
+                output_mint,
+                swap_mode: SwapMode::ExactIn,
+            })
+            .unwrap();
+        if quote.not_enough_liquidity {
+            panic!("Not enough liquidity for {output_mint} in pool");
+        }
+        let min_amount_out = u64::try_from(
+            u128::from(quote.out_amount) * u128::from(BPS_DENOMINATOR.saturating_sub(slippage_bps))
+                / u128::from(BPS_DENOMINATOR),
+        )
+        .unwrap();
+        eprintln!(
+            "Swapping {in_amount} {input_mint} for {} {output_mint} (min {min_amount_out})",
+            quote.out_amount
+        );
+
+        let mut ixs = Vec::with_capacity(2);
+        if dst_lst_acc_fetched.is_none() {
+            ixs.push(create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &output_mint,
+                &dst_token_program,
+            ));
+        }
+        let quote_mint_to_referrer = referrer.map(|r| HashMap::from([(output_mint, r)]));
+        ixs.push(
+            spool
+                .swap_ix(
+                    &SwapParams {
+                        in_amount,
+                        out_amount: min_amount_out,
+                        source_mint: input_mint,
+                        destination_mint: output_mint,
+                        source_token_account: src_lst_acc,
+                        destination_token_account: dst_lst_acc,
+                        token_transfer_authority: payer.pubkey(),
+                        quote_mint_to_referrer: quote_mint_to_referrer.as_ref(),
+                        // dont cares
+                        open_order_address: None,
+                        jupiter_program_id: &Pubkey::default(),
+                        missing_dynamic_accounts_as_default: false,
+                    },
+                    SwapMode::ExactIn,
+                )
+                .unwrap(),
+        );
+
+        handle_tx_full(
+            &rpc,
+            args.fee_limit_cb,
+            args.send_mode,
+            ixs,
+            &[],
+            &mut [payer.as_ref()],
+        )
+        .await;
+    }
+}
@@ -4,8 +4,7 @@ use clap::{
 };
 use s_cli_utils::handle_tx_full;
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, sync_sol_value_ix_full_for_prog,
-    SyncSolValueByMintFreeArgs, SyncSolValuePdas,
+    sync_sol_value_ix_full_for_prog, SyncSolValueByMintFreeArgs, SyncSolValuePdas,
 };
 use sanctum_solana_client_utils::to_est_cu_sim_tx;
 use solana_readonly_account::keyed::Keyed;
@@ -61,7 +60,8 @@ impl SyncArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         // accounts suffix slice including lst_mint as first account
         let suffix = mint.sol_value_calculator_accounts_of().unwrap_or_else(|| {
@@ -78,8 +78,8 @@ impl SyncArgs {
             .collect()
         });
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
         let mint_addr = mint.mint();
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[mint_addr, lst_state_list_addr, pool_state_addr])
@@ -90,6 +90,7 @@ impl SyncArgs {
         let mint_acc = fetched_accs.pop().unwrap().unwrap();
 
         let (keys, lst_index, sol_value_calculator_program_id) = SyncSolValueByMintFreeArgs {
+            pool_state: &pool_state_acc,
             lst_state_list: lst_state_list_acc,
             lst_mint: Keyed {
                 pubkey: mint_addr,
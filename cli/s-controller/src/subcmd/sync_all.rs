@@ -1,15 +1,20 @@
+use std::ops::Range;
+
 use clap::Args;
 use s_cli_utils::handle_tx_full;
-use s_controller_interface::LstState;
+use s_controller_interface::{LstState, SyncSolValueMultiKeys};
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, sync_sol_value_ix_full_for_prog,
-    try_lst_state_list, SyncSolValueByMintFreeArgs, SyncSolValuePdas,
+    sync_sol_value_multi_ix_full_for_prog, try_lst_state_list_versioned, try_pool_state,
+    SyncSolValueMultiByMintFreeArgs, SyncSolValueMultiLst, SyncSolValuePdas,
 };
 use sanctum_lst_list::SanctumLst;
 use sanctum_solana_client_utils::to_est_cu_sim_tx;
 use sanctum_token_lib::MintWithTokenProgram;
 use solana_readonly_account::keyed::Keyed;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::{
+    instruction::AccountMeta, message::Message, packet::PACKET_DATA_SIZE, pubkey::Pubkey,
+    signature::SIGNATURE_BYTES,
+};
 
 use crate::{
     common::{find_sanctum_lst_by_mint, sol_value_calculator_accounts_of_sanctum_lst},
@@ -18,8 +23,10 @@ use crate::{
 
 use super::Subcmd;
 
+/// Leaves room for the compute budget program and its 2 instructions
+/// that are added to the tx before sending
 // TODO: this can probably increase if we use a LUT
-const MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX: usize = 4;
+const MAX_SYNC_SOL_VALUE_MULTI_TX_SIZE: usize = PACKET_DATA_SIZE - 64;
 
 #[derive(Args, Debug)]
 #[command(
@@ -46,10 +53,11 @@ impl SyncAllArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let pool_state_addr = pool.pool_state;
 
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[lst_state_list_addr, pool_state_addr])
@@ -58,9 +66,11 @@ impl SyncAllArgs {
         let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
         let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
 
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+        let version = try_pool_state(&pool_state_acc.data).unwrap().version;
+        let lst_state_list =
+            try_lst_state_list_versioned(version, &lst_state_list_acc.data).unwrap();
 
-        let sanctum_lsts: Vec<&SanctumLst> = lst_state_list
+        let mut sanctum_lsts: Vec<&SanctumLst> = lst_state_list
             .iter()
             .filter_map(|LstState { mint, .. }| {
                 let res = find_sanctum_lst_by_mint(*mint);
@@ -70,37 +80,55 @@ impl SyncAllArgs {
                 res
             })
             .collect();
+        // group LSTs with the same SOL value calculator together
+        // so that they share the calculator's accounts in the same tx
+        sanctum_lsts.sort_by_key(|sanctum_lst| {
+            lst_state_list
+                .iter()
+                .find(|lst_state| lst_state.mint == sanctum_lst.mint)
+                .map(|lst_state| lst_state.sol_value_calculator)
+        });
+
+        let lst_mints: Vec<MintWithTokenProgram> = sanctum_lsts
+            .iter()
+            .map(|sanctum_lst| MintWithTokenProgram {
+                pubkey: sanctum_lst.mint,
+                token_program: sanctum_lst.token_program,
+            })
+            .collect();
+        let (multi_keys, lst_keys) = SyncSolValueMultiByMintFreeArgs {
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_acc,
+            lst_mints: &lst_mints,
+        }
+        .resolve_with_pdas(SyncSolValuePdas {
+            pool_state: pool_state_addr,
+            lst_state_list: lst_state_list_addr,
+        })
+        .unwrap();
+        let sol_value_calculator_accounts: Vec<Vec<AccountMeta>> = sanctum_lsts
+            .iter()
+            .map(|sanctum_lst| sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst))
+            .collect();
+        let multi_lsts: Vec<SyncSolValueMultiLst> = lst_keys
+            .into_iter()
+            .zip(sol_value_calculator_accounts.iter())
+            .map(|(keys, accounts)| SyncSolValueMultiLst {
+                keys,
+                sol_value_calculator_accounts: accounts,
+            })
+            .collect();
 
         // dyn Signer is not Sync, so just send the txes sequentially
-        let fut_iter = sanctum_lsts
-            .chunks(MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX)
-            .map(|chunk| async {
-                let ixs: Vec<Instruction> = chunk
-                    .iter()
-                    .map(|sanctum_lst| {
-                        let (keys, index, sol_value_calculator_program_id) =
-                            SyncSolValueByMintFreeArgs {
-                                lst_state_list: &lst_state_list_acc,
-                                lst_mint: MintWithTokenProgram {
-                                    pubkey: sanctum_lst.mint,
-                                    token_program: sanctum_lst.token_program,
-                                },
-                            }
-                            .resolve_with_pdas(SyncSolValuePdas {
-                                pool_state: pool_state_addr,
-                                lst_state_list: lst_state_list_addr,
-                            })
-                            .unwrap();
-                        sync_sol_value_ix_full_for_prog(
-                            program_id,
-                            keys,
-                            index,
-                            &sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst),
-                            sol_value_calculator_program_id,
-                        )
-                        .unwrap()
-                    })
-                    .collect();
+        let fut_iter = chunk_by_tx_size(&payer.pubkey(), program_id, multi_keys, &multi_lsts)
+            .into_iter()
+            .map(|range| async {
+                let ixs = vec![sync_sol_value_multi_ix_full_for_prog(
+                    program_id,
+                    multi_keys,
+                    &multi_lsts[range.clone()],
+                )
+                .unwrap()];
                 if !force {
                     let should_run = does_tx_modify_pool_state(
                         &rpc,
@@ -113,7 +141,7 @@ impl SyncAllArgs {
                     .await;
                     if !should_run {
                         eprint!("Sync not required for ");
-                        for sanctum_lst in chunk.iter() {
+                        for sanctum_lst in sanctum_lsts[range].iter() {
                             eprint!("{}, ", sanctum_lst.symbol);
                         }
                         eprintln!();
@@ -136,46 +164,89 @@ impl SyncAllArgs {
     }
 }
 
+/// Greedily splits `lsts` into consecutive ranges that can each be synced
+/// by a single SyncSolValueMulti instruction that fits in a tx
+fn chunk_by_tx_size(
+    payer: &Pubkey,
+    program_id: Pubkey,
+    multi_keys: SyncSolValueMultiKeys,
+    lsts: &[SyncSolValueMultiLst],
+) -> Vec<Range<usize>> {
+    let fits = |range: Range<usize>| {
+        let ix =
+            sync_sol_value_multi_ix_full_for_prog(program_id, multi_keys, &lsts[range]).unwrap();
+        let message = Message::new(&[ix], Some(payer));
+        let tx_size = 1
+            + usize::from(message.header.num_required_signatures) * SIGNATURE_BYTES
+            + message.serialize().len();
+        tx_size <= MAX_SYNC_SOL_VALUE_MULTI_TX_SIZE
+    };
+    let mut res = Vec::new();
+    let mut start = 0;
+    while start < lsts.len() {
+        let mut end = start + 1;
+        while end < lsts.len() && fits(start..end + 1) {
+            end += 1;
+        }
+        res.push(start..end);
+        start = end;
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
-    use generic_pool_calculator_interface::SOL_TO_LST_IX_ACCOUNTS_LEN;
-    use s_controller_interface::SyncSolValueKeys;
+    use generic_pool_calculator_interface::{LstToSolKeys, LST_TO_SOL_IX_ACCOUNTS_LEN};
+    use s_controller_lib::SyncSolValueMultiLstKeys;
     use sanctum_solana_test_utils::assert_tx_with_cb_ixs_within_size_limits;
-    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 
     use super::*;
 
     #[test]
-    fn max_generic_sol_val_calc_syncs_per_tx_does_not_exceed_tx_size() {
+    fn chunk_by_tx_size_does_not_exceed_tx_size() {
+        const N_LSTS: usize = 50;
+
         let program_id = Pubkey::new_unique();
-        let base_keys = SyncSolValueKeys {
-            lst_mint: Pubkey::default(),
+        let payer = Pubkey::new_unique();
+        let multi_keys = SyncSolValueMultiKeys {
             pool_state: Pubkey::new_unique(),
             lst_state_list: Pubkey::new_unique(),
-            pool_reserves: Pubkey::new_unique(),
         };
-        let ixs: Vec<Instruction> = (0..MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX)
+        // every LST has its own calculator program and accounts for the worst case
+        let sol_value_calculator_accounts: Vec<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN]> = (0
+            ..N_LSTS)
             .map(|_| {
-                let lst_mint = Pubkey::new_unique();
-                let lst_index = 0;
-                let mut accounts = base_keys;
-                accounts.lst_mint = lst_mint;
-                let sol_value_calculator_accounts: [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] =
-                    [0; SOL_TO_LST_IX_ACCOUNTS_LEN].map(|_| AccountMeta {
-                        pubkey: Pubkey::new_unique(),
-                        is_signer: false,
-                        is_writable: false,
-                    });
-                sync_sol_value_ix_full_for_prog(
-                    program_id,
-                    accounts,
+                LstToSolKeys {
+                    lst_mint: Pubkey::new_unique(),
+                    state: Pubkey::new_unique(),
+                    pool_state: Pubkey::new_unique(),
+                    pool_program: Pubkey::new_unique(),
+                    pool_program_data: Pubkey::new_unique(),
+                }
+                .into()
+            })
+            .collect();
+        let lsts: Vec<SyncSolValueMultiLst> = sol_value_calculator_accounts
+            .iter()
+            .enumerate()
+            .map(|(lst_index, accounts)| SyncSolValueMultiLst {
+                keys: SyncSolValueMultiLstKeys {
                     lst_index,
-                    &sol_value_calculator_accounts,
-                    Pubkey::new_unique(),
-                )
-                .unwrap()
+                    lst_mint: accounts[0].pubkey,
+                    pool_reserves: Pubkey::new_unique(),
+                    sol_value_calculator: Pubkey::new_unique(),
+                },
+                sol_value_calculator_accounts: accounts,
             })
             .collect();
-        assert_tx_with_cb_ixs_within_size_limits(&Pubkey::new_unique(), ixs.into_iter(), &[]);
+
+        let ranges = chunk_by_tx_size(&payer, program_id, multi_keys, &lsts);
+        assert_eq!(ranges.iter().map(|r| r.len()).sum::<usize>(), N_LSTS);
+        for range in ranges {
+            assert!(!range.is_empty());
+            let ix = sync_sol_value_multi_ix_full_for_prog(program_id, multi_keys, &lsts[range])
+                .unwrap();
+            assert_tx_with_cb_ixs_within_size_limits(&payer, [ix].into_iter(), &[]);
+        }
     }
 }
@@ -1,12 +1,13 @@
 use clap::Args;
-use s_controller_interface::LstState;
+use s_controller_interface::{LstState, QueuedChange};
 use s_controller_lib::{
     create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address_with_protocol_fee_id, find_lst_state_list_address,
-    find_pool_state_address, find_protocol_fee_address, try_lst_state_list, try_pool_state, U8Bool,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, is_lst_stale,
+    try_lst_state_list_versioned, try_pending_authorities, try_pool_config, try_pool_state,
+    try_queued_change, QueuedChangeKind, U8Bool, CURRENT_PROGRAM_VERS, TIMELOCK_DELAY_UNIT_SLOTS,
 };
 use sanctum_token_lib::{mint_supply, token_account_balance};
-use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey};
 
 use crate::common::find_sanctum_lst_by_mint;
 
@@ -27,21 +28,47 @@ impl ViewArgs {
         };
 
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
-        let protocol_fee_id = find_protocol_fee_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let pending_authorities_addr = pool.pending_authorities_pda().address;
+        let queued_pricing_program_addr = pool.queued_pricing_program_pda().address;
+        let queued_timelock_delay_addr = pool.queued_timelock_delay_pda().address;
+        let pool_config_addr = pool.pool_config_pda().address;
 
         let mut main_accs = rpc
-            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .get_multiple_accounts(&[
+                pool_state_addr,
+                lst_state_list_addr,
+                pending_authorities_addr,
+                queued_pricing_program_addr,
+                queued_timelock_delay_addr,
+                pool_config_addr,
+            ])
             .await
             .unwrap();
+        // pool config PDA is only created the first time a pool config setting is changed
+        let pool_config_data = main_accs
+            .pop()
+            .unwrap()
+            .map_or_else(Vec::new, |acc| acc.data);
+        let pool_config = try_pool_config(&pool_config_data).unwrap();
+        // queued change PDAs only exist while a change is queued
+        let queued_timelock_delay_acc = main_accs.pop().unwrap();
+        let queued_pricing_program_acc = main_accs.pop().unwrap();
+        // pending authorities PDA is only created on the first authority handoff proposal
+        let pending_authorities_acc = main_accs.pop().unwrap();
         let lst_state_list_acc = main_accs.pop().unwrap().unwrap();
         let pool_state_acc = main_accs.pop().unwrap().unwrap();
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+        // pools that have not been migrated yet still have the old LstState layout
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc.data).unwrap();
         let lp_mint_acc = rpc.get_account(&pool_state.lp_token_mint).await.unwrap();
+        let epoch = rpc.get_epoch_info().await.unwrap().epoch;
 
         println!("Viewing info for program id: {program_id}");
         println!(
@@ -66,7 +93,15 @@ impl ViewArgs {
                 "    lp_protocol_fee_bps: {}",
                 pool_state.lp_protocol_fee_bps
             );
-            println!("    version: {}", pool_state.version);
+            println!(
+                "    version: {}{}",
+                pool_state.version,
+                if pool_state.version == CURRENT_PROGRAM_VERS {
+                    ""
+                } else {
+                    " (needs migrate)"
+                }
+            );
             println!("    is_disabled: {}", pool_state.is_disabled);
             println!("    is_rebalancing: {}", pool_state.is_rebalancing);
             println!("    admin: {}", pool_state.admin);
@@ -80,6 +115,94 @@ impl ViewArgs {
             );
             println!("    pricing_program: {}", pool_state.pricing_program);
             println!("    lp_token_mint: {}", pool_state.lp_token_mint);
+            println!(
+                "    timelock_delay: {} ({} slots)",
+                pool_state.timelock_delay,
+                u64::from(pool_state.timelock_delay) * TIMELOCK_DELAY_UNIT_SLOTS
+            );
+        }
+        println!("  Pool Config address: {pool_config_addr}");
+        if raw {
+            println!("{pool_config:#?}");
+            println!();
+        } else {
+            println!("  Pool Config:");
+            println!(
+                "    lp_requires_synced_lsts: {}",
+                U8Bool(pool_config.lp_requires_synced_lsts).is_true()
+            );
+            println!(
+                "    lp_synced_lsts_dust_threshold: {}",
+                lamports_to_sol(pool_config.lp_synced_lsts_dust_threshold)
+            );
+            println!(
+                "    max_rebalance_loss_bps: {}",
+                pool_config.max_rebalance_loss_bps
+            );
+            println!(
+                "    rebalance_authority_epoch_budget: {}",
+                lamports_to_sol(pool_config.rebalance_authority_epoch_budget)
+            );
+            println!(
+                "    rebalance_authority_last_epoch: {}",
+                pool_config.rebalance_authority_last_epoch
+            );
+            println!(
+                "    rebalance_authority_epoch_spent: {}",
+                lamports_to_sol(pool_config.rebalance_authority_epoch_spent)
+            );
+        }
+        for (name, addr, acc) in [
+            (
+                "Queued Pricing Program",
+                queued_pricing_program_addr,
+                queued_pricing_program_acc,
+            ),
+            (
+                "Queued Timelock Delay",
+                queued_timelock_delay_addr,
+                queued_timelock_delay_acc,
+            ),
+        ] {
+            let Some(acc) = acc else {
+                continue;
+            };
+            let queued_change = try_queued_change(&acc.data).unwrap();
+            println!("  {name} address: {addr}");
+            if raw {
+                println!("{queued_change:#?}");
+                println!();
+            } else {
+                print_queued_change(queued_change);
+            }
+        }
+        if let Some(pending_authorities_acc) = pending_authorities_acc {
+            let pending_authorities =
+                try_pending_authorities(&pending_authorities_acc.data).unwrap();
+            println!("  Pending Authorities address: {pending_authorities_addr}");
+            if raw {
+                println!("{pending_authorities:#?}");
+                println!();
+            } else {
+                println!("  Pending Authorities:");
+                for (name, pending) in [
+                    ("admin", pending_authorities.admin),
+                    (
+                        "rebalance_authority",
+                        pending_authorities.rebalance_authority,
+                    ),
+                    (
+                        "protocol_fee_beneficiary",
+                        pending_authorities.protocol_fee_beneficiary,
+                    ),
+                ] {
+                    if pending == Pubkey::default() {
+                        println!("    {name}: none");
+                    } else {
+                        println!("    {name}: {pending}");
+                    }
+                }
+            }
         }
         println!("  Protocol Fee address: {protocol_fee_id}");
         println!("  LST State List address: {lst_state_list_addr}");
@@ -88,26 +211,60 @@ impl ViewArgs {
             println!("{lst_state_list:#?}");
             println!();
         } else {
-            println!("  LST State List:");
-            for lst_state in lst_state_list {
+            println!("  LST State List (current epoch {epoch}):");
+            let mut stale_lsts = Vec::new();
+            for lst_state in lst_state_list.iter() {
                 let LstState {
                     mint,
                     is_input_disabled,
+                    is_output_disabled,
+                    max_share_bps,
+                    flash_loan_fee_bps,
                     sol_value,
                     sol_value_calculator,
+                    last_sol_value_rate,
+                    last_sol_value_rate_epoch,
+                    sol_value_rate_circuit_breaker_bps,
+                    last_sync_epoch,
+                    prev_epoch_sol_value_rate,
                     ..
                 } = lst_state;
                 let sanctum_lst_opt = find_sanctum_lst_by_mint(*mint);
-                println!(
-                    "    {}:",
-                    sanctum_lst_opt.map_or_else(|| mint.to_string(), |lst| lst.symbol.clone())
-                );
+                let name =
+                    sanctum_lst_opt.map_or_else(|| mint.to_string(), |lst| lst.symbol.clone());
+                println!("    {name}:");
                 println!(
                     "      is_input_disabled: {}",
                     U8Bool(*is_input_disabled).is_true()
                 );
+                println!(
+                    "      is_output_disabled: {}",
+                    U8Bool(*is_output_disabled).is_true()
+                );
+                println!("      max_share_bps: {max_share_bps}");
+                println!("      flash_loan_fee_bps: {flash_loan_fee_bps}");
                 println!("      sol_value: {}", lamports_to_sol(*sol_value));
                 println!("      sol_value_calculator: {sol_value_calculator}");
+                println!(
+                    "      sol_value_rate_circuit_breaker_bps: {sol_value_rate_circuit_breaker_bps}"
+                );
+                println!(
+                    "      last_sol_value_rate: {} (epoch {last_sol_value_rate_epoch})",
+                    lamports_to_sol(*last_sol_value_rate)
+                );
+                println!(
+                    "      prev_epoch_sol_value_rate: {}",
+                    lamports_to_sol(*prev_epoch_sol_value_rate)
+                );
+                let is_stale =
+                    is_lst_stale(lst_state, epoch, pool_config.lp_synced_lsts_dust_threshold);
+                println!(
+                    "      last_sync_epoch: {last_sync_epoch}{}",
+                    if is_stale { " (stale)" } else { "" }
+                );
+                if is_stale {
+                    stale_lsts.push(name);
+                }
                 let token_program = match sanctum_lst_opt {
                     Some(s) => std::future::ready(s.token_program).await,
                     None => async { rpc.get_account(mint).await.unwrap().owner }.await,
@@ -125,10 +282,17 @@ impl ViewArgs {
                         token_program,
                     )
                     .unwrap();
+                let queued_sol_value_calculator_addr =
+                    pool.queued_sol_value_calculator_pda(*mint).address;
                 let mut token_accs = rpc
-                    .get_multiple_accounts(&[reserves_addr, protocol_fee_accum_addr])
+                    .get_multiple_accounts(&[
+                        reserves_addr,
+                        protocol_fee_accum_addr,
+                        queued_sol_value_calculator_addr,
+                    ])
                     .await
                     .unwrap();
+                let queued_sol_value_calculator_acc = token_accs.pop().unwrap();
                 let protocol_fee_accum_acc = token_accs.pop().unwrap().unwrap();
                 let reserves_acc = token_accs.pop().unwrap().unwrap();
                 println!(
@@ -139,8 +303,42 @@ impl ViewArgs {
                     "      protocol fees {protocol_fee_accum_addr}: {}",
                     lamports_to_sol(token_account_balance(protocol_fee_accum_acc).unwrap())
                 );
+                if let Some(acc) = queued_sol_value_calculator_acc {
+                    let queued_change = try_queued_change(&acc.data).unwrap();
+                    println!(
+                        "      queued sol_value_calculator {queued_sol_value_calculator_addr}: {} (executable from slot {})",
+                        queued_change.new_program, queued_change.earliest_execution_slot
+                    );
+                }
                 println!();
             }
+            if stale_lsts.is_empty() {
+                println!("  All LSTs synced this epoch");
+            } else {
+                println!("  LSTs not synced this epoch: {}", stale_lsts.join(", "));
+            }
+        }
+    }
+}
+
+fn print_queued_change(
+    QueuedChange {
+        earliest_execution_slot,
+        kind,
+        new_timelock_delay,
+        payer,
+        new_program,
+        ..
+    }: &QueuedChange,
+) {
+    match QueuedChangeKind::try_from(*kind).unwrap() {
+        QueuedChangeKind::TimelockDelay => {
+            println!("    new_timelock_delay: {new_timelock_delay}")
+        }
+        QueuedChangeKind::PricingProgram | QueuedChangeKind::SolValueCalculator => {
+            println!("    new_program: {new_program}")
         }
     }
+    println!("    earliest_execution_slot: {earliest_execution_slot}");
+    println!("    payer: {payer}");
 }
@@ -7,9 +7,8 @@ use s_controller_interface::{
     withdraw_protocol_fees_ix_with_program_id, WithdrawProtocolFeesIxArgs,
 };
 use s_controller_lib::{
-    find_pool_state_address, find_protocol_fee_accumulator_address, find_protocol_fee_address,
-    try_pool_state, FindLstPdaAtaKeys, WithdrawProtocolFeesByMintFreeArgs,
-    WithdrawProtocolFeesPdas,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id, try_pool_state, FindLstPdaAtaKeys,
+    WithdrawProtocolFeesByMintFreeArgs, WithdrawProtocolFeesPdas,
 };
 use sanctum_associated_token_lib::FindAtaAddressArgs;
 use sanctum_solana_cli_utils::parse_signer;
@@ -80,7 +79,8 @@ impl WithdrawProtocolFeesArgs {
 
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
-        let program_id = args.program;
+        let pool = args.pool_key();
+        let program_id = pool.program_id;
 
         let token_program = token_program.unwrap_or_else(|| {
             mint.token_program()
@@ -118,12 +118,15 @@ impl WithdrawProtocolFeesArgs {
             },
         );
         let protocol_fee_accumulator_addr =
-            find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
-                lst_mint: mint.mint(),
-                token_program,
-            })
+            find_protocol_fee_accumulator_address_with_protocol_fee_id(
+                pool.protocol_fee_pda().address,
+                FindLstPdaAtaKeys {
+                    lst_mint: mint.mint(),
+                    token_program,
+                },
+            )
             .0;
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let pool_state_addr = pool.pool_state;
 
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[protocol_fee_accumulator_addr, pool_state_addr, withdraw_to])
@@ -187,7 +190,7 @@ impl WithdrawProtocolFeesArgs {
                 }
                 .resolve_with_pdas(WithdrawProtocolFeesPdas {
                     pool_state: pool_state_addr,
-                    protocol_fee_accumulator_auth: find_protocol_fee_address(program_id).0,
+                    protocol_fee_accumulator_auth: pool.protocol_fee_pda().address,
                     protocol_fee_accumulator: protocol_fee_accumulator_addr,
                 })
                 .unwrap(),
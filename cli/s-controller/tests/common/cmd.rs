@@ -17,6 +17,8 @@ pub trait TestSctrCmd {
 
     fn cmd_set_admin(&mut self) -> &mut Self;
 
+    fn cmd_accept_admin(&mut self) -> &mut Self;
+
     fn cmd_add_disable_auth(&mut self) -> &mut Self;
 
     fn cmd_remove_disable_auth(&mut self) -> &mut Self;
@@ -31,10 +33,20 @@ pub trait TestSctrCmd {
 
     fn cmd_enable_lst_input(&mut self) -> &mut Self;
 
+    fn cmd_disable_lst_output(&mut self) -> &mut Self;
+
+    fn cmd_enable_lst_output(&mut self) -> &mut Self;
+
+    fn cmd_set_lst_max_share(&mut self) -> &mut Self;
+
+    fn cmd_set_lst_flash_loan_fee(&mut self) -> &mut Self;
+
     fn cmd_disable_pool(&mut self) -> &mut Self;
 
     fn cmd_set_protocol_fee_beneficiary(&mut self) -> &mut Self;
 
+    fn cmd_accept_protocol_fee_beneficiary(&mut self) -> &mut Self;
+
     fn cmd_enable_pool(&mut self) -> &mut Self;
 
     fn cmd_set_pricing_prog(&mut self) -> &mut Self;
@@ -43,6 +55,16 @@ pub trait TestSctrCmd {
 
     fn cmd_set_rebalance_auth(&mut self) -> &mut Self;
 
+    fn cmd_accept_rebalance_auth(&mut self) -> &mut Self;
+
+    fn cmd_cancel_authority_handoff(&mut self) -> &mut Self;
+
+    fn cmd_set_timelock_delay(&mut self) -> &mut Self;
+
+    fn cmd_execute_queued_change(&mut self) -> &mut Self;
+
+    fn cmd_cancel_queued_change(&mut self) -> &mut Self;
+
     fn cmd_sync(&mut self) -> &mut Self;
 
     fn cmd_sync_all(&mut self) -> &mut Self;
@@ -52,6 +74,22 @@ pub trait TestSctrCmd {
     fn cmd_view(&mut self) -> &mut Self;
 
     fn cmd_rebal_sol(&mut self, sol_amt_arg: &str, lst_arg: &str) -> &mut Self;
+
+    fn cmd_set_referral_fee(&mut self) -> &mut Self;
+
+    fn cmd_add_fee_beneficiary(&mut self) -> &mut Self;
+
+    fn cmd_set_protocol_fees_as_lp(&mut self) -> &mut Self;
+
+    fn cmd_set_lst_circuit_breaker(&mut self) -> &mut Self;
+
+    fn cmd_set_lp_sync_requirement(&mut self) -> &mut Self;
+
+    fn cmd_add_rebalance_auth(&mut self) -> &mut Self;
+
+    fn cmd_set_pool_rebalance_auth_budget(&mut self) -> &mut Self;
+
+    fn cmd_set_max_rebalance_loss(&mut self) -> &mut Self;
 }
 
 impl TestSctrCmd for Command {
@@ -63,6 +101,10 @@ impl TestSctrCmd for Command {
         self.arg("set-admin")
     }
 
+    fn cmd_accept_admin(&mut self) -> &mut Self {
+        self.arg("accept-admin")
+    }
+
     fn cmd_add_disable_auth(&mut self) -> &mut Self {
         self.arg("add-disable-auth")
     }
@@ -87,6 +129,22 @@ impl TestSctrCmd for Command {
         self.arg("enable-lst-input")
     }
 
+    fn cmd_disable_lst_output(&mut self) -> &mut Self {
+        self.arg("disable-lst-output")
+    }
+
+    fn cmd_enable_lst_output(&mut self) -> &mut Self {
+        self.arg("enable-lst-output")
+    }
+
+    fn cmd_set_lst_max_share(&mut self) -> &mut Self {
+        self.arg("set-lst-max-share")
+    }
+
+    fn cmd_set_lst_flash_loan_fee(&mut self) -> &mut Self {
+        self.arg("set-lst-flash-loan-fee")
+    }
+
     fn cmd_disable_pool(&mut self) -> &mut Self {
         self.arg("disable-pool")
     }
@@ -95,6 +153,10 @@ impl TestSctrCmd for Command {
         self.arg("set-protocol-fee-beneficiary")
     }
 
+    fn cmd_accept_protocol_fee_beneficiary(&mut self) -> &mut Self {
+        self.arg("accept-protocol-fee-beneficiary")
+    }
+
     fn cmd_enable_pool(&mut self) -> &mut Self {
         self.arg("enable-pool")
     }
@@ -115,6 +177,26 @@ impl TestSctrCmd for Command {
         self.arg("set-rebalance-auth")
     }
 
+    fn cmd_accept_rebalance_auth(&mut self) -> &mut Self {
+        self.arg("accept-rebalance-auth")
+    }
+
+    fn cmd_cancel_authority_handoff(&mut self) -> &mut Self {
+        self.arg("cancel-authority-handoff")
+    }
+
+    fn cmd_set_timelock_delay(&mut self) -> &mut Self {
+        self.arg("set-timelock-delay")
+    }
+
+    fn cmd_execute_queued_change(&mut self) -> &mut Self {
+        self.arg("execute-queued-change")
+    }
+
+    fn cmd_cancel_queued_change(&mut self) -> &mut Self {
+        self.arg("cancel-queued-change")
+    }
+
     fn cmd_sync(&mut self) -> &mut Self {
         self.arg("sync")
     }
@@ -137,4 +219,36 @@ impl TestSctrCmd for Command {
             .arg(sol_amt_arg)
             .arg(lst_arg)
     }
+
+    fn cmd_set_referral_fee(&mut self) -> &mut Self {
+        self.arg("set-referral-fee")
+    }
+
+    fn cmd_add_fee_beneficiary(&mut self) -> &mut Self {
+        self.arg("add-fee-beneficiary")
+    }
+
+    fn cmd_set_protocol_fees_as_lp(&mut self) -> &mut Self {
+        self.arg("set-protocol-fees-as-lp")
+    }
+
+    fn cmd_set_lst_circuit_breaker(&mut self) -> &mut Self {
+        self.arg("set-lst-circuit-breaker")
+    }
+
+    fn cmd_set_lp_sync_requirement(&mut self) -> &mut Self {
+        self.arg("set-lp-sync-requirement")
+    }
+
+    fn cmd_add_rebalance_auth(&mut self) -> &mut Self {
+        self.arg("add-rebalance-auth")
+    }
+
+    fn cmd_set_pool_rebalance_auth_budget(&mut self) -> &mut Self {
+        self.arg("set-pool-rebalance-auth-budget")
+    }
+
+    fn cmd_set_max_rebalance_loss(&mut self) -> &mut Self {
+        self.arg("set-max-rebalance-loss")
+    }
 }
@@ -0,0 +1,36 @@
+use s_controller_interface::ProtocolFeeBeneficiaryShare;
+use s_controller_lib::try_protocol_fee_beneficiary_list;
+use s_controller_test_utils::{
+    PoolStateProgramTest, ProtocolFeeBeneficiaryListBanksClient, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn add_fee_beneficiary_success_payer_init_auth() {
+    const WEIGHT_BPS: u16 = 2_500;
+
+    let new_beneficiary = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    cmd.cmd_add_fee_beneficiary()
+        .arg(new_beneficiary.to_string())
+        .arg(WEIGHT_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let list_acc = bc.get_protocol_fee_beneficiary_list_acc().await;
+    let list = try_protocol_fee_beneficiary_list(&list_acc.data).unwrap();
+    assert_eq!(
+        list,
+        &[ProtocolFeeBeneficiaryShare {
+            beneficiary: new_beneficiary,
+            weight_bps: WEIGHT_BPS,
+        }]
+    );
+}
@@ -70,6 +70,7 @@ async fn add_lst_jito_matched_sol_val_calc_success_payer_init_auth() {
             reserves_amt: 0,
             protocol_fee_accumulator_amt: 0,
             is_input_disabled: false,
+            is_output_disabled: false,
         },
     )
     .await;
@@ -96,6 +97,7 @@ async fn add_lst_jitosol_token_name_matched_sol_val_calc_success_payer_init_auth
             reserves_amt: 0,
             protocol_fee_accumulator_amt: 0,
             is_input_disabled: false,
+            is_output_disabled: false,
         },
     )
     .await;
@@ -0,0 +1,38 @@
+use s_controller_interface::RebalanceAuthorityBudget;
+use s_controller_lib::try_rebalance_authority_list;
+use s_controller_test_utils::{
+    PoolStateProgramTest, RebalanceAuthorityListBanksClient, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn add_rebalance_auth_success_payer_init_auth() {
+    const EPOCH_BUDGET: u64 = 1_000_000_000_000;
+
+    let new_rebalance_authority = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    cmd.cmd_add_rebalance_auth()
+        .arg(new_rebalance_authority.to_string())
+        .arg(EPOCH_BUDGET.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let list_acc = bc.get_rebalance_authority_list_acc().await;
+    let list = try_rebalance_authority_list(&list_acc.data).unwrap();
+    assert_eq!(
+        list,
+        &[RebalanceAuthorityBudget {
+            authority: new_rebalance_authority,
+            epoch_budget: EPOCH_BUDGET,
+            last_epoch: 0,
+            epoch_spent: 0,
+        }]
+    );
+}
@@ -0,0 +1,55 @@
+use s_controller_interface::PendingAuthorities;
+use s_controller_test_utils::{
+    PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest, PoolStateProgramTest,
+    DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancel_authority_handoff_all() {
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(PendingAuthorities {
+            admin: Pubkey::new_unique(),
+            rebalance_authority: Pubkey::new_unique(),
+            protocol_fee_beneficiary: Pubkey::new_unique(),
+        });
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_cancel_authority_handoff();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(bc.get_pending_authorities().await, NO_PENDING_AUTHORITIES);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancel_authority_handoff_admin_only() {
+    let pending_rebalance_auth = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(PendingAuthorities {
+            admin: Pubkey::new_unique(),
+            rebalance_authority: pending_rebalance_auth,
+            ..NO_PENDING_AUTHORITIES
+        });
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_cancel_authority_handoff().arg("--cancel-admin");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(
+        bc.get_pending_authorities().await,
+        PendingAuthorities {
+            rebalance_authority: pending_rebalance_auth,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+}
@@ -0,0 +1,31 @@
+use s_controller_test_utils::{
+    assert_lst_output_disabled, assert_lst_output_enabled, jito_marinade_no_fee_program_test,
+    JitoMarinadeProgramTestArgs,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_sdk::pubkey::Pubkey;
+use test_utils::jitosol;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn disable_jito_output_success_payer_init_auth() {
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        // all don't cares
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert_lst_output_enabled(&mut bc, jitosol::ID).await;
+    cmd.cmd_disable_lst_output().arg(jitosol::ID_STR);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_lst_output_disabled(&mut bc, jitosol::ID).await;
+}
@@ -30,6 +30,7 @@ async fn enable_jito_input_success_payer_init_auth() {
         reserves_amt: 0,
         protocol_fee_accumulator_amt: 0,
         is_input_disabled: true,
+        is_output_disabled: false,
     })
     .lst_state]); // override lst_state_list with disabled jitoSOL
     let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
@@ -1,3 +1,4 @@
+use s_controller_lib::{program::POOL_STATE_ID, try_pool_state, PoolKey};
 use s_controller_test_utils::PoolStateBanksClient;
 use sanctum_solana_test_utils::{
     cli::{assert_all_txs_success_nonempty, ExtendedCommand},
@@ -33,3 +34,37 @@ async fn init_success_payer_init_auth() {
     // check pool state exists
     bc.get_pool_state_acc().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn init_additional_pool_success() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let lp_mint = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_tokenkeg_mint_from_args(
+            lp_mint,
+            MockMintArgs {
+                mint_authority: Some(mock_auth_kp.pubkey()),
+                freeze_authority: Some(mock_auth_kp.pubkey()),
+                supply: 0,
+                decimals: 9,
+            },
+        );
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    cmd.cmd_init().arg("--additional").arg(lp_mint.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let pool_state_acc = bc
+        .get_account(PoolKey::new(lp_mint).pool_state)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        try_pool_state(&pool_state_acc.data).unwrap().lp_token_mint,
+        lp_mint
+    );
+    assert!(bc.get_account(POOL_STATE_ID).await.unwrap().is_none());
+}
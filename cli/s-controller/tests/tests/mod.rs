@@ -1,20 +1,34 @@
 mod add_disable_auth;
+mod add_fee_beneficiary;
 mod add_lst;
+mod add_rebalance_auth;
+mod cancel_authority_handoff;
 mod disable_lst_input;
+mod disable_lst_output;
 mod disable_pool;
 mod enable_lst_input;
+mod enable_lst_output;
 mod enable_pool;
 mod init;
 mod rebal_sol;
 mod remove_disable_auth;
 mod remove_lst;
 mod set_admin;
+mod set_lp_sync_requirement;
+mod set_lst_circuit_breaker;
+mod set_lst_flash_loan_fee;
+mod set_lst_max_share;
+mod set_max_rebalance_loss;
+mod set_pool_rebalance_auth_budget;
 mod set_pricing_prog;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
+mod set_protocol_fees_as_lp;
 mod set_rebalance_auth;
+mod set_referral_fee;
 mod set_sol_value_calculator;
 mod sync;
 mod sync_all;
+mod timelock;
 mod view;
 mod withdraw_protocol_fees;
@@ -24,6 +24,7 @@ async fn remove_lst_jito_success_payer_init_auth() {
             reserves_amt: 0,
             protocol_fee_accumulator_amt: 0,
             is_input_disabled: true,
+            is_output_disabled: false,
         }]);
     let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
     cmd.cmd_remove_lst().arg(jitosol::ID_STR);
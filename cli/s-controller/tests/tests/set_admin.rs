@@ -1,9 +1,15 @@
-use s_controller_test_utils::{assert_admin, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use s_controller_interface::PendingAuthorities;
+use s_controller_test_utils::{
+    assert_admin, PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
+};
 use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
 use solana_program_test::ProgramTest;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
-use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+use crate::common::{
+    setup_with_init_auth_as_payer, setup_with_payer, SctrProgramTest, TestSctrCmd,
+};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn set_admin_success_payer_admin() {
@@ -13,10 +19,38 @@ async fn set_admin_success_payer_admin() {
         .add_s_program()
         .add_pool_state(DEFAULT_POOL_STATE);
 
-    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    let (mut cmd, _cfg, mut bc, mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
 
     cmd.cmd_set_admin().arg(new_admin.to_string());
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_admin(&mut bc, new_admin).await;
+    assert_admin(&mut bc, mock_auth_kp.pubkey()).await;
+    assert_eq!(
+        bc.get_pending_authorities().await,
+        PendingAuthorities {
+            admin: new_admin,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_admin_success_payer_pending_admin() {
+    let pending_admin = Keypair::new();
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(PendingAuthorities {
+            admin: pending_admin.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        });
+
+    let (mut cmd, _cfg, mut bc, pending_admin) = setup_with_payer(pt, pending_admin).await;
+
+    cmd.cmd_accept_admin();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_admin(&mut bc, pending_admin.pubkey()).await;
+    assert_eq!(bc.get_pending_authorities().await, NO_PENDING_AUTHORITIES);
 }
@@ -0,0 +1,27 @@
+use s_controller_lib::U8Bool;
+use s_controller_test_utils::{PoolConfigBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lp_sync_requirement_success_payer_init_auth() {
+    const DUST_THRESHOLD: u64 = 1_000_000;
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert!(U8Bool(bc.get_pool_config().await.lp_requires_synced_lsts).is_false());
+
+    cmd.cmd_set_lp_sync_requirement()
+        .arg("--dust-threshold")
+        .arg(DUST_THRESHOLD.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    let pool_config = bc.get_pool_config().await;
+    assert!(U8Bool(pool_config.lp_requires_synced_lsts).is_true());
+    assert_eq!(pool_config.lp_synced_lsts_dust_threshold, DUST_THRESHOLD);
+}
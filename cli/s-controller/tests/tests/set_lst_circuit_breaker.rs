@@ -0,0 +1,44 @@
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_sdk::pubkey::Pubkey;
+use test_utils::jitosol;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_jito_circuit_breaker_success_payer_init_auth() {
+    const CIRCUIT_BREAKER_BPS: u16 = 50;
+
+    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        // all don't cares
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert_eq!(
+        bc.get_lst_state(jitosol::ID)
+            .await
+            .sol_value_rate_circuit_breaker_bps,
+        0
+    );
+    cmd.cmd_set_lst_circuit_breaker()
+        .arg(jitosol::ID_STR)
+        .arg(CIRCUIT_BREAKER_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(
+        bc.get_lst_state(jitosol::ID)
+            .await
+            .sol_value_rate_circuit_breaker_bps,
+        CIRCUIT_BREAKER_BPS
+    );
+}
@@ -0,0 +1,26 @@
+use s_controller_test_utils::{PoolConfigBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_max_rebalance_loss_success_payer_init_auth() {
+    const MAX_REBALANCE_LOSS_BPS: u16 = 5;
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert_eq!(bc.get_pool_config().await.max_rebalance_loss_bps, 0);
+
+    cmd.cmd_set_max_rebalance_loss()
+        .arg(MAX_REBALANCE_LOSS_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(
+        bc.get_pool_config().await.max_rebalance_loss_bps,
+        MAX_REBALANCE_LOSS_BPS
+    );
+}
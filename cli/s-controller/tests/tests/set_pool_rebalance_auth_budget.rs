@@ -0,0 +1,30 @@
+use s_controller_lib::UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET;
+use s_controller_test_utils::{PoolConfigBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_pool_rebalance_auth_budget_success_payer_init_auth() {
+    const EPOCH_BUDGET: u64 = 1_000_000_000;
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert_eq!(
+        bc.get_pool_config().await.rebalance_authority_epoch_budget,
+        UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET
+    );
+
+    cmd.cmd_set_pool_rebalance_auth_budget()
+        .arg(EPOCH_BUDGET.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(
+        bc.get_pool_config().await.rebalance_authority_epoch_budget,
+        EPOCH_BUDGET
+    );
+}
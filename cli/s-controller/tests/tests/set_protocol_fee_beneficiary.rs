@@ -1,6 +1,9 @@
-use s_controller_interface::PoolState;
+use s_controller_interface::{PendingAuthorities, PoolState};
 use s_controller_lib::try_pool_state;
-use s_controller_test_utils::{PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use s_controller_test_utils::{
+    PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest, PoolStateBanksClient,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
+};
 use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
 use solana_program_test::{BanksClient, ProgramTest};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
@@ -24,12 +27,40 @@ async fn set_protocol_fee_beneficiary_success_payer_beneficiary() {
             ..DEFAULT_POOL_STATE
         });
 
-    let (mut cmd, _cfg, mut bc, _mock_auth_kp) =
+    let (mut cmd, _cfg, mut bc, curr_protocol_fee_beneficiary) =
         setup_with_payer(pt, curr_protocol_fee_beneficiary).await;
 
     cmd.cmd_set_protocol_fee_beneficiary()
         .arg(new_protocol_fee_beneficiary.to_string());
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_protocol_fee_beneficiary(&mut bc, new_protocol_fee_beneficiary).await;
+    assert_protocol_fee_beneficiary(&mut bc, curr_protocol_fee_beneficiary.pubkey()).await;
+    assert_eq!(
+        bc.get_pending_authorities().await,
+        PendingAuthorities {
+            protocol_fee_beneficiary: new_protocol_fee_beneficiary,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_protocol_fee_beneficiary_success_payer_pending_beneficiary() {
+    let pending_beneficiary = Keypair::new();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(PendingAuthorities {
+            protocol_fee_beneficiary: pending_beneficiary.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        });
+
+    let (mut cmd, _cfg, mut bc, pending_beneficiary) =
+        setup_with_payer(pt, pending_beneficiary).await;
+
+    cmd.cmd_accept_protocol_fee_beneficiary();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_protocol_fee_beneficiary(&mut bc, pending_beneficiary.pubkey()).await;
+    assert_eq!(bc.get_pending_authorities().await, NO_PENDING_AUTHORITIES);
 }
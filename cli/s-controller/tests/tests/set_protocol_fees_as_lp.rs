@@ -0,0 +1,35 @@
+use s_controller_interface::PoolState;
+use s_controller_lib::find_protocol_fee_lp_address;
+use s_controller_test_utils::{
+    LpTokenProgramTest, PoolConfigBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_protocol_fees_as_lp_success_payer_init_auth() {
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(PoolState {
+            lp_token_mint,
+            ..DEFAULT_POOL_STATE
+        })
+        .add_mock_lp_mint(lp_token_mint, 0);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+    assert_eq!(bc.get_pool_config().await.protocol_fees_as_lp, 0);
+
+    cmd.cmd_set_protocol_fees_as_lp();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_eq!(bc.get_pool_config().await.protocol_fees_as_lp, 1);
+    assert!(bc
+        .get_account(find_protocol_fee_lp_address(lp_token_mint).0)
+        .await
+        .unwrap()
+        .is_some());
+}
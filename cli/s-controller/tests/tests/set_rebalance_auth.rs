@@ -1,11 +1,15 @@
+use s_controller_interface::PendingAuthorities;
 use s_controller_test_utils::{
-    assert_rebalance_authority, PoolStateProgramTest, DEFAULT_POOL_STATE,
+    assert_rebalance_authority, PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
 };
 use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
 use solana_program_test::ProgramTest;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
-use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+use crate::common::{
+    setup_with_init_auth_as_payer, setup_with_payer, SctrProgramTest, TestSctrCmd,
+};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn set_rebalance_auth_success_payer_admin() {
@@ -21,5 +25,34 @@ async fn set_rebalance_auth_success_payer_admin() {
         .arg(new_rebalance_auth.to_string());
     let exec_res = cmd.exec_b64_txs(&mut bc).await;
     assert_all_txs_success_nonempty(&exec_res);
-    assert_rebalance_authority(&mut bc, new_rebalance_auth).await;
+    assert_rebalance_authority(&mut bc, DEFAULT_POOL_STATE.rebalance_authority).await;
+    assert_eq!(
+        bc.get_pending_authorities().await,
+        PendingAuthorities {
+            rebalance_authority: new_rebalance_auth,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn accept_rebalance_auth_success_payer_pending_rebalance_auth() {
+    let pending_rebalance_auth = Keypair::new();
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(PendingAuthorities {
+            rebalance_authority: pending_rebalance_auth.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        });
+
+    let (mut cmd, _cfg, mut bc, pending_rebalance_auth) =
+        setup_with_payer(pt, pending_rebalance_auth).await;
+
+    cmd.cmd_accept_rebalance_auth();
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_rebalance_authority(&mut bc, pending_rebalance_auth.pubkey()).await;
+    assert_eq!(bc.get_pending_authorities().await, NO_PENDING_AUTHORITIES);
 }
@@ -0,0 +1,107 @@
+use s_controller_interface::{PoolState, QueuedChange};
+use s_controller_lib::{
+    program::{QUEUED_PRICING_PROGRAM_BUMP, QUEUED_PRICING_PROGRAM_ID, QUEUED_TIMELOCK_DELAY_ID},
+    QueuedChangeKind,
+};
+use s_controller_test_utils::{
+    assert_pricing_prog_set, PoolStateProgramTest, QueuedChangeBanksClient,
+    QueuedChangeProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::cli::{assert_all_txs_success_nonempty, ExtendedCommand};
+use solana_program_test::ProgramTest;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+fn timelocked_pt() -> ProgramTest {
+    ProgramTest::default()
+        .add_s_program()
+        .add_flat_fee_pricing_program()
+        .add_pool_state(PoolState {
+            timelock_delay: 1,
+            ..DEFAULT_POOL_STATE
+        })
+}
+
+fn queued_pricing_prog_change(payer: Pubkey) -> QueuedChange {
+    QueuedChange {
+        earliest_execution_slot: 0,
+        kind: QueuedChangeKind::PricingProgram as u8,
+        bump: QUEUED_PRICING_PROGRAM_BUMP,
+        new_timelock_delay: 0,
+        padding: [0; 5],
+        payer,
+        lst_mint: Pubkey::default(),
+        new_program: flat_fee_lib::program::ID,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_pricing_prog_queue_success() {
+    let pt = timelocked_pt();
+
+    let (mut cmd, _cfg, mut bc, mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_set_pricing_prog().arg("flat-fee").arg("--queue");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let queued_change = bc
+        .get_queued_change(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap();
+    assert_eq!(queued_change.new_program, flat_fee_lib::program::ID);
+    assert_eq!(queued_change.payer, mock_auth_kp.pubkey());
+    assert_pricing_prog_set(&mut bc, DEFAULT_POOL_STATE.pricing_program).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_timelock_delay_success() {
+    let pt = timelocked_pt();
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_set_timelock_delay().arg("3");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let queued_change = bc
+        .get_queued_change(QUEUED_TIMELOCK_DELAY_ID)
+        .await
+        .unwrap();
+    assert_eq!(queued_change.new_timelock_delay, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn execute_queued_pricing_prog_success() {
+    let pt = timelocked_pt().add_queued_change(queued_pricing_prog_change(Pubkey::new_unique()));
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_execute_queued_change().arg("pricing-prog");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_pricing_prog_set(&mut bc, flat_fee_lib::program::ID).await;
+    assert!(bc
+        .get_queued_change(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn cancel_queued_pricing_prog_success() {
+    let pt = timelocked_pt().add_queued_change(queued_pricing_prog_change(Pubkey::new_unique()));
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_cancel_queued_change().arg("pricing-prog");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_pricing_prog_set(&mut bc, DEFAULT_POOL_STATE.pricing_program).await;
+    assert!(bc
+        .get_queued_change(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .is_none());
+}
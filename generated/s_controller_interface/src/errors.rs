@@ -84,6 +84,102 @@ pub enum SControllerError {
     SwapSameLst = 35,
     #[error("Cannot add the same authority that has already been authorized")]
     DuplicateDisablePoolAuthority = 36,
+    #[error("LST would exceed its maximum share of the pool's total SOL value")]
+    LstMaxShareExceeded = 37,
+    #[error("Attempting to set a max share over 100%")]
+    MaxShareTooHigh = 38,
+    #[error("Invalid pending authorities data")]
+    InvalidPendingAuthoritiesData = 39,
+    #[error("No pending authority handoff to accept or cancel")]
+    NoPendingAuthority = 40,
+    #[error("Change must be queued and executed after the timelock delay")]
+    TimelockActive = 41,
+    #[error("Queued change's timelock delay has not elapsed")]
+    TimelockNotElapsed = 42,
+    #[error("A change of this kind is already queued")]
+    ChangeAlreadyQueued = 43,
+    #[error("Invalid queued change data")]
+    InvalidQueuedChangeData = 44,
+    #[error("Output is currently disabled for this LST")]
+    LstOutputDisabled = 45,
+    #[error("No succeeding FlashRepay instruction found")]
+    NoSucceedingFlashRepay = 46,
+    #[error("Invalid flash loan record data")]
+    InvalidFlashLoanRecordData = 47,
+    #[error("Incorrect flash loan record account")]
+    IncorrectFlashLoanRecord = 48,
+    #[error("All additional rebalance legs must be settled before ending the rebalance")]
+    RebalanceLegsNotSettled = 49,
+    #[error("LST is not an unsettled destination of the current rebalance")]
+    RebalanceLegNotFound = 50,
+    #[error("Invalid pool config data")]
+    InvalidPoolConfigData = 51,
+    #[error("Incorrect pool config account")]
+    IncorrectPoolConfig = 52,
+    #[error("Must provide exactly one entry for each LST in the pool, in lst_state_list order")]
+    InvalidRemoveLiquidityProportionalEntries = 53,
+    #[error("Must provide at least one entry and each LST at most once")]
+    InvalidAddLiquidityMultiEntries = 54,
+    #[error("Deposit stake program is not a supported stake pool program")]
+    IncorrectDepositStakeProgram = 55,
+    #[error("Withdraw stake program is not a supported SPL stake pool program")]
+    IncorrectWithdrawStakeProgram = 56,
+    #[error("Withdraw stake accounts do not match the pool reserves, LST mint or signer")]
+    IncorrectWithdrawStakeAccounts = 57,
+    #[error("Invalid protocol fee beneficiary list data")]
+    InvalidProtocolFeeBeneficiaryListData = 58,
+    #[error("Incorrect protocol fee beneficiary list account")]
+    IncorrectProtocolFeeBeneficiaryList = 59,
+    #[error("Protocol fee beneficiary with given index does not exist")]
+    InvalidProtocolFeeBeneficiaryIndex = 60,
+    #[error("Cannot add a protocol fee beneficiary that's already on the list")]
+    DuplicateProtocolFeeBeneficiary = 61,
+    #[error("Sum of protocol fee beneficiary weights would exceed 100%")]
+    ProtocolFeeBeneficiaryWeightsTooHigh = 62,
+    #[error(
+        "Must provide a token account owned by each protocol fee beneficiary, in protocol_fee_beneficiary_list order"
+    )]
+    IncorrectProtocolFeeBeneficiaryTokenAccounts = 63,
+    #[error(
+        "protocol_fee_lp_acc must be created before protocol fees can be accrued as LP tokens"
+    )]
+    ProtocolFeeLpAccountNotCreated = 64,
+    #[error("Pool is not configured to accrue protocol fees as LP tokens")]
+    ProtocolFeesAsLpNotEnabled = 65,
+    #[error(
+        "LST SOL value rate moved by more than the LST's circuit breaker threshold within an epoch"
+    )]
+    SolValueRateCircuitBreakerTripped = 66,
+    #[error(
+        "Pool requires every LST above the dust threshold to have been synced this epoch for LP instructions"
+    )]
+    LstNotSyncedThisEpoch = 67,
+    #[error(
+        "Pool accounts are not on the version required by this instruction. Older pools must be migrated with Migrate first"
+    )]
+    InvalidPoolVersion = 68,
+    #[error("LP token mint must be a Tokenkeg or token-2022 mint")]
+    UnsupportedLpTokenProgram = 69,
+    #[error("Invalid rebalance authority list data")]
+    InvalidRebalanceAuthorityListData = 70,
+    #[error("Incorrect rebalance authority list account")]
+    IncorrectRebalanceAuthorityList = 71,
+    #[error("Rebalance authority with given index does not exist")]
+    InvalidRebalanceAuthorityIndex = 72,
+    #[error("Cannot add a rebalance authority that's already on the list")]
+    DuplicateRebalanceAuthority = 73,
+    #[error(
+        "Signer is neither the pool's rebalance authority nor on the rebalance authority list"
+    )]
+    UnauthorizedRebalanceAuthority = 74,
+    #[error("Rebalance would exceed the rebalance authority's SOL value budget for this epoch")]
+    RebalanceBudgetExceeded = 75,
+    #[error("Attempting to set a max rebalance loss over 100%")]
+    MaxRebalanceLossTooHigh = 76,
+    #[error("LST mint has a token-2022 extension that is not supported by the pool")]
+    UnsupportedLstMintExtension = 77,
+    #[error("LP token metadata name, symbol or uri is longer than Metaplex allows")]
+    LpTokenMetadataTooLong = 78,
 }
 impl From<SControllerError> for ProgramError {
     fn from(e: SControllerError) -> Self {
@@ -1,3 +1,4 @@
+use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
@@ -33,6 +34,51 @@ pub enum SControllerProgramIx {
     EndRebalance,
     SetRebalanceAuthority,
     Initialize,
+    SetLstMaxShare(SetLstMaxShareIxArgs),
+    AcceptAdmin,
+    AcceptRebalanceAuthority,
+    AcceptProtocolFeeBeneficiary,
+    CancelAuthorityHandoff(CancelAuthorityHandoffIxArgs),
+    QueueSetPricingProgram,
+    ExecuteSetPricingProgram,
+    QueueSetSolValueCalculator(QueueSetSolValueCalculatorIxArgs),
+    ExecuteSetSolValueCalculator(ExecuteSetSolValueCalculatorIxArgs),
+    QueueSetTimelockDelay(QueueSetTimelockDelayIxArgs),
+    ExecuteSetTimelockDelay,
+    CancelQueuedChange,
+    SyncSolValueMulti(SyncSolValueMultiIxArgs),
+    DisableLstOutput(DisableLstOutputIxArgs),
+    EnableLstOutput(EnableLstOutputIxArgs),
+    QuoteSwapExactIn(QuoteSwapExactInIxArgs),
+    QuoteSwapExactOut(QuoteSwapExactOutIxArgs),
+    QuoteAddLiquidity(QuoteAddLiquidityIxArgs),
+    QuoteRemoveLiquidity(QuoteRemoveLiquidityIxArgs),
+    FlashBorrow(FlashBorrowIxArgs),
+    FlashRepay,
+    SetLstFlashLoanFee(SetLstFlashLoanFeeIxArgs),
+    AddRebalanceLeg(AddRebalanceLegIxArgs),
+    SettleRebalanceLeg,
+    RemoveLiquidityProportional(RemoveLiquidityProportionalIxArgs),
+    SetRemoveLiquidityProportionalFee(SetRemoveLiquidityProportionalFeeIxArgs),
+    AddLiquidityMulti(AddLiquidityMultiIxArgs),
+    DepositStake(DepositStakeIxArgs),
+    RemoveLiquidityToStake(RemoveLiquidityToStakeIxArgs),
+    SetReferralFee(SetReferralFeeIxArgs),
+    AddProtocolFeeBeneficiary(AddProtocolFeeBeneficiaryIxArgs),
+    RemoveProtocolFeeBeneficiary(RemoveProtocolFeeBeneficiaryIxArgs),
+    SetProtocolFeeBeneficiaryWeight(SetProtocolFeeBeneficiaryWeightIxArgs),
+    DistributeProtocolFees,
+    SetProtocolFeesAsLp(SetProtocolFeesAsLpIxArgs),
+    ConvertProtocolFeesToLp(ConvertProtocolFeesToLpIxArgs),
+    SetLstCircuitBreaker(SetLstCircuitBreakerIxArgs),
+    SetLpSyncRequirement(SetLpSyncRequirementIxArgs),
+    Migrate,
+    SetLpTokenMetadata(SetLpTokenMetadataIxArgs),
+    AddRebalanceAuthority(AddRebalanceAuthorityIxArgs),
+    RemoveRebalanceAuthority(RemoveRebalanceAuthorityIxArgs),
+    SetRebalanceAuthorityBudget(SetRebalanceAuthorityBudgetIxArgs),
+    SetPoolRebalanceAuthorityBudget(SetPoolRebalanceAuthorityBudgetIxArgs),
+    SetMaxRebalanceLoss(SetMaxRebalanceLossIxArgs),
 }
 impl SControllerProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -88,6 +134,125 @@ impl SControllerProgramIx {
             END_REBALANCE_IX_DISCM => Ok(Self::EndRebalance),
             SET_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::SetRebalanceAuthority),
             INITIALIZE_IX_DISCM => Ok(Self::Initialize),
+            SET_LST_MAX_SHARE_IX_DISCM => Ok(Self::SetLstMaxShare(
+                SetLstMaxShareIxArgs::deserialize(&mut reader)?,
+            )),
+            ACCEPT_ADMIN_IX_DISCM => Ok(Self::AcceptAdmin),
+            ACCEPT_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::AcceptRebalanceAuthority),
+            ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM => Ok(Self::AcceptProtocolFeeBeneficiary),
+            CANCEL_AUTHORITY_HANDOFF_IX_DISCM => Ok(Self::CancelAuthorityHandoff(
+                CancelAuthorityHandoffIxArgs::deserialize(&mut reader)?,
+            )),
+            QUEUE_SET_PRICING_PROGRAM_IX_DISCM => Ok(Self::QueueSetPricingProgram),
+            EXECUTE_SET_PRICING_PROGRAM_IX_DISCM => Ok(Self::ExecuteSetPricingProgram),
+            QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM => Ok(Self::QueueSetSolValueCalculator(
+                QueueSetSolValueCalculatorIxArgs::deserialize(&mut reader)?,
+            )),
+            EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM => Ok(Self::ExecuteSetSolValueCalculator(
+                ExecuteSetSolValueCalculatorIxArgs::deserialize(&mut reader)?,
+            )),
+            QUEUE_SET_TIMELOCK_DELAY_IX_DISCM => Ok(Self::QueueSetTimelockDelay(
+                QueueSetTimelockDelayIxArgs::deserialize(&mut reader)?,
+            )),
+            EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM => Ok(Self::ExecuteSetTimelockDelay),
+            CANCEL_QUEUED_CHANGE_IX_DISCM => Ok(Self::CancelQueuedChange),
+            SYNC_SOL_VALUE_MULTI_IX_DISCM => Ok(Self::SyncSolValueMulti(
+                SyncSolValueMultiIxArgs::deserialize(&mut reader)?,
+            )),
+            DISABLE_LST_OUTPUT_IX_DISCM => Ok(Self::DisableLstOutput(
+                DisableLstOutputIxArgs::deserialize(&mut reader)?,
+            )),
+            ENABLE_LST_OUTPUT_IX_DISCM => Ok(Self::EnableLstOutput(
+                EnableLstOutputIxArgs::deserialize(&mut reader)?,
+            )),
+            QUOTE_SWAP_EXACT_IN_IX_DISCM => Ok(Self::QuoteSwapExactIn(
+                QuoteSwapExactInIxArgs::deserialize(&mut reader)?,
+            )),
+            QUOTE_SWAP_EXACT_OUT_IX_DISCM => Ok(Self::QuoteSwapExactOut(
+                QuoteSwapExactOutIxArgs::deserialize(&mut reader)?,
+            )),
+            QUOTE_ADD_LIQUIDITY_IX_DISCM => Ok(Self::QuoteAddLiquidity(
+                QuoteAddLiquidityIxArgs::deserialize(&mut reader)?,
+            )),
+            QUOTE_REMOVE_LIQUIDITY_IX_DISCM => Ok(Self::QuoteRemoveLiquidity(
+                QuoteRemoveLiquidityIxArgs::deserialize(&mut reader)?,
+            )),
+            FLASH_BORROW_IX_DISCM => Ok(Self::FlashBorrow(FlashBorrowIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            FLASH_REPAY_IX_DISCM => Ok(Self::FlashRepay),
+            SET_LST_FLASH_LOAN_FEE_IX_DISCM => Ok(Self::SetLstFlashLoanFee(
+                SetLstFlashLoanFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_REBALANCE_LEG_IX_DISCM => Ok(Self::AddRebalanceLeg(
+                AddRebalanceLegIxArgs::deserialize(&mut reader)?,
+            )),
+            SETTLE_REBALANCE_LEG_IX_DISCM => Ok(Self::SettleRebalanceLeg),
+            REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM => Ok(Self::RemoveLiquidityProportional(
+                RemoveLiquidityProportionalIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM => {
+                Ok(Self::SetRemoveLiquidityProportionalFee(
+                    SetRemoveLiquidityProportionalFeeIxArgs::deserialize(&mut reader)?,
+                ))
+            }
+            ADD_LIQUIDITY_MULTI_IX_DISCM => Ok(Self::AddLiquidityMulti(
+                AddLiquidityMultiIxArgs::deserialize(&mut reader)?,
+            )),
+            DEPOSIT_STAKE_IX_DISCM => Ok(Self::DepositStake(DepositStakeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM => Ok(Self::RemoveLiquidityToStake(
+                RemoveLiquidityToStakeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_REFERRAL_FEE_IX_DISCM => Ok(Self::SetReferralFee(
+                SetReferralFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM => Ok(Self::AddProtocolFeeBeneficiary(
+                AddProtocolFeeBeneficiaryIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM => Ok(Self::RemoveProtocolFeeBeneficiary(
+                RemoveProtocolFeeBeneficiaryIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM => {
+                Ok(Self::SetProtocolFeeBeneficiaryWeight(
+                    SetProtocolFeeBeneficiaryWeightIxArgs::deserialize(&mut reader)?,
+                ))
+            }
+            DISTRIBUTE_PROTOCOL_FEES_IX_DISCM => Ok(Self::DistributeProtocolFees),
+            SET_PROTOCOL_FEES_AS_LP_IX_DISCM => Ok(Self::SetProtocolFeesAsLp(
+                SetProtocolFeesAsLpIxArgs::deserialize(&mut reader)?,
+            )),
+            CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM => Ok(Self::ConvertProtocolFeesToLp(
+                ConvertProtocolFeesToLpIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LST_CIRCUIT_BREAKER_IX_DISCM => Ok(Self::SetLstCircuitBreaker(
+                SetLstCircuitBreakerIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LP_SYNC_REQUIREMENT_IX_DISCM => Ok(Self::SetLpSyncRequirement(
+                SetLpSyncRequirementIxArgs::deserialize(&mut reader)?,
+            )),
+            MIGRATE_IX_DISCM => Ok(Self::Migrate),
+            SET_LP_TOKEN_METADATA_IX_DISCM => Ok(Self::SetLpTokenMetadata(
+                SetLpTokenMetadataIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::AddRebalanceAuthority(
+                AddRebalanceAuthorityIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::RemoveRebalanceAuthority(
+                RemoveRebalanceAuthorityIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM => Ok(Self::SetRebalanceAuthorityBudget(
+                SetRebalanceAuthorityBudgetIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM => {
+                Ok(Self::SetPoolRebalanceAuthorityBudget(
+                    SetPoolRebalanceAuthorityBudgetIxArgs::deserialize(&mut reader)?,
+                ))
+            }
+            SET_MAX_REBALANCE_LOSS_IX_DISCM => Ok(Self::SetMaxRebalanceLoss(
+                SetMaxRebalanceLossIxArgs::deserialize(&mut reader)?,
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("discm {:?} not found", maybe_discm),
@@ -162,6 +327,161 @@ impl SControllerProgramIx {
             Self::EndRebalance => writer.write_all(&[END_REBALANCE_IX_DISCM]),
             Self::SetRebalanceAuthority => writer.write_all(&[SET_REBALANCE_AUTHORITY_IX_DISCM]),
             Self::Initialize => writer.write_all(&[INITIALIZE_IX_DISCM]),
+            Self::SetLstMaxShare(args) => {
+                writer.write_all(&[SET_LST_MAX_SHARE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AcceptAdmin => writer.write_all(&[ACCEPT_ADMIN_IX_DISCM]),
+            Self::AcceptRebalanceAuthority => {
+                writer.write_all(&[ACCEPT_REBALANCE_AUTHORITY_IX_DISCM])
+            }
+            Self::AcceptProtocolFeeBeneficiary => {
+                writer.write_all(&[ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])
+            }
+            Self::CancelAuthorityHandoff(args) => {
+                writer.write_all(&[CANCEL_AUTHORITY_HANDOFF_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QueueSetPricingProgram => writer.write_all(&[QUEUE_SET_PRICING_PROGRAM_IX_DISCM]),
+            Self::ExecuteSetPricingProgram => {
+                writer.write_all(&[EXECUTE_SET_PRICING_PROGRAM_IX_DISCM])
+            }
+            Self::QueueSetSolValueCalculator(args) => {
+                writer.write_all(&[QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ExecuteSetSolValueCalculator(args) => {
+                writer.write_all(&[EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QueueSetTimelockDelay(args) => {
+                writer.write_all(&[QUEUE_SET_TIMELOCK_DELAY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ExecuteSetTimelockDelay => {
+                writer.write_all(&[EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM])
+            }
+            Self::CancelQueuedChange => writer.write_all(&[CANCEL_QUEUED_CHANGE_IX_DISCM]),
+            Self::SyncSolValueMulti(args) => {
+                writer.write_all(&[SYNC_SOL_VALUE_MULTI_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DisableLstOutput(args) => {
+                writer.write_all(&[DISABLE_LST_OUTPUT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::EnableLstOutput(args) => {
+                writer.write_all(&[ENABLE_LST_OUTPUT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QuoteSwapExactIn(args) => {
+                writer.write_all(&[QUOTE_SWAP_EXACT_IN_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QuoteSwapExactOut(args) => {
+                writer.write_all(&[QUOTE_SWAP_EXACT_OUT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QuoteAddLiquidity(args) => {
+                writer.write_all(&[QUOTE_ADD_LIQUIDITY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QuoteRemoveLiquidity(args) => {
+                writer.write_all(&[QUOTE_REMOVE_LIQUIDITY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::FlashBorrow(args) => {
+                writer.write_all(&[FLASH_BORROW_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::FlashRepay => writer.write_all(&[FLASH_REPAY_IX_DISCM]),
+            Self::SetLstFlashLoanFee(args) => {
+                writer.write_all(&[SET_LST_FLASH_LOAN_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddRebalanceLeg(args) => {
+                writer.write_all(&[ADD_REBALANCE_LEG_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SettleRebalanceLeg => writer.write_all(&[SETTLE_REBALANCE_LEG_IX_DISCM]),
+            Self::RemoveLiquidityProportional(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetRemoveLiquidityProportionalFee(args) => {
+                writer.write_all(&[SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidityMulti(args) => {
+                writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DepositStake(args) => {
+                writer.write_all(&[DEPOSIT_STAKE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidityToStake(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetReferralFee(args) => {
+                writer.write_all(&[SET_REFERRAL_FEE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddProtocolFeeBeneficiary(args) => {
+                writer.write_all(&[ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveProtocolFeeBeneficiary(args) => {
+                writer.write_all(&[REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetProtocolFeeBeneficiaryWeight(args) => {
+                writer.write_all(&[SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DistributeProtocolFees => writer.write_all(&[DISTRIBUTE_PROTOCOL_FEES_IX_DISCM]),
+            Self::SetProtocolFeesAsLp(args) => {
+                writer.write_all(&[SET_PROTOCOL_FEES_AS_LP_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ConvertProtocolFeesToLp(args) => {
+                writer.write_all(&[CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstCircuitBreaker(args) => {
+                writer.write_all(&[SET_LST_CIRCUIT_BREAKER_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLpSyncRequirement(args) => {
+                writer.write_all(&[SET_LP_SYNC_REQUIREMENT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::Migrate => writer.write_all(&[MIGRATE_IX_DISCM]),
+            Self::SetLpTokenMetadata(args) => {
+                writer.write_all(&[SET_LP_TOKEN_METADATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddRebalanceAuthority(args) => {
+                writer.write_all(&[ADD_REBALANCE_AUTHORITY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveRebalanceAuthority(args) => {
+                writer.write_all(&[REMOVE_REBALANCE_AUTHORITY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetRebalanceAuthorityBudget(args) => {
+                writer.write_all(&[SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetPoolRebalanceAuthorityBudget(args) => {
+                writer.write_all(&[SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetMaxRebalanceLoss(args) => {
+                writer.write_all(&[SET_MAX_REBALANCE_LOSS_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -401,7 +721,7 @@ pub fn sync_sol_value_verify_account_privileges<'me, 'info>(
     sync_sol_value_verify_writable_privileges(accounts)?;
     Ok(())
 }
-pub const SWAP_EXACT_IN_IX_ACCOUNTS_LEN: usize = 12;
+pub const SWAP_EXACT_IN_IX_ACCOUNTS_LEN: usize = 17;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapExactInAccounts<'me, 'info> {
     ///Authority of src_lst_acc. User making the swap.
@@ -428,6 +748,16 @@ pub struct SwapExactInAccounts<'me, 'info> {
     pub src_pool_reserves: &'me AccountInfo<'info>,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///dst_lst token account of the referrer to transfer the referral fee share of protocol fees to. Set to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct SwapExactInKeys {
@@ -455,6 +785,16 @@ pub struct SwapExactInKeys {
     pub src_pool_reserves: Pubkey,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///dst_lst token account of the referrer to transfer the referral fee share of protocol fees to. Set to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
 }
 impl From<SwapExactInAccounts<'_, '_>> for SwapExactInKeys {
     fn from(accounts: SwapExactInAccounts) -> Self {
@@ -471,6 +811,11 @@ impl From<SwapExactInAccounts<'_, '_>> for SwapExactInKeys {
             lst_state_list: *accounts.lst_state_list.key,
             src_pool_reserves: *accounts.src_pool_reserves.key,
             dst_pool_reserves: *accounts.dst_pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            referrer_dst_lst_acc: *accounts.referrer_dst_lst_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+            lp_token_program: *accounts.lp_token_program.key,
         }
     }
 }
@@ -537,6 +882,31 @@ impl From<SwapExactInKeys> for [AccountMeta; SWAP_EXACT_IN_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer_dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -555,6 +925,11 @@ impl From<[Pubkey; SWAP_EXACT_IN_IX_ACCOUNTS_LEN]> for SwapExactInKeys {
             lst_state_list: pubkeys[9],
             src_pool_reserves: pubkeys[10],
             dst_pool_reserves: pubkeys[11],
+            pool_config: pubkeys[12],
+            referrer_dst_lst_acc: pubkeys[13],
+            lp_token_mint: pubkeys[14],
+            protocol_fee_lp_acc: pubkeys[15],
+            lp_token_program: pubkeys[16],
         }
     }
 }
@@ -575,6 +950,11 @@ impl<'info> From<SwapExactInAccounts<'_, 'info>>
             accounts.lst_state_list.clone(),
             accounts.src_pool_reserves.clone(),
             accounts.dst_pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.referrer_dst_lst_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+            accounts.lp_token_program.clone(),
         ]
     }
 }
@@ -595,6 +975,11 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_EXACT_IN_IX_ACCOUNTS_LEN]>
             lst_state_list: &arr[9],
             src_pool_reserves: &arr[10],
             dst_pool_reserves: &arr[11],
+            pool_config: &arr[12],
+            referrer_dst_lst_acc: &arr[13],
+            lp_token_mint: &arr[14],
+            protocol_fee_lp_acc: &arr[15],
+            lp_token_program: &arr[16],
         }
     }
 }
@@ -720,6 +1105,14 @@ pub fn swap_exact_in_verify_account_keys(
         (accounts.lst_state_list.key, &keys.lst_state_list),
         (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
         (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (
+            accounts.referrer_dst_lst_acc.key,
+            &keys.referrer_dst_lst_acc,
+        ),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -738,6 +1131,9 @@ pub fn swap_exact_in_verify_writable_privileges<'me, 'info>(
         accounts.lst_state_list,
         accounts.src_pool_reserves,
         accounts.dst_pool_reserves,
+        accounts.referrer_dst_lst_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_lp_acc,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -762,7 +1158,7 @@ pub fn swap_exact_in_verify_account_privileges<'me, 'info>(
     swap_exact_in_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const SWAP_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 12;
+pub const SWAP_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 17;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapExactOutAccounts<'me, 'info> {
     ///Authority of src_lst_acc. User making the swap.
@@ -789,6 +1185,16 @@ pub struct SwapExactOutAccounts<'me, 'info> {
     pub src_pool_reserves: &'me AccountInfo<'info>,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///dst_lst token account of the referrer to transfer the referral fee share of protocol fees to. Set to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct SwapExactOutKeys {
@@ -816,6 +1222,16 @@ pub struct SwapExactOutKeys {
     pub src_pool_reserves: Pubkey,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///dst_lst token account of the referrer to transfer the referral fee share of protocol fees to. Set to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
 }
 impl From<SwapExactOutAccounts<'_, '_>> for SwapExactOutKeys {
     fn from(accounts: SwapExactOutAccounts) -> Self {
@@ -832,6 +1248,11 @@ impl From<SwapExactOutAccounts<'_, '_>> for SwapExactOutKeys {
             lst_state_list: *accounts.lst_state_list.key,
             src_pool_reserves: *accounts.src_pool_reserves.key,
             dst_pool_reserves: *accounts.dst_pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            referrer_dst_lst_acc: *accounts.referrer_dst_lst_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+            lp_token_program: *accounts.lp_token_program.key,
         }
     }
 }
@@ -898,6 +1319,31 @@ impl From<SwapExactOutKeys> for [AccountMeta; SWAP_EXACT_OUT_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.referrer_dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -916,6 +1362,11 @@ impl From<[Pubkey; SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]> for SwapExactOutKeys {
             lst_state_list: pubkeys[9],
             src_pool_reserves: pubkeys[10],
             dst_pool_reserves: pubkeys[11],
+            pool_config: pubkeys[12],
+            referrer_dst_lst_acc: pubkeys[13],
+            lp_token_mint: pubkeys[14],
+            protocol_fee_lp_acc: pubkeys[15],
+            lp_token_program: pubkeys[16],
         }
     }
 }
@@ -936,6 +1387,11 @@ impl<'info> From<SwapExactOutAccounts<'_, 'info>>
             accounts.lst_state_list.clone(),
             accounts.src_pool_reserves.clone(),
             accounts.dst_pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.referrer_dst_lst_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+            accounts.lp_token_program.clone(),
         ]
     }
 }
@@ -956,6 +1412,11 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]>
             lst_state_list: &arr[9],
             src_pool_reserves: &arr[10],
             dst_pool_reserves: &arr[11],
+            pool_config: &arr[12],
+            referrer_dst_lst_acc: &arr[13],
+            lp_token_mint: &arr[14],
+            protocol_fee_lp_acc: &arr[15],
+            lp_token_program: &arr[16],
         }
     }
 }
@@ -1081,6 +1542,14 @@ pub fn swap_exact_out_verify_account_keys(
         (accounts.lst_state_list.key, &keys.lst_state_list),
         (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
         (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (
+            accounts.referrer_dst_lst_acc.key,
+            &keys.referrer_dst_lst_acc,
+        ),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1099,6 +1568,9 @@ pub fn swap_exact_out_verify_writable_privileges<'me, 'info>(
         accounts.lst_state_list,
         accounts.src_pool_reserves,
         accounts.dst_pool_reserves,
+        accounts.referrer_dst_lst_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_lp_acc,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -1123,7 +1595,7 @@ pub fn swap_exact_out_verify_account_privileges<'me, 'info>(
     swap_exact_out_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 11;
+pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct AddLiquidityAccounts<'me, 'info> {
     ///Authority of src_lst_acc. User who's adding liquidity.
@@ -1148,6 +1620,10 @@ pub struct AddLiquidityAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
     ///LST reserves token account of the pool
     pub pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct AddLiquidityKeys {
@@ -1173,6 +1649,10 @@ pub struct AddLiquidityKeys {
     pub lst_state_list: Pubkey,
     ///LST reserves token account of the pool
     pub pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
 }
 impl From<AddLiquidityAccounts<'_, '_>> for AddLiquidityKeys {
     fn from(accounts: AddLiquidityAccounts) -> Self {
@@ -1188,6 +1668,8 @@ impl From<AddLiquidityAccounts<'_, '_>> for AddLiquidityKeys {
             pool_state: *accounts.pool_state.key,
             lst_state_list: *accounts.lst_state_list.key,
             pool_reserves: *accounts.pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
         }
     }
 }
@@ -1249,6 +1731,16 @@ impl From<AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
         ]
     }
 }
@@ -1266,6 +1758,8 @@ impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
             pool_state: pubkeys[8],
             lst_state_list: pubkeys[9],
             pool_reserves: pubkeys[10],
+            pool_config: pubkeys[11],
+            protocol_fee_lp_acc: pubkeys[12],
         }
     }
 }
@@ -1285,6 +1779,8 @@ impl<'info> From<AddLiquidityAccounts<'_, 'info>>
             accounts.pool_state.clone(),
             accounts.lst_state_list.clone(),
             accounts.pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
         ]
     }
 }
@@ -1304,6 +1800,8 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
             pool_state: &arr[8],
             lst_state_list: &arr[9],
             pool_reserves: &arr[10],
+            pool_config: &arr[11],
+            protocol_fee_lp_acc: &arr[12],
         }
     }
 }
@@ -1420,6 +1918,8 @@ pub fn add_liquidity_verify_account_keys(
         (accounts.pool_state.key, &keys.pool_state),
         (accounts.lst_state_list.key, &keys.lst_state_list),
         (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1438,6 +1938,7 @@ pub fn add_liquidity_verify_writable_privileges<'me, 'info>(
         accounts.pool_state,
         accounts.lst_state_list,
         accounts.pool_reserves,
+        accounts.protocol_fee_lp_acc,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -1462,7 +1963,7 @@ pub fn add_liquidity_verify_account_privileges<'me, 'info>(
     add_liquidity_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 11;
+pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct RemoveLiquidityAccounts<'me, 'info> {
     ///Authority of lp_acc. User who's removing liquidity.
@@ -1487,6 +1988,10 @@ pub struct RemoveLiquidityAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
     ///LST reserves token account of the pool
     pub pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct RemoveLiquidityKeys {
@@ -1512,6 +2017,10 @@ pub struct RemoveLiquidityKeys {
     pub lst_state_list: Pubkey,
     ///LST reserves token account of the pool
     pub pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
 }
 impl From<RemoveLiquidityAccounts<'_, '_>> for RemoveLiquidityKeys {
     fn from(accounts: RemoveLiquidityAccounts) -> Self {
@@ -1527,6 +2036,8 @@ impl From<RemoveLiquidityAccounts<'_, '_>> for RemoveLiquidityKeys {
             pool_state: *accounts.pool_state.key,
             lst_state_list: *accounts.lst_state_list.key,
             pool_reserves: *accounts.pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
         }
     }
 }
@@ -1588,6 +2099,16 @@ impl From<RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LE
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
         ]
     }
 }
@@ -1605,6 +2126,8 @@ impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
             pool_state: pubkeys[8],
             lst_state_list: pubkeys[9],
             pool_reserves: pubkeys[10],
+            pool_config: pubkeys[11],
+            protocol_fee_lp_acc: pubkeys[12],
         }
     }
 }
@@ -1624,6 +2147,8 @@ impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
             accounts.pool_state.clone(),
             accounts.lst_state_list.clone(),
             accounts.pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
         ]
     }
 }
@@ -1643,6 +2168,8 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
             pool_state: &arr[8],
             lst_state_list: &arr[9],
             pool_reserves: &arr[10],
+            pool_config: &arr[11],
+            protocol_fee_lp_acc: &arr[12],
         }
     }
 }
@@ -1759,6 +2286,8 @@ pub fn remove_liquidity_verify_account_keys(
         (accounts.pool_state.key, &keys.pool_state),
         (accounts.lst_state_list.key, &keys.lst_state_list),
         (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1777,6 +2306,7 @@ pub fn remove_liquidity_verify_writable_privileges<'me, 'info>(
         accounts.pool_state,
         accounts.lst_state_list,
         accounts.pool_reserves,
+        accounts.protocol_fee_lp_acc,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -3135,24 +3665,36 @@ pub fn set_sol_value_calculator_verify_account_privileges<'me, 'info>(
     set_sol_value_calculator_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const SET_ADMIN_IX_ACCOUNTS_LEN: usize = 3;
+pub const SET_ADMIN_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct SetAdminAccounts<'me, 'info> {
     ///The pool's current admin
     pub current_admin: &'me AccountInfo<'info>,
-    ///The pool's new admin
+    ///The pool's new admin to propose
     pub new_admin: &'me AccountInfo<'info>,
     ///The pool's state singleton PDA
     pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct SetAdminKeys {
     ///The pool's current admin
     pub current_admin: Pubkey,
-    ///The pool's new admin
+    ///The pool's new admin to propose
     pub new_admin: Pubkey,
     ///The pool's state singleton PDA
     pub pool_state: Pubkey,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
 }
 impl From<SetAdminAccounts<'_, '_>> for SetAdminKeys {
     fn from(accounts: SetAdminAccounts) -> Self {
@@ -3160,6 +3702,9 @@ impl From<SetAdminAccounts<'_, '_>> for SetAdminKeys {
             current_admin: *accounts.current_admin.key,
             new_admin: *accounts.new_admin.key,
             pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pending_authorities: *accounts.pending_authorities.key,
+            system_program: *accounts.system_program.key,
         }
     }
 }
@@ -3179,8 +3724,23 @@ impl From<SetAdminKeys> for [AccountMeta; SET_ADMIN_IX_ACCOUNTS_LEN] {
             AccountMeta {
                 pubkey: keys.pool_state,
                 is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -3190,6 +3750,9 @@ impl From<[Pubkey; SET_ADMIN_IX_ACCOUNTS_LEN]> for SetAdminKeys {
             current_admin: pubkeys[0],
             new_admin: pubkeys[1],
             pool_state: pubkeys[2],
+            payer: pubkeys[3],
+            pending_authorities: pubkeys[4],
+            system_program: pubkeys[5],
         }
     }
 }
@@ -3199,6 +3762,9 @@ impl<'info> From<SetAdminAccounts<'_, 'info>> for [AccountInfo<'info>; SET_ADMIN
             accounts.current_admin.clone(),
             accounts.new_admin.clone(),
             accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pending_authorities.clone(),
+            accounts.system_program.clone(),
         ]
     }
 }
@@ -3210,6 +3776,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_ADMIN_IX_ACCOUNTS_LEN]>
             current_admin: &arr[0],
             new_admin: &arr[1],
             pool_state: &arr[2],
+            payer: &arr[3],
+            pending_authorities: &arr[4],
+            system_program: &arr[5],
         }
     }
 }
@@ -3290,6 +3859,9 @@ pub fn set_admin_verify_account_keys(
         (accounts.current_admin.key, &keys.current_admin),
         (accounts.new_admin.key, &keys.new_admin),
         (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+        (accounts.system_program.key, &keys.system_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -3300,7 +3872,7 @@ pub fn set_admin_verify_account_keys(
 pub fn set_admin_verify_writable_privileges<'me, 'info>(
     accounts: SetAdminAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.pool_state] {
+    for should_be_writable in [accounts.payer, accounts.pending_authorities] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -3310,7 +3882,7 @@ pub fn set_admin_verify_writable_privileges<'me, 'info>(
 pub fn set_admin_verify_signer_privileges<'me, 'info>(
     accounts: SetAdminAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.current_admin] {
+    for should_be_signer in [accounts.current_admin, accounts.payer] {
         if !should_be_signer.is_signer {
             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
         }
@@ -3521,24 +4093,36 @@ pub fn set_protocol_fee_verify_account_privileges<'me, 'info>(
     set_protocol_fee_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const SET_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN: usize = 3;
+pub const SET_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct SetProtocolFeeBeneficiaryAccounts<'me, 'info> {
     ///The pool's current protocol fee beneficiary
     pub current_beneficiary: &'me AccountInfo<'info>,
-    ///The pool's new protocol fee beneficiary
+    ///The pool's new protocol fee beneficiary to propose
     pub new_beneficiary: &'me AccountInfo<'info>,
     ///The pool's state singleton PDA
     pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct SetProtocolFeeBeneficiaryKeys {
     ///The pool's current protocol fee beneficiary
     pub current_beneficiary: Pubkey,
-    ///The pool's new protocol fee beneficiary
+    ///The pool's new protocol fee beneficiary to propose
     pub new_beneficiary: Pubkey,
     ///The pool's state singleton PDA
     pub pool_state: Pubkey,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
 }
 impl From<SetProtocolFeeBeneficiaryAccounts<'_, '_>> for SetProtocolFeeBeneficiaryKeys {
     fn from(accounts: SetProtocolFeeBeneficiaryAccounts) -> Self {
@@ -3546,6 +4130,9 @@ impl From<SetProtocolFeeBeneficiaryAccounts<'_, '_>> for SetProtocolFeeBeneficia
             current_beneficiary: *accounts.current_beneficiary.key,
             new_beneficiary: *accounts.new_beneficiary.key,
             pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pending_authorities: *accounts.pending_authorities.key,
+            system_program: *accounts.system_program.key,
         }
     }
 }
@@ -3567,8 +4154,23 @@ impl From<SetProtocolFeeBeneficiaryKeys>
             AccountMeta {
                 pubkey: keys.pool_state,
                 is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -3580,6 +4182,9 @@ impl From<[Pubkey; SET_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
             current_beneficiary: pubkeys[0],
             new_beneficiary: pubkeys[1],
             pool_state: pubkeys[2],
+            payer: pubkeys[3],
+            pending_authorities: pubkeys[4],
+            system_program: pubkeys[5],
         }
     }
 }
@@ -3591,6 +4196,9 @@ impl<'info> From<SetProtocolFeeBeneficiaryAccounts<'_, 'info>>
             accounts.current_beneficiary.clone(),
             accounts.new_beneficiary.clone(),
             accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pending_authorities.clone(),
+            accounts.system_program.clone(),
         ]
     }
 }
@@ -3602,6 +4210,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_BENEFICIARY_IX_
             current_beneficiary: &arr[0],
             new_beneficiary: &arr[1],
             pool_state: &arr[2],
+            payer: &arr[3],
+            pending_authorities: &arr[4],
+            system_program: &arr[5],
         }
     }
 }
@@ -3686,6 +4297,9 @@ pub fn set_protocol_fee_beneficiary_verify_account_keys(
         (accounts.current_beneficiary.key, &keys.current_beneficiary),
         (accounts.new_beneficiary.key, &keys.new_beneficiary),
         (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+        (accounts.system_program.key, &keys.system_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -3696,7 +4310,7 @@ pub fn set_protocol_fee_beneficiary_verify_account_keys(
 pub fn set_protocol_fee_beneficiary_verify_writable_privileges<'me, 'info>(
     accounts: SetProtocolFeeBeneficiaryAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.pool_state] {
+    for should_be_writable in [accounts.payer, accounts.pending_authorities] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -3706,7 +4320,7 @@ pub fn set_protocol_fee_beneficiary_verify_writable_privileges<'me, 'info>(
 pub fn set_protocol_fee_beneficiary_verify_signer_privileges<'me, 'info>(
     accounts: SetProtocolFeeBeneficiaryAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.current_beneficiary] {
+    for should_be_signer in [accounts.current_beneficiary, accounts.payer] {
         if !should_be_signer.is_signer {
             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
         }
@@ -5057,10 +5671,10 @@ pub fn enable_pool_verify_account_privileges<'me, 'info>(
     enable_pool_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const START_REBALANCE_IX_ACCOUNTS_LEN: usize = 12;
+pub const START_REBALANCE_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
 pub struct StartRebalanceAccounts<'me, 'info> {
-    ///The pool's rebalance authority
+    ///The pool's rebalance authority or a rebalance authority on the rebalance authority list
     pub rebalance_authority: &'me AccountInfo<'info>,
     ///The pool's state singleton PDA
     pub pool_state: &'me AccountInfo<'info>,
@@ -5084,10 +5698,14 @@ pub struct StartRebalanceAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
     ///Source LST token program
     pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct StartRebalanceKeys {
-    ///The pool's rebalance authority
+    ///The pool's rebalance authority or a rebalance authority on the rebalance authority list
     pub rebalance_authority: Pubkey,
     ///The pool's state singleton PDA
     pub pool_state: Pubkey,
@@ -5111,6 +5729,10 @@ pub struct StartRebalanceKeys {
     pub system_program: Pubkey,
     ///Source LST token program
     pub src_lst_token_program: Pubkey,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
 }
 impl From<StartRebalanceAccounts<'_, '_>> for StartRebalanceKeys {
     fn from(accounts: StartRebalanceAccounts) -> Self {
@@ -5127,6 +5749,8 @@ impl From<StartRebalanceAccounts<'_, '_>> for StartRebalanceKeys {
             instructions: *accounts.instructions.key,
             system_program: *accounts.system_program.key,
             src_lst_token_program: *accounts.src_lst_token_program.key,
+            rebalance_authority_list: *accounts.rebalance_authority_list.key,
+            pool_config: *accounts.pool_config.key,
         }
     }
 }
@@ -5193,6 +5817,16 @@ impl From<StartRebalanceKeys> for [AccountMeta; START_REBALANCE_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.rebalance_authority_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
         ]
     }
 }
@@ -5211,6 +5845,8 @@ impl From<[Pubkey; START_REBALANCE_IX_ACCOUNTS_LEN]> for StartRebalanceKeys {
             instructions: pubkeys[9],
             system_program: pubkeys[10],
             src_lst_token_program: pubkeys[11],
+            rebalance_authority_list: pubkeys[12],
+            pool_config: pubkeys[13],
         }
     }
 }
@@ -5231,6 +5867,8 @@ impl<'info> From<StartRebalanceAccounts<'_, 'info>>
             accounts.instructions.clone(),
             accounts.system_program.clone(),
             accounts.src_lst_token_program.clone(),
+            accounts.rebalance_authority_list.clone(),
+            accounts.pool_config.clone(),
         ]
     }
 }
@@ -5251,6 +5889,8 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; START_REBALANCE_IX_ACCOUNTS_LEN]
             instructions: &arr[9],
             system_program: &arr[10],
             src_lst_token_program: &arr[11],
+            rebalance_authority_list: &arr[12],
+            pool_config: &arr[13],
         }
     }
 }
@@ -5264,6 +5904,7 @@ pub struct StartRebalanceIxArgs {
     pub amount: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    pub max_loss_lamports: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct StartRebalanceIxData(pub StartRebalanceIxArgs);
@@ -5370,6 +6011,11 @@ pub fn start_rebalance_verify_account_keys(
             accounts.src_lst_token_program.key,
             &keys.src_lst_token_program,
         ),
+        (
+            accounts.rebalance_authority_list.key,
+            &keys.rebalance_authority_list,
+        ),
+        (accounts.pool_config.key, &keys.pool_config),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -5387,6 +6033,8 @@ pub fn start_rebalance_verify_writable_privileges<'me, 'info>(
         accounts.src_pool_reserves,
         accounts.dst_pool_reserves,
         accounts.withdraw_to,
+        accounts.rebalance_authority_list,
+        accounts.pool_config,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -5414,7 +6062,7 @@ pub fn start_rebalance_verify_account_privileges<'me, 'info>(
 pub const END_REBALANCE_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct EndRebalanceAccounts<'me, 'info> {
-    ///The pool's rebalance authority
+    ///The rebalance authority that signed the StartRebalance instruction
     pub rebalance_authority: &'me AccountInfo<'info>,
     ///The pool's state singleton PDA
     pub pool_state: &'me AccountInfo<'info>,
@@ -5429,7 +6077,7 @@ pub struct EndRebalanceAccounts<'me, 'info> {
 }
 #[derive(Copy, Clone, Debug)]
 pub struct EndRebalanceKeys {
-    ///The pool's rebalance authority
+    ///The rebalance authority that signed the StartRebalance instruction
     pub rebalance_authority: Pubkey,
     ///The pool's state singleton PDA
     pub pool_state: Pubkey,
@@ -5648,24 +6296,36 @@ pub fn end_rebalance_verify_account_privileges<'me, 'info>(
     end_rebalance_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const SET_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
+pub const SET_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct SetRebalanceAuthorityAccounts<'me, 'info> {
     ///Either the pool's rebalance authority or admin
     pub signer: &'me AccountInfo<'info>,
-    ///The new rebalance authority to set to
+    ///The new rebalance authority to propose
     pub new_rebalance_authority: &'me AccountInfo<'info>,
     ///The pool's state singleton PDA
     pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct SetRebalanceAuthorityKeys {
     ///Either the pool's rebalance authority or admin
     pub signer: Pubkey,
-    ///The new rebalance authority to set to
+    ///The new rebalance authority to propose
     pub new_rebalance_authority: Pubkey,
     ///The pool's state singleton PDA
     pub pool_state: Pubkey,
+    ///Account paying for the PendingAuthorities PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
 }
 impl From<SetRebalanceAuthorityAccounts<'_, '_>> for SetRebalanceAuthorityKeys {
     fn from(accounts: SetRebalanceAuthorityAccounts) -> Self {
@@ -5673,6 +6333,9 @@ impl From<SetRebalanceAuthorityAccounts<'_, '_>> for SetRebalanceAuthorityKeys {
             signer: *accounts.signer.key,
             new_rebalance_authority: *accounts.new_rebalance_authority.key,
             pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pending_authorities: *accounts.pending_authorities.key,
+            system_program: *accounts.system_program.key,
         }
     }
 }
@@ -5692,8 +6355,23 @@ impl From<SetRebalanceAuthorityKeys> for [AccountMeta; SET_REBALANCE_AUTHORITY_I
             AccountMeta {
                 pubkey: keys.pool_state,
                 is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -5703,6 +6381,9 @@ impl From<[Pubkey; SET_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]> for SetRebalanceAut
             signer: pubkeys[0],
             new_rebalance_authority: pubkeys[1],
             pool_state: pubkeys[2],
+            payer: pubkeys[3],
+            pending_authorities: pubkeys[4],
+            system_program: pubkeys[5],
         }
     }
 }
@@ -5714,6 +6395,9 @@ impl<'info> From<SetRebalanceAuthorityAccounts<'_, 'info>>
             accounts.signer.clone(),
             accounts.new_rebalance_authority.clone(),
             accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pending_authorities.clone(),
+            accounts.system_program.clone(),
         ]
     }
 }
@@ -5725,6 +6409,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_REBALANCE_AUTHORITY_IX_ACCOU
             signer: &arr[0],
             new_rebalance_authority: &arr[1],
             pool_state: &arr[2],
+            payer: &arr[3],
+            pending_authorities: &arr[4],
+            system_program: &arr[5],
         }
     }
 }
@@ -5810,6 +6497,9 @@ pub fn set_rebalance_authority_verify_account_keys(
             &keys.new_rebalance_authority,
         ),
         (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+        (accounts.system_program.key, &keys.system_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -5820,7 +6510,7 @@ pub fn set_rebalance_authority_verify_account_keys(
 pub fn set_rebalance_authority_verify_writable_privileges<'me, 'info>(
     accounts: SetRebalanceAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.pool_state] {
+    for should_be_writable in [accounts.payer, accounts.pending_authorities] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -5830,7 +6520,7 @@ pub fn set_rebalance_authority_verify_writable_privileges<'me, 'info>(
 pub fn set_rebalance_authority_verify_signer_privileges<'me, 'info>(
     accounts: SetRebalanceAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.signer] {
+    for should_be_signer in [accounts.signer, accounts.payer] {
         if !should_be_signer.is_signer {
             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
         }
@@ -6077,3 +6767,11261 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+pub const SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstMaxShareAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to set the max share for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstMaxShareKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to set the max share for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SetLstMaxShareAccounts<'_, '_>> for SetLstMaxShareKeys {
+    fn from(accounts: SetLstMaxShareAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SetLstMaxShareKeys> for [AccountMeta; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstMaxShareKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN]> for SetLstMaxShareKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLstMaxShareAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstMaxShareAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN]>
+    for SetLstMaxShareAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const SET_LST_MAX_SHARE_IX_DISCM: u8 = 23u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstMaxShareIxArgs {
+    pub index: u32,
+    pub max_share_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstMaxShareIxData(pub SetLstMaxShareIxArgs);
+impl From<SetLstMaxShareIxArgs> for SetLstMaxShareIxData {
+    fn from(args: SetLstMaxShareIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstMaxShareIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_MAX_SHARE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_MAX_SHARE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstMaxShareIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_MAX_SHARE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_max_share_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstMaxShareKeys,
+    args: SetLstMaxShareIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_MAX_SHARE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstMaxShareIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_max_share_ix(
+    keys: SetLstMaxShareKeys,
+    args: SetLstMaxShareIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_max_share_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_max_share_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstMaxShareAccounts<'_, '_>,
+    args: SetLstMaxShareIxArgs,
+) -> ProgramResult {
+    let keys: SetLstMaxShareKeys = accounts.into();
+    let ix = set_lst_max_share_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_max_share_invoke(
+    accounts: SetLstMaxShareAccounts<'_, '_>,
+    args: SetLstMaxShareIxArgs,
+) -> ProgramResult {
+    set_lst_max_share_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_max_share_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstMaxShareAccounts<'_, '_>,
+    args: SetLstMaxShareIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstMaxShareKeys = accounts.into();
+    let ix = set_lst_max_share_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_max_share_invoke_signed(
+    accounts: SetLstMaxShareAccounts<'_, '_>,
+    args: SetLstMaxShareIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_max_share_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_max_share_verify_account_keys(
+    accounts: SetLstMaxShareAccounts<'_, '_>,
+    keys: SetLstMaxShareKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_max_share_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstMaxShareAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_max_share_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstMaxShareAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_max_share_verify_account_privileges<'me, 'info>(
+    accounts: SetLstMaxShareAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_max_share_verify_writable_privileges(accounts)?;
+    set_lst_max_share_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_ADMIN_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptAdminAccounts<'me, 'info> {
+    ///The pool's pending admin
+    pub pending_admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptAdminKeys {
+    ///The pool's pending admin
+    pub pending_admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+}
+impl From<AcceptAdminAccounts<'_, '_>> for AcceptAdminKeys {
+    fn from(accounts: AcceptAdminAccounts) -> Self {
+        Self {
+            pending_admin: *accounts.pending_admin.key,
+            pool_state: *accounts.pool_state.key,
+            pending_authorities: *accounts.pending_authorities.key,
+        }
+    }
+}
+impl From<AcceptAdminKeys> for [AccountMeta; ACCEPT_ADMIN_IX_ACCOUNTS_LEN] {
+    fn from(keys: AcceptAdminKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pending_admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_ADMIN_IX_ACCOUNTS_LEN]> for AcceptAdminKeys {
+    fn from(pubkeys: [Pubkey; ACCEPT_ADMIN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            pending_authorities: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<AcceptAdminAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ACCEPT_ADMIN_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AcceptAdminAccounts<'_, 'info>) -> Self {
+        [
+            accounts.pending_admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_authorities.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_ADMIN_IX_ACCOUNTS_LEN]>
+    for AcceptAdminAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ACCEPT_ADMIN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_admin: &arr[0],
+            pool_state: &arr[1],
+            pending_authorities: &arr[2],
+        }
+    }
+}
+pub const ACCEPT_ADMIN_IX_DISCM: u8 = 24u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptAdminIxData;
+impl AcceptAdminIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ACCEPT_ADMIN_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ACCEPT_ADMIN_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ACCEPT_ADMIN_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_admin_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptAdminKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_ADMIN_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptAdminIxData.try_to_vec()?,
+    })
+}
+pub fn accept_admin_ix(keys: AcceptAdminKeys) -> std::io::Result<Instruction> {
+    accept_admin_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_admin_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptAdminAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptAdminKeys = accounts.into();
+    let ix = accept_admin_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_admin_invoke(accounts: AcceptAdminAccounts<'_, '_>) -> ProgramResult {
+    accept_admin_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_admin_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptAdminAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptAdminKeys = accounts.into();
+    let ix = accept_admin_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_admin_invoke_signed(
+    accounts: AcceptAdminAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_admin_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_admin_verify_account_keys(
+    accounts: AcceptAdminAccounts<'_, '_>,
+    keys: AcceptAdminKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pending_admin.key, &keys.pending_admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_admin_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.pending_authorities] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_admin_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.pending_admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_admin_verify_account_privileges<'me, 'info>(
+    accounts: AcceptAdminAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_admin_verify_writable_privileges(accounts)?;
+    accept_admin_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptRebalanceAuthorityAccounts<'me, 'info> {
+    ///The pool's pending rebalance authority
+    pub pending_rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptRebalanceAuthorityKeys {
+    ///The pool's pending rebalance authority
+    pub pending_rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+}
+impl From<AcceptRebalanceAuthorityAccounts<'_, '_>> for AcceptRebalanceAuthorityKeys {
+    fn from(accounts: AcceptRebalanceAuthorityAccounts) -> Self {
+        Self {
+            pending_rebalance_authority: *accounts.pending_rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            pending_authorities: *accounts.pending_authorities.key,
+        }
+    }
+}
+impl From<AcceptRebalanceAuthorityKeys>
+    for [AccountMeta; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: AcceptRebalanceAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pending_rebalance_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]> for AcceptRebalanceAuthorityKeys {
+    fn from(pubkeys: [Pubkey; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_rebalance_authority: pubkeys[0],
+            pool_state: pubkeys[1],
+            pending_authorities: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<AcceptRebalanceAuthorityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AcceptRebalanceAuthorityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.pending_rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_authorities.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]>
+    for AcceptRebalanceAuthorityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_rebalance_authority: &arr[0],
+            pool_state: &arr[1],
+            pending_authorities: &arr[2],
+        }
+    }
+}
+pub const ACCEPT_REBALANCE_AUTHORITY_IX_DISCM: u8 = 25u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptRebalanceAuthorityIxData;
+impl AcceptRebalanceAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ACCEPT_REBALANCE_AUTHORITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ACCEPT_REBALANCE_AUTHORITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ACCEPT_REBALANCE_AUTHORITY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_rebalance_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptRebalanceAuthorityKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptRebalanceAuthorityIxData.try_to_vec()?,
+    })
+}
+pub fn accept_rebalance_authority_ix(
+    keys: AcceptRebalanceAuthorityKeys,
+) -> std::io::Result<Instruction> {
+    accept_rebalance_authority_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_rebalance_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptRebalanceAuthorityAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptRebalanceAuthorityKeys = accounts.into();
+    let ix = accept_rebalance_authority_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_rebalance_authority_invoke(
+    accounts: AcceptRebalanceAuthorityAccounts<'_, '_>,
+) -> ProgramResult {
+    accept_rebalance_authority_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_rebalance_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptRebalanceAuthorityAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptRebalanceAuthorityKeys = accounts.into();
+    let ix = accept_rebalance_authority_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_rebalance_authority_invoke_signed(
+    accounts: AcceptRebalanceAuthorityAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_rebalance_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_rebalance_authority_verify_account_keys(
+    accounts: AcceptRebalanceAuthorityAccounts<'_, '_>,
+    keys: AcceptRebalanceAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            accounts.pending_rebalance_authority.key,
+            &keys.pending_rebalance_authority,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_rebalance_authority_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.pending_authorities] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_rebalance_authority_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.pending_rebalance_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_rebalance_authority_verify_account_privileges<'me, 'info>(
+    accounts: AcceptRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_rebalance_authority_verify_writable_privileges(accounts)?;
+    accept_rebalance_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptProtocolFeeBeneficiaryAccounts<'me, 'info> {
+    ///The pool's pending protocol fee beneficiary
+    pub pending_beneficiary: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptProtocolFeeBeneficiaryKeys {
+    ///The pool's pending protocol fee beneficiary
+    pub pending_beneficiary: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+}
+impl From<AcceptProtocolFeeBeneficiaryAccounts<'_, '_>> for AcceptProtocolFeeBeneficiaryKeys {
+    fn from(accounts: AcceptProtocolFeeBeneficiaryAccounts) -> Self {
+        Self {
+            pending_beneficiary: *accounts.pending_beneficiary.key,
+            pool_state: *accounts.pool_state.key,
+            pending_authorities: *accounts.pending_authorities.key,
+        }
+    }
+}
+impl From<AcceptProtocolFeeBeneficiaryKeys>
+    for [AccountMeta; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: AcceptProtocolFeeBeneficiaryKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pending_beneficiary,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for AcceptProtocolFeeBeneficiaryKeys
+{
+    fn from(pubkeys: [Pubkey; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pending_beneficiary: pubkeys[0],
+            pool_state: pubkeys[1],
+            pending_authorities: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<AcceptProtocolFeeBeneficiaryAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, 'info>) -> Self {
+        [
+            accounts.pending_beneficiary.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_authorities.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for AcceptProtocolFeeBeneficiaryAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            pending_beneficiary: &arr[0],
+            pool_state: &arr[1],
+            pending_authorities: &arr[2],
+        }
+    }
+}
+pub const ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM: u8 = 26u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptProtocolFeeBeneficiaryIxData;
+impl AcceptProtocolFeeBeneficiaryIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn accept_protocol_fee_beneficiary_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AcceptProtocolFeeBeneficiaryKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ACCEPT_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AcceptProtocolFeeBeneficiaryIxData.try_to_vec()?,
+    })
+}
+pub fn accept_protocol_fee_beneficiary_ix(
+    keys: AcceptProtocolFeeBeneficiaryKeys,
+) -> std::io::Result<Instruction> {
+    accept_protocol_fee_beneficiary_ix_with_program_id(crate::ID, keys)
+}
+pub fn accept_protocol_fee_beneficiary_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: AcceptProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = accept_protocol_fee_beneficiary_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn accept_protocol_fee_beneficiary_invoke(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, '_>,
+) -> ProgramResult {
+    accept_protocol_fee_beneficiary_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn accept_protocol_fee_beneficiary_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AcceptProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = accept_protocol_fee_beneficiary_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn accept_protocol_fee_beneficiary_invoke_signed(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    accept_protocol_fee_beneficiary_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn accept_protocol_fee_beneficiary_verify_account_keys(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'_, '_>,
+    keys: AcceptProtocolFeeBeneficiaryKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pending_beneficiary.key, &keys.pending_beneficiary),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn accept_protocol_fee_beneficiary_verify_writable_privileges<'me, 'info>(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.pending_authorities] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn accept_protocol_fee_beneficiary_verify_signer_privileges<'me, 'info>(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.pending_beneficiary] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn accept_protocol_fee_beneficiary_verify_account_privileges<'me, 'info>(
+    accounts: AcceptProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    accept_protocol_fee_beneficiary_verify_writable_privileges(accounts)?;
+    accept_protocol_fee_beneficiary_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct CancelAuthorityHandoffAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CancelAuthorityHandoffKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The PendingAuthorities singleton PDA
+    pub pending_authorities: Pubkey,
+}
+impl From<CancelAuthorityHandoffAccounts<'_, '_>> for CancelAuthorityHandoffKeys {
+    fn from(accounts: CancelAuthorityHandoffAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            pending_authorities: *accounts.pending_authorities.key,
+        }
+    }
+}
+impl From<CancelAuthorityHandoffKeys> for [AccountMeta; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN] {
+    fn from(keys: CancelAuthorityHandoffKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pending_authorities,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN]> for CancelAuthorityHandoffKeys {
+    fn from(pubkeys: [Pubkey; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            pending_authorities: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<CancelAuthorityHandoffAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CancelAuthorityHandoffAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_authorities.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN]>
+    for CancelAuthorityHandoffAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            pending_authorities: &arr[2],
+        }
+    }
+}
+pub const CANCEL_AUTHORITY_HANDOFF_IX_DISCM: u8 = 27u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelAuthorityHandoffIxArgs {
+    pub cancel_admin: bool,
+    pub cancel_rebalance_authority: bool,
+    pub cancel_protocol_fee_beneficiary: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancelAuthorityHandoffIxData(pub CancelAuthorityHandoffIxArgs);
+impl From<CancelAuthorityHandoffIxArgs> for CancelAuthorityHandoffIxData {
+    fn from(args: CancelAuthorityHandoffIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CancelAuthorityHandoffIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CANCEL_AUTHORITY_HANDOFF_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CANCEL_AUTHORITY_HANDOFF_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(CancelAuthorityHandoffIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[CANCEL_AUTHORITY_HANDOFF_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn cancel_authority_handoff_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CancelAuthorityHandoffKeys,
+    args: CancelAuthorityHandoffIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CANCEL_AUTHORITY_HANDOFF_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CancelAuthorityHandoffIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn cancel_authority_handoff_ix(
+    keys: CancelAuthorityHandoffKeys,
+    args: CancelAuthorityHandoffIxArgs,
+) -> std::io::Result<Instruction> {
+    cancel_authority_handoff_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn cancel_authority_handoff_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelAuthorityHandoffAccounts<'_, '_>,
+    args: CancelAuthorityHandoffIxArgs,
+) -> ProgramResult {
+    let keys: CancelAuthorityHandoffKeys = accounts.into();
+    let ix = cancel_authority_handoff_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn cancel_authority_handoff_invoke(
+    accounts: CancelAuthorityHandoffAccounts<'_, '_>,
+    args: CancelAuthorityHandoffIxArgs,
+) -> ProgramResult {
+    cancel_authority_handoff_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn cancel_authority_handoff_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelAuthorityHandoffAccounts<'_, '_>,
+    args: CancelAuthorityHandoffIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CancelAuthorityHandoffKeys = accounts.into();
+    let ix = cancel_authority_handoff_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn cancel_authority_handoff_invoke_signed(
+    accounts: CancelAuthorityHandoffAccounts<'_, '_>,
+    args: CancelAuthorityHandoffIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    cancel_authority_handoff_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn cancel_authority_handoff_verify_account_keys(
+    accounts: CancelAuthorityHandoffAccounts<'_, '_>,
+    keys: CancelAuthorityHandoffKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_authorities.key, &keys.pending_authorities),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_authority_handoff_verify_writable_privileges<'me, 'info>(
+    accounts: CancelAuthorityHandoffAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pending_authorities] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_authority_handoff_verify_signer_privileges<'me, 'info>(
+    accounts: CancelAuthorityHandoffAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_authority_handoff_verify_account_privileges<'me, 'info>(
+    accounts: CancelAuthorityHandoffAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    cancel_authority_handoff_verify_writable_privileges(accounts)?;
+    cancel_authority_handoff_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetPricingProgramAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the queued change account's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///New pricing program to queue
+    pub new_pricing_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The queued pricing program change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetPricingProgramKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the queued change account's rent
+    pub payer: Pubkey,
+    ///New pricing program to queue
+    pub new_pricing_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The queued pricing program change PDA
+    pub queued_change: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<QueueSetPricingProgramAccounts<'_, '_>> for QueueSetPricingProgramKeys {
+    fn from(accounts: QueueSetPricingProgramAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            new_pricing_program: *accounts.new_pricing_program.key,
+            pool_state: *accounts.pool_state.key,
+            queued_change: *accounts.queued_change.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<QueueSetPricingProgramKeys> for [AccountMeta; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN] {
+    fn from(keys: QueueSetPricingProgramKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_pricing_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]> for QueueSetPricingProgramKeys {
+    fn from(pubkeys: [Pubkey; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            new_pricing_program: pubkeys[2],
+            pool_state: pubkeys[3],
+            queued_change: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<QueueSetPricingProgramAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QueueSetPricingProgramAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.new_pricing_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.queued_change.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]>
+    for QueueSetPricingProgramAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            new_pricing_program: &arr[2],
+            pool_state: &arr[3],
+            queued_change: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const QUEUE_SET_PRICING_PROGRAM_IX_DISCM: u8 = 28u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueSetPricingProgramIxData;
+impl QueueSetPricingProgramIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUEUE_SET_PRICING_PROGRAM_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUEUE_SET_PRICING_PROGRAM_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUEUE_SET_PRICING_PROGRAM_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn queue_set_pricing_program_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QueueSetPricingProgramKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUEUE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: QueueSetPricingProgramIxData.try_to_vec()?,
+    })
+}
+pub fn queue_set_pricing_program_ix(
+    keys: QueueSetPricingProgramKeys,
+) -> std::io::Result<Instruction> {
+    queue_set_pricing_program_ix_with_program_id(crate::ID, keys)
+}
+pub fn queue_set_pricing_program_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetPricingProgramAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: QueueSetPricingProgramKeys = accounts.into();
+    let ix = queue_set_pricing_program_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn queue_set_pricing_program_invoke(
+    accounts: QueueSetPricingProgramAccounts<'_, '_>,
+) -> ProgramResult {
+    queue_set_pricing_program_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn queue_set_pricing_program_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetPricingProgramAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QueueSetPricingProgramKeys = accounts.into();
+    let ix = queue_set_pricing_program_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn queue_set_pricing_program_invoke_signed(
+    accounts: QueueSetPricingProgramAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    queue_set_pricing_program_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn queue_set_pricing_program_verify_account_keys(
+    accounts: QueueSetPricingProgramAccounts<'_, '_>,
+    keys: QueueSetPricingProgramKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.new_pricing_program.key, &keys.new_pricing_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_pricing_program_verify_writable_privileges<'me, 'info>(
+    accounts: QueueSetPricingProgramAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.queued_change] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_pricing_program_verify_signer_privileges<'me, 'info>(
+    accounts: QueueSetPricingProgramAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_pricing_program_verify_account_privileges<'me, 'info>(
+    accounts: QueueSetPricingProgramAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    queue_set_pricing_program_verify_writable_privileges(accounts)?;
+    queue_set_pricing_program_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetPricingProgramAccounts<'me, 'info> {
+    ///New pricing program that was queued
+    pub new_pricing_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The queued pricing program change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetPricingProgramKeys {
+    ///New pricing program that was queued
+    pub new_pricing_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The queued pricing program change PDA
+    pub queued_change: Pubkey,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: Pubkey,
+}
+impl From<ExecuteSetPricingProgramAccounts<'_, '_>> for ExecuteSetPricingProgramKeys {
+    fn from(accounts: ExecuteSetPricingProgramAccounts) -> Self {
+        Self {
+            new_pricing_program: *accounts.new_pricing_program.key,
+            pool_state: *accounts.pool_state.key,
+            queued_change: *accounts.queued_change.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+        }
+    }
+}
+impl From<ExecuteSetPricingProgramKeys>
+    for [AccountMeta; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: ExecuteSetPricingProgramKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.new_pricing_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]> for ExecuteSetPricingProgramKeys {
+    fn from(pubkeys: [Pubkey; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            new_pricing_program: pubkeys[0],
+            pool_state: pubkeys[1],
+            queued_change: pubkeys[2],
+            refund_rent_to: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<ExecuteSetPricingProgramAccounts<'_, 'info>>
+    for [AccountInfo<'info>; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ExecuteSetPricingProgramAccounts<'_, 'info>) -> Self {
+        [
+            accounts.new_pricing_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.queued_change.clone(),
+            accounts.refund_rent_to.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]>
+    for ExecuteSetPricingProgramAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            new_pricing_program: &arr[0],
+            pool_state: &arr[1],
+            queued_change: &arr[2],
+            refund_rent_to: &arr[3],
+        }
+    }
+}
+pub const EXECUTE_SET_PRICING_PROGRAM_IX_DISCM: u8 = 29u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecuteSetPricingProgramIxData;
+impl ExecuteSetPricingProgramIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != EXECUTE_SET_PRICING_PROGRAM_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    EXECUTE_SET_PRICING_PROGRAM_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[EXECUTE_SET_PRICING_PROGRAM_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn execute_set_pricing_program_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ExecuteSetPricingProgramKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; EXECUTE_SET_PRICING_PROGRAM_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ExecuteSetPricingProgramIxData.try_to_vec()?,
+    })
+}
+pub fn execute_set_pricing_program_ix(
+    keys: ExecuteSetPricingProgramKeys,
+) -> std::io::Result<Instruction> {
+    execute_set_pricing_program_ix_with_program_id(crate::ID, keys)
+}
+pub fn execute_set_pricing_program_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetPricingProgramAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: ExecuteSetPricingProgramKeys = accounts.into();
+    let ix = execute_set_pricing_program_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn execute_set_pricing_program_invoke(
+    accounts: ExecuteSetPricingProgramAccounts<'_, '_>,
+) -> ProgramResult {
+    execute_set_pricing_program_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn execute_set_pricing_program_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetPricingProgramAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ExecuteSetPricingProgramKeys = accounts.into();
+    let ix = execute_set_pricing_program_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn execute_set_pricing_program_invoke_signed(
+    accounts: ExecuteSetPricingProgramAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    execute_set_pricing_program_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn execute_set_pricing_program_verify_account_keys(
+    accounts: ExecuteSetPricingProgramAccounts<'_, '_>,
+    keys: ExecuteSetPricingProgramKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.new_pricing_program.key, &keys.new_pricing_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_pricing_program_verify_writable_privileges<'me, 'info>(
+    accounts: ExecuteSetPricingProgramAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.queued_change,
+        accounts.refund_rent_to,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_pricing_program_verify_account_privileges<'me, 'info>(
+    accounts: ExecuteSetPricingProgramAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    execute_set_pricing_program_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetSolValueCalculatorAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the queued change account's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///Mint of the LST to queue a new SOL value calculator for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///New SOL value calculator program to queue
+    pub new_sol_value_calculator: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The LST's queued SOL value calculator change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetSolValueCalculatorKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the queued change account's rent
+    pub payer: Pubkey,
+    ///Mint of the LST to queue a new SOL value calculator for
+    pub lst_mint: Pubkey,
+    ///New SOL value calculator program to queue
+    pub new_sol_value_calculator: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///The LST's queued SOL value calculator change PDA
+    pub queued_change: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<QueueSetSolValueCalculatorAccounts<'_, '_>> for QueueSetSolValueCalculatorKeys {
+    fn from(accounts: QueueSetSolValueCalculatorAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            lst_mint: *accounts.lst_mint.key,
+            new_sol_value_calculator: *accounts.new_sol_value_calculator.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            queued_change: *accounts.queued_change.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<QueueSetSolValueCalculatorKeys>
+    for [AccountMeta; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: QueueSetSolValueCalculatorKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_sol_value_calculator,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]>
+    for QueueSetSolValueCalculatorKeys
+{
+    fn from(pubkeys: [Pubkey; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            lst_mint: pubkeys[2],
+            new_sol_value_calculator: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+            queued_change: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<QueueSetSolValueCalculatorAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QueueSetSolValueCalculatorAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.new_sol_value_calculator.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.queued_change.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]>
+    for QueueSetSolValueCalculatorAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            lst_mint: &arr[2],
+            new_sol_value_calculator: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+            queued_change: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM: u8 = 30u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueueSetSolValueCalculatorIxArgs {
+    pub lst_index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueSetSolValueCalculatorIxData(pub QueueSetSolValueCalculatorIxArgs);
+impl From<QueueSetSolValueCalculatorIxArgs> for QueueSetSolValueCalculatorIxData {
+    fn from(args: QueueSetSolValueCalculatorIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QueueSetSolValueCalculatorIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QueueSetSolValueCalculatorIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUEUE_SET_SOL_VALUE_CALCULATOR_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn queue_set_sol_value_calculator_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QueueSetSolValueCalculatorKeys,
+    args: QueueSetSolValueCalculatorIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUEUE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QueueSetSolValueCalculatorIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn queue_set_sol_value_calculator_ix(
+    keys: QueueSetSolValueCalculatorKeys,
+    args: QueueSetSolValueCalculatorIxArgs,
+) -> std::io::Result<Instruction> {
+    queue_set_sol_value_calculator_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn queue_set_sol_value_calculator_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetSolValueCalculatorAccounts<'_, '_>,
+    args: QueueSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    let keys: QueueSetSolValueCalculatorKeys = accounts.into();
+    let ix = queue_set_sol_value_calculator_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn queue_set_sol_value_calculator_invoke(
+    accounts: QueueSetSolValueCalculatorAccounts<'_, '_>,
+    args: QueueSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    queue_set_sol_value_calculator_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn queue_set_sol_value_calculator_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetSolValueCalculatorAccounts<'_, '_>,
+    args: QueueSetSolValueCalculatorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QueueSetSolValueCalculatorKeys = accounts.into();
+    let ix = queue_set_sol_value_calculator_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn queue_set_sol_value_calculator_invoke_signed(
+    accounts: QueueSetSolValueCalculatorAccounts<'_, '_>,
+    args: QueueSetSolValueCalculatorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    queue_set_sol_value_calculator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn queue_set_sol_value_calculator_verify_account_keys(
+    accounts: QueueSetSolValueCalculatorAccounts<'_, '_>,
+    keys: QueueSetSolValueCalculatorKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (
+            accounts.new_sol_value_calculator.key,
+            &keys.new_sol_value_calculator,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_sol_value_calculator_verify_writable_privileges<'me, 'info>(
+    accounts: QueueSetSolValueCalculatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.queued_change] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_sol_value_calculator_verify_signer_privileges<'me, 'info>(
+    accounts: QueueSetSolValueCalculatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_sol_value_calculator_verify_account_privileges<'me, 'info>(
+    accounts: QueueSetSolValueCalculatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    queue_set_sol_value_calculator_verify_writable_privileges(accounts)?;
+    queue_set_sol_value_calculator_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetSolValueCalculatorAccounts<'me, 'info> {
+    ///Mint of the LST to set the queued SOL value calculator for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The LST's queued SOL value calculator change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetSolValueCalculatorKeys {
+    ///Mint of the LST to set the queued SOL value calculator for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///The LST's queued SOL value calculator change PDA
+    pub queued_change: Pubkey,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: Pubkey,
+}
+impl From<ExecuteSetSolValueCalculatorAccounts<'_, '_>> for ExecuteSetSolValueCalculatorKeys {
+    fn from(accounts: ExecuteSetSolValueCalculatorAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            queued_change: *accounts.queued_change.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+        }
+    }
+}
+impl From<ExecuteSetSolValueCalculatorKeys>
+    for [AccountMeta; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: ExecuteSetSolValueCalculatorKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]>
+    for ExecuteSetSolValueCalculatorKeys
+{
+    fn from(pubkeys: [Pubkey; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            pool_state: pubkeys[1],
+            pool_reserves: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            queued_change: pubkeys[4],
+            refund_rent_to: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<ExecuteSetSolValueCalculatorAccounts<'_, 'info>>
+    for [AccountInfo<'info>; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ExecuteSetSolValueCalculatorAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.queued_change.clone(),
+            accounts.refund_rent_to.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN]>
+    for ExecuteSetSolValueCalculatorAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            pool_state: &arr[1],
+            pool_reserves: &arr[2],
+            lst_state_list: &arr[3],
+            queued_change: &arr[4],
+            refund_rent_to: &arr[5],
+        }
+    }
+}
+pub const EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM: u8 = 31u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecuteSetSolValueCalculatorIxArgs {
+    pub lst_index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecuteSetSolValueCalculatorIxData(pub ExecuteSetSolValueCalculatorIxArgs);
+impl From<ExecuteSetSolValueCalculatorIxArgs> for ExecuteSetSolValueCalculatorIxData {
+    fn from(args: ExecuteSetSolValueCalculatorIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ExecuteSetSolValueCalculatorIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ExecuteSetSolValueCalculatorIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn execute_set_sol_value_calculator_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ExecuteSetSolValueCalculatorKeys,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ExecuteSetSolValueCalculatorIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn execute_set_sol_value_calculator_ix(
+    keys: ExecuteSetSolValueCalculatorKeys,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+) -> std::io::Result<Instruction> {
+    execute_set_sol_value_calculator_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn execute_set_sol_value_calculator_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetSolValueCalculatorAccounts<'_, '_>,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    let keys: ExecuteSetSolValueCalculatorKeys = accounts.into();
+    let ix = execute_set_sol_value_calculator_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn execute_set_sol_value_calculator_invoke(
+    accounts: ExecuteSetSolValueCalculatorAccounts<'_, '_>,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    execute_set_sol_value_calculator_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn execute_set_sol_value_calculator_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetSolValueCalculatorAccounts<'_, '_>,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ExecuteSetSolValueCalculatorKeys = accounts.into();
+    let ix = execute_set_sol_value_calculator_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn execute_set_sol_value_calculator_invoke_signed(
+    accounts: ExecuteSetSolValueCalculatorAccounts<'_, '_>,
+    args: ExecuteSetSolValueCalculatorIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    execute_set_sol_value_calculator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn execute_set_sol_value_calculator_verify_account_keys(
+    accounts: ExecuteSetSolValueCalculatorAccounts<'_, '_>,
+    keys: ExecuteSetSolValueCalculatorKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_sol_value_calculator_verify_writable_privileges<'me, 'info>(
+    accounts: ExecuteSetSolValueCalculatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.queued_change,
+        accounts.refund_rent_to,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_sol_value_calculator_verify_account_privileges<'me, 'info>(
+    accounts: ExecuteSetSolValueCalculatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    execute_set_sol_value_calculator_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetTimelockDelayAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the queued change account's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The queued timelock delay change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QueueSetTimelockDelayKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the queued change account's rent
+    pub payer: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The queued timelock delay change PDA
+    pub queued_change: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<QueueSetTimelockDelayAccounts<'_, '_>> for QueueSetTimelockDelayKeys {
+    fn from(accounts: QueueSetTimelockDelayAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            pool_state: *accounts.pool_state.key,
+            queued_change: *accounts.queued_change.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<QueueSetTimelockDelayKeys> for [AccountMeta; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN] {
+    fn from(keys: QueueSetTimelockDelayKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]> for QueueSetTimelockDelayKeys {
+    fn from(pubkeys: [Pubkey; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            pool_state: pubkeys[2],
+            queued_change: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<QueueSetTimelockDelayAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QueueSetTimelockDelayAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.pool_state.clone(),
+            accounts.queued_change.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]>
+    for QueueSetTimelockDelayAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            pool_state: &arr[2],
+            queued_change: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const QUEUE_SET_TIMELOCK_DELAY_IX_DISCM: u8 = 32u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueueSetTimelockDelayIxArgs {
+    pub timelock_delay: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueSetTimelockDelayIxData(pub QueueSetTimelockDelayIxArgs);
+impl From<QueueSetTimelockDelayIxArgs> for QueueSetTimelockDelayIxData {
+    fn from(args: QueueSetTimelockDelayIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QueueSetTimelockDelayIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUEUE_SET_TIMELOCK_DELAY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUEUE_SET_TIMELOCK_DELAY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QueueSetTimelockDelayIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUEUE_SET_TIMELOCK_DELAY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn queue_set_timelock_delay_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QueueSetTimelockDelayKeys,
+    args: QueueSetTimelockDelayIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUEUE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QueueSetTimelockDelayIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn queue_set_timelock_delay_ix(
+    keys: QueueSetTimelockDelayKeys,
+    args: QueueSetTimelockDelayIxArgs,
+) -> std::io::Result<Instruction> {
+    queue_set_timelock_delay_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn queue_set_timelock_delay_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetTimelockDelayAccounts<'_, '_>,
+    args: QueueSetTimelockDelayIxArgs,
+) -> ProgramResult {
+    let keys: QueueSetTimelockDelayKeys = accounts.into();
+    let ix = queue_set_timelock_delay_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn queue_set_timelock_delay_invoke(
+    accounts: QueueSetTimelockDelayAccounts<'_, '_>,
+    args: QueueSetTimelockDelayIxArgs,
+) -> ProgramResult {
+    queue_set_timelock_delay_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn queue_set_timelock_delay_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QueueSetTimelockDelayAccounts<'_, '_>,
+    args: QueueSetTimelockDelayIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QueueSetTimelockDelayKeys = accounts.into();
+    let ix = queue_set_timelock_delay_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn queue_set_timelock_delay_invoke_signed(
+    accounts: QueueSetTimelockDelayAccounts<'_, '_>,
+    args: QueueSetTimelockDelayIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    queue_set_timelock_delay_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn queue_set_timelock_delay_verify_account_keys(
+    accounts: QueueSetTimelockDelayAccounts<'_, '_>,
+    keys: QueueSetTimelockDelayKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_timelock_delay_verify_writable_privileges<'me, 'info>(
+    accounts: QueueSetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.queued_change] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_timelock_delay_verify_signer_privileges<'me, 'info>(
+    accounts: QueueSetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn queue_set_timelock_delay_verify_account_privileges<'me, 'info>(
+    accounts: QueueSetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    queue_set_timelock_delay_verify_writable_privileges(accounts)?;
+    queue_set_timelock_delay_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetTimelockDelayAccounts<'me, 'info> {
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The queued timelock delay change PDA
+    pub queued_change: &'me AccountInfo<'info>,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ExecuteSetTimelockDelayKeys {
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The queued timelock delay change PDA
+    pub queued_change: Pubkey,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: Pubkey,
+}
+impl From<ExecuteSetTimelockDelayAccounts<'_, '_>> for ExecuteSetTimelockDelayKeys {
+    fn from(accounts: ExecuteSetTimelockDelayAccounts) -> Self {
+        Self {
+            pool_state: *accounts.pool_state.key,
+            queued_change: *accounts.queued_change.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+        }
+    }
+}
+impl From<ExecuteSetTimelockDelayKeys>
+    for [AccountMeta; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: ExecuteSetTimelockDelayKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]> for ExecuteSetTimelockDelayKeys {
+    fn from(pubkeys: [Pubkey; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: pubkeys[0],
+            queued_change: pubkeys[1],
+            refund_rent_to: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<ExecuteSetTimelockDelayAccounts<'_, 'info>>
+    for [AccountInfo<'info>; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ExecuteSetTimelockDelayAccounts<'_, 'info>) -> Self {
+        [
+            accounts.pool_state.clone(),
+            accounts.queued_change.clone(),
+            accounts.refund_rent_to.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]>
+    for ExecuteSetTimelockDelayAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: &arr[0],
+            queued_change: &arr[1],
+            refund_rent_to: &arr[2],
+        }
+    }
+}
+pub const EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM: u8 = 33u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecuteSetTimelockDelayIxData;
+impl ExecuteSetTimelockDelayIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[EXECUTE_SET_TIMELOCK_DELAY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn execute_set_timelock_delay_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ExecuteSetTimelockDelayKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; EXECUTE_SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ExecuteSetTimelockDelayIxData.try_to_vec()?,
+    })
+}
+pub fn execute_set_timelock_delay_ix(
+    keys: ExecuteSetTimelockDelayKeys,
+) -> std::io::Result<Instruction> {
+    execute_set_timelock_delay_ix_with_program_id(crate::ID, keys)
+}
+pub fn execute_set_timelock_delay_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetTimelockDelayAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: ExecuteSetTimelockDelayKeys = accounts.into();
+    let ix = execute_set_timelock_delay_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn execute_set_timelock_delay_invoke(
+    accounts: ExecuteSetTimelockDelayAccounts<'_, '_>,
+) -> ProgramResult {
+    execute_set_timelock_delay_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn execute_set_timelock_delay_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecuteSetTimelockDelayAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ExecuteSetTimelockDelayKeys = accounts.into();
+    let ix = execute_set_timelock_delay_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn execute_set_timelock_delay_invoke_signed(
+    accounts: ExecuteSetTimelockDelayAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    execute_set_timelock_delay_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn execute_set_timelock_delay_verify_account_keys(
+    accounts: ExecuteSetTimelockDelayAccounts<'_, '_>,
+    keys: ExecuteSetTimelockDelayKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_timelock_delay_verify_writable_privileges<'me, 'info>(
+    accounts: ExecuteSetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.queued_change,
+        accounts.refund_rent_to,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn execute_set_timelock_delay_verify_account_privileges<'me, 'info>(
+    accounts: ExecuteSetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    execute_set_timelock_delay_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct CancelQueuedChangeAccounts<'me, 'info> {
+    ///Either the pool's admin or a disable pool authority
+    pub signer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's disable pool authority list singleton PDA
+    pub disable_pool_authority_list: &'me AccountInfo<'info>,
+    ///The queued change PDA to cancel
+    pub queued_change: &'me AccountInfo<'info>,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CancelQueuedChangeKeys {
+    ///Either the pool's admin or a disable pool authority
+    pub signer: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's disable pool authority list singleton PDA
+    pub disable_pool_authority_list: Pubkey,
+    ///The queued change PDA to cancel
+    pub queued_change: Pubkey,
+    ///The payer of the queued change to refund rent to
+    pub refund_rent_to: Pubkey,
+}
+impl From<CancelQueuedChangeAccounts<'_, '_>> for CancelQueuedChangeKeys {
+    fn from(accounts: CancelQueuedChangeAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            pool_state: *accounts.pool_state.key,
+            disable_pool_authority_list: *accounts.disable_pool_authority_list.key,
+            queued_change: *accounts.queued_change.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+        }
+    }
+}
+impl From<CancelQueuedChangeKeys> for [AccountMeta; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: CancelQueuedChangeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.disable_pool_authority_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.queued_change,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN]> for CancelQueuedChangeKeys {
+    fn from(pubkeys: [Pubkey; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            pool_state: pubkeys[1],
+            disable_pool_authority_list: pubkeys[2],
+            queued_change: pubkeys[3],
+            refund_rent_to: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<CancelQueuedChangeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CancelQueuedChangeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.pool_state.clone(),
+            accounts.disable_pool_authority_list.clone(),
+            accounts.queued_change.clone(),
+            accounts.refund_rent_to.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN]>
+    for CancelQueuedChangeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            pool_state: &arr[1],
+            disable_pool_authority_list: &arr[2],
+            queued_change: &arr[3],
+            refund_rent_to: &arr[4],
+        }
+    }
+}
+pub const CANCEL_QUEUED_CHANGE_IX_DISCM: u8 = 34u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancelQueuedChangeIxData;
+impl CancelQueuedChangeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CANCEL_QUEUED_CHANGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CANCEL_QUEUED_CHANGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[CANCEL_QUEUED_CHANGE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn cancel_queued_change_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CancelQueuedChangeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CANCEL_QUEUED_CHANGE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: CancelQueuedChangeIxData.try_to_vec()?,
+    })
+}
+pub fn cancel_queued_change_ix(keys: CancelQueuedChangeKeys) -> std::io::Result<Instruction> {
+    cancel_queued_change_ix_with_program_id(crate::ID, keys)
+}
+pub fn cancel_queued_change_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelQueuedChangeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: CancelQueuedChangeKeys = accounts.into();
+    let ix = cancel_queued_change_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn cancel_queued_change_invoke(accounts: CancelQueuedChangeAccounts<'_, '_>) -> ProgramResult {
+    cancel_queued_change_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn cancel_queued_change_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelQueuedChangeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CancelQueuedChangeKeys = accounts.into();
+    let ix = cancel_queued_change_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn cancel_queued_change_invoke_signed(
+    accounts: CancelQueuedChangeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    cancel_queued_change_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn cancel_queued_change_verify_account_keys(
+    accounts: CancelQueuedChangeAccounts<'_, '_>,
+    keys: CancelQueuedChangeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.disable_pool_authority_list.key,
+            &keys.disable_pool_authority_list,
+        ),
+        (accounts.queued_change.key, &keys.queued_change),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_queued_change_verify_writable_privileges<'me, 'info>(
+    accounts: CancelQueuedChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.queued_change, accounts.refund_rent_to] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_queued_change_verify_signer_privileges<'me, 'info>(
+    accounts: CancelQueuedChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_queued_change_verify_account_privileges<'me, 'info>(
+    accounts: CancelQueuedChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    cancel_queued_change_verify_writable_privileges(accounts)?;
+    cancel_queued_change_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SyncSolValueMultiAccounts<'me, 'info> {
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SyncSolValueMultiKeys {
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SyncSolValueMultiAccounts<'_, '_>> for SyncSolValueMultiKeys {
+    fn from(accounts: SyncSolValueMultiAccounts) -> Self {
+        Self {
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SyncSolValueMultiKeys> for [AccountMeta; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN] {
+    fn from(keys: SyncSolValueMultiKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN]> for SyncSolValueMultiKeys {
+    fn from(pubkeys: [Pubkey; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: pubkeys[0],
+            lst_state_list: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SyncSolValueMultiAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SyncSolValueMultiAccounts<'_, 'info>) -> Self {
+        [accounts.pool_state.clone(), accounts.lst_state_list.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN]>
+    for SyncSolValueMultiAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool_state: &arr[0],
+            lst_state_list: &arr[1],
+        }
+    }
+}
+pub const SYNC_SOL_VALUE_MULTI_IX_DISCM: u8 = 35u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncSolValueMultiIxArgs {
+    pub entries: Vec<SyncSolValueMultiEntry>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncSolValueMultiIxData(pub SyncSolValueMultiIxArgs);
+impl From<SyncSolValueMultiIxArgs> for SyncSolValueMultiIxData {
+    fn from(args: SyncSolValueMultiIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SyncSolValueMultiIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SYNC_SOL_VALUE_MULTI_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SYNC_SOL_VALUE_MULTI_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SyncSolValueMultiIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SYNC_SOL_VALUE_MULTI_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn sync_sol_value_multi_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SyncSolValueMultiKeys,
+    args: SyncSolValueMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SyncSolValueMultiIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn sync_sol_value_multi_ix(
+    keys: SyncSolValueMultiKeys,
+    args: SyncSolValueMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    sync_sol_value_multi_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn sync_sol_value_multi_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncSolValueMultiAccounts<'_, '_>,
+    args: SyncSolValueMultiIxArgs,
+) -> ProgramResult {
+    let keys: SyncSolValueMultiKeys = accounts.into();
+    let ix = sync_sol_value_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn sync_sol_value_multi_invoke(
+    accounts: SyncSolValueMultiAccounts<'_, '_>,
+    args: SyncSolValueMultiIxArgs,
+) -> ProgramResult {
+    sync_sol_value_multi_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn sync_sol_value_multi_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncSolValueMultiAccounts<'_, '_>,
+    args: SyncSolValueMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SyncSolValueMultiKeys = accounts.into();
+    let ix = sync_sol_value_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn sync_sol_value_multi_invoke_signed(
+    accounts: SyncSolValueMultiAccounts<'_, '_>,
+    args: SyncSolValueMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    sync_sol_value_multi_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn sync_sol_value_multi_verify_account_keys(
+    accounts: SyncSolValueMultiAccounts<'_, '_>,
+    keys: SyncSolValueMultiKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn sync_sol_value_multi_verify_writable_privileges<'me, 'info>(
+    accounts: SyncSolValueMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn sync_sol_value_multi_verify_account_privileges<'me, 'info>(
+    accounts: SyncSolValueMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    sync_sol_value_multi_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct DisableLstOutputAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to disable output for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DisableLstOutputKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to disable output for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<DisableLstOutputAccounts<'_, '_>> for DisableLstOutputKeys {
+    fn from(accounts: DisableLstOutputAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<DisableLstOutputKeys> for [AccountMeta; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN] {
+    fn from(keys: DisableLstOutputKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]> for DisableLstOutputKeys {
+    fn from(pubkeys: [Pubkey; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<DisableLstOutputAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DisableLstOutputAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]>
+    for DisableLstOutputAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const DISABLE_LST_OUTPUT_IX_DISCM: u8 = 36u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisableLstOutputIxArgs {
+    pub index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisableLstOutputIxData(pub DisableLstOutputIxArgs);
+impl From<DisableLstOutputIxArgs> for DisableLstOutputIxData {
+    fn from(args: DisableLstOutputIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DisableLstOutputIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DISABLE_LST_OUTPUT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DISABLE_LST_OUTPUT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DisableLstOutputIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[DISABLE_LST_OUTPUT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn disable_lst_output_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DisableLstOutputKeys,
+    args: DisableLstOutputIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DISABLE_LST_OUTPUT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DisableLstOutputIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn disable_lst_output_ix(
+    keys: DisableLstOutputKeys,
+    args: DisableLstOutputIxArgs,
+) -> std::io::Result<Instruction> {
+    disable_lst_output_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn disable_lst_output_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableLstOutputAccounts<'_, '_>,
+    args: DisableLstOutputIxArgs,
+) -> ProgramResult {
+    let keys: DisableLstOutputKeys = accounts.into();
+    let ix = disable_lst_output_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn disable_lst_output_invoke(
+    accounts: DisableLstOutputAccounts<'_, '_>,
+    args: DisableLstOutputIxArgs,
+) -> ProgramResult {
+    disable_lst_output_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn disable_lst_output_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableLstOutputAccounts<'_, '_>,
+    args: DisableLstOutputIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DisableLstOutputKeys = accounts.into();
+    let ix = disable_lst_output_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn disable_lst_output_invoke_signed(
+    accounts: DisableLstOutputAccounts<'_, '_>,
+    args: DisableLstOutputIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    disable_lst_output_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn disable_lst_output_verify_account_keys(
+    accounts: DisableLstOutputAccounts<'_, '_>,
+    keys: DisableLstOutputKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn disable_lst_output_verify_writable_privileges<'me, 'info>(
+    accounts: DisableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn disable_lst_output_verify_signer_privileges<'me, 'info>(
+    accounts: DisableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn disable_lst_output_verify_account_privileges<'me, 'info>(
+    accounts: DisableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    disable_lst_output_verify_writable_privileges(accounts)?;
+    disable_lst_output_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct EnableLstOutputAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to re-enable output for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct EnableLstOutputKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to re-enable output for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<EnableLstOutputAccounts<'_, '_>> for EnableLstOutputKeys {
+    fn from(accounts: EnableLstOutputAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<EnableLstOutputKeys> for [AccountMeta; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN] {
+    fn from(keys: EnableLstOutputKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]> for EnableLstOutputKeys {
+    fn from(pubkeys: [Pubkey; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<EnableLstOutputAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: EnableLstOutputAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]>
+    for EnableLstOutputAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const ENABLE_LST_OUTPUT_IX_DISCM: u8 = 37u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnableLstOutputIxArgs {
+    pub index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnableLstOutputIxData(pub EnableLstOutputIxArgs);
+impl From<EnableLstOutputIxArgs> for EnableLstOutputIxData {
+    fn from(args: EnableLstOutputIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl EnableLstOutputIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ENABLE_LST_OUTPUT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ENABLE_LST_OUTPUT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(EnableLstOutputIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ENABLE_LST_OUTPUT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn enable_lst_output_ix_with_program_id(
+    program_id: Pubkey,
+    keys: EnableLstOutputKeys,
+    args: EnableLstOutputIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ENABLE_LST_OUTPUT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: EnableLstOutputIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn enable_lst_output_ix(
+    keys: EnableLstOutputKeys,
+    args: EnableLstOutputIxArgs,
+) -> std::io::Result<Instruction> {
+    enable_lst_output_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn enable_lst_output_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableLstOutputAccounts<'_, '_>,
+    args: EnableLstOutputIxArgs,
+) -> ProgramResult {
+    let keys: EnableLstOutputKeys = accounts.into();
+    let ix = enable_lst_output_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn enable_lst_output_invoke(
+    accounts: EnableLstOutputAccounts<'_, '_>,
+    args: EnableLstOutputIxArgs,
+) -> ProgramResult {
+    enable_lst_output_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn enable_lst_output_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableLstOutputAccounts<'_, '_>,
+    args: EnableLstOutputIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: EnableLstOutputKeys = accounts.into();
+    let ix = enable_lst_output_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn enable_lst_output_invoke_signed(
+    accounts: EnableLstOutputAccounts<'_, '_>,
+    args: EnableLstOutputIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    enable_lst_output_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn enable_lst_output_verify_account_keys(
+    accounts: EnableLstOutputAccounts<'_, '_>,
+    keys: EnableLstOutputKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn enable_lst_output_verify_writable_privileges<'me, 'info>(
+    accounts: EnableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state, accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn enable_lst_output_verify_signer_privileges<'me, 'info>(
+    accounts: EnableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn enable_lst_output_verify_account_privileges<'me, 'info>(
+    accounts: EnableLstOutputAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    enable_lst_output_verify_writable_privileges(accounts)?;
+    enable_lst_output_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteSwapExactInAccounts<'me, 'info> {
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteSwapExactInKeys {
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<QuoteSwapExactInAccounts<'_, '_>> for QuoteSwapExactInKeys {
+    fn from(accounts: QuoteSwapExactInAccounts) -> Self {
+        Self {
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<QuoteSwapExactInKeys> for [AccountMeta; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN] {
+    fn from(keys: QuoteSwapExactInKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN]> for QuoteSwapExactInKeys {
+    fn from(pubkeys: [Pubkey; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            src_lst_mint: pubkeys[0],
+            dst_lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            src_pool_reserves: pubkeys[4],
+            dst_pool_reserves: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<QuoteSwapExactInAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QuoteSwapExactInAccounts<'_, 'info>) -> Self {
+        [
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN]>
+    for QuoteSwapExactInAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            src_lst_mint: &arr[0],
+            dst_lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            src_pool_reserves: &arr[4],
+            dst_pool_reserves: &arr[5],
+        }
+    }
+}
+pub const QUOTE_SWAP_EXACT_IN_IX_DISCM: u8 = 38u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuoteSwapExactInIxArgs {
+    pub src_lst_value_calc_accs: u8,
+    pub dst_lst_value_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteSwapExactInIxData(pub QuoteSwapExactInIxArgs);
+impl From<QuoteSwapExactInIxArgs> for QuoteSwapExactInIxData {
+    fn from(args: QuoteSwapExactInIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QuoteSwapExactInIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUOTE_SWAP_EXACT_IN_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUOTE_SWAP_EXACT_IN_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QuoteSwapExactInIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUOTE_SWAP_EXACT_IN_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn quote_swap_exact_in_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QuoteSwapExactInKeys,
+    args: QuoteSwapExactInIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QuoteSwapExactInIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn quote_swap_exact_in_ix(
+    keys: QuoteSwapExactInKeys,
+    args: QuoteSwapExactInIxArgs,
+) -> std::io::Result<Instruction> {
+    quote_swap_exact_in_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn quote_swap_exact_in_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactInAccounts<'_, '_>,
+    args: QuoteSwapExactInIxArgs,
+) -> ProgramResult {
+    let keys: QuoteSwapExactInKeys = accounts.into();
+    let ix = quote_swap_exact_in_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn quote_swap_exact_in_invoke(
+    accounts: QuoteSwapExactInAccounts<'_, '_>,
+    args: QuoteSwapExactInIxArgs,
+) -> ProgramResult {
+    quote_swap_exact_in_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn quote_swap_exact_in_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactInAccounts<'_, '_>,
+    args: QuoteSwapExactInIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QuoteSwapExactInKeys = accounts.into();
+    let ix = quote_swap_exact_in_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn quote_swap_exact_in_invoke_signed(
+    accounts: QuoteSwapExactInAccounts<'_, '_>,
+    args: QuoteSwapExactInIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    quote_swap_exact_in_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn quote_swap_exact_in_verify_account_keys(
+    accounts: QuoteSwapExactInAccounts<'_, '_>,
+    keys: QuoteSwapExactInKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteSwapExactOutAccounts<'me, 'info> {
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteSwapExactOutKeys {
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<QuoteSwapExactOutAccounts<'_, '_>> for QuoteSwapExactOutKeys {
+    fn from(accounts: QuoteSwapExactOutAccounts) -> Self {
+        Self {
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<QuoteSwapExactOutKeys> for [AccountMeta; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN] {
+    fn from(keys: QuoteSwapExactOutKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]> for QuoteSwapExactOutKeys {
+    fn from(pubkeys: [Pubkey; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            src_lst_mint: pubkeys[0],
+            dst_lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            src_pool_reserves: pubkeys[4],
+            dst_pool_reserves: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<QuoteSwapExactOutAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QuoteSwapExactOutAccounts<'_, 'info>) -> Self {
+        [
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]>
+    for QuoteSwapExactOutAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            src_lst_mint: &arr[0],
+            dst_lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            src_pool_reserves: &arr[4],
+            dst_pool_reserves: &arr[5],
+        }
+    }
+}
+pub const QUOTE_SWAP_EXACT_OUT_IX_DISCM: u8 = 39u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuoteSwapExactOutIxArgs {
+    pub src_lst_value_calc_accs: u8,
+    pub dst_lst_value_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteSwapExactOutIxData(pub QuoteSwapExactOutIxArgs);
+impl From<QuoteSwapExactOutIxArgs> for QuoteSwapExactOutIxData {
+    fn from(args: QuoteSwapExactOutIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QuoteSwapExactOutIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUOTE_SWAP_EXACT_OUT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUOTE_SWAP_EXACT_OUT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QuoteSwapExactOutIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUOTE_SWAP_EXACT_OUT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn quote_swap_exact_out_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QuoteSwapExactOutKeys,
+    args: QuoteSwapExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUOTE_SWAP_EXACT_OUT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QuoteSwapExactOutIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn quote_swap_exact_out_ix(
+    keys: QuoteSwapExactOutKeys,
+    args: QuoteSwapExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    quote_swap_exact_out_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn quote_swap_exact_out_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactOutAccounts<'_, '_>,
+    args: QuoteSwapExactOutIxArgs,
+) -> ProgramResult {
+    let keys: QuoteSwapExactOutKeys = accounts.into();
+    let ix = quote_swap_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn quote_swap_exact_out_invoke(
+    accounts: QuoteSwapExactOutAccounts<'_, '_>,
+    args: QuoteSwapExactOutIxArgs,
+) -> ProgramResult {
+    quote_swap_exact_out_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn quote_swap_exact_out_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactOutAccounts<'_, '_>,
+    args: QuoteSwapExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QuoteSwapExactOutKeys = accounts.into();
+    let ix = quote_swap_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn quote_swap_exact_out_invoke_signed(
+    accounts: QuoteSwapExactOutAccounts<'_, '_>,
+    args: QuoteSwapExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    quote_swap_exact_out_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn quote_swap_exact_out_verify_account_keys(
+    accounts: QuoteSwapExactOutAccounts<'_, '_>,
+    keys: QuoteSwapExactOutKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteAddLiquidityAccounts<'me, 'info> {
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteAddLiquidityKeys {
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<QuoteAddLiquidityAccounts<'_, '_>> for QuoteAddLiquidityKeys {
+    fn from(accounts: QuoteAddLiquidityAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<QuoteAddLiquidityKeys> for [AccountMeta; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: QuoteAddLiquidityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for QuoteAddLiquidityKeys {
+    fn from(pubkeys: [Pubkey; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            lp_token_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            pool_reserves: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<QuoteAddLiquidityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QuoteAddLiquidityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
+    for QuoteAddLiquidityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            lp_token_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            pool_reserves: &arr[4],
+        }
+    }
+}
+pub const QUOTE_ADD_LIQUIDITY_IX_DISCM: u8 = 40u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuoteAddLiquidityIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lst_amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteAddLiquidityIxData(pub QuoteAddLiquidityIxArgs);
+impl From<QuoteAddLiquidityIxArgs> for QuoteAddLiquidityIxData {
+    fn from(args: QuoteAddLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QuoteAddLiquidityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUOTE_ADD_LIQUIDITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUOTE_ADD_LIQUIDITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QuoteAddLiquidityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUOTE_ADD_LIQUIDITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn quote_add_liquidity_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QuoteAddLiquidityKeys,
+    args: QuoteAddLiquidityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QuoteAddLiquidityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn quote_add_liquidity_ix(
+    keys: QuoteAddLiquidityKeys,
+    args: QuoteAddLiquidityIxArgs,
+) -> std::io::Result<Instruction> {
+    quote_add_liquidity_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn quote_add_liquidity_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteAddLiquidityAccounts<'_, '_>,
+    args: QuoteAddLiquidityIxArgs,
+) -> ProgramResult {
+    let keys: QuoteAddLiquidityKeys = accounts.into();
+    let ix = quote_add_liquidity_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn quote_add_liquidity_invoke(
+    accounts: QuoteAddLiquidityAccounts<'_, '_>,
+    args: QuoteAddLiquidityIxArgs,
+) -> ProgramResult {
+    quote_add_liquidity_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn quote_add_liquidity_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteAddLiquidityAccounts<'_, '_>,
+    args: QuoteAddLiquidityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QuoteAddLiquidityKeys = accounts.into();
+    let ix = quote_add_liquidity_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn quote_add_liquidity_invoke_signed(
+    accounts: QuoteAddLiquidityAccounts<'_, '_>,
+    args: QuoteAddLiquidityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    quote_add_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn quote_add_liquidity_verify_account_keys(
+    accounts: QuoteAddLiquidityAccounts<'_, '_>,
+    keys: QuoteAddLiquidityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteRemoveLiquidityAccounts<'me, 'info> {
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QuoteRemoveLiquidityKeys {
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<QuoteRemoveLiquidityAccounts<'_, '_>> for QuoteRemoveLiquidityKeys {
+    fn from(accounts: QuoteRemoveLiquidityAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<QuoteRemoveLiquidityKeys> for [AccountMeta; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: QuoteRemoveLiquidityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for QuoteRemoveLiquidityKeys {
+    fn from(pubkeys: [Pubkey; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            lp_token_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+            pool_reserves: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<QuoteRemoveLiquidityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QuoteRemoveLiquidityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
+    for QuoteRemoveLiquidityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            lp_token_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+            pool_reserves: &arr[4],
+        }
+    }
+}
+pub const QUOTE_REMOVE_LIQUIDITY_IX_DISCM: u8 = 41u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuoteRemoveLiquidityIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lp_token_amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteRemoveLiquidityIxData(pub QuoteRemoveLiquidityIxArgs);
+impl From<QuoteRemoveLiquidityIxArgs> for QuoteRemoveLiquidityIxData {
+    fn from(args: QuoteRemoveLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QuoteRemoveLiquidityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUOTE_REMOVE_LIQUIDITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUOTE_REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QuoteRemoveLiquidityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUOTE_REMOVE_LIQUIDITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn quote_remove_liquidity_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QuoteRemoveLiquidityKeys,
+    args: QuoteRemoveLiquidityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QuoteRemoveLiquidityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn quote_remove_liquidity_ix(
+    keys: QuoteRemoveLiquidityKeys,
+    args: QuoteRemoveLiquidityIxArgs,
+) -> std::io::Result<Instruction> {
+    quote_remove_liquidity_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn quote_remove_liquidity_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteRemoveLiquidityAccounts<'_, '_>,
+    args: QuoteRemoveLiquidityIxArgs,
+) -> ProgramResult {
+    let keys: QuoteRemoveLiquidityKeys = accounts.into();
+    let ix = quote_remove_liquidity_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn quote_remove_liquidity_invoke(
+    accounts: QuoteRemoveLiquidityAccounts<'_, '_>,
+    args: QuoteRemoveLiquidityIxArgs,
+) -> ProgramResult {
+    quote_remove_liquidity_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn quote_remove_liquidity_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QuoteRemoveLiquidityAccounts<'_, '_>,
+    args: QuoteRemoveLiquidityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QuoteRemoveLiquidityKeys = accounts.into();
+    let ix = quote_remove_liquidity_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn quote_remove_liquidity_invoke_signed(
+    accounts: QuoteRemoveLiquidityAccounts<'_, '_>,
+    args: QuoteRemoveLiquidityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    quote_remove_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn quote_remove_liquidity_verify_account_keys(
+    accounts: QuoteRemoveLiquidityAccounts<'_, '_>,
+    keys: QuoteRemoveLiquidityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub const FLASH_BORROW_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone, Debug)]
+pub struct FlashBorrowAccounts<'me, 'info> {
+    ///Mint of the LST to borrow
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to transfer the borrowed LST to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///The FlashLoanRecord PDA
+    pub flash_loan_record: &'me AccountInfo<'info>,
+    ///Instructions sysvar
+    pub instructions: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct FlashBorrowKeys {
+    ///Mint of the LST to borrow
+    pub lst_mint: Pubkey,
+    ///LST token account to transfer the borrowed LST to
+    pub dst_lst_acc: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+    ///The FlashLoanRecord PDA
+    pub flash_loan_record: Pubkey,
+    ///Instructions sysvar
+    pub instructions: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<FlashBorrowAccounts<'_, '_>> for FlashBorrowKeys {
+    fn from(accounts: FlashBorrowAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            flash_loan_record: *accounts.flash_loan_record.key,
+            instructions: *accounts.instructions.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<FlashBorrowKeys> for [AccountMeta; FLASH_BORROW_IX_ACCOUNTS_LEN] {
+    fn from(keys: FlashBorrowKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.flash_loan_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.instructions,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; FLASH_BORROW_IX_ACCOUNTS_LEN]> for FlashBorrowKeys {
+    fn from(pubkeys: [Pubkey; FLASH_BORROW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            dst_lst_acc: pubkeys[1],
+            lst_token_program: pubkeys[2],
+            pool_state: pubkeys[3],
+            lst_state_list: pubkeys[4],
+            pool_reserves: pubkeys[5],
+            flash_loan_record: pubkeys[6],
+            instructions: pubkeys[7],
+            system_program: pubkeys[8],
+        }
+    }
+}
+impl<'info> From<FlashBorrowAccounts<'_, 'info>>
+    for [AccountInfo<'info>; FLASH_BORROW_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: FlashBorrowAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.flash_loan_record.clone(),
+            accounts.instructions.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; FLASH_BORROW_IX_ACCOUNTS_LEN]>
+    for FlashBorrowAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; FLASH_BORROW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            dst_lst_acc: &arr[1],
+            lst_token_program: &arr[2],
+            pool_state: &arr[3],
+            lst_state_list: &arr[4],
+            pool_reserves: &arr[5],
+            flash_loan_record: &arr[6],
+            instructions: &arr[7],
+            system_program: &arr[8],
+        }
+    }
+}
+pub const FLASH_BORROW_IX_DISCM: u8 = 42u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlashBorrowIxArgs {
+    pub lst_index: u32,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlashBorrowIxData(pub FlashBorrowIxArgs);
+impl From<FlashBorrowIxArgs> for FlashBorrowIxData {
+    fn from(args: FlashBorrowIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl FlashBorrowIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != FLASH_BORROW_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    FLASH_BORROW_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(FlashBorrowIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[FLASH_BORROW_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn flash_borrow_ix_with_program_id(
+    program_id: Pubkey,
+    keys: FlashBorrowKeys,
+    args: FlashBorrowIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; FLASH_BORROW_IX_ACCOUNTS_LEN] = keys.into();
+    let data: FlashBorrowIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn flash_borrow_ix(
+    keys: FlashBorrowKeys,
+    args: FlashBorrowIxArgs,
+) -> std::io::Result<Instruction> {
+    flash_borrow_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn flash_borrow_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: FlashBorrowAccounts<'_, '_>,
+    args: FlashBorrowIxArgs,
+) -> ProgramResult {
+    let keys: FlashBorrowKeys = accounts.into();
+    let ix = flash_borrow_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn flash_borrow_invoke(
+    accounts: FlashBorrowAccounts<'_, '_>,
+    args: FlashBorrowIxArgs,
+) -> ProgramResult {
+    flash_borrow_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn flash_borrow_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: FlashBorrowAccounts<'_, '_>,
+    args: FlashBorrowIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: FlashBorrowKeys = accounts.into();
+    let ix = flash_borrow_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn flash_borrow_invoke_signed(
+    accounts: FlashBorrowAccounts<'_, '_>,
+    args: FlashBorrowIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    flash_borrow_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn flash_borrow_verify_account_keys(
+    accounts: FlashBorrowAccounts<'_, '_>,
+    keys: FlashBorrowKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.flash_loan_record.key, &keys.flash_loan_record),
+        (accounts.instructions.key, &keys.instructions),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn flash_borrow_verify_writable_privileges<'me, 'info>(
+    accounts: FlashBorrowAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lst_acc,
+        accounts.pool_state,
+        accounts.pool_reserves,
+        accounts.flash_loan_record,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn flash_borrow_verify_account_privileges<'me, 'info>(
+    accounts: FlashBorrowAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    flash_borrow_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const FLASH_REPAY_IX_ACCOUNTS_LEN: usize = 13;
+#[derive(Copy, Clone, Debug)]
+pub struct FlashRepayAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User repaying the flash loan.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the borrowed LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to repay the flash loan from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///The FlashLoanRecord PDA
+    pub flash_loan_record: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct FlashRepayKeys {
+    ///Authority of src_lst_acc. User repaying the flash loan.
+    pub signer: Pubkey,
+    ///Mint of the borrowed LST
+    pub lst_mint: Pubkey,
+    ///LST token account to repay the flash loan from
+    pub src_lst_acc: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+    ///The FlashLoanRecord PDA
+    pub flash_loan_record: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+}
+impl From<FlashRepayAccounts<'_, '_>> for FlashRepayKeys {
+    fn from(accounts: FlashRepayAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            flash_loan_record: *accounts.flash_loan_record.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_config: *accounts.pool_config.key,
+        }
+    }
+}
+impl From<FlashRepayKeys> for [AccountMeta; FLASH_REPAY_IX_ACCOUNTS_LEN] {
+    fn from(keys: FlashRepayKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.flash_loan_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; FLASH_REPAY_IX_ACCOUNTS_LEN]> for FlashRepayKeys {
+    fn from(pubkeys: [Pubkey; FLASH_REPAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            src_lst_acc: pubkeys[2],
+            protocol_fee_accumulator: pubkeys[3],
+            lst_token_program: pubkeys[4],
+            pool_state: pubkeys[5],
+            lst_state_list: pubkeys[6],
+            pool_reserves: pubkeys[7],
+            flash_loan_record: pubkeys[8],
+            lp_token_mint: pubkeys[9],
+            protocol_fee_lp_acc: pubkeys[10],
+            lp_token_program: pubkeys[11],
+            pool_config: pubkeys[12],
+        }
+    }
+}
+impl<'info> From<FlashRepayAccounts<'_, 'info>>
+    for [AccountInfo<'info>; FLASH_REPAY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: FlashRepayAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.flash_loan_record.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_config.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; FLASH_REPAY_IX_ACCOUNTS_LEN]>
+    for FlashRepayAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; FLASH_REPAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            src_lst_acc: &arr[2],
+            protocol_fee_accumulator: &arr[3],
+            lst_token_program: &arr[4],
+            pool_state: &arr[5],
+            lst_state_list: &arr[6],
+            pool_reserves: &arr[7],
+            flash_loan_record: &arr[8],
+            lp_token_mint: &arr[9],
+            protocol_fee_lp_acc: &arr[10],
+            lp_token_program: &arr[11],
+            pool_config: &arr[12],
+        }
+    }
+}
+pub const FLASH_REPAY_IX_DISCM: u8 = 43u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlashRepayIxData;
+impl FlashRepayIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != FLASH_REPAY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    FLASH_REPAY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[FLASH_REPAY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn flash_repay_ix_with_program_id(
+    program_id: Pubkey,
+    keys: FlashRepayKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; FLASH_REPAY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: FlashRepayIxData.try_to_vec()?,
+    })
+}
+pub fn flash_repay_ix(keys: FlashRepayKeys) -> std::io::Result<Instruction> {
+    flash_repay_ix_with_program_id(crate::ID, keys)
+}
+pub fn flash_repay_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: FlashRepayAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: FlashRepayKeys = accounts.into();
+    let ix = flash_repay_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn flash_repay_invoke(accounts: FlashRepayAccounts<'_, '_>) -> ProgramResult {
+    flash_repay_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn flash_repay_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: FlashRepayAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: FlashRepayKeys = accounts.into();
+    let ix = flash_repay_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn flash_repay_invoke_signed(
+    accounts: FlashRepayAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    flash_repay_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn flash_repay_verify_account_keys(
+    accounts: FlashRepayAccounts<'_, '_>,
+    keys: FlashRepayKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.flash_loan_record.key, &keys.flash_loan_record),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_config.key, &keys.pool_config),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn flash_repay_verify_writable_privileges<'me, 'info>(
+    accounts: FlashRepayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+        accounts.flash_loan_record,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_lp_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn flash_repay_verify_signer_privileges<'me, 'info>(
+    accounts: FlashRepayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn flash_repay_verify_account_privileges<'me, 'info>(
+    accounts: FlashRepayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    flash_repay_verify_writable_privileges(accounts)?;
+    flash_repay_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstFlashLoanFeeAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to set the flash loan fee for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstFlashLoanFeeKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to set the flash loan fee for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SetLstFlashLoanFeeAccounts<'_, '_>> for SetLstFlashLoanFeeKeys {
+    fn from(accounts: SetLstFlashLoanFeeAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SetLstFlashLoanFeeKeys> for [AccountMeta; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstFlashLoanFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN]> for SetLstFlashLoanFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLstFlashLoanFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstFlashLoanFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN]>
+    for SetLstFlashLoanFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const SET_LST_FLASH_LOAN_FEE_IX_DISCM: u8 = 44u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstFlashLoanFeeIxArgs {
+    pub index: u32,
+    pub flash_loan_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstFlashLoanFeeIxData(pub SetLstFlashLoanFeeIxArgs);
+impl From<SetLstFlashLoanFeeIxArgs> for SetLstFlashLoanFeeIxData {
+    fn from(args: SetLstFlashLoanFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstFlashLoanFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_FLASH_LOAN_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_FLASH_LOAN_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstFlashLoanFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_FLASH_LOAN_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_flash_loan_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstFlashLoanFeeKeys,
+    args: SetLstFlashLoanFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstFlashLoanFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_flash_loan_fee_ix(
+    keys: SetLstFlashLoanFeeKeys,
+    args: SetLstFlashLoanFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_flash_loan_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_flash_loan_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstFlashLoanFeeAccounts<'_, '_>,
+    args: SetLstFlashLoanFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetLstFlashLoanFeeKeys = accounts.into();
+    let ix = set_lst_flash_loan_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_flash_loan_fee_invoke(
+    accounts: SetLstFlashLoanFeeAccounts<'_, '_>,
+    args: SetLstFlashLoanFeeIxArgs,
+) -> ProgramResult {
+    set_lst_flash_loan_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_flash_loan_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstFlashLoanFeeAccounts<'_, '_>,
+    args: SetLstFlashLoanFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstFlashLoanFeeKeys = accounts.into();
+    let ix = set_lst_flash_loan_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_flash_loan_fee_invoke_signed(
+    accounts: SetLstFlashLoanFeeAccounts<'_, '_>,
+    args: SetLstFlashLoanFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_flash_loan_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_flash_loan_fee_verify_account_keys(
+    accounts: SetLstFlashLoanFeeAccounts<'_, '_>,
+    keys: SetLstFlashLoanFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_flash_loan_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstFlashLoanFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_flash_loan_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstFlashLoanFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_flash_loan_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetLstFlashLoanFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_flash_loan_fee_verify_writable_privileges(accounts)?;
+    set_lst_flash_loan_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct AddRebalanceLegAccounts<'me, 'info> {
+    ///The pool's rebalance authority or a rebalance authority on the rebalance authority list
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+    ///Source LST token account to withdraw to
+    pub withdraw_to: &'me AccountInfo<'info>,
+    ///Source LST token program
+    pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddRebalanceLegKeys {
+    ///The pool's rebalance authority or a rebalance authority on the rebalance authority list
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: Pubkey,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: Pubkey,
+    ///Mint of the LST being swapped from
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+    ///Source LST token account to withdraw to
+    pub withdraw_to: Pubkey,
+    ///Source LST token program
+    pub src_lst_token_program: Pubkey,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+}
+impl From<AddRebalanceLegAccounts<'_, '_>> for AddRebalanceLegKeys {
+    fn from(accounts: AddRebalanceLegAccounts) -> Self {
+        Self {
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            rebalance_record: *accounts.rebalance_record.key,
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+            withdraw_to: *accounts.withdraw_to.key,
+            src_lst_token_program: *accounts.src_lst_token_program.key,
+            rebalance_authority_list: *accounts.rebalance_authority_list.key,
+            pool_config: *accounts.pool_config.key,
+        }
+    }
+}
+impl From<AddRebalanceLegKeys> for [AccountMeta; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddRebalanceLegKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN]> for AddRebalanceLegKeys {
+    fn from(pubkeys: [Pubkey; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            rebalance_record: pubkeys[3],
+            src_lst_mint: pubkeys[4],
+            dst_lst_mint: pubkeys[5],
+            src_pool_reserves: pubkeys[6],
+            dst_pool_reserves: pubkeys[7],
+            withdraw_to: pubkeys[8],
+            src_lst_token_program: pubkeys[9],
+            rebalance_authority_list: pubkeys[10],
+            pool_config: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<AddRebalanceLegAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddRebalanceLegAccounts<'_, 'info>) -> Self {
+        [
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.rebalance_record.clone(),
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+            accounts.withdraw_to.clone(),
+            accounts.src_lst_token_program.clone(),
+            accounts.rebalance_authority_list.clone(),
+            accounts.pool_config.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN]>
+    for AddRebalanceLegAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            rebalance_record: &arr[3],
+            src_lst_mint: &arr[4],
+            dst_lst_mint: &arr[5],
+            src_pool_reserves: &arr[6],
+            dst_pool_reserves: &arr[7],
+            withdraw_to: &arr[8],
+            src_lst_token_program: &arr[9],
+            rebalance_authority_list: &arr[10],
+            pool_config: &arr[11],
+        }
+    }
+}
+pub const ADD_REBALANCE_LEG_IX_DISCM: u8 = 45u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddRebalanceLegIxArgs {
+    pub src_lst_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub amount: u64,
+    pub min_starting_src_lst: u64,
+    pub max_starting_dst_lst: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddRebalanceLegIxData(pub AddRebalanceLegIxArgs);
+impl From<AddRebalanceLegIxArgs> for AddRebalanceLegIxData {
+    fn from(args: AddRebalanceLegIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddRebalanceLegIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_REBALANCE_LEG_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_REBALANCE_LEG_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddRebalanceLegIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_REBALANCE_LEG_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_rebalance_leg_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddRebalanceLegKeys,
+    args: AddRebalanceLegIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddRebalanceLegIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_rebalance_leg_ix(
+    keys: AddRebalanceLegKeys,
+    args: AddRebalanceLegIxArgs,
+) -> std::io::Result<Instruction> {
+    add_rebalance_leg_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_rebalance_leg_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddRebalanceLegAccounts<'_, '_>,
+    args: AddRebalanceLegIxArgs,
+) -> ProgramResult {
+    let keys: AddRebalanceLegKeys = accounts.into();
+    let ix = add_rebalance_leg_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_rebalance_leg_invoke(
+    accounts: AddRebalanceLegAccounts<'_, '_>,
+    args: AddRebalanceLegIxArgs,
+) -> ProgramResult {
+    add_rebalance_leg_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_rebalance_leg_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddRebalanceLegAccounts<'_, '_>,
+    args: AddRebalanceLegIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddRebalanceLegKeys = accounts.into();
+    let ix = add_rebalance_leg_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_rebalance_leg_invoke_signed(
+    accounts: AddRebalanceLegAccounts<'_, '_>,
+    args: AddRebalanceLegIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_rebalance_leg_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_rebalance_leg_verify_account_keys(
+    accounts: AddRebalanceLegAccounts<'_, '_>,
+    keys: AddRebalanceLegKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.rebalance_record.key, &keys.rebalance_record),
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+        (accounts.withdraw_to.key, &keys.withdraw_to),
+        (
+            accounts.src_lst_token_program.key,
+            &keys.src_lst_token_program,
+        ),
+        (
+            accounts.rebalance_authority_list.key,
+            &keys.rebalance_authority_list,
+        ),
+        (accounts.pool_config.key, &keys.pool_config),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_leg_verify_writable_privileges<'me, 'info>(
+    accounts: AddRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.rebalance_record,
+        accounts.src_pool_reserves,
+        accounts.dst_pool_reserves,
+        accounts.withdraw_to,
+        accounts.rebalance_authority_list,
+        accounts.pool_config,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_leg_verify_signer_privileges<'me, 'info>(
+    accounts: AddRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.rebalance_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_leg_verify_account_privileges<'me, 'info>(
+    accounts: AddRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_rebalance_leg_verify_writable_privileges(accounts)?;
+    add_rebalance_leg_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct SettleRebalanceLegAccounts<'me, 'info> {
+    ///The rebalance authority that signed the StartRebalance instruction
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: &'me AccountInfo<'info>,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SettleRebalanceLegKeys {
+    ///The rebalance authority that signed the StartRebalance instruction
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each lst in the pool
+    pub lst_state_list: Pubkey,
+    ///The RebalanceRecord PDA
+    pub rebalance_record: Pubkey,
+    ///Mint of the LST being swapped to
+    pub dst_lst_mint: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<SettleRebalanceLegAccounts<'_, '_>> for SettleRebalanceLegKeys {
+    fn from(accounts: SettleRebalanceLegAccounts) -> Self {
+        Self {
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            rebalance_record: *accounts.rebalance_record.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<SettleRebalanceLegKeys> for [AccountMeta; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN] {
+    fn from(keys: SettleRebalanceLegKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN]> for SettleRebalanceLegKeys {
+    fn from(pubkeys: [Pubkey; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            rebalance_record: pubkeys[3],
+            dst_lst_mint: pubkeys[4],
+            dst_pool_reserves: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<SettleRebalanceLegAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SettleRebalanceLegAccounts<'_, 'info>) -> Self {
+        [
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.rebalance_record.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN]>
+    for SettleRebalanceLegAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            rebalance_authority: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            rebalance_record: &arr[3],
+            dst_lst_mint: &arr[4],
+            dst_pool_reserves: &arr[5],
+        }
+    }
+}
+pub const SETTLE_REBALANCE_LEG_IX_DISCM: u8 = 46u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettleRebalanceLegIxData;
+impl SettleRebalanceLegIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SETTLE_REBALANCE_LEG_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SETTLE_REBALANCE_LEG_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SETTLE_REBALANCE_LEG_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn settle_rebalance_leg_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SettleRebalanceLegKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SettleRebalanceLegIxData.try_to_vec()?,
+    })
+}
+pub fn settle_rebalance_leg_ix(keys: SettleRebalanceLegKeys) -> std::io::Result<Instruction> {
+    settle_rebalance_leg_ix_with_program_id(crate::ID, keys)
+}
+pub fn settle_rebalance_leg_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleRebalanceLegAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SettleRebalanceLegKeys = accounts.into();
+    let ix = settle_rebalance_leg_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn settle_rebalance_leg_invoke(accounts: SettleRebalanceLegAccounts<'_, '_>) -> ProgramResult {
+    settle_rebalance_leg_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn settle_rebalance_leg_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SettleRebalanceLegAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SettleRebalanceLegKeys = accounts.into();
+    let ix = settle_rebalance_leg_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn settle_rebalance_leg_invoke_signed(
+    accounts: SettleRebalanceLegAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    settle_rebalance_leg_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn settle_rebalance_leg_verify_account_keys(
+    accounts: SettleRebalanceLegAccounts<'_, '_>,
+    keys: SettleRebalanceLegKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.rebalance_record.key, &keys.rebalance_record),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn settle_rebalance_leg_verify_writable_privileges<'me, 'info>(
+    accounts: SettleRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.rebalance_record,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn settle_rebalance_leg_verify_signer_privileges<'me, 'info>(
+    accounts: SettleRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.rebalance_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn settle_rebalance_leg_verify_account_privileges<'me, 'info>(
+    accounts: SettleRebalanceLegAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    settle_rebalance_leg_verify_writable_privileges(accounts)?;
+    settle_rebalance_leg_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityProportionalAccounts<'me, 'info> {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created yet
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityProportionalKeys {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created yet
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+}
+impl From<RemoveLiquidityProportionalAccounts<'_, '_>> for RemoveLiquidityProportionalKeys {
+    fn from(accounts: RemoveLiquidityProportionalAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+        }
+    }
+}
+impl From<RemoveLiquidityProportionalKeys>
+    for [AccountMeta; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: RemoveLiquidityProportionalKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityProportionalKeys
+{
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+            pool_config: pubkeys[6],
+            protocol_fee_lp_acc: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityProportionalAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityProportionalAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityProportionalAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+            pool_config: &arr[6],
+            protocol_fee_lp_acc: &arr[7],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM: u8 = 47u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityProportionalIxArgs {
+    pub lp_token_amount: u64,
+    pub entries: Vec<RemoveLiquidityProportionalEntry>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityProportionalIxData(pub RemoveLiquidityProportionalIxArgs);
+impl From<RemoveLiquidityProportionalIxArgs> for RemoveLiquidityProportionalIxData {
+    fn from(args: RemoveLiquidityProportionalIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityProportionalIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityProportionalIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_PROPORTIONAL_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_proportional_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityProportionalKeys,
+    args: RemoveLiquidityProportionalIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityProportionalIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_proportional_ix(
+    keys: RemoveLiquidityProportionalKeys,
+    args: RemoveLiquidityProportionalIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_proportional_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_proportional_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProportionalAccounts<'_, '_>,
+    args: RemoveLiquidityProportionalIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityProportionalKeys = accounts.into();
+    let ix = remove_liquidity_proportional_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_proportional_invoke(
+    accounts: RemoveLiquidityProportionalAccounts<'_, '_>,
+    args: RemoveLiquidityProportionalIxArgs,
+) -> ProgramResult {
+    remove_liquidity_proportional_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_proportional_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProportionalAccounts<'_, '_>,
+    args: RemoveLiquidityProportionalIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityProportionalKeys = accounts.into();
+    let ix = remove_liquidity_proportional_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_proportional_invoke_signed(
+    accounts: RemoveLiquidityProportionalAccounts<'_, '_>,
+    args: RemoveLiquidityProportionalIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_proportional_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_proportional_verify_account_keys(
+    accounts: RemoveLiquidityProportionalAccounts<'_, '_>,
+    keys: RemoveLiquidityProportionalKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_proportional_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProportionalAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.protocol_fee_lp_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_proportional_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProportionalAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_proportional_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProportionalAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_proportional_verify_writable_privileges(accounts)?;
+    remove_liquidity_proportional_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetRemoveLiquidityProportionalFeeAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetRemoveLiquidityProportionalFeeKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetRemoveLiquidityProportionalFeeAccounts<'_, '_>>
+    for SetRemoveLiquidityProportionalFeeKeys
+{
+    fn from(accounts: SetRemoveLiquidityProportionalFeeAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetRemoveLiquidityProportionalFeeKeys>
+    for [AccountMeta; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetRemoveLiquidityProportionalFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN]>
+    for SetRemoveLiquidityProportionalFeeKeys
+{
+    fn from(pubkeys: [Pubkey; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetRemoveLiquidityProportionalFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN]>
+    for SetRemoveLiquidityProportionalFeeAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM: u8 = 48u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetRemoveLiquidityProportionalFeeIxArgs {
+    pub new_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetRemoveLiquidityProportionalFeeIxData(pub SetRemoveLiquidityProportionalFeeIxArgs);
+impl From<SetRemoveLiquidityProportionalFeeIxArgs> for SetRemoveLiquidityProportionalFeeIxData {
+    fn from(args: SetRemoveLiquidityProportionalFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetRemoveLiquidityProportionalFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetRemoveLiquidityProportionalFeeIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_remove_liquidity_proportional_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetRemoveLiquidityProportionalFeeKeys,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_REMOVE_LIQUIDITY_PROPORTIONAL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetRemoveLiquidityProportionalFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_remove_liquidity_proportional_fee_ix(
+    keys: SetRemoveLiquidityProportionalFeeKeys,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_remove_liquidity_proportional_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_remove_liquidity_proportional_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, '_>,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetRemoveLiquidityProportionalFeeKeys = accounts.into();
+    let ix = set_remove_liquidity_proportional_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_remove_liquidity_proportional_fee_invoke(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, '_>,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+) -> ProgramResult {
+    set_remove_liquidity_proportional_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_remove_liquidity_proportional_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, '_>,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetRemoveLiquidityProportionalFeeKeys = accounts.into();
+    let ix = set_remove_liquidity_proportional_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_remove_liquidity_proportional_fee_invoke_signed(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, '_>,
+    args: SetRemoveLiquidityProportionalFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_remove_liquidity_proportional_fee_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn set_remove_liquidity_proportional_fee_verify_account_keys(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'_, '_>,
+    keys: SetRemoveLiquidityProportionalFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_remove_liquidity_proportional_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_remove_liquidity_proportional_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_remove_liquidity_proportional_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetRemoveLiquidityProportionalFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_remove_liquidity_proportional_fee_verify_writable_privileges(accounts)?;
+    set_remove_liquidity_proportional_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiAccounts<'me, 'info> {
+    ///Authority of the src LST token accounts. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiKeys {
+    ///Authority of the src LST token accounts. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+}
+impl From<AddLiquidityMultiAccounts<'_, '_>> for AddLiquidityMultiKeys {
+    fn from(accounts: AddLiquidityMultiAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+        }
+    }
+}
+impl From<AddLiquidityMultiKeys> for [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLiquidityMultiKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]> for AddLiquidityMultiKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            dst_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+            pool_config: pubkeys[6],
+            protocol_fee_lp_acc: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<AddLiquidityMultiAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddLiquidityMultiAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]>
+    for AddLiquidityMultiAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            dst_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+            pool_config: &arr[6],
+            protocol_fee_lp_acc: &arr[7],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_MULTI_IX_DISCM: u8 = 49u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityMultiIxArgs {
+    pub min_lp_out: u64,
+    pub entries: Vec<AddLiquidityMultiEntry>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityMultiIxData(pub AddLiquidityMultiIxArgs);
+impl From<AddLiquidityMultiIxArgs> for AddLiquidityMultiIxData {
+    fn from(args: AddLiquidityMultiIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityMultiIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LIQUIDITY_MULTI_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LIQUIDITY_MULTI_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLiquidityMultiIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_liquidity_multi_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityMultiIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_multi_ix(
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    add_liquidity_multi_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_liquidity_multi_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_liquidity_multi_invoke(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    add_liquidity_multi_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_multi_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_liquidity_multi_invoke_signed(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_liquidity_multi_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_liquidity_multi_verify_account_keys(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    keys: AddLiquidityMultiKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_writable_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.protocol_fee_lp_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_signer_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_account_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_liquidity_multi_verify_writable_privileges(accounts)?;
+    add_liquidity_multi_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPOSIT_STAKE_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct DepositStakeAccounts<'me, 'info> {
+    ///Authority of the stake account to deposit. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool. The stake pool mints the LST to this account
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DepositStakeKeys {
+    ///Authority of the stake account to deposit. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool. The stake pool mints the LST to this account
+    pub pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+}
+impl From<DepositStakeAccounts<'_, '_>> for DepositStakeKeys {
+    fn from(accounts: DepositStakeAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+        }
+    }
+}
+impl From<DepositStakeKeys> for [AccountMeta; DEPOSIT_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositStakeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]> for DepositStakeKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            dst_lp_acc: pubkeys[2],
+            lp_token_mint: pubkeys[3],
+            protocol_fee_accumulator: pubkeys[4],
+            lst_token_program: pubkeys[5],
+            lp_token_program: pubkeys[6],
+            pool_state: pubkeys[7],
+            lst_state_list: pubkeys[8],
+            pool_reserves: pubkeys[9],
+            pool_config: pubkeys[10],
+            protocol_fee_lp_acc: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<DepositStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DepositStakeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]>
+    for DepositStakeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            dst_lp_acc: &arr[2],
+            lp_token_mint: &arr[3],
+            protocol_fee_accumulator: &arr[4],
+            lst_token_program: &arr[5],
+            lp_token_program: &arr[6],
+            pool_state: &arr[7],
+            lst_state_list: &arr[8],
+            pool_reserves: &arr[9],
+            pool_config: &arr[10],
+            protocol_fee_lp_acc: &arr[11],
+        }
+    }
+}
+pub const DEPOSIT_STAKE_IX_DISCM: u8 = 50u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositStakeIxArgs {
+    pub deposit_stake_accs: u8,
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub validator_index: u32,
+    pub min_lp_out: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositStakeIxData(pub DepositStakeIxArgs);
+impl From<DepositStakeIxArgs> for DepositStakeIxData {
+    fn from(args: DepositStakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DepositStakeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPOSIT_STAKE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DEPOSIT_STAKE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DepositStakeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[DEPOSIT_STAKE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn deposit_stake_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DepositStakeKeys,
+    args: DepositStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPOSIT_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DepositStakeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deposit_stake_ix(
+    keys: DepositStakeKeys,
+    args: DepositStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    deposit_stake_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn deposit_stake_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositStakeAccounts<'_, '_>,
+    args: DepositStakeIxArgs,
+) -> ProgramResult {
+    let keys: DepositStakeKeys = accounts.into();
+    let ix = deposit_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn deposit_stake_invoke(
+    accounts: DepositStakeAccounts<'_, '_>,
+    args: DepositStakeIxArgs,
+) -> ProgramResult {
+    deposit_stake_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_stake_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositStakeAccounts<'_, '_>,
+    args: DepositStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DepositStakeKeys = accounts.into();
+    let ix = deposit_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn deposit_stake_invoke_signed(
+    accounts: DepositStakeAccounts<'_, '_>,
+    args: DepositStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    deposit_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn deposit_stake_verify_account_keys(
+    accounts: DepositStakeAccounts<'_, '_>,
+    keys: DepositStakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_stake_verify_writable_privileges<'me, 'info>(
+    accounts: DepositStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+        accounts.protocol_fee_lp_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_stake_verify_signer_privileges<'me, 'info>(
+    accounts: DepositStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_stake_verify_account_privileges<'me, 'info>(
+    accounts: DepositStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    deposit_stake_verify_writable_privileges(accounts)?;
+    deposit_stake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityToStakeAccounts<'me, 'info> {
+    ///Authority of src_lp_acc. User who's removing liquidity. Set as the withdraw authority of the received stake account
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool. The stake pool burns the LST from this account
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityToStakeKeys {
+    ///Authority of src_lp_acc. User who's removing liquidity. Set as the withdraw authority of the received stake account
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool. The stake pool burns the LST from this account
+    pub pool_reserves: Pubkey,
+    ///The PoolConfig singleton PDA. Does not need to have been created
+    pub pool_config: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint. Protocol fees are minted to it as LP tokens if the pool's protocol_fees_as_lp is set
+    pub protocol_fee_lp_acc: Pubkey,
+}
+impl From<RemoveLiquidityToStakeAccounts<'_, '_>> for RemoveLiquidityToStakeKeys {
+    fn from(accounts: RemoveLiquidityToStakeAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            pool_config: *accounts.pool_config.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+        }
+    }
+}
+impl From<RemoveLiquidityToStakeKeys> for [AccountMeta; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveLiquidityToStakeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN]> for RemoveLiquidityToStakeKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            src_lp_acc: pubkeys[2],
+            lp_token_mint: pubkeys[3],
+            protocol_fee_accumulator: pubkeys[4],
+            lst_token_program: pubkeys[5],
+            lp_token_program: pubkeys[6],
+            pool_state: pubkeys[7],
+            lst_state_list: pubkeys[8],
+            pool_reserves: pubkeys[9],
+            pool_config: pubkeys[10],
+            protocol_fee_lp_acc: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityToStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityToStakeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.pool_config.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityToStakeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            src_lp_acc: &arr[2],
+            lp_token_mint: &arr[3],
+            protocol_fee_accumulator: &arr[4],
+            lst_token_program: &arr[5],
+            lp_token_program: &arr[6],
+            pool_state: &arr[7],
+            lst_state_list: &arr[8],
+            pool_reserves: &arr[9],
+            pool_config: &arr[10],
+            protocol_fee_lp_acc: &arr[11],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM: u8 = 51u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityToStakeIxArgs {
+    pub withdraw_stake_accs: u8,
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lp_token_amount: u64,
+    pub min_lst_out: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityToStakeIxData(pub RemoveLiquidityToStakeIxArgs);
+impl From<RemoveLiquidityToStakeIxArgs> for RemoveLiquidityToStakeIxData {
+    fn from(args: RemoveLiquidityToStakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityToStakeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityToStakeIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_TO_STAKE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_to_stake_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityToStakeKeys,
+    args: RemoveLiquidityToStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityToStakeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_to_stake_ix(
+    keys: RemoveLiquidityToStakeKeys,
+    args: RemoveLiquidityToStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_to_stake_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_to_stake_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityToStakeAccounts<'_, '_>,
+    args: RemoveLiquidityToStakeIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityToStakeKeys = accounts.into();
+    let ix = remove_liquidity_to_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_to_stake_invoke(
+    accounts: RemoveLiquidityToStakeAccounts<'_, '_>,
+    args: RemoveLiquidityToStakeIxArgs,
+) -> ProgramResult {
+    remove_liquidity_to_stake_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_to_stake_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityToStakeAccounts<'_, '_>,
+    args: RemoveLiquidityToStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityToStakeKeys = accounts.into();
+    let ix = remove_liquidity_to_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_to_stake_invoke_signed(
+    accounts: RemoveLiquidityToStakeAccounts<'_, '_>,
+    args: RemoveLiquidityToStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_to_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_to_stake_verify_account_keys(
+    accounts: RemoveLiquidityToStakeAccounts<'_, '_>,
+    keys: RemoveLiquidityToStakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_to_stake_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityToStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+        accounts.protocol_fee_lp_acc,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_to_stake_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityToStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_to_stake_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityToStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_to_stake_verify_writable_privileges(accounts)?;
+    remove_liquidity_to_stake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_REFERRAL_FEE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetReferralFeeAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetReferralFeeKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetReferralFeeAccounts<'_, '_>> for SetReferralFeeKeys {
+    fn from(accounts: SetReferralFeeAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetReferralFeeKeys> for [AccountMeta; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetReferralFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN]> for SetReferralFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetReferralFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetReferralFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN]>
+    for SetReferralFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_REFERRAL_FEE_IX_DISCM: u8 = 52u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetReferralFeeIxArgs {
+    pub new_fee_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetReferralFeeIxData(pub SetReferralFeeIxArgs);
+impl From<SetReferralFeeIxArgs> for SetReferralFeeIxData {
+    fn from(args: SetReferralFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetReferralFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_REFERRAL_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_REFERRAL_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetReferralFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_REFERRAL_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_referral_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetReferralFeeKeys,
+    args: SetReferralFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_REFERRAL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetReferralFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_referral_fee_ix(
+    keys: SetReferralFeeKeys,
+    args: SetReferralFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_referral_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_referral_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetReferralFeeAccounts<'_, '_>,
+    args: SetReferralFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetReferralFeeKeys = accounts.into();
+    let ix = set_referral_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_referral_fee_invoke(
+    accounts: SetReferralFeeAccounts<'_, '_>,
+    args: SetReferralFeeIxArgs,
+) -> ProgramResult {
+    set_referral_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_referral_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetReferralFeeAccounts<'_, '_>,
+    args: SetReferralFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetReferralFeeKeys = accounts.into();
+    let ix = set_referral_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_referral_fee_invoke_signed(
+    accounts: SetReferralFeeAccounts<'_, '_>,
+    args: SetReferralFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_referral_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_referral_fee_verify_account_keys(
+    accounts: SetReferralFeeAccounts<'_, '_>,
+    keys: SetReferralFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetReferralFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetReferralFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_referral_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetReferralFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_referral_fee_verify_writable_privileges(accounts)?;
+    set_referral_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddProtocolFeeBeneficiaryAccounts<'me, 'info> {
+    ///Account paying for additional rent for realloc
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The new protocol fee beneficiary to add
+    pub new_beneficiary: &'me AccountInfo<'info>,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddProtocolFeeBeneficiaryKeys {
+    ///Account paying for additional rent for realloc
+    pub payer: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The new protocol fee beneficiary to add
+    pub new_beneficiary: Pubkey,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddProtocolFeeBeneficiaryAccounts<'_, '_>> for AddProtocolFeeBeneficiaryKeys {
+    fn from(accounts: AddProtocolFeeBeneficiaryAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            new_beneficiary: *accounts.new_beneficiary.key,
+            protocol_fee_beneficiary_list: *accounts.protocol_fee_beneficiary_list.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddProtocolFeeBeneficiaryKeys>
+    for [AccountMeta; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: AddProtocolFeeBeneficiaryKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_beneficiary,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for AddProtocolFeeBeneficiaryKeys
+{
+    fn from(pubkeys: [Pubkey; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            admin: pubkeys[1],
+            pool_state: pubkeys[2],
+            new_beneficiary: pubkeys[3],
+            protocol_fee_beneficiary_list: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddProtocolFeeBeneficiaryAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddProtocolFeeBeneficiaryAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.new_beneficiary.clone(),
+            accounts.protocol_fee_beneficiary_list.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for AddProtocolFeeBeneficiaryAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            admin: &arr[1],
+            pool_state: &arr[2],
+            new_beneficiary: &arr[3],
+            protocol_fee_beneficiary_list: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM: u8 = 53u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddProtocolFeeBeneficiaryIxArgs {
+    pub weight_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddProtocolFeeBeneficiaryIxData(pub AddProtocolFeeBeneficiaryIxArgs);
+impl From<AddProtocolFeeBeneficiaryIxArgs> for AddProtocolFeeBeneficiaryIxData {
+    fn from(args: AddProtocolFeeBeneficiaryIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddProtocolFeeBeneficiaryIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddProtocolFeeBeneficiaryIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_protocol_fee_beneficiary_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddProtocolFeeBeneficiaryKeys,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddProtocolFeeBeneficiaryIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_protocol_fee_beneficiary_ix(
+    keys: AddProtocolFeeBeneficiaryKeys,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+) -> std::io::Result<Instruction> {
+    add_protocol_fee_beneficiary_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_protocol_fee_beneficiary_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    let keys: AddProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = add_protocol_fee_beneficiary_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_protocol_fee_beneficiary_invoke(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    add_protocol_fee_beneficiary_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_protocol_fee_beneficiary_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = add_protocol_fee_beneficiary_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_protocol_fee_beneficiary_invoke_signed(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: AddProtocolFeeBeneficiaryIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_protocol_fee_beneficiary_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_protocol_fee_beneficiary_verify_account_keys(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'_, '_>,
+    keys: AddProtocolFeeBeneficiaryKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.new_beneficiary.key, &keys.new_beneficiary),
+        (
+            accounts.protocol_fee_beneficiary_list.key,
+            &keys.protocol_fee_beneficiary_list,
+        ),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_protocol_fee_beneficiary_verify_writable_privileges<'me, 'info>(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.protocol_fee_beneficiary_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_protocol_fee_beneficiary_verify_signer_privileges<'me, 'info>(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_protocol_fee_beneficiary_verify_account_privileges<'me, 'info>(
+    accounts: AddProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_protocol_fee_beneficiary_verify_writable_privileges(accounts)?;
+    add_protocol_fee_beneficiary_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveProtocolFeeBeneficiaryAccounts<'me, 'info> {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The protocol fee beneficiary to remove
+    pub beneficiary: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveProtocolFeeBeneficiaryKeys {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The protocol fee beneficiary to remove
+    pub beneficiary: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: Pubkey,
+}
+impl From<RemoveProtocolFeeBeneficiaryAccounts<'_, '_>> for RemoveProtocolFeeBeneficiaryKeys {
+    fn from(accounts: RemoveProtocolFeeBeneficiaryAccounts) -> Self {
+        Self {
+            refund_rent_to: *accounts.refund_rent_to.key,
+            admin: *accounts.admin.key,
+            beneficiary: *accounts.beneficiary.key,
+            pool_state: *accounts.pool_state.key,
+            protocol_fee_beneficiary_list: *accounts.protocol_fee_beneficiary_list.key,
+        }
+    }
+}
+impl From<RemoveProtocolFeeBeneficiaryKeys>
+    for [AccountMeta; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: RemoveProtocolFeeBeneficiaryKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.beneficiary,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for RemoveProtocolFeeBeneficiaryKeys
+{
+    fn from(pubkeys: [Pubkey; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: pubkeys[0],
+            admin: pubkeys[1],
+            beneficiary: pubkeys[2],
+            pool_state: pubkeys[3],
+            protocol_fee_beneficiary_list: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<RemoveProtocolFeeBeneficiaryAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, 'info>) -> Self {
+        [
+            accounts.refund_rent_to.clone(),
+            accounts.admin.clone(),
+            accounts.beneficiary.clone(),
+            accounts.pool_state.clone(),
+            accounts.protocol_fee_beneficiary_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN]>
+    for RemoveProtocolFeeBeneficiaryAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            refund_rent_to: &arr[0],
+            admin: &arr[1],
+            beneficiary: &arr[2],
+            pool_state: &arr[3],
+            protocol_fee_beneficiary_list: &arr[4],
+        }
+    }
+}
+pub const REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM: u8 = 54u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveProtocolFeeBeneficiaryIxArgs {
+    pub index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveProtocolFeeBeneficiaryIxData(pub RemoveProtocolFeeBeneficiaryIxArgs);
+impl From<RemoveProtocolFeeBeneficiaryIxArgs> for RemoveProtocolFeeBeneficiaryIxData {
+    fn from(args: RemoveProtocolFeeBeneficiaryIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveProtocolFeeBeneficiaryIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveProtocolFeeBeneficiaryIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_protocol_fee_beneficiary_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveProtocolFeeBeneficiaryKeys,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_PROTOCOL_FEE_BENEFICIARY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveProtocolFeeBeneficiaryIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_protocol_fee_beneficiary_ix(
+    keys: RemoveProtocolFeeBeneficiaryKeys,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_protocol_fee_beneficiary_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_protocol_fee_beneficiary_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    let keys: RemoveProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = remove_protocol_fee_beneficiary_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_protocol_fee_beneficiary_invoke(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    remove_protocol_fee_beneficiary_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_protocol_fee_beneficiary_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveProtocolFeeBeneficiaryKeys = accounts.into();
+    let ix = remove_protocol_fee_beneficiary_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_protocol_fee_beneficiary_invoke_signed(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, '_>,
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_protocol_fee_beneficiary_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_protocol_fee_beneficiary_verify_account_keys(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'_, '_>,
+    keys: RemoveProtocolFeeBeneficiaryKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.admin.key, &keys.admin),
+        (accounts.beneficiary.key, &keys.beneficiary),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.protocol_fee_beneficiary_list.key,
+            &keys.protocol_fee_beneficiary_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_protocol_fee_beneficiary_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.refund_rent_to,
+        accounts.protocol_fee_beneficiary_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_protocol_fee_beneficiary_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_protocol_fee_beneficiary_verify_account_privileges<'me, 'info>(
+    accounts: RemoveProtocolFeeBeneficiaryAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_protocol_fee_beneficiary_verify_writable_privileges(accounts)?;
+    remove_protocol_fee_beneficiary_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The protocol fee beneficiary to set the weight of
+    pub beneficiary: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolFeeBeneficiaryWeightKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The protocol fee beneficiary to set the weight of
+    pub beneficiary: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: Pubkey,
+}
+impl From<SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>> for SetProtocolFeeBeneficiaryWeightKeys {
+    fn from(accounts: SetProtocolFeeBeneficiaryWeightAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            beneficiary: *accounts.beneficiary.key,
+            pool_state: *accounts.pool_state.key,
+            protocol_fee_beneficiary_list: *accounts.protocol_fee_beneficiary_list.key,
+        }
+    }
+}
+impl From<SetProtocolFeeBeneficiaryWeightKeys>
+    for [AccountMeta; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetProtocolFeeBeneficiaryWeightKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.beneficiary,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN]>
+    for SetProtocolFeeBeneficiaryWeightKeys
+{
+    fn from(pubkeys: [Pubkey; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            beneficiary: pubkeys[1],
+            pool_state: pubkeys[2],
+            protocol_fee_beneficiary_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetProtocolFeeBeneficiaryWeightAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.beneficiary.clone(),
+            accounts.pool_state.clone(),
+            accounts.protocol_fee_beneficiary_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN]>
+    for SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            admin: &arr[0],
+            beneficiary: &arr[1],
+            pool_state: &arr[2],
+            protocol_fee_beneficiary_list: &arr[3],
+        }
+    }
+}
+pub const SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM: u8 = 55u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetProtocolFeeBeneficiaryWeightIxArgs {
+    pub index: u32,
+    pub weight_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetProtocolFeeBeneficiaryWeightIxData(pub SetProtocolFeeBeneficiaryWeightIxArgs);
+impl From<SetProtocolFeeBeneficiaryWeightIxArgs> for SetProtocolFeeBeneficiaryWeightIxData {
+    fn from(args: SetProtocolFeeBeneficiaryWeightIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetProtocolFeeBeneficiaryWeightIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetProtocolFeeBeneficiaryWeightIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_protocol_fee_beneficiary_weight_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetProtocolFeeBeneficiaryWeightKeys,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PROTOCOL_FEE_BENEFICIARY_WEIGHT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetProtocolFeeBeneficiaryWeightIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_protocol_fee_beneficiary_weight_ix(
+    keys: SetProtocolFeeBeneficiaryWeightKeys,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> std::io::Result<Instruction> {
+    set_protocol_fee_beneficiary_weight_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_protocol_fee_beneficiary_weight_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> ProgramResult {
+    let keys: SetProtocolFeeBeneficiaryWeightKeys = accounts.into();
+    let ix = set_protocol_fee_beneficiary_weight_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_protocol_fee_beneficiary_weight_invoke(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> ProgramResult {
+    set_protocol_fee_beneficiary_weight_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_protocol_fee_beneficiary_weight_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetProtocolFeeBeneficiaryWeightKeys = accounts.into();
+    let ix = set_protocol_fee_beneficiary_weight_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_protocol_fee_beneficiary_weight_invoke_signed(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>,
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_protocol_fee_beneficiary_weight_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn set_protocol_fee_beneficiary_weight_verify_account_keys(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'_, '_>,
+    keys: SetProtocolFeeBeneficiaryWeightKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.beneficiary.key, &keys.beneficiary),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.protocol_fee_beneficiary_list.key,
+            &keys.protocol_fee_beneficiary_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fee_beneficiary_weight_verify_writable_privileges<'me, 'info>(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.protocol_fee_beneficiary_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fee_beneficiary_weight_verify_signer_privileges<'me, 'info>(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fee_beneficiary_weight_verify_account_privileges<'me, 'info>(
+    accounts: SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_protocol_fee_beneficiary_weight_verify_writable_privileges(accounts)?;
+    set_protocol_fee_beneficiary_weight_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct DistributeProtocolFeesAccounts<'me, 'info> {
+    ///The LST protocol fee accumulator token account to distribute
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: &'me AccountInfo<'info>,
+    ///Token program
+    pub token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The LST mint
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DistributeProtocolFeesKeys {
+    ///The LST protocol fee accumulator token account to distribute
+    pub protocol_fee_accumulator: Pubkey,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The LST mint
+    pub lst_mint: Pubkey,
+    ///The pool's protocol fee beneficiary list singleton PDA
+    pub protocol_fee_beneficiary_list: Pubkey,
+}
+impl From<DistributeProtocolFeesAccounts<'_, '_>> for DistributeProtocolFeesKeys {
+    fn from(accounts: DistributeProtocolFeesAccounts) -> Self {
+        Self {
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            protocol_fee_accumulator_auth: *accounts.protocol_fee_accumulator_auth.key,
+            token_program: *accounts.token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_mint: *accounts.lst_mint.key,
+            protocol_fee_beneficiary_list: *accounts.protocol_fee_beneficiary_list.key,
+        }
+    }
+}
+impl From<DistributeProtocolFeesKeys> for [AccountMeta; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN] {
+    fn from(keys: DistributeProtocolFeesKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator_auth,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary_list,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN]> for DistributeProtocolFeesKeys {
+    fn from(pubkeys: [Pubkey; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_accumulator: pubkeys[0],
+            protocol_fee_accumulator_auth: pubkeys[1],
+            token_program: pubkeys[2],
+            pool_state: pubkeys[3],
+            lst_mint: pubkeys[4],
+            protocol_fee_beneficiary_list: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<DistributeProtocolFeesAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DistributeProtocolFeesAccounts<'_, 'info>) -> Self {
+        [
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.protocol_fee_accumulator_auth.clone(),
+            accounts.token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_mint.clone(),
+            accounts.protocol_fee_beneficiary_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN]>
+    for DistributeProtocolFeesAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_accumulator: &arr[0],
+            protocol_fee_accumulator_auth: &arr[1],
+            token_program: &arr[2],
+            pool_state: &arr[3],
+            lst_mint: &arr[4],
+            protocol_fee_beneficiary_list: &arr[5],
+        }
+    }
+}
+pub const DISTRIBUTE_PROTOCOL_FEES_IX_DISCM: u8 = 56u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistributeProtocolFeesIxData;
+impl DistributeProtocolFeesIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DISTRIBUTE_PROTOCOL_FEES_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DISTRIBUTE_PROTOCOL_FEES_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[DISTRIBUTE_PROTOCOL_FEES_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn distribute_protocol_fees_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DistributeProtocolFeesKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DistributeProtocolFeesIxData.try_to_vec()?,
+    })
+}
+pub fn distribute_protocol_fees_ix(
+    keys: DistributeProtocolFeesKeys,
+) -> std::io::Result<Instruction> {
+    distribute_protocol_fees_ix_with_program_id(crate::ID, keys)
+}
+pub fn distribute_protocol_fees_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DistributeProtocolFeesAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: DistributeProtocolFeesKeys = accounts.into();
+    let ix = distribute_protocol_fees_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn distribute_protocol_fees_invoke(
+    accounts: DistributeProtocolFeesAccounts<'_, '_>,
+) -> ProgramResult {
+    distribute_protocol_fees_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn distribute_protocol_fees_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DistributeProtocolFeesAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DistributeProtocolFeesKeys = accounts.into();
+    let ix = distribute_protocol_fees_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn distribute_protocol_fees_invoke_signed(
+    accounts: DistributeProtocolFeesAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    distribute_protocol_fees_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn distribute_protocol_fees_verify_account_keys(
+    accounts: DistributeProtocolFeesAccounts<'_, '_>,
+    keys: DistributeProtocolFeesKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (
+            accounts.protocol_fee_accumulator_auth.key,
+            &keys.protocol_fee_accumulator_auth,
+        ),
+        (accounts.token_program.key, &keys.token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (
+            accounts.protocol_fee_beneficiary_list.key,
+            &keys.protocol_fee_beneficiary_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn distribute_protocol_fees_verify_writable_privileges<'me, 'info>(
+    accounts: DistributeProtocolFeesAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.protocol_fee_accumulator] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn distribute_protocol_fees_verify_account_privileges<'me, 'info>(
+    accounts: DistributeProtocolFeesAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    distribute_protocol_fees_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolFeesAsLpAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of the pool's LP token mint. Must have been created if enabling
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolFeesAsLpKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of the pool's LP token mint. Must have been created if enabling
+    pub protocol_fee_lp_acc: Pubkey,
+}
+impl From<SetProtocolFeesAsLpAccounts<'_, '_>> for SetProtocolFeesAsLpKeys {
+    fn from(accounts: SetProtocolFeesAsLpAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+        }
+    }
+}
+impl From<SetProtocolFeesAsLpKeys> for [AccountMeta; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetProtocolFeesAsLpKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN]> for SetProtocolFeesAsLpKeys {
+    fn from(pubkeys: [Pubkey; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+            protocol_fee_lp_acc: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<SetProtocolFeesAsLpAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetProtocolFeesAsLpAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN]>
+    for SetProtocolFeesAsLpAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+            protocol_fee_lp_acc: &arr[5],
+        }
+    }
+}
+pub const SET_PROTOCOL_FEES_AS_LP_IX_DISCM: u8 = 57u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetProtocolFeesAsLpIxArgs {
+    pub protocol_fees_as_lp: bool,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetProtocolFeesAsLpIxData(pub SetProtocolFeesAsLpIxArgs);
+impl From<SetProtocolFeesAsLpIxArgs> for SetProtocolFeesAsLpIxData {
+    fn from(args: SetProtocolFeesAsLpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetProtocolFeesAsLpIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_PROTOCOL_FEES_AS_LP_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_PROTOCOL_FEES_AS_LP_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetProtocolFeesAsLpIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_PROTOCOL_FEES_AS_LP_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_protocol_fees_as_lp_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetProtocolFeesAsLpKeys,
+    args: SetProtocolFeesAsLpIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PROTOCOL_FEES_AS_LP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetProtocolFeesAsLpIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_protocol_fees_as_lp_ix(
+    keys: SetProtocolFeesAsLpKeys,
+    args: SetProtocolFeesAsLpIxArgs,
+) -> std::io::Result<Instruction> {
+    set_protocol_fees_as_lp_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_protocol_fees_as_lp_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeesAsLpAccounts<'_, '_>,
+    args: SetProtocolFeesAsLpIxArgs,
+) -> ProgramResult {
+    let keys: SetProtocolFeesAsLpKeys = accounts.into();
+    let ix = set_protocol_fees_as_lp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_protocol_fees_as_lp_invoke(
+    accounts: SetProtocolFeesAsLpAccounts<'_, '_>,
+    args: SetProtocolFeesAsLpIxArgs,
+) -> ProgramResult {
+    set_protocol_fees_as_lp_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_protocol_fees_as_lp_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeesAsLpAccounts<'_, '_>,
+    args: SetProtocolFeesAsLpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetProtocolFeesAsLpKeys = accounts.into();
+    let ix = set_protocol_fees_as_lp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_protocol_fees_as_lp_invoke_signed(
+    accounts: SetProtocolFeesAsLpAccounts<'_, '_>,
+    args: SetProtocolFeesAsLpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_protocol_fees_as_lp_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_protocol_fees_as_lp_verify_account_keys(
+    accounts: SetProtocolFeesAsLpAccounts<'_, '_>,
+    keys: SetProtocolFeesAsLpKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fees_as_lp_verify_writable_privileges<'me, 'info>(
+    accounts: SetProtocolFeesAsLpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fees_as_lp_verify_signer_privileges<'me, 'info>(
+    accounts: SetProtocolFeesAsLpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_protocol_fees_as_lp_verify_account_privileges<'me, 'info>(
+    accounts: SetProtocolFeesAsLpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_protocol_fees_as_lp_verify_writable_privileges(accounts)?;
+    set_protocol_fees_as_lp_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone, Debug)]
+pub struct ConvertProtocolFeesToLpAccounts<'me, 'info> {
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The LST's protocol fee accumulator token account to convert the entire balance of
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ConvertProtocolFeesToLpKeys {
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///The LST's protocol fee accumulator token account to convert the entire balance of
+    pub protocol_fee_accumulator: Pubkey,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///The protocol fee LP token account, the PROTOCOL_FEE PDA's ATA of lp_token_mint
+    pub protocol_fee_lp_acc: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+}
+impl From<ConvertProtocolFeesToLpAccounts<'_, '_>> for ConvertProtocolFeesToLpKeys {
+    fn from(accounts: ConvertProtocolFeesToLpAccounts) -> Self {
+        Self {
+            lst_mint: *accounts.lst_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            protocol_fee_accumulator_auth: *accounts.protocol_fee_accumulator_auth.key,
+            pool_reserves: *accounts.pool_reserves.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_lp_acc: *accounts.protocol_fee_lp_acc.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_config: *accounts.pool_config.key,
+        }
+    }
+}
+impl From<ConvertProtocolFeesToLpKeys>
+    for [AccountMeta; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: ConvertProtocolFeesToLpKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator_auth,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]> for ConvertProtocolFeesToLpKeys {
+    fn from(pubkeys: [Pubkey; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: pubkeys[0],
+            protocol_fee_accumulator: pubkeys[1],
+            protocol_fee_accumulator_auth: pubkeys[2],
+            pool_reserves: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            protocol_fee_lp_acc: pubkeys[5],
+            lst_token_program: pubkeys[6],
+            lp_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            pool_config: pubkeys[10],
+        }
+    }
+}
+impl<'info> From<ConvertProtocolFeesToLpAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ConvertProtocolFeesToLpAccounts<'_, 'info>) -> Self {
+        [
+            accounts.lst_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.protocol_fee_accumulator_auth.clone(),
+            accounts.pool_reserves.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_lp_acc.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_config.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]>
+    for ConvertProtocolFeesToLpAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            lst_mint: &arr[0],
+            protocol_fee_accumulator: &arr[1],
+            protocol_fee_accumulator_auth: &arr[2],
+            pool_reserves: &arr[3],
+            lp_token_mint: &arr[4],
+            protocol_fee_lp_acc: &arr[5],
+            lst_token_program: &arr[6],
+            lp_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            pool_config: &arr[10],
+        }
+    }
+}
+pub const CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM: u8 = 58u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvertProtocolFeesToLpIxArgs {
+    pub lst_index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvertProtocolFeesToLpIxData(pub ConvertProtocolFeesToLpIxArgs);
+impl From<ConvertProtocolFeesToLpIxArgs> for ConvertProtocolFeesToLpIxData {
+    fn from(args: ConvertProtocolFeesToLpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ConvertProtocolFeesToLpIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ConvertProtocolFeesToLpIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[CONVERT_PROTOCOL_FEES_TO_LP_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn convert_protocol_fees_to_lp_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ConvertProtocolFeesToLpKeys,
+    args: ConvertProtocolFeesToLpIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ConvertProtocolFeesToLpIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn convert_protocol_fees_to_lp_ix(
+    keys: ConvertProtocolFeesToLpKeys,
+    args: ConvertProtocolFeesToLpIxArgs,
+) -> std::io::Result<Instruction> {
+    convert_protocol_fees_to_lp_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn convert_protocol_fees_to_lp_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ConvertProtocolFeesToLpAccounts<'_, '_>,
+    args: ConvertProtocolFeesToLpIxArgs,
+) -> ProgramResult {
+    let keys: ConvertProtocolFeesToLpKeys = accounts.into();
+    let ix = convert_protocol_fees_to_lp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn convert_protocol_fees_to_lp_invoke(
+    accounts: ConvertProtocolFeesToLpAccounts<'_, '_>,
+    args: ConvertProtocolFeesToLpIxArgs,
+) -> ProgramResult {
+    convert_protocol_fees_to_lp_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn convert_protocol_fees_to_lp_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ConvertProtocolFeesToLpAccounts<'_, '_>,
+    args: ConvertProtocolFeesToLpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ConvertProtocolFeesToLpKeys = accounts.into();
+    let ix = convert_protocol_fees_to_lp_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn convert_protocol_fees_to_lp_invoke_signed(
+    accounts: ConvertProtocolFeesToLpAccounts<'_, '_>,
+    args: ConvertProtocolFeesToLpIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    convert_protocol_fees_to_lp_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn convert_protocol_fees_to_lp_verify_account_keys(
+    accounts: ConvertProtocolFeesToLpAccounts<'_, '_>,
+    keys: ConvertProtocolFeesToLpKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (
+            accounts.protocol_fee_accumulator_auth.key,
+            &keys.protocol_fee_accumulator_auth,
+        ),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.protocol_fee_lp_acc.key, &keys.protocol_fee_lp_acc),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_config.key, &keys.pool_config),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn convert_protocol_fees_to_lp_verify_writable_privileges<'me, 'info>(
+    accounts: ConvertProtocolFeesToLpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.protocol_fee_accumulator,
+        accounts.protocol_fee_accumulator_auth,
+        accounts.pool_reserves,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_lp_acc,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn convert_protocol_fees_to_lp_verify_account_privileges<'me, 'info>(
+    accounts: ConvertProtocolFeesToLpAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    convert_protocol_fees_to_lp_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstCircuitBreakerAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to set the circuit breaker threshold for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstCircuitBreakerKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to set the circuit breaker threshold for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SetLstCircuitBreakerAccounts<'_, '_>> for SetLstCircuitBreakerKeys {
+    fn from(accounts: SetLstCircuitBreakerAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SetLstCircuitBreakerKeys> for [AccountMeta; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstCircuitBreakerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN]> for SetLstCircuitBreakerKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLstCircuitBreakerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstCircuitBreakerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN]>
+    for SetLstCircuitBreakerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const SET_LST_CIRCUIT_BREAKER_IX_DISCM: u8 = 59u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstCircuitBreakerIxArgs {
+    pub index: u32,
+    pub sol_value_rate_circuit_breaker_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstCircuitBreakerIxData(pub SetLstCircuitBreakerIxArgs);
+impl From<SetLstCircuitBreakerIxArgs> for SetLstCircuitBreakerIxData {
+    fn from(args: SetLstCircuitBreakerIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstCircuitBreakerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_CIRCUIT_BREAKER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_CIRCUIT_BREAKER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstCircuitBreakerIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_CIRCUIT_BREAKER_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_circuit_breaker_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstCircuitBreakerKeys,
+    args: SetLstCircuitBreakerIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_CIRCUIT_BREAKER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstCircuitBreakerIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_circuit_breaker_ix(
+    keys: SetLstCircuitBreakerKeys,
+    args: SetLstCircuitBreakerIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lst_circuit_breaker_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_circuit_breaker_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstCircuitBreakerAccounts<'_, '_>,
+    args: SetLstCircuitBreakerIxArgs,
+) -> ProgramResult {
+    let keys: SetLstCircuitBreakerKeys = accounts.into();
+    let ix = set_lst_circuit_breaker_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_circuit_breaker_invoke(
+    accounts: SetLstCircuitBreakerAccounts<'_, '_>,
+    args: SetLstCircuitBreakerIxArgs,
+) -> ProgramResult {
+    set_lst_circuit_breaker_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_circuit_breaker_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstCircuitBreakerAccounts<'_, '_>,
+    args: SetLstCircuitBreakerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstCircuitBreakerKeys = accounts.into();
+    let ix = set_lst_circuit_breaker_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_circuit_breaker_invoke_signed(
+    accounts: SetLstCircuitBreakerAccounts<'_, '_>,
+    args: SetLstCircuitBreakerIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_circuit_breaker_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_circuit_breaker_verify_account_keys(
+    accounts: SetLstCircuitBreakerAccounts<'_, '_>,
+    keys: SetLstCircuitBreakerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_circuit_breaker_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstCircuitBreakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_circuit_breaker_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstCircuitBreakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_circuit_breaker_verify_account_privileges<'me, 'info>(
+    accounts: SetLstCircuitBreakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_circuit_breaker_verify_writable_privileges(accounts)?;
+    set_lst_circuit_breaker_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpSyncRequirementAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpSyncRequirementKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetLpSyncRequirementAccounts<'_, '_>> for SetLpSyncRequirementKeys {
+    fn from(accounts: SetLpSyncRequirementAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetLpSyncRequirementKeys> for [AccountMeta; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLpSyncRequirementKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN]> for SetLpSyncRequirementKeys {
+    fn from(pubkeys: [Pubkey; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetLpSyncRequirementAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLpSyncRequirementAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN]>
+    for SetLpSyncRequirementAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_LP_SYNC_REQUIREMENT_IX_DISCM: u8 = 60u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpSyncRequirementIxArgs {
+    pub lp_requires_synced_lsts: bool,
+    pub lp_synced_lsts_dust_threshold: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLpSyncRequirementIxData(pub SetLpSyncRequirementIxArgs);
+impl From<SetLpSyncRequirementIxArgs> for SetLpSyncRequirementIxData {
+    fn from(args: SetLpSyncRequirementIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLpSyncRequirementIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LP_SYNC_REQUIREMENT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LP_SYNC_REQUIREMENT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLpSyncRequirementIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LP_SYNC_REQUIREMENT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lp_sync_requirement_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLpSyncRequirementKeys,
+    args: SetLpSyncRequirementIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LP_SYNC_REQUIREMENT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLpSyncRequirementIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_sync_requirement_ix(
+    keys: SetLpSyncRequirementKeys,
+    args: SetLpSyncRequirementIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lp_sync_requirement_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lp_sync_requirement_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpSyncRequirementAccounts<'_, '_>,
+    args: SetLpSyncRequirementIxArgs,
+) -> ProgramResult {
+    let keys: SetLpSyncRequirementKeys = accounts.into();
+    let ix = set_lp_sync_requirement_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lp_sync_requirement_invoke(
+    accounts: SetLpSyncRequirementAccounts<'_, '_>,
+    args: SetLpSyncRequirementIxArgs,
+) -> ProgramResult {
+    set_lp_sync_requirement_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lp_sync_requirement_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpSyncRequirementAccounts<'_, '_>,
+    args: SetLpSyncRequirementIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLpSyncRequirementKeys = accounts.into();
+    let ix = set_lp_sync_requirement_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lp_sync_requirement_invoke_signed(
+    accounts: SetLpSyncRequirementAccounts<'_, '_>,
+    args: SetLpSyncRequirementIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lp_sync_requirement_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lp_sync_requirement_verify_account_keys(
+    accounts: SetLpSyncRequirementAccounts<'_, '_>,
+    keys: SetLpSyncRequirementKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_sync_requirement_verify_writable_privileges<'me, 'info>(
+    accounts: SetLpSyncRequirementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_sync_requirement_verify_signer_privileges<'me, 'info>(
+    accounts: SetLpSyncRequirementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_sync_requirement_verify_account_privileges<'me, 'info>(
+    accounts: SetLpSyncRequirementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lp_sync_requirement_verify_writable_privileges(accounts)?;
+    set_lp_sync_requirement_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MIGRATE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateAccounts<'me, 'info> {
+    ///The pool's admin. Pays for the additional rent of the reallocated accounts
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MigrateKeys {
+    ///The pool's admin. Pays for the additional rent of the reallocated accounts
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<MigrateAccounts<'_, '_>> for MigrateKeys {
+    fn from(accounts: MigrateAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<MigrateKeys> for [AccountMeta; MIGRATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: MigrateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MIGRATE_IX_ACCOUNTS_LEN]> for MigrateKeys {
+    fn from(pubkeys: [Pubkey; MIGRATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            lst_state_list: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<MigrateAccounts<'_, 'info>> for [AccountInfo<'info>; MIGRATE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: MigrateAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MIGRATE_IX_ACCOUNTS_LEN]>
+    for MigrateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MIGRATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            lst_state_list: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const MIGRATE_IX_DISCM: u8 = 61u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrateIxData;
+impl MigrateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MIGRATE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MIGRATE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[MIGRATE_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn migrate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MigrateKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MIGRATE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: MigrateIxData.try_to_vec()?,
+    })
+}
+pub fn migrate_ix(keys: MigrateKeys) -> std::io::Result<Instruction> {
+    migrate_ix_with_program_id(crate::ID, keys)
+}
+pub fn migrate_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: MigrateKeys = accounts.into();
+    let ix = migrate_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn migrate_invoke(accounts: MigrateAccounts<'_, '_>) -> ProgramResult {
+    migrate_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn migrate_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MigrateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MigrateKeys = accounts.into();
+    let ix = migrate_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn migrate_invoke_signed(
+    accounts: MigrateAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    migrate_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn migrate_verify_account_keys(
+    accounts: MigrateAccounts<'_, '_>,
+    keys: MigrateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_verify_writable_privileges<'me, 'info>(
+    accounts: MigrateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.admin, accounts.pool_state, accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_verify_signer_privileges<'me, 'info>(
+    accounts: MigrateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn migrate_verify_account_privileges<'me, 'info>(
+    accounts: MigrateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    migrate_verify_writable_privileges(accounts)?;
+    migrate_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Account paying for the metadata account's rent
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA. The LP token's mint authority and metadata update authority
+    pub pool_state: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The LP token mint itself for token-2022 LP token mints, the LP token mint's Metaplex metadata PDA for Tokenkeg LP token mints
+    pub metadata: &'me AccountInfo<'info>,
+    ///Token-2022 program for token-2022 LP token mints, Metaplex token metadata program for Tokenkeg LP token mints
+    pub metadata_program: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Account paying for the metadata account's rent
+    pub payer: Pubkey,
+    ///The pool's state singleton PDA. The LP token's mint authority and metadata update authority
+    pub pool_state: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program
+    pub lp_token_program: Pubkey,
+    ///The LP token mint itself for token-2022 LP token mints, the LP token mint's Metaplex metadata PDA for Tokenkeg LP token mints
+    pub metadata: Pubkey,
+    ///Token-2022 program for token-2022 LP token mints, Metaplex token metadata program for Tokenkeg LP token mints
+    pub metadata_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetLpTokenMetadataAccounts<'_, '_>> for SetLpTokenMetadataKeys {
+    fn from(accounts: SetLpTokenMetadataAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            payer: *accounts.payer.key,
+            pool_state: *accounts.pool_state.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            metadata: *accounts.metadata.key,
+            metadata_program: *accounts.metadata_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetLpTokenMetadataKeys> for [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLpTokenMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]> for SetLpTokenMetadataKeys {
+    fn from(pubkeys: [Pubkey; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            payer: pubkeys[1],
+            pool_state: pubkeys[2],
+            lp_token_mint: pubkeys[3],
+            lp_token_program: pubkeys[4],
+            metadata: pubkeys[5],
+            metadata_program: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<SetLpTokenMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLpTokenMetadataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.payer.clone(),
+            accounts.pool_state.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.metadata.clone(),
+            accounts.metadata_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]>
+    for SetLpTokenMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            payer: &arr[1],
+            pool_state: &arr[2],
+            lp_token_mint: &arr[3],
+            lp_token_program: &arr[4],
+            metadata: &arr[5],
+            metadata_program: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const SET_LP_TOKEN_METADATA_IX_DISCM: u8 = 62u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpTokenMetadataIxArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLpTokenMetadataIxData(pub SetLpTokenMetadataIxArgs);
+impl From<SetLpTokenMetadataIxArgs> for SetLpTokenMetadataIxData {
+    fn from(args: SetLpTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLpTokenMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LP_TOKEN_METADATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LP_TOKEN_METADATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLpTokenMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LP_TOKEN_METADATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lp_token_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLpTokenMetadataKeys,
+    args: SetLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLpTokenMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_token_metadata_ix(
+    keys: SetLpTokenMetadataKeys,
+    args: SetLpTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    set_lp_token_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lp_token_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let keys: SetLpTokenMetadataKeys = accounts.into();
+    let ix = set_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lp_token_metadata_invoke(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    set_lp_token_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lp_token_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLpTokenMetadataKeys = accounts.into();
+    let ix = set_lp_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lp_token_metadata_invoke_signed(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    args: SetLpTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lp_token_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lp_token_metadata_verify_account_keys(
+    accounts: SetLpTokenMetadataAccounts<'_, '_>,
+    keys: SetLpTokenMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.metadata.key, &keys.metadata),
+        (accounts.metadata_program.key, &keys.metadata_program),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.lp_token_mint, accounts.metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lp_token_metadata_verify_account_privileges<'me, 'info>(
+    accounts: SetLpTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lp_token_metadata_verify_writable_privileges(accounts)?;
+    set_lp_token_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddRebalanceAuthorityAccounts<'me, 'info> {
+    ///Account paying for additional rent for realloc
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The new rebalance authority to add
+    pub new_rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddRebalanceAuthorityKeys {
+    ///Account paying for additional rent for realloc
+    pub payer: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The new rebalance authority to add
+    pub new_rebalance_authority: Pubkey,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<AddRebalanceAuthorityAccounts<'_, '_>> for AddRebalanceAuthorityKeys {
+    fn from(accounts: AddRebalanceAuthorityAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            new_rebalance_authority: *accounts.new_rebalance_authority.key,
+            rebalance_authority_list: *accounts.rebalance_authority_list.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<AddRebalanceAuthorityKeys> for [AccountMeta; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddRebalanceAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_rebalance_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]> for AddRebalanceAuthorityKeys {
+    fn from(pubkeys: [Pubkey; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            admin: pubkeys[1],
+            pool_state: pubkeys[2],
+            new_rebalance_authority: pubkeys[3],
+            rebalance_authority_list: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddRebalanceAuthorityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddRebalanceAuthorityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.new_rebalance_authority.clone(),
+            accounts.rebalance_authority_list.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]>
+    for AddRebalanceAuthorityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            admin: &arr[1],
+            pool_state: &arr[2],
+            new_rebalance_authority: &arr[3],
+            rebalance_authority_list: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+pub const ADD_REBALANCE_AUTHORITY_IX_DISCM: u8 = 63u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddRebalanceAuthorityIxArgs {
+    pub epoch_budget: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddRebalanceAuthorityIxData(pub AddRebalanceAuthorityIxArgs);
+impl From<AddRebalanceAuthorityIxArgs> for AddRebalanceAuthorityIxData {
+    fn from(args: AddRebalanceAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddRebalanceAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_REBALANCE_AUTHORITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_REBALANCE_AUTHORITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddRebalanceAuthorityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_REBALANCE_AUTHORITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_rebalance_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddRebalanceAuthorityKeys,
+    args: AddRebalanceAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddRebalanceAuthorityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_rebalance_authority_ix(
+    keys: AddRebalanceAuthorityKeys,
+    args: AddRebalanceAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    add_rebalance_authority_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_rebalance_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddRebalanceAuthorityAccounts<'_, '_>,
+    args: AddRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    let keys: AddRebalanceAuthorityKeys = accounts.into();
+    let ix = add_rebalance_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_rebalance_authority_invoke(
+    accounts: AddRebalanceAuthorityAccounts<'_, '_>,
+    args: AddRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    add_rebalance_authority_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_rebalance_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddRebalanceAuthorityAccounts<'_, '_>,
+    args: AddRebalanceAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddRebalanceAuthorityKeys = accounts.into();
+    let ix = add_rebalance_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_rebalance_authority_invoke_signed(
+    accounts: AddRebalanceAuthorityAccounts<'_, '_>,
+    args: AddRebalanceAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_rebalance_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_rebalance_authority_verify_account_keys(
+    accounts: AddRebalanceAuthorityAccounts<'_, '_>,
+    keys: AddRebalanceAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.new_rebalance_authority.key,
+            &keys.new_rebalance_authority,
+        ),
+        (
+            accounts.rebalance_authority_list.key,
+            &keys.rebalance_authority_list,
+        ),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_authority_verify_writable_privileges<'me, 'info>(
+    accounts: AddRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.rebalance_authority_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_authority_verify_signer_privileges<'me, 'info>(
+    accounts: AddRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_rebalance_authority_verify_account_privileges<'me, 'info>(
+    accounts: AddRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_rebalance_authority_verify_writable_privileges(accounts)?;
+    add_rebalance_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveRebalanceAuthorityAccounts<'me, 'info> {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The rebalance authority to remove
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveRebalanceAuthorityKeys {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The rebalance authority to remove
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: Pubkey,
+}
+impl From<RemoveRebalanceAuthorityAccounts<'_, '_>> for RemoveRebalanceAuthorityKeys {
+    fn from(accounts: RemoveRebalanceAuthorityAccounts) -> Self {
+        Self {
+            refund_rent_to: *accounts.refund_rent_to.key,
+            admin: *accounts.admin.key,
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            rebalance_authority_list: *accounts.rebalance_authority_list.key,
+        }
+    }
+}
+impl From<RemoveRebalanceAuthorityKeys>
+    for [AccountMeta; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: RemoveRebalanceAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]> for RemoveRebalanceAuthorityKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: pubkeys[0],
+            admin: pubkeys[1],
+            rebalance_authority: pubkeys[2],
+            pool_state: pubkeys[3],
+            rebalance_authority_list: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<RemoveRebalanceAuthorityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveRebalanceAuthorityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.refund_rent_to.clone(),
+            accounts.admin.clone(),
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.rebalance_authority_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]>
+    for RemoveRebalanceAuthorityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: &arr[0],
+            admin: &arr[1],
+            rebalance_authority: &arr[2],
+            pool_state: &arr[3],
+            rebalance_authority_list: &arr[4],
+        }
+    }
+}
+pub const REMOVE_REBALANCE_AUTHORITY_IX_DISCM: u8 = 64u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveRebalanceAuthorityIxArgs {
+    pub index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveRebalanceAuthorityIxData(pub RemoveRebalanceAuthorityIxArgs);
+impl From<RemoveRebalanceAuthorityIxArgs> for RemoveRebalanceAuthorityIxData {
+    fn from(args: RemoveRebalanceAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveRebalanceAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_REBALANCE_AUTHORITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_REBALANCE_AUTHORITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveRebalanceAuthorityIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_REBALANCE_AUTHORITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_rebalance_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveRebalanceAuthorityKeys,
+    args: RemoveRebalanceAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_REBALANCE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveRebalanceAuthorityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_rebalance_authority_ix(
+    keys: RemoveRebalanceAuthorityKeys,
+    args: RemoveRebalanceAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_rebalance_authority_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_rebalance_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveRebalanceAuthorityAccounts<'_, '_>,
+    args: RemoveRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    let keys: RemoveRebalanceAuthorityKeys = accounts.into();
+    let ix = remove_rebalance_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_rebalance_authority_invoke(
+    accounts: RemoveRebalanceAuthorityAccounts<'_, '_>,
+    args: RemoveRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    remove_rebalance_authority_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_rebalance_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveRebalanceAuthorityAccounts<'_, '_>,
+    args: RemoveRebalanceAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveRebalanceAuthorityKeys = accounts.into();
+    let ix = remove_rebalance_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_rebalance_authority_invoke_signed(
+    accounts: RemoveRebalanceAuthorityAccounts<'_, '_>,
+    args: RemoveRebalanceAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_rebalance_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_rebalance_authority_verify_account_keys(
+    accounts: RemoveRebalanceAuthorityAccounts<'_, '_>,
+    keys: RemoveRebalanceAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.admin.key, &keys.admin),
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.rebalance_authority_list.key,
+            &keys.rebalance_authority_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_rebalance_authority_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.refund_rent_to, accounts.rebalance_authority_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_rebalance_authority_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_rebalance_authority_verify_account_privileges<'me, 'info>(
+    accounts: RemoveRebalanceAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_rebalance_authority_verify_writable_privileges(accounts)?;
+    remove_rebalance_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetRebalanceAuthorityBudgetAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The rebalance authority to set the budget of
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetRebalanceAuthorityBudgetKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The rebalance authority to set the budget of
+    pub rebalance_authority: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's rebalance authority list singleton PDA
+    pub rebalance_authority_list: Pubkey,
+}
+impl From<SetRebalanceAuthorityBudgetAccounts<'_, '_>> for SetRebalanceAuthorityBudgetKeys {
+    fn from(accounts: SetRebalanceAuthorityBudgetAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            rebalance_authority: *accounts.rebalance_authority.key,
+            pool_state: *accounts.pool_state.key,
+            rebalance_authority_list: *accounts.rebalance_authority_list.key,
+        }
+    }
+}
+impl From<SetRebalanceAuthorityBudgetKeys>
+    for [AccountMeta; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetRebalanceAuthorityBudgetKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_authority_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]>
+    for SetRebalanceAuthorityBudgetKeys
+{
+    fn from(pubkeys: [Pubkey; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            rebalance_authority: pubkeys[1],
+            pool_state: pubkeys[2],
+            rebalance_authority_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetRebalanceAuthorityBudgetAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetRebalanceAuthorityBudgetAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.rebalance_authority.clone(),
+            accounts.pool_state.clone(),
+            accounts.rebalance_authority_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]>
+    for SetRebalanceAuthorityBudgetAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            admin: &arr[0],
+            rebalance_authority: &arr[1],
+            pool_state: &arr[2],
+            rebalance_authority_list: &arr[3],
+        }
+    }
+}
+pub const SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM: u8 = 65u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetRebalanceAuthorityBudgetIxArgs {
+    pub index: u32,
+    pub epoch_budget: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetRebalanceAuthorityBudgetIxData(pub SetRebalanceAuthorityBudgetIxArgs);
+impl From<SetRebalanceAuthorityBudgetIxArgs> for SetRebalanceAuthorityBudgetIxData {
+    fn from(args: SetRebalanceAuthorityBudgetIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetRebalanceAuthorityBudgetIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetRebalanceAuthorityBudgetIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_REBALANCE_AUTHORITY_BUDGET_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_rebalance_authority_budget_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetRebalanceAuthorityBudgetKeys,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetRebalanceAuthorityBudgetIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_rebalance_authority_budget_ix(
+    keys: SetRebalanceAuthorityBudgetKeys,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+) -> std::io::Result<Instruction> {
+    set_rebalance_authority_budget_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_rebalance_authority_budget_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    let keys: SetRebalanceAuthorityBudgetKeys = accounts.into();
+    let ix = set_rebalance_authority_budget_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_rebalance_authority_budget_invoke(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    set_rebalance_authority_budget_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_rebalance_authority_budget_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetRebalanceAuthorityBudgetKeys = accounts.into();
+    let ix = set_rebalance_authority_budget_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_rebalance_authority_budget_invoke_signed(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetRebalanceAuthorityBudgetIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_rebalance_authority_budget_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_rebalance_authority_budget_verify_account_keys(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'_, '_>,
+    keys: SetRebalanceAuthorityBudgetKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.rebalance_authority.key, &keys.rebalance_authority),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.rebalance_authority_list.key,
+            &keys.rebalance_authority_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_authority_budget_verify_writable_privileges<'me, 'info>(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.rebalance_authority_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_authority_budget_verify_signer_privileges<'me, 'info>(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_authority_budget_verify_account_privileges<'me, 'info>(
+    accounts: SetRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_rebalance_authority_budget_verify_writable_privileges(accounts)?;
+    set_rebalance_authority_budget_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolRebalanceAuthorityBudgetAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolRebalanceAuthorityBudgetKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>> for SetPoolRebalanceAuthorityBudgetKeys {
+    fn from(accounts: SetPoolRebalanceAuthorityBudgetAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetPoolRebalanceAuthorityBudgetKeys>
+    for [AccountMeta; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: SetPoolRebalanceAuthorityBudgetKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]>
+    for SetPoolRebalanceAuthorityBudgetKeys
+{
+    fn from(pubkeys: [Pubkey; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetPoolRebalanceAuthorityBudgetAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN]>
+    for SetPoolRebalanceAuthorityBudgetAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM: u8 = 66u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPoolRebalanceAuthorityBudgetIxArgs {
+    pub epoch_budget: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPoolRebalanceAuthorityBudgetIxData(pub SetPoolRebalanceAuthorityBudgetIxArgs);
+impl From<SetPoolRebalanceAuthorityBudgetIxArgs> for SetPoolRebalanceAuthorityBudgetIxData {
+    fn from(args: SetPoolRebalanceAuthorityBudgetIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPoolRebalanceAuthorityBudgetIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetPoolRebalanceAuthorityBudgetIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_pool_rebalance_authority_budget_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetPoolRebalanceAuthorityBudgetKeys,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_POOL_REBALANCE_AUTHORITY_BUDGET_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPoolRebalanceAuthorityBudgetIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pool_rebalance_authority_budget_ix(
+    keys: SetPoolRebalanceAuthorityBudgetKeys,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+) -> std::io::Result<Instruction> {
+    set_pool_rebalance_authority_budget_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_pool_rebalance_authority_budget_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    let keys: SetPoolRebalanceAuthorityBudgetKeys = accounts.into();
+    let ix = set_pool_rebalance_authority_budget_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_pool_rebalance_authority_budget_invoke(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    set_pool_rebalance_authority_budget_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_pool_rebalance_authority_budget_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetPoolRebalanceAuthorityBudgetKeys = accounts.into();
+    let ix = set_pool_rebalance_authority_budget_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_pool_rebalance_authority_budget_invoke_signed(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>,
+    args: SetPoolRebalanceAuthorityBudgetIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_pool_rebalance_authority_budget_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn set_pool_rebalance_authority_budget_verify_account_keys(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'_, '_>,
+    keys: SetPoolRebalanceAuthorityBudgetKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_pool_rebalance_authority_budget_verify_writable_privileges<'me, 'info>(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_pool_rebalance_authority_budget_verify_signer_privileges<'me, 'info>(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_pool_rebalance_authority_budget_verify_account_privileges<'me, 'info>(
+    accounts: SetPoolRebalanceAuthorityBudgetAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_pool_rebalance_authority_budget_verify_writable_privileges(accounts)?;
+    set_pool_rebalance_authority_budget_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetMaxRebalanceLossAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: &'me AccountInfo<'info>,
+    ///The PoolConfig singleton PDA
+    pub pool_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetMaxRebalanceLossKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Account paying for the PoolConfig PDA's rent if it has not been created yet
+    pub payer: Pubkey,
+    ///The PoolConfig singleton PDA
+    pub pool_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetMaxRebalanceLossAccounts<'_, '_>> for SetMaxRebalanceLossKeys {
+    fn from(accounts: SetMaxRebalanceLossAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            payer: *accounts.payer.key,
+            pool_config: *accounts.pool_config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetMaxRebalanceLossKeys> for [AccountMeta; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetMaxRebalanceLossKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]> for SetMaxRebalanceLossKeys {
+    fn from(pubkeys: [Pubkey; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+            payer: pubkeys[2],
+            pool_config: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetMaxRebalanceLossAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetMaxRebalanceLossAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.pool_config.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]>
+    for SetMaxRebalanceLossAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+            payer: &arr[2],
+            pool_config: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_MAX_REBALANCE_LOSS_IX_DISCM: u8 = 67u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMaxRebalanceLossIxArgs {
+    pub max_rebalance_loss_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetMaxRebalanceLossIxData(pub SetMaxRebalanceLossIxArgs);
+impl From<SetMaxRebalanceLossIxArgs> for SetMaxRebalanceLossIxData {
+    fn from(args: SetMaxRebalanceLossIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetMaxRebalanceLossIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_MAX_REBALANCE_LOSS_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_MAX_REBALANCE_LOSS_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetMaxRebalanceLossIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_MAX_REBALANCE_LOSS_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_max_rebalance_loss_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetMaxRebalanceLossKeys,
+    args: SetMaxRebalanceLossIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_MAX_REBALANCE_LOSS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetMaxRebalanceLossIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_max_rebalance_loss_ix(
+    keys: SetMaxRebalanceLossKeys,
+    args: SetMaxRebalanceLossIxArgs,
+) -> std::io::Result<Instruction> {
+    set_max_rebalance_loss_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_max_rebalance_loss_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+) -> ProgramResult {
+    let keys: SetMaxRebalanceLossKeys = accounts.into();
+    let ix = set_max_rebalance_loss_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_max_rebalance_loss_invoke(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+) -> ProgramResult {
+    set_max_rebalance_loss_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_max_rebalance_loss_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetMaxRebalanceLossKeys = accounts.into();
+    let ix = set_max_rebalance_loss_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_max_rebalance_loss_invoke_signed(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    args: SetMaxRebalanceLossIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_max_rebalance_loss_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_max_rebalance_loss_verify_account_keys(
+    accounts: SetMaxRebalanceLossAccounts<'_, '_>,
+    keys: SetMaxRebalanceLossKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.payer.key, &keys.payer),
+        (accounts.pool_config.key, &keys.pool_config),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_writable_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_signer_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_max_rebalance_loss_verify_account_privileges<'me, 'info>(
+    accounts: SetMaxRebalanceLossAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_max_rebalance_loss_verify_writable_privileges(accounts)?;
+    set_max_rebalance_loss_verify_signer_privileges(accounts)?;
+    Ok(())
+}
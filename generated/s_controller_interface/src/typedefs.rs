@@ -11,7 +11,7 @@ pub struct PoolState {
     pub version: u8,
     pub is_disabled: u8,
     pub is_rebalancing: u8,
-    pub padding: [u8; 1],
+    pub timelock_delay: u8,
     pub admin: Pubkey,
     pub rebalance_authority: Pubkey,
     pub protocol_fee_beneficiary: Pubkey,
@@ -25,10 +25,18 @@ pub struct LstState {
     pub is_input_disabled: u8,
     pub pool_reserves_bump: u8,
     pub protocol_fee_accumulator_bump: u8,
-    pub padding: [u8; 5],
+    pub is_output_disabled: u8,
+    pub flash_loan_fee_bps: u16,
+    pub max_share_bps: u16,
     pub sol_value: u64,
     pub mint: Pubkey,
     pub sol_value_calculator: Pubkey,
+    pub last_sol_value_rate: u64,
+    pub last_sol_value_rate_epoch: u64,
+    pub sol_value_rate_circuit_breaker_bps: u16,
+    pub padding: [u8; 6],
+    pub last_sync_epoch: u64,
+    pub prev_epoch_sol_value_rate: u64,
 }
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
@@ -37,4 +45,86 @@ pub struct RebalanceRecord {
     pub old_total_sol_value: u64,
     pub padding: [u8; 4],
     pub dst_lst_index: u32,
+    pub max_sol_value_loss: u64,
+    pub rebalance_authority: Pubkey,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlashLoanRecord {
+    pub amount: u64,
+    pub fee: u64,
+    pub padding: [u8; 4],
+    pub lst_index: u32,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingAuthorities {
+    pub admin: Pubkey,
+    pub rebalance_authority: Pubkey,
+    pub protocol_fee_beneficiary: Pubkey,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolConfig {
+    pub remove_liquidity_proportional_fee_bps: u16,
+    pub referral_fee_bps: u16,
+    pub protocol_fees_as_lp: u8,
+    pub lp_requires_synced_lsts: u8,
+    pub max_rebalance_loss_bps: u16,
+    pub rebalance_authority_epoch_budget: u64,
+    pub rebalance_authority_last_epoch: u64,
+    pub lp_synced_lsts_dust_threshold: u64,
+    pub rebalance_authority_epoch_spent: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolFeeBeneficiaryShare {
+    pub beneficiary: Pubkey,
+    pub weight_bps: u16,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceAuthorityBudget {
+    pub authority: Pubkey,
+    pub epoch_budget: u64,
+    pub last_epoch: u64,
+    pub epoch_spent: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueuedChange {
+    pub earliest_execution_slot: u64,
+    pub kind: u8,
+    pub bump: u8,
+    pub new_timelock_delay: u8,
+    pub padding: [u8; 5],
+    pub payer: Pubkey,
+    pub lst_mint: Pubkey,
+    pub new_program: Pubkey,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncSolValueMultiEntry {
+    pub lst_index: u32,
+    pub lst_value_calc_accs: u8,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityProportionalEntry {
+    pub lst_value_calc_accs: u8,
+    pub min_lst_out: u64,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityMultiEntry {
+    pub lst_index: u32,
+    pub lst_amount: u64,
+    pub lst_value_calc_accs: u8,
+    pub pricing_accs: u8,
 }
@@ -0,0 +1,144 @@
+use s_controller_interface::{
+    AcceptAdminKeys, AcceptProtocolFeeBeneficiaryKeys, AcceptRebalanceAuthorityKeys,
+    PendingAuthorities, SControllerError,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    try_pending_authorities, PoolKey,
+};
+
+/// Resolves the keys for the AcceptAdmin, AcceptRebalanceAuthority and
+/// AcceptProtocolFeeBeneficiary instructions.
+/// Does not check identity of pending_authorities
+#[derive(Clone, Copy, Debug)]
+pub struct AcceptAuthorityFreeArgs<P> {
+    pub pending_authorities: P,
+}
+
+impl<P: ReadonlyAccountData> AcceptAuthorityFreeArgs<P> {
+    pub fn resolve_admin(&self) -> Result<AcceptAdminKeys, SControllerError> {
+        self.resolve_admin_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
+    }
+
+    pub fn resolve_admin_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AcceptAdminKeys, SControllerError> {
+        self.resolve_admin_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_admin_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<AcceptAdminKeys, SControllerError> {
+        self.resolve_admin_with_pdas(pool.pool_state, pool.pending_authorities_pda().address)
+    }
+
+    pub fn resolve_admin_with_pdas(
+        &self,
+        pool_state: Pubkey,
+        pending_authorities: Pubkey,
+    ) -> Result<AcceptAdminKeys, SControllerError> {
+        let PendingAuthorities { admin, .. } = self.pending()?;
+        Ok(AcceptAdminKeys {
+            pending_admin: verify_pending(admin)?,
+            pool_state,
+            pending_authorities,
+        })
+    }
+
+    pub fn resolve_rebalance_authority(
+        &self,
+    ) -> Result<AcceptRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_rebalance_authority_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
+    }
+
+    pub fn resolve_rebalance_authority_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AcceptRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_rebalance_authority_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_rebalance_authority_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<AcceptRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_rebalance_authority_with_pdas(
+            pool.pool_state,
+            pool.pending_authorities_pda().address,
+        )
+    }
+
+    pub fn resolve_rebalance_authority_with_pdas(
+        &self,
+        pool_state: Pubkey,
+        pending_authorities: Pubkey,
+    ) -> Result<AcceptRebalanceAuthorityKeys, SControllerError> {
+        let PendingAuthorities {
+            rebalance_authority,
+            ..
+        } = self.pending()?;
+        Ok(AcceptRebalanceAuthorityKeys {
+            pending_rebalance_authority: verify_pending(rebalance_authority)?,
+            pool_state,
+            pending_authorities,
+        })
+    }
+
+    pub fn resolve_protocol_fee_beneficiary(
+        &self,
+    ) -> Result<AcceptProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_protocol_fee_beneficiary_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
+    }
+
+    pub fn resolve_protocol_fee_beneficiary_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AcceptProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_protocol_fee_beneficiary_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_protocol_fee_beneficiary_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<AcceptProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_protocol_fee_beneficiary_with_pdas(
+            pool.pool_state,
+            pool.pending_authorities_pda().address,
+        )
+    }
+
+    pub fn resolve_protocol_fee_beneficiary_with_pdas(
+        &self,
+        pool_state: Pubkey,
+        pending_authorities: Pubkey,
+    ) -> Result<AcceptProtocolFeeBeneficiaryKeys, SControllerError> {
+        let PendingAuthorities {
+            protocol_fee_beneficiary,
+            ..
+        } = self.pending()?;
+        Ok(AcceptProtocolFeeBeneficiaryKeys {
+            pending_beneficiary: verify_pending(protocol_fee_beneficiary)?,
+            pool_state,
+            pending_authorities,
+        })
+    }
+
+    fn pending(&self) -> Result<PendingAuthorities, SControllerError> {
+        let pending_authorities_data = self.pending_authorities.data();
+        try_pending_authorities(&pending_authorities_data).copied()
+    }
+}
+
+/// Pubkey::default() in a PendingAuthorities slot means no handoff is pending
+fn verify_pending(pending: Pubkey) -> Result<Pubkey, SControllerError> {
+    if pending == Pubkey::default() {
+        Err(SControllerError::NoPendingAuthority)
+    } else {
+        Ok(pending)
+    }
+}
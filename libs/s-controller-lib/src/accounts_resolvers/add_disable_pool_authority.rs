@@ -2,11 +2,7 @@ use s_controller_interface::{AddDisablePoolAuthorityKeys, SControllerError};
 use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
-    program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
-    try_pool_state,
-};
+use crate::{program::POOL_STATE_ID, try_pool_state, PoolKey};
 
 #[derive(Clone, Copy, Debug)]
 pub struct AddDisablePoolAuthorityFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
@@ -20,23 +16,19 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddDisablePoolAuthorityFree
         if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
             return Err(SControllerError::IncorrectPoolState);
         }
-
-        let pool_state_data = self.pool_state_acc.data();
-        let pool_state = try_pool_state(&pool_state_data)?;
-
-        Ok(AddDisablePoolAuthorityKeys {
-            payer: self.payer,
-            admin: pool_state.admin,
-            pool_state: POOL_STATE_ID,
-            new_authority: self.new_authority,
-            disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
-            system_program: system_program::ID,
-        })
+        self.resolve_for_pool(&PoolKey::DEFAULT)
     }
 
     pub fn resolve_for_prog(
         &self,
         program_id: Pubkey,
+    ) -> Result<AddDisablePoolAuthorityKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
     ) -> Result<AddDisablePoolAuthorityKeys, SControllerError> {
         let pool_state_data = self.pool_state_acc.data();
         let pool_state = try_pool_state(&pool_state_data)?;
@@ -44,9 +36,9 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddDisablePoolAuthorityFree
         Ok(AddDisablePoolAuthorityKeys {
             payer: self.payer,
             admin: pool_state.admin,
-            pool_state: find_pool_state_address(program_id).0,
+            pool_state: pool.pool_state,
             new_authority: self.new_authority,
-            disable_pool_authority_list: find_disable_pool_authority_list_address(program_id).0,
+            disable_pool_authority_list: pool.disable_pool_authority_list_pda().address,
             system_program: system_program::ID,
         })
     }
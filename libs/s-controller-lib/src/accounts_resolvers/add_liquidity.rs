@@ -3,12 +3,12 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address,
+    create_pool_reserves_address_with_pool_state_id,
     create_protocol_fee_accumulator_address_with_protocol_fee_id,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
-    AddRemoveLiquidityProgramIds, SwapLiquidityPdas,
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, AddRemoveLiquidityProgramIds, PoolKey, SwapPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -33,6 +33,10 @@ impl<
     > AddLiquidityFreeArgs<S, L, M>
 {
     pub fn resolve(self) -> Result<AddLiquidityKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<AddLiquidityKeys, SControllerError> {
         let Self {
             lst_index,
             signer,
@@ -42,22 +46,38 @@ impl<
             lst_state_list: lst_state_list_account,
             lst_mint,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let pool_config_id = pool.pool_config_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
-        let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
-        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
-        let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
-
         let pool_state_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
 
         Ok(AddLiquidityKeys {
             signer,
@@ -68,9 +88,11 @@ impl<
             protocol_fee_accumulator,
             lst_token_program: *lst_mint.owner(),
             lp_token_program: spl_token::ID,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
             pool_reserves,
+            pool_config: pool_config_id,
+            protocol_fee_lp_acc,
         })
     }
 }
@@ -103,10 +125,11 @@ impl<
     pub fn resolve(
         self,
     ) -> Result<(AddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError> {
-        self.resolve_with_pdas(SwapLiquidityPdas {
+        self.resolve_with_pdas(SwapPdas {
             pool_state: POOL_STATE_ID,
             lst_state_list: LST_STATE_LIST_ID,
             protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
         })
     }
 
@@ -114,7 +137,14 @@ impl<
         self,
         program_id: Pubkey,
     ) -> Result<(AddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError> {
-        self.resolve_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(AddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError> {
+        self.resolve_with_pdas(SwapPdas::for_pool(pool))
     }
 
     /// Does not check identity of pool_state and lst_state_list
@@ -122,11 +152,12 @@ impl<
     /// (partial instructions keys, index of lst on lst_state_list, additional program IDs)
     pub fn resolve_with_pdas(
         self,
-        SwapLiquidityPdas {
+        SwapPdas {
             pool_state: pool_state_id,
             lst_state_list: lst_state_list_id,
             protocol_fee: protocol_fee_id,
-        }: SwapLiquidityPdas,
+            pool_config: pool_config_id,
+        }: SwapPdas,
     ) -> Result<(AddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError> {
         let Self {
             signer,
@@ -136,9 +167,13 @@ impl<
             lst_state_list: lst_state_list_account,
             lst_mint,
         } = self;
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
         let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
-        let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let (lst_index, lst_state) =
+            try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list)?;
         let pool_reserves = create_pool_reserves_address_with_pool_state_id(
             pool_state_id,
             lst_state,
@@ -150,9 +185,11 @@ impl<
                 lst_state,
                 *lst_mint.owner(),
             )?;
-
-        let pool_state_data = pool_state_account.data();
-        let pool_state = try_pool_state(&pool_state_data)?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
 
         Ok((
             AddLiquidityKeys {
@@ -167,6 +204,8 @@ impl<
                 pool_state: pool_state_id,
                 lst_state_list: lst_state_list_id,
                 pool_reserves,
+                pool_config: pool_config_id,
+                protocol_fee_lp_acc,
             },
             lst_index,
             AddRemoveLiquidityProgramIds {
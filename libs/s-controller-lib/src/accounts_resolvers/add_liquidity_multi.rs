@@ -0,0 +1,252 @@
+use s_controller_interface::{AddLiquidityMultiKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_pool_state,
+    AddRemoveLiquidityProgramIds, PoolKey, SwapPdas,
+};
+
+/// The per-LST accounts of an AddLiquidityMulti instruction
+/// that precede the LST's SOL value calculator and pricing program accounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityMultiLstKeys {
+    pub lst_mint: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub protocol_fee_accumulator: Pubkey,
+    pub lst_token_program: Pubkey,
+    pub pool_reserves: Pubkey,
+}
+
+/// The per-LST accounts are verified separately against
+/// AddLiquidityFreeArgs on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddLiquidityMultiFreeArgs<S> {
+    pub fn resolve(self) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        let Self {
+            signer,
+            dst_lp_acc,
+            pool_state: pool_state_account,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let pool_config_id = pool.pool_config_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok(AddLiquidityMultiKeys {
+            signer,
+            dst_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            pool_config: pool_config_id,
+            protocol_fee_lp_acc,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find the index of each LST.
+/// Suitable for use on client-side.
+/// `lst_mints` and `src_lst_accs` must be of the same length.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiByMintsFreeArgs<
+    'a,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mints: &'a [M],
+    pub src_lst_accs: &'a [Pubkey],
+}
+
+impl<
+        'a,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > AddLiquidityMultiByMintsFreeArgs<'a, S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, (LST keys, index of lst on lst_state_list, additional program IDs) for each LST)
+    pub fn resolve(
+        self,
+    ) -> Result<
+        (
+            AddLiquidityMultiKeys,
+            Vec<(
+                AddLiquidityMultiLstKeys,
+                usize,
+                AddRemoveLiquidityProgramIds,
+            )>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(SwapPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            AddLiquidityMultiKeys,
+            Vec<(
+                AddLiquidityMultiLstKeys,
+                usize,
+                AddRemoveLiquidityProgramIds,
+            )>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            AddLiquidityMultiKeys,
+            Vec<(
+                AddLiquidityMultiLstKeys,
+                usize,
+                AddRemoveLiquidityProgramIds,
+            )>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(SwapPdas::for_pool(pool))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, (LST keys, index of lst on lst_state_list, additional program IDs) for each LST)
+    pub fn resolve_with_pdas(
+        self,
+        SwapPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            protocol_fee: protocol_fee_id,
+            pool_config: pool_config_id,
+        }: SwapPdas,
+    ) -> Result<
+        (
+            AddLiquidityMultiKeys,
+            Vec<(
+                AddLiquidityMultiLstKeys,
+                usize,
+                AddRemoveLiquidityProgramIds,
+            )>,
+        ),
+        SControllerError,
+    > {
+        let Self {
+            signer,
+            dst_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mints,
+            src_lst_accs,
+        } = self;
+        if lst_mints.is_empty() || lst_mints.len() != src_lst_accs.len() {
+            return Err(SControllerError::InvalidAddLiquidityMultiEntries);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let lsts = lst_mints
+            .iter()
+            .zip(src_lst_accs)
+            .map(|(lst_mint, src_lst_acc)| {
+                let (lst_index, lst_state) =
+                    try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list)?;
+                let lst_token_program = *lst_mint.owner();
+                Ok((
+                    AddLiquidityMultiLstKeys {
+                        lst_mint: *lst_mint.pubkey(),
+                        src_lst_acc: *src_lst_acc,
+                        protocol_fee_accumulator:
+                            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                                protocol_fee_id,
+                                lst_state,
+                                lst_token_program,
+                            )?,
+                        lst_token_program,
+                        pool_reserves: create_pool_reserves_address_with_pool_state_id(
+                            pool_state_id,
+                            lst_state,
+                            lst_token_program,
+                        )?,
+                    },
+                    lst_index,
+                    AddRemoveLiquidityProgramIds {
+                        lst_calculator_program_id: lst_state.sol_value_calculator,
+                        pricing_program_id: pool_state.pricing_program,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, SControllerError>>()?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok((
+            AddLiquidityMultiKeys {
+                signer,
+                dst_lp_acc,
+                lp_token_mint: pool_state.lp_token_mint,
+                lp_token_program: spl_token::ID,
+                pool_state: pool_state_id,
+                lst_state_list: lst_state_list_id,
+                pool_config: pool_config_id,
+                protocol_fee_lp_acc,
+            },
+            lsts,
+        ))
+    }
+}
@@ -3,11 +3,10 @@ use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    find_lst_state_list_address, find_pool_reserves_address_with_pool_state_id,
-    find_pool_state_address, find_protocol_fee_accumulator_address_with_protocol_fee_id,
-    find_protocol_fee_address,
+    find_pool_reserves_address_with_pool_state_id,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_pool_state, FindLstPdaAtaKeys,
+    try_pool_state, FindLstPdaAtaKeys, PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -51,11 +50,18 @@ impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey> Ad
     pub fn resolve_for_prog(
         self,
         program_id: Pubkey,
+    ) -> Result<(AddLstKeys, LstStateBumps), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
     ) -> Result<(AddLstKeys, LstStateBumps), SControllerError> {
         self.resolve_inner(ResolveInner {
-            pool_state: find_pool_state_address(program_id).0,
-            protocol_fee_accumulator_auth: find_protocol_fee_address(program_id).0,
-            lst_state_list: find_lst_state_list_address(program_id).0,
+            pool_state: pool.pool_state,
+            protocol_fee_accumulator_auth: pool.protocol_fee_pda().address,
+            lst_state_list: pool.lst_state_list_pda().address,
         })
     }
 
@@ -0,0 +1,46 @@
+use s_controller_interface::{AddProtocolFeeBeneficiaryKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{try_pool_state, PoolKey};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddProtocolFeeBeneficiaryFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub new_beneficiary: Pubkey,
+    pub pool_state_acc: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddProtocolFeeBeneficiaryFreeArgs<S> {
+    pub fn resolve(&self) -> Result<AddProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AddProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<AddProtocolFeeBeneficiaryKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(AddProtocolFeeBeneficiaryKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: pool.pool_state,
+            new_beneficiary: self.new_beneficiary,
+            protocol_fee_beneficiary_list: pool.protocol_fee_beneficiary_list_pda().address,
+            system_program: system_program::ID,
+        })
+    }
+}
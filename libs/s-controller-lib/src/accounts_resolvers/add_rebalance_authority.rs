@@ -0,0 +1,46 @@
+use s_controller_interface::{AddRebalanceAuthorityKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{try_pool_state, PoolKey};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddRebalanceAuthorityFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub new_rebalance_authority: Pubkey,
+    pub pool_state_acc: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddRebalanceAuthorityFreeArgs<S> {
+    pub fn resolve(&self) -> Result<AddRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<AddRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<AddRebalanceAuthorityKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(AddRebalanceAuthorityKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: pool.pool_state,
+            new_rebalance_authority: self.new_rebalance_authority,
+            rebalance_authority_list: pool.rebalance_authority_list_pda().address,
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use s_controller_interface::{AddRebalanceLegKeys, PoolState, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    program::{
+        LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, REBALANCE_AUTHORITY_LIST_ID,
+        REBALANCE_RECORD_ID,
+    },
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey, RebalancePdas, SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
+};
+
+/// Requires an existing rebalance_record account.
+/// Should only be used on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct AddRebalanceLegFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    R: ReadonlyAccountPubkey,
+> {
+    pub withdraw_to: Pubkey,
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    pub lst_state_list: L,
+    pub pool_state: S,
+    pub rebalance_record: R,
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        R: ReadonlyAccountPubkey,
+    > AddRebalanceLegFreeArgs<SM, DM, S, L, R>
+{
+    pub fn resolve(self) -> Result<AddRebalanceLegKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<AddRebalanceLegKeys, SControllerError> {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let rebalance_record_id = pool.rebalance_record_pda().address;
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        if *self.pool_state.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.rebalance_record.pubkey() != rebalance_record_id {
+            return Err(SControllerError::IncorrectRebalanceRecord);
+        }
+
+        let pool_state_acc_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_acc_data)?;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let src_lst_state =
+            try_match_lst_mint_on_list(*self.src_lst_mint.pubkey(), &list, self.src_lst_index)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
+
+        let dst_lst_state =
+            try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), &list, self.dst_lst_index)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
+
+        Ok(AddRebalanceLegKeys {
+            rebalance_authority: pool_state.rebalance_authority,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
+            src_lst_mint: src_lst_state.mint,
+            dst_lst_mint: dst_lst_state.mint,
+            src_pool_reserves,
+            dst_pool_reserves,
+            withdraw_to: self.withdraw_to,
+            src_lst_token_program: *self.src_lst_mint.owner(),
+            rebalance_authority_list: pool.rebalance_authority_list_pda().address,
+            pool_config: pool.pool_config_pda().address,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find the lst indexes.
+/// Suitable for use on client side
+#[derive(Clone, Copy, Debug)]
+pub struct AddRebalanceLegByMintsFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub withdraw_to: Pubkey,
+    pub lst_state_list: L,
+    pub pool_state: S,
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > AddRebalanceLegByMintsFreeArgs<SM, DM, S, L>
+{
+    pub fn resolve(
+        self,
+    ) -> Result<
+        (
+            AddRebalanceLegKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(RebalancePdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            rebalance_record: REBALANCE_RECORD_ID,
+            rebalance_authority_list: REBALANCE_AUTHORITY_LIST_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            AddRebalanceLegKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            AddRebalanceLegKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(RebalancePdas::for_pool(pool))
+    }
+
+    pub fn resolve_with_pdas(
+        self,
+        RebalancePdas {
+            pool_state,
+            lst_state_list,
+            rebalance_record,
+            rebalance_authority_list,
+            pool_config,
+        }: RebalancePdas,
+    ) -> Result<
+        (
+            AddRebalanceLegKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        let pool_state_acc_data = self.pool_state.data();
+        let PoolState {
+            rebalance_authority,
+            version,
+            ..
+        } = try_pool_state(&pool_state_acc_data)?;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(*version, &lst_state_list_acc_data)?;
+
+        let (src_lst_index, src_lst_state) =
+            try_find_lst_mint_on_list(*self.src_lst_mint.pubkey(), &list)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
+
+        let (dst_lst_index, dst_lst_state) =
+            try_find_lst_mint_on_list(*self.dst_lst_mint.pubkey(), &list)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
+
+        Ok((
+            AddRebalanceLegKeys {
+                rebalance_authority: *rebalance_authority,
+                pool_state,
+                lst_state_list,
+                rebalance_record,
+                src_lst_mint: src_lst_state.mint,
+                dst_lst_mint: dst_lst_state.mint,
+                src_pool_reserves,
+                dst_pool_reserves,
+                withdraw_to: self.withdraw_to,
+                src_lst_token_program: *self.src_lst_mint.owner(),
+                rebalance_authority_list,
+                pool_config,
+            },
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            SrcDstLstSolValueCalcProgramIds {
+                src_lst_calculator_program_id: src_lst_state.sol_value_calculator,
+                dst_lst_calculator_program_id: dst_lst_state.sol_value_calculator,
+            },
+        ))
+    }
+}
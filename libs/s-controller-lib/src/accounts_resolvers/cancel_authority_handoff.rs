@@ -0,0 +1,53 @@
+use s_controller_interface::{CancelAuthorityHandoffKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct CancelAuthorityHandoffFreeArgs<S> {
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> CancelAuthorityHandoffFreeArgs<S> {
+    pub fn resolve(&self) -> Result<CancelAuthorityHandoffKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> CancelAuthorityHandoffFreeArgs<S> {
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<CancelAuthorityHandoffKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<CancelAuthorityHandoffKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.pending_authorities_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        pending_authorities_id: Pubkey,
+    ) -> Result<CancelAuthorityHandoffKeys, SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(CancelAuthorityHandoffKeys {
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            pending_authorities: pending_authorities_id,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use s_controller_interface::{CancelQueuedChangeKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{create_queued_change_address_for_pool, try_queued_change, PoolKey};
+
+/// The expected queued_change address is derived from
+/// the kind, lst_mint and bump stored in `queued_change`'s data
+#[derive(Clone, Copy, Debug)]
+pub struct CancelQueuedChangeFreeArgs<Q> {
+    pub signer: Pubkey,
+    pub queued_change: Q,
+}
+
+impl<Q: ReadonlyAccountData> CancelQueuedChangeFreeArgs<Q> {
+    pub fn resolve(&self) -> Result<CancelQueuedChangeKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<CancelQueuedChangeKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<CancelQueuedChangeKeys, SControllerError> {
+        let queued_change_data = self.queued_change.data();
+        let queued_change = try_queued_change(&queued_change_data)?;
+
+        Ok(CancelQueuedChangeKeys {
+            signer: self.signer,
+            pool_state: pool.pool_state,
+            disable_pool_authority_list: pool.disable_pool_authority_list_pda().address,
+            queued_change: create_queued_change_address_for_pool(pool, queued_change)?,
+            refund_rent_to: queued_change.payer,
+        })
+    }
+}
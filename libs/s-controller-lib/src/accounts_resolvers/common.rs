@@ -1,6 +1,6 @@
 use solana_program::pubkey::Pubkey;
 
-use crate::{find_lst_state_list_address, find_pool_state_address, find_protocol_fee_address};
+use crate::PoolKey;
 
 #[derive(Clone, Copy, Debug)]
 pub struct SwapLiquidityPdas {
@@ -11,10 +11,14 @@ pub struct SwapLiquidityPdas {
 
 impl SwapLiquidityPdas {
     pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
         Self {
-            pool_state: find_pool_state_address(program_id).0,
-            lst_state_list: find_lst_state_list_address(program_id).0,
-            protocol_fee: find_protocol_fee_address(program_id).0,
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            protocol_fee: pool.protocol_fee_pda().address,
         }
     }
 }
@@ -1,20 +1,23 @@
 use s_controller_interface::{
-    DisableLstInputKeys, EnableLstInputKeys, LstState, PoolState, SControllerError,
+    DisableLstInputKeys, DisableLstOutputKeys, EnableLstInputKeys, EnableLstOutputKeys, LstState,
+    PoolState, SControllerError,
 };
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
 use crate::{
-    find_lst_state_list_address, find_pool_state_address,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_pool_state,
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_pool_state, PoolKey,
 };
 
 struct DisableEnableLstInputComputedKeys {
+    pub lst_state_list: Pubkey,
     pub admin: Pubkey,
     pub lst_mint: Pubkey,
 }
 
+/// DisableLstOutput and EnableLstOutput have the same accounts
+/// as DisableLstInput and EnableLstInput so they're resolved here too
 pub struct DisableEnableLstInputFreeArgs<
     S: ReadonlyAccountData + ReadonlyAccountPubkey,
     L: ReadonlyAccountData + ReadonlyAccountPubkey,
@@ -29,59 +32,129 @@ impl<
         L: ReadonlyAccountData + ReadonlyAccountPubkey,
     > DisableEnableLstInputFreeArgs<S, L>
 {
-    fn compute_keys(&self) -> Result<DisableEnableLstInputComputedKeys, SControllerError> {
+    fn compute_keys(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<DisableEnableLstInputComputedKeys, SControllerError> {
         let Self {
             lst_index,
             pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
         let lst_state_list_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
         let LstState { mint, .. } = lst_state_list
             .get(*lst_index)
             .ok_or(SControllerError::InvalidLstIndex)?;
-
-        let pool_state_data = pool_state_account.data();
-        let pool_state = try_pool_state(&pool_state_data)?;
         let PoolState { admin, .. } = pool_state;
 
         Ok(DisableEnableLstInputComputedKeys {
+            lst_state_list: lst_state_list_id,
             admin: *admin,
             lst_mint: *mint,
         })
     }
 
     pub fn resolve_disable(&self) -> Result<DisableLstInputKeys, SControllerError> {
-        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys()?;
+        self.resolve_disable_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_disable_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<DisableLstInputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys {
+            lst_state_list,
+            admin,
+            lst_mint,
+        } = self.compute_keys(pool)?;
         Ok(DisableLstInputKeys {
             admin,
             lst_mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pool.pool_state,
+            lst_state_list,
         })
     }
 
     pub fn resolve_enable(&self) -> Result<EnableLstInputKeys, SControllerError> {
-        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys()?;
+        self.resolve_enable_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_enable_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<EnableLstInputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys {
+            lst_state_list,
+            admin,
+            lst_mint,
+        } = self.compute_keys(pool)?;
         Ok(EnableLstInputKeys {
             admin,
             lst_mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pool.pool_state,
+            lst_state_list,
+        })
+    }
+
+    pub fn resolve_disable_output(&self) -> Result<DisableLstOutputKeys, SControllerError> {
+        self.resolve_disable_output_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_disable_output_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<DisableLstOutputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys {
+            lst_state_list,
+            admin,
+            lst_mint,
+        } = self.compute_keys(pool)?;
+        Ok(DisableLstOutputKeys {
+            admin,
+            lst_mint,
+            pool_state: pool.pool_state,
+            lst_state_list,
+        })
+    }
+
+    pub fn resolve_enable_output(&self) -> Result<EnableLstOutputKeys, SControllerError> {
+        self.resolve_enable_output_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_enable_output_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<EnableLstOutputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys {
+            lst_state_list,
+            admin,
+            lst_mint,
+        } = self.compute_keys(pool)?;
+        Ok(EnableLstOutputKeys {
+            admin,
+            lst_mint,
+            pool_state: pool.pool_state,
+            lst_state_list,
         })
     }
 }
 
 /// Iterates through lst_state_list to find lst_index.
 /// Suitable for use on client-side.
-/// Does not check identity of pool_state and lst_state_list
+/// Does not check identity of pool_state and lst_state_list.
+/// Also resolves DisableLstOutput and EnableLstOutput
 pub struct DisableEnableLstInputByMintFreeArgs<S: ReadonlyAccountData, L: ReadonlyAccountData> {
     pub lst_mint: Pubkey,
     pub pool_state: S,
@@ -97,13 +170,13 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
         } = self;
-        let lst_state_list_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
-        let (lst_index, LstState { mint, .. }) =
-            try_find_lst_mint_on_list(*lst_mint, lst_state_list)?;
-
         let pool_state_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        let (lst_index, LstState { mint, .. }) =
+            try_find_lst_mint_on_list(*lst_mint, &lst_state_list)?;
         let PoolState { admin, .. } = pool_state;
 
         Ok((
@@ -135,6 +208,14 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
     pub fn resolve_disable_for_prog(
         &self,
         program_id: Pubkey,
+    ) -> Result<(DisableLstInputKeys, usize), SControllerError> {
+        self.resolve_disable_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_disable_for_pool(
+        &self,
+        pool: &PoolKey,
     ) -> Result<(DisableLstInputKeys, usize), SControllerError> {
         let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
             self.compute_keys_and_index()?;
@@ -143,8 +224,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             DisableLstInputKeys {
                 admin,
                 lst_mint,
-                pool_state: find_pool_state_address(program_id).0,
-                lst_state_list: find_lst_state_list_address(program_id).0,
+                pool_state: pool.pool_state,
+                lst_state_list: pool.lst_state_list_pda().address,
             },
             lst_index,
         ))
@@ -170,6 +251,14 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
     pub fn resolve_enable_for_prog(
         &self,
         program_id: Pubkey,
+    ) -> Result<(EnableLstInputKeys, usize), SControllerError> {
+        self.resolve_enable_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_enable_for_pool(
+        &self,
+        pool: &PoolKey,
     ) -> Result<(EnableLstInputKeys, usize), SControllerError> {
         let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
             self.compute_keys_and_index()?;
@@ -178,8 +267,96 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             EnableLstInputKeys {
                 admin,
                 lst_mint,
-                pool_state: find_pool_state_address(program_id).0,
-                lst_state_list: find_lst_state_list_address(program_id).0,
+                pool_state: pool.pool_state,
+                lst_state_list: pool.lst_state_list_pda().address,
+            },
+            lst_index,
+        ))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_disable_output(
+        &self,
+    ) -> Result<(DisableLstOutputKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            DisableLstOutputKeys {
+                admin,
+                lst_mint,
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+            },
+            lst_index,
+        ))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_disable_output_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(DisableLstOutputKeys, usize), SControllerError> {
+        self.resolve_disable_output_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_disable_output_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(DisableLstOutputKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            DisableLstOutputKeys {
+                admin,
+                lst_mint,
+                pool_state: pool.pool_state,
+                lst_state_list: pool.lst_state_list_pda().address,
+            },
+            lst_index,
+        ))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_enable_output(&self) -> Result<(EnableLstOutputKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            EnableLstOutputKeys {
+                admin,
+                lst_mint,
+                pool_state: POOL_STATE_ID,
+                lst_state_list: LST_STATE_LIST_ID,
+            },
+            lst_index,
+        ))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_enable_output_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(EnableLstOutputKeys, usize), SControllerError> {
+        self.resolve_enable_output_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_enable_output_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(EnableLstOutputKeys, usize), SControllerError> {
+        let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
+            self.compute_keys_and_index()?;
+
+        Ok((
+            EnableLstOutputKeys {
+                admin,
+                lst_mint,
+                pool_state: pool.pool_state,
+                lst_state_list: pool.lst_state_list_pda().address,
             },
             lst_index,
         ))
@@ -2,8 +2,8 @@ use s_controller_interface::DisablePoolKeys;
 use solana_program::pubkey::Pubkey;
 
 use crate::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
     program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
+    PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -17,9 +17,14 @@ impl DisablePoolFreeArgs {
     }
 
     pub fn resolve_for_prog(&self, program_id: Pubkey) -> DisablePoolKeys {
-        let pool_state_id = find_pool_state_address(program_id).0;
-        let disable_pool_authority_list_id = find_disable_pool_authority_list_address(program_id).0;
-        self.resolve_inner(pool_state_id, disable_pool_authority_list_id)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(&self, pool: &PoolKey) -> DisablePoolKeys {
+        self.resolve_inner(
+            pool.pool_state,
+            pool.disable_pool_authority_list_pda().address,
+        )
     }
 
     fn resolve_inner(
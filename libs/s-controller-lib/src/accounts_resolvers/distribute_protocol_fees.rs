@@ -0,0 +1,73 @@
+use s_controller_interface::DistributeProtocolFeesKeys;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    find_protocol_fee_accumulator_address_with_protocol_fee_id,
+    program::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID, PROTOCOL_FEE_ID},
+    FindLstPdaAtaKeys, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DistributeProtocolFeesPdas {
+    pub pool_state: Pubkey,
+    pub protocol_fee_accumulator_auth: Pubkey,
+    pub protocol_fee_beneficiary_list: Pubkey,
+}
+
+/// The beneficiary token accounts are not part of the resolved keys.
+/// Use [`crate::protocol_fee_beneficiary_atas`] to derive them client-side
+#[derive(Clone, Copy, Debug)]
+pub struct DistributeProtocolFeesFreeArgs<M> {
+    pub lst_mint: M,
+}
+
+impl<M: ReadonlyAccountOwner + ReadonlyAccountPubkey> DistributeProtocolFeesFreeArgs<M> {
+    pub fn resolve(&self) -> DistributeProtocolFeesKeys {
+        self.resolve_with_pdas(DistributeProtocolFeesPdas {
+            pool_state: POOL_STATE_ID,
+            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
+            protocol_fee_beneficiary_list: PROTOCOL_FEE_BENEFICIARY_LIST_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> DistributeProtocolFeesKeys {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(&self, pool: &PoolKey) -> DistributeProtocolFeesKeys {
+        self.resolve_with_pdas(DistributeProtocolFeesPdas {
+            pool_state: pool.pool_state,
+            protocol_fee_accumulator_auth: pool.protocol_fee_pda().address,
+            protocol_fee_beneficiary_list: pool.protocol_fee_beneficiary_list_pda().address,
+        })
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        DistributeProtocolFeesPdas {
+            pool_state,
+            protocol_fee_accumulator_auth,
+            protocol_fee_beneficiary_list,
+        }: DistributeProtocolFeesPdas,
+    ) -> DistributeProtocolFeesKeys {
+        let lst_mint = *self.lst_mint.pubkey();
+        let token_program = *self.lst_mint.owner();
+        let protocol_fee_accumulator = find_protocol_fee_accumulator_address_with_protocol_fee_id(
+            protocol_fee_accumulator_auth,
+            FindLstPdaAtaKeys {
+                lst_mint,
+                token_program,
+            },
+        )
+        .0;
+        DistributeProtocolFeesKeys {
+            protocol_fee_accumulator,
+            protocol_fee_accumulator_auth,
+            token_program,
+            pool_state,
+            lst_mint,
+            protocol_fee_beneficiary_list,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use s_controller_interface::{EnablePoolKeys, SControllerError};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{program::POOL_STATE_ID, try_pool_state, PoolKey};
 
 #[derive(Clone, Copy, Debug)]
 pub struct EnablePoolFreeArgs<S> {
@@ -20,8 +20,11 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> EnablePoolFreeArgs<S> {
 
 impl<S: ReadonlyAccountData> EnablePoolFreeArgs<S> {
     pub fn resolve_for_prog(&self, program_id: Pubkey) -> Result<EnablePoolKeys, SControllerError> {
-        let pool_state_id = find_pool_state_address(program_id).0;
-        self.resolve_with_pool_state_id(pool_state_id)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(&self, pool: &PoolKey) -> Result<EnablePoolKeys, SControllerError> {
+        self.resolve_with_pool_state_id(pool.pool_state)
     }
 
     pub fn resolve_with_pool_state_id(
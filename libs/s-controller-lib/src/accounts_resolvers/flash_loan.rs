@@ -0,0 +1,379 @@
+use s_controller_interface::{FlashBorrowKeys, FlashLoanRecord, FlashRepayKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program, sysvar};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
+    find_protocol_fee_lp_address_with_protocol_fee_id, index_to_usize,
+    program::{
+        FLASH_LOAN_RECORD_ID, LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID,
+    },
+    try_find_lst_mint_on_list, try_flash_loan_record, try_lst_state_list_versioned,
+    try_match_lst_mint_on_list, try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct FlashLoanPdas {
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub protocol_fee: Pubkey,
+    pub flash_loan_record: Pubkey,
+    pub pool_config: Pubkey,
+}
+
+impl FlashLoanPdas {
+    pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
+        Self {
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            protocol_fee: pool.protocol_fee_pda().address,
+            flash_loan_record: pool.flash_loan_record_pda().address,
+            pool_config: pool.pool_config_pda().address,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FlashBorrowFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub dst_lst_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > FlashBorrowFreeArgs<S, L, M>
+{
+    pub fn resolve(self) -> Result<FlashBorrowKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<FlashBorrowKeys, SControllerError> {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let flash_loan_record_id = pool.flash_loan_record_pda().address;
+        if *self.pool_state.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        let pool_state_acc_data = self.pool_state.data();
+        let version = try_pool_state(&pool_state_acc_data)?.version;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
+
+        let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), &list, self.lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
+
+        Ok(FlashBorrowKeys {
+            lst_mint: lst_state.mint,
+            dst_lst_acc: self.dst_lst_acc,
+            lst_token_program: *self.lst_mint.owner(),
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            pool_reserves,
+            flash_loan_record: flash_loan_record_id,
+            instructions: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side
+#[derive(Clone, Copy, Debug)]
+pub struct FlashBorrowByMintFreeArgs<S, L, M> {
+    pub dst_lst_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > FlashBorrowByMintFreeArgs<S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list)
+    pub fn resolve(self) -> Result<(FlashBorrowKeys, usize), SControllerError> {
+        self.resolve_with_pdas(FlashLoanPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+            flash_loan_record: FLASH_LOAN_RECORD_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(FlashBorrowKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list)
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(FlashBorrowKeys, usize), SControllerError> {
+        self.resolve_with_pdas(FlashLoanPdas::for_pool(pool))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list)
+    pub fn resolve_with_pdas(
+        self,
+        FlashLoanPdas {
+            pool_state,
+            lst_state_list,
+            flash_loan_record,
+            ..
+        }: FlashLoanPdas,
+    ) -> Result<(FlashBorrowKeys, usize), SControllerError> {
+        let pool_state_acc_data = self.pool_state.data();
+        let version = try_pool_state(&pool_state_acc_data)?.version;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
+
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), &list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
+
+        Ok((
+            FlashBorrowKeys {
+                lst_mint: lst_state.mint,
+                dst_lst_acc: self.dst_lst_acc,
+                lst_token_program: *self.lst_mint.owner(),
+                pool_state,
+                lst_state_list,
+                pool_reserves,
+                flash_loan_record,
+                instructions: sysvar::instructions::ID,
+                system_program: system_program::ID,
+            },
+            lst_index,
+        ))
+    }
+}
+
+/// Requires an existing flash_loan_record account.
+/// Should only be used on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct FlashRepayFreeArgs<S, L, R, M> {
+    pub signer: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub flash_loan_record: R,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        R: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > FlashRepayFreeArgs<S, L, R, M>
+{
+    /// Returns (keys, lst_index)
+    pub fn resolve(self) -> Result<(FlashRepayKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    /// Returns (keys, lst_index)
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(FlashRepayKeys, usize), SControllerError> {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let flash_loan_record_id = pool.flash_loan_record_pda().address;
+        let pool_config_id = pool.pool_config_pda().address;
+        if *self.pool_state.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        if *self.flash_loan_record.pubkey() != flash_loan_record_id {
+            return Err(SControllerError::IncorrectFlashLoanRecord);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let flash_loan_record_acc_data = self.flash_loan_record.data();
+        let FlashLoanRecord { lst_index, .. } = try_flash_loan_record(&flash_loan_record_acc_data)?;
+        let lst_index = index_to_usize(*lst_index)?;
+
+        let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), &list, lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *self.lst_mint.owner(),
+            )?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok((
+            FlashRepayKeys {
+                signer: self.signer,
+                lst_mint: lst_state.mint,
+                src_lst_acc: self.src_lst_acc,
+                protocol_fee_accumulator,
+                lst_token_program: *self.lst_mint.owner(),
+                pool_state: pool.pool_state,
+                lst_state_list: lst_state_list_id,
+                pool_reserves,
+                flash_loan_record: flash_loan_record_id,
+                lp_token_mint: pool_state.lp_token_mint,
+                protocol_fee_lp_acc,
+                lp_token_program: spl_token::ID,
+                pool_config: pool_config_id,
+            },
+            lst_index,
+        ))
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side, where the flash_loan_record
+/// does not exist yet at the time of building the transaction
+#[derive(Clone, Copy, Debug)]
+pub struct FlashRepayByMintFreeArgs<S, L, M> {
+    pub signer: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > FlashRepayByMintFreeArgs<S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve(self) -> Result<(FlashRepayKeys, usize, Pubkey), SControllerError> {
+        self.resolve_with_pdas(FlashLoanPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+            flash_loan_record: FLASH_LOAN_RECORD_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(FlashRepayKeys, usize, Pubkey), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(FlashRepayKeys, usize, Pubkey), SControllerError> {
+        self.resolve_with_pdas(FlashLoanPdas::for_pool(pool))
+    }
+
+    /// Does not check identity of lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_with_pdas(
+        self,
+        FlashLoanPdas {
+            pool_state: pool_state_id,
+            lst_state_list,
+            protocol_fee,
+            flash_loan_record,
+            pool_config,
+        }: FlashLoanPdas,
+    ) -> Result<(FlashRepayKeys, usize, Pubkey), SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), &list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee,
+                lst_state,
+                *self.lst_mint.owner(),
+            )?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok((
+            FlashRepayKeys {
+                signer: self.signer,
+                lst_mint: lst_state.mint,
+                src_lst_acc: self.src_lst_acc,
+                protocol_fee_accumulator,
+                lst_token_program: *self.lst_mint.owner(),
+                pool_state: pool_state_id,
+                lst_state_list,
+                pool_reserves,
+                flash_loan_record,
+                lp_token_mint: pool_state.lp_token_mint,
+                protocol_fee_lp_acc,
+                lp_token_program: spl_token::ID,
+                pool_config,
+            },
+            lst_index,
+            lst_state.sol_value_calculator,
+        ))
+    }
+}
@@ -1,7 +1,7 @@
 use s_controller_interface::InitializeKeys;
 use solana_program::{pubkey::Pubkey, system_program};
 
-use crate::{find_pool_state_address, initial_authority, program::POOL_STATE_ID};
+use crate::{initial_authority, PoolKey};
 
 #[derive(Clone, Copy, Debug)]
 pub struct InitializeFreeArgs {
@@ -17,6 +17,12 @@ pub struct InitializeResolveForProg {
 
 impl InitializeFreeArgs {
     pub fn resolve(self) -> InitializeKeys {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    /// Initializes `pool`, whose LP token mint should be `self.lp_token_mint`
+    /// if it is not the default pool
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> InitializeKeys {
         let Self {
             payer,
             lp_token_mint,
@@ -25,7 +31,7 @@ impl InitializeFreeArgs {
             payer,
             lp_token_mint,
             authority: initial_authority::ID,
-            pool_state: POOL_STATE_ID,
+            pool_state: pool.pool_state,
             lp_token_program: spl_token::ID,
             system_program: system_program::ID,
         }
@@ -45,7 +51,7 @@ impl InitializeFreeArgs {
         InitializeKeys {
             payer,
             authority: initial_authority,
-            pool_state: find_pool_state_address(program_id).0,
+            pool_state: PoolKey::default_for_prog(program_id).pool_state,
             lp_token_mint,
             lp_token_program: spl_token::ID,
             system_program: system_program::ID,
@@ -0,0 +1,48 @@
+use s_controller_interface::{MigrateKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct MigrateFreeArgs<S> {
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> MigrateFreeArgs<S> {
+    pub fn resolve(self) -> Result<MigrateKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_inner(POOL_STATE_ID, LST_STATE_LIST_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> MigrateFreeArgs<S> {
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<MigrateKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<MigrateKeys, SControllerError> {
+        self.resolve_inner(pool.pool_state, pool.lst_state_list_pda().address)
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+        lst_state_list_id: Pubkey,
+    ) -> Result<MigrateKeys, SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(MigrateKeys {
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -1,45 +1,113 @@
+mod accept_authority;
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
+mod add_protocol_fee_beneficiary;
+mod add_rebalance_authority;
+mod add_rebalance_leg;
+mod cancel_authority_handoff;
+mod cancel_queued_change;
 mod common;
+mod convert_protocol_fees_to_lp;
+mod deposit_stake;
 mod disable_enable_lst_input;
 mod disable_pool;
+mod distribute_protocol_fees;
 mod enable_pool;
 mod end_rebalance;
+mod flash_loan;
 mod initialize;
+mod migrate;
+mod queue_execute_set_pricing_program;
+mod queue_execute_set_sol_value_calculator;
+mod queue_execute_set_timelock_delay;
+mod quote;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_proportional;
+mod remove_liquidity_to_stake;
 mod remove_lst;
+mod remove_protocol_fee_beneficiary;
+mod remove_rebalance_authority;
 mod set_admin;
+mod set_lp_sync_requirement;
+mod set_lp_token_metadata;
+mod set_lst_circuit_breaker;
+mod set_lst_flash_loan_fee;
+mod set_lst_max_share;
+mod set_max_rebalance_loss;
+mod set_pool_rebalance_authority_budget;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
+mod set_protocol_fee_beneficiary_weight;
+mod set_protocol_fees_as_lp;
 mod set_rebalance_authority;
+mod set_rebalance_authority_budget;
+mod set_referral_fee;
+mod set_remove_liquidity_proportional_fee;
 mod set_sol_value_calculator;
+mod settle_rebalance_leg;
 mod start_rebalance;
 mod swap;
 mod sync_sol_value;
+mod sync_sol_value_multi;
 mod withdraw_protocol_fees;
 
+pub use accept_authority::*;
 pub use add_disable_pool_authority::*;
 pub use add_liquidity::*;
+pub use add_liquidity_multi::*;
 pub use add_lst::*;
+pub use add_protocol_fee_beneficiary::*;
+pub use add_rebalance_authority::*;
+pub use add_rebalance_leg::*;
+pub use cancel_authority_handoff::*;
+pub use cancel_queued_change::*;
 pub use common::*;
+pub use convert_protocol_fees_to_lp::*;
+pub use deposit_stake::*;
 pub use disable_enable_lst_input::*;
 pub use disable_pool::*;
+pub use distribute_protocol_fees::*;
 pub use enable_pool::*;
 pub use end_rebalance::*;
+pub use flash_loan::*;
 pub use initialize::*;
+pub use migrate::*;
+pub use queue_execute_set_pricing_program::*;
+pub use queue_execute_set_sol_value_calculator::*;
+pub use queue_execute_set_timelock_delay::*;
+pub use quote::*;
 pub use remove_disable_pool_authority::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_proportional::*;
+pub use remove_liquidity_to_stake::*;
 pub use remove_lst::*;
+pub use remove_protocol_fee_beneficiary::*;
+pub use remove_rebalance_authority::*;
 pub use set_admin::*;
+pub use set_lp_sync_requirement::*;
+pub use set_lp_token_metadata::*;
+pub use set_lst_circuit_breaker::*;
+pub use set_lst_flash_loan_fee::*;
+pub use set_lst_max_share::*;
+pub use set_max_rebalance_loss::*;
+pub use set_pool_rebalance_authority_budget::*;
 pub use set_pricing_program::*;
 pub use set_protocol_fee::*;
 pub use set_protocol_fee_beneficiary::*;
+pub use set_protocol_fee_beneficiary_weight::*;
+pub use set_protocol_fees_as_lp::*;
 pub use set_rebalance_authority::*;
+pub use set_rebalance_authority_budget::*;
+pub use set_referral_fee::*;
+pub use set_remove_liquidity_proportional_fee::*;
 pub use set_sol_value_calculator::*;
+pub use settle_rebalance_leg::*;
 pub use start_rebalance::*;
 pub use swap::*;
 pub use sync_sol_value::*;
+pub use sync_sol_value_multi::*;
 pub use withdraw_protocol_fees::*;
@@ -0,0 +1,102 @@
+use s_controller_interface::{
+    ExecuteSetPricingProgramKeys, QueueSetPricingProgramKeys, SControllerError,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{POOL_STATE_ID, QUEUED_PRICING_PROGRAM_ID},
+    try_pool_state, try_queued_change, verify_queued_change_kind, PoolKey, QueuedChangeKind,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct QueueSetPricingProgramFreeArgs<S> {
+    pub payer: Pubkey,
+    pub new_pricing_program: Pubkey,
+    pub pool_state_acc: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> QueueSetPricingProgramFreeArgs<S> {
+    pub fn resolve(&self) -> Result<QueueSetPricingProgramKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_with_pdas(POOL_STATE_ID, QUEUED_PRICING_PROGRAM_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> QueueSetPricingProgramFreeArgs<S> {
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<QueueSetPricingProgramKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QueueSetPricingProgramKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.queued_pricing_program_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        queued_change_id: Pubkey,
+    ) -> Result<QueueSetPricingProgramKeys, SControllerError> {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(QueueSetPricingProgramKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            new_pricing_program: self.new_pricing_program,
+            pool_state: pool_state_id,
+            queued_change: queued_change_id,
+            system_program: system_program::ID,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExecuteSetPricingProgramFreeArgs<Q> {
+    pub queued_change: Q,
+}
+
+impl<Q: ReadonlyAccountData> ExecuteSetPricingProgramFreeArgs<Q> {
+    pub fn resolve(&self) -> Result<ExecuteSetPricingProgramKeys, SControllerError> {
+        self.resolve_with_pdas(POOL_STATE_ID, QUEUED_PRICING_PROGRAM_ID)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<ExecuteSetPricingProgramKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<ExecuteSetPricingProgramKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.queued_pricing_program_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        queued_change_id: Pubkey,
+    ) -> Result<ExecuteSetPricingProgramKeys, SControllerError> {
+        let queued_change_data = self.queued_change.data();
+        let queued_change = try_queued_change(&queued_change_data)?;
+        verify_queued_change_kind(queued_change, QueuedChangeKind::PricingProgram)?;
+
+        Ok(ExecuteSetPricingProgramKeys {
+            new_pricing_program: queued_change.new_program,
+            pool_state: pool_state_id,
+            queued_change: queued_change_id,
+            refund_rent_to: queued_change.payer,
+        })
+    }
+}
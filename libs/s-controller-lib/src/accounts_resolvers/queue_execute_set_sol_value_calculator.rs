@@ -0,0 +1,282 @@
+use s_controller_interface::{
+    ExecuteSetSolValueCalculatorKeys, QueueSetSolValueCalculatorKeys, SControllerError,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id, create_queued_change_address_for_pool,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, try_queued_change, verify_queued_change_kind, PoolKey, QueuedChangeKind,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct QueueSetSolValueCalculatorFreeArgs<S, L> {
+    pub payer: Pubkey,
+    pub lst_index: usize,
+    pub lst_mint: Pubkey,
+    pub new_sol_value_calculator: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > QueueSetSolValueCalculatorFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<QueueSetSolValueCalculatorKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> QueueSetSolValueCalculatorFreeArgs<S, L> {
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<QueueSetSolValueCalculatorKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QueueSetSolValueCalculatorKeys, SControllerError> {
+        self.resolve_with_pdas(
+            pool.pool_state,
+            pool.lst_state_list_pda().address,
+            pool.queued_sol_value_calculator_pda(self.lst_mint).address,
+        )
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        lst_state_list_id: Pubkey,
+        queued_change_id: Pubkey,
+    ) -> Result<QueueSetSolValueCalculatorKeys, SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = self.lst_state_list.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        let lst_state = try_match_lst_mint_on_list(self.lst_mint, &lst_state_list, self.lst_index)?;
+
+        Ok(QueueSetSolValueCalculatorKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            lst_mint: lst_state.mint,
+            new_sol_value_calculator: self.new_sol_value_calculator,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            queued_change: queued_change_id,
+            system_program: system_program::ID,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct QueueSetSolValueCalculatorByMintFreeArgs<S, L> {
+    pub payer: Pubkey,
+    pub lst_mint: Pubkey,
+    pub new_sol_value_calculator: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData>
+    QueueSetSolValueCalculatorByMintFreeArgs<S, L>
+{
+    /// Returns (keys, lst_index)
+    pub fn resolve(&self) -> Result<(QueueSetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(QueueSetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(QueueSetSolValueCalculatorKeys, usize), SControllerError> {
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_index = {
+            let lst_state_list_data = self.lst_state_list.data();
+            let lst_state_list =
+                try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+            try_find_lst_mint_on_list(self.lst_mint, &lst_state_list)?.0
+        };
+        let keys = QueueSetSolValueCalculatorKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            lst_mint: self.lst_mint,
+            new_sol_value_calculator: self.new_sol_value_calculator,
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            queued_change: pool.queued_sol_value_calculator_pda(self.lst_mint).address,
+            system_program: system_program::ID,
+        };
+        Ok((keys, lst_index))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExecuteSetSolValueCalculatorFreeArgs<S, L, M, Q> {
+    pub lst_index: usize,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+    pub queued_change: Q,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        Q: ReadonlyAccountData,
+    > ExecuteSetSolValueCalculatorFreeArgs<S, L, M, Q>
+{
+    pub fn resolve(&self) -> Result<ExecuteSetSolValueCalculatorKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        Q: ReadonlyAccountData,
+    > ExecuteSetSolValueCalculatorFreeArgs<S, L, M, Q>
+{
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<ExecuteSetSolValueCalculatorKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<ExecuteSetSolValueCalculatorKeys, SControllerError> {
+        resolve_execute_keys(
+            pool,
+            &self.pool_state,
+            &self.lst_state_list,
+            &self.lst_mint,
+            &self.queued_change,
+            self.lst_index,
+        )
+    }
+}
+
+fn resolve_execute_keys(
+    pool: &PoolKey,
+    pool_state: &impl ReadonlyAccountData,
+    lst_state_list: &impl ReadonlyAccountData,
+    lst_mint: &(impl ReadonlyAccountOwner + ReadonlyAccountPubkey),
+    queued_change: &impl ReadonlyAccountData,
+    lst_index: usize,
+) -> Result<ExecuteSetSolValueCalculatorKeys, SControllerError> {
+    let pool_state_data = pool_state.data();
+    let version = try_pool_state(&pool_state_data)?.version;
+    let lst_state_list_data = lst_state_list.data();
+    let lst_state_list = try_lst_state_list_versioned(version, &lst_state_list_data)?;
+    let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, lst_index)?;
+    let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+        pool.pool_state,
+        lst_state,
+        *lst_mint.owner(),
+    )?;
+
+    let queued_change_data = queued_change.data();
+    let queued_change = try_queued_change(&queued_change_data)?;
+    verify_queued_change_kind(queued_change, QueuedChangeKind::SolValueCalculator)?;
+    if queued_change.lst_mint != lst_state.mint {
+        return Err(SControllerError::InvalidQueuedChangeData);
+    }
+
+    Ok(ExecuteSetSolValueCalculatorKeys {
+        lst_mint: lst_state.mint,
+        pool_state: pool.pool_state,
+        pool_reserves,
+        lst_state_list: pool.lst_state_list_pda().address,
+        queued_change: create_queued_change_address_for_pool(pool, queued_change)?,
+        refund_rent_to: queued_change.payer,
+    })
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct ExecuteSetSolValueCalculatorByMintFreeArgs<S, L, M, Q> {
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+    pub queued_change: Q,
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        Q: ReadonlyAccountData,
+    > ExecuteSetSolValueCalculatorByMintFreeArgs<S, L, M, Q>
+{
+    /// Returns (keys, lst_index)
+    pub fn resolve(&self) -> Result<(ExecuteSetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(ExecuteSetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(ExecuteSetSolValueCalculatorKeys, usize), SControllerError> {
+        let lst_index = {
+            let pool_state_data = self.pool_state.data();
+            let version = try_pool_state(&pool_state_data)?.version;
+            let lst_state_list_data = self.lst_state_list.data();
+            let lst_state_list = try_lst_state_list_versioned(version, &lst_state_list_data)?;
+            try_find_lst_mint_on_list(*self.lst_mint.pubkey(), &lst_state_list)?.0
+        };
+        let keys = resolve_execute_keys(
+            pool,
+            &self.pool_state,
+            &self.lst_state_list,
+            &self.lst_mint,
+            &self.queued_change,
+            lst_index,
+        )?;
+        Ok((keys, lst_index))
+    }
+}
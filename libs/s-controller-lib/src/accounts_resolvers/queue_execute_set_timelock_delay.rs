@@ -0,0 +1,99 @@
+use s_controller_interface::{
+    ExecuteSetTimelockDelayKeys, QueueSetTimelockDelayKeys, SControllerError,
+};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{POOL_STATE_ID, QUEUED_TIMELOCK_DELAY_ID},
+    try_pool_state, try_queued_change, verify_queued_change_kind, PoolKey, QueuedChangeKind,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct QueueSetTimelockDelayFreeArgs<S> {
+    pub payer: Pubkey,
+    pub pool_state_acc: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> QueueSetTimelockDelayFreeArgs<S> {
+    pub fn resolve(&self) -> Result<QueueSetTimelockDelayKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_with_pdas(POOL_STATE_ID, QUEUED_TIMELOCK_DELAY_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> QueueSetTimelockDelayFreeArgs<S> {
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<QueueSetTimelockDelayKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QueueSetTimelockDelayKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.queued_timelock_delay_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        queued_change_id: Pubkey,
+    ) -> Result<QueueSetTimelockDelayKeys, SControllerError> {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(QueueSetTimelockDelayKeys {
+            admin: pool_state.admin,
+            payer: self.payer,
+            pool_state: pool_state_id,
+            queued_change: queued_change_id,
+            system_program: system_program::ID,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExecuteSetTimelockDelayFreeArgs<Q> {
+    pub queued_change: Q,
+}
+
+impl<Q: ReadonlyAccountData> ExecuteSetTimelockDelayFreeArgs<Q> {
+    pub fn resolve(&self) -> Result<ExecuteSetTimelockDelayKeys, SControllerError> {
+        self.resolve_with_pdas(POOL_STATE_ID, QUEUED_TIMELOCK_DELAY_ID)
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<ExecuteSetTimelockDelayKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<ExecuteSetTimelockDelayKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.queued_timelock_delay_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pool_state_id: Pubkey,
+        queued_change_id: Pubkey,
+    ) -> Result<ExecuteSetTimelockDelayKeys, SControllerError> {
+        let queued_change_data = self.queued_change.data();
+        let queued_change = try_queued_change(&queued_change_data)?;
+        verify_queued_change_kind(queued_change, QueuedChangeKind::TimelockDelay)?;
+
+        Ok(ExecuteSetTimelockDelayKeys {
+            pool_state: pool_state_id,
+            queued_change: queued_change_id,
+            refund_rent_to: queued_change.payer,
+        })
+    }
+}
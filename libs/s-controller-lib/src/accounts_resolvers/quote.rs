@@ -0,0 +1,699 @@
+use s_controller_interface::{
+    QuoteAddLiquidityKeys, QuoteRemoveLiquidityKeys, QuoteSwapExactInKeys, QuoteSwapExactOutKeys,
+    SControllerError,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, AddRemoveLiquidityProgramIds, PoolKey, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcProgramIds, SwapLiquidityPdas,
+};
+
+pub struct QuoteSwapFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+struct QuoteSwapComputedKeys {
+    pub lst_state_list: Pubkey,
+    pub src_pool_reserves: Pubkey,
+    pub dst_pool_reserves: Pubkey,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > QuoteSwapFreeArgs<SM, DM, S, L>
+{
+    fn compute_keys(&self, pool: &PoolKey) -> Result<QuoteSwapComputedKeys, SControllerError> {
+        let Self {
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            src_lst_mint,
+            dst_lst_mint,
+            src_lst_index,
+            dst_lst_index,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let src_lst_state =
+            try_match_lst_mint_on_list(*src_lst_mint.pubkey(), &lst_state_list, *src_lst_index)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            src_lst_state,
+            *src_lst_mint.owner(),
+        )?;
+
+        let dst_lst_state =
+            try_match_lst_mint_on_list(*dst_lst_mint.pubkey(), &lst_state_list, *dst_lst_index)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            dst_lst_state,
+            *dst_lst_mint.owner(),
+        )?;
+
+        Ok(QuoteSwapComputedKeys {
+            lst_state_list: lst_state_list_id,
+            src_pool_reserves,
+            dst_pool_reserves,
+        })
+    }
+
+    pub fn resolve_exact_in(&self) -> Result<QuoteSwapExactInKeys, SControllerError> {
+        self.resolve_exact_in_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_exact_in_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QuoteSwapExactInKeys, SControllerError> {
+        let QuoteSwapComputedKeys {
+            lst_state_list,
+            src_pool_reserves,
+            dst_pool_reserves,
+        } = self.compute_keys(pool)?;
+        Ok(QuoteSwapExactInKeys {
+            src_lst_mint: *self.src_lst_mint.pubkey(),
+            dst_lst_mint: *self.dst_lst_mint.pubkey(),
+            pool_state: pool.pool_state,
+            lst_state_list,
+            src_pool_reserves,
+            dst_pool_reserves,
+        })
+    }
+
+    pub fn resolve_exact_out(&self) -> Result<QuoteSwapExactOutKeys, SControllerError> {
+        self.resolve_exact_out_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_exact_out_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QuoteSwapExactOutKeys, SControllerError> {
+        let QuoteSwapComputedKeys {
+            lst_state_list,
+            src_pool_reserves,
+            dst_pool_reserves,
+        } = self.compute_keys(pool)?;
+        Ok(QuoteSwapExactOutKeys {
+            src_lst_mint: *self.src_lst_mint.pubkey(),
+            dst_lst_mint: *self.dst_lst_mint.pubkey(),
+            pool_state: pool.pool_state,
+            lst_state_list,
+            src_pool_reserves,
+            dst_pool_reserves,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find the lst indexes.
+/// Suitable for use on client side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct QuoteSwapByMintsFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+> {
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+    > QuoteSwapByMintsFreeArgs<SM, DM, S, L>
+{
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_in(
+        &self,
+    ) -> Result<
+        (
+            QuoteSwapExactInKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_in_with_pdas(SwapLiquidityPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+        })
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_out(
+        &self,
+    ) -> Result<
+        (
+            QuoteSwapExactOutKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_with_pdas(SwapLiquidityPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+        })
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_in_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            QuoteSwapExactInKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_in_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_in_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            QuoteSwapExactInKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_in_with_pdas(SwapLiquidityPdas::for_pool(pool))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_out_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            QuoteSwapExactOutKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_out_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            QuoteSwapExactOutKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_with_pdas(SwapLiquidityPdas::for_pool(pool))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_in_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            QuoteSwapExactInKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        let (
+            QuoteSwapComputedKeys {
+                lst_state_list,
+                src_pool_reserves,
+                dst_pool_reserves,
+            },
+            indexes,
+            program_ids,
+        ) = self.compute_keys_and_indexes(pdas)?;
+        Ok((
+            QuoteSwapExactInKeys {
+                src_lst_mint: *self.src_lst_mint.pubkey(),
+                dst_lst_mint: *self.dst_lst_mint.pubkey(),
+                pool_state: pdas.pool_state,
+                lst_state_list,
+                src_pool_reserves,
+                dst_pool_reserves,
+            },
+            indexes,
+            program_ids,
+        ))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids)
+    pub fn resolve_exact_out_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            QuoteSwapExactOutKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        let (
+            QuoteSwapComputedKeys {
+                lst_state_list,
+                src_pool_reserves,
+                dst_pool_reserves,
+            },
+            indexes,
+            program_ids,
+        ) = self.compute_keys_and_indexes(pdas)?;
+        Ok((
+            QuoteSwapExactOutKeys {
+                src_lst_mint: *self.src_lst_mint.pubkey(),
+                dst_lst_mint: *self.dst_lst_mint.pubkey(),
+                pool_state: pdas.pool_state,
+                lst_state_list,
+                src_pool_reserves,
+                dst_pool_reserves,
+            },
+            indexes,
+            program_ids,
+        ))
+    }
+
+    fn compute_keys_and_indexes(
+        &self,
+        SwapLiquidityPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            ..
+        }: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            QuoteSwapComputedKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        let Self {
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            src_lst_mint,
+            dst_lst_mint,
+        } = self;
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let (src_lst_index, src_lst_state) =
+            try_find_lst_mint_on_list(*src_lst_mint.pubkey(), &lst_state_list)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            src_lst_state,
+            *src_lst_mint.owner(),
+        )?;
+
+        let (dst_lst_index, dst_lst_state) =
+            try_find_lst_mint_on_list(*dst_lst_mint.pubkey(), &lst_state_list)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            dst_lst_state,
+            *dst_lst_mint.owner(),
+        )?;
+
+        Ok((
+            QuoteSwapComputedKeys {
+                lst_state_list: lst_state_list_id,
+                src_pool_reserves,
+                dst_pool_reserves,
+            },
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            SrcDstLstSolValueCalcProgramIds {
+                src_lst_calculator_program_id: src_lst_state.sol_value_calculator,
+                dst_lst_calculator_program_id: dst_lst_state.sol_value_calculator,
+            },
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct QuoteLiquidityFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+struct QuoteLiquidityComputedKeys {
+    pub lst_state_list: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub pool_reserves: Pubkey,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > QuoteLiquidityFreeArgs<S, L, M>
+{
+    fn compute_keys(&self, pool: &PoolKey) -> Result<QuoteLiquidityComputedKeys, SControllerError> {
+        let Self {
+            lst_index,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let lst_state =
+            try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, *lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+
+        Ok(QuoteLiquidityComputedKeys {
+            lst_state_list: lst_state_list_id,
+            lp_token_mint: pool_state.lp_token_mint,
+            pool_reserves,
+        })
+    }
+
+    pub fn resolve_add(&self) -> Result<QuoteAddLiquidityKeys, SControllerError> {
+        self.resolve_add_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_add_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QuoteAddLiquidityKeys, SControllerError> {
+        let QuoteLiquidityComputedKeys {
+            lst_state_list,
+            lp_token_mint,
+            pool_reserves,
+        } = self.compute_keys(pool)?;
+        Ok(QuoteAddLiquidityKeys {
+            lst_mint: *self.lst_mint.pubkey(),
+            lp_token_mint,
+            pool_state: pool.pool_state,
+            lst_state_list,
+            pool_reserves,
+        })
+    }
+
+    pub fn resolve_remove(&self) -> Result<QuoteRemoveLiquidityKeys, SControllerError> {
+        self.resolve_remove_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_remove_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<QuoteRemoveLiquidityKeys, SControllerError> {
+        let QuoteLiquidityComputedKeys {
+            lst_state_list,
+            lp_token_mint,
+            pool_reserves,
+        } = self.compute_keys(pool)?;
+        Ok(QuoteRemoveLiquidityKeys {
+            lst_mint: *self.lst_mint.pubkey(),
+            lp_token_mint,
+            pool_state: pool.pool_state,
+            lst_state_list,
+            pool_reserves,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct QuoteLiquidityByMintFreeArgs<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > QuoteLiquidityByMintFreeArgs<S, L, M>
+{
+    /// Returns:
+    /// (instruction keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_add(
+        &self,
+    ) -> Result<(QuoteAddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError>
+    {
+        self.resolve_add_with_pdas(SwapLiquidityPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+        })
+    }
+
+    /// Returns:
+    /// (instruction keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_remove(
+        &self,
+    ) -> Result<
+        (
+            QuoteRemoveLiquidityKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_remove_with_pdas(SwapLiquidityPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+        })
+    }
+
+    pub fn resolve_add_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(QuoteAddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError>
+    {
+        self.resolve_add_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_add_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(QuoteAddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError>
+    {
+        self.resolve_add_with_pdas(SwapLiquidityPdas::for_pool(pool))
+    }
+
+    pub fn resolve_remove_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            QuoteRemoveLiquidityKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_remove_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_remove_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            QuoteRemoveLiquidityKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_remove_with_pdas(SwapLiquidityPdas::for_pool(pool))
+    }
+
+    /// Returns:
+    /// (instruction keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_add_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<(QuoteAddLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError>
+    {
+        let (
+            QuoteLiquidityComputedKeys {
+                lp_token_mint,
+                pool_reserves,
+            },
+            lst_index,
+            program_ids,
+        ) = self.compute_keys_and_index(pdas)?;
+        Ok((
+            QuoteAddLiquidityKeys {
+                lst_mint: *self.lst_mint.pubkey(),
+                lp_token_mint,
+                pool_state: pdas.pool_state,
+                lst_state_list: pdas.lst_state_list,
+                pool_reserves,
+            },
+            lst_index,
+            program_ids,
+        ))
+    }
+
+    /// Returns:
+    /// (instruction keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_remove_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            QuoteRemoveLiquidityKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        let (
+            QuoteLiquidityComputedKeys {
+                lp_token_mint,
+                pool_reserves,
+            },
+            lst_index,
+            program_ids,
+        ) = self.compute_keys_and_index(pdas)?;
+        Ok((
+            QuoteRemoveLiquidityKeys {
+                lst_mint: *self.lst_mint.pubkey(),
+                lp_token_mint,
+                pool_state: pdas.pool_state,
+                lst_state_list: pdas.lst_state_list,
+                pool_reserves,
+            },
+            lst_index,
+            program_ids,
+        ))
+    }
+
+    fn compute_keys_and_index(
+        &self,
+        SwapLiquidityPdas {
+            pool_state: pool_state_id,
+            ..
+        }: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            QuoteLiquidityComputedKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        let Self {
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let (lst_index, lst_state) =
+            try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+
+        Ok((
+            QuoteLiquidityComputedKeys {
+                lp_token_mint: pool_state.lp_token_mint,
+                pool_reserves,
+            },
+            lst_index,
+            AddRemoveLiquidityProgramIds {
+                lst_calculator_program_id: lst_state.sol_value_calculator,
+                pricing_program_id: pool_state.pricing_program,
+            },
+        ))
+    }
+}
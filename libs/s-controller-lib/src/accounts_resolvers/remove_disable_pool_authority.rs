@@ -5,9 +5,9 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
 use crate::{
-    find_disable_pool_authority_list_address, find_pool_state_address, index_to_u32,
+    index_to_u32,
     program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
-    try_disable_pool_authority_list, try_find_element_in_list,
+    try_disable_pool_authority_list, try_find_element_in_list, PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -28,10 +28,18 @@ impl<
     > RemoveDisablePoolAuthorityFreeArgs<S, L>
 {
     pub fn resolve(&self) -> Result<RemoveDisablePoolAuthorityKeys, SControllerError> {
-        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<RemoveDisablePoolAuthorityKeys, SControllerError> {
+        let disable_pool_authority_list_id = pool.disable_pool_authority_list_pda().address;
+        if *self.pool_state_acc.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *self.disable_pool_authority_list.pubkey() != DISABLE_POOL_AUTHORITY_LIST_ID {
+        if *self.disable_pool_authority_list.pubkey() != disable_pool_authority_list_id {
             return Err(SControllerError::IncorrectDisablePoolAuthorityList);
         }
 
@@ -44,9 +52,9 @@ impl<
         Ok(RemoveDisablePoolAuthorityKeys {
             refund_rent_to: self.refund_rent_to,
             signer: self.signer,
-            pool_state: POOL_STATE_ID,
+            pool_state: pool.pool_state,
             authority: *authority,
-            disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
+            disable_pool_authority_list: disable_pool_authority_list_id,
         })
     }
 }
@@ -102,10 +110,23 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData>
             RemoveDisablePoolAuthorityIxArgs,
         ),
         SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            RemoveDisablePoolAuthorityKeys,
+            RemoveDisablePoolAuthorityIxArgs,
+        ),
+        SControllerError,
     > {
         self.resolve_with_pdas(RemoveDisablePoolAuthorityPdas {
-            pool_state: find_pool_state_address(program_id).0,
-            disable_pool_authority_list: find_disable_pool_authority_list_address(program_id).0,
+            pool_state: pool.pool_state,
+            disable_pool_authority_list: pool.disable_pool_authority_list_pda().address,
         })
     }
 
@@ -0,0 +1,252 @@
+use s_controller_interface::{RemoveLiquidityProportionalKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_lst_state_list_versioned, try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProportionalPdas {
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub protocol_fee: Pubkey,
+    pub pool_config: Pubkey,
+}
+
+impl RemoveLiquidityProportionalPdas {
+    pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
+        Self {
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            protocol_fee: pool.protocol_fee_pda().address,
+            pool_config: pool.pool_config_pda().address,
+        }
+    }
+}
+
+/// The per-LST accounts of a RemoveLiquidityProportional instruction
+/// that precede the LST's SOL value calculator accounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityProportionalLstKeys {
+    pub lst_mint: Pubkey,
+    pub lst_token_program: Pubkey,
+    pub pool_reserves: Pubkey,
+    pub protocol_fee_accumulator: Pubkey,
+    pub dst_lst_acc: Pubkey,
+    pub sol_value_calculator: Pubkey,
+}
+
+/// The per-LST accounts are verified separately against
+/// RemoveLiquidityFreeArgs on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProportionalFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> RemoveLiquidityProportionalFreeArgs<S> {
+    pub fn resolve(self) -> Result<RemoveLiquidityProportionalKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<RemoveLiquidityProportionalKeys, SControllerError> {
+        let Self {
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let pool_config_id = pool.pool_config_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok(RemoveLiquidityProportionalKeys {
+            signer,
+            src_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            pool_config: pool_config_id,
+            protocol_fee_lp_acc,
+        })
+    }
+}
+
+/// Suitable for use on client-side.
+/// `lst_mints` and `dst_lst_accs` must contain exactly one entry for each LST
+/// in the pool, in lst_state_list order.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProportionalByListFreeArgs<
+    'a,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mints: &'a [M],
+    pub dst_lst_accs: &'a [Pubkey],
+}
+
+impl<
+        'a,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > RemoveLiquidityProportionalByListFreeArgs<'a, S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve(
+        self,
+    ) -> Result<
+        (
+            RemoveLiquidityProportionalKeys,
+            Vec<RemoveLiquidityProportionalLstKeys>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(RemoveLiquidityProportionalPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            RemoveLiquidityProportionalKeys,
+            Vec<RemoveLiquidityProportionalLstKeys>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            RemoveLiquidityProportionalKeys,
+            Vec<RemoveLiquidityProportionalLstKeys>,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(RemoveLiquidityProportionalPdas::for_pool(pool))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_with_pdas(
+        self,
+        RemoveLiquidityProportionalPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            protocol_fee: protocol_fee_id,
+            pool_config: pool_config_id,
+        }: RemoveLiquidityProportionalPdas,
+    ) -> Result<
+        (
+            RemoveLiquidityProportionalKeys,
+            Vec<RemoveLiquidityProportionalLstKeys>,
+        ),
+        SControllerError,
+    > {
+        let Self {
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mints,
+            dst_lst_accs,
+        } = self;
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        if lst_mints.len() != lst_state_list.len() || dst_lst_accs.len() != lst_state_list.len() {
+            return Err(SControllerError::InvalidRemoveLiquidityProportionalEntries);
+        }
+
+        let lsts = lst_state_list
+            .iter()
+            .zip(lst_mints)
+            .zip(dst_lst_accs)
+            .map(|((lst_state, lst_mint), dst_lst_acc)| {
+                if *lst_mint.pubkey() != lst_state.mint {
+                    return Err(SControllerError::InvalidRemoveLiquidityProportionalEntries);
+                }
+                let lst_token_program = *lst_mint.owner();
+                Ok(RemoveLiquidityProportionalLstKeys {
+                    lst_mint: lst_state.mint,
+                    lst_token_program,
+                    pool_reserves: create_pool_reserves_address_with_pool_state_id(
+                        pool_state_id,
+                        lst_state,
+                        lst_token_program,
+                    )?,
+                    protocol_fee_accumulator:
+                        create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                            protocol_fee_id,
+                            lst_state,
+                            lst_token_program,
+                        )?,
+                    dst_lst_acc: *dst_lst_acc,
+                    sol_value_calculator: lst_state.sol_value_calculator,
+                })
+            })
+            .collect::<Result<Vec<_>, SControllerError>>()?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok((
+            RemoveLiquidityProportionalKeys {
+                signer,
+                src_lp_acc,
+                lp_token_mint: pool_state.lp_token_mint,
+                lp_token_program: spl_token::ID,
+                pool_state: pool_state_id,
+                lst_state_list: lst_state_list_id,
+                pool_config: pool_config_id,
+                protocol_fee_lp_acc,
+            },
+            lsts,
+        ))
+    }
+}
@@ -0,0 +1,243 @@
+use s_controller_interface::{RemoveLiquidityToStakeKeys, SControllerError};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, AddRemoveLiquidityProgramIds, PoolKey, SwapPdas,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityToStakeFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > RemoveLiquidityToStakeFreeArgs<S, L, M>
+{
+    pub fn resolve(self) -> Result<RemoveLiquidityToStakeKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<RemoveLiquidityToStakeKeys, SControllerError> {
+        let Self {
+            lst_index,
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        let pool_config_id = pool.pool_config_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok(RemoveLiquidityToStakeKeys {
+            signer,
+            lst_mint: *lst_mint.pubkey(),
+            src_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            protocol_fee_accumulator,
+            lst_token_program: *lst_mint.owner(),
+            lp_token_program: spl_token::ID,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            pool_reserves,
+            pool_config: pool_config_id,
+            protocol_fee_lp_acc,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityToStakeByMintFreeArgs<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > RemoveLiquidityToStakeByMintFreeArgs<S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve(
+        self,
+    ) -> Result<
+        (
+            RemoveLiquidityToStakeKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(SwapPdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            RemoveLiquidityToStakeKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            RemoveLiquidityToStakeKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(SwapPdas::for_pool(pool))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_with_pdas(
+        self,
+        SwapPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            protocol_fee: protocol_fee_id,
+            pool_config: pool_config_id,
+        }: SwapPdas,
+    ) -> Result<
+        (
+            RemoveLiquidityToStakeKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        let Self {
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lst_mint,
+        } = self;
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+        let (lst_index, lst_state) =
+            try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
+        let protocol_fee_accumulator =
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
+        let protocol_fee_lp_acc = find_protocol_fee_lp_address_with_protocol_fee_id(
+            protocol_fee_id,
+            pool_state.lp_token_mint,
+        )
+        .0;
+
+        Ok((
+            RemoveLiquidityToStakeKeys {
+                signer,
+                lst_mint: *lst_mint.pubkey(),
+                src_lp_acc,
+                lp_token_mint: pool_state.lp_token_mint,
+                protocol_fee_accumulator,
+                lst_token_program: *lst_mint.owner(),
+                lp_token_program: spl_token::ID,
+                pool_state: pool_state_id,
+                lst_state_list: lst_state_list_id,
+                pool_reserves,
+                pool_config: pool_config_id,
+                protocol_fee_lp_acc,
+            },
+            lst_index,
+            AddRemoveLiquidityProgramIds {
+                lst_calculator_program_id: lst_state.sol_value_calculator,
+                pricing_program_id: pool_state.pricing_program,
+            },
+        ))
+    }
+}
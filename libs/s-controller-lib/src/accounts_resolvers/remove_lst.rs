@@ -3,10 +3,11 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_protocol_fee_accumulator_address,
-    find_lst_state_list_address, find_pool_state_address, find_protocol_fee_address,
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey,
 };
 
 /// Must ensure protocol_fee_accumulator and pool_reserves token accounts
@@ -31,6 +32,10 @@ impl<
     > RemoveLstFreeArgs<S, L, M>
 {
     pub fn resolve(self) -> Result<RemoveLstKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<RemoveLstKeys, SControllerError> {
         let Self {
             lst_index,
             refund_rent_to,
@@ -38,23 +43,33 @@ impl<
             lst_state_list: lst_state_list_account,
             lst_mint,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let protocol_fee_id = pool.protocol_fee_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
+        let pool_state_acc_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_acc_data)?;
         let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
 
-        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, lst_index)?;
 
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
-
-        let pool_state_acc_data = pool_state_account.data();
-        let pool_state = try_pool_state(&pool_state_acc_data)?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
 
         Ok(RemoveLstKeys {
             admin: pool_state.admin,
@@ -62,9 +77,9 @@ impl<
             lst_mint: *lst_mint.pubkey(),
             pool_reserves,
             protocol_fee_accumulator,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee_accumulator_auth: protocol_fee_id,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
             lst_token_program: *lst_mint.owner(),
         })
     }
@@ -106,11 +121,18 @@ impl<
     pub fn resolve_for_prog(
         self,
         program_id: Pubkey,
+    ) -> Result<(RemoveLstKeys, RemoveLstIxArgs), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
     ) -> Result<(RemoveLstKeys, RemoveLstIxArgs), SControllerError> {
         self.resolve_with_pdas(RemoveLstPdas {
-            pool_state: find_pool_state_address(program_id).0,
-            protocol_fee_accumulator_auth: find_protocol_fee_address(program_id).0,
-            lst_state_list: find_lst_state_list_address(program_id).0,
+            pool_state: pool.pool_state,
+            protocol_fee_accumulator_auth: pool.protocol_fee_pda().address,
+            lst_state_list: pool.lst_state_list_pda().address,
         })
     }
 
@@ -130,17 +152,25 @@ impl<
             lst_mint,
         } = self;
 
+        let pool_state_acc_data = pool_state_account.data();
+        let pool_state_deser = try_pool_state(&pool_state_acc_data)?;
         let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list_deser = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state_list_deser =
+            try_lst_state_list_versioned(pool_state_deser.version, &lst_state_list_acc_data)?;
 
         let (lst_index, lst_state) =
-            try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list_deser)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+            try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list_deser)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
-
-        let pool_state_acc_data = pool_state_account.data();
-        let pool_state_deser = try_pool_state(&pool_state_acc_data)?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_accumulator_auth,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
 
         Ok((
             RemoveLstKeys {
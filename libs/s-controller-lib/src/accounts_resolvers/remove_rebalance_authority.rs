@@ -0,0 +1,151 @@
+use s_controller_interface::{
+    RemoveRebalanceAuthorityIxArgs, RemoveRebalanceAuthorityKeys, SControllerError,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32,
+    program::{POOL_STATE_ID, REBALANCE_AUTHORITY_LIST_ID},
+    try_find_rebalance_authority, try_pool_state, try_rebalance_authority_list, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveRebalanceAuthorityFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub index: usize,
+    pub refund_rent_to: Pubkey,
+    pub pool_state_acc: S,
+    pub rebalance_authority_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > RemoveRebalanceAuthorityFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<RemoveRebalanceAuthorityKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<RemoveRebalanceAuthorityKeys, SControllerError> {
+        let rebalance_authority_list_id = pool.rebalance_authority_list_pda().address;
+        if *self.pool_state_acc.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.rebalance_authority_list.pubkey() != rebalance_authority_list_id {
+            return Err(SControllerError::IncorrectRebalanceAuthorityList);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let list_data = self.rebalance_authority_list.data();
+        let list = try_rebalance_authority_list(&list_data)?;
+        let budget = list
+            .get(self.index)
+            .ok_or(SControllerError::InvalidRebalanceAuthorityIndex)?;
+
+        Ok(RemoveRebalanceAuthorityKeys {
+            refund_rent_to: self.refund_rent_to,
+            admin: pool_state.admin,
+            rebalance_authority: budget.authority,
+            pool_state: pool.pool_state,
+            rebalance_authority_list: rebalance_authority_list_id,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RebalanceAuthorityListPdas {
+    pub pool_state: Pubkey,
+    pub rebalance_authority_list: Pubkey,
+}
+
+impl RebalanceAuthorityListPdas {
+    pub fn for_prog(program_id: Pubkey) -> Self {
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
+        Self {
+            pool_state: pool.pool_state,
+            rebalance_authority_list: pool.rebalance_authority_list_pda().address,
+        }
+    }
+}
+
+/// Iterates through rebalance_authority_list to find the index.
+/// Does not check identity of pool_state_acc and rebalance_authority_list
+/// Suitable for use client-side.
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveRebalanceAuthorityByPubkeyFreeArgs<S, L> {
+    pub refund_rent_to: Pubkey,
+    pub rebalance_authority: Pubkey,
+    pub pool_state_acc: S,
+    pub rebalance_authority_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData>
+    RemoveRebalanceAuthorityByPubkeyFreeArgs<S, L>
+{
+    pub fn resolve(
+        &self,
+    ) -> Result<(RemoveRebalanceAuthorityKeys, RemoveRebalanceAuthorityIxArgs), SControllerError>
+    {
+        self.resolve_with_pdas(RebalanceAuthorityListPdas {
+            pool_state: POOL_STATE_ID,
+            rebalance_authority_list: REBALANCE_AUTHORITY_LIST_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(RemoveRebalanceAuthorityKeys, RemoveRebalanceAuthorityIxArgs), SControllerError>
+    {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(RemoveRebalanceAuthorityKeys, RemoveRebalanceAuthorityIxArgs), SControllerError>
+    {
+        self.resolve_with_pdas(RebalanceAuthorityListPdas::for_pool(pool))
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        RebalanceAuthorityListPdas {
+            pool_state: pool_state_id,
+            rebalance_authority_list,
+        }: RebalanceAuthorityListPdas,
+    ) -> Result<(RemoveRebalanceAuthorityKeys, RemoveRebalanceAuthorityIxArgs), SControllerError>
+    {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let list_data = self.rebalance_authority_list.data();
+        let list = try_rebalance_authority_list(&list_data)?;
+        let (index, _budget) = try_find_rebalance_authority(self.rebalance_authority, list)
+            .ok_or(SControllerError::InvalidRebalanceAuthorityIndex)?;
+        Ok((
+            RemoveRebalanceAuthorityKeys {
+                refund_rent_to: self.refund_rent_to,
+                admin: pool_state.admin,
+                rebalance_authority: self.rebalance_authority,
+                pool_state: pool_state_id,
+                rebalance_authority_list,
+            },
+            RemoveRebalanceAuthorityIxArgs {
+                index: index_to_u32(index)?,
+            },
+        ))
+    }
+}
@@ -1,27 +1,39 @@
 use s_controller_interface::{SControllerError, SetAdminKeys};
-use solana_program::pubkey::Pubkey;
+use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetAdminFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
     pub new_admin: Pubkey,
     pub pool_state: S,
 }
 
 impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetAdminFreeArgs<S> {
     pub fn resolve(self) -> Result<SetAdminKeys, SControllerError> {
-        self.resolve_inner(POOL_STATE_ID)
+        self.resolve_inner(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetAdminKeys, SControllerError> {
-        let pool_state_id = find_pool_state_address(program_id).0;
-        self.resolve_inner(pool_state_id)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
     }
 
-    fn resolve_inner(self, pool_state_id: Pubkey) -> Result<SetAdminKeys, SControllerError> {
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<SetAdminKeys, SControllerError> {
+        self.resolve_inner(pool.pool_state, pool.pending_authorities_pda().address)
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+        pending_authorities_id: Pubkey,
+    ) -> Result<SetAdminKeys, SControllerError> {
         let SetAdminFreeArgs {
+            payer,
             new_admin,
             pool_state: pool_state_acc,
         } = self;
@@ -37,6 +49,9 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetAdminFreeArgs<S> {
             current_admin: pool_state.admin,
             new_admin,
             pool_state: pool_state_id,
+            payer,
+            pending_authorities: pending_authorities_id,
+            system_program: system_program::ID,
         })
     }
 }
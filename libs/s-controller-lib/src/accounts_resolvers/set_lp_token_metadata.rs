@@ -0,0 +1,68 @@
+use s_controller_interface::{SControllerError, SetLpTokenMetadataKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{program::POOL_STATE_ID, try_pool_state, LpTokenMetadataProgram, PoolKey};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetLpTokenMetadataFreeArgs<S, M> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+    pub lp_token_mint: M,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey, M: ReadonlyAccountOwner>
+    SetLpTokenMetadataFreeArgs<S, M>
+{
+    pub fn resolve(self) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_inner(POOL_STATE_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner> SetLpTokenMetadataFreeArgs<S, M> {
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        self.resolve_inner(pool.pool_state)
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+    ) -> Result<SetLpTokenMetadataKeys, SControllerError> {
+        let Self {
+            payer,
+            pool_state: pool_state_acc,
+            lp_token_mint,
+        } = self;
+
+        let pool_state_data = pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let lp_token_program = *lp_token_mint.owner();
+        let metadata_program =
+            LpTokenMetadataProgram::try_from_lp_token_program(&lp_token_program)?;
+
+        Ok(SetLpTokenMetadataKeys {
+            admin: pool_state.admin,
+            payer,
+            pool_state: pool_state_id,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program,
+            metadata: metadata_program.metadata_address(pool_state.lp_token_mint),
+            metadata_program: metadata_program.program_id(),
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use s_controller_interface::{LstState, PoolState, SControllerError, SetLstCircuitBreakerKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_pool_state, PoolKey,
+};
+
+pub struct SetLstCircuitBreakerFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > SetLstCircuitBreakerFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<SetLstCircuitBreakerKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SetLstCircuitBreakerKeys, SControllerError> {
+        let Self {
+            lst_index,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        if *pool_state_account.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        let LstState { mint, .. } = lst_state_list
+            .get(*lst_index)
+            .ok_or(SControllerError::InvalidLstIndex)?;
+        let PoolState { admin, .. } = pool_state;
+
+        Ok(SetLstCircuitBreakerKeys {
+            admin: *admin,
+            lst_mint: *mint,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct SetLstCircuitBreakerByMintFreeArgs<S: ReadonlyAccountData, L: ReadonlyAccountData> {
+    pub lst_mint: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SetLstCircuitBreakerByMintFreeArgs<S, L> {
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve(&self) -> Result<(SetLstCircuitBreakerKeys, usize), SControllerError> {
+        self.resolve_with_pdas(POOL_STATE_ID, LST_STATE_LIST_ID)
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(SetLstCircuitBreakerKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<(SetLstCircuitBreakerKeys, usize), SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.lst_state_list_pda().address)
+    }
+
+    fn resolve_with_pdas(
+        &self,
+        pool_state: Pubkey,
+        lst_state_list: Pubkey,
+    ) -> Result<(SetLstCircuitBreakerKeys, usize), SControllerError> {
+        let Self {
+            lst_mint,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        let pool_state_data = pool_state_account.data();
+        let PoolState { admin, version, .. } = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = lst_state_list_account.data();
+        let list = try_lst_state_list_versioned(*version, &lst_state_list_data)?;
+        let (lst_index, LstState { mint, .. }) = try_find_lst_mint_on_list(*lst_mint, &list)?;
+
+        Ok((
+            SetLstCircuitBreakerKeys {
+                admin: *admin,
+                lst_mint: *mint,
+                pool_state,
+                lst_state_list,
+            },
+            lst_index,
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+use s_controller_interface::{SControllerError, SetPoolRebalanceAuthorityBudgetKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    program::{POOL_CONFIG_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetPoolRebalanceAuthorityBudgetFreeArgs<S> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetPoolRebalanceAuthorityBudgetFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetPoolRebalanceAuthorityBudgetKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_inner(POOL_STATE_ID, POOL_CONFIG_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> SetPoolRebalanceAuthorityBudgetFreeArgs<S> {
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetPoolRebalanceAuthorityBudgetKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<SetPoolRebalanceAuthorityBudgetKeys, SControllerError> {
+        self.resolve_inner(pool.pool_state, pool.pool_config_pda().address)
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+        pool_config_id: Pubkey,
+    ) -> Result<SetPoolRebalanceAuthorityBudgetKeys, SControllerError> {
+        let Self {
+            payer,
+            pool_state: pool_state_acc,
+        } = self;
+
+        let pool_state_data = pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetPoolRebalanceAuthorityBudgetKeys {
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            payer,
+            pool_config: pool_config_id,
+            system_program: system_program::ID,
+        })
+    }
+}
@@ -2,7 +2,7 @@ use s_controller_interface::{SControllerError, SetPricingProgramKeys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{program::POOL_STATE_ID, try_pool_state, PoolKey};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetPricingProgramFreeArgs<S> {
@@ -24,7 +24,14 @@ impl<S: ReadonlyAccountData> SetPricingProgramFreeArgs<S> {
         &self,
         program_id: Pubkey,
     ) -> Result<SetPricingProgramKeys, SControllerError> {
-        self.resolve_with_pool_state_id(find_pool_state_address(program_id).0)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SetPricingProgramKeys, SControllerError> {
+        self.resolve_with_pool_state_id(pool.pool_state)
     }
 
     pub fn resolve_with_pool_state_id(
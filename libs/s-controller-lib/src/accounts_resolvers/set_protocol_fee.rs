@@ -2,7 +2,7 @@ use s_controller_interface::{SControllerError, SetProtocolFeeKeys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{program::POOL_STATE_ID, try_pool_state, PoolKey};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetProtocolFeeFreeArgs<S> {
@@ -23,8 +23,11 @@ impl<S: ReadonlyAccountData> SetProtocolFeeFreeArgs<S> {
         self,
         program_id: Pubkey,
     ) -> Result<SetProtocolFeeKeys, SControllerError> {
-        let pool_state_id = find_pool_state_address(program_id).0;
-        self.resolve_with_pool_state_id(pool_state_id)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<SetProtocolFeeKeys, SControllerError> {
+        self.resolve_with_pool_state_id(pool.pool_state)
     }
 
     pub fn resolve_with_pool_state_id(
@@ -1,11 +1,15 @@
 use s_controller_interface::{SControllerError, SetProtocolFeeBeneficiaryKeys};
-use solana_program::pubkey::Pubkey;
+use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetProtocolFeeBeneficiaryFreeArgs<S> {
+    pub payer: Pubkey,
     pub new_beneficiary: Pubkey,
     pub pool_state: S,
 }
@@ -15,7 +19,7 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetProtocolFeeBeneficiaryFr
         if *self.pool_state.pubkey() != POOL_STATE_ID {
             return Err(SControllerError::IncorrectPoolState);
         }
-        self.resolve_with_pool_state_id(POOL_STATE_ID)
+        self.resolve_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
     }
 }
 
@@ -24,15 +28,23 @@ impl<S: ReadonlyAccountData> SetProtocolFeeBeneficiaryFreeArgs<S> {
         self,
         program_id: Pubkey,
     ) -> Result<SetProtocolFeeBeneficiaryKeys, SControllerError> {
-        let pool_state_id = find_pool_state_address(program_id).0;
-        self.resolve_with_pool_state_id(pool_state_id)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
     }
 
-    pub fn resolve_with_pool_state_id(
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<SetProtocolFeeBeneficiaryKeys, SControllerError> {
+        self.resolve_with_pdas(pool.pool_state, pool.pending_authorities_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
         self,
         pool_state_id: Pubkey,
+        pending_authorities_id: Pubkey,
     ) -> Result<SetProtocolFeeBeneficiaryKeys, SControllerError> {
         let SetProtocolFeeBeneficiaryFreeArgs {
+            payer,
             new_beneficiary,
             pool_state,
         } = self;
@@ -44,6 +56,9 @@ impl<S: ReadonlyAccountData> SetProtocolFeeBeneficiaryFreeArgs<S> {
             current_beneficiary: pool_state.protocol_fee_beneficiary,
             new_beneficiary,
             pool_state: pool_state_id,
+            payer,
+            pending_authorities: pending_authorities_id,
+            system_program: system_program::ID,
         })
     }
 }
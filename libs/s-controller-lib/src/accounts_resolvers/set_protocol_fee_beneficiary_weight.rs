@@ -0,0 +1,151 @@
+use s_controller_interface::{
+    SControllerError, SetProtocolFeeBeneficiaryWeightIxArgs, SetProtocolFeeBeneficiaryWeightKeys,
+};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32,
+    program::{POOL_STATE_ID, PROTOCOL_FEE_BENEFICIARY_LIST_ID},
+    try_find_protocol_fee_beneficiary, try_pool_state, try_protocol_fee_beneficiary_list, PoolKey,
+    ProtocolFeeBeneficiaryListPdas,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetProtocolFeeBeneficiaryWeightFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub index: usize,
+    pub pool_state_acc: S,
+    pub protocol_fee_beneficiary_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > SetProtocolFeeBeneficiaryWeightFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<SetProtocolFeeBeneficiaryWeightKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SetProtocolFeeBeneficiaryWeightKeys, SControllerError> {
+        let protocol_fee_beneficiary_list_id = pool.protocol_fee_beneficiary_list_pda().address;
+        if *self.pool_state_acc.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.protocol_fee_beneficiary_list.pubkey() != protocol_fee_beneficiary_list_id {
+            return Err(SControllerError::IncorrectProtocolFeeBeneficiaryList);
+        }
+
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let list_data = self.protocol_fee_beneficiary_list.data();
+        let list = try_protocol_fee_beneficiary_list(&list_data)?;
+        let share = list
+            .get(self.index)
+            .ok_or(SControllerError::InvalidProtocolFeeBeneficiaryIndex)?;
+
+        Ok(SetProtocolFeeBeneficiaryWeightKeys {
+            admin: pool_state.admin,
+            beneficiary: share.beneficiary,
+            pool_state: pool.pool_state,
+            protocol_fee_beneficiary_list: protocol_fee_beneficiary_list_id,
+        })
+    }
+}
+
+/// Iterates through protocol_fee_beneficiary_list to find the index.
+/// Does not check identity of pool_state_acc and protocol_fee_beneficiary_list
+/// Suitable for use client-side.
+#[derive(Clone, Copy, Debug)]
+pub struct SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs<S, L> {
+    pub beneficiary: Pubkey,
+    pub pool_state_acc: S,
+    pub protocol_fee_beneficiary_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData>
+    SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs<S, L>
+{
+    /// Returns the ix args with `weight_bps` set to the beneficiary's current weight
+    pub fn resolve(
+        &self,
+    ) -> Result<
+        (
+            SetProtocolFeeBeneficiaryWeightKeys,
+            SetProtocolFeeBeneficiaryWeightIxArgs,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(ProtocolFeeBeneficiaryListPdas {
+            pool_state: POOL_STATE_ID,
+            protocol_fee_beneficiary_list: PROTOCOL_FEE_BENEFICIARY_LIST_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            SetProtocolFeeBeneficiaryWeightKeys,
+            SetProtocolFeeBeneficiaryWeightIxArgs,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            SetProtocolFeeBeneficiaryWeightKeys,
+            SetProtocolFeeBeneficiaryWeightIxArgs,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(ProtocolFeeBeneficiaryListPdas::for_pool(pool))
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        ProtocolFeeBeneficiaryListPdas {
+            pool_state: pool_state_id,
+            protocol_fee_beneficiary_list,
+        }: ProtocolFeeBeneficiaryListPdas,
+    ) -> Result<
+        (
+            SetProtocolFeeBeneficiaryWeightKeys,
+            SetProtocolFeeBeneficiaryWeightIxArgs,
+        ),
+        SControllerError,
+    > {
+        let pool_state_data = self.pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let list_data = self.protocol_fee_beneficiary_list.data();
+        let list = try_protocol_fee_beneficiary_list(&list_data)?;
+        let (index, share) = try_find_protocol_fee_beneficiary(self.beneficiary, list)
+            .ok_or(SControllerError::InvalidProtocolFeeBeneficiaryIndex)?;
+        Ok((
+            SetProtocolFeeBeneficiaryWeightKeys {
+                admin: pool_state.admin,
+                beneficiary: self.beneficiary,
+                pool_state: pool_state_id,
+                protocol_fee_beneficiary_list,
+            },
+            SetProtocolFeeBeneficiaryWeightIxArgs {
+                index: index_to_u32(index)?,
+                weight_bps: share.weight_bps,
+            },
+        ))
+    }
+}
@@ -0,0 +1,72 @@
+use s_controller_interface::{SControllerError, SetProtocolFeesAsLpKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_pool_state, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetProtocolFeesAsLpFreeArgs<S> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetProtocolFeesAsLpFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetProtocolFeesAsLpKeys, SControllerError> {
+        if *self.pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_inner(POOL_STATE_ID, POOL_CONFIG_ID, PROTOCOL_FEE_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> SetProtocolFeesAsLpFreeArgs<S> {
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetProtocolFeesAsLpKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<SetProtocolFeesAsLpKeys, SControllerError> {
+        self.resolve_inner(
+            pool.pool_state,
+            pool.pool_config_pda().address,
+            pool.protocol_fee_pda().address,
+        )
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+        pool_config_id: Pubkey,
+        protocol_fee_id: Pubkey,
+    ) -> Result<SetProtocolFeesAsLpKeys, SControllerError> {
+        let Self {
+            payer,
+            pool_state: pool_state_acc,
+        } = self;
+
+        let pool_state_data = pool_state_acc.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetProtocolFeesAsLpKeys {
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            payer,
+            pool_config: pool_config_id,
+            system_program: system_program::ID,
+            protocol_fee_lp_acc: find_protocol_fee_lp_address_with_protocol_fee_id(
+                protocol_fee_id,
+                pool_state.lp_token_mint,
+            )
+            .0,
+        })
+    }
+}
@@ -1,26 +1,39 @@
 use s_controller_interface::{SControllerError, SetRebalanceAuthorityKeys};
-use solana_program::pubkey::Pubkey;
+use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    try_pool_state, PoolKey,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetRebalanceAuthorityFreeArgs {
+    pub payer: Pubkey,
     pub signer: Pubkey,
     pub new_rebalance_authority: Pubkey,
 }
 
 impl SetRebalanceAuthorityFreeArgs {
     pub fn resolve(self) -> SetRebalanceAuthorityKeys {
-        self.resolve_with_pool_state_id(POOL_STATE_ID)
+        self.resolve_with_pdas(POOL_STATE_ID, PENDING_AUTHORITIES_ID)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> SetRebalanceAuthorityKeys {
-        self.resolve_with_pool_state_id(find_pool_state_address(program_id).0)
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
     }
 
-    pub fn resolve_with_pool_state_id(self, pool_state_id: Pubkey) -> SetRebalanceAuthorityKeys {
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> SetRebalanceAuthorityKeys {
+        self.resolve_with_pdas(pool.pool_state, pool.pending_authorities_pda().address)
+    }
+
+    pub fn resolve_with_pdas(
+        self,
+        pool_state_id: Pubkey,
+        pending_authorities_id: Pubkey,
+    ) -> SetRebalanceAuthorityKeys {
         let Self {
+            payer,
             signer,
             new_rebalance_authority,
         } = self;
@@ -28,6 +41,9 @@ impl SetRebalanceAuthorityFreeArgs {
             signer,
             new_rebalance_authority,
             pool_state: pool_state_id,
+            payer,
+            pending_authorities: pending_authorities_id,
+            system_program: system_program::ID,
         }
     }
 }
@@ -35,6 +51,7 @@ impl SetRebalanceAuthorityFreeArgs {
 /// For use client-side, does not check identity of pool_state
 #[derive(Clone, Copy, Debug)]
 pub struct KnownAuthoritySetRebalanceAuthorityFreeArgs<D> {
+    pub payer: Pubkey,
     pub new_rebalance_authority: Pubkey,
     pub pool_state: D,
 }
@@ -44,28 +61,33 @@ impl<D: ReadonlyAccountData + ReadonlyAccountPubkey>
 {
     pub fn resolve_pool_admin_with_pool_state_id(
         &self,
+        pending_authorities_id: Pubkey,
     ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.pool_admin()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
-        .resolve_with_pool_state_id(*self.pool_state.pubkey()))
+        .resolve_with_pdas(*self.pool_state.pubkey(), pending_authorities_id))
     }
 
     pub fn resolve_current_rebalance_authority_with_pool_state_id(
         &self,
+        pending_authorities_id: Pubkey,
     ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.current_rebalance_authority()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
-        .resolve_with_pool_state_id(*self.pool_state.pubkey()))
+        .resolve_with_pdas(*self.pool_state.pubkey(), pending_authorities_id))
     }
 }
 
 impl<D: ReadonlyAccountData> KnownAuthoritySetRebalanceAuthorityFreeArgs<D> {
     pub fn resolve_pool_admin(&self) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.pool_admin()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
@@ -77,16 +99,30 @@ impl<D: ReadonlyAccountData> KnownAuthoritySetRebalanceAuthorityFreeArgs<D> {
         program_id: Pubkey,
     ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.pool_admin()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
         .resolve_for_prog(program_id))
     }
 
+    pub fn resolve_pool_admin_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
+        Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
+            signer: self.pool_admin()?,
+            new_rebalance_authority: self.new_rebalance_authority,
+        }
+        .resolve_for_pool(pool))
+    }
+
     pub fn resolve_current_rebalance_authority(
         &self,
     ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.current_rebalance_authority()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
@@ -98,12 +134,25 @@ impl<D: ReadonlyAccountData> KnownAuthoritySetRebalanceAuthorityFreeArgs<D> {
         program_id: Pubkey,
     ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
         Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
             signer: self.current_rebalance_authority()?,
             new_rebalance_authority: self.new_rebalance_authority,
         }
         .resolve_for_prog(program_id))
     }
 
+    pub fn resolve_current_rebalance_authority_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SetRebalanceAuthorityKeys, SControllerError> {
+        Ok(SetRebalanceAuthorityFreeArgs {
+            payer: self.payer,
+            signer: self.current_rebalance_authority()?,
+            new_rebalance_authority: self.new_rebalance_authority,
+        }
+        .resolve_for_pool(pool))
+    }
+
     fn pool_admin(&self) -> Result<Pubkey, SControllerError> {
         let pool_state_acc_data = self.pool_state.data();
         let pool_state = try_pool_state(&pool_state_acc_data)?;
@@ -3,9 +3,10 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
+    create_pool_reserves_address_with_pool_state_id,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -55,10 +56,17 @@ impl<
     pub fn resolve_for_prog(
         &self,
         program_id: Pubkey,
+    ) -> Result<SetSolValueCalculatorKeys, SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
     ) -> Result<SetSolValueCalculatorKeys, SControllerError> {
         self.resolve_inner(ResolveInner {
-            pool_state_id: find_pool_state_address(program_id).0,
-            lst_state_list_id: find_lst_state_list_address(program_id).0,
+            pool_state_id: pool.pool_state,
+            lst_state_list_id: pool.lst_state_list_pda().address,
         })
     }
 
@@ -75,13 +83,18 @@ impl<
             lst_state_list: lst_state_list_acc,
             lst_mint,
         } = self;
-        let lst_state_list_data = lst_state_list_acc.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
-        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, *lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
-
         let pool_state_data = pool_state_acc.data();
         let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = lst_state_list_acc.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        let lst_state =
+            try_match_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list, *lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
 
         Ok(SetSolValueCalculatorKeys {
             admin: pool_state.admin,
@@ -120,10 +133,17 @@ impl<
     pub fn resolve_for_prog(
         &self,
         program_id: Pubkey,
+    ) -> Result<(SetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        pool: &PoolKey,
     ) -> Result<(SetSolValueCalculatorKeys, usize), SControllerError> {
         self.resolve_inner(ResolveInner {
-            pool_state_id: find_pool_state_address(program_id).0,
-            lst_state_list_id: find_lst_state_list_address(program_id).0,
+            pool_state_id: pool.pool_state,
+            lst_state_list_id: pool.lst_state_list_pda().address,
         })
     }
     fn resolve_inner(
@@ -139,13 +159,18 @@ impl<
             lst_mint,
         } = self;
 
-        let lst_state_list_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
-        let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
-
         let pool_state_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_data)?;
+        let lst_state_list_data = lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        let (lst_index, lst_state) =
+            try_find_lst_mint_on_list(*lst_mint.pubkey(), &lst_state_list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
 
         Ok((
             SetSolValueCalculatorKeys {
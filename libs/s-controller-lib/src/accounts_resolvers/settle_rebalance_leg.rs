@@ -0,0 +1,117 @@
+use s_controller_interface::{AddRebalanceLegKeys, SControllerError, SettleRebalanceLegKeys};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id, index_to_usize, try_lst_state_list_versioned,
+    try_pool_state, try_rebalance_record_unsettled_dst_lst_indexes, PoolKey,
+};
+
+/// Requires an existing rebalance_record account.
+/// Should only be used on-chain
+#[derive(Clone, Copy, Debug)]
+pub struct SettleRebalanceLegFreeArgs<S, L, R, M> {
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub rebalance_record: R,
+    pub dst_lst_mint: M,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+        R: ReadonlyAccountData + ReadonlyAccountPubkey,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > SettleRebalanceLegFreeArgs<S, L, R, M>
+{
+    /// Returns (keys, dst_lst_index, position of dst_lst_index on the rebalance record's unsettled list)
+    pub fn resolve(self) -> Result<(SettleRebalanceLegKeys, usize, usize), SControllerError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    /// Returns (keys, dst_lst_index, position of dst_lst_index on the rebalance record's unsettled list)
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(SettleRebalanceLegKeys, usize, usize), SControllerError> {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let rebalance_record_id = pool.rebalance_record_pda().address;
+        if *self.pool_state.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        if *self.rebalance_record.pubkey() != rebalance_record_id {
+            return Err(SControllerError::IncorrectRebalanceRecord);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+
+        let rebalance_record_acc_data = self.rebalance_record.data();
+        let unsettled = try_rebalance_record_unsettled_dst_lst_indexes(&rebalance_record_acc_data)?;
+
+        let (position, dst_lst_index, dst_lst_state) = unsettled
+            .iter()
+            .enumerate()
+            .find_map(|(position, dst_lst_index)| {
+                let dst_lst_index = index_to_usize(*dst_lst_index).ok()?;
+                let dst_lst_state = list.get(dst_lst_index)?;
+                (dst_lst_state.mint == *self.dst_lst_mint.pubkey()).then_some((
+                    position,
+                    dst_lst_index,
+                    dst_lst_state,
+                ))
+            })
+            .ok_or(SControllerError::RebalanceLegNotFound)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
+
+        Ok((
+            SettleRebalanceLegKeys {
+                rebalance_authority: pool_state.rebalance_authority,
+                dst_lst_mint: dst_lst_state.mint,
+                dst_pool_reserves,
+                pool_state: pool.pool_state,
+                lst_state_list: lst_state_list_id,
+                rebalance_record: rebalance_record_id,
+            },
+            dst_lst_index,
+            position,
+        ))
+    }
+}
+
+/// Creates a corresponding SettleRebalanceLegKeys for a reference AddRebalanceLegKeys.
+///
+/// Suitable for use on client side.
+#[derive(Clone, Copy, Debug)]
+pub struct SettleRebalanceLegFromAddRebalanceLegKeys<'a>(pub &'a AddRebalanceLegKeys);
+
+impl<'a> SettleRebalanceLegFromAddRebalanceLegKeys<'a> {
+    pub fn resolve(self) -> SettleRebalanceLegKeys {
+        let Self(AddRebalanceLegKeys {
+            rebalance_authority,
+            dst_lst_mint,
+            dst_pool_reserves,
+            pool_state,
+            lst_state_list,
+            rebalance_record,
+            ..
+        }) = self;
+        SettleRebalanceLegKeys {
+            rebalance_authority: *rebalance_authority,
+            dst_lst_mint: *dst_lst_mint,
+            dst_pool_reserves: *dst_pool_reserves,
+            pool_state: *pool_state,
+            lst_state_list: *lst_state_list,
+            rebalance_record: *rebalance_record,
+        }
+    }
+}
@@ -3,11 +3,13 @@ use solana_program::{pubkey::Pubkey, system_program, sysvar};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
-    find_rebalance_record_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
-    SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
+    create_pool_reserves_address_with_pool_state_id,
+    program::{
+        LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, REBALANCE_AUTHORITY_LIST_ID,
+        REBALANCE_RECORD_ID,
+    },
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey, SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -15,17 +17,22 @@ pub struct RebalancePdas {
     pub pool_state: Pubkey,
     pub lst_state_list: Pubkey,
     pub rebalance_record: Pubkey,
+    pub rebalance_authority_list: Pubkey,
+    pub pool_config: Pubkey,
 }
 
 impl RebalancePdas {
     pub fn find_for_program_id(program_id: Pubkey) -> Self {
-        let (pool_state, _) = find_pool_state_address(program_id);
-        let (lst_state_list, _) = find_lst_state_list_address(program_id);
-        let (rebalance_record, _) = find_rebalance_record_address(program_id);
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
         Self {
-            pool_state,
-            lst_state_list,
-            rebalance_record,
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            rebalance_record: pool.rebalance_record_pda().address,
+            rebalance_authority_list: pool.rebalance_authority_list_pda().address,
+            pool_config: pool.pool_config_pda().address,
         }
     }
 }
@@ -54,34 +61,45 @@ impl<
     > StartRebalanceFreeArgs<SM, DM, S, L>
 {
     pub fn resolve(self) -> Result<StartRebalanceKeys, SControllerError> {
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<StartRebalanceKeys, SControllerError> {
+        let lst_state_list_id = pool.lst_state_list_pda().address;
+        let rebalance_record_id = pool.rebalance_record_pda().address;
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
-        if *self.pool_state.pubkey() != POOL_STATE_ID {
+        if *self.pool_state.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
 
+        let pool_state_acc_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_acc_data)?;
         let lst_state_list_acc_data = self.lst_state_list.data();
-        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let list = try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
 
         let src_lst_state =
-            try_match_lst_mint_on_list(*self.src_lst_mint.pubkey(), list, self.src_lst_index)?;
-        let src_pool_reserves =
-            create_pool_reserves_address(src_lst_state, *self.src_lst_mint.owner())?;
+            try_match_lst_mint_on_list(*self.src_lst_mint.pubkey(), &list, self.src_lst_index)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
 
         let dst_lst_state =
-            try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list, self.dst_lst_index)?;
-        let dst_pool_reserves =
-            create_pool_reserves_address(dst_lst_state, *self.dst_lst_mint.owner())?;
-
-        let pool_state_acc_data = self.pool_state.data();
-        let pool_state = try_pool_state(&pool_state_acc_data)?;
+            try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), &list, self.dst_lst_index)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
 
         Ok(StartRebalanceKeys {
             rebalance_authority: pool_state.rebalance_authority,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-            rebalance_record: REBALANCE_RECORD_ID,
+            pool_state: pool.pool_state,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
             src_lst_mint: src_lst_state.mint,
             dst_lst_mint: dst_lst_state.mint,
             src_pool_reserves,
@@ -90,6 +108,8 @@ impl<
             instructions: sysvar::instructions::ID,
             system_program: system_program::ID,
             src_lst_token_program: *self.src_lst_mint.owner(),
+            rebalance_authority_list: pool.rebalance_authority_list_pda().address,
+            pool_config: pool.pool_config_pda().address,
         })
     }
 }
@@ -131,6 +151,8 @@ impl<
             pool_state: POOL_STATE_ID,
             lst_state_list: LST_STATE_LIST_ID,
             rebalance_record: REBALANCE_RECORD_ID,
+            rebalance_authority_list: REBALANCE_AUTHORITY_LIST_ID,
+            pool_config: POOL_CONFIG_ID,
         })
     }
 
@@ -145,7 +167,21 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_with_pdas(RebalancePdas::find_for_program_id(program_id))
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            StartRebalanceKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_with_pdas(RebalancePdas::for_pool(pool))
     }
 
     pub fn resolve_with_pdas(
@@ -154,6 +190,8 @@ impl<
             pool_state,
             lst_state_list,
             rebalance_record,
+            rebalance_authority_list,
+            pool_config,
         }: RebalancePdas,
     ) -> Result<
         (
@@ -163,24 +201,30 @@ impl<
         ),
         SControllerError,
     > {
-        let lst_state_list_acc_data = self.lst_state_list.data();
-        let list = try_lst_state_list(&lst_state_list_acc_data)?;
-
-        let (src_lst_index, src_lst_state) =
-            try_find_lst_mint_on_list(*self.src_lst_mint.pubkey(), list)?;
-        let src_pool_reserves =
-            create_pool_reserves_address(src_lst_state, *self.src_lst_mint.owner())?;
-
-        let (dst_lst_index, dst_lst_state) =
-            try_find_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list)?;
-        let dst_pool_reserves =
-            create_pool_reserves_address(dst_lst_state, *self.dst_lst_mint.owner())?;
-
         let pool_state_acc_data = self.pool_state.data();
         let PoolState {
             rebalance_authority,
+            version,
             ..
         } = try_pool_state(&pool_state_acc_data)?;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(*version, &lst_state_list_acc_data)?;
+
+        let (src_lst_index, src_lst_state) =
+            try_find_lst_mint_on_list(*self.src_lst_mint.pubkey(), &list)?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
+
+        let (dst_lst_index, dst_lst_state) =
+            try_find_lst_mint_on_list(*self.dst_lst_mint.pubkey(), &list)?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
 
         Ok((
             StartRebalanceKeys {
@@ -196,6 +240,8 @@ impl<
                 instructions: sysvar::instructions::ID,
                 system_program: system_program::ID,
                 src_lst_token_program: *self.src_lst_mint.owner(),
+                rebalance_authority_list,
+                pool_config,
             },
             SrcDstLstIndexes {
                 src_lst_index,
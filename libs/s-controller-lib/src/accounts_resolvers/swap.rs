@@ -3,17 +3,41 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address,
+    create_pool_reserves_address_with_pool_state_id,
     create_protocol_fee_accumulator_address_with_protocol_fee_id,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, SrcDstLstIndexes,
-    SrcDstLstSolValueCalcProgramIds, SwapLiquidityPdas,
+    find_protocol_fee_lp_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_CONFIG_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey, SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
 };
 
+#[derive(Clone, Copy, Debug)]
+pub struct SwapPdas {
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub protocol_fee: Pubkey,
+    pub pool_config: Pubkey,
+}
+
+impl SwapPdas {
+    pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Self::for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn for_pool(pool: &PoolKey) -> Self {
+        Self {
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+            protocol_fee: pool.protocol_fee_pda().address,
+            pool_config: pool.pool_config_pda().address,
+        }
+    }
+}
+
 pub struct SwapFreeArgs<
     SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
     DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
     L: ReadonlyAccountData + ReadonlyAccountPubkey,
 > {
     pub src_lst_index: usize,
@@ -21,25 +45,33 @@ pub struct SwapFreeArgs<
     pub signer: Pubkey,
     pub src_lst_acc: Pubkey,
     pub dst_lst_acc: Pubkey,
+    /// Defaults to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: Option<Pubkey>,
     pub src_lst_mint: SM,
     pub dst_lst_mint: DM,
+    pub pool_state: S,
     pub lst_state_list: L,
 }
 
 struct SwapComputedKeys {
+    pub pdas: SwapPdas,
     pub src_pool_reserves: Pubkey,
     pub dst_pool_reserves: Pubkey,
     pub protocol_fee_accumulator: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub protocol_fee_lp_acc: Pubkey,
 }
 
 impl<
         SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
         DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
         L: ReadonlyAccountData + ReadonlyAccountPubkey,
-    > SwapFreeArgs<SM, DM, L>
+    > SwapFreeArgs<SM, DM, S, L>
 {
-    fn compute_keys(&self) -> Result<SwapComputedKeys, SControllerError> {
+    fn compute_keys(&self, pool: &PoolKey) -> Result<SwapComputedKeys, SControllerError> {
         let Self {
+            pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
             src_lst_mint,
             dst_lst_mint,
@@ -47,40 +79,98 @@ impl<
             dst_lst_index,
             ..
         } = self;
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        let SwapPdas {
+            pool_state,
+            lst_state_list,
+            protocol_fee,
+            pool_config,
+        } = SwapPdas::for_pool(pool);
+        if *pool_state_account.pubkey() != pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != lst_state_list {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
+        let pool_state_acc_data = pool_state_account.data();
+        let pool_state_data = try_pool_state(&pool_state_acc_data)?;
         let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state_list_data =
+            try_lst_state_list_versioned(pool_state_data.version, &lst_state_list_acc_data)?;
 
-        let src_lst_state =
-            try_match_lst_mint_on_list(*src_lst_mint.pubkey(), lst_state_list, *src_lst_index)?;
-        let src_pool_reserves = create_pool_reserves_address(src_lst_state, *src_lst_mint.owner())?;
+        let src_lst_state = try_match_lst_mint_on_list(
+            *src_lst_mint.pubkey(),
+            &lst_state_list_data,
+            *src_lst_index,
+        )?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            src_lst_state,
+            *src_lst_mint.owner(),
+        )?;
 
-        let dst_lst_state =
-            try_match_lst_mint_on_list(*dst_lst_mint.pubkey(), lst_state_list, *dst_lst_index)?;
-        let dst_pool_reserves = create_pool_reserves_address(dst_lst_state, *dst_lst_mint.owner())?;
+        let dst_lst_state = try_match_lst_mint_on_list(
+            *dst_lst_mint.pubkey(),
+            &lst_state_list_data,
+            *dst_lst_index,
+        )?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            dst_lst_state,
+            *dst_lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(dst_lst_state, *dst_lst_mint.owner())?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee,
+                dst_lst_state,
+                *dst_lst_mint.owner(),
+            )?;
+        let lp_token_mint = pool_state_data.lp_token_mint;
+        let protocol_fee_lp_acc =
+            find_protocol_fee_lp_address_with_protocol_fee_id(protocol_fee, lp_token_mint).0;
 
         Ok(SwapComputedKeys {
+            pdas: SwapPdas {
+                pool_state,
+                lst_state_list,
+                protocol_fee,
+                pool_config,
+            },
             src_pool_reserves,
             dst_pool_reserves,
             protocol_fee_accumulator,
+            lp_token_mint,
+            protocol_fee_lp_acc,
         })
     }
 
     pub fn resolve_exact_in(&self) -> Result<SwapExactInKeys, SControllerError> {
+        self.resolve_exact_in_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_exact_in_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SwapExactInKeys, SControllerError> {
         let SwapComputedKeys {
+            pdas:
+                SwapPdas {
+                    pool_state,
+                    lst_state_list,
+                    pool_config,
+                    ..
+                },
             src_pool_reserves,
             dst_pool_reserves,
             protocol_fee_accumulator,
-        } = self.compute_keys()?;
+            lp_token_mint,
+            protocol_fee_lp_acc,
+        } = self.compute_keys(pool)?;
         let Self {
             signer,
             src_lst_acc,
             dst_lst_acc,
+            referrer_dst_lst_acc,
             src_lst_mint,
             dst_lst_mint,
             ..
@@ -94,23 +184,45 @@ impl<
             protocol_fee_accumulator,
             src_lst_token_program: *src_lst_mint.owner(),
             dst_lst_token_program: *dst_lst_mint.owner(),
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state,
+            lst_state_list,
             src_pool_reserves,
             dst_pool_reserves,
+            pool_config,
+            referrer_dst_lst_acc: referrer_dst_lst_acc.unwrap_or(protocol_fee_accumulator),
+            lp_token_mint,
+            protocol_fee_lp_acc,
+            lp_token_program: spl_token::ID,
         })
     }
 
     pub fn resolve_exact_out(&self) -> Result<SwapExactOutKeys, SControllerError> {
+        self.resolve_exact_out_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_exact_out_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<SwapExactOutKeys, SControllerError> {
         let SwapComputedKeys {
+            pdas:
+                SwapPdas {
+                    pool_state,
+                    lst_state_list,
+                    pool_config,
+                    ..
+                },
             src_pool_reserves,
             dst_pool_reserves,
             protocol_fee_accumulator,
-        } = self.compute_keys()?;
+            lp_token_mint,
+            protocol_fee_lp_acc,
+        } = self.compute_keys(pool)?;
         let Self {
             signer,
             src_lst_acc,
             dst_lst_acc,
+            referrer_dst_lst_acc,
             src_lst_mint,
             dst_lst_mint,
             ..
@@ -124,35 +236,45 @@ impl<
             protocol_fee_accumulator,
             src_lst_token_program: *src_lst_mint.owner(),
             dst_lst_token_program: *dst_lst_mint.owner(),
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state,
+            lst_state_list,
             src_pool_reserves,
             dst_pool_reserves,
+            pool_config,
+            referrer_dst_lst_acc: referrer_dst_lst_acc.unwrap_or(protocol_fee_accumulator),
+            lp_token_mint,
+            protocol_fee_lp_acc,
+            lp_token_program: spl_token::ID,
         })
     }
 }
 
 /// Iterates through lst_state_list to find the lst indexes.
 /// Suitable for use on client side.
-/// Does not check identity of lst_state_list
+/// Does not check identity of pool_state and lst_state_list
 pub struct SwapByMintsFreeArgs<
     SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
     DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
     L: ReadonlyAccountData,
 > {
     pub signer: Pubkey,
     pub src_lst_acc: Pubkey,
     pub dst_lst_acc: Pubkey,
+    /// Defaults to protocol_fee_accumulator if there is no referrer
+    pub referrer_dst_lst_acc: Option<Pubkey>,
     pub src_lst_mint: SM,
     pub dst_lst_mint: DM,
+    pub pool_state: S,
     pub lst_state_list: L,
 }
 
 impl<
         SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
         DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData,
         L: ReadonlyAccountData,
-    > SwapByMintsFreeArgs<SM, DM, L>
+    > SwapByMintsFreeArgs<SM, DM, S, L>
 {
     /// Returns
     /// (keys, indices, src_dst_lst_sol_value_calc_program_ids, pricing_program_program_id)
@@ -166,10 +288,11 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_in_with_pdas(SwapLiquidityPdas {
+        self.resolve_exact_in_with_pdas(SwapPdas {
             pool_state: POOL_STATE_ID,
             lst_state_list: LST_STATE_LIST_ID,
             protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
         })
     }
 
@@ -185,10 +308,11 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_out_with_pdas(SwapLiquidityPdas {
+        self.resolve_exact_out_with_pdas(SwapPdas {
             pool_state: POOL_STATE_ID,
             lst_state_list: LST_STATE_LIST_ID,
             protocol_fee: PROTOCOL_FEE_ID,
+            pool_config: POOL_CONFIG_ID,
         })
     }
 
@@ -205,7 +329,23 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_in_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+        self.resolve_exact_in_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids, pricing_program_program_id)
+    pub fn resolve_exact_in_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            SwapExactInKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_in_with_pdas(SwapPdas::for_pool(pool))
     }
 
     /// Returns
@@ -221,14 +361,30 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_out_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+        self.resolve_exact_out_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Returns
+    /// (keys, indices, src_dst_lst_sol_value_calc_program_ids, pricing_program_program_id)
+    pub fn resolve_exact_out_for_pool(
+        &self,
+        pool: &PoolKey,
+    ) -> Result<
+        (
+            SwapExactOutKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_with_pdas(SwapPdas::for_pool(pool))
     }
 
     /// Returns
     /// (keys, indices, src_dst_lst_sol_value_calc_program_ids, pricing_program_program_id)
     pub fn resolve_exact_in_with_pdas(
         &self,
-        pdas: SwapLiquidityPdas,
+        pdas: SwapPdas,
     ) -> Result<
         (
             SwapExactInKeys,
@@ -242,6 +398,9 @@ impl<
                 src_pool_reserves,
                 dst_pool_reserves,
                 protocol_fee_accumulator,
+                lp_token_mint,
+                protocol_fee_lp_acc,
+                ..
             },
             indexes,
             program_ids,
@@ -251,6 +410,7 @@ impl<
             signer,
             src_lst_acc,
             dst_lst_acc,
+            referrer_dst_lst_acc,
             src_lst_mint,
             dst_lst_mint,
             ..
@@ -269,6 +429,11 @@ impl<
                 lst_state_list: pdas.lst_state_list,
                 src_pool_reserves,
                 dst_pool_reserves,
+                pool_config: pdas.pool_config,
+                referrer_dst_lst_acc: referrer_dst_lst_acc.unwrap_or(protocol_fee_accumulator),
+                lp_token_mint,
+                protocol_fee_lp_acc,
+                lp_token_program: spl_token::ID,
             },
             indexes,
             program_ids,
@@ -279,7 +444,7 @@ impl<
     /// (keys, indices, src_dst_lst_sol_value_calc_program_ids, pricing_program_program_id)
     pub fn resolve_exact_out_with_pdas(
         &self,
-        pdas: SwapLiquidityPdas,
+        pdas: SwapPdas,
     ) -> Result<
         (
             SwapExactOutKeys,
@@ -293,6 +458,9 @@ impl<
                 src_pool_reserves,
                 dst_pool_reserves,
                 protocol_fee_accumulator,
+                lp_token_mint,
+                protocol_fee_lp_acc,
+                ..
             },
             indexes,
             program_ids,
@@ -302,6 +470,7 @@ impl<
             signer,
             src_lst_acc,
             dst_lst_acc,
+            referrer_dst_lst_acc,
             src_lst_mint,
             dst_lst_mint,
             ..
@@ -320,6 +489,11 @@ impl<
                 lst_state_list: pdas.lst_state_list,
                 src_pool_reserves,
                 dst_pool_reserves,
+                pool_config: pdas.pool_config,
+                referrer_dst_lst_acc: referrer_dst_lst_acc.unwrap_or(protocol_fee_accumulator),
+                lp_token_mint,
+                protocol_fee_lp_acc,
+                lp_token_program: spl_token::ID,
             },
             indexes,
             program_ids,
@@ -328,11 +502,7 @@ impl<
 
     fn compute_keys_and_indexes(
         &self,
-        SwapLiquidityPdas {
-            pool_state: pool_state_id,
-            protocol_fee: protocol_fee_id,
-            ..
-        }: SwapLiquidityPdas,
+        pdas: SwapPdas,
     ) -> Result<
         (
             SwapComputedKeys,
@@ -342,17 +512,26 @@ impl<
         SControllerError,
     > {
         let Self {
+            pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
             src_lst_mint,
             dst_lst_mint,
             ..
         } = self;
+        let SwapPdas {
+            pool_state: pool_state_id,
+            protocol_fee: protocol_fee_id,
+            ..
+        } = pdas;
 
+        let pool_state_acc_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_acc_data)?;
         let lst_state_list_acc_data = lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
 
         let (src_lst_index, src_lst_state) =
-            try_find_lst_mint_on_list(*src_lst_mint.pubkey(), lst_state_list)?;
+            try_find_lst_mint_on_list(*src_lst_mint.pubkey(), &lst_state_list)?;
         let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
             pool_state_id,
             src_lst_state,
@@ -360,7 +539,7 @@ impl<
         )?;
 
         let (dst_lst_index, dst_lst_state) =
-            try_find_lst_mint_on_list(*dst_lst_mint.pubkey(), lst_state_list)?;
+            try_find_lst_mint_on_list(*dst_lst_mint.pubkey(), &lst_state_list)?;
         let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
             pool_state_id,
             dst_lst_state,
@@ -372,12 +551,18 @@ impl<
                 dst_lst_state,
                 *dst_lst_mint.owner(),
             )?;
+        let lp_token_mint = pool_state.lp_token_mint;
+        let protocol_fee_lp_acc =
+            find_protocol_fee_lp_address_with_protocol_fee_id(protocol_fee_id, lp_token_mint).0;
 
         Ok((
             SwapComputedKeys {
+                pdas,
                 src_pool_reserves,
                 dst_pool_reserves,
                 protocol_fee_accumulator,
+                lp_token_mint,
+                protocol_fee_lp_acc,
             },
             SrcDstLstIndexes {
                 src_lst_index,
@@ -3,40 +3,58 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
+    create_pool_reserves_address_with_pool_state_id,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list,
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_match_lst_mint_on_list,
+    try_pool_state, PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
 pub struct SyncSolValueFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
     L: ReadonlyAccountData + ReadonlyAccountPubkey,
     M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
 > {
     pub lst_index: usize,
+    pub pool_state: S,
     pub lst_state_list: L,
     pub lst_mint: M,
 }
 
 impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
         L: ReadonlyAccountData + ReadonlyAccountPubkey,
         M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
-    > SyncSolValueFreeArgs<L, M>
+    > SyncSolValueFreeArgs<S, L, M>
 {
     pub fn resolve(self) -> Result<SyncSolValueKeys, SControllerError> {
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(self, pool: &PoolKey) -> Result<SyncSolValueKeys, SControllerError> {
+        let lst_state_list = pool.lst_state_list_pda().address;
+        if *self.pool_state.pubkey() != pool.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *self.lst_state_list.pubkey() != lst_state_list {
             return Err(SControllerError::IncorrectLstStateList);
         }
+        let pool_state_acc_data = self.pool_state.data();
+        let version = try_pool_state(&pool_state_acc_data)?.version;
         let lst_state_list_acc_data = self.lst_state_list.data();
-        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
 
-        let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), list, self.lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+        let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), &list, self.lst_index)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool.pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
 
         Ok(SyncSolValueKeys {
             lst_mint: lst_state.mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pool.pool_state,
+            lst_state_list,
             pool_reserves,
         })
     }
@@ -45,7 +63,8 @@ impl<
 /// Iterates through lst_state_list to find lst_index.
 /// Suitable for use on client-side
 #[derive(Clone, Copy, Debug)]
-pub struct SyncSolValueByMintFreeArgs<L, M> {
+pub struct SyncSolValueByMintFreeArgs<S, L, M> {
+    pub pool_state: S,
     pub lst_state_list: L,
     pub lst_mint: M,
 }
@@ -56,8 +75,11 @@ pub struct SyncSolValuePdas {
     pub lst_state_list: Pubkey,
 }
 
-impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
-    SyncSolValueByMintFreeArgs<L, M>
+impl<
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > SyncSolValueByMintFreeArgs<S, L, M>
 {
     /// Does not check identity of pool_state and lst_state_list
     /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
@@ -73,10 +95,19 @@ impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
     pub fn resolve_for_prog(
         self,
         program_id: Pubkey,
+    ) -> Result<(SyncSolValueKeys, usize, Pubkey), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
     ) -> Result<(SyncSolValueKeys, usize, Pubkey), SControllerError> {
         self.resolve_with_pdas(SyncSolValuePdas {
-            pool_state: find_pool_state_address(program_id).0,
-            lst_state_list: find_lst_state_list_address(program_id).0,
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
         })
     }
 
@@ -89,11 +120,17 @@ impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
             lst_state_list,
         }: SyncSolValuePdas,
     ) -> Result<(SyncSolValueKeys, usize, Pubkey), SControllerError> {
+        let pool_state_acc_data = self.pool_state.data();
+        let version = try_pool_state(&pool_state_acc_data)?.version;
         let lst_state_list_acc_data = self.lst_state_list.data();
-        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
 
-        let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), list)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+        let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), &list)?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
 
         Ok((
             SyncSolValueKeys {
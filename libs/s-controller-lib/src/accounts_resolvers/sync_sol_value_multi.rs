@@ -0,0 +1,106 @@
+use s_controller_interface::{SControllerError, SyncSolValueMultiKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list_versioned, try_pool_state, PoolKey,
+    SyncSolValuePdas,
+};
+
+/// The accounts of a single LST to sync in a SyncSolValueMulti instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SyncSolValueMultiLstKeys {
+    pub lst_index: usize,
+    pub lst_mint: Pubkey,
+    pub pool_reserves: Pubkey,
+    pub sol_value_calculator: Pubkey,
+}
+
+/// Iterates through lst_state_list to find each of lst_mints' index.
+/// Suitable for use on client-side
+#[derive(Clone, Copy, Debug)]
+pub struct SyncSolValueMultiByMintFreeArgs<'a, S, L, M> {
+    pub pool_state: S,
+    pub lst_state_list: L,
+    pub lst_mints: &'a [M],
+}
+
+impl<
+        'a,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > SyncSolValueMultiByMintFreeArgs<'a, S, L, M>
+{
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve(
+        self,
+    ) -> Result<(SyncSolValueMultiKeys, Vec<SyncSolValueMultiLstKeys>), SControllerError> {
+        self.resolve_with_pdas(SyncSolValuePdas {
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+        })
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(SyncSolValueMultiKeys, Vec<SyncSolValueMultiLstKeys>), SControllerError> {
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<(SyncSolValueMultiKeys, Vec<SyncSolValueMultiLstKeys>), SControllerError> {
+        self.resolve_with_pdas(SyncSolValuePdas {
+            pool_state: pool.pool_state,
+            lst_state_list: pool.lst_state_list_pda().address,
+        })
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    pub fn resolve_with_pdas(
+        self,
+        SyncSolValuePdas {
+            pool_state,
+            lst_state_list,
+        }: SyncSolValuePdas,
+    ) -> Result<(SyncSolValueMultiKeys, Vec<SyncSolValueMultiLstKeys>), SControllerError> {
+        let pool_state_acc_data = self.pool_state.data();
+        let version = try_pool_state(&pool_state_acc_data)?.version;
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
+
+        let lsts = self
+            .lst_mints
+            .iter()
+            .map(|lst_mint| {
+                let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), &list)?;
+                let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+                    pool_state,
+                    lst_state,
+                    *lst_mint.owner(),
+                )?;
+                Ok(SyncSolValueMultiLstKeys {
+                    lst_index,
+                    lst_mint: *lst_mint.pubkey(),
+                    pool_reserves,
+                    sol_value_calculator: lst_state.sol_value_calculator,
+                })
+            })
+            .collect::<Result<Vec<_>, SControllerError>>()?;
+
+        Ok((
+            SyncSolValueMultiKeys {
+                pool_state,
+                lst_state_list,
+            },
+            lsts,
+        ))
+    }
+}
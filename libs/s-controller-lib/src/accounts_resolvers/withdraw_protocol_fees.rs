@@ -4,10 +4,8 @@ use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    find_pool_state_address, find_protocol_fee_accumulator_address,
-    find_protocol_fee_accumulator_address_with_protocol_fee_id, find_protocol_fee_address,
-    program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_pool_state, FindLstPdaAtaKeys,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id, try_pool_state, FindLstPdaAtaKeys,
+    PoolKey,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -29,21 +27,32 @@ impl<
     > WithdrawProtocolFeesFreeArgs<S, W>
 {
     pub fn resolve(self) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
+        self.resolve_for_pool(&PoolKey::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
+        let protocol_fee_id = pool.protocol_fee_pda().address;
         let WithdrawProtocolFeesFreeArgs {
             pool_state,
             withdraw_to,
         } = self;
 
-        if *pool_state.pubkey() != POOL_STATE_ID {
+        if *pool_state.pubkey() != pool.pool_state {
             return Err(SControllerError::IncorrectPoolState.into());
         }
 
         let lst_mint = token_account_mint(&withdraw_to)?;
         let (protocol_fee_accumulator, _protocol_fee_accumulator_bump) =
-            find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
-                lst_mint,
-                token_program: *withdraw_to.owner(),
-            });
+            find_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                FindLstPdaAtaKeys {
+                    lst_mint,
+                    token_program: *withdraw_to.owner(),
+                },
+            );
         WithdrawProtocolFeesByMintFreeArgs {
             pool_state,
             withdraw_to: *withdraw_to.pubkey(),
@@ -53,8 +62,8 @@ impl<
             },
         }
         .resolve_with_pdas(WithdrawProtocolFeesPdas {
-            pool_state: POOL_STATE_ID,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
+            pool_state: pool.pool_state,
+            protocol_fee_accumulator_auth: protocol_fee_id,
             protocol_fee_accumulator,
         })
     }
@@ -74,7 +83,14 @@ impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
         self,
         program_id: Pubkey,
     ) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
-        let protocol_fee_accumulator_auth = find_protocol_fee_address(program_id).0;
+        self.resolve_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pool: &PoolKey,
+    ) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
+        let protocol_fee_accumulator_auth = pool.protocol_fee_pda().address;
         let protocol_fee_accumulator = find_protocol_fee_accumulator_address_with_protocol_fee_id(
             protocol_fee_accumulator_auth,
             FindLstPdaAtaKeys {
@@ -84,7 +100,7 @@ impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
         )
         .0;
         self.resolve_with_pdas(WithdrawProtocolFeesPdas {
-            pool_state: find_pool_state_address(program_id).0,
+            pool_state: pool.pool_state,
             protocol_fee_accumulator_auth,
             protocol_fee_accumulator,
         })
@@ -1,7 +1,13 @@
 use bytemuck::{try_from_bytes, try_from_bytes_mut, AnyBitPattern};
-use s_controller_interface::{LstState, PoolState, RebalanceRecord, SControllerError};
+use s_controller_interface::{
+    FlashLoanRecord, LstState, PendingAuthorities, PoolConfig, PoolState,
+    ProtocolFeeBeneficiaryShare, QueuedChange, RebalanceAuthorityBudget, RebalanceRecord,
+    SControllerError,
+};
 use solana_program::pubkey::Pubkey;
 
+use crate::{DEFAULT_POOL_CONFIG, REBALANCE_RECORD_SIZE};
+
 /// Tries to reinterpret `pool_state_acc_data` bytes as a PoolState
 pub fn try_pool_state(pool_state_acc_data: &[u8]) -> Result<&PoolState, SControllerError> {
     try_from_bytes(pool_state_acc_data).map_err(|_e| SControllerError::InvalidPoolStateData)
@@ -40,18 +46,137 @@ pub fn try_disable_pool_authority_list_mut(
         .ok_or(SControllerError::InvalidDisablePoolAuthorityListData)
 }
 
+/// Tries to reinterpret `protocol_fee_beneficiary_list_acc_data` bytes as a list of
+/// ProtocolFeeBeneficiaryShares. The PDA not having been created yet is an empty list.
+pub fn try_protocol_fee_beneficiary_list(
+    protocol_fee_beneficiary_list_acc_data: &[u8],
+) -> Result<&[ProtocolFeeBeneficiaryShare], SControllerError> {
+    try_list(protocol_fee_beneficiary_list_acc_data)
+        .ok_or(SControllerError::InvalidProtocolFeeBeneficiaryListData)
+}
+
+pub fn try_protocol_fee_beneficiary_list_mut(
+    protocol_fee_beneficiary_list_acc_data: &mut [u8],
+) -> Result<&mut [ProtocolFeeBeneficiaryShare], SControllerError> {
+    try_list_mut(protocol_fee_beneficiary_list_acc_data)
+        .ok_or(SControllerError::InvalidProtocolFeeBeneficiaryListData)
+}
+
+/// Tries to reinterpret `rebalance_authority_list_acc_data` bytes as a list of
+/// RebalanceAuthorityBudgets. The PDA not having been created yet is an empty list.
+pub fn try_rebalance_authority_list(
+    rebalance_authority_list_acc_data: &[u8],
+) -> Result<&[RebalanceAuthorityBudget], SControllerError> {
+    try_list(rebalance_authority_list_acc_data)
+        .ok_or(SControllerError::InvalidRebalanceAuthorityListData)
+}
+
+pub fn try_rebalance_authority_list_mut(
+    rebalance_authority_list_acc_data: &mut [u8],
+) -> Result<&mut [RebalanceAuthorityBudget], SControllerError> {
+    try_list_mut(rebalance_authority_list_acc_data)
+        .ok_or(SControllerError::InvalidRebalanceAuthorityListData)
+}
+
+/// Tries to reinterpret the header of `rebalance_record_acc_data` bytes as a RebalanceRecord.
+///
+/// The rebalance record is variable-length: the fixed-size header
+/// is followed by a list of the dst lst indexes of unsettled additional legs
 pub fn try_rebalance_record(
     rebalance_record_acc_data: &[u8],
 ) -> Result<&RebalanceRecord, SControllerError> {
-    try_from_bytes(rebalance_record_acc_data)
-        .map_err(|_e| SControllerError::InvalidRebalanceRecordData)
+    let header = rebalance_record_acc_data
+        .get(..REBALANCE_RECORD_SIZE)
+        .ok_or(SControllerError::InvalidRebalanceRecordData)?;
+    try_from_bytes(header).map_err(|_e| SControllerError::InvalidRebalanceRecordData)
 }
 
+/// Tries to reinterpret the header of `rebalance_record_acc_data` bytes as a mutable RebalanceRecord.
 pub fn try_rebalance_record_mut(
     rebalance_record_acc_data: &mut [u8],
 ) -> Result<&mut RebalanceRecord, SControllerError> {
-    try_from_bytes_mut(rebalance_record_acc_data)
-        .map_err(|_e| SControllerError::InvalidRebalanceRecordData)
+    let header = rebalance_record_acc_data
+        .get_mut(..REBALANCE_RECORD_SIZE)
+        .ok_or(SControllerError::InvalidRebalanceRecordData)?;
+    try_from_bytes_mut(header).map_err(|_e| SControllerError::InvalidRebalanceRecordData)
+}
+
+/// Tries to reinterpret the bytes following the header of `rebalance_record_acc_data`
+/// as the list of dst lst indexes of additional legs that have not been settled yet
+pub fn try_rebalance_record_unsettled_dst_lst_indexes(
+    rebalance_record_acc_data: &[u8],
+) -> Result<&[u32], SControllerError> {
+    rebalance_record_acc_data
+        .get(REBALANCE_RECORD_SIZE..)
+        .and_then(try_list)
+        .ok_or(SControllerError::InvalidRebalanceRecordData)
+}
+
+/// Tries to reinterpret the bytes following the header of `rebalance_record_acc_data`
+/// as the mutable list of dst lst indexes of additional legs that have not been settled yet
+pub fn try_rebalance_record_unsettled_dst_lst_indexes_mut(
+    rebalance_record_acc_data: &mut [u8],
+) -> Result<&mut [u32], SControllerError> {
+    rebalance_record_acc_data
+        .get_mut(REBALANCE_RECORD_SIZE..)
+        .and_then(try_list_mut)
+        .ok_or(SControllerError::InvalidRebalanceRecordData)
+}
+
+pub fn try_flash_loan_record(
+    flash_loan_record_acc_data: &[u8],
+) -> Result<&FlashLoanRecord, SControllerError> {
+    try_from_bytes(flash_loan_record_acc_data)
+        .map_err(|_e| SControllerError::InvalidFlashLoanRecordData)
+}
+
+pub fn try_flash_loan_record_mut(
+    flash_loan_record_acc_data: &mut [u8],
+) -> Result<&mut FlashLoanRecord, SControllerError> {
+    try_from_bytes_mut(flash_loan_record_acc_data)
+        .map_err(|_e| SControllerError::InvalidFlashLoanRecordData)
+}
+
+pub fn try_pending_authorities(
+    pending_authorities_acc_data: &[u8],
+) -> Result<&PendingAuthorities, SControllerError> {
+    try_from_bytes(pending_authorities_acc_data)
+        .map_err(|_e| SControllerError::InvalidPendingAuthoritiesData)
+}
+
+pub fn try_pending_authorities_mut(
+    pending_authorities_acc_data: &mut [u8],
+) -> Result<&mut PendingAuthorities, SControllerError> {
+    try_from_bytes_mut(pending_authorities_acc_data)
+        .map_err(|_e| SControllerError::InvalidPendingAuthoritiesData)
+}
+
+/// Reads the PoolConfig from `pool_config_acc_data`.
+/// Returns [`DEFAULT_POOL_CONFIG`] if the PDA has not been created yet.
+pub fn try_pool_config(pool_config_acc_data: &[u8]) -> Result<PoolConfig, SControllerError> {
+    if pool_config_acc_data.is_empty() {
+        return Ok(DEFAULT_POOL_CONFIG);
+    }
+    try_from_bytes(pool_config_acc_data)
+        .copied()
+        .map_err(|_e| SControllerError::InvalidPoolConfigData)
+}
+
+pub fn try_pool_config_mut(
+    pool_config_acc_data: &mut [u8],
+) -> Result<&mut PoolConfig, SControllerError> {
+    try_from_bytes_mut(pool_config_acc_data).map_err(|_e| SControllerError::InvalidPoolConfigData)
+}
+
+pub fn try_queued_change(queued_change_acc_data: &[u8]) -> Result<&QueuedChange, SControllerError> {
+    try_from_bytes(queued_change_acc_data).map_err(|_e| SControllerError::InvalidQueuedChangeData)
+}
+
+pub fn try_queued_change_mut(
+    queued_change_acc_data: &mut [u8],
+) -> Result<&mut QueuedChange, SControllerError> {
+    try_from_bytes_mut(queued_change_acc_data)
+        .map_err(|_e| SControllerError::InvalidQueuedChangeData)
 }
 
 /// Tries to reinterpret `list_acc_data` bytes as a slice.
@@ -59,6 +59,32 @@ pub fn calc_lp_tokens_sol_value(
     .apply(lp_tokens_amount)
 }
 
+/// Args:
+/// - `pool_total_sol_value`: total SOL value of the pool after the protocol fees have been
+///                           left in the pool reserves
+/// - `protocol_fees_sol_value`: SOL value of the protocol fees left in the pool reserves
+///
+/// Returns amount of LP tokens to mint to the protocol fee LP token account
+/// so that it has a claim on exactly `protocol_fees_sol_value`
+pub fn calc_protocol_fee_lp_tokens_to_mint(
+    LpTokenRateArgs {
+        lp_token_supply,
+        pool_total_sol_value,
+    }: LpTokenRateArgs,
+    protocol_fees_sol_value: u64,
+) -> Result<u64, MathError> {
+    let pool_total_sol_value_before_fees = pool_total_sol_value
+        .checked_sub(protocol_fees_sol_value)
+        .ok_or(MathError)?;
+    calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: pool_total_sol_value_before_fees,
+        },
+        protocol_fees_sol_value,
+    )
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CalcAddLiquidityArgs {
     pub lst_amount: u64,
@@ -175,3 +201,113 @@ pub fn calc_swap_protocol_fees(
     .apply(protocol_fees_sol_value)?;
     Ok(to_protocol_fees_lst_amount)
 }
+
+/// Returns amount of the swap's protocol fees, as returned by [`calc_swap_protocol_fees`],
+/// to transfer to the referrer instead of protocol_fee_accumulator
+pub fn calc_swap_referral_fees(
+    to_protocol_fees_lst_amount: u64,
+    referral_fee_bps: u16,
+) -> Result<u64, MathError> {
+    let aaf = CeilDiv(U64BpsFee::try_new(referral_fee_bps)?).apply(to_protocol_fees_lst_amount)?;
+    Ok(aaf.fee_charged())
+}
+
+/// Returns amount of LST to transfer from protocol_fee_accumulator
+/// to a protocol fee beneficiary with share `weight_bps`
+/// of the accumulator's `protocol_fee_accumulator_balance`
+pub fn calc_protocol_fee_beneficiary_share(
+    protocol_fee_accumulator_balance: u64,
+    weight_bps: u16,
+) -> Result<u64, MathError> {
+    let aaf = FloorDiv(U64BpsFee::try_new(weight_bps)?).apply(protocol_fee_accumulator_balance)?;
+    Ok(aaf.fee_charged())
+}
+
+/// Returns the max SOL value a rebalance started with `max_loss_lamports`
+/// is allowed to lose from the pool's `old_total_sol_value`
+pub fn calc_max_rebalance_sol_value_loss(
+    old_total_sol_value: u64,
+    max_rebalance_loss_bps: u16,
+    max_loss_lamports: u64,
+) -> Result<u64, MathError> {
+    let aaf = FloorDiv(U64BpsFee::try_new(max_rebalance_loss_bps)?).apply(old_total_sol_value)?;
+    Ok(aaf.fee_charged().min(max_loss_lamports))
+}
+
+/// Returns amount of LST the borrower must pay on top of
+/// `amount` to repay a flash loan of `amount`
+pub fn calc_flash_loan_fee(amount: u64, flash_loan_fee_bps: u16) -> Result<u64, MathError> {
+    let aaf = CeilDiv(U64BpsFee::try_new(flash_loan_fee_bps)?).apply(amount)?;
+    Ok(aaf.fee_charged())
+}
+
+/// Returns amount of the flash loan's `fee` to transfer
+/// to protocol_fee_accumulator instead of pool_reserves
+pub fn calc_flash_loan_protocol_fees(
+    fee: u64,
+    trading_protocol_fee_bps: u16,
+) -> Result<u64, MathError> {
+    let aaf = CeilDiv(U64BpsFee::try_new(trading_protocol_fee_bps)?).apply(fee)?;
+    Ok(aaf.fee_charged())
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CalcRemoveLiquidityProportionalArgs {
+    pub lp_token_amount: u64,
+
+    /// LP token supply before lp_token_amount is burnt
+    pub lp_token_supply: u64,
+
+    /// PoolConfig.remove_liquidity_proportional_fee_bps
+    pub remove_liquidity_proportional_fee_bps: u16,
+
+    pub lp_protocol_fee_bps: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RemoveLiquidityProportionalLstAmts {
+    /// Amount of LST to transfer from pool_reserves to the user
+    pub to_user_lst_amount: u64,
+
+    /// Amount of LST to transfer from pool_reserves to protocol_fee_accumulator
+    pub to_protocol_fees_lst_amount: u64,
+}
+
+/// Returns the amounts of a single LST to transfer out of its `pool_reserves_balance`
+/// for a proportional removal of `lp_token_amount`.
+///
+/// The redeemed LST is the pro-rata share `pool_reserves_balance * lp_token_amount / lp_token_supply`,
+/// of which `remove_liquidity_proportional_fee_bps` is charged as fees that stay in the pool,
+/// except for the `lp_protocol_fee_bps` cut of it that goes to the protocol.
+pub fn calc_remove_liquidity_proportional(
+    CalcRemoveLiquidityProportionalArgs {
+        lp_token_amount,
+        lp_token_supply,
+        remove_liquidity_proportional_fee_bps,
+        lp_protocol_fee_bps,
+    }: CalcRemoveLiquidityProportionalArgs,
+    pool_reserves_balance: u64,
+) -> Result<RemoveLiquidityProportionalLstAmts, MathError> {
+    if lp_token_amount > lp_token_supply {
+        return Err(MathError);
+    }
+    if lp_token_supply == 0 {
+        return Ok(RemoveLiquidityProportionalLstAmts::default());
+    }
+    let redeemed_lst_amount = FloorDiv(U64Ratio {
+        num: lp_token_amount,
+        denom: lp_token_supply,
+    })
+    .apply(pool_reserves_balance)?;
+    let aaf = CeilDiv(U64BpsFee::try_new(remove_liquidity_proportional_fee_bps)?)
+        .apply(redeemed_lst_amount)?;
+    let to_user_lst_amount = aaf.amt_after_fee();
+    let fees_lst_amount = aaf.fee_charged();
+    let to_protocol_fees_lst_amount = CeilDiv(U64BpsFee::try_new(lp_protocol_fee_bps)?)
+        .apply(fees_lst_amount)?
+        .fee_charged();
+    Ok(RemoveLiquidityProportionalLstAmts {
+        to_user_lst_amount,
+        to_protocol_fees_lst_amount,
+    })
+}
@@ -1,3 +1,4 @@
+use s_controller_interface::PoolConfig;
 use solana_program::pubkey::Pubkey;
 
 pub mod initial_authority {
@@ -8,7 +9,10 @@ pub mod initial_authority {
     sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
 }
 
-pub const CURRENT_PROGRAM_VERS: u8 = 1;
+pub const CURRENT_PROGRAM_VERS: u8 = 2;
+
+/// Pools on this version can be upgraded to [`CURRENT_PROGRAM_VERS`] with Migrate
+pub const MIGRATABLE_PROGRAM_VERS: u8 = 1;
 
 /// 10% of trading fees
 pub const DEFAULT_TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
@@ -17,3 +21,32 @@ pub const DEFAULT_TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
 pub const DEFAULT_LP_PROTOCOL_FEE_BPS: u16 = 1_000;
 
 pub const DEFAULT_PRICING_PROGRAM: Pubkey = flat_fee_lib::program::ID;
+
+/// `RebalanceAuthorityBudget.epoch_budget` and `PoolConfig.rebalance_authority_epoch_budget`
+/// of a rebalance authority that is not rate-limited. A budget of 0 means the
+/// rebalance authority cannot withdraw any SOL value
+pub const UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET: u64 = u64::MAX;
+
+/// PoolConfig of a pool whose PoolConfig PDA has not been created yet,
+/// and the PoolConfig the PDA is created with
+pub const DEFAULT_POOL_CONFIG: PoolConfig = PoolConfig {
+    remove_liquidity_proportional_fee_bps: 0,
+    referral_fee_bps: 0,
+    protocol_fees_as_lp: 0,
+    lp_requires_synced_lsts: 0,
+    max_rebalance_loss_bps: 0,
+    rebalance_authority_epoch_budget: UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+    rebalance_authority_last_epoch: 0,
+    lp_synced_lsts_dust_threshold: 0,
+    rebalance_authority_epoch_spent: 0,
+};
+
+/// Unit of `PoolState.timelock_delay`, ~1 hour at 400ms slots
+pub const TIMELOCK_DELAY_UNIT_SLOTS: u64 = 9_000;
+
+/// `LstState.last_sol_value_rate` is the SOL value of this many atomics of the LST
+pub const SOL_VALUE_RATE_UNIT: u64 = 1_000_000_000;
+
+/// Pool reserves balances below this are too small to give a precise
+/// SOL value rate and are not recorded or checked by the SOL value rate circuit breaker
+pub const MIN_RESERVES_FOR_SOL_VALUE_RATE: u64 = 1_000_000;
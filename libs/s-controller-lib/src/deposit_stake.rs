@@ -0,0 +1,43 @@
+use marinade_keys::marinade_program;
+use sanctum_spl_multi_stake_pool_keys::sanctum_spl_multi_stake_pool_program;
+use sanctum_spl_stake_pool_keys::sanctum_spl_stake_pool_program;
+use solana_program::pubkey::Pubkey;
+use spl_stake_pool_keys::spl_stake_pool_program;
+
+/// `StakePoolInstruction::DepositStake` discriminant
+pub const SPL_STAKE_POOL_DEPOSIT_STAKE_DISCM: u8 = 9;
+
+/// anchor discriminant of marinade's `deposit_stake_account` instruction
+pub const MARINADE_DEPOSIT_STAKE_ACCOUNT_DISCM: [u8; 8] = [110, 130, 115, 41, 164, 102, 2, 59];
+
+/// The stake pool programs that DepositStake can CPI into to deposit a stake account
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositStakeProgram {
+    /// SPL stake pool, Sanctum SPL stake pool and Sanctum SPL multi-validator stake pool programs
+    SplStakePool,
+    Marinade,
+}
+
+impl DepositStakeProgram {
+    pub fn try_from_program_id(program_id: &Pubkey) -> Option<Self> {
+        match *program_id {
+            spl_stake_pool_program::ID
+            | sanctum_spl_stake_pool_program::ID
+            | sanctum_spl_multi_stake_pool_program::ID => Some(Self::SplStakePool),
+            marinade_program::ID => Some(Self::Marinade),
+            _ => None,
+        }
+    }
+
+    /// `validator_index` is only used by marinade
+    pub fn deposit_stake_ix_data(&self, validator_index: u32) -> Vec<u8> {
+        match self {
+            Self::SplStakePool => vec![SPL_STAKE_POOL_DEPOSIT_STAKE_DISCM],
+            Self::Marinade => [
+                MARINADE_DEPOSIT_STAKE_ACCOUNT_DISCM.as_slice(),
+                &validator_index.to_le_bytes(),
+            ]
+            .concat(),
+        }
+    }
+}
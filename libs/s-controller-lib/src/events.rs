@@ -0,0 +1,309 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use data_encoding::BASE64;
+use solana_program::{log::sol_log_data, program_error::ProgramError, pubkey::Pubkey};
+
+/// Prefix of the log line emitted by `sol_log_data()`
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SwapEvent {
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+
+    /// Amount of src LST transferred from the user to the pool
+    pub src_lst_in: u64,
+
+    /// Amount of dst LST transferred from the pool to the user,
+    /// excluding protocol fees
+    pub dst_lst_out: u64,
+
+    pub in_sol_value: u64,
+
+    pub out_sol_value: u64,
+
+    /// Amount of dst LST charged as protocol fees,
+    /// including referral_fees
+    pub protocol_fees: u64,
+
+    /// Pool's total SOL value after the swap
+    pub total_sol_value: u64,
+
+    /// Amount of dst LST transferred to the referrer,
+    /// carved out of protocol_fees
+    pub referral_fees: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct AddLiquidityEvent {
+    pub lst_index: u32,
+
+    /// Total amount of LST transferred from the user, including protocol fees
+    pub lst_amount: u64,
+
+    pub lst_amount_sol_value: u64,
+
+    pub lp_tokens_minted: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fees: u64,
+
+    /// Pool's total SOL value after adding liquidity
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct RemoveLiquidityEvent {
+    pub lst_index: u32,
+
+    pub lp_tokens_burnt: u64,
+
+    pub lp_tokens_sol_value: u64,
+
+    /// Amount of LST transferred from the pool to the user,
+    /// excluding protocol fees
+    pub lst_out: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fees: u64,
+
+    /// Pool's total SOL value after removing liquidity
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct StartRebalanceEvent {
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+
+    /// Amount of src LST withdrawn from the pool
+    pub src_lst_withdrawn: u64,
+
+    /// Pool's total SOL value before the withdrawal,
+    /// which EndRebalance must restore
+    pub old_total_sol_value: u64,
+
+    /// Pool's total SOL value after the withdrawal
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct EndRebalanceEvent {
+    pub dst_lst_index: u32,
+
+    /// Pool's total SOL value before the corresponding StartRebalance
+    pub old_total_sol_value: u64,
+
+    /// Pool's total SOL value after the rebalance
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SyncSolValueEvent {
+    pub lst_index: u32,
+
+    pub old_sol_value: u64,
+
+    pub new_sol_value: u64,
+
+    /// Pool's total SOL value after the sync
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct WithdrawProtocolFeesEvent {
+    pub lst_mint: Pubkey,
+
+    pub amount: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct DistributeProtocolFeesEvent {
+    pub lst_mint: Pubkey,
+
+    pub beneficiary: Pubkey,
+
+    /// Amount of LST transferred to the beneficiary's token account
+    pub amount: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct ProtocolFeesMintedAsLpEvent {
+    pub lst_index: u32,
+
+    /// Amount of LST protocol fees left in the pool reserves
+    pub protocol_fees: u64,
+
+    /// SOL value of `protocol_fees`
+    pub protocol_fees_sol_value: u64,
+
+    /// Amount of LP tokens minted to the protocol fee LP token account
+    pub lp_tokens_minted: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct FlashLoanEvent {
+    pub lst_index: u32,
+
+    /// Amount of LST borrowed from the pool
+    pub amount: u64,
+
+    /// Amount of LST paid on top of `amount` to repay the loan,
+    /// including protocol fees
+    pub fee: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fees: u64,
+
+    /// Pool's total SOL value after the loan was repaid
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SettleRebalanceLegEvent {
+    pub dst_lst_index: u32,
+
+    /// Pool's total SOL value after the leg was settled
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct RemoveLiquidityProportionalEvent {
+    pub lst_index: u32,
+
+    /// Total amount of LP tokens burnt by the instruction
+    pub lp_tokens_burnt: u64,
+
+    /// Amount of LST transferred from the pool to the user,
+    /// excluding fees
+    pub lst_out: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fees: u64,
+
+    /// Pool's total SOL value after removing liquidity
+    pub total_sol_value: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SolValueRateCircuitBreakerTrippedEvent {
+    pub lst_index: u32,
+
+    /// Last SOL value rate recorded before the current epoch, the circuit breaker's baseline
+    pub last_sol_value_rate: u64,
+
+    /// SOL value rate that tripped the circuit breaker
+    pub new_sol_value_rate: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct RebalanceSolValueLostEvent {
+    pub dst_lst_index: u32,
+
+    /// Pool's total SOL value before the rebalance minus after it
+    pub sol_value_lost: u64,
+
+    /// Max SOL value the rebalance was allowed to lose,
+    /// set by StartRebalance from its `max_loss_lamports` and the pool's `max_rebalance_loss_bps`
+    pub max_sol_value_loss: u64,
+}
+
+/// Events emitted by the program's instruction processors.
+///
+/// Each event is emitted with `sol_log_data()` as a single
+/// borsh-serialized `SControllerEvent`, so the first byte is the event's variant index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum SControllerEvent {
+    SwapExactIn(SwapEvent),
+    SwapExactOut(SwapEvent),
+    AddLiquidity(AddLiquidityEvent),
+    RemoveLiquidity(RemoveLiquidityEvent),
+    StartRebalance(StartRebalanceEvent),
+    EndRebalance(EndRebalanceEvent),
+    SyncSolValue(SyncSolValueEvent),
+    WithdrawProtocolFees(WithdrawProtocolFeesEvent),
+    FlashLoan(FlashLoanEvent),
+    /// `old_total_sol_value` is the rebalance record's value after
+    /// adjusting for any SOL value changes of LSTs newly synced by the leg
+    AddRebalanceLeg(StartRebalanceEvent),
+    SettleRebalanceLeg(SettleRebalanceLegEvent),
+    /// Emitted once for each LST, after all LSTs have been synced
+    RemoveLiquidityProportional(RemoveLiquidityProportionalEvent),
+    /// Emitted once for each entry, after all LSTs have been synced.
+    /// `lp_tokens_minted` is the total amount of LP tokens minted by the instruction
+    AddLiquidityMulti(AddLiquidityEvent),
+    /// `lst_amount` is the amount of LST minted to the pool's reserves
+    /// by the stake pool in exchange for the deposited stake account
+    DepositStake(AddLiquidityEvent),
+    /// `lst_out` is the amount of LST burnt from the pool's reserves
+    /// by the stake pool in exchange for the withdrawn stake account
+    RemoveLiquidityToStake(RemoveLiquidityEvent),
+    /// Emitted once for each beneficiary that a nonzero amount was transferred to
+    DistributeProtocolFees(DistributeProtocolFeesEvent),
+    /// Emitted after the instruction's own event if the pool's `protocol_fees_as_lp` is set,
+    /// and by ConvertProtocolFeesToLp
+    ProtocolFeesMintedAsLp(ProtocolFeesMintedAsLpEvent),
+    /// Emitted by SyncSolValue and SyncSolValueMulti instead of SyncSolValue
+    /// when the LST's input and output are disabled by its circuit breaker
+    SolValueRateCircuitBreakerTripped(SolValueRateCircuitBreakerTrippedEvent),
+    /// Emitted after EndRebalance if the pool's total SOL value decreased over the rebalance
+    RebalanceSolValueLost(RebalanceSolValueLostEvent),
+}
+
+impl SControllerEvent {
+    pub fn emit(&self) -> Result<(), ProgramError> {
+        let data = self
+            .try_to_vec()
+            .map_err(|e| ProgramError::BorshIoError(e.to_string()))?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+
+    pub fn try_from_log_data(data: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(data)
+    }
+}
+
+/// Decodes all the events emitted by `program_id` from a transaction's log messages,
+/// in the order they were emitted.
+///
+/// Program data logged by other programs, including CPIs made by `program_id`,
+/// and data that fail to decode are skipped.
+pub fn decode_s_controller_events_from_logs<S: AsRef<str>>(
+    program_id: Pubkey,
+    log_messages: &[S],
+) -> Vec<SControllerEvent> {
+    let program_id_str = program_id.to_string();
+    // stack of program IDs currently executing
+    let mut invoke_stack: Vec<&str> = Vec::new();
+    let mut res = Vec::new();
+    for log in log_messages.iter().map(AsRef::as_ref) {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+            if invoke_stack.last() != Some(&program_id_str.as_str()) {
+                continue;
+            }
+            res.extend(
+                data.split(' ')
+                    .filter_map(|b64| BASE64.decode(b64.as_bytes()).ok())
+                    .filter_map(|bytes| SControllerEvent::try_from_log_data(&bytes).ok()),
+            );
+            continue;
+        }
+        let mut words = log.split(' ');
+        let (Some("Program"), Some(id), Some(verb)) = (words.next(), words.next(), words.next())
+        else {
+            continue;
+        };
+        // skip "Program log: ", "Program return: " etc
+        if id.ends_with(':') {
+            continue;
+        }
+        match verb {
+            "invoke" => invoke_stack.push(id),
+            "success" | "failed:" => {
+                invoke_stack.pop();
+            }
+            _ => (),
+        }
+    }
+    res
+}
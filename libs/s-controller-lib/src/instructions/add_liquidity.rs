@@ -8,7 +8,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
     ix_extend_with_sol_value_calculator_accounts, AddLiquidityByMintFreeArgs,
-    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
+    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts, PoolKey,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -111,9 +111,27 @@ pub fn add_liquidity_ix_by_mint_full_for_prog<
     amts: AddLiquidityIxAmts,
     account_suffixes: AddRemoveLiquidityAccountSuffixes,
 ) -> Result<Instruction, ProgramError> {
-    let (keys, lst_index, program_ids) = free_args.resolve_for_prog(program_id)?;
+    add_liquidity_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        amts,
+        account_suffixes,
+    )
+}
+
+pub fn add_liquidity_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: AddLiquidityByMintFreeArgs<S, L, M>,
+    amts: AddLiquidityIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_for_pool(pool)?;
     let ix = add_liquidity_ix_full_for_prog(
-        program_id,
+        pool.program_id,
         keys,
         AddLiquidityIxFullArgs { lst_index, amts },
         AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
@@ -0,0 +1,96 @@
+use s_controller_interface::{
+    add_liquidity_multi_ix_with_program_id, AddLiquidityMultiEntry, AddLiquidityMultiIxArgs,
+    AddLiquidityMultiKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{index_to_u32, AddLiquidityMultiLstKeys, AddRemoveLiquidityExtraAccounts};
+
+use super::utils::{
+    account_metas_extend_with_pricing_program_price_lp_accounts,
+    account_metas_extend_with_sol_value_calculator_accounts,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiLst<'me> {
+    pub keys: AddLiquidityMultiLstKeys,
+    pub lst_index: usize,
+    pub lst_amount: u64,
+    pub extra_accounts: AddRemoveLiquidityExtraAccounts<'me>,
+}
+
+pub fn add_liquidity_multi_ix_full(
+    accounts: AddLiquidityMultiKeys,
+    min_lp_out: u64,
+    lsts: &[AddLiquidityMultiLst],
+) -> Result<Instruction, ProgramError> {
+    add_liquidity_multi_ix_full_for_prog(crate::program::ID, accounts, min_lp_out, lsts)
+}
+
+pub fn add_liquidity_multi_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiKeys,
+    min_lp_out: u64,
+    lsts: &[AddLiquidityMultiLst],
+) -> Result<Instruction, ProgramError> {
+    let mut entries = Vec::with_capacity(lsts.len());
+    let mut lst_accounts = Vec::new();
+    for AddLiquidityMultiLst {
+        keys:
+            AddLiquidityMultiLstKeys {
+                lst_mint,
+                src_lst_acc,
+                protocol_fee_accumulator,
+                lst_token_program,
+                pool_reserves,
+            },
+        lst_index,
+        lst_amount,
+        extra_accounts:
+            AddRemoveLiquidityExtraAccounts {
+                lst_calculator_program_id,
+                pricing_program_id,
+                lst_calculator_accounts,
+                pricing_program_price_lp_accounts,
+            },
+    } in lsts
+    {
+        lst_accounts.push(AccountMeta::new_readonly(*lst_mint, false));
+        lst_accounts.push(AccountMeta::new(*src_lst_acc, false));
+        lst_accounts.push(AccountMeta::new(*protocol_fee_accumulator, false));
+        lst_accounts.push(AccountMeta::new_readonly(*lst_token_program, false));
+        lst_accounts.push(AccountMeta::new(*pool_reserves, false));
+        let lst_value_calc_accs = account_metas_extend_with_sol_value_calculator_accounts(
+            &mut lst_accounts,
+            lst_calculator_accounts,
+            *lst_calculator_program_id,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        let pricing_accs = account_metas_extend_with_pricing_program_price_lp_accounts(
+            &mut lst_accounts,
+            pricing_program_price_lp_accounts,
+            *pricing_program_id,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        entries.push(AddLiquidityMultiEntry {
+            lst_index: index_to_u32(*lst_index)?,
+            lst_amount: *lst_amount,
+            lst_value_calc_accs,
+            pricing_accs,
+        });
+    }
+    let mut ix = add_liquidity_multi_ix_with_program_id(
+        program_id,
+        accounts,
+        AddLiquidityMultiIxArgs {
+            min_lp_out,
+            entries,
+        },
+    )?;
+    ix.accounts.extend(lst_accounts);
+    Ok(ix)
+}
@@ -0,0 +1,147 @@
+use s_controller_interface::{
+    add_rebalance_leg_ix_with_program_id, AddRebalanceLegIxArgs, AddRebalanceLegIxData,
+    AddRebalanceLegKeys, SControllerError,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, AddRebalanceLegByMintsFreeArgs, PoolKey, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts,
+};
+
+use super::{ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstSolValueCalcAccounts};
+
+pub fn add_rebalance_leg_ix_full(
+    accounts: AddRebalanceLegKeys,
+    args: StartRebalanceIxFullArgs,
+    sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
+) -> Result<Instruction, ProgramError> {
+    add_rebalance_leg_ix_full_for_prog(crate::program::ID, accounts, args, sol_val_calc_keys)
+}
+
+pub fn add_rebalance_leg_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: AddRebalanceLegKeys,
+    StartRebalanceIxFullArgs {
+        src_lst_index,
+        dst_lst_index,
+        lst_amts:
+            StartRebalanceIxLstAmts {
+                amount,
+                min_starting_src_lst,
+                max_starting_dst_lst,
+                ..
+            },
+    }: StartRebalanceIxFullArgs,
+    sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
+) -> Result<Instruction, ProgramError> {
+    let src_lst_index = index_to_u32(src_lst_index)?;
+    let dst_lst_index = index_to_u32(dst_lst_index)?;
+    let mut ix = add_rebalance_leg_ix_with_program_id(
+        program_id,
+        accounts,
+        AddRebalanceLegIxArgs {
+            src_lst_calc_accs: 0,
+            src_lst_index,
+            dst_lst_index,
+            amount,
+            min_starting_src_lst,
+            max_starting_dst_lst,
+        },
+    )?;
+    let extend_count =
+        ix_extend_with_src_dst_sol_value_calculator_accounts(&mut ix, sol_val_calc_keys)
+            .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update src_lst_calc_accs than double serialization here
+    let mut overwrite = &mut ix.data[..];
+    AddRebalanceLegIxData(AddRebalanceLegIxArgs {
+        src_lst_calc_accs: extend_count.src_lst,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn add_rebalance_leg_ix_by_mints_full<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    free_args: AddRebalanceLegByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (
+        add_rebalance_leg_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        program_ids,
+    ) = free_args.resolve()?;
+    add_rebalance_leg_ix_full(
+        add_rebalance_leg_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts,
+        },
+        SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
+    )
+}
+
+pub fn add_rebalance_leg_ix_by_mints_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: AddRebalanceLegByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    add_rebalance_leg_ix_by_mints_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        lst_amts,
+        sol_val_calc_account_suffixes,
+    )
+}
+
+pub fn add_rebalance_leg_ix_by_mints_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: AddRebalanceLegByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (
+        add_rebalance_leg_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        program_ids,
+    ) = free_args.resolve_for_pool(pool)?;
+    add_rebalance_leg_ix_full_for_prog(
+        pool.program_id,
+        add_rebalance_leg_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts,
+        },
+        SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
+    )
+}
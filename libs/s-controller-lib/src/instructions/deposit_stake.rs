@@ -0,0 +1,182 @@
+use s_controller_interface::{
+    deposit_stake_ix_with_program_id, DepositStakeIxArgs, DepositStakeIxData, DepositStakeKeys,
+    SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, DepositStakeByMintFreeArgs, PoolKey,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositStakeIxFullArgs {
+    pub lst_index: usize,
+    /// Only used by marinade
+    pub validator_index: u32,
+    pub min_lp_out: u64,
+}
+
+/// deposit_stake_accounts should be the stake pool program's deposit stake instruction accounts,
+/// excluding the stake pool program ID
+#[derive(Clone, Copy, Debug)]
+pub struct DepositStakeProgramAccounts<'me> {
+    pub deposit_stake_program_id: Pubkey,
+    pub deposit_stake_accounts: &'me [AccountMeta],
+}
+
+pub fn deposit_stake_ix_full(
+    accounts: DepositStakeKeys,
+    args: DepositStakeIxFullArgs,
+    deposit_stake_program_accounts: DepositStakeProgramAccounts,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    deposit_stake_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        deposit_stake_program_accounts,
+        extra_accounts,
+    )
+}
+
+pub fn deposit_stake_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: DepositStakeKeys,
+    DepositStakeIxFullArgs {
+        lst_index,
+        validator_index,
+        min_lp_out,
+    }: DepositStakeIxFullArgs,
+    DepositStakeProgramAccounts {
+        deposit_stake_program_id,
+        deposit_stake_accounts,
+    }: DepositStakeProgramAccounts,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = deposit_stake_ix_with_program_id(
+        program_id,
+        accounts,
+        DepositStakeIxArgs {
+            deposit_stake_accs: 0,
+            lst_value_calc_accs: 0,
+            lst_index,
+            validator_index,
+            min_lp_out,
+        },
+    )?;
+    ix.accounts
+        .push(AccountMeta::new_readonly(deposit_stake_program_id, false));
+    ix.accounts.extend(deposit_stake_accounts.iter().cloned());
+    // n_accounts = len() + 1
+    let deposit_stake_accs: u8 = (deposit_stake_accounts.len() + 1)
+        .try_into()
+        .map_err(|_e| SControllerError::MathError)?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update deposit_stake_accs and lst_value_calc_accs than double serialization here
+    let mut overwrite = &mut ix.data[..];
+    DepositStakeIxData(DepositStakeIxArgs {
+        deposit_stake_accs,
+        lst_value_calc_accs,
+        lst_index,
+        validator_index,
+        min_lp_out,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn deposit_stake_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: DepositStakeByMintFreeArgs<S, L, M>,
+    validator_index: u32,
+    min_lp_out: u64,
+    deposit_stake_program_accounts: DepositStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve()?;
+    deposit_stake_ix_full(
+        keys,
+        DepositStakeIxFullArgs {
+            lst_index,
+            validator_index,
+            min_lp_out,
+        },
+        deposit_stake_program_accounts,
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
+
+pub fn deposit_stake_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: DepositStakeByMintFreeArgs<S, L, M>,
+    validator_index: u32,
+    min_lp_out: u64,
+    deposit_stake_program_accounts: DepositStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    deposit_stake_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        validator_index,
+        min_lp_out,
+        deposit_stake_program_accounts,
+        account_suffixes,
+    )
+}
+
+pub fn deposit_stake_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: DepositStakeByMintFreeArgs<S, L, M>,
+    validator_index: u32,
+    min_lp_out: u64,
+    deposit_stake_program_accounts: DepositStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_for_pool(pool)?;
+    deposit_stake_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        DepositStakeIxFullArgs {
+            lst_index,
+            validator_index,
+            min_lp_out,
+        },
+        deposit_stake_program_accounts,
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
@@ -1,5 +1,6 @@
 use s_controller_interface::{
-    disable_lst_input_ix, enable_lst_input_ix, DisableLstInputIxArgs, EnableLstInputIxArgs,
+    disable_lst_input_ix, disable_lst_output_ix, enable_lst_input_ix, enable_lst_output_ix,
+    DisableLstInputIxArgs, DisableLstOutputIxArgs, EnableLstInputIxArgs, EnableLstOutputIxArgs,
 };
 use solana_program::{instruction::Instruction, program_error::ProgramError};
 use solana_readonly_account::ReadonlyAccountData;
@@ -23,3 +24,21 @@ pub fn enable_lst_input_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccou
     let ix = enable_lst_input_ix(keys, EnableLstInputIxArgs { index })?;
     Ok(ix)
 }
+
+pub fn disable_lst_output_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &DisableEnableLstInputByMintFreeArgs<S, L>,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_disable_output()?;
+    let index = index_to_u32(lst_index)?;
+    let ix = disable_lst_output_ix(keys, DisableLstOutputIxArgs { index })?;
+    Ok(ix)
+}
+
+pub fn enable_lst_output_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &DisableEnableLstInputByMintFreeArgs<S, L>,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_enable_output()?;
+    let index = index_to_u32(lst_index)?;
+    let ix = enable_lst_output_ix(keys, EnableLstOutputIxArgs { index })?;
+    Ok(ix)
+}
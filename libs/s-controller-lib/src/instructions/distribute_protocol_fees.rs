@@ -0,0 +1,34 @@
+use s_controller_interface::{
+    distribute_protocol_fees_ix_with_program_id, DistributeProtocolFeesKeys,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// beneficiary_token_accounts must be in the same order as the protocol fee beneficiary list
+pub fn distribute_protocol_fees_ix_full(
+    accounts: DistributeProtocolFeesKeys,
+    beneficiary_token_accounts: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    distribute_protocol_fees_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        beneficiary_token_accounts,
+    )
+}
+
+pub fn distribute_protocol_fees_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: DistributeProtocolFeesKeys,
+    beneficiary_token_accounts: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    let mut ix = distribute_protocol_fees_ix_with_program_id(program_id, accounts)?;
+    ix.accounts.extend(
+        beneficiary_token_accounts
+            .iter()
+            .map(|pk| AccountMeta::new(*pk, false)),
+    );
+    Ok(ix)
+}
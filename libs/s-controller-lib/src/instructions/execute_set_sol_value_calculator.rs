@@ -0,0 +1,102 @@
+use s_controller_interface::{
+    execute_set_sol_value_calculator_ix, execute_set_sol_value_calculator_ix_with_program_id,
+    ExecuteSetSolValueCalculatorIxArgs, ExecuteSetSolValueCalculatorKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_sol_value_calculator_accounts,
+    ExecuteSetSolValueCalculatorByMintFreeArgs, PoolKey,
+};
+
+pub fn execute_set_sol_value_calculator_ix_full(
+    accounts: ExecuteSetSolValueCalculatorKeys,
+    lst_index: usize,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = execute_set_sol_value_calculator_ix(
+        accounts,
+        ExecuteSetSolValueCalculatorIxArgs { lst_index },
+    )?;
+    ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    Ok(ix)
+}
+
+pub fn execute_set_sol_value_calculator_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    Q: ReadonlyAccountData,
+>(
+    free_args: &ExecuteSetSolValueCalculatorByMintFreeArgs<S, L, M, Q>,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve()?;
+    let ix = execute_set_sol_value_calculator_ix_full(
+        keys,
+        lst_index,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )?;
+    Ok(ix)
+}
+
+pub fn execute_set_sol_value_calculator_ix_by_mint_full_with_program_id<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    Q: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: &ExecuteSetSolValueCalculatorByMintFreeArgs<S, L, M, Q>,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    execute_set_sol_value_calculator_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+}
+
+pub fn execute_set_sol_value_calculator_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    Q: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: &ExecuteSetSolValueCalculatorByMintFreeArgs<S, L, M, Q>,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = execute_set_sol_value_calculator_ix_with_program_id(
+        pool.program_id,
+        keys,
+        ExecuteSetSolValueCalculatorIxArgs { lst_index },
+    )?;
+    ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+
+    Ok(ix)
+}
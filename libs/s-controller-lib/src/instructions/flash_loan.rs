@@ -0,0 +1,149 @@
+use s_controller_interface::{
+    flash_borrow_ix_with_program_id, flash_repay_ix_with_program_id, FlashBorrowIxArgs,
+    FlashBorrowKeys, FlashRepayKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{index_to_u32, FlashBorrowByMintFreeArgs, FlashRepayByMintFreeArgs, PoolKey};
+
+use super::utils::ix_extend_with_sol_value_calculator_accounts;
+
+pub fn flash_borrow_ix_full(
+    accounts: FlashBorrowKeys,
+    lst_index: usize,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    flash_borrow_ix_full_for_prog(crate::program::ID, accounts, lst_index, amount)
+}
+
+pub fn flash_borrow_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: FlashBorrowKeys,
+    lst_index: usize,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let ix = flash_borrow_ix_with_program_id(
+        program_id,
+        accounts,
+        FlashBorrowIxArgs { lst_index, amount },
+    )?;
+    Ok(ix)
+}
+
+pub fn flash_borrow_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: FlashBorrowByMintFreeArgs<S, L, M>,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve()?;
+    flash_borrow_ix_full(keys, lst_index, amount)
+}
+
+pub fn flash_borrow_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: FlashBorrowByMintFreeArgs<S, L, M>,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    flash_borrow_ix_by_mint_full_for_pool(&PoolKey::default_for_prog(program_id), free_args, amount)
+}
+
+pub fn flash_borrow_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: FlashBorrowByMintFreeArgs<S, L, M>,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
+    flash_borrow_ix_full_for_prog(pool.program_id, keys, lst_index, amount)
+}
+
+pub fn flash_repay_ix_full(
+    accounts: FlashRepayKeys,
+    lst_calculator_accounts: &[AccountMeta],
+    lst_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    flash_repay_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+}
+
+pub fn flash_repay_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: FlashRepayKeys,
+    lst_calculator_accounts: &[AccountMeta],
+    lst_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let mut ix = flash_repay_ix_with_program_id(program_id, accounts)?;
+    ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    Ok(ix)
+}
+
+pub fn flash_repay_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: FlashRepayByMintFreeArgs<S, L, M>,
+    lst_calculator_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let (keys, _lst_index, lst_calculator_program_id) = free_args.resolve()?;
+    flash_repay_ix_full(keys, lst_calculator_accounts, lst_calculator_program_id)
+}
+
+pub fn flash_repay_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: FlashRepayByMintFreeArgs<S, L, M>,
+    lst_calculator_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    flash_repay_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        lst_calculator_accounts,
+    )
+}
+
+pub fn flash_repay_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: FlashRepayByMintFreeArgs<S, L, M>,
+    lst_calculator_accounts: &[AccountMeta],
+) -> Result<Instruction, ProgramError> {
+    let (keys, _lst_index, lst_calculator_program_id) = free_args.resolve_for_pool(pool)?;
+    flash_repay_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+}
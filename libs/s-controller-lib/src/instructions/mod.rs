@@ -2,23 +2,53 @@
 //! e.g. those that requires additional accounts for SOL value calculator and pricing program CPI calls
 
 mod add_liquidity;
+mod add_liquidity_multi;
+mod add_rebalance_leg;
+mod convert_protocol_fees_to_lp;
+mod deposit_stake;
 mod disable_enable_lst_input;
+mod distribute_protocol_fees;
 mod end_rebalance;
+mod execute_set_sol_value_calculator;
+mod flash_loan;
+mod quote;
 mod remove_liquidity;
+mod remove_liquidity_proportional;
+mod remove_liquidity_to_stake;
+mod set_lst_circuit_breaker;
+mod set_lst_flash_loan_fee;
+mod set_lst_max_share;
 mod set_sol_value_calculator;
+mod settle_rebalance_leg;
 mod start_rebalance;
 mod swap_exact_in;
 mod swap_exact_out;
 mod sync_sol_value;
+mod sync_sol_value_multi;
 mod utils;
 
 pub use add_liquidity::*;
+pub use add_liquidity_multi::*;
+pub use add_rebalance_leg::*;
+pub use convert_protocol_fees_to_lp::*;
+pub use deposit_stake::*;
 pub use disable_enable_lst_input::*;
+pub use distribute_protocol_fees::*;
 pub use end_rebalance::*;
+pub use execute_set_sol_value_calculator::*;
+pub use flash_loan::*;
+pub use quote::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_proportional::*;
+pub use remove_liquidity_to_stake::*;
+pub use set_lst_circuit_breaker::*;
+pub use set_lst_flash_loan_fee::*;
+pub use set_lst_max_share::*;
 pub use set_sol_value_calculator::*;
+pub use settle_rebalance_leg::*;
 pub use start_rebalance::*;
 pub use swap_exact_in::*;
 pub use swap_exact_out::*;
 pub use sync_sol_value::*;
+pub use sync_sol_value_multi::*;
 pub use utils::*;
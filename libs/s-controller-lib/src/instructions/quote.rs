@@ -0,0 +1,585 @@
+use s_controller_interface::{
+    quote_add_liquidity_ix_with_program_id, quote_remove_liquidity_ix_with_program_id,
+    quote_swap_exact_in_ix_with_program_id, quote_swap_exact_out_ix_with_program_id,
+    QuoteAddLiquidityIxArgs, QuoteAddLiquidityIxData, QuoteAddLiquidityKeys,
+    QuoteRemoveLiquidityIxArgs, QuoteRemoveLiquidityIxData, QuoteRemoveLiquidityKeys,
+    QuoteSwapExactInIxArgs, QuoteSwapExactInIxData, QuoteSwapExactInKeys, QuoteSwapExactOutIxArgs,
+    QuoteSwapExactOutIxData, QuoteSwapExactOutKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_pricing_program_price_swap_accounts,
+    ix_extend_with_sol_value_calculator_accounts,
+    ix_extend_with_src_dst_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, PoolKey, QuoteLiquidityByMintFreeArgs,
+    QuoteSwapByMintsFreeArgs, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
+    SrcDstLstSolValueCalcAccounts, SrcDstLstSolValueCalcExtendCount,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuoteSwapIxFullArgs {
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    /// src LST amount for QuoteSwapExactIn, dst LST amount for QuoteSwapExactOut
+    pub amount: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuoteLiquidityIxFullArgs {
+    pub lst_index: usize,
+    /// LST amount for QuoteAddLiquidity, LP token amount for QuoteRemoveLiquidity
+    pub amount: u64,
+}
+
+pub fn quote_swap_exact_in_ix_full(
+    accounts: QuoteSwapExactInKeys,
+    args: QuoteSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    quote_swap_exact_in_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_in_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactInKeys,
+    QuoteSwapIxFullArgs {
+        src_lst_index,
+        dst_lst_index,
+        amount,
+    }: QuoteSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let src_lst_index = index_to_u32(src_lst_index)?;
+    let dst_lst_index = index_to_u32(dst_lst_index)?;
+    let mut ix = quote_swap_exact_in_ix_with_program_id(
+        program_id,
+        accounts,
+        QuoteSwapExactInIxArgs {
+            // zeroes replaced by ix_extend below
+            src_lst_value_calc_accs: 0,
+            dst_lst_value_calc_accs: 0,
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+    )?;
+    let SrcDstLstSolValueCalcExtendCount {
+        src_lst: src_lst_value_calc_accs,
+        dst_lst: dst_lst_value_calc_accs,
+    } = ix_extend_with_src_dst_sol_value_calculator_accounts(&mut ix, sol_val_calc_accounts)
+        .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_swap_accounts(
+        &mut ix,
+        pricing_program_accounts,
+        pricing_program_id,
+    )?;
+    let mut overwrite = &mut ix.data[..];
+    QuoteSwapExactInIxData(QuoteSwapExactInIxArgs {
+        src_lst_value_calc_accs,
+        dst_lst_value_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn quote_swap_exact_out_ix_full(
+    accounts: QuoteSwapExactOutKeys,
+    args: QuoteSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    quote_swap_exact_out_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_out_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: QuoteSwapExactOutKeys,
+    QuoteSwapIxFullArgs {
+        src_lst_index,
+        dst_lst_index,
+        amount,
+    }: QuoteSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let src_lst_index = index_to_u32(src_lst_index)?;
+    let dst_lst_index = index_to_u32(dst_lst_index)?;
+    let mut ix = quote_swap_exact_out_ix_with_program_id(
+        program_id,
+        accounts,
+        QuoteSwapExactOutIxArgs {
+            // zeroes replaced by ix_extend below
+            src_lst_value_calc_accs: 0,
+            dst_lst_value_calc_accs: 0,
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+    )?;
+    let SrcDstLstSolValueCalcExtendCount {
+        src_lst: src_lst_value_calc_accs,
+        dst_lst: dst_lst_value_calc_accs,
+    } = ix_extend_with_src_dst_sol_value_calculator_accounts(&mut ix, sol_val_calc_accounts)
+        .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_swap_accounts(
+        &mut ix,
+        pricing_program_accounts,
+        pricing_program_id,
+    )?;
+    let mut overwrite = &mut ix.data[..];
+    QuoteSwapExactOutIxData(QuoteSwapExactOutIxArgs {
+        src_lst_value_calc_accs,
+        dst_lst_value_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn quote_swap_exact_in_ix_by_mint_full<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        src_dst_lst_sol_value_calc_program_ids,
+    ) = free_args.resolve_exact_in()?;
+    quote_swap_exact_in_ix_full(
+        keys,
+        QuoteSwapIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(
+            src_dst_lst_sol_value_calc_program_ids,
+            src_dst_lst_sol_value_calc_account_suffixes,
+        ),
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_in_ix_by_mint_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    quote_swap_exact_in_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        amount,
+        src_dst_lst_sol_value_calc_account_suffixes,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_in_ix_by_mint_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        src_dst_lst_sol_value_calc_program_ids,
+    ) = free_args.resolve_exact_in_for_pool(pool)?;
+    quote_swap_exact_in_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        QuoteSwapIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(
+            src_dst_lst_sol_value_calc_program_ids,
+            src_dst_lst_sol_value_calc_account_suffixes,
+        ),
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_out_ix_by_mint_full<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        src_dst_lst_sol_value_calc_program_ids,
+    ) = free_args.resolve_exact_out()?;
+    quote_swap_exact_out_ix_full(
+        keys,
+        QuoteSwapIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(
+            src_dst_lst_sol_value_calc_program_ids,
+            src_dst_lst_sol_value_calc_account_suffixes,
+        ),
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_out_ix_by_mint_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    quote_swap_exact_out_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        amount,
+        src_dst_lst_sol_value_calc_account_suffixes,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_swap_exact_out_ix_by_mint_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: QuoteSwapByMintsFreeArgs<SM, DM, S, L>,
+    amount: u64,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        src_dst_lst_sol_value_calc_program_ids,
+    ) = free_args.resolve_exact_out_for_pool(pool)?;
+    quote_swap_exact_out_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        QuoteSwapIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(
+            src_dst_lst_sol_value_calc_program_ids,
+            src_dst_lst_sol_value_calc_account_suffixes,
+        ),
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn quote_add_liquidity_ix_full(
+    accounts: QuoteAddLiquidityKeys,
+    args: QuoteLiquidityIxFullArgs,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    quote_add_liquidity_ix_full_for_prog(crate::program::ID, accounts, args, extra_accounts)
+}
+
+pub fn quote_add_liquidity_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: QuoteAddLiquidityKeys,
+    QuoteLiquidityIxFullArgs { lst_index, amount }: QuoteLiquidityIxFullArgs,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = quote_add_liquidity_ix_with_program_id(
+        program_id,
+        accounts,
+        QuoteAddLiquidityIxArgs {
+            lst_value_calc_accs: 0,
+            lst_index,
+            lst_amount: amount,
+        },
+    )?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    let mut overwrite = &mut ix.data[..];
+    QuoteAddLiquidityIxData(QuoteAddLiquidityIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lst_amount: amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn quote_remove_liquidity_ix_full(
+    accounts: QuoteRemoveLiquidityKeys,
+    args: QuoteLiquidityIxFullArgs,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    quote_remove_liquidity_ix_full_for_prog(crate::program::ID, accounts, args, extra_accounts)
+}
+
+pub fn quote_remove_liquidity_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: QuoteRemoveLiquidityKeys,
+    QuoteLiquidityIxFullArgs { lst_index, amount }: QuoteLiquidityIxFullArgs,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = quote_remove_liquidity_ix_with_program_id(
+        program_id,
+        accounts,
+        QuoteRemoveLiquidityIxArgs {
+            lst_value_calc_accs: 0,
+            lst_index,
+            lp_token_amount: amount,
+        },
+    )?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    let mut overwrite = &mut ix.data[..];
+    QuoteRemoveLiquidityIxData(QuoteRemoveLiquidityIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount: amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn quote_add_liquidity_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lst_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_add()?;
+    quote_add_liquidity_ix_full(
+        keys,
+        QuoteLiquidityIxFullArgs {
+            lst_index,
+            amount: lst_amount,
+        },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
+
+pub fn quote_add_liquidity_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lst_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    quote_add_liquidity_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        lst_amount,
+        account_suffixes,
+    )
+}
+
+pub fn quote_add_liquidity_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lst_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_add_for_pool(pool)?;
+    quote_add_liquidity_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        QuoteLiquidityIxFullArgs {
+            lst_index,
+            amount: lst_amount,
+        },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
+
+pub fn quote_remove_liquidity_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lp_token_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_remove()?;
+    quote_remove_liquidity_ix_full(
+        keys,
+        QuoteLiquidityIxFullArgs {
+            lst_index,
+            amount: lp_token_amount,
+        },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
+
+pub fn quote_remove_liquidity_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lp_token_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    quote_remove_liquidity_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        lp_token_amount,
+        account_suffixes,
+    )
+}
+
+pub fn quote_remove_liquidity_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: QuoteLiquidityByMintFreeArgs<S, L, M>,
+    lp_token_amount: u64,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_remove_for_pool(pool)?;
+    quote_remove_liquidity_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        QuoteLiquidityIxFullArgs {
+            lst_index,
+            amount: lp_token_amount,
+        },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
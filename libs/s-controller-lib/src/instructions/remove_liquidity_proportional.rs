@@ -0,0 +1,87 @@
+use s_controller_interface::{
+    remove_liquidity_proportional_ix_with_program_id, RemoveLiquidityProportionalEntry,
+    RemoveLiquidityProportionalIxArgs, RemoveLiquidityProportionalKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::RemoveLiquidityProportionalLstKeys;
+
+use super::utils::account_metas_extend_with_sol_value_calculator_accounts;
+
+/// sol_value_calculator_accounts should include common interface account prefixes
+/// but exclude the SOL value calculator program ID
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProportionalLst<'me> {
+    pub keys: RemoveLiquidityProportionalLstKeys,
+    pub min_lst_out: u64,
+    pub sol_value_calculator_accounts: &'me [AccountMeta],
+}
+
+/// `lsts` must contain exactly one entry for each LST in the pool, in lst_state_list order
+pub fn remove_liquidity_proportional_ix_full(
+    accounts: RemoveLiquidityProportionalKeys,
+    lp_token_amount: u64,
+    lsts: &[RemoveLiquidityProportionalLst],
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_proportional_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        lp_token_amount,
+        lsts,
+    )
+}
+
+/// `lsts` must contain exactly one entry for each LST in the pool, in lst_state_list order
+pub fn remove_liquidity_proportional_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProportionalKeys,
+    lp_token_amount: u64,
+    lsts: &[RemoveLiquidityProportionalLst],
+) -> Result<Instruction, ProgramError> {
+    let mut entries = Vec::with_capacity(lsts.len());
+    let mut lst_accounts = Vec::new();
+    for RemoveLiquidityProportionalLst {
+        keys:
+            RemoveLiquidityProportionalLstKeys {
+                lst_mint,
+                lst_token_program,
+                pool_reserves,
+                protocol_fee_accumulator,
+                dst_lst_acc,
+                sol_value_calculator,
+            },
+        min_lst_out,
+        sol_value_calculator_accounts,
+    } in lsts
+    {
+        lst_accounts.push(AccountMeta::new_readonly(*lst_mint, false));
+        lst_accounts.push(AccountMeta::new_readonly(*lst_token_program, false));
+        lst_accounts.push(AccountMeta::new(*pool_reserves, false));
+        lst_accounts.push(AccountMeta::new(*protocol_fee_accumulator, false));
+        lst_accounts.push(AccountMeta::new(*dst_lst_acc, false));
+        let lst_value_calc_accs = account_metas_extend_with_sol_value_calculator_accounts(
+            &mut lst_accounts,
+            sol_value_calculator_accounts,
+            *sol_value_calculator,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        entries.push(RemoveLiquidityProportionalEntry {
+            lst_value_calc_accs,
+            min_lst_out: *min_lst_out,
+        });
+    }
+    let mut ix = remove_liquidity_proportional_ix_with_program_id(
+        program_id,
+        accounts,
+        RemoveLiquidityProportionalIxArgs {
+            lp_token_amount,
+            entries,
+        },
+    )?;
+    ix.accounts.extend(lst_accounts);
+    Ok(ix)
+}
@@ -0,0 +1,176 @@
+use s_controller_interface::{
+    remove_liquidity_to_stake_ix_with_program_id, RemoveLiquidityToStakeIxArgs,
+    RemoveLiquidityToStakeIxData, RemoveLiquidityToStakeKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, PoolKey, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    RemoveLiquidityToStakeByMintFreeArgs,
+};
+
+/// withdraw_stake_accounts should be the SPL stake pool program's withdraw stake instruction accounts,
+/// excluding the stake pool program ID.
+///
+/// `user_stake_authority` must be the signer, `user_transfer_authority` must be the pool state,
+/// `user_pool_token_account` must be the pool reserves and `pool_mint` must be the LST mint.
+/// `stake_to_receive` must be an uninitialized stake account owned by the stake program.
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawStakeProgramAccounts<'me> {
+    pub withdraw_stake_program_id: Pubkey,
+    pub withdraw_stake_accounts: &'me [AccountMeta],
+}
+
+pub fn remove_liquidity_to_stake_ix_full(
+    accounts: RemoveLiquidityToStakeKeys,
+    args: RemoveLiquidityIxFullArgs,
+    withdraw_stake_program_accounts: WithdrawStakeProgramAccounts,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_to_stake_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        withdraw_stake_program_accounts,
+        extra_accounts,
+    )
+}
+
+pub fn remove_liquidity_to_stake_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityToStakeKeys,
+    RemoveLiquidityIxFullArgs {
+        lst_index,
+        amts:
+            RemoveLiquidityIxAmts {
+                lp_token_amount,
+                min_lst_out,
+            },
+    }: RemoveLiquidityIxFullArgs,
+    WithdrawStakeProgramAccounts {
+        withdraw_stake_program_id,
+        withdraw_stake_accounts,
+    }: WithdrawStakeProgramAccounts,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = remove_liquidity_to_stake_ix_with_program_id(
+        program_id,
+        accounts,
+        RemoveLiquidityToStakeIxArgs {
+            withdraw_stake_accs: 0,
+            lst_value_calc_accs: 0,
+            lst_index,
+            lp_token_amount,
+            min_lst_out,
+        },
+    )?;
+    ix.accounts
+        .push(AccountMeta::new_readonly(withdraw_stake_program_id, false));
+    // pool state signs the CPI as user_transfer_authority,
+    // so it must not be marked as a signer of this instruction
+    ix.accounts
+        .extend(withdraw_stake_accounts.iter().map(|meta| AccountMeta {
+            is_signer: meta.is_signer && meta.pubkey != accounts.pool_state,
+            ..meta.clone()
+        }));
+    // n_accounts = len() + 1
+    let withdraw_stake_accs: u8 = (withdraw_stake_accounts.len() + 1)
+        .try_into()
+        .map_err(|_e| SControllerError::MathError)?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update withdraw_stake_accs and lst_value_calc_accs than double serialization here
+    let mut overwrite = &mut ix.data[..];
+    RemoveLiquidityToStakeIxData(RemoveLiquidityToStakeIxArgs {
+        withdraw_stake_accs,
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+        min_lst_out,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn remove_liquidity_to_stake_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: RemoveLiquidityToStakeByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityIxAmts,
+    withdraw_stake_program_accounts: WithdrawStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve()?;
+    remove_liquidity_to_stake_ix_full(
+        keys,
+        RemoveLiquidityIxFullArgs { lst_index, amts },
+        withdraw_stake_program_accounts,
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
+
+pub fn remove_liquidity_to_stake_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: RemoveLiquidityToStakeByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityIxAmts,
+    withdraw_stake_program_accounts: WithdrawStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_to_stake_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        amts,
+        withdraw_stake_program_accounts,
+        account_suffixes,
+    )
+}
+
+pub fn remove_liquidity_to_stake_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: RemoveLiquidityToStakeByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityIxAmts,
+    withdraw_stake_program_accounts: WithdrawStakeProgramAccounts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_for_pool(pool)?;
+    remove_liquidity_to_stake_ix_full_for_prog(
+        pool.program_id,
+        keys,
+        RemoveLiquidityIxFullArgs { lst_index, amts },
+        withdraw_stake_program_accounts,
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )
+}
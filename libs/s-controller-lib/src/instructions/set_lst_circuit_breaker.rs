@@ -0,0 +1,54 @@
+use s_controller_interface::{
+    set_lst_circuit_breaker_ix_with_program_id, SetLstCircuitBreakerIxArgs,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{index_to_u32, PoolKey, SetLstCircuitBreakerByMintFreeArgs};
+
+pub fn set_lst_circuit_breaker_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &SetLstCircuitBreakerByMintFreeArgs<S, L>,
+    sol_value_rate_circuit_breaker_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_circuit_breaker_ix_by_mint_full_for_prog(
+        crate::program::ID,
+        free_args,
+        sol_value_rate_circuit_breaker_bps,
+    )
+}
+
+pub fn set_lst_circuit_breaker_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: &SetLstCircuitBreakerByMintFreeArgs<S, L>,
+    sol_value_rate_circuit_breaker_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_circuit_breaker_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        sol_value_rate_circuit_breaker_bps,
+    )
+}
+
+pub fn set_lst_circuit_breaker_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: &SetLstCircuitBreakerByMintFreeArgs<S, L>,
+    sol_value_rate_circuit_breaker_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
+    let index = index_to_u32(lst_index)?;
+    let ix = set_lst_circuit_breaker_ix_with_program_id(
+        pool.program_id,
+        keys,
+        SetLstCircuitBreakerIxArgs {
+            index,
+            sol_value_rate_circuit_breaker_bps,
+        },
+    )?;
+    Ok(ix)
+}
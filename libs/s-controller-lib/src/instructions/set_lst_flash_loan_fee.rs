@@ -0,0 +1,52 @@
+use s_controller_interface::{set_lst_flash_loan_fee_ix_with_program_id, SetLstFlashLoanFeeIxArgs};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{index_to_u32, PoolKey, SetLstFlashLoanFeeByMintFreeArgs};
+
+pub fn set_lst_flash_loan_fee_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &SetLstFlashLoanFeeByMintFreeArgs<S, L>,
+    flash_loan_fee_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_flash_loan_fee_ix_by_mint_full_for_prog(
+        crate::program::ID,
+        free_args,
+        flash_loan_fee_bps,
+    )
+}
+
+pub fn set_lst_flash_loan_fee_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: &SetLstFlashLoanFeeByMintFreeArgs<S, L>,
+    flash_loan_fee_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_flash_loan_fee_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        flash_loan_fee_bps,
+    )
+}
+
+pub fn set_lst_flash_loan_fee_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: &SetLstFlashLoanFeeByMintFreeArgs<S, L>,
+    flash_loan_fee_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
+    let index = index_to_u32(lst_index)?;
+    let ix = set_lst_flash_loan_fee_ix_with_program_id(
+        pool.program_id,
+        keys,
+        SetLstFlashLoanFeeIxArgs {
+            index,
+            flash_loan_fee_bps,
+        },
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,48 @@
+use s_controller_interface::{set_lst_max_share_ix_with_program_id, SetLstMaxShareIxArgs};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{index_to_u32, PoolKey, SetLstMaxShareByMintFreeArgs};
+
+pub fn set_lst_max_share_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &SetLstMaxShareByMintFreeArgs<S, L>,
+    max_share_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_max_share_ix_by_mint_full_for_prog(crate::program::ID, free_args, max_share_bps)
+}
+
+pub fn set_lst_max_share_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    free_args: &SetLstMaxShareByMintFreeArgs<S, L>,
+    max_share_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    set_lst_max_share_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        max_share_bps,
+    )
+}
+
+pub fn set_lst_max_share_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: &SetLstMaxShareByMintFreeArgs<S, L>,
+    max_share_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
+    let index = index_to_u32(lst_index)?;
+    let ix = set_lst_max_share_ix_with_program_id(
+        pool.program_id,
+        keys,
+        SetLstMaxShareIxArgs {
+            index,
+            max_share_bps,
+        },
+    )?;
+    Ok(ix)
+}
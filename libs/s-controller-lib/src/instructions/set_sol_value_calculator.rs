@@ -10,7 +10,8 @@ use solana_program::{
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    index_to_u32, ix_extend_with_sol_value_calculator_accounts, SetSolValueCalculatorByMintFreeArgs,
+    index_to_u32, ix_extend_with_sol_value_calculator_accounts, PoolKey,
+    SetSolValueCalculatorByMintFreeArgs,
 };
 
 pub fn set_sol_value_calculator_ix_full(
@@ -59,10 +60,28 @@ pub fn set_sol_value_calculator_ix_by_mint_full_with_program_id<
     sol_value_calculator_accounts: &[AccountMeta],
     sol_value_calculator_program_id: Pubkey,
 ) -> Result<Instruction, ProgramError> {
-    let (keys, lst_index) = free_args.resolve_for_prog(program_id)?;
+    set_sol_value_calculator_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+}
+
+pub fn set_sol_value_calculator_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: &SetSolValueCalculatorByMintFreeArgs<S, L, M>,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pool)?;
     let lst_index = index_to_u32(lst_index)?;
     let mut ix = set_sol_value_calculator_ix_with_program_id(
-        program_id,
+        pool.program_id,
         keys,
         SetSolValueCalculatorIxArgs { lst_index },
     )?;
@@ -0,0 +1,82 @@
+use s_controller_interface::{
+    settle_rebalance_leg_ix_with_program_id, AddRebalanceLegIxArgs, AddRebalanceLegKeys,
+    SControllerError, SControllerProgramIx, SettleRebalanceLegKeys,
+    ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::SettleRebalanceLegFromAddRebalanceLegKeys;
+
+use super::utils::ix_extend_with_sol_value_calculator_accounts;
+
+pub fn settle_rebalance_leg_ix_full(
+    accounts: SettleRebalanceLegKeys,
+    dst_lst_calculator_accounts: &[AccountMeta],
+    dst_lst_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    settle_rebalance_leg_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        dst_lst_calculator_accounts,
+        dst_lst_calculator_program_id,
+    )
+}
+
+pub fn settle_rebalance_leg_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: SettleRebalanceLegKeys,
+    dst_lst_calculator_accounts: &[AccountMeta],
+    dst_lst_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let mut ix = settle_rebalance_leg_ix_with_program_id(program_id, accounts)?;
+    ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        dst_lst_calculator_accounts,
+        dst_lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    Ok(ix)
+}
+
+pub fn settle_rebalance_leg_ix_from_add_rebalance_leg_ix(
+    add_rebalance_leg_ix: &Instruction,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = SControllerProgramIx::deserialize(&add_rebalance_leg_ix.data)?;
+    let AddRebalanceLegIxArgs {
+        src_lst_calc_accs, ..
+    } = match ix_data {
+        SControllerProgramIx::AddRebalanceLeg(args) => args,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    let dst_lst_suffix_start = ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN + usize::from(src_lst_calc_accs);
+    let dst_lst_suffix = add_rebalance_leg_ix
+        .accounts
+        .get(dst_lst_suffix_start..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let add_rebalance_leg_metas = add_rebalance_leg_ix
+        .accounts
+        .get(..ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    // doing this default-then-init thing instead of a map() because AccountMeta is not Copy
+    let mut add_rebalance_leg_keys: [Pubkey; ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN] =
+        Default::default();
+    for (i, meta) in add_rebalance_leg_metas.iter().enumerate() {
+        add_rebalance_leg_keys[i] = meta.pubkey;
+    }
+    let settle_rebalance_leg_keys = SettleRebalanceLegFromAddRebalanceLegKeys(
+        &AddRebalanceLegKeys::from(add_rebalance_leg_keys),
+    )
+    .resolve();
+
+    let mut ix = settle_rebalance_leg_ix_with_program_id(
+        add_rebalance_leg_ix.program_id,
+        settle_rebalance_leg_keys,
+    )?;
+    ix.accounts.extend(dst_lst_suffix.iter().cloned());
+    Ok(ix)
+}
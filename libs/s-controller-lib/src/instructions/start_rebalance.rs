@@ -6,7 +6,7 @@ use solana_program::{instruction::Instruction, program_error::ProgramError, pubk
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    index_to_u32, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
+    index_to_u32, PoolKey, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
     StartRebalanceByMintsFreeArgs,
 };
 
@@ -17,6 +17,10 @@ pub struct StartRebalanceIxLstAmts {
     pub amount: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+
+    /// Max SOL value the rebalance is allowed to lose, further capped by the pool's
+    /// `max_rebalance_loss_bps`. Ignored by AddRebalanceLeg
+    pub max_loss_lamports: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -45,6 +49,7 @@ pub fn start_rebalance_ix_full_for_prog(
                 amount,
                 min_starting_src_lst,
                 max_starting_dst_lst,
+                max_loss_lamports,
             },
     }: StartRebalanceIxFullArgs,
     sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
@@ -61,6 +66,7 @@ pub fn start_rebalance_ix_full_for_prog(
             amount,
             min_starting_src_lst,
             max_starting_dst_lst,
+            max_loss_lamports,
         },
     )?;
     let extend_count =
@@ -75,6 +81,7 @@ pub fn start_rebalance_ix_full_for_prog(
         amount,
         min_starting_src_lst,
         max_starting_dst_lst,
+        max_loss_lamports,
     })
     .serialize(&mut overwrite)?;
     Ok(ix)
@@ -119,6 +126,25 @@ pub fn start_rebalance_ix_by_mints_full_for_prog<
     free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
     lst_amts: StartRebalanceIxLstAmts,
     sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    start_rebalance_ix_by_mints_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        lst_amts,
+        sol_val_calc_account_suffixes,
+    )
+}
+
+pub fn start_rebalance_ix_by_mints_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    pool: &PoolKey,
+    free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
 ) -> Result<Instruction, ProgramError> {
     let (
         start_rebalance_keys,
@@ -127,9 +153,9 @@ pub fn start_rebalance_ix_by_mints_full_for_prog<
             dst_lst_index,
         },
         program_ids,
-    ) = free_args.resolve_for_prog(program_id)?;
+    ) = free_args.resolve_for_pool(pool)?;
     start_rebalance_ix_full_for_prog(
-        program_id,
+        pool.program_id,
         start_rebalance_keys,
         StartRebalanceIxFullArgs {
             src_lst_index,
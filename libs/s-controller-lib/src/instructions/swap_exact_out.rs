@@ -11,7 +11,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_swap_accounts,
-    ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstIndexes,
+    ix_extend_with_src_dst_sol_value_calculator_accounts, PoolKey, SrcDstLstIndexes,
     SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
     SrcDstLstSolValueCalcExtendCount, SwapByMintsFreeArgs,
 };
@@ -103,9 +103,10 @@ pub struct SwapExactOutAmounts {
 pub fn swap_exact_out_ix_by_mint_full<
     SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
     DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
     L: ReadonlyAccountData,
 >(
-    free_args: SwapByMintsFreeArgs<SM, DM, L>,
+    free_args: SwapByMintsFreeArgs<SM, DM, S, L>,
     SwapExactOutAmounts {
         max_amount_in,
         amount,
@@ -143,10 +144,34 @@ pub fn swap_exact_out_ix_by_mint_full<
 pub fn swap_exact_out_ix_by_mint_full_for_prog<
     SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
     DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
     L: ReadonlyAccountData,
 >(
     program_id: Pubkey,
-    free_args: SwapByMintsFreeArgs<SM, DM, L>,
+    free_args: SwapByMintsFreeArgs<SM, DM, S, L>,
+    amounts: SwapExactOutAmounts,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    swap_exact_out_ix_by_mint_full_for_pool(
+        &PoolKey::default_for_prog(program_id),
+        free_args,
+        amounts,
+        src_dst_lst_sol_value_calc_account_suffixes,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn swap_exact_out_ix_by_mint_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+>(
+    pool: &PoolKey,
+    free_args: SwapByMintsFreeArgs<SM, DM, S, L>,
     SwapExactOutAmounts {
         max_amount_in,
         amount,
@@ -162,9 +187,9 @@ pub fn swap_exact_out_ix_by_mint_full_for_prog<
             dst_lst_index,
         },
         src_dst_lst_sol_value_calc_program_ids,
-    ) = free_args.resolve_exact_out_for_prog(program_id)?;
+    ) = free_args.resolve_exact_out_for_pool(pool)?;
     let ix = swap_exact_out_ix_full_for_prog(
-        program_id,
+        pool.program_id,
         keys,
         SwapExactOutIxFullArgs {
             src_lst_index,
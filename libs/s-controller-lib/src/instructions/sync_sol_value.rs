@@ -47,10 +47,11 @@ pub fn sync_sol_value_ix_full_for_prog(
 }
 
 pub fn sync_sol_value_ix_by_mint_full<
+    S: ReadonlyAccountData,
     L: ReadonlyAccountData,
     M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
 >(
-    free_args: SyncSolValueByMintFreeArgs<L, M>,
+    free_args: SyncSolValueByMintFreeArgs<S, L, M>,
     sol_value_calculator_accounts: &[AccountMeta],
 ) -> Result<Instruction, ProgramError> {
     let (keys, lst_index, sol_value_calculator_program_id) = free_args.resolve()?;
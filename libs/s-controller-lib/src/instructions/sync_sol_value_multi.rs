@@ -0,0 +1,68 @@
+use s_controller_interface::{
+    sync_sol_value_multi_ix_with_program_id, SControllerError, SyncSolValueMultiEntry,
+    SyncSolValueMultiIxArgs, SyncSolValueMultiKeys,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{index_to_u32, SyncSolValueMultiLstKeys};
+
+use super::utils::account_metas_extend_with_sol_value_calculator_accounts;
+
+/// sol_value_calculator_accounts should include common interface account prefixes
+/// but exclude the SOL value calculator program ID
+#[derive(Clone, Copy, Debug)]
+pub struct SyncSolValueMultiLst<'me> {
+    pub keys: SyncSolValueMultiLstKeys,
+    pub sol_value_calculator_accounts: &'me [AccountMeta],
+}
+
+pub fn sync_sol_value_multi_ix_full(
+    accounts: SyncSolValueMultiKeys,
+    lsts: &[SyncSolValueMultiLst],
+) -> Result<Instruction, ProgramError> {
+    sync_sol_value_multi_ix_full_for_prog(crate::program::ID, accounts, lsts)
+}
+
+pub fn sync_sol_value_multi_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: SyncSolValueMultiKeys,
+    lsts: &[SyncSolValueMultiLst],
+) -> Result<Instruction, ProgramError> {
+    let mut entries = Vec::with_capacity(lsts.len());
+    let mut lst_accounts = Vec::new();
+    for SyncSolValueMultiLst {
+        keys:
+            SyncSolValueMultiLstKeys {
+                lst_index,
+                lst_mint,
+                pool_reserves,
+                sol_value_calculator,
+            },
+        sol_value_calculator_accounts,
+    } in lsts
+    {
+        lst_accounts.push(AccountMeta::new_readonly(*lst_mint, false));
+        lst_accounts.push(AccountMeta::new_readonly(*pool_reserves, false));
+        let lst_value_calc_accs = account_metas_extend_with_sol_value_calculator_accounts(
+            &mut lst_accounts,
+            sol_value_calculator_accounts,
+            *sol_value_calculator,
+        )
+        .map_err(|_e| SControllerError::MathError)?;
+        entries.push(SyncSolValueMultiEntry {
+            lst_index: index_to_u32(*lst_index)?,
+            lst_value_calc_accs,
+        });
+    }
+    let mut ix = sync_sol_value_multi_ix_with_program_id(
+        program_id,
+        accounts,
+        SyncSolValueMultiIxArgs { entries },
+    )?;
+    ix.accounts.extend(lst_accounts);
+    Ok(ix)
+}
@@ -1,6 +1,7 @@
 use s_controller_interface::{
-    LstState, PoolState, RebalanceRecord, SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
-    SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
+    FlashLoanRecord, LstState, PendingAuthorities, PoolConfig, PoolState,
+    ProtocolFeeBeneficiaryShare, QueuedChange, RebalanceAuthorityBudget, RebalanceRecord,
+    SWAP_EXACT_IN_IX_ACCOUNTS_LEN, SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use static_assertions::const_assert_eq;
 
@@ -8,25 +9,47 @@ mod accounts_resolvers;
 mod accounts_serde;
 mod calc;
 mod consts;
+mod deposit_stake;
 mod disable_pool_authority_list;
+mod events;
 mod instructions;
+mod lp_token_metadata;
 mod lst_indexes;
+mod lst_mint;
 mod lst_state_list;
+mod migrate;
 mod pda;
+mod pool;
+mod protocol_fee_beneficiary_list;
+mod queued_change;
+mod rebalance_authority_list;
+mod return_data;
 mod state;
 mod u8bool;
+mod withdraw_stake;
 
 pub use accounts_resolvers::*;
 pub use accounts_serde::*;
 pub use calc::*;
 pub use consts::*;
+pub use deposit_stake::*;
 pub use disable_pool_authority_list::*;
+pub use events::*;
 pub use instructions::*;
+pub use lp_token_metadata::*;
 pub use lst_indexes::*;
+pub use lst_mint::*;
 pub use lst_state_list::*;
+pub use migrate::*;
 pub use pda::*;
+pub use pool::*;
+pub use protocol_fee_beneficiary_list::*;
+pub use queued_change::*;
+pub use rebalance_authority_list::*;
+pub use return_data::*;
 pub use state::*;
 pub use u8bool::*;
+pub use withdraw_stake::*;
 
 // std::mem::size_of and std::mem::align_of are const fns so we dont technically need these
 // but the const asserts helps guard against unexpected size changes
@@ -36,17 +59,21 @@ const_assert_eq!(std::mem::size_of::<PoolState>(), POOL_STATE_SIZE);
 pub const POOL_STATE_ALIGN: usize = 8;
 const_assert_eq!(std::mem::align_of::<PoolState>(), POOL_STATE_ALIGN);
 
-pub const LST_STATE_SIZE: usize = 80;
+pub const LST_STATE_SIZE: usize = 120;
 const_assert_eq!(std::mem::size_of::<LstState>(), LST_STATE_SIZE);
 pub const LST_STATE_ALIGN: usize = 8;
 const_assert_eq!(std::mem::align_of::<LstState>(), LST_STATE_ALIGN);
 
+/// Size of a LstState in a [`MIGRATABLE_PROGRAM_VERS`] lst_state_list.
+/// The old layout is a prefix of the current one.
+pub const LST_STATE_V1_SIZE: usize = 80;
+
 const_assert_eq!(
     SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
     SWAP_EXACT_OUT_IX_ACCOUNTS_LEN
 );
 
-pub const REBALANCE_RECORD_SIZE: usize = 16;
+pub const REBALANCE_RECORD_SIZE: usize = 56;
 const_assert_eq!(
     std::mem::size_of::<RebalanceRecord>(),
     REBALANCE_RECORD_SIZE
@@ -57,6 +84,60 @@ const_assert_eq!(
     REBALANCE_RECORD_ALIGN
 );
 
+pub const FLASH_LOAN_RECORD_SIZE: usize = 24;
+const_assert_eq!(
+    std::mem::size_of::<FlashLoanRecord>(),
+    FLASH_LOAN_RECORD_SIZE
+);
+pub const FLASH_LOAN_RECORD_ALIGN: usize = 8;
+const_assert_eq!(
+    std::mem::align_of::<FlashLoanRecord>(),
+    FLASH_LOAN_RECORD_ALIGN
+);
+
+pub const PENDING_AUTHORITIES_SIZE: usize = 96;
+const_assert_eq!(
+    std::mem::size_of::<PendingAuthorities>(),
+    PENDING_AUTHORITIES_SIZE
+);
+pub const PENDING_AUTHORITIES_ALIGN: usize = 1;
+const_assert_eq!(
+    std::mem::align_of::<PendingAuthorities>(),
+    PENDING_AUTHORITIES_ALIGN
+);
+
+pub const POOL_CONFIG_SIZE: usize = 40;
+const_assert_eq!(std::mem::size_of::<PoolConfig>(), POOL_CONFIG_SIZE);
+pub const POOL_CONFIG_ALIGN: usize = 8;
+const_assert_eq!(std::mem::align_of::<PoolConfig>(), POOL_CONFIG_ALIGN);
+
+pub const PROTOCOL_FEE_BENEFICIARY_SHARE_SIZE: usize = 34;
+const_assert_eq!(
+    std::mem::size_of::<ProtocolFeeBeneficiaryShare>(),
+    PROTOCOL_FEE_BENEFICIARY_SHARE_SIZE
+);
+pub const PROTOCOL_FEE_BENEFICIARY_SHARE_ALIGN: usize = 2;
+const_assert_eq!(
+    std::mem::align_of::<ProtocolFeeBeneficiaryShare>(),
+    PROTOCOL_FEE_BENEFICIARY_SHARE_ALIGN
+);
+
+pub const REBALANCE_AUTHORITY_BUDGET_SIZE: usize = 56;
+const_assert_eq!(
+    std::mem::size_of::<RebalanceAuthorityBudget>(),
+    REBALANCE_AUTHORITY_BUDGET_SIZE
+);
+pub const REBALANCE_AUTHORITY_BUDGET_ALIGN: usize = 8;
+const_assert_eq!(
+    std::mem::align_of::<RebalanceAuthorityBudget>(),
+    REBALANCE_AUTHORITY_BUDGET_ALIGN
+);
+
+pub const QUEUED_CHANGE_SIZE: usize = 112;
+const_assert_eq!(std::mem::size_of::<QueuedChange>(), QUEUED_CHANGE_SIZE);
+pub const QUEUED_CHANGE_ALIGN: usize = 8;
+const_assert_eq!(std::mem::align_of::<QueuedChange>(), QUEUED_CHANGE_ALIGN);
+
 // putting these consts here instead of in consts.rs
 // so that we dont forget to update the declare_program_keys!()
 // macro below if we change them
@@ -67,7 +148,16 @@ pub const POOL_STATE_PDA_SEED: &[u8] = b"state";
 pub const LST_STATE_LIST_PDA_SEED: &[u8] = b"lst-state-list";
 pub const DISABLE_POOL_AUTHORITY_LIST_PDA_SEED: &[u8] = b"disable-pool-authority-list";
 pub const REBALANCE_RECORD_PDA_SEED: &[u8] = b"rebalance-record";
+pub const FLASH_LOAN_RECORD_PDA_SEED: &[u8] = b"flash-loan-record";
 pub const PROTOCOL_FEE_PDA_SEED: &[u8] = b"protocol-fee";
+pub const PENDING_AUTHORITIES_PDA_SEED: &[u8] = b"pending-authorities";
+pub const POOL_CONFIG_PDA_SEED: &[u8] = b"pool-config";
+pub const PROTOCOL_FEE_BENEFICIARY_LIST_PDA_SEED: &[u8] = b"protocol-fee-beneficiary-list";
+pub const REBALANCE_AUTHORITY_LIST_PDA_SEED: &[u8] = b"rebalance-authority-list";
+pub const QUEUED_PRICING_PROGRAM_PDA_SEED: &[u8] = b"queued-pricing-program";
+pub const QUEUED_TIMELOCK_DELAY_PDA_SEED: &[u8] = b"queued-timelock-delay";
+/// Per-LST PDA, second seed is the LST's mint
+pub const QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED: &[u8] = b"queued-sol-value-calculator";
 
 pub mod program {
     sanctum_macros::declare_program_keys!(
@@ -80,7 +170,17 @@ pub mod program {
                 b"disable-pool-authority-list"
             ),
             ("rebalance-record", b"rebalance-record"),
+            ("flash-loan-record", b"flash-loan-record"),
             ("protocol-fee", b"protocol-fee"),
+            ("pending-authorities", b"pending-authorities"),
+            ("pool-config", b"pool-config"),
+            ("queued-pricing-program", b"queued-pricing-program"),
+            ("queued-timelock-delay", b"queued-timelock-delay"),
+            (
+                "protocol-fee-beneficiary-list",
+                b"protocol-fee-beneficiary-list"
+            ),
+            ("rebalance-authority-list", b"rebalance-authority-list"),
         ]
     );
 }
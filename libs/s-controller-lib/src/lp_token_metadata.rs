@@ -0,0 +1,125 @@
+use s_controller_interface::{SControllerError, SetLpTokenMetadataIxArgs};
+use solana_program::pubkey::Pubkey;
+
+pub mod mpl_token_metadata_program {
+    sanctum_macros::declare_program_keys!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s", []);
+}
+
+/// First seed of a mint's metaplex metadata PDA `["metadata", mpl_token_metadata_program, mint]`
+pub const MPL_METADATA_PDA_SEED: &[u8] = b"metadata";
+
+/// `MetadataInstruction::CreateMetadataAccountV3` discriminant
+pub const MPL_CREATE_METADATA_ACCOUNT_V3_DISCM: u8 = 33;
+
+/// `MetadataInstruction::UpdateMetadataAccountV2` discriminant
+pub const MPL_UPDATE_METADATA_ACCOUNT_V2_DISCM: u8 = 15;
+
+/// Max length in bytes of a metaplex metadata name
+pub const MPL_MAX_NAME_LENGTH: usize = 32;
+
+/// Max length in bytes of a metaplex metadata symbol
+pub const MPL_MAX_SYMBOL_LENGTH: usize = 10;
+
+/// Max length in bytes of a metaplex metadata uri
+pub const MPL_MAX_URI_LENGTH: usize = 200;
+
+/// Where and how a LP token mint's metadata is stored, determined by its token program
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LpTokenMetadataProgram {
+    /// Token-2022 mints: the token metadata extension on the mint itself,
+    /// with the mint's metadata pointer pointing to itself
+    TokenMetadataExtension,
+
+    /// Tokenkeg mints: metaplex metadata PDA
+    MplTokenMetadata,
+}
+
+impl LpTokenMetadataProgram {
+    pub fn try_from_lp_token_program(lp_token_program: &Pubkey) -> Result<Self, SControllerError> {
+        if *lp_token_program == spl_token::ID {
+            Ok(Self::MplTokenMetadata)
+        } else if *lp_token_program == spl_token_2022::ID {
+            Ok(Self::TokenMetadataExtension)
+        } else {
+            Err(SControllerError::UnsupportedLpTokenProgram)
+        }
+    }
+
+    pub const fn program_id(&self) -> Pubkey {
+        match self {
+            Self::TokenMetadataExtension => spl_token_2022::ID,
+            Self::MplTokenMetadata => mpl_token_metadata_program::ID,
+        }
+    }
+
+    /// The account that holds `lp_token_mint`'s metadata
+    pub fn metadata_address(&self, lp_token_mint: Pubkey) -> Pubkey {
+        match self {
+            Self::TokenMetadataExtension => lp_token_mint,
+            Self::MplTokenMetadata => find_mpl_metadata_address(lp_token_mint).0,
+        }
+    }
+}
+
+pub fn find_mpl_metadata_address(mint: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MPL_METADATA_PDA_SEED,
+            mpl_token_metadata_program::ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &mpl_token_metadata_program::ID,
+    )
+}
+
+/// `CreateMetadataAccountV3Args { data, is_mutable: true, collection_details: None }`
+pub fn mpl_create_metadata_account_v3_ix_data(
+    args: &SetLpTokenMetadataIxArgs,
+) -> Result<Vec<u8>, SControllerError> {
+    let mut res = vec![MPL_CREATE_METADATA_ACCOUNT_V3_DISCM];
+    extend_with_mpl_data_v2(&mut res, args)?;
+    res.extend([1, 0]);
+    Ok(res)
+}
+
+/// `UpdateMetadataAccountArgsV2 { data: Some(data), update_authority: None, primary_sale_happened: None, is_mutable: None }`
+pub fn mpl_update_metadata_account_v2_ix_data(
+    args: &SetLpTokenMetadataIxArgs,
+) -> Result<Vec<u8>, SControllerError> {
+    let mut res = vec![MPL_UPDATE_METADATA_ACCOUNT_V2_DISCM, 1];
+    extend_with_mpl_data_v2(&mut res, args)?;
+    res.extend([0, 0, 0]);
+    Ok(res)
+}
+
+/// Metaplex rejects metadata with a name, symbol or uri longer than
+/// [`MPL_MAX_NAME_LENGTH`], [`MPL_MAX_SYMBOL_LENGTH`] or [`MPL_MAX_URI_LENGTH`] bytes
+pub fn verify_mpl_metadata_lens(
+    SetLpTokenMetadataIxArgs { name, symbol, uri }: &SetLpTokenMetadataIxArgs,
+) -> Result<(), SControllerError> {
+    if name.len() > MPL_MAX_NAME_LENGTH
+        || symbol.len() > MPL_MAX_SYMBOL_LENGTH
+        || uri.len() > MPL_MAX_URI_LENGTH
+    {
+        return Err(SControllerError::LpTokenMetadataTooLong);
+    }
+    Ok(())
+}
+
+/// Borsh-serializes `DataV2 { name, symbol, uri, seller_fee_basis_points: 0, creators: None, collection: None, uses: None }`
+fn extend_with_mpl_data_v2(
+    buf: &mut Vec<u8>,
+    args: &SetLpTokenMetadataIxArgs,
+) -> Result<(), SControllerError> {
+    verify_mpl_metadata_lens(args)?;
+    let SetLpTokenMetadataIxArgs { name, symbol, uri } = args;
+    for s in [name, symbol, uri] {
+        // strings are borsh-serialized as u32 len prefix + utf8 bytes
+        let len = u32::try_from(s.len()).map_err(|_e| SControllerError::LpTokenMetadataTooLong)?;
+        buf.extend(len.to_le_bytes());
+        buf.extend(s.as_bytes());
+    }
+    buf.extend(0u16.to_le_bytes());
+    buf.extend([0, 0, 0]);
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use s_controller_interface::SControllerError;
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig, MAX_FEE_BASIS_POINTS},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::Mint,
+};
+
+/// Token-2022 mint extensions a LST may have.
+///
+/// Everything else is rejected by AddLst, since extensions such as transfer hooks,
+/// non-transferable mints, permanent delegates, confidential transfers or
+/// frozen default account states can block or take funds out of the pool reserves.
+pub const SUPPORTED_LST_MINT_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
+
+/// Checks that a tokenkeg or token-2022 LST mint has no extension
+/// outside of [`SUPPORTED_LST_MINT_EXTENSIONS`]
+pub fn verify_lst_mint_extensions_supported(mint_data: &[u8]) -> Result<(), ProgramError> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    if mint
+        .get_extension_types()?
+        .iter()
+        .any(|ext| !SUPPORTED_LST_MINT_EXTENSIONS.contains(ext))
+    {
+        return Err(SControllerError::UnsupportedLstMintExtension.into());
+    }
+    Ok(())
+}
+
+/// The transfer fee a LST mint charges in a given epoch.
+///
+/// Amounts passed to token transfers are amounts before fees:
+/// the destination token account receives the amount minus the fee,
+/// with the fee withheld in it and excluded from its balance.
+/// Tokenkeg mints and token-2022 mints without the transfer fee extension charge no fee.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LstTransferFee(pub Option<TransferFee>);
+
+impl LstTransferFee {
+    pub fn try_from_mint_data(mint_data: &[u8], epoch: u64) -> Result<Self, ProgramError> {
+        let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+        if !mint
+            .get_extension_types()?
+            .contains(&ExtensionType::TransferFeeConfig)
+        {
+            return Ok(Self(None));
+        }
+        let transfer_fee_config = mint.get_extension::<TransferFeeConfig>()?;
+        Ok(Self(Some(*transfer_fee_config.get_epoch_fee(epoch))))
+    }
+
+    pub fn try_from_mint<D: ReadonlyAccountData + ?Sized>(
+        mint: &D,
+        epoch: u64,
+    ) -> Result<Self, ProgramError> {
+        Self::try_from_mint_data(&mint.data(), epoch)
+    }
+
+    /// Fee withheld from a transfer of `amt_bef_fee`
+    pub fn fee(&self, amt_bef_fee: u64) -> Result<u64, SControllerError> {
+        match self.0 {
+            None => Ok(0),
+            Some(transfer_fee) => transfer_fee
+                .calculate_fee(amt_bef_fee)
+                .ok_or(SControllerError::MathError),
+        }
+    }
+
+    /// Amount the destination receives from a transfer of `amt_bef_fee`
+    pub fn amt_after_fee(&self, amt_bef_fee: u64) -> Result<u64, SControllerError> {
+        amt_bef_fee
+            .checked_sub(self.fee(amt_bef_fee)?)
+            .ok_or(SControllerError::MathError)
+    }
+
+    /// Smallest amount to transfer for the destination to receive at least `amt_after_fee`
+    pub fn amt_bef_fee(&self, amt_after_fee: u64) -> Result<u64, SControllerError> {
+        let transfer_fee = match self.0 {
+            None => return Ok(amt_after_fee),
+            Some(t) => t,
+        };
+        if amt_after_fee == 0 {
+            return Ok(0);
+        }
+        let fee_bps: u64 = u16::from(transfer_fee.transfer_fee_basis_points).into();
+        let max_fee_bps: u64 = MAX_FEE_BASIS_POINTS.into();
+        let max_fee_amt_bef_fee = amt_after_fee
+            .checked_add(transfer_fee.maximum_fee.into())
+            .ok_or(SControllerError::MathError)?;
+        let mut amt_bef_fee = match max_fee_bps.checked_sub(fee_bps) {
+            None | Some(0) => max_fee_amt_bef_fee,
+            Some(denom) => {
+                let denom: u128 = denom.into();
+                let uncapped =
+                    (u128::from(amt_after_fee) * u128::from(max_fee_bps) + denom - 1) / denom;
+                u64::try_from(uncapped).map_or(max_fee_amt_bef_fee, |u| u.min(max_fee_amt_bef_fee))
+            }
+        };
+        // token-2022 rounds fees up, which can leave the amount after fee 1 short
+        while self.amt_after_fee(amt_bef_fee)? < amt_after_fee {
+            amt_bef_fee = amt_bef_fee
+                .checked_add(1)
+                .ok_or(SControllerError::MathError)?;
+        }
+        Ok(amt_bef_fee)
+    }
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use bytemuck::{bytes_of_mut, Zeroable};
+use s_controller_interface::{LstState, SControllerError};
+
+use crate::{
+    try_lst_state_list, CURRENT_PROGRAM_VERS, LST_STATE_SIZE, LST_STATE_V1_SIZE,
+    MIGRATABLE_PROGRAM_VERS,
+};
+
+/// Reads a [`MIGRATABLE_PROGRAM_VERS`] LstState, zeroing the fields appended since
+pub fn lst_state_from_v1(lst_state_v1_data: &[u8]) -> Result<LstState, SControllerError> {
+    if lst_state_v1_data.len() != LST_STATE_V1_SIZE {
+        return Err(SControllerError::InvalidLstStateListData);
+    }
+    let mut res = LstState::zeroed();
+    bytes_of_mut(&mut res)[..LST_STATE_V1_SIZE].copy_from_slice(lst_state_v1_data);
+    Ok(res)
+}
+
+/// Reads `lst_state_list_acc_data` according to the pool's `version`,
+/// converting LstStates of a pool that has not been migrated yet to the current layout.
+///
+/// The version cannot be inferred from the lst_state_list data alone
+/// since both layouts' sizes can divide the data's length.
+pub fn try_lst_state_list_versioned(
+    version: u8,
+    lst_state_list_acc_data: &[u8],
+) -> Result<Cow<'_, [LstState]>, SControllerError> {
+    match version {
+        CURRENT_PROGRAM_VERS => try_lst_state_list(lst_state_list_acc_data).map(Cow::Borrowed),
+        MIGRATABLE_PROGRAM_VERS => {
+            if lst_state_list_acc_data.len() % LST_STATE_V1_SIZE != 0 {
+                return Err(SControllerError::InvalidLstStateListData);
+            }
+            lst_state_list_acc_data
+                .chunks_exact(LST_STATE_V1_SIZE)
+                .map(lst_state_from_v1)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Cow::Owned)
+        }
+        _ => Err(SControllerError::InvalidPoolVersion),
+    }
+}
+
+/// Returns the length of a [`MIGRATABLE_PROGRAM_VERS`] lst_state_list
+/// of `lst_state_list_v1_len` bytes after migration
+pub fn migrated_lst_state_list_len(
+    lst_state_list_v1_len: usize,
+) -> Result<usize, SControllerError> {
+    if lst_state_list_v1_len % LST_STATE_V1_SIZE != 0 {
+        return Err(SControllerError::InvalidLstStateListData);
+    }
+    (lst_state_list_v1_len / LST_STATE_V1_SIZE)
+        .checked_mul(LST_STATE_SIZE)
+        .ok_or(SControllerError::MathError)
+}
+
+/// Moves the [`MIGRATABLE_PROGRAM_VERS`] LstStates packed at the start of
+/// `lst_state_list_acc_data` to their offsets in the current layout,
+/// zeroing the fields appended since.
+///
+/// `lst_state_list_acc_data` must have already been resized to the migrated length
+pub fn migrate_lst_state_list_in_place(
+    lst_state_list_acc_data: &mut [u8],
+) -> Result<(), SControllerError> {
+    if lst_state_list_acc_data.len() % LST_STATE_SIZE != 0 {
+        return Err(SControllerError::InvalidLstStateListData);
+    }
+    let n = lst_state_list_acc_data.len() / LST_STATE_SIZE;
+    // Go from the back since each LstState moves to a higher offset.
+    // Entry i's new location only overlaps old entries >= i, which have already been moved
+    for i in (0..n).rev() {
+        let src_start = i * LST_STATE_V1_SIZE;
+        let dst_start = i * LST_STATE_SIZE;
+        lst_state_list_acc_data.copy_within(src_start..src_start + LST_STATE_V1_SIZE, dst_start);
+        lst_state_list_acc_data[dst_start + LST_STATE_V1_SIZE..dst_start + LST_STATE_SIZE].fill(0);
+    }
+    Ok(())
+}
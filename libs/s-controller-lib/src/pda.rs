@@ -4,8 +4,11 @@ use solana_program::pubkey::Pubkey;
 
 use crate::{
     program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED, POOL_STATE_PDA_SEED,
-    PROTOCOL_FEE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, FLASH_LOAN_RECORD_PDA_SEED, LST_STATE_LIST_PDA_SEED,
+    PENDING_AUTHORITIES_PDA_SEED, POOL_CONFIG_PDA_SEED, POOL_STATE_PDA_SEED,
+    PROTOCOL_FEE_BENEFICIARY_LIST_PDA_SEED, PROTOCOL_FEE_PDA_SEED, QUEUED_PRICING_PROGRAM_PDA_SEED,
+    QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED, QUEUED_TIMELOCK_DELAY_PDA_SEED,
+    REBALANCE_AUTHORITY_LIST_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
 };
 
 pub fn create_pool_reserves_address(
@@ -112,6 +115,26 @@ pub fn find_protocol_fee_accumulator_address_with_protocol_fee_id(
     .find_ata_address()
 }
 
+/// Finds the protocol fee LP token account,
+/// the protocol fee PDA's ATA of the LP token mint.
+/// Protocol fees are minted to this account as LP tokens if the pool's `protocol_fees_as_lp` is set
+pub fn find_protocol_fee_lp_address(lp_token_mint: Pubkey) -> (Pubkey, u8) {
+    find_protocol_fee_lp_address_with_protocol_fee_id(PROTOCOL_FEE_ID, lp_token_mint)
+}
+
+pub fn find_protocol_fee_lp_address_with_protocol_fee_id(
+    protocol_fee_id: Pubkey,
+    lp_token_mint: Pubkey,
+) -> (Pubkey, u8) {
+    find_protocol_fee_accumulator_address_with_protocol_fee_id(
+        protocol_fee_id,
+        FindLstPdaAtaKeys {
+            lst_mint: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+    )
+}
+
 /// Finds the pool state PDA
 /// For dynamic program IDs.
 /// If using crate's program ID, you can use [`crate::program::POOL_STATE_ID`] directly
@@ -133,6 +156,20 @@ pub fn find_disable_pool_authority_list_address(program_id: Pubkey) -> (Pubkey,
     Pubkey::find_program_address(&[DISABLE_POOL_AUTHORITY_LIST_PDA_SEED], &program_id)
 }
 
+/// Finds the protocol fee beneficiary list PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::PROTOCOL_FEE_BENEFICIARY_LIST_ID`] directly
+pub fn find_protocol_fee_beneficiary_list_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROTOCOL_FEE_BENEFICIARY_LIST_PDA_SEED], &program_id)
+}
+
+/// Finds the rebalance authority list PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::REBALANCE_AUTHORITY_LIST_ID`] directly
+pub fn find_rebalance_authority_list_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBALANCE_AUTHORITY_LIST_PDA_SEED], &program_id)
+}
+
 /// Finds the rebalance record PDA
 /// For dynamic program IDs.
 /// If using crate's program ID, you can use [`crate::program::REBALANCE_RECORD_ID`] directly
@@ -140,6 +177,13 @@ pub fn find_rebalance_record_address(program_id: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[REBALANCE_RECORD_PDA_SEED], &program_id)
 }
 
+/// Finds the flash loan record PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::FLASH_LOAN_RECORD_ID`] directly
+pub fn find_flash_loan_record_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FLASH_LOAN_RECORD_PDA_SEED], &program_id)
+}
+
 /// Finds the protocol fee auth PDA
 /// For dynamic program IDs.
 /// If using crate's program ID, you can use [`crate::program::PROTOCOL_FEE_ID`] directly.
@@ -147,3 +191,42 @@ pub fn find_rebalance_record_address(program_id: Pubkey) -> (Pubkey, u8) {
 pub fn find_protocol_fee_address(program_id: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[PROTOCOL_FEE_PDA_SEED], &program_id)
 }
+
+/// Finds the pending authorities PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::PENDING_AUTHORITIES_ID`] directly
+pub fn find_pending_authorities_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_AUTHORITIES_PDA_SEED], &program_id)
+}
+
+/// Finds the pool config PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::POOL_CONFIG_ID`] directly
+pub fn find_pool_config_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_CONFIG_PDA_SEED], &program_id)
+}
+
+/// Finds the queued pricing program change PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::QUEUED_PRICING_PROGRAM_ID`] directly
+pub fn find_queued_pricing_program_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[QUEUED_PRICING_PROGRAM_PDA_SEED], &program_id)
+}
+
+/// Finds the queued timelock delay change PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::QUEUED_TIMELOCK_DELAY_ID`] directly
+pub fn find_queued_timelock_delay_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[QUEUED_TIMELOCK_DELAY_PDA_SEED], &program_id)
+}
+
+/// Finds the queued SOL value calculator change PDA of the LST with mint `lst_mint`
+pub fn find_queued_sol_value_calculator_address(
+    program_id: Pubkey,
+    lst_mint: Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED, lst_mint.as_ref()],
+        &program_id,
+    )
+}
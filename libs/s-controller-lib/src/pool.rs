@@ -0,0 +1,297 @@
+use s_controller_interface::{PoolState, SControllerError};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    program, DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, FLASH_LOAN_RECORD_PDA_SEED,
+    LST_STATE_LIST_PDA_SEED, PENDING_AUTHORITIES_PDA_SEED, POOL_CONFIG_PDA_SEED,
+    POOL_STATE_PDA_SEED, PROTOCOL_FEE_BENEFICIARY_LIST_PDA_SEED, PROTOCOL_FEE_PDA_SEED,
+    QUEUED_PRICING_PROGRAM_PDA_SEED, QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED,
+    QUEUED_TIMELOCK_DELAY_PDA_SEED, REBALANCE_AUTHORITY_LIST_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+};
+
+/// Identifies one of the pools sharing a S controller program.
+///
+/// Every program has a default pool whose PDAs use the singleton seeds e.g. `["state"]`.
+///
+/// Additional pools are identified by their LP token mint:
+/// their pool state is the PDA `["state", lp_token_mint]` and
+/// every other PDA of the pool appends the pool state's address to the singleton seeds
+/// e.g. `["lst-state-list", pool_state]`.
+///
+/// Pool reserves and protocol fee accumulators remain ATAs of the pool's
+/// pool state and protocol fee PDAs respectively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoolKey {
+    pub program_id: Pubkey,
+
+    /// `None` for the program's default pool
+    pub lp_token_mint: Option<Pubkey>,
+
+    pub pool_state: Pubkey,
+
+    pub pool_state_bump: u8,
+}
+
+impl PoolKey {
+    /// The default pool of [`crate::program::ID`]
+    pub const DEFAULT: Self = Self {
+        program_id: program::ID,
+        lp_token_mint: None,
+        pool_state: program::POOL_STATE_ID,
+        pool_state_bump: program::POOL_STATE_BUMP,
+    };
+
+    /// The default pool of `program_id`
+    pub fn default_for_prog(program_id: Pubkey) -> Self {
+        if program_id == program::ID {
+            return Self::DEFAULT;
+        }
+        let (pool_state, pool_state_bump) =
+            Pubkey::find_program_address(&[POOL_STATE_PDA_SEED], &program_id);
+        Self {
+            program_id,
+            lp_token_mint: None,
+            pool_state,
+            pool_state_bump,
+        }
+    }
+
+    /// The additional pool of [`crate::program::ID`] with LP token mint `lp_token_mint`
+    pub fn new(lp_token_mint: Pubkey) -> Self {
+        Self::new_for_prog(program::ID, lp_token_mint)
+    }
+
+    /// The additional pool of `program_id` with LP token mint `lp_token_mint`
+    pub fn new_for_prog(program_id: Pubkey, lp_token_mint: Pubkey) -> Self {
+        let (pool_state, pool_state_bump) = Pubkey::find_program_address(
+            &[POOL_STATE_PDA_SEED, lp_token_mint.as_ref()],
+            &program_id,
+        );
+        Self {
+            program_id,
+            lp_token_mint: Some(lp_token_mint),
+            pool_state,
+            pool_state_bump,
+        }
+    }
+
+    /// Returns the key of the pool whose pool state account
+    /// is at `pool_state_id` and has data `pool_state`.
+    ///
+    /// Errors if `pool_state_id` is neither the default pool state PDA
+    /// nor the pool state PDA of `pool_state.lp_token_mint`
+    pub fn try_from_pool_state(
+        pool_state_id: Pubkey,
+        pool_state: &PoolState,
+    ) -> Result<Self, SControllerError> {
+        Self::try_from_pool_state_for_prog(program::ID, pool_state_id, pool_state)
+    }
+
+    pub fn try_from_pool_state_for_prog(
+        program_id: Pubkey,
+        pool_state_id: Pubkey,
+        PoolState { lp_token_mint, .. }: &PoolState,
+    ) -> Result<Self, SControllerError> {
+        let default = Self::default_for_prog(program_id);
+        if pool_state_id == default.pool_state {
+            return Ok(default);
+        }
+        let res = Self::new_for_prog(program_id, *lp_token_mint);
+        if pool_state_id != res.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        Ok(res)
+    }
+
+    pub const fn is_default(&self) -> bool {
+        self.lp_token_mint.is_none()
+    }
+
+    pub fn pool_state_pda(&self) -> PoolPda {
+        PoolPda {
+            address: self.pool_state,
+            seed: POOL_STATE_PDA_SEED,
+            lst_mint: None,
+            pool_seed: self.lp_token_mint,
+            bump: [self.pool_state_bump],
+        }
+    }
+
+    pub fn lst_state_list_pda(&self) -> PoolPda {
+        self.pda(
+            LST_STATE_LIST_PDA_SEED,
+            (program::LST_STATE_LIST_ID, program::LST_STATE_LIST_BUMP),
+        )
+    }
+
+    pub fn disable_pool_authority_list_pda(&self) -> PoolPda {
+        self.pda(
+            DISABLE_POOL_AUTHORITY_LIST_PDA_SEED,
+            (
+                program::DISABLE_POOL_AUTHORITY_LIST_ID,
+                program::DISABLE_POOL_AUTHORITY_LIST_BUMP,
+            ),
+        )
+    }
+
+    pub fn rebalance_record_pda(&self) -> PoolPda {
+        self.pda(
+            REBALANCE_RECORD_PDA_SEED,
+            (program::REBALANCE_RECORD_ID, program::REBALANCE_RECORD_BUMP),
+        )
+    }
+
+    pub fn flash_loan_record_pda(&self) -> PoolPda {
+        self.pda(
+            FLASH_LOAN_RECORD_PDA_SEED,
+            (
+                program::FLASH_LOAN_RECORD_ID,
+                program::FLASH_LOAN_RECORD_BUMP,
+            ),
+        )
+    }
+
+    /// The PDA that has authority over all the pool's protocol fee accumulator token accounts
+    pub fn protocol_fee_pda(&self) -> PoolPda {
+        self.pda(
+            PROTOCOL_FEE_PDA_SEED,
+            (program::PROTOCOL_FEE_ID, program::PROTOCOL_FEE_BUMP),
+        )
+    }
+
+    pub fn pending_authorities_pda(&self) -> PoolPda {
+        self.pda(
+            PENDING_AUTHORITIES_PDA_SEED,
+            (
+                program::PENDING_AUTHORITIES_ID,
+                program::PENDING_AUTHORITIES_BUMP,
+            ),
+        )
+    }
+
+    pub fn pool_config_pda(&self) -> PoolPda {
+        self.pda(
+            POOL_CONFIG_PDA_SEED,
+            (program::POOL_CONFIG_ID, program::POOL_CONFIG_BUMP),
+        )
+    }
+
+    pub fn protocol_fee_beneficiary_list_pda(&self) -> PoolPda {
+        self.pda(
+            PROTOCOL_FEE_BENEFICIARY_LIST_PDA_SEED,
+            (
+                program::PROTOCOL_FEE_BENEFICIARY_LIST_ID,
+                program::PROTOCOL_FEE_BENEFICIARY_LIST_BUMP,
+            ),
+        )
+    }
+
+    pub fn rebalance_authority_list_pda(&self) -> PoolPda {
+        self.pda(
+            REBALANCE_AUTHORITY_LIST_PDA_SEED,
+            (
+                program::REBALANCE_AUTHORITY_LIST_ID,
+                program::REBALANCE_AUTHORITY_LIST_BUMP,
+            ),
+        )
+    }
+
+    pub fn queued_pricing_program_pda(&self) -> PoolPda {
+        self.pda(
+            QUEUED_PRICING_PROGRAM_PDA_SEED,
+            (
+                program::QUEUED_PRICING_PROGRAM_ID,
+                program::QUEUED_PRICING_PROGRAM_BUMP,
+            ),
+        )
+    }
+
+    pub fn queued_timelock_delay_pda(&self) -> PoolPda {
+        self.pda(
+            QUEUED_TIMELOCK_DELAY_PDA_SEED,
+            (
+                program::QUEUED_TIMELOCK_DELAY_ID,
+                program::QUEUED_TIMELOCK_DELAY_BUMP,
+            ),
+        )
+    }
+
+    /// The queued SOL value calculator change PDA of the LST with mint `lst_mint`.
+    /// Not a declared program key, so this always requires a `find_program_address()`
+    pub fn queued_sol_value_calculator_pda(&self, lst_mint: Pubkey) -> PoolPda {
+        let pool_seed = self.pool_seed();
+        let (address, bump) = match pool_seed {
+            None => Pubkey::find_program_address(
+                &[QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED, lst_mint.as_ref()],
+                &self.program_id,
+            ),
+            Some(pool_state) => Pubkey::find_program_address(
+                &[
+                    QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED,
+                    lst_mint.as_ref(),
+                    pool_state.as_ref(),
+                ],
+                &self.program_id,
+            ),
+        };
+        PoolPda {
+            address,
+            seed: QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED,
+            lst_mint: Some(lst_mint),
+            pool_seed,
+            bump: [bump],
+        }
+    }
+
+    /// The pool state's address for additional pools, appended to the singleton seeds
+    fn pool_seed(&self) -> Option<Pubkey> {
+        self.lp_token_mint.map(|_| self.pool_state)
+    }
+
+    /// `canonical` is the declared (address, bump) for [`Self::DEFAULT`],
+    /// used to avoid `find_program_address()`
+    fn pda(&self, seed: &'static [u8], canonical: (Pubkey, u8)) -> PoolPda {
+        let pool_seed = self.pool_seed();
+        let (address, bump) = match pool_seed {
+            None if self.program_id == program::ID => canonical,
+            None => Pubkey::find_program_address(&[seed], &self.program_id),
+            Some(pool_state) => {
+                Pubkey::find_program_address(&[seed, pool_state.as_ref()], &self.program_id)
+            }
+        };
+        PoolPda {
+            address,
+            seed,
+            lst_mint: None,
+            pool_seed,
+            bump: [bump],
+        }
+    }
+}
+
+/// A PDA of a pool, along with the seeds required to sign for it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolPda {
+    pub address: Pubkey,
+    seed: &'static [u8],
+    lst_mint: Option<Pubkey>,
+    /// LP token mint for additional pools' pool state,
+    /// pool state for additional pools' other PDAs
+    pool_seed: Option<Pubkey>,
+    bump: [u8; 1],
+}
+
+impl PoolPda {
+    pub const fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Seeds, including the bump, to pass to `invoke_signed()`
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        let mut res = vec![self.seed];
+        res.extend(self.lst_mint.as_ref().map(|k| k.as_ref()));
+        res.extend(self.pool_seed.as_ref().map(|k| k.as_ref()));
+        res.push(&self.bump);
+        res
+    }
+}
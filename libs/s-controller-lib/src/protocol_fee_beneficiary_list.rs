@@ -0,0 +1,48 @@
+use s_controller_interface::{ProtocolFeeBeneficiaryShare, SControllerError};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::pubkey::Pubkey;
+
+pub fn try_find_protocol_fee_beneficiary(
+    beneficiary: Pubkey,
+    list: &[ProtocolFeeBeneficiaryShare],
+) -> Option<(usize, &ProtocolFeeBeneficiaryShare)> {
+    list.iter()
+        .enumerate()
+        .find(|(_i, s)| s.beneficiary == beneficiary)
+}
+
+/// Sum of all weights in the list
+pub fn protocol_fee_beneficiary_list_total_weight_bps(list: &[ProtocolFeeBeneficiaryShare]) -> u32 {
+    list.iter().map(|s| u32::from(s.weight_bps)).sum()
+}
+
+/// Errors if the sum of all weights in the list exceeds 100%
+pub fn verify_protocol_fee_beneficiary_weights(
+    list: &[ProtocolFeeBeneficiaryShare],
+) -> Result<(), SControllerError> {
+    if protocol_fee_beneficiary_list_total_weight_bps(list) > u32::from(BPS_DENOMINATOR) {
+        return Err(SControllerError::ProtocolFeeBeneficiaryWeightsTooHigh);
+    }
+    Ok(())
+}
+
+/// Returns the ATAs of each beneficiary in the list for the given LST,
+/// in list order
+pub fn protocol_fee_beneficiary_atas(
+    list: &[ProtocolFeeBeneficiaryShare],
+    lst_mint: Pubkey,
+    token_program: Pubkey,
+) -> Vec<Pubkey> {
+    list.iter()
+        .map(|s| {
+            FindAtaAddressArgs {
+                wallet: s.beneficiary,
+                mint: lst_mint,
+                token_program,
+            }
+            .find_ata_address()
+            .0
+        })
+        .collect()
+}
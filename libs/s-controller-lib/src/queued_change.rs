@@ -0,0 +1,95 @@
+use s_controller_interface::{QueuedChange, SControllerError};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    PoolKey, QUEUED_PRICING_PROGRAM_PDA_SEED, QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED,
+    QUEUED_TIMELOCK_DELAY_PDA_SEED, TIMELOCK_DELAY_UNIT_SLOTS,
+};
+
+/// Value of `QueuedChange.kind`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum QueuedChangeKind {
+    PricingProgram = 0,
+    SolValueCalculator = 1,
+    TimelockDelay = 2,
+}
+
+impl TryFrom<u8> for QueuedChangeKind {
+    type Error = SControllerError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::PricingProgram,
+            1 => Self::SolValueCalculator,
+            2 => Self::TimelockDelay,
+            _ => return Err(SControllerError::InvalidQueuedChangeData),
+        })
+    }
+}
+
+/// Checks that `queued_change` is of kind `expected`
+pub fn verify_queued_change_kind(
+    queued_change: &QueuedChange,
+    expected: QueuedChangeKind,
+) -> Result<(), SControllerError> {
+    if QueuedChangeKind::try_from(queued_change.kind)? != expected {
+        return Err(SControllerError::InvalidQueuedChangeData);
+    }
+    Ok(())
+}
+
+/// Derives the address of the PDA `queued_change` is stored at
+/// using its kind and stored bump
+pub fn create_queued_change_address(
+    program_id: Pubkey,
+    queued_change: &QueuedChange,
+) -> Result<Pubkey, SControllerError> {
+    create_queued_change_address_for_pool(&PoolKey::default_for_prog(program_id), queued_change)
+}
+
+/// Derives the address of the PDA `queued_change` of `pool` is stored at
+/// using its kind and stored bump
+pub fn create_queued_change_address_for_pool(
+    pool: &PoolKey,
+    queued_change: &QueuedChange,
+) -> Result<Pubkey, SControllerError> {
+    let bump = [queued_change.bump];
+    let mut seeds: Vec<&[u8]> = match QueuedChangeKind::try_from(queued_change.kind)? {
+        QueuedChangeKind::PricingProgram => vec![QUEUED_PRICING_PROGRAM_PDA_SEED],
+        QueuedChangeKind::SolValueCalculator => vec![
+            QUEUED_SOL_VALUE_CALCULATOR_PDA_SEED,
+            queued_change.lst_mint.as_ref(),
+        ],
+        QueuedChangeKind::TimelockDelay => vec![QUEUED_TIMELOCK_DELAY_PDA_SEED],
+    };
+    if !pool.is_default() {
+        seeds.push(pool.pool_state.as_ref());
+    }
+    seeds.push(&bump);
+    Pubkey::create_program_address(&seeds, &pool.program_id)
+        .map_err(|_e| SControllerError::InvalidQueuedChangeData)
+}
+
+/// Returns the earliest slot a change queued at `current_slot`
+/// can be executed at given the pool's `timelock_delay`
+pub fn calc_earliest_execution_slot(
+    current_slot: u64,
+    timelock_delay: u8,
+) -> Result<u64, SControllerError> {
+    u64::from(timelock_delay)
+        .checked_mul(TIMELOCK_DELAY_UNIT_SLOTS)
+        .and_then(|delay_slots| current_slot.checked_add(delay_slots))
+        .ok_or(SControllerError::MathError)
+}
+
+/// Checks that `queued_change` can be executed at `current_slot`
+pub const fn verify_timelock_elapsed(
+    queued_change: &QueuedChange,
+    current_slot: u64,
+) -> Result<(), SControllerError> {
+    if current_slot < queued_change.earliest_execution_slot {
+        return Err(SControllerError::TimelockNotElapsed);
+    }
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use s_controller_interface::{PoolConfig, PoolState, RebalanceAuthorityBudget, SControllerError};
+use solana_program::pubkey::Pubkey;
+
+use crate::UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET;
+
+pub fn try_find_rebalance_authority(
+    authority: Pubkey,
+    list: &[RebalanceAuthorityBudget],
+) -> Option<(usize, &RebalanceAuthorityBudget)> {
+    list.iter()
+        .enumerate()
+        .find(|(_i, b)| b.authority == authority)
+}
+
+/// Errors if `authority` is neither the pool's rebalance authority
+/// nor on the rebalance authority list
+pub fn verify_rebalance_authority(
+    pool_state: &PoolState,
+    list: &[RebalanceAuthorityBudget],
+    authority: Pubkey,
+) -> Result<(), SControllerError> {
+    if authority == pool_state.rebalance_authority
+        || try_find_rebalance_authority(authority, list).is_some()
+    {
+        Ok(())
+    } else {
+        Err(SControllerError::UnauthorizedRebalanceAuthority)
+    }
+}
+
+/// SOL value the rebalance authority can still withdraw in `epoch`.
+///
+/// Returns `None` if its epoch_budget is [`UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET`],
+/// i.e. the rebalance authority is not rate-limited
+pub fn rebalance_authority_budget_remaining(
+    budget: &RebalanceAuthorityBudget,
+    epoch: u64,
+) -> Option<u64> {
+    epoch_budget_remaining(
+        budget.epoch_budget,
+        budget.last_epoch,
+        budget.epoch_spent,
+        epoch,
+    )
+}
+
+/// SOL value the pool's rebalance authority can still withdraw in `epoch`
+/// if it does not have an entry on the rebalance authority list.
+///
+/// Returns `None` if PoolConfig.rebalance_authority_epoch_budget is
+/// [`UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET`], i.e. the pool's rebalance authority is not rate-limited
+pub fn pool_rebalance_authority_budget_remaining(
+    pool_config: &PoolConfig,
+    epoch: u64,
+) -> Option<u64> {
+    epoch_budget_remaining(
+        pool_config.rebalance_authority_epoch_budget,
+        pool_config.rebalance_authority_last_epoch,
+        pool_config.rebalance_authority_epoch_spent,
+        epoch,
+    )
+}
+
+/// Adds `sol_value` to the SOL value spent by `authority` in `epoch`,
+/// resetting the amount spent first if it was last recorded in a previous epoch.
+///
+/// Authorities not on the list, i.e. the pool's rebalance authority without an entry,
+/// are not charged. Their budget is charged with [`charge_pool_rebalance_authority_budget`] instead.
+pub fn charge_rebalance_authority_budget(
+    list: &mut [RebalanceAuthorityBudget],
+    authority: Pubkey,
+    epoch: u64,
+    sol_value: u64,
+) -> Result<(), SControllerError> {
+    let budget = match list.iter_mut().find(|b| b.authority == authority) {
+        Some(b) => b,
+        None => return Ok(()),
+    };
+    charge_epoch_budget(
+        budget.epoch_budget,
+        &mut budget.last_epoch,
+        &mut budget.epoch_spent,
+        epoch,
+        sol_value,
+    )
+}
+
+/// Adds `sol_value` to the SOL value spent by the pool's rebalance authority in `epoch`,
+/// resetting the amount spent first if it was last recorded in a previous epoch.
+pub fn charge_pool_rebalance_authority_budget(
+    pool_config: &mut PoolConfig,
+    epoch: u64,
+    sol_value: u64,
+) -> Result<(), SControllerError> {
+    charge_epoch_budget(
+        pool_config.rebalance_authority_epoch_budget,
+        &mut pool_config.rebalance_authority_last_epoch,
+        &mut pool_config.rebalance_authority_epoch_spent,
+        epoch,
+        sol_value,
+    )
+}
+
+/// Returns `None` if `epoch_budget` is [`UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET`]
+fn epoch_budget_remaining(
+    epoch_budget: u64,
+    last_epoch: u64,
+    epoch_spent: u64,
+    epoch: u64,
+) -> Option<u64> {
+    if epoch_budget == UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET {
+        return None;
+    }
+    if last_epoch != epoch {
+        return Some(epoch_budget);
+    }
+    Some(epoch_budget.saturating_sub(epoch_spent))
+}
+
+/// No-op if `epoch_budget` is [`UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET`].
+/// A budget of 0 rejects any nonzero `sol_value`
+fn charge_epoch_budget(
+    epoch_budget: u64,
+    last_epoch: &mut u64,
+    epoch_spent: &mut u64,
+    epoch: u64,
+    sol_value: u64,
+) -> Result<(), SControllerError> {
+    let remaining = match epoch_budget_remaining(epoch_budget, *last_epoch, *epoch_spent, epoch) {
+        Some(r) => r,
+        None => return Ok(()),
+    };
+    if sol_value > remaining {
+        return Err(SControllerError::RebalanceBudgetExceeded);
+    }
+    if *last_epoch != epoch {
+        *last_epoch = epoch;
+        *epoch_spent = 0;
+    }
+    // checked above that epoch_spent + sol_value <= epoch_budget
+    *epoch_spent += sol_value;
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    program::{get_return_data, set_return_data},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Amounts set as the instruction's return data by
+/// SwapExactIn, SwapExactOut, AddLiquidity, RemoveLiquidity, DepositStake, RemoveLiquidityToStake
+/// and the Quote* instructions.
+///
+/// - swaps: `amount_in` is the src LST amount and `amount_out` the dst LST amount
+/// - add liquidity: `amount_in` is the LST amount and `amount_out` the LP token amount.
+///   For DepositStake, `amount_in` is the amount of LST minted by the stake pool
+/// - remove liquidity: `amount_in` is the LP token amount and `amount_out` the LST amount.
+///   For RemoveLiquidityToStake, `amount_out` is the amount of LST burnt by the stake pool
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SwapLiquidityReturnData {
+    pub amount_in: u64,
+
+    /// Excludes protocol fees
+    pub amount_out: u64,
+
+    /// Denominated in the LST that is transferred to the protocol fee accumulator:
+    /// dst LST for swaps, the LST for add and remove liquidity
+    pub protocol_fees: u64,
+
+    /// SOL value of `amount_in`
+    pub in_sol_value: u64,
+
+    /// SOL value of `amount_out`
+    pub out_sol_value: u64,
+}
+
+impl SwapLiquidityReturnData {
+    pub fn set(&self) -> Result<(), ProgramError> {
+        let data = self
+            .try_to_vec()
+            .map_err(|e| ProgramError::BorshIoError(e.to_string()))?;
+        set_return_data(&data);
+        Ok(())
+    }
+
+    pub fn try_from_return_data(data: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(data)
+    }
+
+    /// Decodes return data `data` set by `setter_program_id`,
+    /// e.g. a transaction's return data fetched from an RPC simulation.
+    ///
+    /// Errors with `IncorrectProgramId` if `setter_program_id` is not `program_id`
+    pub fn try_from_program_return_data_for_prog(
+        program_id: Pubkey,
+        setter_program_id: Pubkey,
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        if setter_program_id != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        Self::try_from_return_data(data).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+    }
+
+    pub fn try_from_program_return_data(
+        setter_program_id: Pubkey,
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        Self::try_from_program_return_data_for_prog(crate::program::ID, setter_program_id, data)
+    }
+
+    /// For use by programs right after CPI-ing into the s-controller program.
+    ///
+    /// Errors with `IncorrectProgramId` if no return data was set
+    /// or the return data was not set by `program_id`
+    pub fn get_for_prog(program_id: Pubkey) -> Result<Self, ProgramError> {
+        let (setter_program_id, data) =
+            get_return_data().ok_or(ProgramError::IncorrectProgramId)?;
+        Self::try_from_program_return_data_for_prog(program_id, setter_program_id, &data)
+    }
+
+    pub fn get() -> Result<Self, ProgramError> {
+        Self::get_for_prog(crate::program::ID)
+    }
+}
@@ -1,7 +1,10 @@
-use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_interface::{LstState, PoolConfig, PoolState, SControllerError};
+use sanctum_token_ratio::BPS_DENOMINATOR;
 use solana_readonly_account::ReadonlyAccountData;
 
-use crate::{try_pool_state, U8Bool};
+use crate::{
+    try_pool_config, try_pool_state, U8Bool, MIN_RESERVES_FOR_SOL_VALUE_RATE, SOL_VALUE_RATE_UNIT,
+};
 
 /// sync SOL value using the SOL value of the current pool reserves balance,
 /// which is the min val of the range returned by the LST's SOL value calculator
@@ -23,6 +26,157 @@ pub fn sync_sol_value_with_retval(
     Ok(())
 }
 
+/// Checks that the LST's `sol_value` does not make up more than
+/// `max_share_bps` of `pool_total_sol_value`.
+/// `max_share_bps = 0` means the LST is uncapped.
+pub fn verify_lst_max_share(
+    lst_state: &LstState,
+    pool_total_sol_value: u64,
+) -> Result<(), SControllerError> {
+    if lst_state.max_share_bps == 0 {
+        return Ok(());
+    }
+    let lst_sol_value_bps = u128::from(lst_state.sol_value)
+        .checked_mul(u128::from(BPS_DENOMINATOR))
+        .ok_or(SControllerError::MathError)?;
+    let max_sol_value_bps = u128::from(pool_total_sol_value)
+        .checked_mul(u128::from(lst_state.max_share_bps))
+        .ok_or(SControllerError::MathError)?;
+    if lst_sol_value_bps > max_sol_value_bps {
+        return Err(SControllerError::LstMaxShareExceeded);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SolValueRateArgs {
+    /// Pool reserves balance the LST was synced with
+    pub reserves_balance: u64,
+
+    /// SOL value returned by the LST's SOL value calculator for `reserves_balance`
+    pub sol_value: u64,
+
+    /// Current epoch
+    pub epoch: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SolValueRateTrip {
+    /// Baseline the new rate was compared against
+    pub last_sol_value_rate: u64,
+    pub new_sol_value_rate: u64,
+}
+
+/// SOL value of [`SOL_VALUE_RATE_UNIT`] atomics of the LST.
+/// Returns None if `reserves_balance` is below [`MIN_RESERVES_FOR_SOL_VALUE_RATE`]
+pub fn calc_sol_value_rate(
+    SolValueRateArgs {
+        reserves_balance,
+        sol_value,
+        ..
+    }: SolValueRateArgs,
+) -> Result<Option<u64>, SControllerError> {
+    if reserves_balance < MIN_RESERVES_FOR_SOL_VALUE_RATE {
+        return Ok(None);
+    }
+    let rate = u128::from(sol_value)
+        .checked_mul(u128::from(SOL_VALUE_RATE_UNIT))
+        .and_then(|n| n.checked_div(u128::from(reserves_balance)))
+        .ok_or(SControllerError::MathError)?;
+    let rate = rate.try_into().map_err(|_e| SControllerError::MathError)?;
+    Ok(Some(rate))
+}
+
+/// Compares the LST's new SOL value rate against the last rate recorded before the current epoch.
+///
+/// `lst_state.prev_epoch_sol_value_rate` is rolled forward to `lst_state.last_sol_value_rate`
+/// when the epoch changes and stays fixed within an epoch, so that the rate cannot be
+/// ratcheted past the threshold by many small moves, within or across epochs.
+/// The new rate is only recorded, along with the rolled baseline and `last_sol_value_rate_epoch`,
+/// if the circuit breaker does not trip.
+///
+/// Returns the trip if the LST's `sol_value_rate_circuit_breaker_bps` is set and
+/// the rate moved by more than it since the baseline.
+/// `sol_value_rate_circuit_breaker_bps = 0` means the circuit breaker is off.
+pub fn record_sol_value_rate(
+    lst_state: &mut LstState,
+    args: SolValueRateArgs,
+) -> Result<Option<SolValueRateTrip>, SControllerError> {
+    let new_sol_value_rate = match calc_sol_value_rate(args)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+    let baseline = if lst_state.last_sol_value_rate == 0 {
+        new_sol_value_rate
+    } else if lst_state.last_sol_value_rate_epoch != args.epoch {
+        lst_state.last_sol_value_rate
+    } else {
+        lst_state.prev_epoch_sol_value_rate
+    };
+    if lst_state.sol_value_rate_circuit_breaker_bps != 0 {
+        let diff_bps = u128::from(new_sol_value_rate.abs_diff(baseline))
+            .checked_mul(u128::from(BPS_DENOMINATOR))
+            .ok_or(SControllerError::MathError)?;
+        let max_diff_bps = u128::from(baseline)
+            .checked_mul(u128::from(lst_state.sol_value_rate_circuit_breaker_bps))
+            .ok_or(SControllerError::MathError)?;
+        if diff_bps > max_diff_bps {
+            return Ok(Some(SolValueRateTrip {
+                last_sol_value_rate: baseline,
+                new_sol_value_rate,
+            }));
+        }
+    }
+    lst_state.prev_epoch_sol_value_rate = baseline;
+    lst_state.last_sol_value_rate = new_sol_value_rate;
+    lst_state.last_sol_value_rate_epoch = args.epoch;
+    Ok(None)
+}
+
+/// [`sync_sol_value_with_retval`] after recording the LST's new SOL value rate
+/// with [`record_sol_value_rate`], then records `args.epoch` as the LST's `last_sync_epoch`.
+///
+/// If the circuit breaker trips, `lst_state.sol_value`, `lst_state.last_sync_epoch`
+/// and `pool_state.total_sol_value` are left unchanged and the trip is returned
+pub fn sync_sol_value_with_circuit_breaker(
+    pool_state: &mut PoolState,
+    lst_state: &mut LstState,
+    args: SolValueRateArgs,
+) -> Result<Option<SolValueRateTrip>, SControllerError> {
+    if let Some(trip) = record_sol_value_rate(lst_state, args)? {
+        return Ok(Some(trip));
+    }
+    sync_sol_value_with_retval(pool_state, lst_state, args.sol_value)?;
+    lst_state.last_sync_epoch = args.epoch;
+    Ok(None)
+}
+
+/// An LST is stale if it has not been synced in `epoch`
+/// and its `sol_value` is not below `dust_threshold`
+pub fn is_lst_stale(lst_state: &LstState, epoch: u64, dust_threshold: u64) -> bool {
+    lst_state.last_sync_epoch != epoch && lst_state.sol_value >= dust_threshold
+}
+
+/// Checks that no LST in `lst_state_list` is stale if
+/// the pool's `lp_requires_synced_lsts` is set.
+/// LSTs with `sol_value` below `lp_synced_lsts_dust_threshold` are exempt.
+pub fn verify_lsts_synced_for_lp(
+    pool_config: &PoolConfig,
+    lst_state_list: &[LstState],
+    epoch: u64,
+) -> Result<(), SControllerError> {
+    if U8Bool(pool_config.lp_requires_synced_lsts).is_false() {
+        return Ok(());
+    }
+    if lst_state_list
+        .iter()
+        .any(|lst_state| is_lst_stale(lst_state, epoch, pool_config.lp_synced_lsts_dust_threshold))
+    {
+        return Err(SControllerError::LstNotSyncedThisEpoch);
+    }
+    Ok(())
+}
+
 /// For nice method call syntax both onchain and offchain,
 /// and to reduce scope of borrowing AccountInfo.data
 /// to avoid CPI account data borrow failed errors.
@@ -63,3 +217,28 @@ impl<D: ReadonlyAccountData> PoolStateAccount for D {
         Ok(U8Bool(deser.is_disabled).is_true())
     }
 }
+
+/// Same as [`PoolStateAccount`], for the PoolConfig PDA.
+/// Returns the defaults if the PDA has not been created yet.
+pub trait PoolConfigAccount {
+    /// Whether protocol fees should be minted as LP tokens to the protocol fee LP token account
+    /// instead of being transferred to the LST protocol fee accumulators
+    fn protocol_fees_as_lp(&self) -> Result<bool, SControllerError>;
+
+    /// Max SOL value a rebalance is allowed to lose, in bps of the pool's total SOL value
+    fn max_rebalance_loss_bps(&self) -> Result<u16, SControllerError>;
+}
+
+impl<D: ReadonlyAccountData> PoolConfigAccount for D {
+    fn protocol_fees_as_lp(&self) -> Result<bool, SControllerError> {
+        let bytes = self.data();
+        let deser = try_pool_config(&bytes)?;
+        Ok(U8Bool(deser.protocol_fees_as_lp).is_true())
+    }
+
+    fn max_rebalance_loss_bps(&self) -> Result<u16, SControllerError> {
+        let bytes = self.data();
+        let deser = try_pool_config(&bytes)?;
+        Ok(deser.max_rebalance_loss_bps)
+    }
+}
@@ -0,0 +1,38 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::DepositStakeProgram;
+
+/// `StakePoolInstruction::WithdrawStake` discriminant
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_DISCM: u8 = 10;
+
+/// Number of accounts of `StakePoolInstruction::WithdrawStake`
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_ACCOUNTS_LEN: usize = 13;
+
+/// Index of `user_stake_authority`, the new withdraw authority of the received stake account,
+/// in `StakePoolInstruction::WithdrawStake`'s accounts
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_USER_STAKE_AUTHORITY_INDEX: usize = 5;
+
+/// Index of `user_transfer_authority`, the authority of the pool tokens to burn,
+/// in `StakePoolInstruction::WithdrawStake`'s accounts
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_USER_TRANSFER_AUTHORITY_INDEX: usize = 6;
+
+/// Index of `user_pool_token_account`, the token account to burn pool tokens from,
+/// in `StakePoolInstruction::WithdrawStake`'s accounts
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_BURN_FROM_INDEX: usize = 7;
+
+/// Index of `pool_mint` in `StakePoolInstruction::WithdrawStake`'s accounts
+pub const SPL_STAKE_POOL_WITHDRAW_STAKE_POOL_MINT_INDEX: usize = 9;
+
+/// The stake pool programs that RemoveLiquidityToStake can CPI into to withdraw a stake account.
+/// Only the SPL stake pool family is supported.
+pub fn is_withdraw_stake_program(program_id: &Pubkey) -> bool {
+    DepositStakeProgram::try_from_program_id(program_id) == Some(DepositStakeProgram::SplStakePool)
+}
+
+pub fn spl_stake_pool_withdraw_stake_ix_data(pool_tokens: u64) -> Vec<u8> {
+    [
+        [SPL_STAKE_POOL_WITHDRAW_STAKE_DISCM].as_slice(),
+        &pool_tokens.to_le_bytes(),
+    ]
+    .concat()
+}
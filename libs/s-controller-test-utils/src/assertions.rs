@@ -21,6 +21,16 @@ pub async fn assert_lst_input_enabled(banks_client: &mut BanksClient, lst_mint:
     assert!(U8Bool(lst_state.is_input_disabled).is_false())
 }
 
+pub async fn assert_lst_output_disabled(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let lst_state = banks_client.get_lst_state(lst_mint).await;
+    assert!(U8Bool(lst_state.is_output_disabled).is_true())
+}
+
+pub async fn assert_lst_output_enabled(banks_client: &mut BanksClient, lst_mint: Pubkey) {
+    let lst_state = banks_client.get_lst_state(lst_mint).await;
+    assert!(U8Bool(lst_state.is_output_disabled).is_false())
+}
+
 pub async fn assert_pool_disabled(banks_client: &mut BanksClient) {
     let pool_state_acc = banks_client.get_pool_state_acc().await;
     let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
@@ -1,11 +1,23 @@
 mod assertions;
 mod disable_pool_authority_list;
 mod lst_state;
+mod pending_authorities;
+mod pool_config;
 mod program_test;
+mod protocol_fee_beneficiary_list;
+mod queued_change;
+mod rebalance_authority_list;
 mod state;
+mod token_2022;
 
 pub use assertions::*;
 pub use disable_pool_authority_list::*;
 pub use lst_state::*;
+pub use pending_authorities::*;
+pub use pool_config::*;
 pub use program_test::*;
+pub use protocol_fee_beneficiary_list::*;
+pub use queued_change::*;
+pub use rebalance_authority_list::*;
 pub use state::*;
+pub use token_2022::*;
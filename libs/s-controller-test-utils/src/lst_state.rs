@@ -1,18 +1,19 @@
 use async_trait::async_trait;
 use s_controller_interface::LstState;
 use s_controller_lib::{
-    find_pool_reserves_address, find_protocol_fee_accumulator_address, try_find_lst_mint_on_list,
-    try_lst_state_list, try_lst_state_list_mut, FindLstPdaAtaKeys, LST_STATE_SIZE,
+    find_pool_reserves_address_with_pool_state_id,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_lst_state_list_mut, FindLstPdaAtaKeys, PoolKey, LST_STATE_SIZE,
 };
 use sanctum_solana_test_utils::{
-    est_rent_exempt_lamports,
-    token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
-    ExtendedBanksClient, IntoAccount,
+    est_rent_exempt_lamports, token::MockTokenAccountArgs, ExtendedBanksClient,
 };
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{BanksClient, ProgramTest};
 use solana_sdk::account::Account;
 
+use crate::mock_lst_token_account;
+
 #[derive(Clone, Copy, Debug)]
 pub struct MockLstStateArgs {
     pub mint: Pubkey,
@@ -22,6 +23,7 @@ pub struct MockLstStateArgs {
     pub reserves_amt: u64,
     pub protocol_fee_accumulator_amt: u64,
     pub is_input_disabled: bool,
+    pub is_output_disabled: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -33,8 +35,14 @@ pub struct MockLstStateRet {
     pub protocol_fee_accumulator_account: Account,
 }
 
-/// Assumes LST uses original spl-token program
-pub fn mock_lst_state(
+/// Token-2022 LSTs' token accounts are created with the TransferFeeAmount extension
+pub fn mock_lst_state(args: MockLstStateArgs) -> MockLstStateRet {
+    mock_lst_state_for_pool(&PoolKey::DEFAULT, args)
+}
+
+/// See [`mock_lst_state`]
+pub fn mock_lst_state_for_pool(
+    pool: &PoolKey,
     MockLstStateArgs {
         mint,
         sol_value_calculator,
@@ -43,40 +51,57 @@ pub fn mock_lst_state(
         reserves_amt,
         protocol_fee_accumulator_amt,
         is_input_disabled,
+        is_output_disabled,
     }: MockLstStateArgs,
 ) -> MockLstStateRet {
     let find_keys = FindLstPdaAtaKeys {
         lst_mint: mint,
         token_program,
     };
-    let (reserves_address, pool_reserves_bump) = find_pool_reserves_address(find_keys);
+    let protocol_fee_id = pool.protocol_fee_pda().address;
+    let (reserves_address, pool_reserves_bump) =
+        find_pool_reserves_address_with_pool_state_id(pool.pool_state, find_keys);
     let (protocol_fee_accumulator_address, protocol_fee_accumulator_bump) =
-        find_protocol_fee_accumulator_address(find_keys);
+        find_protocol_fee_accumulator_address_with_protocol_fee_id(protocol_fee_id, find_keys);
     let lst_state = LstState {
         mint,
         sol_value,
         is_input_disabled: is_input_disabled.into(),
+        is_output_disabled: is_output_disabled.into(),
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
-        padding: Default::default(),
+        flash_loan_fee_bps: 0,
+        max_share_bps: 0,
         sol_value_calculator,
+        last_sol_value_rate: 0,
+        last_sol_value_rate_epoch: 0,
+        sol_value_rate_circuit_breaker_bps: 0,
+        padding: Default::default(),
+        last_sync_epoch: 0,
+        prev_epoch_sol_value_rate: 0,
     };
-    let reserves_account = mock_tokenkeg_account(MockTokenAccountArgs {
-        mint,
-        authority: s_controller_lib::program::POOL_STATE_ID,
-        amount: reserves_amt,
-    });
-    let protocol_fee_accumulator_account = mock_tokenkeg_account(MockTokenAccountArgs {
-        mint,
-        authority: s_controller_lib::program::PROTOCOL_FEE_ID,
-        amount: protocol_fee_accumulator_amt,
-    });
+    let reserves_account = mock_lst_token_account(
+        token_program,
+        MockTokenAccountArgs {
+            mint,
+            authority: pool.pool_state,
+            amount: reserves_amt,
+        },
+    );
+    let protocol_fee_accumulator_account = mock_lst_token_account(
+        token_program,
+        MockTokenAccountArgs {
+            mint,
+            authority: protocol_fee_id,
+            amount: protocol_fee_accumulator_amt,
+        },
+    );
     MockLstStateRet {
         lst_state,
         reserves_address,
-        reserves_account: reserves_account.into_account(),
+        reserves_account,
         protocol_fee_accumulator_address,
-        protocol_fee_accumulator_account: protocol_fee_accumulator_account.into_account(),
+        protocol_fee_accumulator_account,
     }
 }
 
@@ -88,10 +113,22 @@ pub trait LstStateListProgramTest {
     fn add_lst_state_list(self, lst_states: &[LstState]) -> Self;
 
     fn add_mock_lst_states(self, args: &[MockLstStateArgs]) -> Self;
+
+    fn add_lst_state_list_for_pool(self, pool: &PoolKey, lst_states: &[LstState]) -> Self;
+
+    fn add_mock_lst_states_for_pool(self, pool: &PoolKey, args: &[MockLstStateArgs]) -> Self;
 }
 
 impl LstStateListProgramTest for ProgramTest {
-    fn add_lst_state_list(mut self, lst_states: &[LstState]) -> Self {
+    fn add_lst_state_list(self, lst_states: &[LstState]) -> Self {
+        self.add_lst_state_list_for_pool(&PoolKey::DEFAULT, lst_states)
+    }
+
+    fn add_mock_lst_states(self, args: &[MockLstStateArgs]) -> Self {
+        self.add_mock_lst_states_for_pool(&PoolKey::DEFAULT, args)
+    }
+
+    fn add_lst_state_list_for_pool(mut self, pool: &PoolKey, lst_states: &[LstState]) -> Self {
         let mut data = vec![0u8; lst_states.len() * LST_STATE_SIZE];
         let lst_state_list = try_lst_state_list_mut(&mut data).unwrap();
         lst_state_list.copy_from_slice(lst_states);
@@ -104,11 +141,11 @@ impl LstStateListProgramTest for ProgramTest {
             rent_epoch: u64::MAX,
         };
 
-        self.add_account(s_controller_lib::program::LST_STATE_LIST_ID, account);
+        self.add_account(pool.lst_state_list_pda().address, account);
         self
     }
 
-    fn add_mock_lst_states(mut self, args: &[MockLstStateArgs]) -> Self {
+    fn add_mock_lst_states_for_pool(mut self, pool: &PoolKey, args: &[MockLstStateArgs]) -> Self {
         let mut lst_states = Vec::new();
         for arg in args {
             let MockLstStateRet {
@@ -117,7 +154,7 @@ impl LstStateListProgramTest for ProgramTest {
                 reserves_account,
                 protocol_fee_accumulator_address,
                 protocol_fee_accumulator_account,
-            } = mock_lst_state(*arg);
+            } = mock_lst_state_for_pool(pool, *arg);
             self.add_account(reserves_address, reserves_account);
             self.add_account(
                 protocol_fee_accumulator_address,
@@ -125,7 +162,7 @@ impl LstStateListProgramTest for ProgramTest {
             );
             lst_states.push(lst_state);
         }
-        self.add_lst_state_list(&lst_states)
+        self.add_lst_state_list_for_pool(pool, &lst_states)
     }
 }
 
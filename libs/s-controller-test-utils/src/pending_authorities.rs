@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use s_controller_interface::PendingAuthorities;
+use s_controller_lib::{
+    program::PENDING_AUTHORITIES_ID, try_pending_authorities, try_pending_authorities_mut,
+    PENDING_AUTHORITIES_SIZE,
+};
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports, ExtendedBanksClient, ExtendedProgramTest, IntoAccount,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+pub const NO_PENDING_AUTHORITIES: PendingAuthorities = PendingAuthorities {
+    admin: Pubkey::new_from_array([0u8; 32]),
+    rebalance_authority: Pubkey::new_from_array([0u8; 32]),
+    protocol_fee_beneficiary: Pubkey::new_from_array([0u8; 32]),
+};
+
+pub struct MockPendingAuthorities(pub PendingAuthorities);
+
+impl IntoAccount for MockPendingAuthorities {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; PENDING_AUTHORITIES_SIZE];
+        let dst = try_pending_authorities_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(PENDING_AUTHORITIES_SIZE),
+            data,
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait PendingAuthoritiesBanksClient {
+    async fn get_pending_authorities(&mut self) -> PendingAuthorities;
+}
+
+#[async_trait]
+impl PendingAuthoritiesBanksClient for BanksClient {
+    async fn get_pending_authorities(&mut self) -> PendingAuthorities {
+        let acc = self.get_account_unwrapped(PENDING_AUTHORITIES_ID).await;
+        *try_pending_authorities(&acc.data).unwrap()
+    }
+}
+
+pub trait PendingAuthoritiesProgramTest {
+    fn add_pending_authorities(self, pending_authorities: PendingAuthorities) -> Self;
+}
+
+impl PendingAuthoritiesProgramTest for ProgramTest {
+    fn add_pending_authorities(self, pending_authorities: PendingAuthorities) -> Self {
+        self.add_account_chained(
+            PENDING_AUTHORITIES_ID,
+            MockPendingAuthorities(pending_authorities).into_account(),
+        )
+    }
+}
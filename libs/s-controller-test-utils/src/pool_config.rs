@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use s_controller_interface::PoolConfig;
+use s_controller_lib::{
+    program::POOL_CONFIG_ID, try_pool_config, try_pool_config_mut, POOL_CONFIG_SIZE,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedProgramTest, IntoAccount};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+pub struct MockPoolConfig(pub PoolConfig);
+
+impl IntoAccount for MockPoolConfig {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; POOL_CONFIG_SIZE];
+        let dst = try_pool_config_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(POOL_CONFIG_SIZE),
+            data,
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait PoolConfigBanksClient {
+    /// Returns the default PoolConfig if the PDA has not been created yet
+    async fn get_pool_config(&mut self) -> PoolConfig;
+}
+
+#[async_trait]
+impl PoolConfigBanksClient for BanksClient {
+    async fn get_pool_config(&mut self) -> PoolConfig {
+        let data = self
+            .get_account(POOL_CONFIG_ID)
+            .await
+            .unwrap()
+            .map_or_else(Vec::new, |acc| acc.data);
+        try_pool_config(&data).unwrap()
+    }
+}
+
+pub trait PoolConfigProgramTest {
+    fn add_pool_config(self, pool_config: PoolConfig) -> Self;
+}
+
+impl PoolConfigProgramTest for ProgramTest {
+    fn add_pool_config(self, pool_config: PoolConfig) -> Self {
+        self.add_account_chained(POOL_CONFIG_ID, MockPoolConfig(pool_config).into_account())
+    }
+}
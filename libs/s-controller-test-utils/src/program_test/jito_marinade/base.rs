@@ -1,12 +1,15 @@
 use marinade_keys::msol;
 use s_controller_interface::PoolState;
+use s_controller_lib::PoolKey;
+use sanctum_solana_test_utils::ExtendedProgramTest;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::ProgramTest;
+use spl_token_2022::extension::transfer_fee::TransferFee;
 use test_utils::jitosol;
 
 use crate::{
-    AddMarinadeProgramTest, AddSplProgramTest, LpTokenProgramTest, LstStateListProgramTest,
-    MockLstStateArgs, DEFAULT_POOL_STATE,
+    mock_transfer_fee_mint, AddMarinadeProgramTest, AddSplProgramTest, LpTokenProgramTest,
+    LstStateListProgramTest, MockLstStateArgs, DEFAULT_POOL_STATE,
 };
 
 #[derive(Clone, Copy, Default, Debug)]
@@ -33,6 +36,46 @@ impl JitoMarinadeProgramTestArgs {
 /// Dont forget to add the s_controller program afterwards.
 /// Omitted to avoid circular dependencies
 pub fn jito_marinade_base_program_test(
+    args: JitoMarinadeProgramTestArgs,
+) -> (ProgramTest, PoolState) {
+    jito_marinade_base_program_test_for_pool(&PoolKey::DEFAULT, args)
+}
+
+/// Same as [`jito_marinade_base_program_test`] but for the pool `pool`,
+/// whose LP token mint should be `args.lp_token_mint` for additional pools
+pub fn jito_marinade_base_program_test_for_pool(
+    pool: &PoolKey,
+    args: JitoMarinadeProgramTestArgs,
+) -> (ProgramTest, PoolState) {
+    jito_marinade_base_program_test_with_jitosol(
+        ProgramTest::default().add_jito_stake_pool(),
+        pool,
+        spl_token::ID,
+        args,
+    )
+}
+
+/// Same as [`jito_marinade_base_program_test`] but the jitoSOL mint is replaced
+/// by a token-2022 mint at the same address that charges `jitosol_transfer_fee`
+pub fn jito_marinade_transfer_fee_base_program_test(
+    args: JitoMarinadeProgramTestArgs,
+    jitosol_transfer_fee: TransferFee,
+) -> (ProgramTest, PoolState) {
+    jito_marinade_base_program_test_with_jitosol(
+        ProgramTest::default()
+            .add_test_fixtures_account("jito-stake-pool.json")
+            .add_account_chained(jitosol::ID, mock_transfer_fee_mint(jitosol_transfer_fee)),
+        &PoolKey::DEFAULT,
+        spl_token_2022::ID,
+        args,
+    )
+}
+
+/// `program_test` should already have the jito stake pool and jitoSOL mint added
+fn jito_marinade_base_program_test_with_jitosol(
+    mut program_test: ProgramTest,
+    pool: &PoolKey,
+    jitosol_token_program: Pubkey,
     JitoMarinadeProgramTestArgs {
         jitosol_sol_value,
         msol_sol_value,
@@ -44,33 +87,36 @@ pub fn jito_marinade_base_program_test(
         lp_token_supply,
     }: JitoMarinadeProgramTestArgs,
 ) -> (ProgramTest, PoolState) {
-    let mut program_test = ProgramTest::default();
     program_test = program_test
         .add_spl_progs()
         .add_marinade_progs()
-        .add_jito_stake_pool()
         .add_marinade_stake_pool()
-        .add_mock_lst_states(&[
-            MockLstStateArgs {
-                mint: jitosol::ID,
-                sol_value: jitosol_sol_value,
-                reserves_amt: jitosol_reserves,
-                protocol_fee_accumulator_amt: jitosol_protocol_fee_accumulator,
-                token_program: spl_token::ID,
-                sol_value_calculator: spl_calculator_lib::program::ID,
-                is_input_disabled: false,
-            },
-            MockLstStateArgs {
-                mint: msol::ID,
-                sol_value: msol_sol_value,
-                reserves_amt: msol_reserves,
-                protocol_fee_accumulator_amt: msol_protocol_fee_accumulator,
-                token_program: spl_token::ID,
-                sol_value_calculator: marinade_calculator_lib::program::ID,
-                is_input_disabled: false,
-            },
-        ])
-        .add_mock_lp_mint(lp_token_mint, lp_token_supply);
+        .add_mock_lst_states_for_pool(
+            pool,
+            &[
+                MockLstStateArgs {
+                    mint: jitosol::ID,
+                    sol_value: jitosol_sol_value,
+                    reserves_amt: jitosol_reserves,
+                    protocol_fee_accumulator_amt: jitosol_protocol_fee_accumulator,
+                    token_program: jitosol_token_program,
+                    sol_value_calculator: spl_calculator_lib::program::ID,
+                    is_input_disabled: false,
+                    is_output_disabled: false,
+                },
+                MockLstStateArgs {
+                    mint: msol::ID,
+                    sol_value: msol_sol_value,
+                    reserves_amt: msol_reserves,
+                    protocol_fee_accumulator_amt: msol_protocol_fee_accumulator,
+                    token_program: spl_token::ID,
+                    sol_value_calculator: marinade_calculator_lib::program::ID,
+                    is_input_disabled: false,
+                    is_output_disabled: false,
+                },
+            ],
+        )
+        .add_mock_lp_mint_for_pool(pool, lp_token_mint, lp_token_supply);
 
     let total_sol_value = jitosol_sol_value + msol_sol_value;
 
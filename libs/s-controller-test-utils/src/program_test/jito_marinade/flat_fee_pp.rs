@@ -1,10 +1,15 @@
 use flat_fee_test_utils::{MockFeeAccount, MockFeeAccountArgs, MockProgramState};
+use s_controller_interface::PoolState;
 use sanctum_solana_test_utils::IntoAccount;
 use solana_program_test::{processor, ProgramTest};
+use spl_token_2022::extension::transfer_fee::TransferFee;
 
 use crate::{MockPoolState, MockProtocolFeeBps};
 
-use super::{jito_marinade_base_program_test, JitoMarinadeProgramTestArgs};
+use super::{
+    jito_marinade_base_program_test, jito_marinade_transfer_fee_base_program_test,
+    JitoMarinadeProgramTestArgs,
+};
 
 /// dont forget to
 ///
@@ -19,9 +24,39 @@ pub fn jito_marinade_flat_fee_program_test(
     args: JitoMarinadeProgramTestArgs,
     flat_fee_state: flat_fee_interface::ProgramState,
     mock_fee_accounts: [MockFeeAccountArgs; 2],
+    protocol_fee_bps: MockProtocolFeeBps,
+) -> ProgramTest {
+    with_flat_fee_pricing_program(
+        jito_marinade_base_program_test(args),
+        flat_fee_state,
+        mock_fee_accounts,
+        protocol_fee_bps,
+    )
+}
+
+/// Same as [`jito_marinade_flat_fee_program_test`] but the jitoSOL mint is replaced
+/// by a token-2022 mint at the same address that charges `jitosol_transfer_fee`
+pub fn jito_marinade_transfer_fee_flat_fee_program_test(
+    args: JitoMarinadeProgramTestArgs,
+    flat_fee_state: flat_fee_interface::ProgramState,
+    mock_fee_accounts: [MockFeeAccountArgs; 2],
+    protocol_fee_bps: MockProtocolFeeBps,
+    jitosol_transfer_fee: TransferFee,
+) -> ProgramTest {
+    with_flat_fee_pricing_program(
+        jito_marinade_transfer_fee_base_program_test(args, jitosol_transfer_fee),
+        flat_fee_state,
+        mock_fee_accounts,
+        protocol_fee_bps,
+    )
+}
+
+fn with_flat_fee_pricing_program(
+    (mut program_test, mut pool_state): (ProgramTest, PoolState),
+    flat_fee_state: flat_fee_interface::ProgramState,
+    mock_fee_accounts: [MockFeeAccountArgs; 2],
     MockProtocolFeeBps { trading, lp }: MockProtocolFeeBps,
 ) -> ProgramTest {
-    let (mut program_test, mut pool_state) = jito_marinade_base_program_test(args);
     program_test.add_program(
         "flat_fee",
         flat_fee_interface::ID,
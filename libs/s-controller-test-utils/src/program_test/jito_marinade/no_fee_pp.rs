@@ -1,9 +1,15 @@
+use s_controller_interface::PoolState;
+use s_controller_lib::PoolKey;
 use sanctum_solana_test_utils::IntoAccount;
 use solana_program_test::{processor, ProgramTest};
+use spl_token_2022::extension::transfer_fee::TransferFee;
 
 use crate::MockPoolState;
 
-use super::{jito_marinade_base_program_test, JitoMarinadeProgramTestArgs};
+use super::{
+    jito_marinade_base_program_test_for_pool, jito_marinade_transfer_fee_base_program_test,
+    JitoMarinadeProgramTestArgs,
+};
 
 /// dont forget to
 ///
@@ -15,16 +21,40 @@ use super::{jito_marinade_base_program_test, JitoMarinadeProgramTestArgs};
 /// });
 /// ```
 pub fn jito_marinade_no_fee_program_test(args: JitoMarinadeProgramTestArgs) -> ProgramTest {
-    let (mut program_test, mut pool_state) = jito_marinade_base_program_test(args);
+    jito_marinade_no_fee_program_test_for_pool(&PoolKey::DEFAULT, args)
+}
+
+/// Same as [`jito_marinade_no_fee_program_test`] but for the pool `pool`,
+/// whose LP token mint should be `args.lp_token_mint` for additional pools
+pub fn jito_marinade_no_fee_program_test_for_pool(
+    pool: &PoolKey,
+    args: JitoMarinadeProgramTestArgs,
+) -> ProgramTest {
+    with_no_fee_pricing_program(pool, jito_marinade_base_program_test_for_pool(pool, args))
+}
+
+/// Same as [`jito_marinade_no_fee_program_test`] but the jitoSOL mint is replaced
+/// by a token-2022 mint at the same address that charges `jitosol_transfer_fee`
+pub fn jito_marinade_transfer_fee_no_fee_program_test(
+    args: JitoMarinadeProgramTestArgs,
+    jitosol_transfer_fee: TransferFee,
+) -> ProgramTest {
+    with_no_fee_pricing_program(
+        &PoolKey::DEFAULT,
+        jito_marinade_transfer_fee_base_program_test(args, jitosol_transfer_fee),
+    )
+}
+
+fn with_no_fee_pricing_program(
+    pool: &PoolKey,
+    (mut program_test, mut pool_state): (ProgramTest, PoolState),
+) -> ProgramTest {
     program_test.add_program(
         "no_fee_pricing_program",
         no_fee_pricing_program::ID,
         processor!(no_fee_pricing_program::process_instruction),
     );
     pool_state.pricing_program = no_fee_pricing_program::ID;
-    program_test.add_account(
-        s_controller_lib::program::POOL_STATE_ID,
-        MockPoolState(pool_state).into_account(),
-    );
+    program_test.add_account(pool.pool_state, MockPoolState(pool_state).into_account());
     program_test
 }
@@ -53,6 +53,7 @@ pub fn lido_wsol_base_program_test(
                 token_program: spl_token::ID,
                 sol_value_calculator: lido_calculator_lib::program::ID,
                 is_input_disabled: false,
+                is_output_disabled: false,
             },
             MockLstStateArgs {
                 mint: native_mint::ID,
@@ -62,6 +63,7 @@ pub fn lido_wsol_base_program_test(
                 token_program: spl_token::ID,
                 sol_value_calculator: wsol_calculator_lib::program::ID,
                 is_input_disabled: false,
+                is_output_disabled: false,
             },
         ])
         .add_mock_lp_mint(lp_token_mint, lp_token_supply);
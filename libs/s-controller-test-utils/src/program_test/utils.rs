@@ -16,6 +16,8 @@ use test_utils::{
     SPL_STAKE_POOL_PROG_LAST_UPDATED_SLOT,
 };
 
+use crate::mock_transfer_fee_token_account;
+
 pub trait AddSplProgramTest {
     fn add_spl_progs(self) -> Self;
 
@@ -105,6 +107,10 @@ impl AddLidoProgramTest for ProgramTest {
 
 pub trait GenAndAddTokenAccountProgramTest {
     fn gen_and_add_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
+
+    /// Token-2022 counterpart of [`Self::gen_and_add_token_account`]
+    /// for mints with transfer fees
+    fn gen_and_add_transfer_fee_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey;
 }
 
 impl GenAndAddTokenAccountProgramTest for ProgramTest {
@@ -114,4 +120,10 @@ impl GenAndAddTokenAccountProgramTest for ProgramTest {
         self.add_account(addr, token_acc.into_account());
         addr
     }
+
+    fn gen_and_add_transfer_fee_token_account(&mut self, args: MockTokenAccountArgs) -> Pubkey {
+        let addr = Pubkey::new_unique();
+        self.add_account(addr, mock_transfer_fee_token_account(args));
+        addr
+    }
 }
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use s_controller_interface::ProtocolFeeBeneficiaryShare;
+use s_controller_lib::{
+    try_protocol_fee_beneficiary_list_mut, PROTOCOL_FEE_BENEFICIARY_SHARE_SIZE,
+};
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports, ExtendedBanksClient, ExtendedProgramTest,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+#[async_trait]
+pub trait ProtocolFeeBeneficiaryListBanksClient {
+    async fn get_protocol_fee_beneficiary_list_acc(&mut self) -> Account;
+}
+
+#[async_trait]
+impl ProtocolFeeBeneficiaryListBanksClient for BanksClient {
+    async fn get_protocol_fee_beneficiary_list_acc(&mut self) -> Account {
+        self.get_account_unwrapped(s_controller_lib::program::PROTOCOL_FEE_BENEFICIARY_LIST_ID)
+            .await
+    }
+}
+
+pub const fn protocol_fee_beneficiary_list_rent_exempt_lamports(
+    protocol_fee_beneficiary_list: &[ProtocolFeeBeneficiaryShare],
+) -> u64 {
+    est_rent_exempt_lamports(
+        protocol_fee_beneficiary_list.len() * PROTOCOL_FEE_BENEFICIARY_SHARE_SIZE,
+    )
+}
+
+pub trait ProtocolFeeBeneficiaryListProgramTest {
+    fn add_protocol_fee_beneficiary_list(self, shares: &[ProtocolFeeBeneficiaryShare]) -> Self;
+}
+
+impl ProtocolFeeBeneficiaryListProgramTest for ProgramTest {
+    fn add_protocol_fee_beneficiary_list(self, shares: &[ProtocolFeeBeneficiaryShare]) -> Self {
+        assert!(!shares.is_empty());
+        let mut data = vec![0u8; shares.len() * PROTOCOL_FEE_BENEFICIARY_SHARE_SIZE];
+        let list = try_protocol_fee_beneficiary_list_mut(&mut data).unwrap();
+        list.copy_from_slice(shares);
+
+        let account = Account {
+            data,
+            lamports: protocol_fee_beneficiary_list_rent_exempt_lamports(shares),
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        self.add_account_chained(
+            s_controller_lib::program::PROTOCOL_FEE_BENEFICIARY_LIST_ID,
+            account,
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use s_controller_interface::QueuedChange;
+use s_controller_lib::{
+    create_queued_change_address, try_queued_change, try_queued_change_mut, QUEUED_CHANGE_SIZE,
+};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedProgramTest, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+pub struct MockQueuedChange(pub QueuedChange);
+
+impl IntoAccount for MockQueuedChange {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; QUEUED_CHANGE_SIZE];
+        let dst = try_queued_change_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(QUEUED_CHANGE_SIZE),
+            data,
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait QueuedChangeBanksClient {
+    /// Returns None if no change is queued at `queued_change_addr`
+    async fn get_queued_change(&mut self, queued_change_addr: Pubkey) -> Option<QueuedChange>;
+}
+
+#[async_trait]
+impl QueuedChangeBanksClient for BanksClient {
+    async fn get_queued_change(&mut self, queued_change_addr: Pubkey) -> Option<QueuedChange> {
+        let acc = self.get_account(queued_change_addr).await.unwrap()?;
+        Some(*try_queued_change(&acc.data).unwrap())
+    }
+}
+
+pub trait QueuedChangeProgramTest {
+    /// Adds the queued change at the PDA derived from its kind, lst_mint and bump
+    fn add_queued_change(self, queued_change: QueuedChange) -> Self;
+}
+
+impl QueuedChangeProgramTest for ProgramTest {
+    fn add_queued_change(self, queued_change: QueuedChange) -> Self {
+        let addr =
+            create_queued_change_address(s_controller_lib::program::ID, &queued_change).unwrap();
+        self.add_account_chained(addr, MockQueuedChange(queued_change).into_account())
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use s_controller_interface::RebalanceAuthorityBudget;
+use s_controller_lib::{try_rebalance_authority_list_mut, REBALANCE_AUTHORITY_BUDGET_SIZE};
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports, ExtendedBanksClient, ExtendedProgramTest,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+#[async_trait]
+pub trait RebalanceAuthorityListBanksClient {
+    async fn get_rebalance_authority_list_acc(&mut self) -> Account;
+}
+
+#[async_trait]
+impl RebalanceAuthorityListBanksClient for BanksClient {
+    async fn get_rebalance_authority_list_acc(&mut self) -> Account {
+        self.get_account_unwrapped(s_controller_lib::program::REBALANCE_AUTHORITY_LIST_ID)
+            .await
+    }
+}
+
+pub const fn rebalance_authority_list_rent_exempt_lamports(
+    rebalance_authority_list: &[RebalanceAuthorityBudget],
+) -> u64 {
+    est_rent_exempt_lamports(rebalance_authority_list.len() * REBALANCE_AUTHORITY_BUDGET_SIZE)
+}
+
+pub trait RebalanceAuthorityListProgramTest {
+    fn add_rebalance_authority_list(self, budgets: &[RebalanceAuthorityBudget]) -> Self;
+}
+
+impl RebalanceAuthorityListProgramTest for ProgramTest {
+    fn add_rebalance_authority_list(self, budgets: &[RebalanceAuthorityBudget]) -> Self {
+        assert!(!budgets.is_empty());
+        let mut data = vec![0u8; budgets.len() * REBALANCE_AUTHORITY_BUDGET_SIZE];
+        let list = try_rebalance_authority_list_mut(&mut data).unwrap();
+        list.copy_from_slice(budgets);
+
+        let account = Account {
+            data,
+            lamports: rebalance_authority_list_rent_exempt_lamports(budgets),
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        self.add_account_chained(
+            s_controller_lib::program::REBALANCE_AUTHORITY_LIST_ID,
+            account,
+        )
+    }
+}
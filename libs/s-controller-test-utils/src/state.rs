@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use s_controller_interface::PoolState;
 use s_controller_lib::{
-    initial_authority, program::POOL_STATE_ID, try_pool_state_mut, DEFAULT_PRICING_PROGRAM,
-    POOL_STATE_SIZE,
+    initial_authority, program::POOL_STATE_ID, try_pool_state_mut, PoolKey, CURRENT_PROGRAM_VERS,
+    DEFAULT_PRICING_PROGRAM, POOL_STATE_SIZE,
 };
 use sanctum_solana_test_utils::{
     est_rent_exempt_lamports,
@@ -23,10 +23,10 @@ pub const DEFAULT_POOL_STATE: PoolState = PoolState {
     total_sol_value: 0,
     trading_protocol_fee_bps: 0,
     lp_protocol_fee_bps: 0,
-    version: 0,
+    version: CURRENT_PROGRAM_VERS,
     is_disabled: 0,
     is_rebalancing: 0,
-    padding: [0u8; 1],
+    timelock_delay: 0,
     admin: initial_authority::ID,
     rebalance_authority: initial_authority::ID,
     protocol_fee_beneficiary: initial_authority::ID,
@@ -66,12 +66,18 @@ impl PoolStateBanksClient for BanksClient {
 
 pub trait PoolStateProgramTest {
     fn add_pool_state(self, pool_state: PoolState) -> Self;
+
+    fn add_pool_state_for_pool(self, pool: &PoolKey, pool_state: PoolState) -> Self;
 }
 
 impl PoolStateProgramTest for ProgramTest {
     fn add_pool_state(self, pool_state: PoolState) -> Self {
         self.add_account_chained(POOL_STATE_ID, MockPoolState(pool_state).into_account())
     }
+
+    fn add_pool_state_for_pool(self, pool: &PoolKey, pool_state: PoolState) -> Self {
+        self.add_account_chained(pool.pool_state, MockPoolState(pool_state).into_account())
+    }
 }
 
 pub struct MockLpMintToInitArgs {
@@ -82,6 +88,9 @@ pub struct MockLpMintToInitArgs {
 pub trait LpTokenProgramTest {
     fn add_mock_lp_mint_to_init(self, args: MockLpMintToInitArgs) -> Self;
     fn add_mock_lp_mint(self, addr: Pubkey, supply: u64) -> Self;
+
+    /// Mint and freeze authority is the pool state of `pool`
+    fn add_mock_lp_mint_for_pool(self, pool: &PoolKey, addr: Pubkey, supply: u64) -> Self;
 }
 
 impl LpTokenProgramTest for ProgramTest {
@@ -104,11 +113,15 @@ impl LpTokenProgramTest for ProgramTest {
     }
 
     fn add_mock_lp_mint(self, addr: Pubkey, supply: u64) -> Self {
+        self.add_mock_lp_mint_for_pool(&PoolKey::DEFAULT, addr, supply)
+    }
+
+    fn add_mock_lp_mint_for_pool(self, pool: &PoolKey, addr: Pubkey, supply: u64) -> Self {
         self.add_tokenkeg_mint_from_args(
             addr,
             MockMintArgs {
-                mint_authority: Some(POOL_STATE_ID),
-                freeze_authority: Some(POOL_STATE_ID),
+                mint_authority: Some(pool.pool_state),
+                freeze_authority: Some(pool.pool_state),
                 supply,
                 decimals: 9,
             },
@@ -0,0 +1,90 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    token::{tokenkeg::mock_tokenkeg_account, MockTokenAccountArgs},
+    IntoAccount,
+};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_sdk::account::Account;
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        ExtensionType, StateWithExtensionsMut,
+    },
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+
+/// An initialized token-2022 mint with 9 decimals
+/// that charges `transfer_fee` on transfers in every epoch
+pub fn mock_transfer_fee_mint(transfer_fee: TransferFee) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+        .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let transfer_fee_config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    transfer_fee_config.older_transfer_fee = transfer_fee;
+    transfer_fee_config.newer_transfer_fee = transfer_fee;
+    state.base = Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+/// An initialized token-2022 token account with the TransferFeeAmount extension,
+/// which token accounts of mints with transfer fees must have
+pub fn mock_transfer_fee_token_account(
+    MockTokenAccountArgs {
+        mint,
+        authority,
+        amount,
+    }: MockTokenAccountArgs,
+) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<TokenAccount>(&[
+        ExtensionType::TransferFeeAmount,
+    ])
+    .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state =
+        StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut data).unwrap();
+    state.init_extension::<TransferFeeAmount>(true).unwrap();
+    state.base = TokenAccount {
+        mint,
+        owner: authority,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+/// A token account of a LST using `token_program`.
+/// Token-2022 token accounts are created with the TransferFeeAmount extension
+pub fn mock_lst_token_account(token_program: Pubkey, args: MockTokenAccountArgs) -> Account {
+    if token_program == spl_token_2022::ID {
+        mock_transfer_fee_token_account(args)
+    } else {
+        mock_tokenkeg_account(args).into_account()
+    }
+}
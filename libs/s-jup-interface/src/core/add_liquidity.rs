@@ -5,9 +5,10 @@ use s_controller_interface::{add_liquidity_ix, AddLiquidityIxArgs, SControllerEr
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_lp_accounts,
     account_metas_extend_with_sol_value_calculator_accounts,
-    add_liquidity_ix_by_mint_full_for_prog, calc_lp_tokens_to_mint, index_to_u32, try_pool_state,
-    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes,
-    AddRemoveLiquidityProgramIds, LpTokenRateArgs, U8Bool,
+    add_liquidity_ix_by_mint_full_for_pool, calc_add_liquidity_protocol_fees,
+    calc_lp_tokens_to_mint, index_to_u32, try_pool_state, AddLiquidityByMintFreeArgs,
+    AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityProgramIds,
+    CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult, LpTokenRateArgs, U8Bool,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
@@ -21,7 +22,7 @@ use solana_sdk::{
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{apply_sync_sol_value, calc_quote_fees, verify_input_lst_max_share};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_add_liquidity(
@@ -44,21 +45,39 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         if U8Bool(input_lst_state.is_input_disabled).is_true() {
             return Err(SControllerError::LstInputDisabled.into());
         }
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
+        let (pool_state, input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
 
-        let lst_amount_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        let lst_amount_received = input_lst_data.transfer_fee()?.amt_after_fee(*amount)?;
+        let lst_amount_sol_value = input_lst_data
+            .sol_val_calc
+            .lst_to_sol(lst_amount_received)?
+            .get_min();
 
         let lst_amount_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_mint(
             *input_mint,
             &PriceLpTokensToMintIxArgs {
-                amount: *amount,
+                amount: lst_amount_received,
                 sol_value: lst_amount_sol_value,
             },
         )?;
         if lst_amount_sol_value_after_fees > lst_amount_sol_value {
             return Err(SControllerError::PoolWouldLoseSolValue.into());
         }
+        let CalcAddLiquidityProtocolFeesResult {
+            to_reserves_lst_amount,
+            ..
+        } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount: lst_amount_received,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        })?;
+        let to_reserves_sol_value = input_lst_data
+            .sol_val_calc
+            .lst_to_sol(to_reserves_lst_amount)?
+            .get_min();
+        verify_input_lst_max_share(&pool_state, input_lst_state, to_reserves_sol_value, 0)?;
         let lp_tokens_to_mint = calc_lp_tokens_to_mint(
             LpTokenRateArgs {
                 lp_token_supply,
@@ -125,8 +144,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*source_mint)?;
-        Ok(add_liquidity_ix_by_mint_full_for_prog(
-            self.program_id,
+        Ok(add_liquidity_ix_by_mint_full_for_pool(
+            &self.pool,
             self.add_liquidity_free_args(src_token_program, swap_params)?,
             AddLiquidityIxAmts {
                 lst_amount: *in_amount,
@@ -161,10 +180,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 lst_calculator_program_id,
                 pricing_program_id,
             },
-        ) = free_args.resolve_for_prog(self.program_id)?;
+        ) = free_args.resolve_for_pool(&self.pool)?;
 
         let mut account_metas = vec![AccountMeta {
-            pubkey: self.program_id,
+            pubkey: self.pool.program_id,
             is_signer: false,
             is_writable: false,
         }];
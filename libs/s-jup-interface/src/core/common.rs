@@ -1,7 +1,7 @@
 use anyhow::anyhow;
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use s_controller_interface::{LstState, PoolState};
-use s_controller_lib::sync_sol_value_with_retval;
+use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_lib::{sync_sol_value_with_retval, verify_lst_max_share};
 use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc};
 use sanctum_token_ratio::AmtsAfterFee;
 
@@ -26,6 +26,31 @@ pub fn apply_sync_sol_value(
     Ok((pool_state, lst_state, reserves_balance))
 }
 
+/// Checks that the input LST does not exceed its max share of the pool
+/// after `lst_sol_value_in` is added to its reserves and
+/// `sol_value_out` leaves the pool
+pub fn verify_input_lst_max_share(
+    pool_state: &PoolState,
+    input_lst_state: LstState,
+    lst_sol_value_in: u64,
+    sol_value_out: u64,
+) -> anyhow::Result<()> {
+    let input_lst_state = LstState {
+        sol_value: input_lst_state
+            .sol_value
+            .checked_add(lst_sol_value_in)
+            .ok_or(SControllerError::MathError)?,
+        ..input_lst_state
+    };
+    let pool_total_sol_value = pool_state
+        .total_sol_value
+        .checked_add(lst_sol_value_in)
+        .ok_or(SControllerError::MathError)?
+        .saturating_sub(sol_value_out);
+    verify_lst_max_share(&input_lst_state, pool_total_sol_value)?;
+    Ok(())
+}
+
 /// Returns (fee_amount, fee_pct)
 /// fee_pct is [0.0, 1.0], not [0, 100],
 /// so 0.1 (NOT 10.0) means 10%
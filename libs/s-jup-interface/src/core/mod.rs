@@ -3,7 +3,7 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapMode, SwapParams};
 use s_controller_interface::LstState;
-use s_controller_lib::try_lst_state_list;
+use s_controller_lib::try_lst_state_list_versioned;
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 
@@ -12,11 +12,13 @@ use crate::SPool;
 mod add_liquidity;
 mod common;
 mod remove_liquidity;
+mod remove_liquidity_proportional;
 mod swap_exact_in;
 mod swap_exact_out;
 
 pub use add_liquidity::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_proportional::*;
 pub use swap_exact_in::*;
 pub use swap_exact_out::*;
 
@@ -91,10 +93,11 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     /// Returns all mints this SPool can swap between (includes LP token mint)
     pub fn get_reserve_mints_full(&self) -> Vec<Pubkey> {
         let lst_state_list_data = self.lst_state_list_account.data();
-        let mut res: Vec<Pubkey> = try_lst_state_list(&lst_state_list_data).map_or_else(
-            |_e| vec![],
-            |list| list.iter().map(|LstState { mint, .. }| *mint).collect(),
-        );
+        let mut res: Vec<Pubkey> =
+            try_lst_state_list_versioned(self.pool_version(), &lst_state_list_data).map_or_else(
+                |_e| vec![],
+                |list| list.iter().map(|LstState { mint, .. }| *mint).collect(),
+            );
         if let Ok(lp_token_mint) = self.lp_token_mint() {
             res.push(lp_token_mint);
         }
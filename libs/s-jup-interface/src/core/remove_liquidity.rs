@@ -5,10 +5,10 @@ use s_controller_interface::{remove_liquidity_ix, RemoveLiquidityIxArgs, SContro
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_lp_accounts,
     account_metas_extend_with_sol_value_calculator_accounts, calc_lp_tokens_sol_value,
-    calc_remove_liquidity_protocol_fees, index_to_u32, remove_liquidity_ix_by_mint_full_for_prog,
+    calc_remove_liquidity_protocol_fees, index_to_u32, remove_liquidity_ix_by_mint_full_for_pool,
     try_pool_state, AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityProgramIds,
     CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, RemoveLiquidityByMintFreeArgs,
-    RemoveLiquidityIxAmts,
+    RemoveLiquidityIxAmts, U8Bool,
 };
 use s_pricing_prog_aggregate::PricingProg;
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
@@ -44,6 +44,9 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .ok_or_else(|| anyhow!("LP mint not fetched"))?;
 
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        if U8Bool(output_lst_state.is_output_disabled).is_true() {
+            return Err(SControllerError::LstOutputDisabled.into());
+        }
         let (pool_state, _output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(*pool_state, output_lst_state, output_lst_data)?;
 
@@ -81,6 +84,9 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
+        let to_user_lst_amount_received = output_lst_data
+            .transfer_fee()?
+            .amt_after_fee(to_user_lst_amount)?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(lp_tokens_sol_value)
                 .with_amt_aft_fee(lp_tokens_sol_value_after_fees)?,
@@ -91,7 +97,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             min_in_amount: None,
             min_out_amount: None,
             in_amount: *amount,
-            out_amount: to_user_lst_amount,
+            out_amount: to_user_lst_amount_received,
             fee_mint: *output_mint,
             fee_amount,
             fee_pct,
@@ -143,8 +149,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*destination_mint)?;
-        Ok(remove_liquidity_ix_by_mint_full_for_prog(
-            self.program_id,
+        Ok(remove_liquidity_ix_by_mint_full_for_pool(
+            &self.pool,
             self.remove_liquidity_free_args(src_token_program, swap_params)?,
             RemoveLiquidityIxAmts {
                 lp_token_amount: *in_amount,
@@ -179,10 +185,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 lst_calculator_program_id,
                 pricing_program_id,
             },
-        ) = free_args.resolve_for_prog(self.program_id)?;
+        ) = free_args.resolve_for_pool(&self.pool)?;
 
         let mut account_metas = vec![AccountMeta {
-            pubkey: self.program_id,
+            pubkey: self.pool.program_id,
             is_signer: false,
             is_writable: false,
         }];
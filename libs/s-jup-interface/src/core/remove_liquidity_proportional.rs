@@ -0,0 +1,162 @@
+use anyhow::anyhow;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    calc_remove_liquidity_proportional, remove_liquidity_proportional_ix_full_for_prog,
+    try_lst_state_list_versioned, try_pool_state, CalcRemoveLiquidityProportionalArgs,
+    RemoveLiquidityProportionalByListFreeArgs, RemoveLiquidityProportionalLst,
+    RemoveLiquidityProportionalLstAmts, U8Bool,
+};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{LstData, SPool};
+
+/// Quoted amounts of a single LST for a RemoveLiquidityProportional
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityProportionalLstQuote {
+    pub lst_mint: Pubkey,
+
+    /// Amount of LST the user receives
+    pub out_amount: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fees: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProportionalParams<'a> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub lp_token_amount: u64,
+
+    /// One for each LST in the pool, in lst_state_list order
+    pub dst_lst_accs: &'a [Pubkey],
+
+    /// One for each LST in the pool, in lst_state_list order
+    pub min_lst_outs: &'a [u64],
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Returns the amounts of every LST in the pool, in lst_state_list order,
+    /// that burning `lp_token_amount` with RemoveLiquidityProportional redeems
+    pub fn quote_remove_liquidity_proportional(
+        &self,
+        lp_token_amount: u64,
+    ) -> anyhow::Result<Vec<RemoveLiquidityProportionalLstQuote>> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+        let lp_token_supply = self
+            .lp_mint_supply
+            .ok_or_else(|| anyhow!("LP mint not fetched"))?;
+        let calc_args = CalcRemoveLiquidityProportionalArgs {
+            lp_token_amount,
+            lp_token_supply,
+            remove_liquidity_proportional_fee_bps: self
+                .pool_config()?
+                .remove_liquidity_proportional_fee_bps,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        };
+
+        let lst_state_list_data = self.lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(pool_state.version, &lst_state_list_data)?;
+        lst_state_list
+            .iter()
+            .zip(self.lst_data_list.iter())
+            .map(|(lst_state, lst_data)| -> anyhow::Result<_> {
+                if U8Bool(lst_state.is_output_disabled).is_true() {
+                    return Err(SControllerError::LstOutputDisabled.into());
+                }
+                let lst_data = lst_data
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("LST {} not supported", lst_state.mint))?;
+                let reserves_balance = lst_data
+                    .reserves_balance
+                    .ok_or_else(|| anyhow!("Reserves balance of {} not fetched", lst_state.mint))?;
+                let RemoveLiquidityProportionalLstAmts {
+                    to_user_lst_amount,
+                    to_protocol_fees_lst_amount,
+                } = calc_remove_liquidity_proportional(calc_args, reserves_balance)?;
+                Ok(RemoveLiquidityProportionalLstQuote {
+                    lst_mint: lst_state.mint,
+                    out_amount: lst_data.transfer_fee()?.amt_after_fee(to_user_lst_amount)?,
+                    protocol_fees: to_protocol_fees_lst_amount,
+                })
+            })
+            .collect()
+    }
+
+    pub fn remove_liquidity_proportional_ix(
+        &self,
+        RemoveLiquidityProportionalParams {
+            signer,
+            src_lp_acc,
+            lp_token_amount,
+            dst_lst_accs,
+            min_lst_outs,
+        }: RemoveLiquidityProportionalParams,
+    ) -> anyhow::Result<Instruction> {
+        let lst_state_list_data = self.lst_state_list_account.data();
+        let lst_state_list =
+            try_lst_state_list_versioned(self.pool_version(), &lst_state_list_data)?;
+        if min_lst_outs.len() != lst_state_list.len() {
+            return Err(SControllerError::InvalidRemoveLiquidityProportionalEntries.into());
+        }
+        let lst_datas = lst_state_list
+            .iter()
+            .zip(self.lst_data_list.iter())
+            .map(|(lst_state, lst_data)| {
+                lst_data
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("LST {} not supported", lst_state.mint))
+            })
+            .collect::<anyhow::Result<Vec<&LstData>>>()?;
+        let lst_mints: Vec<MintWithTokenProgram> = lst_state_list
+            .iter()
+            .zip(lst_datas.iter())
+            .map(|(lst_state, lst_data)| MintWithTokenProgram {
+                pubkey: lst_state.mint,
+                token_program: lst_data.token_program,
+            })
+            .collect();
+
+        let (keys, lst_keys) = RemoveLiquidityProportionalByListFreeArgs {
+            signer,
+            src_lp_acc,
+            pool_state: self
+                .pool_state_account
+                .as_ref()
+                .ok_or_else(|| anyhow!("Pool state not fetched"))?,
+            lst_state_list: &self.lst_state_list_account,
+            lst_mints: &lst_mints,
+            dst_lst_accs,
+        }
+        .resolve_for_pool(&self.pool)?;
+
+        let sol_value_calculator_accounts: Vec<_> = lst_datas
+            .iter()
+            .map(|lst_data| lst_data.sol_val_calc.ix_accounts())
+            .collect();
+        let lsts: Vec<RemoveLiquidityProportionalLst> = lst_keys
+            .into_iter()
+            .zip(min_lst_outs)
+            .zip(sol_value_calculator_accounts.iter())
+            .map(|((keys, min_lst_out), sol_value_calculator_accounts)| {
+                RemoveLiquidityProportionalLst {
+                    keys,
+                    min_lst_out: *min_lst_out,
+                    sol_value_calculator_accounts,
+                }
+            })
+            .collect();
+
+        Ok(remove_liquidity_proportional_ix_full_for_prog(
+            self.pool.program_id,
+            keys,
+            lp_token_amount,
+            &lsts,
+        )?)
+    }
+}
@@ -5,7 +5,7 @@ use s_controller_interface::{swap_exact_in_ix, SControllerError, SwapExactInIxAr
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_swap_accounts,
     account_metas_extend_with_src_dst_sol_value_calculator_accounts, calc_swap_protocol_fees,
-    index_to_u32, swap_exact_in_ix_by_mint_full_for_prog, try_pool_state, CalcSwapProtocolFeesArgs,
+    index_to_u32, swap_exact_in_ix_by_mint_full_for_pool, try_pool_state, CalcSwapProtocolFeesArgs,
     SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
     SrcDstLstSolValueCalcExtendCount, SrcDstLstSolValueCalcProgramIds, SwapByMintsFreeArgs,
     SwapExactInAmounts, U8Bool,
@@ -22,7 +22,7 @@ use solana_sdk::{
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{apply_sync_sol_value, calc_quote_fees, verify_input_lst_max_share};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_swap_exact_in(
@@ -45,13 +45,20 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         if U8Bool(input_lst_state.is_input_disabled).is_true() {
             return Err(SControllerError::LstInputDisabled.into());
         }
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
+        let (pool_state, input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        if U8Bool(output_lst_state.is_output_disabled).is_true() {
+            return Err(SControllerError::LstOutputDisabled.into());
+        }
         let (pool_state, _output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
 
-        let in_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
+        let src_lst_received = input_lst_data.transfer_fee()?.amt_after_fee(*amount)?;
+        let in_sol_value = input_lst_data
+            .sol_val_calc
+            .lst_to_sol(src_lst_received)?
+            .get_min();
         if in_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
@@ -61,7 +68,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 output_lst_mint: *output_mint,
             },
             &PriceExactInIxArgs {
-                amount: *amount,
+                amount: src_lst_received,
                 sol_value: in_sol_value,
             },
         )?;
@@ -72,7 +79,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .sol_val_calc
             .sol_to_lst(out_sol_value)?
             .get_min();
-        if dst_lst_out == 0 {
+        let dst_lst_received = output_lst_data.transfer_fee()?.amt_after_fee(dst_lst_out)?;
+        if dst_lst_received == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
         let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
@@ -85,6 +93,16 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
+        let total_dst_lst_out_sol_value = output_lst_data
+            .sol_val_calc
+            .lst_to_sol(total_dst_lst_out)?
+            .get_min();
+        verify_input_lst_max_share(
+            &pool_state,
+            input_lst_state,
+            in_sol_value,
+            total_dst_lst_out_sol_value,
+        )?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(in_sol_value).with_amt_aft_fee(out_sol_value)?,
             &output_lst_data.sol_val_calc,
@@ -94,7 +112,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             min_in_amount: None,
             min_out_amount: None,
             in_amount: *amount,
-            out_amount: dst_lst_out,
+            out_amount: dst_lst_received,
             fee_mint: *output_mint,
             fee_amount,
             fee_pct,
@@ -111,13 +129,19 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             source_token_account,
             destination_token_account,
             token_transfer_authority,
+            quote_mint_to_referrer,
             ..
         }: &SwapParams,
-    ) -> anyhow::Result<SwapByMintsFreeArgs<MintWithTokenProgram, MintWithTokenProgram, &L>> {
+    ) -> anyhow::Result<SwapByMintsFreeArgs<MintWithTokenProgram, MintWithTokenProgram, &S, &L>>
+    {
         Ok(SwapByMintsFreeArgs {
             signer: *token_transfer_authority,
             src_lst_acc: *source_token_account,
             dst_lst_acc: *destination_token_account,
+            // referral fees are paid in dst_lst
+            referrer_dst_lst_acc: quote_mint_to_referrer
+                .and_then(|referrers| referrers.get(destination_mint))
+                .copied(),
             src_lst_mint: MintWithTokenProgram {
                 pubkey: *source_mint,
                 token_program: src_token_program,
@@ -126,6 +150,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 pubkey: *destination_mint,
                 token_program: dst_token_program,
             },
+            pool_state: self
+                .pool_state_account
+                .as_ref()
+                .ok_or_else(|| anyhow!("Pool state not fetched"))?,
             lst_state_list: &self.lst_state_list_account,
         })
     }
@@ -160,8 +188,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_in_ix_by_mint_full_for_prog(
-            self.program_id,
+        Ok(swap_exact_in_ix_by_mint_full_for_pool(
+            &self.pool,
             self.swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?,
             SwapExactInAmounts {
                 // TODO: where did other_amount_threshold go?
@@ -220,10 +248,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 src_lst_calculator_program_id,
                 dst_lst_calculator_program_id,
             },
-        ) = free_args.resolve_exact_in_for_prog(self.program_id)?;
+        ) = free_args.resolve_exact_in_for_pool(&self.pool)?;
 
         let mut account_metas = vec![AccountMeta {
-            pubkey: self.program_id,
+            pubkey: self.pool.program_id,
             is_signer: false,
             is_writable: false,
         }];
@@ -5,7 +5,7 @@ use s_controller_interface::{swap_exact_out_ix, SControllerError, SwapExactOutIx
 use s_controller_lib::{
     account_metas_extend_with_pricing_program_price_swap_accounts,
     account_metas_extend_with_src_dst_sol_value_calculator_accounts, calc_swap_protocol_fees,
-    index_to_u32, swap_exact_out_ix_by_mint_full_for_prog, try_pool_state,
+    index_to_u32, swap_exact_out_ix_by_mint_full_for_pool, try_pool_state,
     CalcSwapProtocolFeesArgs, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
     SrcDstLstSolValueCalcAccounts, SrcDstLstSolValueCalcExtendCount,
     SrcDstLstSolValueCalcProgramIds, SwapExactOutAmounts, U8Bool,
@@ -18,7 +18,7 @@ use solana_sdk::instruction::{AccountMeta, Instruction};
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::{apply_sync_sol_value, calc_quote_fees, verify_input_lst_max_share};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_swap_exact_out(
@@ -41,13 +41,20 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
         if U8Bool(input_lst_state.is_input_disabled).is_true() {
             return Err(SControllerError::LstInputDisabled.into());
         }
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
+        let (pool_state, input_lst_state, _input_reserves_balance) =
             apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
         let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
+        if U8Bool(output_lst_state.is_output_disabled).is_true() {
+            return Err(SControllerError::LstOutputDisabled.into());
+        }
         let (pool_state, _output_lst_state, output_reserves_balance) =
             apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
 
-        let out_sol_value = output_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_max();
+        let dst_lst_out = output_lst_data.transfer_fee()?.amt_bef_fee(*amount)?;
+        let out_sol_value = output_lst_data
+            .sol_val_calc
+            .lst_to_sol(dst_lst_out)?
+            .get_max();
         if out_sol_value == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
@@ -57,30 +64,43 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 output_lst_mint: *output_mint,
             },
             &PriceExactOutIxArgs {
-                amount: *amount,
+                amount: dst_lst_out,
                 sol_value: out_sol_value,
             },
         )?;
         if out_sol_value > in_sol_value {
             return Err(SControllerError::PoolWouldLoseSolValue.into());
         }
-        let src_lst_in = input_lst_data
+        let src_lst_received = input_lst_data
             .sol_val_calc
             .sol_to_lst(in_sol_value)?
             .get_max();
+        let src_lst_in = input_lst_data
+            .transfer_fee()?
+            .amt_bef_fee(src_lst_received)?;
         if src_lst_in == 0 {
             return Err(SControllerError::ZeroValue.into());
         }
         let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
             in_sol_value,
             out_sol_value,
-            dst_lst_out: *amount,
+            dst_lst_out,
             trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
         })?;
-        let total_dst_lst_out = amount
+        let total_dst_lst_out = dst_lst_out
             .checked_add(to_protocol_fees_lst_amount)
             .ok_or(SControllerError::MathError)?;
         let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
+        let total_dst_lst_out_sol_value = output_lst_data
+            .sol_val_calc
+            .lst_to_sol(total_dst_lst_out)?
+            .get_min();
+        verify_input_lst_max_share(
+            &pool_state,
+            input_lst_state,
+            in_sol_value,
+            total_dst_lst_out_sol_value,
+        )?;
         let (fee_amount, fee_pct) = calc_quote_fees(
             AmtsAfterFeeBuilder::new_amt_bef_fee(in_sol_value).with_amt_aft_fee(out_sol_value)?,
             &output_lst_data.sol_val_calc,
@@ -130,8 +150,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_out_ix_by_mint_full_for_prog(
-            self.program_id,
+        Ok(swap_exact_out_ix_by_mint_full_for_pool(
+            &self.pool,
             self.swap_by_mints_free_args(*src_token_program, *dst_token_program, swap_params)?,
             SwapExactOutAmounts {
                 // TODO: where did other_amount_threshold go?
@@ -190,10 +210,10 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 src_lst_calculator_program_id,
                 dst_lst_calculator_program_id,
             },
-        ) = free_args.resolve_exact_out_for_prog(self.program_id)?;
+        ) = free_args.resolve_exact_out_for_pool(&self.pool)?;
 
         let mut account_metas = vec![AccountMeta {
-            pubkey: self.program_id,
+            pubkey: self.pool.program_id,
             is_signer: false,
             is_writable: false,
         }];
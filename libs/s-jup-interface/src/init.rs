@@ -1,5 +1,5 @@
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, try_lst_state_list, try_pool_state,
+    try_lst_state_list_versioned, try_pool_state, PoolKey, CURRENT_PROGRAM_VERS,
 };
 use sanctum_lst_list::SanctumLst;
 use solana_program::pubkey::Pubkey;
@@ -46,9 +46,13 @@ impl<S, L> SPool<S, L> {
     /// Gets the list of accounts that must be fetched first to initialize
     /// SPool by passing the result into [`Self::from_fetched_accounts`]
     pub fn init_keys(program_id: Pubkey) -> SPoolInitKeys {
+        Self::init_keys_for_pool(&PoolKey::default_for_prog(program_id))
+    }
+
+    pub fn init_keys_for_pool(pool: &PoolKey) -> SPoolInitKeys {
         SPoolInitKeys {
-            lst_state_list: find_lst_state_list_address(program_id).0,
-            pool_state: find_pool_state_address(program_id).0,
+            lst_state_list: pool.lst_state_list_pda().address,
+            pool_state: pool.pool_state,
         }
     }
 }
@@ -61,25 +65,56 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         program_id: Pubkey,
         lst_state_list_account: L,
         lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_lst_state_list_account_for_pool(
+            PoolKey::default_for_prog(program_id),
+            lst_state_list_account,
+            lst_list,
+        )
+    }
+
+    /// See [`Self::from_lst_state_list_account`].
+    /// Assumes the pool has been migrated to the current program version
+    pub fn from_lst_state_list_account_for_pool(
+        pool: PoolKey,
+        lst_state_list_account: L,
+        lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_lst_state_list_account_versioned(
+            pool,
+            CURRENT_PROGRAM_VERS,
+            lst_state_list_account,
+            lst_list,
+        )
+    }
+
+    fn from_lst_state_list_account_versioned(
+        pool: PoolKey,
+        version: u8,
+        lst_state_list_account: L,
+        lst_list: &[SanctumLst],
     ) -> anyhow::Result<Self> {
         let SPoolInitKeys {
             lst_state_list: lst_state_list_addr,
             pool_state: pool_state_addr,
-        } = Self::init_keys(program_id);
+        } = Self::init_keys_for_pool(&pool);
         let lst_data_list = {
             let lst_state_list_account_data = lst_state_list_account.data();
-            let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
+            let lst_state_list =
+                try_lst_state_list_versioned(version, &lst_state_list_account_data)?;
             lst_state_list
                 .iter()
                 .map(|lst_state| try_lst_data(lst_list, lst_state))
                 .collect()
         };
         Ok(Self {
-            program_id,
+            pool,
             lst_state_list_addr,
             pool_state_addr,
+            pool_config_addr: pool.pool_config_pda().address,
             pool_state_account: None,
             pricing_prog: None,
+            pool_config: None,
             lp_mint_supply: None,
             lst_state_list_account,
             lst_data_list,
@@ -92,20 +127,42 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     ///  - this update updates the various sol value calculator programs and pricing program
     pub fn from_init_accounts(
         program_id: Pubkey,
+        init_accounts: SPoolInitAccounts<S, L>,
+        lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_init_accounts_for_pool(
+            PoolKey::default_for_prog(program_id),
+            init_accounts,
+            lst_list,
+        )
+    }
+
+    /// See [`Self::from_init_accounts`]
+    pub fn from_init_accounts_for_pool(
+        pool: PoolKey,
         SPoolInitAccounts {
             lst_state_list: lst_state_list_acc,
             pool_state: pool_state_acc,
         }: SPoolInitAccounts<S, L>,
         lst_list: &[SanctumLst],
     ) -> anyhow::Result<Self> {
-        let pricing_prog = {
-            let lst_state_list_acc_data = lst_state_list_acc.data();
-            let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let (version, pricing_prog) = {
             let pool_state_acc_data = pool_state_acc.data();
             let pool_state = try_pool_state(&pool_state_acc_data)?;
-            try_pricing_prog(pool_state, lst_state_list)?
+            let lst_state_list_acc_data = lst_state_list_acc.data();
+            let lst_state_list =
+                try_lst_state_list_versioned(pool_state.version, &lst_state_list_acc_data)?;
+            (
+                pool_state.version,
+                try_pricing_prog(pool_state, &lst_state_list)?,
+            )
         };
-        let mut res = Self::from_lst_state_list_account(program_id, lst_state_list_acc, lst_list)?;
+        let mut res = Self::from_lst_state_list_account_versioned(
+            pool,
+            version,
+            lst_state_list_acc,
+            lst_list,
+        )?;
         res.pool_state_account = Some(pool_state_acc);
         res.pricing_prog = Some(pricing_prog);
         Ok(res)
@@ -2,7 +2,7 @@ use anyhow::anyhow;
 use jupiter_amm_interface::{
     AccountMap, Amm, KeyedAccount, Quote, QuoteParams, SwapAndAccountMetas, SwapParams,
 };
-use s_controller_lib::find_lst_state_list_address;
+use s_controller_lib::PoolKey;
 use sanctum_lst_list::{
     lido_program, marinade_program, sanctum_spl_multi_stake_pool_program,
     sanctum_spl_stake_pool_program, spl_stake_pool_program, SanctumLstList,
@@ -17,7 +17,11 @@ pub const LABEL: &str = "Sanctum Infinity";
 impl Amm for SPoolJup {
     /// Initialized by lst_state_list account, NOT pool_state.
     ///
-    /// params can optionally be a b58-encoded pubkey string that is the S controller program's program_id.
+    /// params can optionally be either:
+    /// - a b58-encoded pubkey string that is the S controller program's program_id, for its default pool
+    /// - an object `{ "program_id"?: string, "lp_token_mint"?: string }` of b58-encoded pubkeys.
+    ///   `lp_token_mint` selects the additional pool with that LP token mint,
+    ///   the default pool is used if omitted.
     ///
     /// Must be updated 2 more times before it can be used, see docs for [`Self::from_lst_state_list_account`].
     ///
@@ -32,26 +36,19 @@ impl Amm for SPoolJup {
     where
         Self: Sized,
     {
-        let (program_id, lst_state_list_addr) = match params {
+        let pool = match params {
             // default to INF if program_id params not provided
-            None => (
-                s_controller_lib::program::ID,
-                s_controller_lib::program::LST_STATE_LIST_ID,
-            ),
-            Some(value) => {
-                // TODO: maybe unnecessary clone() here?
-                let program_id =
-                    Pubkey::from_str(&serde_json::from_value::<String>(value.clone())?)?;
-                (program_id, find_lst_state_list_address(program_id).0)
-            }
+            None => PoolKey::DEFAULT,
+            Some(value) => pool_from_params(value)?,
         };
+        let lst_state_list_addr = pool.lst_state_list_pda().address;
         if *key != lst_state_list_addr {
             return Err(anyhow!(
                 "Incorrect LST state list addr. Expected {lst_state_list_addr}. Got {key}"
             ));
         }
         let SanctumLstList { sanctum_lst_list } = SanctumLstList::load();
-        Self::from_lst_state_list_account(program_id, account.clone(), &sanctum_lst_list)
+        Self::from_lst_state_list_account_for_pool(pool, account.clone(), &sanctum_lst_list)
     }
 
     fn label(&self) -> String {
@@ -59,12 +56,17 @@ impl Amm for SPoolJup {
     }
 
     fn program_id(&self) -> Pubkey {
-        self.program_id
+        self.pool.program_id
     }
 
-    /// S Pools are 1 per program, so just use program ID as key
+    /// The default pool uses the program ID as key for backward compatibility,
+    /// additional pools use their pool state's address
     fn key(&self) -> Pubkey {
-        self.program_id()
+        if self.pool.is_default() {
+            self.program_id()
+        } else {
+            self.pool.pool_state
+        }
     }
 
     fn get_reserve_mints(&self) -> Vec<Pubkey> {
@@ -154,3 +156,23 @@ impl Amm for SPoolJup {
         ]
     }
 }
+
+fn pool_from_params(value: &serde_json::Value) -> anyhow::Result<PoolKey> {
+    let parse_pubkey = |v: &serde_json::Value| -> anyhow::Result<Pubkey> {
+        let s = v
+            .as_str()
+            .ok_or_else(|| anyhow!("Expected b58-encoded pubkey string. Got {v}"))?;
+        Ok(Pubkey::from_str(s)?)
+    };
+    if value.is_string() {
+        return Ok(PoolKey::default_for_prog(parse_pubkey(value)?));
+    }
+    let program_id = match value.get("program_id") {
+        Some(v) => parse_pubkey(v)?,
+        None => s_controller_lib::program::ID,
+    };
+    Ok(match value.get("lp_token_mint") {
+        Some(v) => PoolKey::new_for_prog(program_id, parse_pubkey(v)?),
+        None => PoolKey::default_for_prog(program_id),
+    })
+}
@@ -1,8 +1,10 @@
 use anyhow::anyhow;
-use s_controller_interface::LstState;
-use s_controller_lib::{try_lst_state_list, try_pool_state};
+use s_controller_interface::{LstState, PoolConfig};
+use s_controller_lib::{
+    try_lst_state_list_versioned, try_pool_state, LstTransferFee, PoolKey, CURRENT_PROGRAM_VERS,
+};
 use s_pricing_prog_aggregate::KnownPricingProg;
-use s_sol_val_calc_prog_aggregate::KnownLstSolValCalc;
+use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc};
 use sanctum_associated_token_lib::{CreateAtaAddressArgs, FindAtaAddressArgs};
 use solana_program::pubkey::{Pubkey, PubkeyError};
 use solana_readonly_account::ReadonlyAccountData;
@@ -25,6 +27,16 @@ pub struct LstData {
     pub sol_val_calc: KnownLstSolValCalc,
     pub reserves_balance: Option<u64>,
     pub token_program: Pubkey,
+    // None if the token-2022 mint and clock have not been fetched yet.
+    // Tokenkeg mints charge no transfer fees so this is always the default for them
+    pub transfer_fee: Option<LstTransferFee>,
+}
+
+impl LstData {
+    pub fn transfer_fee(&self) -> anyhow::Result<LstTransferFee> {
+        self.transfer_fee
+            .ok_or_else(|| anyhow!("LST {} mint not fetched", self.sol_val_calc.lst_mint()))
+    }
 }
 
 /// Convenience type alias for jupiter
@@ -32,14 +44,17 @@ pub type SPoolJup = SPool<Account, Account>;
 
 #[derive(Debug, Clone)]
 pub struct SPool<S, L> {
-    pub program_id: Pubkey,
+    pub pool: PoolKey,
     pub lst_state_list_addr: Pubkey,
     pub pool_state_addr: Pubkey,
+    pub pool_config_addr: Pubkey,
     pub lp_mint_supply: Option<u64>,
     // pool_state optional since lst_state_list is the KeyedAccount we initialize with
     pub pool_state_account: Option<S>,
     pub lst_state_list_account: L,
     pub pricing_prog: Option<KnownPricingProg>,
+    // None if not fetched yet. Default if fetched but the PDA has not been created yet
+    pub pool_config: Option<PoolConfig>,
     // indices match that of lst_state_list.
     // None means we don't know how to handle the given lst
     // this could be due to incomplete data or unknown LST sol value calculator program
@@ -49,12 +64,14 @@ pub struct SPool<S, L> {
 impl<S, L: Default> Default for SPool<S, L> {
     fn default() -> Self {
         Self {
-            program_id: s_controller_lib::program::ID,
+            pool: PoolKey::DEFAULT,
             lst_state_list_addr: s_controller_lib::program::LST_STATE_LIST_ID,
             pool_state_addr: s_controller_lib::program::POOL_STATE_ID,
+            pool_config_addr: s_controller_lib::program::POOL_CONFIG_ID,
             lp_mint_supply: None,
             pool_state_account: None,
             pricing_prog: None,
+            pool_config: None,
             lst_state_list_account: L::default(),
             lst_data_list: Vec::new(),
         }
@@ -70,6 +87,12 @@ impl<S, L> SPool<S, L> {
             .ok_or_else(|| anyhow!("pricing program not fetched"))
     }
 
+    pub fn pool_config(&self) -> anyhow::Result<&PoolConfig> {
+        self.pool_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("pool config not fetched"))
+    }
+
     pub fn pool_reserves_account(
         &self,
         LstState {
@@ -106,12 +129,22 @@ impl<S: ReadonlyAccountData, L> SPool<S, L> {
         let pool_state_acc_data = self.pool_state_data()?;
         Ok(try_pool_state(&pool_state_acc_data).map(|ps| ps.lp_token_mint)?)
     }
+
+    /// The pool's program version, which determines the layout of lst_state_list.
+    /// Assumes the current version if pool_state has not been fetched yet
+    pub fn pool_version(&self) -> u8 {
+        self.pool_state_data()
+            .ok()
+            .and_then(|d| try_pool_state(&d).ok().map(|ps| ps.version))
+            .unwrap_or(CURRENT_PROGRAM_VERS)
+    }
 }
 
-impl<S, L: ReadonlyAccountData> SPool<S, L> {
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub fn find_ready_lst(&self, lst_mint: Pubkey) -> anyhow::Result<(LstState, &LstData)> {
         let lst_state_list_account_data = self.lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
+        let lst_state_list =
+            try_lst_state_list_versioned(self.pool_version(), &lst_state_list_account_data)?;
         let (lst_state, lst_data) = lst_state_list
             .iter()
             .zip(self.lst_data_list.iter())
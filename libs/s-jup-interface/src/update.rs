@@ -1,12 +1,17 @@
 use std::collections::HashMap;
 
 use s_controller_interface::LstState;
-use s_controller_lib::{try_lst_state_list, try_pool_state};
+use s_controller_lib::{
+    try_lst_state_list_versioned, try_pool_config, try_pool_state, LstTransferFee,
+};
 use s_pricing_prog_aggregate::MutablePricingProg;
 use s_sol_val_calc_prog_aggregate::{LstSolValCalc, MutableLstSolValCalc};
 use sanctum_token_lib::{mint_supply, token_account_balance};
 use solana_readonly_account::ReadonlyAccountData;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{
+    pubkey::Pubkey,
+    sysvar::{self, clock::Clock},
+};
 
 use crate::{utils::try_pricing_prog, LstData, SPool};
 
@@ -39,6 +44,7 @@ impl<D: ReadonlyAccountData + Clone> SPool<D, D> {
         // finally, update LP token supply using the newest pool state
         self.update_lst_data_list(account_map)
             .and(self.update_pricing_prog(account_map))
+            .and(self.update_pool_config(account_map))
             .and(self.update_lst_state_list(account_map))
             .and(self.update_pool_state(account_map))
             .and(self.update_lp_token_supply(account_map))
@@ -46,8 +52,32 @@ impl<D: ReadonlyAccountData + Clone> SPool<D, D> {
 }
 
 impl<S, L> SPool<S, L> {
-    pub fn get_accounts_to_update_base(&self) -> [Pubkey; 2] {
-        [self.lst_state_list_addr, self.pool_state_addr]
+    pub fn get_accounts_to_update_base(&self) -> [Pubkey; 3] {
+        [
+            self.lst_state_list_addr,
+            self.pool_state_addr,
+            self.pool_config_addr,
+        ]
+    }
+
+    /// The PoolConfig PDA might not have been created yet, in which case
+    /// it will be missing from `account_map`. It is treated as the default PoolConfig
+    /// if pool_state was fetched in the same update.
+    pub fn update_pool_config<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        let new_pool_config = match account_map.get(&self.pool_config_addr) {
+            Some(acc) => try_pool_config(&acc.data())?,
+            None => {
+                if !account_map.contains_key(&self.pool_state_addr) {
+                    return Ok(());
+                }
+                try_pool_config(&[])?
+            }
+        };
+        self.pool_config = Some(new_pool_config);
+        Ok(())
     }
 
     pub fn get_accounts_to_update_pricing_prog(&self) -> Vec<Pubkey> {
@@ -82,7 +112,7 @@ impl<S, L> SPool<S, L> {
     }
 }
 
-impl<S, L: ReadonlyAccountData> SPool<S, L> {
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     fn lst_accounts_to_update(
         &self,
         lst_state: &LstState,
@@ -96,15 +126,20 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         if let Ok(ata) = self.pool_reserves_account(lst_state, lst_data) {
             res.push(ata);
         }
+        // token-2022 mints may charge transfer fees, which depend on the epoch
+        if lst_data.token_program != spl_token::ID {
+            res.extend([lst_state.mint, sysvar::clock::ID]);
+        }
         res
     }
 
     pub fn get_accounts_to_update_lsts_all(&self) -> Vec<Pubkey> {
         let lst_state_list_data = self.lst_state_list_account.data();
-        let lst_state_list = match try_lst_state_list(&lst_state_list_data) {
-            Ok(l) => l,
-            Err(_) => return vec![],
-        };
+        let lst_state_list =
+            match try_lst_state_list_versioned(self.pool_version(), &lst_state_list_data) {
+                Ok(l) => l,
+                Err(_) => return vec![],
+            };
         lst_state_list
             .iter()
             .zip(self.lst_data_list.iter())
@@ -118,10 +153,11 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         mut filter_pred: F,
     ) -> Vec<Pubkey> {
         let lst_state_list_data = self.lst_state_list_account.data();
-        let lst_state_list = match try_lst_state_list(&lst_state_list_data) {
-            Ok(l) => l,
-            Err(_) => return vec![],
-        };
+        let lst_state_list =
+            match try_lst_state_list_versioned(self.pool_version(), &lst_state_list_data) {
+                Ok(l) => l,
+                Err(_) => return vec![],
+            };
         lst_state_list
             .iter()
             .zip(self.lst_data_list.iter())
@@ -150,8 +186,10 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
                     None => return Ok(()),
                 };
                 let lst_state_list_acc_data = self.lst_state_list_account.data();
-                let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+                let lst_state_list =
+                    try_lst_state_list_versioned(self.pool_version(), &lst_state_list_acc_data)?;
                 let ata_res = self.pool_reserves_account(&lst_state_list[i], ld);
+                let lst_mint = lst_state_list[i].mint;
                 let ld = match &mut self.lst_data_list[i] {
                     Some(l) => l,
                     None => return Ok(()),
@@ -166,11 +204,35 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
                         Ok(())
                     },
                 ))
+                .and(update_transfer_fee(ld, lst_mint, account_map))
             })
             .fold(Ok(()), |res, curr_res| res.and(curr_res))
     }
 }
-impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
+
+/// Only updated if both the mint and the clock were fetched
+/// since transfer fees are set per epoch
+fn update_transfer_fee<D: ReadonlyAccountData>(
+    lst_data: &mut LstData,
+    lst_mint: Pubkey,
+    account_map: &HashMap<Pubkey, D>,
+) -> anyhow::Result<()> {
+    let (mint_acc, clock_acc) = match (
+        account_map.get(&lst_mint),
+        account_map.get(&sysvar::clock::ID),
+    ) {
+        (Some(m), Some(c)) => (m, c),
+        _ => return Ok(()),
+    };
+    let clock: Clock = bincode::deserialize(&clock_acc.data())?;
+    lst_data.transfer_fee = Some(LstTransferFee::try_from_mint_data(
+        &mint_acc.data(),
+        clock.epoch,
+    )?);
+    Ok(())
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData + Clone> SPool<S, L> {
     pub fn update_lst_state_list(
         &mut self,
         account_map: &HashMap<Pubkey, L>,
@@ -185,10 +247,19 @@ impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
         //   SanctumLstList to reinitialize the KnownLstSolValCalc
         // - if list was extended, the new entries will just be None and we cant handle it. Otherwise we would need a
         //   SanctumLstList to initialize the KnownLstSolValCalc
+        //
+        // The new lst_state_list is read according to the version of the new pool_state if it was fetched
+        // alongside so that a pool being migrated is diffed correctly
+        let version = self.pool_version();
+        let new_version = account_map
+            .get(&self.pool_state_addr)
+            .and_then(|acc| try_pool_state(&acc.data()).ok().map(|ps| ps.version))
+            .unwrap_or(version);
         let lst_state_list_acc_data = self.lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let lst_state_list = try_lst_state_list_versioned(version, &lst_state_list_acc_data)?;
         let new_lst_state_list_account_data = new_lst_state_list_account.data();
-        let new_lst_state_list = try_lst_state_list(&new_lst_state_list_account_data)?;
+        let new_lst_state_list =
+            try_lst_state_list_versioned(new_version, &new_lst_state_list_account_data)?;
         if lst_state_list.len() == new_lst_state_list.len()
             && lst_state_list.iter().zip(new_lst_state_list.iter()).all(
                 |(old_lst_state, new_lst_state)| {
@@ -284,32 +355,31 @@ impl<S: ReadonlyAccountData + Clone, L: ReadonlyAccountData> SPool<S, L> {
         let old_pool_state = self
             .pool_state_data()
             .map_or_else(Err, |d| Ok(*try_pool_state(&d)?));
+        let new_pool_state_acc_data = new_pool_state_acc.data();
+        let new_pool_state = try_pool_state(&new_pool_state_acc_data)?;
         let lst_state_list_acc_data = self.lst_state_list_account.data();
-        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
-        try_pool_state(&new_pool_state_acc.data()).map_or_else(
-            |e| Err(e.into()),
-            |new_pool_state| {
-                let mut r = Ok(());
-                // reinitialize pricing program if changed
-                let should_reinitialize_pricing_program = self.pricing_prog.is_none()
-                    || old_pool_state.map_or_else(
-                        |_err| false,
-                        |old_ps| old_ps.pricing_program != new_pool_state.pricing_program,
-                    );
-                if should_reinitialize_pricing_program {
-                    // None if unable to initialize new_pricing_prog, with error captured
-                    // for return later
-                    let new_pricing_prog = try_pricing_prog(new_pool_state, lst_state_list)
-                        .map(|mut pp| {
-                            r = pp.update(account_map);
-                            pp
-                        })
-                        .ok();
-                    self.pricing_prog = new_pricing_prog;
-                }
-                self.pool_state_account = Some(new_pool_state_acc.clone());
-                r
-            },
-        )
+        // lst_state_list was updated before pool_state, so it is in the new pool_state's layout
+        let lst_state_list =
+            try_lst_state_list_versioned(new_pool_state.version, &lst_state_list_acc_data)?;
+        let mut r = Ok(());
+        // reinitialize pricing program if changed
+        let should_reinitialize_pricing_program = self.pricing_prog.is_none()
+            || old_pool_state.map_or_else(
+                |_err| false,
+                |old_ps| old_ps.pricing_program != new_pool_state.pricing_program,
+            );
+        if should_reinitialize_pricing_program {
+            // None if unable to initialize new_pricing_prog, with error captured
+            // for return later
+            let new_pricing_prog = try_pricing_prog(new_pool_state, &lst_state_list)
+                .map(|mut pp| {
+                    r = pp.update(account_map);
+                    pp
+                })
+                .ok();
+            self.pricing_prog = new_pricing_prog;
+        }
+        self.pool_state_account = Some(new_pool_state_acc.clone());
+        r
     }
 }
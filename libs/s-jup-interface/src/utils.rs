@@ -1,4 +1,5 @@
 use s_controller_interface::{LstState, PoolState};
+use s_controller_lib::LstTransferFee;
 use s_pricing_prog_aggregate::{KnownPricingProg, MutablePricingProg};
 use s_sol_val_calc_prog_aggregate::{
     KnownLstSolValCalc, LidoLstSolValCalc, LstSolValCalc, MarinadeLstSolValCalc,
@@ -65,6 +66,11 @@ pub fn try_lst_data(
             sol_val_calc: calc,
             reserves_balance: None,
             token_program: *token_program,
+            transfer_fee: if *token_program == spl_token::ID {
+                Some(LstTransferFee::default())
+            } else {
+                None
+            },
         })
     }
 }
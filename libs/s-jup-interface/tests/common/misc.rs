@@ -1,5 +1,6 @@
+use s_controller_test_utils::mock_lst_token_account;
 use sanctum_associated_token_lib::FindAtaAddressArgs;
-use sanctum_solana_test_utils::token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs};
+use sanctum_solana_test_utils::{token::MockTokenAccountArgs, ExtendedProgramTest};
 use sanctum_token_lib::MintWithTokenProgram;
 use solana_program_test::{processor, ProgramTest};
 use solana_sdk::pubkey::Pubkey;
@@ -29,14 +30,16 @@ impl MiscProgramTest for ProgramTest {
         }
         .find_ata_address()
         .0;
-        // TODO: handle token-22, no LSTs use that yet
-        self.add_tokenkeg_account_from_args(
+        self.add_account_chained(
             ata_addr,
-            MockTokenAccountArgs {
-                mint,
-                authority: wallet,
-                amount,
-            },
+            mock_lst_token_account(
+                token_program,
+                MockTokenAccountArgs {
+                    mint,
+                    authority: wallet,
+                    amount,
+                },
+            ),
         )
     }
 
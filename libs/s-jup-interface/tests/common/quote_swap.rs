@@ -1,4 +1,4 @@
-use jupiter_amm_interface::{Amm, Quote, QuoteParams, SwapParams};
+use jupiter_amm_interface::{Amm, Quote, QuoteParams, SwapAndAccountMetas, SwapMode, SwapParams};
 use s_jup_interface::SPoolJup;
 use sanctum_associated_token_lib::FindAtaAddressArgs;
 use sanctum_solana_test_utils::ExtendedBanksClient;
@@ -43,24 +43,34 @@ pub async fn assert_quote_swap_eq(
         token_account_balance(bc.get_account_unwrapped(destination_token_account).await).unwrap();
 
     assert!(!not_enough_liquidity);
-    let ix = s
-        .swap_ix(
-            &SwapParams {
-                in_amount,
-                out_amount,
-                source_mint: quote.input_mint,
-                destination_mint: quote.output_mint,
-                source_token_account,
-                destination_token_account,
-                token_transfer_authority: ata_wallet.pubkey(),
-                open_order_address: None,
-                quote_mint_to_referrer: None,
-                jupiter_program_id: &Pubkey::default(),
-                missing_dynamic_accounts_as_default: false,
+    let swap_params = SwapParams {
+        in_amount,
+        out_amount,
+        source_mint: quote.input_mint,
+        destination_mint: quote.output_mint,
+        source_token_account,
+        destination_token_account,
+        token_transfer_authority: ata_wallet.pubkey(),
+        open_order_address: None,
+        quote_mint_to_referrer: None,
+        jupiter_program_id: &Pubkey::default(),
+        missing_dynamic_accounts_as_default: false,
+    };
+    let ix = s.swap_ix(&swap_params, quote.swap_mode).unwrap();
+
+    // account metas passed to the jup program must follow the instruction's account layout
+    let SwapAndAccountMetas { account_metas, .. } = s
+        .get_swap_and_account_metas(&SwapParams {
+            // get_swap_and_account_metas() treats in_amount == 0 as ExactOut
+            in_amount: match quote.swap_mode {
+                SwapMode::ExactIn => in_amount,
+                SwapMode::ExactOut => 0,
             },
-            quote.swap_mode,
-        )
+            ..swap_params
+        })
         .unwrap();
+    assert_eq!(account_metas[0].pubkey, s.program_id());
+    assert_eq!(account_metas[1..], ix.accounts);
     let mut tx = Transaction::new_with_payer(&[ix], Some(&ata_wallet.pubkey()));
     let last_blockhash = bc.get_latest_blockhash().await.unwrap();
     tx.sign(&[ata_wallet], last_blockhash);
@@ -1,10 +1,12 @@
 use async_trait::async_trait;
 use jupiter_amm_interface::{Amm, KeyedAccount};
 use s_jup_interface::{SPoolInitKeys, SPoolJup};
+use sanctum_lst_list::SanctumLstList;
 use sanctum_solana_test_utils::ExtendedBanksClient;
 use solana_program_test::BanksClient;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::{HashMap, HashSet};
+use test_utils::jitosol;
 
 pub async fn fully_init_amm(bc: &mut BanksClient, program_id: Pubkey) -> SPoolJup {
     let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
@@ -19,6 +21,27 @@ pub async fn fully_init_amm(bc: &mut BanksClient, program_id: Pubkey) -> SPoolJu
     .await
 }
 
+/// Same as [`fully_init_amm`], but for program tests whose jitoSOL mint
+/// has been replaced by a token-2022 mint with transfer fees
+pub async fn fully_init_amm_token_2022_jitosol(
+    bc: &mut BanksClient,
+    program_id: Pubkey,
+) -> SPoolJup {
+    let SPoolInitKeys { lst_state_list, .. } = SPoolJup::init_keys(program_id);
+    let lst_state_list_acc = bc.get_account_unwrapped(lst_state_list).await;
+    let SanctumLstList {
+        mut sanctum_lst_list,
+    } = SanctumLstList::load();
+    sanctum_lst_list
+        .iter_mut()
+        .filter(|lst| lst.mint == jitosol::ID)
+        .for_each(|lst| lst.token_program = spl_token_2022::ID);
+    SPoolJup::from_lst_state_list_account(program_id, lst_state_list_acc, &sanctum_lst_list)
+        .unwrap()
+        .initial_update(bc)
+        .await
+}
+
 #[async_trait]
 pub trait UpdatingSPoolJup {
     async fn update_with_banks(self, bc: &mut BanksClient) -> Self;
@@ -38,8 +61,11 @@ impl UpdatingSPoolJup for SPoolJup {
                     hs
                 });
         let mut fetched = HashMap::new();
+        // accounts that dont exist yet, such as the PoolConfig PDA, are omitted
         for pk in accounts {
-            fetched.insert(pk, bc.get_account_unwrapped(pk).await);
+            if let Some(acc) = bc.get_account(pk).await.unwrap() {
+                fetched.insert(pk, acc);
+            }
         }
         self.update(&fetched).unwrap();
         self
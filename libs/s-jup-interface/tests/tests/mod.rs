@@ -3,5 +3,6 @@
 
 mod add_liquidity;
 mod remove_liquidity;
+mod remove_liquidity_proportional;
 mod swap_exact_in;
 mod swap_exact_out;
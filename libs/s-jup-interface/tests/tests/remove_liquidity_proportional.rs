@@ -0,0 +1,139 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use marinade_keys::msol;
+use s_controller_interface::PoolConfig;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+    PoolConfigProgramTest,
+};
+use s_jup_interface::{RemoveLiquidityProportionalLstQuote, RemoveLiquidityProportionalParams};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_solana_test_utils::{ExtendedBanksClient, ExtendedProgramTest};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+#[tokio::test]
+async fn remove_liquidity_proportional_jito_marinade_basic() {
+    const AMT: u64 = 1_000_000_000;
+    // 1_000_000_000 pro-rata share - 10 bps fee
+    const EXPECTED_OUT_AMOUNT: u64 = 999_000_000;
+    // 10% of the 1_000_000 fee
+    const EXPECTED_PROTOCOL_FEES: u64 = 100_000;
+
+    let wallet = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let lst_mints = [jitosol::ID, msol::ID];
+    let mut pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+        },
+        lst_mints.map(|lst_mint| MockFeeAccountArgs {
+            input_fee_bps: 4,
+            output_fee_bps: 4,
+            lst_mint,
+        }),
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 10,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 0,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: 0,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: 0,
+    })
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: lp_token_mint,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    for lst_mint in lst_mints {
+        pt = pt.add_ata(
+            wallet.pubkey(),
+            MintWithTokenProgram {
+                pubkey: lst_mint,
+                token_program: spl_token::ID,
+            },
+            0,
+        );
+    }
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let quotes = s.quote_remove_liquidity_proportional(AMT).unwrap();
+    assert_eq!(
+        quotes,
+        lst_mints.map(|lst_mint| RemoveLiquidityProportionalLstQuote {
+            lst_mint,
+            out_amount: EXPECTED_OUT_AMOUNT,
+            protocol_fees: EXPECTED_PROTOCOL_FEES,
+        })
+    );
+
+    let [src_lp_acc, jitosol_ata, msol_ata] = [lp_token_mint, jitosol::ID, msol::ID].map(|mint| {
+        FindAtaAddressArgs {
+            wallet: wallet.pubkey(),
+            mint,
+            token_program: spl_token::ID,
+        }
+        .find_ata_address()
+        .0
+    });
+    let dst_lst_accs = [jitosol_ata, msol_ata];
+    let min_lst_outs: Vec<u64> = quotes.iter().map(|q| q.out_amount).collect();
+    let ix = s
+        .remove_liquidity_proportional_ix(RemoveLiquidityProportionalParams {
+            signer: wallet.pubkey(),
+            src_lp_acc,
+            lp_token_amount: AMT,
+            dst_lst_accs: &dst_lst_accs,
+            min_lst_outs: &min_lst_outs,
+        })
+        .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&wallet.pubkey()));
+    let last_blockhash = bc.get_latest_blockhash().await.unwrap();
+    tx.sign(&[&wallet], last_blockhash);
+    bc.process_transaction(tx).await.unwrap();
+
+    for dst_lst_acc in dst_lst_accs {
+        let balance = token_account_balance(bc.get_account_unwrapped(dst_lst_acc).await).unwrap();
+        assert_eq!(balance, EXPECTED_OUT_AMOUNT);
+    }
+    let lp_balance = token_account_balance(bc.get_account_unwrapped(src_lp_acc).await).unwrap();
+    assert_eq!(lp_balance, 0);
+}
@@ -2,15 +2,20 @@ use flat_fee_test_utils::MockFeeAccountArgs;
 use jupiter_amm_interface::{QuoteParams, SwapMode};
 use marinade_keys::msol;
 use s_controller_test_utils::{
-    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+    jito_marinade_flat_fee_program_test, jito_marinade_transfer_fee_flat_fee_program_test,
+    JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
 };
 use sanctum_solana_test_utils::ExtendedProgramTest;
 use sanctum_token_lib::MintWithTokenProgram;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use spl_token_2022::extension::transfer_fee::TransferFee;
 use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::{assert_quote_swap_eq, fully_init_amm, MiscProgramTest};
+use crate::{
+    assert_quote_swap_eq, fully_init_amm, fully_init_amm_token_2022_jitosol, MiscProgramTest,
+    UpdatingSPoolJup,
+};
 
 #[tokio::test]
 async fn swap_exact_in_jito_marinade_basic() {
@@ -92,3 +97,92 @@ async fn swap_exact_in_jito_marinade_basic() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn swap_exact_in_jito_marinade_transfer_fee() {
+    const AMT: u64 = 1_000_000_000;
+
+    let wallet = Keypair::new();
+    let pt = jito_marinade_transfer_fee_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+        TransferFee {
+            epoch: 0u64.into(),
+            maximum_fee: u64::MAX.into(),
+            transfer_fee_basis_points: 100u16.into(),
+        },
+    )
+    .add_s_program()
+    .add_system_account(wallet.pubkey(), 1_000_000_000)
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token_2022::ID,
+        },
+        AMT,
+    )
+    .add_ata(
+        wallet.pubkey(),
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        AMT,
+    );
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let mut s = fully_init_amm_token_2022_jitosol(&mut bc, s_controller_lib::program::ID).await;
+    // transfer fee charged on the way in, then on the way out
+    for (input_mint, output_mint) in [(jitosol::ID, msol::ID), (msol::ID, jitosol::ID)] {
+        assert_quote_swap_eq(
+            &mut bc,
+            &s,
+            &wallet,
+            &QuoteParams {
+                amount: AMT,
+                input_mint,
+                output_mint,
+                swap_mode: SwapMode::ExactIn,
+            },
+        )
+        .await;
+        s = s.update_with_banks(&mut bc).await;
+    }
+}
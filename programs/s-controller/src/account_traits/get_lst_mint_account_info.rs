@@ -1,5 +1,8 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, ConvertProtocolFeesToLpAccounts, DepositStakeAccounts,
+    EndRebalanceAccounts, FlashRepayAccounts, QuoteAddLiquidityAccounts,
+    QuoteRemoveLiquidityAccounts, RemoveLiquidityAccounts, RemoveLiquidityToStakeAccounts,
+    SettleRebalanceLegAccounts, SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -25,14 +28,56 @@ impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for EndRebalanceAccounts<'me,
     }
 }
 
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for SettleRebalanceLegAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.dst_lst_mint
+    }
+}
+
 impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for AddLiquidityAccounts<'me, 'info> {
     fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_mint
     }
 }
 
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for DepositStakeAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for ConvertProtocolFeesToLpAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
 impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_mint
     }
 }
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for RemoveLiquidityToStakeAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for QuoteAddLiquidityAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for QuoteRemoveLiquidityAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for FlashRepayAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
@@ -1,7 +1,10 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
-    SwapExactOutAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddRebalanceLegAccounts, ConvertProtocolFeesToLpAccounts,
+    DepositStakeAccounts, EndRebalanceAccounts, ExecuteSetSolValueCalculatorAccounts,
+    FlashRepayAccounts, QuoteAddLiquidityAccounts, QuoteRemoveLiquidityAccounts,
+    QuoteSwapExactInAccounts, QuoteSwapExactOutAccounts, RemoveLiquidityAccounts,
+    RemoveLiquidityToStakeAccounts, SetSolValueCalculatorAccounts, SettleRebalanceLegAccounts,
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts, SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -31,24 +34,58 @@ impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for StartRebalanceAccoun
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for AddRebalanceLegAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for EndRebalanceAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for SettleRebalanceLegAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for AddLiquidityAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for DepositStakeAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for ConvertProtocolFeesToLpAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for RemoveLiquidityToStakeAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for SwapExactInAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
@@ -69,6 +106,46 @@ impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for ExecuteSetSolValueCalculatorAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for QuoteSwapExactInAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for QuoteSwapExactOutAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for QuoteAddLiquidityAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for QuoteRemoveLiquidityAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for FlashRepayAccounts<'me, 'info> {
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 // impls for src_dst wrapper newtypes
 
 impl<'me, 'info, A: GetLstStateListAccountInfo<'me, 'info>> GetLstStateListAccountInfo<'me, 'info>
@@ -1,6 +1,9 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, ConvertProtocolFeesToLpAccounts, DepositStakeAccounts,
+    EndRebalanceAccounts, ExecuteSetSolValueCalculatorAccounts, FlashRepayAccounts,
+    QuoteAddLiquidityAccounts, QuoteRemoveLiquidityAccounts, RemoveLiquidityAccounts,
+    RemoveLiquidityToStakeAccounts, SetSolValueCalculatorAccounts, SettleRebalanceLegAccounts,
+    SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -30,18 +33,46 @@ impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for EndRebalanceAccounts
     }
 }
 
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for SettleRebalanceLegAccounts<'me, 'info> {
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.dst_pool_reserves
+    }
+}
+
 impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for AddLiquidityAccounts<'me, 'info> {
     fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_reserves
     }
 }
 
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for DepositStakeAccounts<'me, 'info> {
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for ConvertProtocolFeesToLpAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
 impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_reserves
     }
 }
 
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for RemoveLiquidityToStakeAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
 impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
     for SetSolValueCalculatorAccounts<'me, 'info>
 {
@@ -50,6 +81,34 @@ impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
     }
 }
 
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for ExecuteSetSolValueCalculatorAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for QuoteAddLiquidityAccounts<'me, 'info> {
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for QuoteRemoveLiquidityAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for FlashRepayAccounts<'me, 'info> {
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
 // SrcLstPoolReservesOf + DstLstPoolReservesOf
 
 impl<'me, 'info, A> GetPoolReservesAccountInfo<'me, 'info> for SrcLstPoolReservesOf<A>
@@ -1,7 +1,10 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
-    SwapExactOutAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddRebalanceLegAccounts, ConvertProtocolFeesToLpAccounts,
+    DepositStakeAccounts, EndRebalanceAccounts, ExecuteSetSolValueCalculatorAccounts,
+    FlashRepayAccounts, QuoteAddLiquidityAccounts, QuoteRemoveLiquidityAccounts,
+    QuoteSwapExactInAccounts, QuoteSwapExactOutAccounts, RemoveLiquidityAccounts,
+    RemoveLiquidityToStakeAccounts, SetSolValueCalculatorAccounts, SettleRebalanceLegAccounts,
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts, SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -31,24 +34,58 @@ impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for StartRebalanceAccounts<
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for AddRebalanceLegAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for EndRebalanceAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for SettleRebalanceLegAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for AddLiquidityAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for DepositStakeAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info>
+    for ConvertProtocolFeesToLpAccounts<'me, 'info>
+{
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info>
+    for RemoveLiquidityToStakeAccounts<'me, 'info>
+{
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for SwapExactInAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
@@ -67,6 +104,44 @@ impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for SetSolValueCalculatorAc
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info>
+    for ExecuteSetSolValueCalculatorAccounts<'me, 'info>
+{
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for QuoteSwapExactInAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for QuoteSwapExactOutAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for QuoteAddLiquidityAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for QuoteRemoveLiquidityAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for FlashRepayAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 // impls for src_dst wrapper newtypes
 
 impl<'me, 'info, A: GetPoolStateAccountInfo<'me, 'info>> GetPoolStateAccountInfo<'me, 'info>
@@ -1,4 +1,7 @@
-use s_controller_interface::{StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts};
+use s_controller_interface::{
+    AddRebalanceLegAccounts, QuoteSwapExactInAccounts, QuoteSwapExactOutAccounts,
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts,
+};
 use solana_program::account_info::AccountInfo;
 
 #[derive(Clone, Copy, Debug)]
@@ -41,6 +44,16 @@ impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for StartRebalanceAccou
     }
 }
 
+impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for AddRebalanceLegAccounts<'me, 'info> {
+    fn get_src_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.src_lst_mint
+    }
+
+    fn get_dst_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.dst_lst_mint
+    }
+}
+
 impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for SwapExactInAccounts<'me, 'info> {
     fn get_src_lst_mint(&self) -> &'me AccountInfo<'info> {
         self.src_lst_mint
@@ -61,6 +74,26 @@ impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for SwapExactOutAccount
     }
 }
 
+impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for QuoteSwapExactInAccounts<'me, 'info> {
+    fn get_src_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.src_lst_mint
+    }
+
+    fn get_dst_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.dst_lst_mint
+    }
+}
+
+impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for QuoteSwapExactOutAccounts<'me, 'info> {
+    fn get_src_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.src_lst_mint
+    }
+
+    fn get_dst_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.dst_lst_mint
+    }
+}
+
 pub trait GetSrcDstLstPoolReservesAccountInfo<'me, 'info> {
     fn get_src_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
     fn get_dst_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
@@ -98,6 +131,18 @@ impl<'me, 'info> GetSrcDstLstPoolReservesAccountInfo<'me, 'info>
     }
 }
 
+impl<'me, 'info> GetSrcDstLstPoolReservesAccountInfo<'me, 'info>
+    for AddRebalanceLegAccounts<'me, 'info>
+{
+    fn get_src_lst_pool_reserves(&self) -> &'me AccountInfo<'info> {
+        self.src_pool_reserves
+    }
+
+    fn get_dst_lst_pool_reserves(&self) -> &'me AccountInfo<'info> {
+        self.dst_pool_reserves
+    }
+}
+
 impl<'me, 'info> GetSrcDstLstPoolReservesAccountInfo<'me, 'info>
     for SwapExactInAccounts<'me, 'info>
 {
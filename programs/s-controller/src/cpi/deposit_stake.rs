@@ -0,0 +1,57 @@
+use s_controller_interface::SControllerError;
+use s_controller_lib::DepositStakeProgram;
+use sanctum_misc_utils::ToAccountMeta;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_error::ProgramError,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DepositStakeCpi<'me, 'info> {
+    /// The stake pool program to invoke
+    pub program: &'me AccountInfo<'info>,
+    pub deposit_stake_program: DepositStakeProgram,
+    pub remaining_accounts: &'me [AccountInfo<'info>],
+}
+
+impl<'me, 'info> DepositStakeCpi<'me, 'info> {
+    /// Args:
+    /// - `accounts_suffix_slice`: subslice of instruction accounts where first account is the stake pool program
+    ///     and remaining slice is the stake pool program's deposit stake instruction accounts
+    pub fn from_account_suffix_slice(
+        accounts_suffix_slice: &'me [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let program = accounts_suffix_slice
+            .get(0)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let deposit_stake_program = DepositStakeProgram::try_from_program_id(program.key)
+            .ok_or(SControllerError::IncorrectDepositStakeProgram)?;
+        Ok(Self {
+            program,
+            deposit_stake_program,
+            remaining_accounts: accounts_suffix_slice
+                .get(1..)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+        })
+    }
+
+    /// Not signed by the pool state PDA: the stake account's authority
+    /// must be a signer of the calling instruction
+    pub fn invoke_deposit_stake(self, validator_index: u32) -> ProgramResult {
+        let ix = Instruction {
+            program_id: *self.program.key,
+            accounts: self
+                .remaining_accounts
+                .iter()
+                .map(|a| a.to_account_meta())
+                .collect::<Vec<AccountMeta>>(),
+            data: self
+                .deposit_stake_program
+                .deposit_stake_ix_data(validator_index),
+        };
+        invoke(&ix, self.remaining_accounts)
+    }
+}
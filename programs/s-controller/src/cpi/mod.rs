@@ -1,7 +1,11 @@
 //! Utilities for CPI
 
+mod deposit_stake;
 mod pricing_program;
 mod sol_value_calculator;
+mod withdraw_stake;
 
+pub use deposit_stake::*;
 pub use pricing_program::*;
 pub use sol_value_calculator::*;
+pub use withdraw_stake::*;
@@ -0,0 +1,120 @@
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    is_withdraw_stake_program, spl_stake_pool_withdraw_stake_ix_data, PoolKey,
+    SPL_STAKE_POOL_WITHDRAW_STAKE_ACCOUNTS_LEN, SPL_STAKE_POOL_WITHDRAW_STAKE_BURN_FROM_INDEX,
+    SPL_STAKE_POOL_WITHDRAW_STAKE_POOL_MINT_INDEX,
+    SPL_STAKE_POOL_WITHDRAW_STAKE_USER_STAKE_AUTHORITY_INDEX,
+    SPL_STAKE_POOL_WITHDRAW_STAKE_USER_TRANSFER_AUTHORITY_INDEX,
+};
+use sanctum_misc_utils::ToAccountMeta;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawStakeCpi<'me, 'info> {
+    /// The SPL stake pool program to invoke
+    pub program: &'me AccountInfo<'info>,
+    pub remaining_accounts: &'me [AccountInfo<'info>],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawStakeCpiExpectedKeys<'a> {
+    pub user_stake_authority: &'a Pubkey,
+    pub user_transfer_authority: &'a Pubkey,
+    pub burn_from: &'a Pubkey,
+    pub pool_mint: &'a Pubkey,
+}
+
+impl<'me, 'info> WithdrawStakeCpi<'me, 'info> {
+    /// Args:
+    /// - `accounts_suffix_slice`: subslice of instruction accounts where first account is the stake pool program
+    ///     and remaining slice is the stake pool program's withdraw stake instruction accounts
+    pub fn from_account_suffix_slice(
+        accounts_suffix_slice: &'me [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let program = accounts_suffix_slice
+            .get(0)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if !is_withdraw_stake_program(program.key) {
+            return Err(SControllerError::IncorrectWithdrawStakeProgram.into());
+        }
+        let remaining_accounts = accounts_suffix_slice
+            .get(1..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if remaining_accounts.len() < SPL_STAKE_POOL_WITHDRAW_STAKE_ACCOUNTS_LEN {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            program,
+            remaining_accounts,
+        })
+    }
+
+    /// The CPI is signed by the pool state PDA, so the accounts that
+    /// determine whose tokens are burnt and who receives the stake must be pinned
+    pub fn verify_keys(&self, expected: WithdrawStakeCpiExpectedKeys) -> Result<(), ProgramError> {
+        for (index, expected_key) in [
+            (
+                SPL_STAKE_POOL_WITHDRAW_STAKE_USER_STAKE_AUTHORITY_INDEX,
+                expected.user_stake_authority,
+            ),
+            (
+                SPL_STAKE_POOL_WITHDRAW_STAKE_USER_TRANSFER_AUTHORITY_INDEX,
+                expected.user_transfer_authority,
+            ),
+            (
+                SPL_STAKE_POOL_WITHDRAW_STAKE_BURN_FROM_INDEX,
+                expected.burn_from,
+            ),
+            (
+                SPL_STAKE_POOL_WITHDRAW_STAKE_POOL_MINT_INDEX,
+                expected.pool_mint,
+            ),
+        ] {
+            // len checked in from_account_suffix_slice()
+            let actual_key = self.remaining_accounts[index].key;
+            if actual_key != expected_key {
+                msg!(
+                    "Withdraw stake account {}: expected {} got {}",
+                    index,
+                    expected_key,
+                    actual_key
+                );
+                return Err(SControllerError::IncorrectWithdrawStakeAccounts.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Signed by `pool`'s pool state PDA as the authority of the pool reserves
+    pub fn invoke_signed_withdraw_stake(self, pool: &PoolKey, pool_tokens: u64) -> ProgramResult {
+        let ix = Instruction {
+            program_id: *self.program.key,
+            accounts: self
+                .remaining_accounts
+                .iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let mut meta = a.to_account_meta();
+                    if i == SPL_STAKE_POOL_WITHDRAW_STAKE_USER_TRANSFER_AUTHORITY_INDEX {
+                        meta.is_signer = true;
+                    }
+                    meta
+                })
+                .collect::<Vec<AccountMeta>>(),
+            data: spl_stake_pool_withdraw_stake_ix_data(pool_tokens),
+        };
+        invoke_signed(
+            &ix,
+            self.remaining_accounts,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )
+    }
+}
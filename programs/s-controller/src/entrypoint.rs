@@ -57,6 +57,111 @@ pub fn process_instruction(
         SControllerProgramIx::EndRebalance => process_end_rebalance(accounts),
         SControllerProgramIx::SetRebalanceAuthority => process_set_rebalance_authority(accounts),
         SControllerProgramIx::Initialize => process_initialize(accounts),
+        SControllerProgramIx::SetLstMaxShare(args) => process_set_lst_max_share(accounts, args),
+        SControllerProgramIx::AcceptAdmin => process_accept_admin(accounts),
+        SControllerProgramIx::AcceptRebalanceAuthority => {
+            process_accept_rebalance_authority(accounts)
+        }
+        SControllerProgramIx::AcceptProtocolFeeBeneficiary => {
+            process_accept_protocol_fee_beneficiary(accounts)
+        }
+        SControllerProgramIx::CancelAuthorityHandoff(args) => {
+            process_cancel_authority_handoff(accounts, args)
+        }
+        SControllerProgramIx::QueueSetPricingProgram => process_queue_set_pricing_program(accounts),
+        SControllerProgramIx::ExecuteSetPricingProgram => {
+            process_execute_set_pricing_program(accounts)
+        }
+        SControllerProgramIx::QueueSetSolValueCalculator(args) => {
+            process_queue_set_sol_value_calculator(accounts, args)
+        }
+        SControllerProgramIx::ExecuteSetSolValueCalculator(args) => {
+            process_execute_set_sol_value_calculator(accounts, args)
+        }
+        SControllerProgramIx::QueueSetTimelockDelay(args) => {
+            process_queue_set_timelock_delay(accounts, args)
+        }
+        SControllerProgramIx::ExecuteSetTimelockDelay => {
+            process_execute_set_timelock_delay(accounts)
+        }
+        SControllerProgramIx::CancelQueuedChange => process_cancel_queued_change(accounts),
+        SControllerProgramIx::SyncSolValueMulti(args) => {
+            process_sync_sol_value_multi(accounts, args)
+        }
+        SControllerProgramIx::DisableLstOutput(args) => process_disable_lst_output(accounts, args),
+        SControllerProgramIx::EnableLstOutput(args) => process_enable_lst_output(accounts, args),
+        SControllerProgramIx::QuoteSwapExactIn(args) => process_quote_swap_exact_in(accounts, args),
+        SControllerProgramIx::QuoteSwapExactOut(args) => {
+            process_quote_swap_exact_out(accounts, args)
+        }
+        SControllerProgramIx::QuoteAddLiquidity(args) => {
+            process_quote_add_liquidity(accounts, args)
+        }
+        SControllerProgramIx::QuoteRemoveLiquidity(args) => {
+            process_quote_remove_liquidity(accounts, args)
+        }
+        SControllerProgramIx::FlashBorrow(args) => process_flash_borrow(accounts, args),
+        SControllerProgramIx::FlashRepay => process_flash_repay(accounts),
+        SControllerProgramIx::SetLstFlashLoanFee(args) => {
+            process_set_lst_flash_loan_fee(accounts, args)
+        }
+        SControllerProgramIx::AddRebalanceLeg(args) => process_add_rebalance_leg(accounts, args),
+        SControllerProgramIx::SettleRebalanceLeg => process_settle_rebalance_leg(accounts),
+        SControllerProgramIx::RemoveLiquidityProportional(args) => {
+            process_remove_liquidity_proportional(accounts, args)
+        }
+        SControllerProgramIx::SetRemoveLiquidityProportionalFee(args) => {
+            process_set_remove_liquidity_proportional_fee(accounts, args)
+        }
+        SControllerProgramIx::AddLiquidityMulti(args) => {
+            process_add_liquidity_multi(accounts, args)
+        }
+        SControllerProgramIx::DepositStake(args) => process_deposit_stake(accounts, args),
+        SControllerProgramIx::RemoveLiquidityToStake(args) => {
+            process_remove_liquidity_to_stake(accounts, args)
+        }
+        SControllerProgramIx::SetReferralFee(args) => process_set_referral_fee(accounts, args),
+        SControllerProgramIx::AddProtocolFeeBeneficiary(args) => {
+            process_add_protocol_fee_beneficiary(accounts, args)
+        }
+        SControllerProgramIx::RemoveProtocolFeeBeneficiary(args) => {
+            process_remove_protocol_fee_beneficiary(accounts, args)
+        }
+        SControllerProgramIx::SetProtocolFeeBeneficiaryWeight(args) => {
+            process_set_protocol_fee_beneficiary_weight(accounts, args)
+        }
+        SControllerProgramIx::DistributeProtocolFees => process_distribute_protocol_fees(accounts),
+        SControllerProgramIx::SetProtocolFeesAsLp(args) => {
+            process_set_protocol_fees_as_lp(accounts, args)
+        }
+        SControllerProgramIx::ConvertProtocolFeesToLp(args) => {
+            process_convert_protocol_fees_to_lp(accounts, args)
+        }
+        SControllerProgramIx::SetLstCircuitBreaker(args) => {
+            process_set_lst_circuit_breaker(accounts, args)
+        }
+        SControllerProgramIx::SetLpSyncRequirement(args) => {
+            process_set_lp_sync_requirement(accounts, args)
+        }
+        SControllerProgramIx::Migrate => process_migrate(accounts),
+        SControllerProgramIx::SetLpTokenMetadata(args) => {
+            process_set_lp_token_metadata(accounts, args)
+        }
+        SControllerProgramIx::AddRebalanceAuthority(args) => {
+            process_add_rebalance_authority(accounts, args)
+        }
+        SControllerProgramIx::RemoveRebalanceAuthority(args) => {
+            process_remove_rebalance_authority(accounts, args)
+        }
+        SControllerProgramIx::SetRebalanceAuthorityBudget(args) => {
+            process_set_rebalance_authority_budget(accounts, args)
+        }
+        SControllerProgramIx::SetPoolRebalanceAuthorityBudget(args) => {
+            process_set_pool_rebalance_authority_budget(accounts, args)
+        }
+        SControllerProgramIx::SetMaxRebalanceLoss(args) => {
+            process_set_max_rebalance_loss(accounts, args)
+        }
     };
     if let Err(e) = res.as_ref() {
         e.print::<SControllerError>();
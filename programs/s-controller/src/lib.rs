@@ -2,5 +2,9 @@ pub mod account_traits;
 pub mod cpi;
 pub mod entrypoint;
 pub mod list_account;
+pub mod pending_authorities;
+pub mod pool_config;
 pub mod processor;
+pub mod queued_change;
+pub mod rebalance_authority_list;
 pub mod verify;
@@ -0,0 +1,33 @@
+use s_controller_interface::PendingAuthorities;
+use s_controller_lib::PoolKey;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+
+pub struct CreatePendingAuthoritiesAccounts<'me, 'info> {
+    pub pending_authorities: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+}
+
+/// Creates the pool's PendingAuthorities PDA with all slots zeroed if it does not exist yet,
+/// transfering enough lamports from `payer` to make it rent-exempt.
+/// No-op if the PDA has already been created
+pub fn create_pending_authorities_if_not_exist(
+    pool: &PoolKey,
+    CreatePendingAuthoritiesAccounts {
+        pending_authorities,
+        payer,
+    }: CreatePendingAuthoritiesAccounts,
+) -> Result<(), ProgramError> {
+    if !pending_authorities.data_is_empty() {
+        return Ok(());
+    }
+    // a singleton account is just a list of len 1
+    extend_list_pda::<PendingAuthorities>(
+        ExtendListPdaAccounts {
+            list_pda: pending_authorities,
+            payer,
+        },
+        &[pool.pending_authorities_pda().signer_seeds().as_slice()],
+    )
+}
@@ -0,0 +1,33 @@
+use s_controller_interface::PoolConfig;
+use s_controller_lib::{try_pool_config_mut, PoolKey, DEFAULT_POOL_CONFIG};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+
+pub struct CreatePoolConfigAccounts<'me, 'info> {
+    pub pool_config: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+}
+
+/// Creates the pool's PoolConfig PDA with [`DEFAULT_POOL_CONFIG`] if it does not exist yet,
+/// transfering enough lamports from `payer` to make it rent-exempt.
+/// No-op if the PDA has already been created
+pub fn create_pool_config_if_not_exist(
+    pool: &PoolKey,
+    CreatePoolConfigAccounts { pool_config, payer }: CreatePoolConfigAccounts,
+) -> Result<(), ProgramError> {
+    if !pool_config.data_is_empty() {
+        return Ok(());
+    }
+    // a singleton account is just a list of len 1
+    extend_list_pda::<PoolConfig>(
+        ExtendListPdaAccounts {
+            list_pda: pool_config,
+            payer,
+        },
+        &[pool.pool_config_pda().signer_seeds().as_slice()],
+    )?;
+    let mut pool_config_data = pool_config.try_borrow_mut_data()?;
+    *try_pool_config_mut(&mut pool_config_data)? = DEFAULT_POOL_CONFIG;
+    Ok(())
+}
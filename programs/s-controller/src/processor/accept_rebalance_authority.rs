@@ -0,0 +1,53 @@
+use s_controller_interface::{
+    accept_rebalance_authority_verify_account_keys,
+    accept_rebalance_authority_verify_account_privileges, AcceptRebalanceAuthorityAccounts,
+};
+use s_controller_lib::{
+    try_pending_authorities_mut, try_pool_state, try_pool_state_mut, AcceptAuthorityFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key};
+
+pub fn process_accept_rebalance_authority(accounts: &[AccountInfo]) -> ProgramResult {
+    let checked = verify_accept_rebalance_authority(accounts)?;
+
+    let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
+    let pool_state = try_pool_state_mut(&mut pool_state_data)?;
+    pool_state.rebalance_authority = *checked.pending_rebalance_authority.key;
+
+    let mut pending_authorities_data = checked.pending_authorities.try_borrow_mut_data()?;
+    let pending_authorities = try_pending_authorities_mut(&mut pending_authorities_data)?;
+    pending_authorities.rebalance_authority = Pubkey::default();
+
+    Ok(())
+}
+
+fn verify_accept_rebalance_authority<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<AcceptRebalanceAuthorityAccounts<'a, 'info>, ProgramError> {
+    let actual: AcceptRebalanceAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = AcceptAuthorityFreeArgs {
+        pending_authorities: actual.pending_authorities,
+    }
+    .resolve_rebalance_authority_for_pool(&pool)?;
+
+    accept_rebalance_authority_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    accept_rebalance_authority_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok(actual)
+}
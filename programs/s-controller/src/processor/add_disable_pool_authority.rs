@@ -4,9 +4,8 @@ use s_controller_interface::{
     SControllerError,
 };
 use s_controller_lib::{
-    program::{DISABLE_POOL_AUTHORITY_LIST_BUMP, DISABLE_POOL_AUTHORITY_LIST_SEED},
     try_disable_pool_authority_list, try_disable_pool_authority_list_mut,
-    AddDisablePoolAuthorityFreeArgs,
+    AddDisablePoolAuthorityFreeArgs, PoolKey,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -16,20 +15,23 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+use crate::{
+    list_account::{extend_list_pda, ExtendListPdaAccounts},
+    verify::verify_pool_key,
+};
 
 pub fn process_add_disable_pool_authority(accounts: &[AccountInfo]) -> ProgramResult {
-    let checked = verify_add_disable_pool_authority(accounts)?;
+    let (checked, pool) = verify_add_disable_pool_authority(accounts)?;
 
     extend_list_pda::<Pubkey>(
         ExtendListPdaAccounts {
             list_pda: checked.disable_pool_authority_list,
             payer: checked.payer,
         },
-        &[&[
-            DISABLE_POOL_AUTHORITY_LIST_SEED,
-            &[DISABLE_POOL_AUTHORITY_LIST_BUMP],
-        ]],
+        &[pool
+            .disable_pool_authority_list_pda()
+            .signer_seeds()
+            .as_slice()],
     )?;
 
     let mut disable_pool_authority_list_data =
@@ -59,15 +61,16 @@ fn verify_not_duplicate(
 
 fn verify_add_disable_pool_authority<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
-) -> Result<AddDisablePoolAuthorityAccounts<'me, 'info>, ProgramError> {
+) -> Result<(AddDisablePoolAuthorityAccounts<'me, 'info>, PoolKey), ProgramError> {
     let actual: AddDisablePoolAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = AddDisablePoolAuthorityFreeArgs {
         payer: *actual.payer.key,
         new_authority: *actual.new_authority.key,
         pool_state_acc: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     add_disable_pool_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -79,5 +82,5 @@ fn verify_add_disable_pool_authority<'me, 'info>(
         *actual.new_authority.key,
     )?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
@@ -3,11 +3,11 @@ use s_controller_interface::{
     AddLiquidityAccounts, AddLiquidityIxArgs, SControllerError, ADD_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, AddLiquidityFreeArgs, AddLiquidityIxAmts,
-    AddLiquidityIxFullArgs, CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult,
-    LpTokenRateArgs, PoolStateAccount,
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_state, AddLiquidityEvent, AddLiquidityFreeArgs,
+    AddLiquidityIxAmts, AddLiquidityIxFullArgs, CalcAddLiquidityArgs,
+    CalcAddLiquidityProtocolFeesResult, LpTokenRateArgs, LstTransferFee, PoolConfigAccount,
+    PoolKey, PoolStateAccount, SControllerEvent, SwapLiquidityReturnData,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -17,22 +17,28 @@ use sanctum_token_lib::{
     TransferCheckedAccounts,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 use crate::{
     cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
     verify::{
-        verify_lp_cpis, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
-        VerifyLpCpiAccounts,
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_lst_max_share_not_exceeded,
+        verify_lsts_synced_for_lp_if_required, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLpCpiAccounts,
     },
 };
 
-use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
 
 pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs) -> ProgramResult {
     let (
         accounts,
+        pool,
         AddLiquidityIxFullArgs {
             lst_index,
             amts:
@@ -49,12 +55,17 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
 
     sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
 
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_min();
+    let transfer_fee = LstTransferFee::try_from_mint(accounts.lst_mint, Clock::get()?.epoch)?;
+    let lst_amount_received = transfer_fee.amt_after_fee(lst_amount)?;
+
+    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount_received)?.get_min();
     let lst_amount_sol_value_after_fees =
         pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
-            amount: lst_amount,
+            amount: lst_amount_received,
             sol_value: lst_amount_sol_value,
         })?;
     // Will dilute existing LPs if unchecked
@@ -66,7 +77,7 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         to_reserves_lst_amount,
         to_protocol_fees_lst_amount,
     } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
-        lst_amount,
+        lst_amount: lst_amount_received,
         lst_amount_sol_value,
         lst_amount_sol_value_after_fees,
         lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
@@ -90,6 +101,13 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
 
+    // protocol fees are transferred to the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    let to_reserves_transfer_amount = if protocol_fees_as_lp {
+        lst_amount
+    } else {
+        transfer_fee.amt_bef_fee(to_reserves_lst_amount)?
+    };
     transfer_checked_decimal_agnostic_invoke(
         TransferCheckedAccounts {
             from: accounts.src_lst_acc,
@@ -98,18 +116,24 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
             authority: accounts.signer,
             mint: accounts.lst_mint,
         },
-        to_reserves_lst_amount,
-    )?;
-    transfer_checked_decimal_agnostic_invoke(
-        TransferCheckedAccounts {
-            from: accounts.src_lst_acc,
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.lst_token_program,
-            authority: accounts.signer,
-            mint: accounts.lst_mint,
-        },
-        to_protocol_fees_lst_amount,
+        to_reserves_transfer_amount,
     )?;
+    if !protocol_fees_as_lp {
+        // the protocol fee accumulator bears the transfer fee on the rest of lst_amount
+        let to_protocol_fees_transfer_amount = lst_amount
+            .checked_sub(to_reserves_transfer_amount)
+            .ok_or(SControllerError::MathError)?;
+        transfer_checked_decimal_agnostic_invoke(
+            TransferCheckedAccounts {
+                from: accounts.src_lst_acc,
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.lst_token_program,
+                authority: accounts.signer,
+                mint: accounts.lst_mint,
+            },
+            to_protocol_fees_transfer_amount,
+        )?;
+    }
     mint_to_invoke_signed(
         MintToAccounts {
             mint: accounts.lp_token_mint,
@@ -118,16 +142,55 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
             token_program: accounts.lp_token_program,
         },
         lp_tokens_to_mint,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
     sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
 
+    verify_lst_max_share_not_exceeded(accounts.pool_state, accounts.lst_state_list, lst_index)?;
+
     let end_total_sol_value = accounts.pool_state.total_sol_value()?;
     if end_total_sol_value < start_total_sol_value {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
 
-    Ok(())
+    SControllerEvent::AddLiquidity(AddLiquidityEvent {
+        lst_index: index_to_u32(lst_index)?,
+        lst_amount,
+        lst_amount_sol_value,
+        lp_tokens_minted: lp_tokens_to_mint,
+        protocol_fees: to_protocol_fees_lst_amount,
+        total_sol_value: end_total_sol_value,
+    })
+    .emit()?;
+
+    if protocol_fees_as_lp && to_protocol_fees_lst_amount > 0 {
+        let protocol_fees_sol_value = lst_cpi
+            .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+            .get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index,
+                protocol_fees: to_protocol_fees_lst_amount,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lst_amount,
+        amount_out: lp_tokens_to_mint,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lst_amount_sol_value,
+        out_sol_value: lst_amount_sol_value_after_fees,
+    }
+    .set()
 }
 
 fn verify_add_liquidity<'a, 'info>(
@@ -141,6 +204,7 @@ fn verify_add_liquidity<'a, 'info>(
 ) -> Result<
     (
         AddLiquidityAccounts<'a, 'info>,
+        PoolKey,
         AddLiquidityIxFullArgs,
         SolValueCalculatorCpi<'a, 'info>,
         PricingProgramPriceLpCpi<'a, 'info>,
@@ -154,6 +218,7 @@ fn verify_add_liquidity<'a, 'info>(
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: AddLiquidityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = AddLiquidityFreeArgs {
         lst_index,
@@ -164,13 +229,14 @@ fn verify_add_liquidity<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     add_liquidity_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     add_liquidity_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
     let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
@@ -192,6 +258,7 @@ fn verify_add_liquidity<'a, 'info>(
 
     Ok((
         actual,
+        pool,
         AddLiquidityIxFullArgs {
             lst_index,
             amts: AddLiquidityIxAmts {
@@ -0,0 +1,430 @@
+use s_controller_interface::{
+    add_liquidity_multi_verify_account_keys, add_liquidity_multi_verify_account_privileges,
+    add_liquidity_verify_account_keys, add_liquidity_verify_account_privileges,
+    AddLiquidityAccounts, AddLiquidityMultiAccounts, AddLiquidityMultiEntry,
+    AddLiquidityMultiIxArgs, SControllerError, ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_state, AddLiquidityEvent, AddLiquidityFreeArgs,
+    AddLiquidityMultiFreeArgs, CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult,
+    LpTokenRateArgs, LstTransferFee, PoolConfigAccount, PoolKey, PoolStateAccount,
+    SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke, MintToAccounts,
+    TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    verify::{
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_lst_max_share_not_exceeded,
+        verify_lsts_synced_for_lp_if_required, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLpCpiAccounts,
+    },
+};
+
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
+
+/// Number of accounts preceding each entry's SOL value calculator accounts:
+/// lst_mint, src_lst_acc, protocol_fee_accumulator, lst_token_program, pool_reserves
+const ADD_LIQUIDITY_MULTI_ENTRY_PREFIX_LEN: usize = 5;
+
+pub fn process_add_liquidity_multi(
+    accounts: &[AccountInfo],
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let (accounts, pool, min_lp_out, entries) = verify_add_liquidity_multi(accounts, &args)?;
+
+    for VerifiedAddLiquidityMultiEntry {
+        lst_index,
+        lst,
+        lst_cpi,
+        ..
+    } in entries.iter()
+    {
+        sync_sol_value_unchecked(
+            SyncSolValueUncheckedAccounts::from(*lst),
+            *lst_cpi,
+            *lst_index,
+        )?;
+    }
+
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_protocol_fee_bps = accounts.pool_state.lp_protocol_fee_bps()?;
+
+    // protocol fees are transferred to the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    let epoch = Clock::get()?.epoch;
+    let mut total_sol_value_after_fees: u64 = 0;
+    let amts = entries
+        .iter()
+        .map(
+            |VerifiedAddLiquidityMultiEntry {
+                 lst_amount,
+                 lst,
+                 lst_cpi,
+                 pricing_cpi,
+                 ..
+             }| {
+                let transfer_fee = LstTransferFee::try_from_mint(lst.lst_mint, epoch)?;
+                let lst_amount_received = transfer_fee.amt_after_fee(*lst_amount)?;
+                let lst_amount_sol_value =
+                    lst_cpi.invoke_lst_to_sol(lst_amount_received)?.get_min();
+                let lst_amount_sol_value_after_fees =
+                    pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+                        amount: lst_amount_received,
+                        sol_value: lst_amount_sol_value,
+                    })?;
+                // Will dilute existing LPs if unchecked
+                if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+                    return Err(SControllerError::PoolWouldLoseSolValue.into());
+                }
+                total_sol_value_after_fees = total_sol_value_after_fees
+                    .checked_add(lst_amount_sol_value_after_fees)
+                    .ok_or(SControllerError::MathError)?;
+                let fees = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+                    lst_amount: lst_amount_received,
+                    lst_amount_sol_value,
+                    lst_amount_sol_value_after_fees,
+                    lp_protocol_fee_bps,
+                })?;
+                if fees.to_reserves_lst_amount == 0 {
+                    return Err(SControllerError::ZeroValue.into());
+                }
+                let to_reserves_transfer_amount = if protocol_fees_as_lp {
+                    *lst_amount
+                } else {
+                    transfer_fee.amt_bef_fee(fees.to_reserves_lst_amount)?
+                };
+                Ok((lst_amount_sol_value, to_reserves_transfer_amount, fees))
+            },
+        )
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: start_total_sol_value,
+        },
+        total_sol_value_after_fees,
+    )?;
+
+    if lp_tokens_to_mint == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    if lp_tokens_to_mint < min_lp_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    for (
+        VerifiedAddLiquidityMultiEntry {
+            lst, lst_amount, ..
+        },
+        (_, to_reserves_transfer_amount, _),
+    ) in entries.iter().zip(amts.iter())
+    {
+        transfer_checked_decimal_agnostic_invoke(
+            TransferCheckedAccounts {
+                from: lst.src_lst_acc,
+                to: lst.pool_reserves,
+                token_program: lst.lst_token_program,
+                authority: lst.signer,
+                mint: lst.lst_mint,
+            },
+            *to_reserves_transfer_amount,
+        )?;
+        if !protocol_fees_as_lp {
+            // the protocol fee accumulator bears the transfer fee on the rest of lst_amount
+            let to_protocol_fees_transfer_amount = lst_amount
+                .checked_sub(*to_reserves_transfer_amount)
+                .ok_or(SControllerError::MathError)?;
+            transfer_checked_decimal_agnostic_invoke(
+                TransferCheckedAccounts {
+                    from: lst.src_lst_acc,
+                    to: lst.protocol_fee_accumulator,
+                    token_program: lst.lst_token_program,
+                    authority: lst.signer,
+                    mint: lst.lst_mint,
+                },
+                to_protocol_fees_transfer_amount,
+            )?;
+        }
+    }
+    mint_to_invoke_signed(
+        MintToAccounts {
+            mint: accounts.lp_token_mint,
+            mint_to: accounts.dst_lp_acc,
+            mint_authority: accounts.pool_state,
+            token_program: accounts.lp_token_program,
+        },
+        lp_tokens_to_mint,
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
+    )?;
+
+    for VerifiedAddLiquidityMultiEntry {
+        lst_index,
+        lst,
+        lst_cpi,
+        ..
+    } in entries.iter()
+    {
+        sync_sol_value_unchecked(
+            SyncSolValueUncheckedAccounts::from(*lst),
+            *lst_cpi,
+            *lst_index,
+        )?;
+    }
+    for VerifiedAddLiquidityMultiEntry { lst_index, .. } in entries.iter() {
+        verify_lst_max_share_not_exceeded(
+            accounts.pool_state,
+            accounts.lst_state_list,
+            *lst_index,
+        )?;
+    }
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    for (
+        VerifiedAddLiquidityMultiEntry {
+            lst_index,
+            lst_amount,
+            ..
+        },
+        (
+            lst_amount_sol_value,
+            _,
+            CalcAddLiquidityProtocolFeesResult {
+                to_protocol_fees_lst_amount,
+                ..
+            },
+        ),
+    ) in entries.iter().zip(amts.iter())
+    {
+        SControllerEvent::AddLiquidityMulti(AddLiquidityEvent {
+            lst_index: index_to_u32(*lst_index)?,
+            lst_amount: *lst_amount,
+            lst_amount_sol_value: *lst_amount_sol_value,
+            lp_tokens_minted: lp_tokens_to_mint,
+            protocol_fees: *to_protocol_fees_lst_amount,
+            total_sol_value: end_total_sol_value,
+        })
+        .emit()?;
+    }
+
+    if protocol_fees_as_lp {
+        for (
+            VerifiedAddLiquidityMultiEntry {
+                lst_index, lst_cpi, ..
+            },
+            (
+                _,
+                _,
+                CalcAddLiquidityProtocolFeesResult {
+                    to_protocol_fees_lst_amount,
+                    ..
+                },
+            ),
+        ) in entries.iter().zip(amts)
+        {
+            if to_protocol_fees_lst_amount == 0 {
+                continue;
+            }
+            let protocol_fees_sol_value = lst_cpi
+                .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+                .get_min();
+            mint_protocol_fees_as_lp_unchecked(
+                &pool,
+                MintProtocolFeesAsLpUncheckedAccounts {
+                    lp_token_mint: accounts.lp_token_mint,
+                    protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                    lp_token_program: accounts.lp_token_program,
+                    pool_state: accounts.pool_state,
+                },
+                MintProtocolFeesAsLpArgs {
+                    lst_index: *lst_index,
+                    protocol_fees: to_protocol_fees_lst_amount,
+                    protocol_fees_sol_value,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+struct VerifiedAddLiquidityMultiEntry<'a, 'info> {
+    lst_index: usize,
+    lst_amount: u64,
+    /// Reuses AddLiquidityAccounts to verify the LST's accounts
+    lst: AddLiquidityAccounts<'a, 'info>,
+    lst_cpi: SolValueCalculatorCpi<'a, 'info>,
+    pricing_cpi: PricingProgramPriceLpCpi<'a, 'info>,
+}
+
+fn verify_add_liquidity_multi<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    AddLiquidityMultiIxArgs {
+        min_lp_out,
+        entries,
+    }: &AddLiquidityMultiIxArgs,
+) -> Result<
+    (
+        AddLiquidityMultiAccounts<'a, 'info>,
+        PoolKey,
+        u64,
+        Vec<VerifiedAddLiquidityMultiEntry<'a, 'info>>,
+    ),
+    ProgramError,
+> {
+    if entries.is_empty()
+        || entries.iter().enumerate().any(|(i, entry)| {
+            entries[..i]
+                .iter()
+                .any(|prev| prev.lst_index == entry.lst_index)
+        })
+    {
+        return Err(SControllerError::InvalidAddLiquidityMultiEntries.into());
+    }
+    if entries.iter().any(|entry| entry.lst_amount == 0) {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let actual: AddLiquidityMultiAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = AddLiquidityMultiFreeArgs {
+        signer: *actual.signer.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    add_liquidity_multi_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_liquidity_multi_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let mut accounts_suffix_slice = accounts
+        .get(ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let verified_entries = entries
+        .iter()
+        .map(|entry| {
+            let (verified, rest) =
+                verify_add_liquidity_multi_entry(actual, &pool, accounts_suffix_slice, entry)?;
+            accounts_suffix_slice = rest;
+            Ok(verified)
+        })
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+
+    Ok((actual, pool, *min_lp_out, verified_entries))
+}
+
+/// Returns the verified entry and the remaining accounts suffix slice
+/// following this entry's accounts
+fn verify_add_liquidity_multi_entry<'a, 'info>(
+    actual: AddLiquidityMultiAccounts<'a, 'info>,
+    pool: &PoolKey,
+    accounts_suffix_slice: &'a [AccountInfo<'info>],
+    AddLiquidityMultiEntry {
+        lst_index,
+        lst_amount,
+        lst_value_calc_accs,
+        pricing_accs,
+    }: &AddLiquidityMultiEntry,
+) -> Result<
+    (
+        VerifiedAddLiquidityMultiEntry<'a, 'info>,
+        &'a [AccountInfo<'info>],
+    ),
+    ProgramError,
+> {
+    let lst_index = index_to_usize(*lst_index)?;
+
+    let entry_accounts_len = ADD_LIQUIDITY_MULTI_ENTRY_PREFIX_LEN
+        + usize::from(*lst_value_calc_accs)
+        + usize::from(*pricing_accs);
+    if accounts_suffix_slice.len() < entry_accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (entry_accounts, rest) = accounts_suffix_slice.split_at(entry_accounts_len);
+
+    let lst_actual = AddLiquidityAccounts {
+        signer: actual.signer,
+        lst_mint: &entry_accounts[0],
+        src_lst_acc: &entry_accounts[1],
+        dst_lp_acc: actual.dst_lp_acc,
+        lp_token_mint: actual.lp_token_mint,
+        protocol_fee_accumulator: &entry_accounts[2],
+        lst_token_program: &entry_accounts[3],
+        lp_token_program: actual.lp_token_program,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        pool_reserves: &entry_accounts[4],
+        pool_config: actual.pool_config,
+        protocol_fee_lp_acc: actual.protocol_fee_lp_acc,
+    };
+    let lst_expected = AddLiquidityFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        src_lst_acc: *lst_actual.src_lst_acc.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: lst_actual.lst_mint,
+    }
+    .resolve_for_pool(pool)?;
+    add_liquidity_verify_account_keys(lst_actual, lst_expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_liquidity_verify_account_privileges(lst_actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    {
+        let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+        let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+        // lst_index checked above
+        verify_lst_input_not_disabled(&lst_state_list[lst_index])?;
+    }
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(lst_actual),
+        &entry_accounts[ADD_LIQUIDITY_MULTI_ENTRY_PREFIX_LEN..],
+        *lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((
+        VerifiedAddLiquidityMultiEntry {
+            lst_index,
+            lst_amount: *lst_amount,
+            lst: lst_actual,
+            lst_cpi,
+            pricing_cpi,
+        },
+        rest,
+    ))
+}
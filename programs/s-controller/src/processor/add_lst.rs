@@ -3,8 +3,8 @@ use s_controller_interface::{
     SControllerError,
 };
 use s_controller_lib::{
-    program::{LST_STATE_LIST_BUMP, LST_STATE_LIST_SEED},
-    try_lst_state_list, try_lst_state_list_mut, try_pool_state, AddLstFreeArgs, LstStateBumps,
+    try_lst_state_list, try_lst_state_list_mut, try_pool_state,
+    verify_lst_mint_extensions_supported, AddLstFreeArgs, LstStateBumps, PoolKey,
 };
 use sanctum_associated_token_lib::{create_ata_invoke, CreateAtaAccounts};
 use sanctum_misc_utils::{
@@ -18,12 +18,16 @@ use solana_program::{
 
 use crate::{
     list_account::{extend_list_pda, ExtendListPdaAccounts},
-    verify::{verify_not_rebalancing_and_not_disabled, verify_sol_value_calculator_is_program},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_sol_value_calculator_is_program,
+    },
 };
 
 pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
     let (
         accounts,
+        pool,
         LstStateBumps {
             protocol_fee_accumulator: protocol_fee_accumulator_bump,
             pool_reserves: pool_reserves_bump,
@@ -55,7 +59,7 @@ pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
             list_pda: accounts.lst_state_list,
             payer: accounts.payer,
         },
-        &[&[LST_STATE_LIST_SEED, &[LST_STATE_LIST_BUMP]]],
+        &[pool.lst_state_list_pda().signer_seeds().as_slice()],
     )?;
 
     let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
@@ -71,7 +75,15 @@ pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
         mint: *accounts.lst_mint.key,
         sol_value_calculator: *accounts.sol_value_calculator.key,
         is_input_disabled: 0,
-        padding: [0u8; 5],
+        is_output_disabled: 0,
+        flash_loan_fee_bps: 0,
+        max_share_bps: 0,
+        last_sol_value_rate: 0,
+        last_sol_value_rate_epoch: 0,
+        sol_value_rate_circuit_breaker_bps: 0,
+        padding: Default::default(),
+        last_sync_epoch: 0,
+        prev_epoch_sol_value_rate: 0,
     };
 
     Ok(())
@@ -102,8 +114,9 @@ fn verify_not_duplicate(
 
 fn verify_add_lst<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<(AddLstAccounts<'a, 'info>, LstStateBumps), ProgramError> {
+) -> Result<(AddLstAccounts<'a, 'info>, PoolKey, LstStateBumps), ProgramError> {
     let actual: AddLstAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = AddLstFreeArgs {
         payer: *actual.payer.key,
@@ -111,19 +124,21 @@ fn verify_add_lst<'a, 'info>(
         pool_state: actual.pool_state,
         lst_mint: actual.lst_mint,
     };
-    let (expected, bumps) = free_args.resolve()?;
+    let (expected, bumps) = free_args.resolve_for_pool(&pool)?;
 
     add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+    verify_lst_mint_extensions_supported(&actual.lst_mint.try_borrow_data()?)?;
     verify_sol_value_calculator_is_program(actual.sol_value_calculator)?;
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
 
     verify_not_duplicate(actual.lst_state_list, *actual.lst_mint.key)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok((actual, bumps))
+    Ok((actual, pool, bumps))
 }
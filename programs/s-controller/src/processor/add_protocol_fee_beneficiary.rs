@@ -0,0 +1,96 @@
+use s_controller_interface::{
+    add_protocol_fee_beneficiary_verify_account_keys,
+    add_protocol_fee_beneficiary_verify_account_privileges, AddProtocolFeeBeneficiaryAccounts,
+    AddProtocolFeeBeneficiaryIxArgs, ProtocolFeeBeneficiaryShare, SControllerError,
+};
+use s_controller_lib::{
+    try_find_protocol_fee_beneficiary, try_protocol_fee_beneficiary_list,
+    try_protocol_fee_beneficiary_list_mut, verify_protocol_fee_beneficiary_weights,
+    AddProtocolFeeBeneficiaryFreeArgs, PoolKey,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    list_account::{extend_list_pda, ExtendListPdaAccounts},
+    verify::verify_pool_key,
+};
+
+pub fn process_add_protocol_fee_beneficiary(
+    accounts: &[AccountInfo],
+    AddProtocolFeeBeneficiaryIxArgs { weight_bps }: AddProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_add_protocol_fee_beneficiary(accounts)?;
+
+    extend_list_pda::<ProtocolFeeBeneficiaryShare>(
+        ExtendListPdaAccounts {
+            list_pda: checked.protocol_fee_beneficiary_list,
+            payer: checked.payer,
+        },
+        &[pool
+            .protocol_fee_beneficiary_list_pda()
+            .signer_seeds()
+            .as_slice()],
+    )?;
+
+    let mut list_data = checked
+        .protocol_fee_beneficiary_list
+        .try_borrow_mut_data()?;
+    let list = try_protocol_fee_beneficiary_list_mut(&mut list_data)?;
+    let new_entry = list
+        .last_mut()
+        .ok_or(SControllerError::InvalidProtocolFeeBeneficiaryListData)?;
+
+    *new_entry = ProtocolFeeBeneficiaryShare {
+        beneficiary: *checked.new_beneficiary.key,
+        weight_bps,
+    };
+
+    verify_protocol_fee_beneficiary_weights(list)?;
+
+    Ok(())
+}
+
+fn verify_not_duplicate(
+    protocol_fee_beneficiary_list: &AccountInfo,
+    beneficiary: Pubkey,
+) -> Result<(), ProgramError> {
+    let d = protocol_fee_beneficiary_list.try_borrow_data()?;
+    let list = try_protocol_fee_beneficiary_list(&d)?;
+    if try_find_protocol_fee_beneficiary(beneficiary, list).is_some() {
+        Err(SControllerError::DuplicateProtocolFeeBeneficiary.into())
+    } else {
+        Ok(())
+    }
+}
+
+fn verify_add_protocol_fee_beneficiary<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(AddProtocolFeeBeneficiaryAccounts<'me, 'info>, PoolKey), ProgramError> {
+    let actual: AddProtocolFeeBeneficiaryAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = AddProtocolFeeBeneficiaryFreeArgs {
+        payer: *actual.payer.key,
+        new_beneficiary: *actual.new_beneficiary.key,
+        pool_state_acc: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    add_protocol_fee_beneficiary_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_protocol_fee_beneficiary_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_not_duplicate(
+        actual.protocol_fee_beneficiary_list,
+        *actual.new_beneficiary.key,
+    )?;
+
+    Ok((actual, pool))
+}
@@ -0,0 +1,93 @@
+use s_controller_interface::{
+    add_rebalance_authority_verify_account_keys, add_rebalance_authority_verify_account_privileges,
+    AddRebalanceAuthorityAccounts, AddRebalanceAuthorityIxArgs, RebalanceAuthorityBudget,
+    SControllerError,
+};
+use s_controller_lib::{
+    try_find_rebalance_authority, try_rebalance_authority_list, try_rebalance_authority_list_mut,
+    AddRebalanceAuthorityFreeArgs, PoolKey,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    list_account::{extend_list_pda, ExtendListPdaAccounts},
+    verify::verify_pool_key,
+};
+
+pub fn process_add_rebalance_authority(
+    accounts: &[AccountInfo],
+    AddRebalanceAuthorityIxArgs { epoch_budget }: AddRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_add_rebalance_authority(accounts)?;
+
+    extend_list_pda::<RebalanceAuthorityBudget>(
+        ExtendListPdaAccounts {
+            list_pda: checked.rebalance_authority_list,
+            payer: checked.payer,
+        },
+        &[pool
+            .rebalance_authority_list_pda()
+            .signer_seeds()
+            .as_slice()],
+    )?;
+
+    let mut list_data = checked.rebalance_authority_list.try_borrow_mut_data()?;
+    let list = try_rebalance_authority_list_mut(&mut list_data)?;
+    let new_entry = list
+        .last_mut()
+        .ok_or(SControllerError::InvalidRebalanceAuthorityListData)?;
+
+    *new_entry = RebalanceAuthorityBudget {
+        authority: *checked.new_rebalance_authority.key,
+        epoch_budget,
+        last_epoch: 0,
+        epoch_spent: 0,
+    };
+
+    Ok(())
+}
+
+fn verify_not_duplicate(
+    rebalance_authority_list: &AccountInfo,
+    rebalance_authority: Pubkey,
+) -> Result<(), ProgramError> {
+    let d = rebalance_authority_list.try_borrow_data()?;
+    let list = try_rebalance_authority_list(&d)?;
+    if try_find_rebalance_authority(rebalance_authority, list).is_some() {
+        Err(SControllerError::DuplicateRebalanceAuthority.into())
+    } else {
+        Ok(())
+    }
+}
+
+fn verify_add_rebalance_authority<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(AddRebalanceAuthorityAccounts<'me, 'info>, PoolKey), ProgramError> {
+    let actual: AddRebalanceAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = AddRebalanceAuthorityFreeArgs {
+        payer: *actual.payer.key,
+        new_rebalance_authority: *actual.new_rebalance_authority.key,
+        pool_state_acc: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    add_rebalance_authority_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_rebalance_authority_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_not_duplicate(
+        actual.rebalance_authority_list,
+        *actual.new_rebalance_authority.key,
+    )?;
+
+    Ok((actual, pool))
+}
@@ -0,0 +1,255 @@
+use s_controller_interface::{
+    add_rebalance_leg_verify_account_keys, add_rebalance_leg_verify_account_privileges,
+    AddRebalanceLegAccounts, AddRebalanceLegIxArgs, SControllerError,
+    ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_usize, try_lst_state_list, try_pool_state, try_rebalance_record,
+    try_rebalance_record_mut, try_rebalance_record_unsettled_dst_lst_indexes,
+    try_rebalance_record_unsettled_dst_lst_indexes_mut, AddRebalanceLegFreeArgs, PoolKey,
+    PoolStateAccount, SControllerEvent, SrcDstLstIndexes, StartRebalanceEvent, U8Bool,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
+    cpi::SrcDstLstSolValueCalculatorCpis,
+    rebalance_authority_list::{
+        charge_rebalance_authority_budget_for_epoch, ChargeRebalanceAuthorityBudgetAccounts,
+    },
+    verify::{
+        verify_is_rebalancing, verify_lst_input_not_disabled, verify_lst_output_not_disabled,
+        verify_pool_key, verify_rebalance_authority_or_on_list,
+        verify_src_dst_lst_sol_val_calc_cpis, VerifySrcDstLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+pub fn process_add_rebalance_leg(
+    accounts: &[AccountInfo],
+    args: AddRebalanceLegIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        pool,
+        SrcDstLstSolValueCalculatorCpis {
+            src_lst: src_lst_cpi,
+            dst_lst: dst_lst_cpi,
+        },
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+    ) = verify_add_rebalance_leg(accounts, &args)?;
+
+    // LSTs entering the rebalance for the first time may have stale SOL values.
+    // Syncing them changes total_sol_value, so the rebalance record's
+    // old_total_sol_value must be adjusted by the same amount.
+    // Reserves of LSTs already in the rebalance can only have grown since their last sync
+    // from funds returned to the pool, so leaving these out only makes EndRebalance stricter
+    let unsynced_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    let src_sync_sol_value_accounts =
+        SyncSolValueUncheckedAccounts::from(SrcLstPoolReservesOf(accounts));
+    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(DstLstPoolReservesOf(accounts)),
+        dst_lst_cpi,
+        dst_lst_index,
+    )?;
+
+    let synced_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    let old_total_sol_value = {
+        let mut rebalance_record_data = accounts.rebalance_record.try_borrow_mut_data()?;
+        let rebalance_record = try_rebalance_record_mut(&mut rebalance_record_data)?;
+        rebalance_record.old_total_sol_value =
+            if synced_total_sol_value >= unsynced_total_sol_value {
+                rebalance_record
+                    .old_total_sol_value
+                    .checked_add(synced_total_sol_value - unsynced_total_sol_value)
+            } else {
+                rebalance_record
+                    .old_total_sol_value
+                    .checked_sub(unsynced_total_sol_value - synced_total_sol_value)
+            }
+            .ok_or(SControllerError::MathError)?;
+        rebalance_record.old_total_sol_value
+    };
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            token_program: accounts.src_lst_token_program,
+            from: accounts.src_pool_reserves,
+            to: accounts.withdraw_to,
+            authority: accounts.pool_state,
+            mint: accounts.src_lst_mint,
+        },
+        args.amount,
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
+    )?;
+
+    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+
+    let total_sol_value = accounts.pool_state.total_sol_value()?;
+    charge_rebalance_authority_budget_for_epoch(
+        ChargeRebalanceAuthorityBudgetAccounts {
+            rebalance_authority: accounts.rebalance_authority,
+            rebalance_authority_list: accounts.rebalance_authority_list,
+            pool_config: accounts.pool_config,
+        },
+        synced_total_sol_value.saturating_sub(total_sol_value),
+    )?;
+
+    record_unsettled_dst_lst_index(accounts.rebalance_record, args.dst_lst_index)?;
+
+    SControllerEvent::AddRebalanceLeg(StartRebalanceEvent {
+        src_lst_index: args.src_lst_index,
+        dst_lst_index: args.dst_lst_index,
+        src_lst_withdrawn: args.amount,
+        old_total_sol_value,
+        total_sol_value,
+    })
+    .emit()
+}
+
+/// Appends `dst_lst_index` to the rebalance record's list of unsettled legs
+/// if it is not the StartRebalance's dst LST and not already on the list.
+///
+/// The rebalance record only lives for the duration of the transaction
+/// and is closed by EndRebalance, so it is not topped up to rent-exemption
+fn record_unsettled_dst_lst_index(
+    rebalance_record: &AccountInfo,
+    dst_lst_index: u32,
+) -> Result<(), ProgramError> {
+    {
+        let rebalance_record_data = rebalance_record.try_borrow_data()?;
+        let header = try_rebalance_record(&rebalance_record_data)?;
+        if header.dst_lst_index == dst_lst_index {
+            return Ok(());
+        }
+        let unsettled = try_rebalance_record_unsettled_dst_lst_indexes(&rebalance_record_data)?;
+        if unsettled.contains(&dst_lst_index) {
+            return Ok(());
+        }
+    }
+
+    let new_len = rebalance_record
+        .data_len()
+        .checked_add(std::mem::size_of::<u32>())
+        .ok_or(SControllerError::MathError)?;
+    rebalance_record.realloc(new_len, false)?;
+
+    let mut rebalance_record_data = rebalance_record.try_borrow_mut_data()?;
+    let unsettled = try_rebalance_record_unsettled_dst_lst_indexes_mut(&mut rebalance_record_data)?;
+    let last = unsettled
+        .last_mut()
+        .ok_or(SControllerError::InvalidRebalanceRecordData)?;
+    *last = dst_lst_index;
+    Ok(())
+}
+
+fn verify_add_rebalance_leg<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    AddRebalanceLegIxArgs {
+        src_lst_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount: _,
+        min_starting_src_lst,
+        max_starting_dst_lst,
+    }: &AddRebalanceLegIxArgs,
+) -> Result<
+    (
+        AddRebalanceLegAccounts<'a, 'info>,
+        PoolKey,
+        SrcDstLstSolValueCalculatorCpis<'a, 'info>,
+        SrcDstLstIndexes,
+    ),
+    ProgramError,
+> {
+    let src_lst_index = index_to_usize(*src_lst_index)?;
+    let dst_lst_index = index_to_usize(*dst_lst_index)?;
+
+    let actual: AddRebalanceLegAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = AddRebalanceLegFreeArgs {
+        withdraw_to: *actual.withdraw_to.key,
+        src_lst_index,
+        dst_lst_index,
+        lst_state_list: actual.lst_state_list,
+        pool_state: actual.pool_state,
+        rebalance_record: actual.rebalance_record,
+        src_lst_mint: actual.src_lst_mint,
+        dst_lst_mint: actual.dst_lst_mint,
+    };
+    let mut expected = free_args.resolve_for_pool(&pool)?;
+
+    // is_rebalancing is also set during flash loans,
+    // so also make sure a rebalance is actually in progress.
+    // Legs can only be added by the rebalance authority that started the rebalance
+    {
+        let rebalance_record_bytes = actual.rebalance_record.try_borrow_data()?;
+        let rebalance_record = try_rebalance_record(&rebalance_record_bytes)?;
+        expected.rebalance_authority = rebalance_record.rebalance_authority;
+    }
+
+    add_rebalance_leg_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_rebalance_leg_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    if token_account_balance(actual.src_pool_reserves)? < *min_starting_src_lst {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+    if token_account_balance(actual.dst_pool_reserves)? > *max_starting_dst_lst {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_is_rebalancing(pool_state)?;
+    if U8Bool(pool_state.is_disabled).is_true() {
+        return Err(SControllerError::PoolDisabled.into());
+    }
+    verify_rebalance_authority_or_on_list(
+        *actual.rebalance_authority.key,
+        pool_state,
+        actual.rebalance_authority_list,
+    )?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let dst_lst_state = lst_state_list[dst_lst_index]; // dst_lst_index checked above
+    verify_lst_input_not_disabled(&dst_lst_state)?;
+    let src_lst_state = lst_state_list[src_lst_index]; // src_lst_index checked above
+    verify_lst_output_not_disabled(&src_lst_state)?;
+
+    let accounts_suffix_slice = accounts
+        .get(ADD_REBALANCE_LEG_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let src_dst_lst_indexes = SrcDstLstIndexes {
+        src_lst_index,
+        dst_lst_index,
+    };
+
+    let src_dst_lst_cpis = verify_src_dst_lst_sol_val_calc_cpis(
+        VerifySrcDstLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        *src_lst_calc_accs,
+        src_dst_lst_indexes,
+    )?;
+
+    Ok((actual, pool, src_dst_lst_cpis, src_dst_lst_indexes))
+}
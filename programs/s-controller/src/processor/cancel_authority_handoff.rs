@@ -0,0 +1,71 @@
+use s_controller_interface::{
+    cancel_authority_handoff_verify_account_keys,
+    cancel_authority_handoff_verify_account_privileges, CancelAuthorityHandoffAccounts,
+    CancelAuthorityHandoffIxArgs, SControllerError,
+};
+use s_controller_lib::{try_pending_authorities_mut, CancelAuthorityHandoffFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::verify::verify_pool_key;
+
+pub fn process_cancel_authority_handoff(
+    accounts: &[AccountInfo],
+    CancelAuthorityHandoffIxArgs {
+        cancel_admin,
+        cancel_rebalance_authority,
+        cancel_protocol_fee_beneficiary,
+    }: CancelAuthorityHandoffIxArgs,
+) -> ProgramResult {
+    let checked = verify_cancel_authority_handoff(accounts)?;
+
+    let mut pending_authorities_data = checked.pending_authorities.try_borrow_mut_data()?;
+    let pending_authorities = try_pending_authorities_mut(&mut pending_authorities_data)?;
+
+    let mut cancelled_any = false;
+    for (should_cancel, slot) in [
+        (cancel_admin, &mut pending_authorities.admin),
+        (
+            cancel_rebalance_authority,
+            &mut pending_authorities.rebalance_authority,
+        ),
+        (
+            cancel_protocol_fee_beneficiary,
+            &mut pending_authorities.protocol_fee_beneficiary,
+        ),
+    ] {
+        if should_cancel && *slot != Pubkey::default() {
+            *slot = Pubkey::default();
+            cancelled_any = true;
+        }
+    }
+    if !cancelled_any {
+        return Err(SControllerError::NoPendingAuthority.into());
+    }
+
+    Ok(())
+}
+
+fn verify_cancel_authority_handoff<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<CancelAuthorityHandoffAccounts<'a, 'info>, ProgramError> {
+    let actual: CancelAuthorityHandoffAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = CancelAuthorityHandoffFreeArgs {
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    cancel_authority_handoff_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    cancel_authority_handoff_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
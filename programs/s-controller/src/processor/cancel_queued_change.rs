@@ -0,0 +1,55 @@
+use s_controller_interface::{
+    cancel_queued_change_verify_account_keys, cancel_queued_change_verify_account_privileges,
+    CancelQueuedChangeAccounts,
+};
+use s_controller_lib::{try_pool_state, CancelQueuedChangeFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::verify::{verify_admin_or_disable_pool_authority, verify_pool_key};
+
+pub fn process_cancel_queued_change(accounts: &[AccountInfo]) -> ProgramResult {
+    let CancelQueuedChangeAccounts {
+        queued_change,
+        refund_rent_to,
+        ..
+    } = verify_cancel_queued_change(accounts)?;
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: queued_change,
+    })
+}
+
+fn verify_cancel_queued_change<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<CancelQueuedChangeAccounts<'me, 'info>, ProgramError> {
+    let actual: CancelQueuedChangeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = CancelQueuedChangeFreeArgs {
+        signer: *actual.signer.key,
+        queued_change: actual.queued_change,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    cancel_queued_change_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    cancel_queued_change_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_admin_or_disable_pool_authority(
+        *actual.signer.key,
+        pool_state,
+        actual.disable_pool_authority_list,
+    )?;
+
+    Ok(actual)
+}
@@ -0,0 +1,198 @@
+use s_controller_interface::{
+    convert_protocol_fees_to_lp_verify_account_keys,
+    convert_protocol_fees_to_lp_verify_account_privileges, ConvertProtocolFeesToLpAccounts,
+    ConvertProtocolFeesToLpIxArgs, SControllerError, CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_protocol_fee_lp_tokens_to_mint, index_to_u32, index_to_usize, try_pool_state,
+    ConvertProtocolFeesToLpFreeArgs, LpTokenRateArgs, PoolConfigAccount, PoolKey, PoolStateAccount,
+    ProtocolFeesMintedAsLpEvent, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    mint_supply, mint_to_invoke_signed, token_account_balance,
+    transfer_checked_decimal_agnostic_invoke_signed, MintToAccounts, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled, verify_pool_key,
+        verify_pool_migrated, VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+pub fn process_convert_protocol_fees_to_lp(
+    accounts: &[AccountInfo],
+    args: ConvertProtocolFeesToLpIxArgs,
+) -> ProgramResult {
+    let (accounts, pool, lst_index, cpi) = verify_convert_protocol_fees_to_lp(accounts, args)?;
+
+    let protocol_fees = token_account_balance(accounts.protocol_fee_accumulator)?;
+    if protocol_fees == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+    sync_sol_value_unchecked(sync_sol_value_accounts, cpi, lst_index)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            from: accounts.protocol_fee_accumulator,
+            to: accounts.pool_reserves,
+            token_program: accounts.lst_token_program,
+            authority: accounts.protocol_fee_accumulator_auth,
+            mint: accounts.lst_mint,
+        },
+        protocol_fees,
+        &[pool.protocol_fee_pda().signer_seeds().as_slice()],
+    )?;
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, cpi, lst_index)?;
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let protocol_fees_sol_value = end_total_sol_value
+        .checked_sub(start_total_sol_value)
+        .ok_or(SControllerError::PoolWouldLoseSolValue)?;
+
+    mint_protocol_fees_as_lp_unchecked(
+        &pool,
+        MintProtocolFeesAsLpUncheckedAccounts {
+            lp_token_mint: accounts.lp_token_mint,
+            protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+            lp_token_program: accounts.lp_token_program,
+            pool_state: accounts.pool_state,
+        },
+        MintProtocolFeesAsLpArgs {
+            lst_index,
+            protocol_fees,
+            protocol_fees_sol_value,
+        },
+    )
+}
+
+fn verify_convert_protocol_fees_to_lp<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    ConvertProtocolFeesToLpIxArgs { lst_index }: ConvertProtocolFeesToLpIxArgs,
+) -> Result<
+    (
+        ConvertProtocolFeesToLpAccounts<'a, 'info>,
+        PoolKey,
+        usize,
+        SolValueCalculatorCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: ConvertProtocolFeesToLpAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = ConvertProtocolFeesToLpFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    convert_protocol_fees_to_lp_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    convert_protocol_fees_to_lp_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    if !actual.pool_config.protocol_fees_as_lp()? {
+        return Err(SControllerError::ProtocolFeesAsLpNotEnabled.into());
+    }
+
+    let accounts_suffix_slice = accounts
+        .get(CONVERT_PROTOCOL_FEES_TO_LP_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_index,
+    )?;
+
+    Ok((actual, pool, lst_index, cpi))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MintProtocolFeesAsLpUncheckedAccounts<'me, 'info> {
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    pub protocol_fee_lp_acc: &'me AccountInfo<'info>,
+    pub lp_token_program: &'me AccountInfo<'info>,
+    pub pool_state: &'me AccountInfo<'info>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MintProtocolFeesAsLpArgs {
+    pub lst_index: usize,
+
+    /// Amount of LST protocol fees that were left in the pool reserves
+    pub protocol_fees: u64,
+
+    /// SOL value of `protocol_fees`, already included in the pool's `total_sol_value`
+    pub protocol_fees_sol_value: u64,
+}
+
+/// Mints LP tokens worth `protocol_fees_sol_value` to the protocol fee LP token account
+/// and emits `ProtocolFeesMintedAsLp`, exported for use by other instruction processors.
+///
+/// Must be called after the LST has been synced with the protocol fees left in the pool reserves.
+pub fn mint_protocol_fees_as_lp_unchecked(
+    pool: &PoolKey,
+    MintProtocolFeesAsLpUncheckedAccounts {
+        lp_token_mint,
+        protocol_fee_lp_acc,
+        lp_token_program,
+        pool_state,
+    }: MintProtocolFeesAsLpUncheckedAccounts,
+    MintProtocolFeesAsLpArgs {
+        lst_index,
+        protocol_fees,
+        protocol_fees_sol_value,
+    }: MintProtocolFeesAsLpArgs,
+) -> Result<(), ProgramError> {
+    let lp_tokens_to_mint = calc_protocol_fee_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply: mint_supply(lp_token_mint)?,
+            pool_total_sol_value: pool_state.total_sol_value()?,
+        },
+        protocol_fees_sol_value,
+    )?;
+    if lp_tokens_to_mint > 0 {
+        mint_to_invoke_signed(
+            MintToAccounts {
+                mint: lp_token_mint,
+                mint_to: protocol_fee_lp_acc,
+                mint_authority: pool_state,
+                token_program: lp_token_program,
+            },
+            lp_tokens_to_mint,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+
+    SControllerEvent::ProtocolFeesMintedAsLp(ProtocolFeesMintedAsLpEvent {
+        lst_index: index_to_u32(lst_index)?,
+        protocol_fees,
+        protocol_fees_sol_value,
+        lp_tokens_minted: lp_tokens_to_mint,
+    })
+    .emit()
+}
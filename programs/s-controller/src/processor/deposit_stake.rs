@@ -0,0 +1,264 @@
+use s_controller_interface::{
+    deposit_stake_verify_account_keys, deposit_stake_verify_account_privileges,
+    DepositStakeAccounts, DepositStakeIxArgs, SControllerError, DEPOSIT_STAKE_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_state, AddLiquidityEvent, CalcAddLiquidityArgs,
+    CalcAddLiquidityProtocolFeesResult, DepositStakeFreeArgs, DepositStakeIxFullArgs,
+    LpTokenRateArgs, PoolConfigAccount, PoolKey, PoolStateAccount, SControllerEvent,
+    SwapLiquidityReturnData,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    mint_supply, mint_to_invoke_signed, token_account_balance,
+    transfer_checked_decimal_agnostic_invoke_signed, MintToAccounts, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{DepositStakeCpi, PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    verify::{
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_lst_max_share_not_exceeded,
+        verify_lsts_synced_for_lp_if_required, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLpCpiAccounts,
+    },
+};
+
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
+
+pub fn process_deposit_stake(accounts: &[AccountInfo], args: DepositStakeIxArgs) -> ProgramResult {
+    let (
+        accounts,
+        pool,
+        DepositStakeIxFullArgs {
+            lst_index,
+            validator_index,
+            min_lp_out,
+        },
+        deposit_stake_cpi,
+        lst_cpi,
+        pricing_cpi,
+    ) = verify_deposit_stake(accounts, args)?;
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    // The stake pool mints the LST directly to pool_reserves,
+    // so the amount added is the change in pool_reserves' balance
+    let pool_reserves_balance_before = token_account_balance(accounts.pool_reserves)?;
+    deposit_stake_cpi.invoke_deposit_stake(validator_index)?;
+    let lst_amount = token_account_balance(accounts.pool_reserves)?
+        .checked_sub(pool_reserves_balance_before)
+        .ok_or(SControllerError::MathError)?;
+    if lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_min();
+    let lst_amount_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+            amount: lst_amount,
+            sol_value: lst_amount_sol_value,
+        })?;
+    // Will dilute existing LPs if unchecked
+    if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    let CalcAddLiquidityProtocolFeesResult {
+        to_reserves_lst_amount,
+        to_protocol_fees_lst_amount,
+    } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+        lst_amount,
+        lst_amount_sol_value,
+        lst_amount_sol_value_after_fees,
+        lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
+    })?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: start_total_sol_value,
+        },
+        lst_amount_sol_value_after_fees,
+    )?;
+
+    if to_reserves_lst_amount == 0 || lp_tokens_to_mint == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    if lp_tokens_to_mint < min_lp_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    // protocol fees are left in the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    if !protocol_fees_as_lp {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: accounts.pool_reserves,
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.lst_token_program,
+                authority: accounts.pool_state,
+                mint: accounts.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+    mint_to_invoke_signed(
+        MintToAccounts {
+            mint: accounts.lp_token_mint,
+            mint_to: accounts.dst_lp_acc,
+            mint_authority: accounts.pool_state,
+            token_program: accounts.lp_token_program,
+        },
+        lp_tokens_to_mint,
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
+    )?;
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    verify_lst_max_share_not_exceeded(accounts.pool_state, accounts.lst_state_list, lst_index)?;
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    SControllerEvent::DepositStake(AddLiquidityEvent {
+        lst_index: index_to_u32(lst_index)?,
+        lst_amount,
+        lst_amount_sol_value,
+        lp_tokens_minted: lp_tokens_to_mint,
+        protocol_fees: to_protocol_fees_lst_amount,
+        total_sol_value: end_total_sol_value,
+    })
+    .emit()?;
+
+    if protocol_fees_as_lp && to_protocol_fees_lst_amount > 0 {
+        let protocol_fees_sol_value = lst_cpi
+            .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+            .get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index,
+                protocol_fees: to_protocol_fees_lst_amount,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lst_amount,
+        amount_out: lp_tokens_to_mint,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lst_amount_sol_value,
+        out_sol_value: lst_amount_sol_value_after_fees,
+    }
+    .set()
+}
+
+fn verify_deposit_stake<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    DepositStakeIxArgs {
+        deposit_stake_accs,
+        lst_value_calc_accs,
+        lst_index,
+        validator_index,
+        min_lp_out,
+    }: DepositStakeIxArgs,
+) -> Result<
+    (
+        DepositStakeAccounts<'a, 'info>,
+        PoolKey,
+        DepositStakeIxFullArgs,
+        DepositStakeCpi<'a, 'info>,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: DepositStakeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = DepositStakeFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    deposit_stake_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    deposit_stake_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    // lst_index checked above
+    let lst_state = lst_state_list[lst_index];
+    verify_lst_input_not_disabled(&lst_state)?;
+
+    let deposit_stake_accounts_suffix_slice_end: usize = deposit_stake_accs.into();
+    let deposit_stake_accounts_suffix_slice = accounts
+        .get(
+            DEPOSIT_STAKE_IX_ACCOUNTS_LEN
+                ..DEPOSIT_STAKE_IX_ACCOUNTS_LEN + deposit_stake_accounts_suffix_slice_end,
+        )
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let deposit_stake_cpi =
+        DepositStakeCpi::from_account_suffix_slice(deposit_stake_accounts_suffix_slice)?;
+
+    let accounts_suffix_slice = accounts
+        .get(DEPOSIT_STAKE_IX_ACCOUNTS_LEN + deposit_stake_accounts_suffix_slice_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((
+        actual,
+        pool,
+        DepositStakeIxFullArgs {
+            lst_index,
+            validator_index,
+            min_lp_out,
+        },
+        deposit_stake_cpi,
+        lst_cpi,
+        pricing_cpi,
+    ))
+}
@@ -11,6 +11,7 @@ use solana_program::{
 
 use crate::verify::{
     verify_admin_or_disable_pool_authority, verify_not_rebalancing_and_not_disabled,
+    verify_pool_key,
 };
 
 pub fn process_disable_pool(accounts: &[AccountInfo]) -> ProgramResult {
@@ -32,11 +33,12 @@ fn verify_disable_pool<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
 ) -> Result<DisablePoolAccounts<'me, 'info>, ProgramError> {
     let actual: DisablePoolAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = DisablePoolFreeArgs {
         signer: *actual.signer.key,
     };
-    let expected = free_args.resolve();
+    let expected = free_args.resolve_for_pool(&pool);
 
     disable_pool_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     disable_pool_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -0,0 +1,113 @@
+use s_controller_interface::{
+    distribute_protocol_fees_verify_account_keys,
+    distribute_protocol_fees_verify_account_privileges, DistributeProtocolFeesAccounts,
+    SControllerError, DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_protocol_fee_beneficiary_share, try_pool_state, try_protocol_fee_beneficiary_list,
+    DistributeProtocolFeesEvent, DistributeProtocolFeesFreeArgs, PoolKey, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_token_account_authority;
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key};
+
+pub fn process_distribute_protocol_fees(accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, pool, beneficiary_token_accounts) = verify_distribute_protocol_fees(accounts)?;
+
+    let protocol_fee_accumulator_balance =
+        token_account_balance(accounts.protocol_fee_accumulator)?;
+    let list_data = accounts.protocol_fee_beneficiary_list.try_borrow_data()?;
+    let list = try_protocol_fee_beneficiary_list(&list_data)?;
+
+    let mut total_distributed: u64 = 0;
+    for (share, beneficiary_token_account) in list.iter().zip(beneficiary_token_accounts) {
+        let amount = calc_protocol_fee_beneficiary_share(
+            protocol_fee_accumulator_balance,
+            share.weight_bps,
+        )?;
+        if amount == 0 {
+            continue;
+        }
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: accounts.protocol_fee_accumulator,
+                to: beneficiary_token_account,
+                token_program: accounts.token_program,
+                authority: accounts.protocol_fee_accumulator_auth,
+                mint: accounts.lst_mint,
+            },
+            amount,
+            &[pool.protocol_fee_pda().signer_seeds().as_slice()],
+        )?;
+        total_distributed = total_distributed
+            .checked_add(amount)
+            .ok_or(SControllerError::MathError)?;
+
+        SControllerEvent::DistributeProtocolFees(DistributeProtocolFeesEvent {
+            lst_mint: *accounts.lst_mint.key,
+            beneficiary: share.beneficiary,
+            amount,
+        })
+        .emit()?;
+    }
+
+    if total_distributed == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    Ok(())
+}
+
+/// Returns the beneficiary token accounts suffix slice, in list order
+fn verify_distribute_protocol_fees<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<
+    (
+        DistributeProtocolFeesAccounts<'a, 'info>,
+        PoolKey,
+        &'a [AccountInfo<'info>],
+    ),
+    ProgramError,
+> {
+    let actual: DistributeProtocolFeesAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = DistributeProtocolFeesFreeArgs {
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(&pool);
+
+    distribute_protocol_fees_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    distribute_protocol_fees_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let list_data = actual.protocol_fee_beneficiary_list.try_borrow_data()?;
+    let list = try_protocol_fee_beneficiary_list(&list_data)?;
+
+    let beneficiary_token_accounts = accounts
+        .get(DISTRIBUTE_PROTOCOL_FEES_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if beneficiary_token_accounts.len() != list.len() {
+        return Err(SControllerError::IncorrectProtocolFeeBeneficiaryTokenAccounts.into());
+    }
+    for (share, beneficiary_token_account) in list.iter().zip(beneficiary_token_accounts) {
+        verify_token_account_authority(beneficiary_token_account, share.beneficiary)
+            .map_err(|_e| SControllerError::IncorrectProtocolFeeBeneficiaryTokenAccounts)?;
+    }
+
+    Ok((actual, pool, beneficiary_token_accounts))
+}
@@ -13,7 +13,9 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::verify::{
+    verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+};
 
 pub fn process_enable_lst_input(
     accounts: &[AccountInfo],
@@ -37,19 +39,21 @@ fn verify_enable_lst_input<'me, 'info>(
     let lst_index = index_to_usize(index)?;
 
     let actual: EnableLstInputAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = DisableEnableLstInputFreeArgs {
         lst_index,
         pool_state: actual.pool_state,
         lst_state_list: actual.lst_state_list,
     };
-    let expected = free_args.resolve_enable()?;
+    let expected = free_args.resolve_enable_for_pool(&pool)?;
 
     enable_lst_input_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     enable_lst_input_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
     Ok((actual, lst_index))
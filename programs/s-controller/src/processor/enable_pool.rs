@@ -10,6 +10,8 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
+use crate::verify::verify_pool_key;
+
 pub fn process_enable_pool(accounts: &[AccountInfo]) -> ProgramResult {
     let EnablePoolAccounts {
         admin: _,
@@ -28,11 +30,12 @@ fn verify_enable_pool<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
 ) -> Result<EnablePoolAccounts<'me, 'info>, ProgramError> {
     let actual: EnablePoolAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = EnablePoolFreeArgs {
         pool_state_acc: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     enable_pool_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     enable_pool_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
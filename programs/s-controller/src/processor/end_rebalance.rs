@@ -1,11 +1,11 @@
 use s_controller_interface::{
     end_rebalance_verify_account_keys, end_rebalance_verify_account_privileges,
-    EndRebalanceAccounts, PoolState, RebalanceRecord, SControllerError,
-    END_REBALANCE_IX_ACCOUNTS_LEN,
+    EndRebalanceAccounts, RebalanceRecord, SControllerError, END_REBALANCE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    try_pool_state, try_pool_state_mut, try_rebalance_record, EndRebalanceFreeArgs,
-    PoolStateAccount, U8Bool, U8BoolMut,
+    index_to_u32, try_pool_state, try_pool_state_mut, try_rebalance_record,
+    try_rebalance_record_unsettled_dst_lst_indexes, EndRebalanceEvent, EndRebalanceFreeArgs,
+    PoolStateAccount, RebalanceSolValueLostEvent, SControllerEvent, U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -17,7 +17,10 @@ use solana_program::{
 
 use crate::{
     cpi::SolValueCalculatorCpi,
-    verify::{verify_lst_sol_val_calc_cpi, VerifyLstSolValCalcCpiAccounts},
+    verify::{
+        verify_is_rebalancing, verify_lst_sol_val_calc_cpi, verify_pool_key,
+        VerifyLstSolValCalcCpiAccounts,
+    },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
@@ -30,13 +33,14 @@ pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
         let pool_state = try_pool_state_mut(&mut pool_state_data)?;
         U8BoolMut(&mut pool_state.is_rebalancing).set_false();
     }
-    let old_total_sol_value = {
+    let (old_total_sol_value, max_sol_value_loss) = {
         let rebalance_record_data = accounts.rebalance_record.try_borrow_data()?;
         let RebalanceRecord {
             old_total_sol_value,
+            max_sol_value_loss,
             ..
         } = try_rebalance_record(&rebalance_record_data)?;
-        *old_total_sol_value
+        (*old_total_sol_value, *max_sol_value_loss)
     };
 
     sync_sol_value_unchecked(
@@ -45,10 +49,28 @@ pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
         dst_lst_index,
     )?;
 
-    if accounts.pool_state.total_sol_value()? < old_total_sol_value {
+    let total_sol_value = accounts.pool_state.total_sol_value()?;
+    let sol_value_lost = old_total_sol_value.saturating_sub(total_sol_value);
+    if sol_value_lost > max_sol_value_loss {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
 
+    let dst_lst_index = index_to_u32(dst_lst_index)?;
+    SControllerEvent::EndRebalance(EndRebalanceEvent {
+        dst_lst_index,
+        old_total_sol_value,
+        total_sol_value,
+    })
+    .emit()?;
+    if sol_value_lost > 0 {
+        SControllerEvent::RebalanceSolValueLost(RebalanceSolValueLostEvent {
+            dst_lst_index,
+            sol_value_lost,
+            max_sol_value_loss,
+        })
+        .emit()?;
+    }
+
     close_account(CloseAccountAccounts {
         refund_rent_to: accounts.pool_state,
         close: accounts.rebalance_record,
@@ -66,6 +88,7 @@ fn verify_end_rebalance<'a, 'info>(
     ProgramError,
 > {
     let actual: EndRebalanceAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = EndRebalanceFreeArgs {
         pool_state: actual.pool_state,
@@ -73,7 +96,14 @@ fn verify_end_rebalance<'a, 'info>(
         rebalance_record: actual.rebalance_record,
         dst_lst_mint: actual.dst_lst_mint,
     };
-    let (expected, dst_lst_index) = free_args.resolve()?;
+    let (mut expected, dst_lst_index) = free_args.resolve_for_pool(&pool)?;
+    // StartRebalance already checked that its signer is an authorized rebalance authority,
+    // possibly one on the rebalance authority list, and recorded it
+    {
+        let rebalance_record_bytes = actual.rebalance_record.try_borrow_data()?;
+        let rebalance_record = try_rebalance_record(&rebalance_record_bytes)?;
+        expected.rebalance_authority = rebalance_record.rebalance_authority;
+    }
 
     end_rebalance_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     end_rebalance_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -82,6 +112,12 @@ fn verify_end_rebalance<'a, 'info>(
     let pool_state = try_pool_state(&pool_state_bytes)?;
     verify_is_rebalancing(pool_state)?;
 
+    let rebalance_record_bytes = actual.rebalance_record.try_borrow_data()?;
+    let unsettled = try_rebalance_record_unsettled_dst_lst_indexes(&rebalance_record_bytes)?;
+    if !unsettled.is_empty() {
+        return Err(SControllerError::RebalanceLegsNotSettled.into());
+    }
+
     let accounts_suffix_slice = accounts
         .get(END_REBALANCE_IX_ACCOUNTS_LEN..)
         .ok_or(ProgramError::NotEnoughAccountKeys)?;
@@ -93,11 +129,3 @@ fn verify_end_rebalance<'a, 'info>(
 
     Ok((actual, dst_lst_cpi, dst_lst_index))
 }
-
-const fn verify_is_rebalancing(pool_state: &PoolState) -> Result<(), SControllerError> {
-    if U8Bool(pool_state.is_rebalancing).is_true() {
-        Ok(())
-    } else {
-        Err(SControllerError::PoolNotRebalancing)
-    }
-}
@@ -0,0 +1,66 @@
+use s_controller_interface::{
+    execute_set_pricing_program_verify_account_keys,
+    execute_set_pricing_program_verify_account_privileges, ExecuteSetPricingProgramAccounts,
+};
+use s_controller_lib::{try_pool_state, try_pool_state_mut, ExecuteSetPricingProgramFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    queued_change::verify_queued_change_executable,
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pricing_program_is_program,
+    },
+};
+
+pub fn process_execute_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
+    let ExecuteSetPricingProgramAccounts {
+        new_pricing_program,
+        pool_state,
+        queued_change,
+        refund_rent_to,
+    } = verify_execute_set_pricing_program(accounts)?;
+
+    {
+        let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+        pool_state.pricing_program = *new_pricing_program.key;
+    }
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: queued_change,
+    })
+}
+
+fn verify_execute_set_pricing_program<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<ExecuteSetPricingProgramAccounts<'me, 'info>, ProgramError> {
+    let actual: ExecuteSetPricingProgramAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = ExecuteSetPricingProgramFreeArgs {
+        queued_change: actual.queued_change,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    execute_set_pricing_program_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    execute_set_pricing_program_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_pricing_program_is_program(actual.new_pricing_program)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    verify_queued_change_executable(actual.queued_change)?;
+
+    Ok(actual)
+}
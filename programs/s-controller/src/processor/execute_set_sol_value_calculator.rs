@@ -0,0 +1,106 @@
+use s_controller_interface::{
+    execute_set_sol_value_calculator_verify_account_keys,
+    execute_set_sol_value_calculator_verify_account_privileges,
+    ExecuteSetSolValueCalculatorAccounts, ExecuteSetSolValueCalculatorIxArgs, SControllerError,
+    EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_usize, try_lst_state_list_mut, try_pool_state, ExecuteSetSolValueCalculatorFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    queued_change::verify_queued_change_executable,
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_sol_value_calculator_is_program,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+pub fn process_execute_set_sol_value_calculator(
+    accounts: &[AccountInfo],
+    args: ExecuteSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    let (accounts, lst_index, cpi) = verify_execute_set_sol_value_calculator(accounts, args)?;
+
+    {
+        let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
+        let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
+        // lst_index checked in verify
+        let lst_state = &mut lst_state_list[lst_index];
+        lst_state.sol_value_calculator = *cpi.program.key;
+        // rates returned by the old calculator should not trip the circuit breaker
+        lst_state.last_sol_value_rate = 0;
+    }
+
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(accounts),
+        cpi,
+        lst_index,
+    )?;
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to: accounts.refund_rent_to,
+        close: accounts.queued_change,
+    })
+}
+
+fn verify_execute_set_sol_value_calculator<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    ExecuteSetSolValueCalculatorIxArgs { lst_index }: ExecuteSetSolValueCalculatorIxArgs,
+) -> Result<
+    (
+        ExecuteSetSolValueCalculatorAccounts<'a, 'info>,
+        usize,
+        SolValueCalculatorCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    let lst_index = index_to_usize(lst_index)?;
+    let actual: ExecuteSetSolValueCalculatorAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = ExecuteSetSolValueCalculatorFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+        queued_change: actual.queued_change,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    execute_set_sol_value_calculator_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    execute_set_sol_value_calculator_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let queued_change = verify_queued_change_executable(actual.queued_change)?;
+
+    let accounts_suffix_slice = accounts
+        .get(EXECUTE_SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let cpi = SolValueCalculatorCpi::from_lst_mint_and_account_suffix_slice(
+        actual.lst_mint,
+        accounts_suffix_slice,
+    )?;
+    if *cpi.program.key != queued_change.new_program {
+        return Err(SControllerError::IncorrectSolValueCalculator.into());
+    }
+    verify_sol_value_calculator_is_program(cpi.program)?;
+
+    Ok((actual, lst_index, cpi))
+}
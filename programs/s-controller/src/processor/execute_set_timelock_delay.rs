@@ -0,0 +1,58 @@
+use s_controller_interface::{
+    execute_set_timelock_delay_verify_account_keys,
+    execute_set_timelock_delay_verify_account_privileges, ExecuteSetTimelockDelayAccounts,
+};
+use s_controller_lib::{try_pool_state_mut, ExecuteSetTimelockDelayFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{queued_change::verify_queued_change_executable, verify::verify_pool_key};
+
+pub fn process_execute_set_timelock_delay(accounts: &[AccountInfo]) -> ProgramResult {
+    let (
+        ExecuteSetTimelockDelayAccounts {
+            pool_state,
+            queued_change,
+            refund_rent_to,
+        },
+        new_timelock_delay,
+    ) = verify_execute_set_timelock_delay(accounts)?;
+
+    {
+        let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+        pool_state.timelock_delay = new_timelock_delay;
+    }
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: queued_change,
+    })
+}
+
+/// Returns (accounts, new_timelock_delay)
+fn verify_execute_set_timelock_delay<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(ExecuteSetTimelockDelayAccounts<'me, 'info>, u8), ProgramError> {
+    let actual: ExecuteSetTimelockDelayAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = ExecuteSetTimelockDelayFreeArgs {
+        queued_change: actual.queued_change,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    execute_set_timelock_delay_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    execute_set_timelock_delay_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let queued_change = verify_queued_change_executable(actual.queued_change)?;
+
+    Ok((actual, queued_change.new_timelock_delay))
+}
@@ -0,0 +1,191 @@
+use s_controller_interface::{
+    flash_borrow_verify_account_keys, flash_borrow_verify_account_privileges, FlashBorrowAccounts,
+    FlashBorrowIxArgs, SControllerError, FLASH_REPAY_IX_DISCM,
+};
+use s_controller_lib::{
+    calc_flash_loan_fee, index_to_usize, try_flash_loan_record_mut, try_lst_state_list,
+    try_pool_state, try_pool_state_mut, FlashBorrowFreeArgs, PoolKey, U8BoolMut,
+    FLASH_LOAN_RECORD_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{space_to_u64, transfer_direct_increment};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use system_program_interface::{
+    allocate_invoke_signed, assign_invoke_signed, AllocateAccounts, AllocateIxArgs, AssignAccounts,
+    AssignIxArgs, TransferAccounts,
+};
+
+use crate::verify::{
+    verify_lst_output_not_disabled, verify_not_rebalancing_and_not_disabled, verify_pool_key,
+    verify_pool_migrated,
+};
+
+pub fn process_flash_borrow(accounts: &[AccountInfo], args: FlashBorrowIxArgs) -> ProgramResult {
+    let (accounts, pool, fee) = verify_flash_borrow(accounts, &args)?;
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            token_program: accounts.lst_token_program,
+            from: accounts.pool_reserves,
+            to: accounts.dst_lst_acc,
+            authority: accounts.pool_state,
+            mint: accounts.lst_mint,
+        },
+        args.amount,
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
+    )?;
+
+    let flash_loan_record_pda = pool.flash_loan_record_pda();
+    allocate_invoke_signed(
+        AllocateAccounts {
+            allocate: accounts.flash_loan_record,
+        },
+        AllocateIxArgs {
+            space: space_to_u64(FLASH_LOAN_RECORD_SIZE)?,
+        },
+        &[flash_loan_record_pda.signer_seeds().as_slice()],
+    )?;
+    assign_invoke_signed(
+        AssignAccounts {
+            assign: accounts.flash_loan_record,
+        },
+        AssignIxArgs {
+            owner: s_controller_lib::program::ID,
+        },
+        &[flash_loan_record_pda.signer_seeds().as_slice()],
+    )?;
+    transfer_direct_increment(
+        TransferAccounts {
+            from: accounts.pool_state,
+            to: accounts.flash_loan_record,
+        },
+        1,
+    )?;
+
+    let mut flash_loan_record_data = accounts.flash_loan_record.try_borrow_mut_data()?;
+    let flash_loan_record = try_flash_loan_record_mut(&mut flash_loan_record_data)?;
+    flash_loan_record.amount = args.amount;
+    flash_loan_record.fee = fee;
+    flash_loan_record.lst_index = args.lst_index;
+
+    // the pool is locked the same way it is during a rebalance
+    // until the succeeding FlashRepay instruction
+    let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
+    let pool_state = try_pool_state_mut(&mut pool_state_data)?;
+    U8BoolMut(&mut pool_state.is_rebalancing).set_true();
+
+    Ok(())
+}
+
+/// Returns (accounts, fee)
+fn verify_flash_borrow<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    FlashBorrowIxArgs { lst_index, amount }: &FlashBorrowIxArgs,
+) -> Result<(FlashBorrowAccounts<'a, 'info>, PoolKey, u64), ProgramError> {
+    if *amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(*lst_index)?;
+
+    let actual: FlashBorrowAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = FlashBorrowFreeArgs {
+        lst_index,
+        dst_lst_acc: *actual.dst_lst_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    flash_borrow_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    flash_borrow_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let lst_state = lst_state_list[lst_index]; // lst_index checked above
+    verify_lst_output_not_disabled(&lst_state)?;
+
+    if *amount > token_account_balance(actual.pool_reserves)? {
+        return Err(SControllerError::NotEnoughLiquidity.into());
+    }
+
+    let fee = calc_flash_loan_fee(*amount, lst_state.flash_loan_fee_bps)?;
+
+    verify_has_succeeding_flash_repay_ix(
+        actual.instructions,
+        pool.pool_state,
+        *actual.lst_mint.key,
+    )?;
+
+    Ok((actual, pool, fee))
+}
+
+fn verify_has_succeeding_flash_repay_ix(
+    instructions_sysvar: &AccountInfo,
+    pool_state: Pubkey,
+    lst_mint: Pubkey,
+) -> Result<(), ProgramError> {
+    let mut next_ix_idx: usize = load_current_index_checked(instructions_sysvar)?.into();
+    loop {
+        next_ix_idx = next_ix_idx
+            .checked_add(1)
+            .ok_or(SControllerError::MathError)?;
+        let next_ix = load_instruction_at_checked(next_ix_idx, instructions_sysvar)
+            .map_err(|_| SControllerError::NoSucceedingFlashRepay)?;
+        if is_flash_repay_ix(&next_ix, pool_state, lst_mint) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+const FLASH_REPAY_IX_LST_MINT_INDEX: usize = 1;
+
+const FLASH_REPAY_IX_POOL_STATE_INDEX: usize = 5;
+
+/// The FlashRepay must be for the same pool as the FlashBorrow,
+/// otherwise a loan from this pool could be closed out by repaying another pool
+fn is_flash_repay_ix(ix: &Instruction, pool_state: Pubkey, lst_mint: Pubkey) -> bool {
+    let discm = match ix.data.first() {
+        Some(d) => d,
+        None => return false,
+    };
+    if *discm != FLASH_REPAY_IX_DISCM {
+        return false;
+    }
+    if ix.program_id != s_controller_lib::program::ID {
+        return false;
+    }
+    let pool_state_account = match ix.accounts.get(FLASH_REPAY_IX_POOL_STATE_INDEX) {
+        Some(a) => a,
+        None => return false,
+    };
+    if pool_state_account.pubkey != pool_state {
+        return false;
+    }
+    let lst_mint_account = match ix.accounts.get(FLASH_REPAY_IX_LST_MINT_INDEX) {
+        Some(a) => a,
+        None => return false,
+    };
+    lst_mint_account.pubkey == lst_mint
+}
@@ -0,0 +1,169 @@
+use s_controller_interface::{
+    flash_repay_verify_account_keys, flash_repay_verify_account_privileges, FlashLoanRecord,
+    FlashRepayAccounts, SControllerError, FLASH_REPAY_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_flash_loan_protocol_fees, index_to_u32, try_flash_loan_record, try_pool_state,
+    try_pool_state_mut, FlashLoanEvent, FlashRepayFreeArgs, LstTransferFee, PoolConfigAccount,
+    PoolKey, PoolStateAccount, SControllerEvent, U8BoolMut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use sanctum_token_lib::{transfer_checked_decimal_agnostic_invoke, TransferCheckedAccounts};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_is_rebalancing, verify_lst_sol_val_calc_cpi, verify_pool_key,
+        VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
+
+pub fn process_flash_repay(accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, pool, cpi, lst_index) = verify_flash_repay(accounts)?;
+
+    let (amount, fee) = {
+        let flash_loan_record_data = accounts.flash_loan_record.try_borrow_data()?;
+        let FlashLoanRecord { amount, fee, .. } = try_flash_loan_record(&flash_loan_record_data)?;
+        (*amount, *fee)
+    };
+
+    let trading_protocol_fee_bps = accounts.pool_state.trading_protocol_fee_bps()?;
+    let protocol_fees = calc_flash_loan_protocol_fees(fee, trading_protocol_fee_bps)?;
+    // protocol fees are transferred to the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    let total_repay = amount.checked_add(fee).ok_or(SControllerError::MathError)?;
+    let to_pool_reserves = if protocol_fees_as_lp {
+        total_repay
+    } else {
+        total_repay
+            .checked_sub(protocol_fees)
+            .ok_or(SControllerError::MathError)?
+    };
+    // the borrower pays the transfer fee so that the reserves are made whole
+    let to_pool_reserves_transfer_amount =
+        LstTransferFee::try_from_mint(accounts.lst_mint, Clock::get()?.epoch)?
+            .amt_bef_fee(to_pool_reserves)?;
+
+    transfer_checked_decimal_agnostic_invoke(
+        TransferCheckedAccounts {
+            from: accounts.src_lst_acc,
+            to: accounts.pool_reserves,
+            token_program: accounts.lst_token_program,
+            authority: accounts.signer,
+            mint: accounts.lst_mint,
+        },
+        to_pool_reserves_transfer_amount,
+    )?;
+    if !protocol_fees_as_lp && protocol_fees > 0 {
+        transfer_checked_decimal_agnostic_invoke(
+            TransferCheckedAccounts {
+                from: accounts.src_lst_acc,
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.lst_token_program,
+                authority: accounts.signer,
+                mint: accounts.lst_mint,
+            },
+            protocol_fees,
+        )?;
+    }
+
+    // braces to limit scope of pool_state_data borrow
+    {
+        let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_data)?;
+        U8BoolMut(&mut pool_state.is_rebalancing).set_false();
+    }
+
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(accounts),
+        cpi,
+        lst_index,
+    )?;
+
+    SControllerEvent::FlashLoan(FlashLoanEvent {
+        lst_index: index_to_u32(lst_index)?,
+        amount,
+        fee,
+        protocol_fees,
+        total_sol_value: accounts.pool_state.total_sol_value()?,
+    })
+    .emit()?;
+
+    if protocol_fees_as_lp && protocol_fees > 0 {
+        let protocol_fees_sol_value = cpi.invoke_lst_to_sol(protocol_fees)?.get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index,
+                protocol_fees,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to: accounts.pool_state,
+        close: accounts.flash_loan_record,
+    })
+}
+
+fn verify_flash_repay<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<
+    (
+        FlashRepayAccounts<'a, 'info>,
+        PoolKey,
+        SolValueCalculatorCpi<'a, 'info>,
+        usize,
+    ),
+    ProgramError,
+> {
+    let actual: FlashRepayAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = FlashRepayFreeArgs {
+        signer: *actual.signer.key,
+        src_lst_acc: *actual.src_lst_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        flash_loan_record: actual.flash_loan_record,
+        lst_mint: actual.lst_mint,
+    };
+    let (expected, lst_index) = free_args.resolve_for_pool(&pool)?;
+
+    flash_repay_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    flash_repay_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_is_rebalancing(pool_state)?;
+
+    let accounts_suffix_slice = accounts
+        .get(FLASH_REPAY_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let lst_cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_index,
+    )?;
+
+    Ok((actual, pool, lst_cpi, lst_index))
+}
@@ -3,8 +3,9 @@ use s_controller_interface::{
     PoolState, SControllerError,
 };
 use s_controller_lib::{
-    try_pool_state_mut, InitializeFreeArgs, CURRENT_PROGRAM_VERS, DEFAULT_LP_PROTOCOL_FEE_BPS,
-    DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS, POOL_STATE_SIZE,
+    program::POOL_STATE_ID, try_pool_state_mut, InitializeFreeArgs, PoolKey, CURRENT_PROGRAM_VERS,
+    DEFAULT_LP_PROTOCOL_FEE_BPS, DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+    POOL_STATE_SIZE,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -22,7 +23,7 @@ use spl_token_2022::instruction::AuthorityType;
 use system_program_interface::CreateAccountAccounts;
 
 pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
-    let accounts = verify_initialize(accounts)?;
+    let (accounts, pool) = verify_initialize(accounts)?;
 
     init_rent_exempt_account_invoke_signed(
         CreateAccountAccounts {
@@ -33,10 +34,7 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
             space: POOL_STATE_SIZE,
             owner: s_controller_lib::program::ID,
         },
-        &[&[
-            s_controller_lib::program::POOL_STATE_SEED,
-            &[s_controller_lib::program::POOL_STATE_BUMP],
-        ]],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
 
     // need to drop borrow of pool_state before mint CPIs
@@ -50,7 +48,7 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
             version: CURRENT_PROGRAM_VERS,
             is_disabled: 0,
             is_rebalancing: 0,
-            padding: [0],
+            timelock_delay: 0,
             admin: *accounts.authority.key,
             rebalance_authority: *accounts.authority.key,
             protocol_fee_beneficiary: *accounts.authority.key,
@@ -83,21 +81,28 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
 
 fn verify_initialize<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<InitializeAccounts<'a, 'info>, ProgramError> {
+) -> Result<(InitializeAccounts<'a, 'info>, PoolKey), ProgramError> {
     let actual: InitializeAccounts = load_accounts(accounts)?;
+    // pool state is not initialized yet, so the pool is determined by the account passed in:
+    // any other pool state is checked against the LP token mint's pool state PDA below
+    let pool = if *actual.pool_state.key == POOL_STATE_ID {
+        PoolKey::DEFAULT
+    } else {
+        PoolKey::new(*actual.lp_token_mint.key)
+    };
 
     let expected = InitializeFreeArgs {
         payer: *actual.payer.key,
         lp_token_mint: *actual.lp_token_mint.key,
     }
-    .resolve();
+    .resolve_for_pool(&pool);
 
     initialize_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     initialize_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     verify_lp_token_mint(actual.lp_token_mint)?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
 
 fn verify_lp_token_mint(lp_token_mint: &AccountInfo<'_>) -> Result<(), ProgramError> {
@@ -0,0 +1,101 @@
+use s_controller_interface::{
+    migrate_verify_account_keys, migrate_verify_account_privileges, MigrateAccounts,
+    SControllerError,
+};
+use s_controller_lib::{
+    migrate_lst_state_list_in_place, migrated_lst_state_list_len, try_pool_state,
+    try_pool_state_mut, MigrateFreeArgs, U8Bool, CURRENT_PROGRAM_VERS, MIGRATABLE_PROGRAM_VERS,
+    POOL_STATE_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::ResizableAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+use crate::verify::verify_pool_key;
+
+pub fn process_migrate(accounts: &[AccountInfo]) -> ProgramResult {
+    let MigrateAccounts {
+        admin,
+        pool_state,
+        lst_state_list,
+        system_program: _,
+    } = verify_migrate(accounts)?;
+
+    // PoolState's layout is unchanged from MIGRATABLE_PROGRAM_VERS,
+    // this only does anything if fields are appended to it in the future
+    let pool_state_len = pool_state.data_len();
+    if pool_state_len < POOL_STATE_SIZE {
+        extend_paid_by(pool_state, admin, POOL_STATE_SIZE - pool_state_len)?;
+    }
+
+    let lst_state_list_len = lst_state_list.data_len();
+    let new_lst_state_list_len = migrated_lst_state_list_len(lst_state_list_len)?;
+    extend_paid_by(
+        lst_state_list,
+        admin,
+        new_lst_state_list_len - lst_state_list_len,
+    )?;
+    migrate_lst_state_list_in_place(&mut lst_state_list.try_borrow_mut_data()?)?;
+
+    let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
+    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+    pool_state.version = CURRENT_PROGRAM_VERS;
+
+    Ok(())
+}
+
+/// Resizes `account` and tops up its rent-exemption from `payer`
+fn extend_paid_by(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    additional_bytes: usize,
+) -> Result<(), ProgramError> {
+    if additional_bytes == 0 {
+        return Ok(());
+    }
+    let lamports_short = account.extend_by(additional_bytes)?;
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: payer,
+                to: account,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn verify_migrate<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<MigrateAccounts<'me, 'info>, ProgramError> {
+    let actual: MigrateAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = MigrateFreeArgs {
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    migrate_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    migrate_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_data = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_data)?;
+
+    if pool_state.version != MIGRATABLE_PROGRAM_VERS {
+        return Err(SControllerError::InvalidPoolVersion.into());
+    }
+    if U8Bool(pool_state.is_rebalancing).is_true() {
+        return Err(SControllerError::PoolRebalancing.into());
+    }
+
+    Ok(actual)
+}
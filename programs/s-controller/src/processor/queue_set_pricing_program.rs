@@ -0,0 +1,90 @@
+use s_controller_interface::{
+    queue_set_pricing_program_verify_account_keys,
+    queue_set_pricing_program_verify_account_privileges, QueueSetPricingProgramAccounts,
+    QueuedChange,
+};
+use s_controller_lib::{
+    calc_earliest_execution_slot, try_pool_state, PoolKey, QueueSetPricingProgramFreeArgs,
+    QueuedChangeKind,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    queued_change::{create_queued_change, CreateQueuedChangeAccounts},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pricing_program_is_program,
+    },
+};
+
+pub fn process_queue_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
+    let (
+        QueueSetPricingProgramAccounts {
+            admin: _,
+            payer,
+            new_pricing_program,
+            pool_state,
+            queued_change,
+            system_program: _,
+        },
+        pool,
+    ) = verify_queue_set_pricing_program(accounts)?;
+
+    let earliest_execution_slot = {
+        let pool_state_bytes = pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        calc_earliest_execution_slot(Clock::get()?.slot, pool_state.timelock_delay)?
+    };
+
+    let queued_pricing_program_pda = pool.queued_pricing_program_pda();
+    create_queued_change(
+        CreateQueuedChangeAccounts {
+            queued_change,
+            payer,
+        },
+        &[queued_pricing_program_pda.signer_seeds().as_slice()],
+        QueuedChange {
+            earliest_execution_slot,
+            kind: QueuedChangeKind::PricingProgram as u8,
+            bump: queued_pricing_program_pda.bump(),
+            new_timelock_delay: 0,
+            padding: Default::default(),
+            payer: *payer.key,
+            lst_mint: Pubkey::default(),
+            new_program: *new_pricing_program.key,
+        },
+    )
+}
+
+fn verify_queue_set_pricing_program<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(QueueSetPricingProgramAccounts<'me, 'info>, PoolKey), ProgramError> {
+    let actual: QueueSetPricingProgramAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = QueueSetPricingProgramFreeArgs {
+        payer: *actual.payer.key,
+        new_pricing_program: *actual.new_pricing_program.key,
+        pool_state_acc: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    queue_set_pricing_program_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    queue_set_pricing_program_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_pricing_program_is_program(actual.new_pricing_program)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok((actual, pool))
+}
@@ -0,0 +1,107 @@
+use s_controller_interface::{
+    queue_set_sol_value_calculator_verify_account_keys,
+    queue_set_sol_value_calculator_verify_account_privileges, QueueSetSolValueCalculatorAccounts,
+    QueueSetSolValueCalculatorIxArgs, QueuedChange,
+};
+use s_controller_lib::{
+    calc_earliest_execution_slot, index_to_usize, try_pool_state, PoolPda,
+    QueueSetSolValueCalculatorFreeArgs, QueuedChangeKind,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    queued_change::{create_queued_change, CreateQueuedChangeAccounts},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_sol_value_calculator_is_program,
+    },
+};
+
+pub fn process_queue_set_sol_value_calculator(
+    accounts: &[AccountInfo],
+    args: QueueSetSolValueCalculatorIxArgs,
+) -> ProgramResult {
+    let (
+        QueueSetSolValueCalculatorAccounts {
+            admin: _,
+            payer,
+            lst_mint,
+            new_sol_value_calculator,
+            pool_state,
+            lst_state_list: _,
+            queued_change,
+            system_program: _,
+        },
+        queued_change_pda,
+    ) = verify_queue_set_sol_value_calculator(accounts, args)?;
+
+    let earliest_execution_slot = {
+        let pool_state_bytes = pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        calc_earliest_execution_slot(Clock::get()?.slot, pool_state.timelock_delay)?
+    };
+
+    create_queued_change(
+        CreateQueuedChangeAccounts {
+            queued_change,
+            payer,
+        },
+        &[queued_change_pda.signer_seeds().as_slice()],
+        QueuedChange {
+            earliest_execution_slot,
+            kind: QueuedChangeKind::SolValueCalculator as u8,
+            bump: queued_change_pda.bump(),
+            new_timelock_delay: 0,
+            padding: Default::default(),
+            payer: *payer.key,
+            lst_mint: *lst_mint.key,
+            new_program: *new_sol_value_calculator.key,
+        },
+    )
+}
+
+/// Returns (accounts, queued_change_pda)
+fn verify_queue_set_sol_value_calculator<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    QueueSetSolValueCalculatorIxArgs { lst_index }: QueueSetSolValueCalculatorIxArgs,
+) -> Result<(QueueSetSolValueCalculatorAccounts<'me, 'info>, PoolPda), ProgramError> {
+    let lst_index = index_to_usize(lst_index)?;
+    let actual: QueueSetSolValueCalculatorAccounts = load_accounts(accounts)?;
+
+    let pool = verify_pool_key(actual.pool_state)?;
+    let queued_change_pda = pool.queued_sol_value_calculator_pda(*actual.lst_mint.key);
+    let free_args = QueueSetSolValueCalculatorFreeArgs {
+        payer: *actual.payer.key,
+        lst_index,
+        lst_mint: *actual.lst_mint.key,
+        new_sol_value_calculator: *actual.new_sol_value_calculator.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    // pool_state and lst_state_list identities checked by verify_account_keys below
+    let expected = free_args.resolve_with_pdas(
+        pool.pool_state,
+        pool.lst_state_list_pda().address,
+        queued_change_pda.address,
+    )?;
+
+    queue_set_sol_value_calculator_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    queue_set_sol_value_calculator_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_sol_value_calculator_is_program(actual.new_sol_value_calculator)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok((actual, queued_change_pda))
+}
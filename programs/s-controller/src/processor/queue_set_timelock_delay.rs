@@ -0,0 +1,83 @@
+use s_controller_interface::{
+    queue_set_timelock_delay_verify_account_keys,
+    queue_set_timelock_delay_verify_account_privileges, QueueSetTimelockDelayAccounts,
+    QueueSetTimelockDelayIxArgs, QueuedChange,
+};
+use s_controller_lib::{
+    calc_earliest_execution_slot, try_pool_state, PoolKey, QueueSetTimelockDelayFreeArgs,
+    QueuedChangeKind,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    queued_change::{create_queued_change, CreateQueuedChangeAccounts},
+    verify::verify_pool_key,
+};
+
+pub fn process_queue_set_timelock_delay(
+    accounts: &[AccountInfo],
+    QueueSetTimelockDelayIxArgs { timelock_delay }: QueueSetTimelockDelayIxArgs,
+) -> ProgramResult {
+    let (
+        QueueSetTimelockDelayAccounts {
+            admin: _,
+            payer,
+            pool_state,
+            queued_change,
+            system_program: _,
+        },
+        pool,
+    ) = verify_queue_set_timelock_delay(accounts)?;
+
+    // changes to the delay itself are subject to the current delay
+    let earliest_execution_slot = {
+        let pool_state_bytes = pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        calc_earliest_execution_slot(Clock::get()?.slot, pool_state.timelock_delay)?
+    };
+
+    let queued_timelock_delay_pda = pool.queued_timelock_delay_pda();
+    create_queued_change(
+        CreateQueuedChangeAccounts {
+            queued_change,
+            payer,
+        },
+        &[queued_timelock_delay_pda.signer_seeds().as_slice()],
+        QueuedChange {
+            earliest_execution_slot,
+            kind: QueuedChangeKind::TimelockDelay as u8,
+            bump: queued_timelock_delay_pda.bump(),
+            new_timelock_delay: timelock_delay,
+            padding: Default::default(),
+            payer: *payer.key,
+            lst_mint: Pubkey::default(),
+            new_program: Pubkey::default(),
+        },
+    )
+}
+
+fn verify_queue_set_timelock_delay<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(QueueSetTimelockDelayAccounts<'me, 'info>, PoolKey), ProgramError> {
+    let actual: QueueSetTimelockDelayAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = QueueSetTimelockDelayFreeArgs {
+        payer: *actual.payer.key,
+        pool_state_acc: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    queue_set_timelock_delay_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    queue_set_timelock_delay_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, pool))
+}
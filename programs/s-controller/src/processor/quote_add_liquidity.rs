@@ -0,0 +1,155 @@
+use s_controller_interface::{
+    quote_add_liquidity_verify_account_keys, PoolState, QuoteAddLiquidityAccounts,
+    QuoteAddLiquidityIxArgs, SControllerError, QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize, try_lst_state_list,
+    try_pool_state, CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult, LpTokenRateArgs,
+    LstTransferFee, QuoteLiquidityFreeArgs, SwapLiquidityReturnData,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::mint_supply;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    verify::{
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLpCpiAccounts,
+    },
+};
+
+use super::{simulate_sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+/// Computes the amounts AddLiquidity would result in and sets them as return data.
+///
+/// The LST's SOL value sync is applied to a copy of the pool's state,
+/// and the max share and pool loss checks made at the end of AddLiquidity are not simulated.
+pub fn process_quote_add_liquidity(
+    accounts: &[AccountInfo],
+    args: QuoteAddLiquidityIxArgs,
+) -> ProgramResult {
+    let (accounts, lst_index, lst_amount, lst_cpi, pricing_cpi) =
+        verify_quote_add_liquidity(accounts, args)?;
+
+    let (
+        PoolState {
+            total_sol_value: pool_total_sol_value,
+            lp_protocol_fee_bps,
+            ..
+        },
+        _lst_state,
+    ) = simulate_sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(accounts),
+        lst_cpi,
+        lst_index,
+    )?;
+
+    let lst_amount_received =
+        LstTransferFee::try_from_mint(accounts.lst_mint, Clock::get()?.epoch)?
+            .amt_after_fee(lst_amount)?;
+    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount_received)?.get_min();
+    let lst_amount_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+            amount: lst_amount_received,
+            sol_value: lst_amount_sol_value,
+        })?;
+    if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    let CalcAddLiquidityProtocolFeesResult {
+        to_reserves_lst_amount,
+        to_protocol_fees_lst_amount,
+    } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+        lst_amount: lst_amount_received,
+        lst_amount_sol_value,
+        lst_amount_sol_value_after_fees,
+        lp_protocol_fee_bps,
+    })?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value,
+        },
+        lst_amount_sol_value_after_fees,
+    )?;
+
+    if to_reserves_lst_amount == 0 || lp_tokens_to_mint == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lst_amount,
+        amount_out: lp_tokens_to_mint,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lst_amount_sol_value,
+        out_sol_value: lst_amount_sol_value_after_fees,
+    }
+    .set()
+}
+
+fn verify_quote_add_liquidity<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    QuoteAddLiquidityIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lst_amount,
+    }: QuoteAddLiquidityIxArgs,
+) -> Result<
+    (
+        QuoteAddLiquidityAccounts<'a, 'info>,
+        usize,
+        u64,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: QuoteAddLiquidityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = QuoteLiquidityFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_add_for_pool(&pool)?;
+
+    quote_add_liquidity_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    verify_lst_input_not_disabled(&lst_state_list[lst_index])?; // lst_index checked above
+
+    let accounts_suffix_slice = accounts
+        .get(QUOTE_ADD_LIQUIDITY_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((actual, lst_index, lst_amount, lst_cpi, pricing_cpi))
+}
@@ -0,0 +1,160 @@
+use s_controller_interface::{
+    quote_remove_liquidity_verify_account_keys, PoolState, QuoteRemoveLiquidityAccounts,
+    QuoteRemoveLiquidityIxArgs, SControllerError, QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_usize,
+    try_lst_state_list, try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs,
+    LstTransferFee, QuoteLiquidityFreeArgs, SwapLiquidityReturnData,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::{mint_supply, token_account_balance};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    verify::{
+        verify_lp_cpis, verify_lst_output_not_disabled, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLpCpiAccounts,
+    },
+};
+
+use super::{simulate_sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+/// Computes the amounts RemoveLiquidity would result in and sets them as return data.
+///
+/// The LST's SOL value sync is applied to a copy of the pool's state.
+pub fn process_quote_remove_liquidity(
+    accounts: &[AccountInfo],
+    args: QuoteRemoveLiquidityIxArgs,
+) -> ProgramResult {
+    let (accounts, lst_index, lp_token_amount, lst_cpi, pricing_cpi) =
+        verify_quote_remove_liquidity(accounts, args)?;
+
+    let (
+        PoolState {
+            total_sol_value: pool_total_sol_value,
+            lp_protocol_fee_bps,
+            ..
+        },
+        _lst_state,
+    ) = simulate_sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(accounts),
+        lst_cpi,
+        lst_index,
+    )?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_sol_value = calc_lp_tokens_sol_value(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value,
+        },
+        lp_token_amount,
+    )?;
+
+    let lp_tokens_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_redeem(PricingProgramIxArgs {
+            amount: lp_token_amount,
+            sol_value: lp_tokens_sol_value,
+        })?;
+    if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    let to_user_lst_amount = lst_cpi
+        .invoke_sol_to_lst(lp_tokens_sol_value_after_fees)?
+        .get_min();
+    let to_protocol_fees_lst_amount =
+        calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            to_user_lst_amount,
+            lp_protocol_fee_bps,
+        })?;
+
+    let to_user_lst_amount_received =
+        LstTransferFee::try_from_mint(accounts.lst_mint, Clock::get()?.epoch)?
+            .amt_after_fee(to_user_lst_amount)?;
+    if to_user_lst_amount_received == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let total_lst_out = to_user_lst_amount
+        .checked_add(to_protocol_fees_lst_amount)
+        .ok_or(SControllerError::MathError)?;
+    if total_lst_out > token_account_balance(accounts.pool_reserves)? {
+        return Err(SControllerError::NotEnoughLiquidity.into());
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lp_token_amount,
+        amount_out: to_user_lst_amount,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lp_tokens_sol_value,
+        out_sol_value: lp_tokens_sol_value_after_fees,
+    }
+    .set()
+}
+
+fn verify_quote_remove_liquidity<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    QuoteRemoveLiquidityIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+    }: QuoteRemoveLiquidityIxArgs,
+) -> Result<
+    (
+        QuoteRemoveLiquidityAccounts<'a, 'info>,
+        usize,
+        u64,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: QuoteRemoveLiquidityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = QuoteLiquidityFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_remove_for_pool(&pool)?;
+
+    quote_remove_liquidity_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    verify_lst_output_not_disabled(&lst_state_list[lst_index])?; // lst_index checked above
+
+    let accounts_suffix_slice = accounts
+        .get(QUOTE_REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((actual, lst_index, lp_token_amount, lst_cpi, pricing_cpi))
+}
@@ -0,0 +1,157 @@
+use s_controller_interface::{
+    quote_swap_exact_in_verify_account_keys, QuoteSwapExactInAccounts, QuoteSwapExactInIxArgs,
+    SControllerError, QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_swap_protocol_fees, index_to_usize, try_lst_state_list, try_pool_state,
+    CalcSwapProtocolFeesArgs, LstTransferFee, PoolStateAccount, QuoteSwapFreeArgs,
+    SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapLiquidityReturnData,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::token_account_balance;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
+    verify::{
+        verify_lst_input_not_disabled, verify_lst_output_not_disabled,
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_swap_cpis, verify_swap_not_same_lst, VerifySwapCpiAccounts,
+    },
+};
+
+/// Computes the amounts SwapExactIn would result in and sets them as return data.
+///
+/// SOL values are not synced since they do not affect the swap amounts,
+/// so the max share and pool loss checks made at the end of SwapExactIn are not simulated.
+pub fn process_quote_swap_exact_in(
+    accounts: &[AccountInfo],
+    args: QuoteSwapExactInIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        amount,
+        SrcDstLstSolValueCalculatorCpis {
+            src_lst: src_lst_cpi,
+            dst_lst: dst_lst_cpi,
+        },
+        pricing_cpi,
+    ) = verify_quote_swap_exact_in(accounts, args)?;
+
+    let epoch = Clock::get()?.epoch;
+    let src_lst_received =
+        LstTransferFee::try_from_mint(accounts.src_lst_mint, epoch)?.amt_after_fee(amount)?;
+    let in_sol_value = src_lst_cpi.invoke_lst_to_sol(src_lst_received)?.get_min();
+    if in_sol_value == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let out_sol_value = pricing_cpi.invoke_price_exact_in(PricingProgramIxArgs {
+        amount: src_lst_received,
+        sol_value: in_sol_value,
+    })?;
+    let dst_lst_out = dst_lst_cpi.invoke_sol_to_lst(out_sol_value)?.get_min();
+    let dst_lst_received =
+        LstTransferFee::try_from_mint(accounts.dst_lst_mint, epoch)?.amt_after_fee(dst_lst_out)?;
+    if dst_lst_received == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let trading_protocol_fee_bps = accounts.pool_state.trading_protocol_fee_bps()?;
+    let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
+        in_sol_value,
+        out_sol_value,
+        dst_lst_out,
+        trading_protocol_fee_bps,
+    })?;
+
+    let total_dst_lst_out = dst_lst_out
+        .checked_add(to_protocol_fees_lst_amount)
+        .ok_or(SControllerError::MathError)?;
+    if total_dst_lst_out > token_account_balance(accounts.dst_pool_reserves)? {
+        return Err(SControllerError::NotEnoughLiquidity.into());
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: amount,
+        amount_out: dst_lst_out,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value,
+        out_sol_value,
+    }
+    .set()
+}
+
+fn verify_quote_swap_exact_in<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    QuoteSwapExactInIxArgs {
+        src_lst_value_calc_accs,
+        dst_lst_value_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+    }: QuoteSwapExactInIxArgs,
+) -> Result<
+    (
+        QuoteSwapExactInAccounts<'a, 'info>,
+        u64,
+        SrcDstLstSolValueCalculatorCpis<'a, 'info>,
+        PricingProgramPriceSwapCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let src_lst_index = index_to_usize(src_lst_index)?;
+    let dst_lst_index = index_to_usize(dst_lst_index)?;
+
+    let actual: QuoteSwapExactInAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = QuoteSwapFreeArgs {
+        src_lst_index,
+        dst_lst_index,
+        src_lst_mint: actual.src_lst_mint,
+        dst_lst_mint: actual.dst_lst_mint,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    let expected = free_args.resolve_exact_in_for_pool(&pool)?;
+
+    quote_swap_exact_in_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    verify_swap_not_same_lst(actual.src_lst_mint, actual.dst_lst_mint)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    verify_lst_input_not_disabled(&lst_state_list[src_lst_index])?;
+    verify_lst_output_not_disabled(&lst_state_list[dst_lst_index])?;
+
+    let accounts_suffix_slice = accounts
+        .get(QUOTE_SWAP_EXACT_IN_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let (src_dst_cpis, pricing_cpi) = verify_swap_cpis(
+        VerifySwapCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        SrcDstLstValueCalcAccs {
+            src_lst_value_calc_accs,
+            dst_lst_value_calc_accs,
+        },
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+    )?;
+
+    Ok((actual, amount, src_dst_cpis, pricing_cpi))
+}
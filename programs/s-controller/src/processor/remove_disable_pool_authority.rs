@@ -14,7 +14,7 @@ use solana_program::{
 
 use crate::{
     list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
-    verify::verify_admin_or_disable_pool_authority,
+    verify::{verify_admin_or_disable_pool_authority, verify_pool_key},
 };
 
 pub fn process_remove_disable_pool_authority(
@@ -37,6 +37,7 @@ fn verify_remove_disable_pool_authority<'me, 'info>(
     RemoveDisablePoolAuthorityIxArgs { index }: RemoveDisablePoolAuthorityIxArgs,
 ) -> Result<(RemoveDisablePoolAuthorityAccounts<'me, 'info>, usize), ProgramError> {
     let actual: RemoveDisablePoolAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
     let index = index_to_usize(index)?;
 
     let free_args = RemoveDisablePoolAuthorityFreeArgs {
@@ -46,7 +47,7 @@ fn verify_remove_disable_pool_authority<'me, 'info>(
         pool_state_acc: actual.pool_state,
         disable_pool_authority_list: actual.disable_pool_authority_list,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     remove_disable_pool_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
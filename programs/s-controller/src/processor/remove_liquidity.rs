@@ -4,10 +4,11 @@ use s_controller_interface::{
     REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, PoolStateAccount,
-    RemoveLiquidityFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs,
+    LstTransferFee, PoolConfigAccount, PoolKey, PoolStateAccount, RemoveLiquidityEvent,
+    RemoveLiquidityFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs, SControllerEvent,
+    SwapLiquidityReturnData,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -17,15 +18,23 @@ use sanctum_token_lib::{
     TransferCheckedAccounts,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 use crate::{
     cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
-    verify::{verify_lp_cpis, verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts},
+    verify::{
+        verify_lp_cpis, verify_lst_output_not_disabled, verify_lsts_synced_for_lp_if_required,
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        VerifyLpCpiAccounts,
+    },
 };
 
-use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
 
 pub fn process_remove_liquidity(
     accounts: &[AccountInfo],
@@ -33,6 +42,7 @@ pub fn process_remove_liquidity(
 ) -> ProgramResult {
     let (
         accounts,
+        pool,
         RemoveLiquidityIxFullArgs {
             lst_index,
             amts:
@@ -48,6 +58,8 @@ pub fn process_remove_liquidity(
     let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
     sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
 
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
     let pool_total_sol_value = accounts.pool_state.total_sol_value()?;
     let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
     let lp_tokens_sol_value = calc_lp_tokens_sol_value(
@@ -78,10 +90,13 @@ pub fn process_remove_liquidity(
             lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
         })?;
 
-    if to_user_lst_amount == 0 {
+    let transfer_fee = LstTransferFee::try_from_mint(accounts.lst_mint, Clock::get()?.epoch)?;
+    let to_user_lst_amount_received = transfer_fee.amt_after_fee(to_user_lst_amount)?;
+
+    if to_user_lst_amount_received == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
-    if to_user_lst_amount < min_lst_out {
+    if to_user_lst_amount_received < min_lst_out {
         return Err(SControllerError::SlippageToleranceExceeded.into());
     }
 
@@ -104,22 +119,65 @@ pub fn process_remove_liquidity(
             mint: accounts.lst_mint,
         },
         to_user_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
 
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.lst_token_program,
-            from: accounts.pool_reserves,
-            authority: accounts.pool_state,
-            mint: accounts.lst_mint,
-        },
-        to_protocol_fees_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
-    )?;
+    // protocol fees are left in the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    if !protocol_fees_as_lp {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.lst_token_program,
+                from: accounts.pool_reserves,
+                authority: accounts.pool_state,
+                mint: accounts.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    SControllerEvent::RemoveLiquidity(RemoveLiquidityEvent {
+        lst_index: index_to_u32(lst_index)?,
+        lp_tokens_burnt: lp_token_amount,
+        lp_tokens_sol_value,
+        lst_out: to_user_lst_amount,
+        protocol_fees: to_protocol_fees_lst_amount,
+        total_sol_value: accounts.pool_state.total_sol_value()?,
+    })
+    .emit()?;
 
-    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)
+    if protocol_fees_as_lp && to_protocol_fees_lst_amount > 0 {
+        let protocol_fees_sol_value = lst_cpi
+            .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+            .get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index,
+                protocol_fees: to_protocol_fees_lst_amount,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lp_token_amount,
+        amount_out: to_user_lst_amount,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lp_tokens_sol_value,
+        out_sol_value: lp_tokens_sol_value_after_fees,
+    }
+    .set()
 }
 
 fn verify_remove_liquidity<'a, 'info>(
@@ -133,6 +191,7 @@ fn verify_remove_liquidity<'a, 'info>(
 ) -> Result<
     (
         RemoveLiquidityAccounts<'a, 'info>,
+        PoolKey,
         RemoveLiquidityIxFullArgs,
         SolValueCalculatorCpi<'a, 'info>,
         PricingProgramPriceLpCpi<'a, 'info>,
@@ -146,6 +205,7 @@ fn verify_remove_liquidity<'a, 'info>(
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: RemoveLiquidityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = RemoveLiquidityFreeArgs {
         lst_index,
@@ -156,15 +216,21 @@ fn verify_remove_liquidity<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     remove_liquidity_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     remove_liquidity_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let lst_state = lst_state_list[lst_index]; // lst_index checked above
+    verify_lst_output_not_disabled(&lst_state)?;
+
     let accounts_suffix_slice = accounts
         .get(REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..)
         .ok_or(ProgramError::NotEnoughAccountKeys)?;
@@ -178,6 +244,7 @@ fn verify_remove_liquidity<'a, 'info>(
 
     Ok((
         actual,
+        pool,
         RemoveLiquidityIxFullArgs {
             lst_index,
             amts: RemoveLiquidityIxAmts {
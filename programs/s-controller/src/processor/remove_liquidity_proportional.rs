@@ -0,0 +1,349 @@
+use s_controller_interface::{
+    remove_liquidity_proportional_verify_account_keys,
+    remove_liquidity_proportional_verify_account_privileges, remove_liquidity_verify_account_keys,
+    remove_liquidity_verify_account_privileges, RemoveLiquidityAccounts,
+    RemoveLiquidityProportionalAccounts, RemoveLiquidityProportionalEntry,
+    RemoveLiquidityProportionalIxArgs, SControllerError,
+    REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_remove_liquidity_proportional, index_to_u32, try_lst_state_list, try_pool_config,
+    try_pool_state, CalcRemoveLiquidityProportionalArgs, LstTransferFee, PoolConfigAccount,
+    PoolKey, PoolStateAccount, RemoveLiquidityFreeArgs, RemoveLiquidityProportionalEvent,
+    RemoveLiquidityProportionalFreeArgs, RemoveLiquidityProportionalLstAmts, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    burn_invoke, mint_supply, token_account_balance,
+    transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_lst_output_not_disabled, verify_lst_sol_val_calc_cpi,
+        verify_lsts_synced_for_lp_if_required, verify_not_rebalancing_and_not_disabled,
+        verify_pool_key, verify_pool_migrated, VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
+
+/// Number of accounts preceding each entry's SOL value calculator accounts:
+/// lst_mint, lst_token_program, pool_reserves, protocol_fee_accumulator, dst_lst_acc
+const REMOVE_LIQUIDITY_PROPORTIONAL_ENTRY_PREFIX_LEN: usize = 5;
+
+pub fn process_remove_liquidity_proportional(
+    accounts: &[AccountInfo],
+    args: RemoveLiquidityProportionalIxArgs,
+) -> ProgramResult {
+    let (accounts, pool, lp_token_amount, entries) =
+        verify_remove_liquidity_proportional(accounts, &args)?;
+
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let remove_liquidity_proportional_fee_bps = {
+        let pool_config_bytes = accounts.pool_config.try_borrow_data()?;
+        try_pool_config(&pool_config_bytes)?.remove_liquidity_proportional_fee_bps
+    };
+    let calc_args = CalcRemoveLiquidityProportionalArgs {
+        lp_token_amount,
+        lp_token_supply,
+        remove_liquidity_proportional_fee_bps,
+        lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
+    };
+
+    // calculate all amounts against the reserves before any transfers
+    let epoch = Clock::get()?.epoch;
+    let amts = entries
+        .iter()
+        .map(
+            |VerifiedRemoveLiquidityProportionalEntry {
+                 lst, min_lst_out, ..
+             }| {
+                let amts = calc_remove_liquidity_proportional(
+                    calc_args,
+                    token_account_balance(lst.pool_reserves)?,
+                )?;
+                let to_user_lst_amount_received =
+                    LstTransferFee::try_from_mint(lst.lst_mint, epoch)?
+                        .amt_after_fee(amts.to_user_lst_amount)?;
+                if to_user_lst_amount_received < *min_lst_out {
+                    return Err(SControllerError::SlippageToleranceExceeded.into());
+                }
+                Ok(amts)
+            },
+        )
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+    if amts.iter().all(|a| a.to_user_lst_amount == 0) {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    // protocol fees are left in the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+
+    burn_invoke(
+        BurnAccounts {
+            mint: accounts.lp_token_mint,
+            burn_from: accounts.src_lp_acc,
+            burn_from_authority: accounts.signer,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+    )?;
+
+    for (
+        VerifiedRemoveLiquidityProportionalEntry {
+            lst,
+            lst_index,
+            cpi,
+            ..
+        },
+        RemoveLiquidityProportionalLstAmts {
+            to_user_lst_amount,
+            to_protocol_fees_lst_amount,
+        },
+    ) in entries.iter().zip(amts.iter())
+    {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                to: lst.dst_lst_acc,
+                token_program: lst.lst_token_program,
+                from: lst.pool_reserves,
+                authority: accounts.pool_state,
+                mint: lst.lst_mint,
+            },
+            *to_user_lst_amount,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+
+        if !protocol_fees_as_lp {
+            transfer_checked_decimal_agnostic_invoke_signed(
+                TransferCheckedAccounts {
+                    to: lst.protocol_fee_accumulator,
+                    token_program: lst.lst_token_program,
+                    from: lst.pool_reserves,
+                    authority: accounts.pool_state,
+                    mint: lst.lst_mint,
+                },
+                *to_protocol_fees_lst_amount,
+                &[pool.pool_state_pda().signer_seeds().as_slice()],
+            )?;
+        }
+
+        sync_sol_value_unchecked(SyncSolValueUncheckedAccounts::from(*lst), *cpi, *lst_index)?;
+    }
+
+    let total_sol_value = accounts.pool_state.total_sol_value()?;
+    for (
+        VerifiedRemoveLiquidityProportionalEntry { lst_index, .. },
+        RemoveLiquidityProportionalLstAmts {
+            to_user_lst_amount,
+            to_protocol_fees_lst_amount,
+        },
+    ) in entries.iter().zip(amts.iter())
+    {
+        SControllerEvent::RemoveLiquidityProportional(RemoveLiquidityProportionalEvent {
+            lst_index: index_to_u32(*lst_index)?,
+            lp_tokens_burnt: lp_token_amount,
+            lst_out: *to_user_lst_amount,
+            protocol_fees: *to_protocol_fees_lst_amount,
+            total_sol_value,
+        })
+        .emit()?;
+    }
+
+    if protocol_fees_as_lp {
+        for (
+            VerifiedRemoveLiquidityProportionalEntry { lst_index, cpi, .. },
+            RemoveLiquidityProportionalLstAmts {
+                to_protocol_fees_lst_amount,
+                ..
+            },
+        ) in entries.iter().zip(amts)
+        {
+            if to_protocol_fees_lst_amount == 0 {
+                continue;
+            }
+            let protocol_fees_sol_value = cpi
+                .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+                .get_min();
+            mint_protocol_fees_as_lp_unchecked(
+                &pool,
+                MintProtocolFeesAsLpUncheckedAccounts {
+                    lp_token_mint: accounts.lp_token_mint,
+                    protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                    lp_token_program: accounts.lp_token_program,
+                    pool_state: accounts.pool_state,
+                },
+                MintProtocolFeesAsLpArgs {
+                    lst_index: *lst_index,
+                    protocol_fees: to_protocol_fees_lst_amount,
+                    protocol_fees_sol_value,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+struct VerifiedRemoveLiquidityProportionalEntry<'a, 'info> {
+    lst_index: usize,
+    min_lst_out: u64,
+    /// Reuses RemoveLiquidityAccounts to verify the LST's accounts
+    lst: RemoveLiquidityAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+}
+
+fn verify_remove_liquidity_proportional<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    RemoveLiquidityProportionalIxArgs {
+        lp_token_amount,
+        entries,
+    }: &RemoveLiquidityProportionalIxArgs,
+) -> Result<
+    (
+        RemoveLiquidityProportionalAccounts<'a, 'info>,
+        PoolKey,
+        u64,
+        Vec<VerifiedRemoveLiquidityProportionalEntry<'a, 'info>>,
+    ),
+    ProgramError,
+> {
+    if *lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let actual: RemoveLiquidityProportionalAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = RemoveLiquidityProportionalFreeArgs {
+        signer: *actual.signer.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    remove_liquidity_proportional_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_proportional_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    if entries.len() != lst_state_list.len() {
+        return Err(SControllerError::InvalidRemoveLiquidityProportionalEntries.into());
+    }
+    for lst_state in lst_state_list {
+        verify_lst_output_not_disabled(lst_state)?;
+    }
+
+    let mut accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_PROPORTIONAL_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let verified_entries = entries
+        .iter()
+        .enumerate()
+        .map(|(lst_index, entry)| {
+            let (verified, rest) = verify_remove_liquidity_proportional_entry(
+                actual,
+                &pool,
+                accounts_suffix_slice,
+                lst_index,
+                entry,
+            )?;
+            accounts_suffix_slice = rest;
+            Ok(verified)
+        })
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+
+    Ok((actual, pool, *lp_token_amount, verified_entries))
+}
+
+/// Returns the verified entry and the remaining accounts suffix slice
+/// following this entry's accounts
+fn verify_remove_liquidity_proportional_entry<'a, 'info>(
+    actual: RemoveLiquidityProportionalAccounts<'a, 'info>,
+    pool: &PoolKey,
+    accounts_suffix_slice: &'a [AccountInfo<'info>],
+    lst_index: usize,
+    RemoveLiquidityProportionalEntry {
+        lst_value_calc_accs,
+        min_lst_out,
+    }: &RemoveLiquidityProportionalEntry,
+) -> Result<
+    (
+        VerifiedRemoveLiquidityProportionalEntry<'a, 'info>,
+        &'a [AccountInfo<'info>],
+    ),
+    ProgramError,
+> {
+    let entry_accounts_len =
+        REMOVE_LIQUIDITY_PROPORTIONAL_ENTRY_PREFIX_LEN + usize::from(*lst_value_calc_accs);
+    if accounts_suffix_slice.len() < entry_accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (entry_accounts, rest) = accounts_suffix_slice.split_at(entry_accounts_len);
+
+    let lst_actual = RemoveLiquidityAccounts {
+        signer: actual.signer,
+        lst_mint: &entry_accounts[0],
+        dst_lst_acc: &entry_accounts[4],
+        src_lp_acc: actual.src_lp_acc,
+        lp_token_mint: actual.lp_token_mint,
+        protocol_fee_accumulator: &entry_accounts[3],
+        lst_token_program: &entry_accounts[1],
+        lp_token_program: actual.lp_token_program,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        pool_reserves: &entry_accounts[2],
+        pool_config: actual.pool_config,
+        protocol_fee_lp_acc: actual.protocol_fee_lp_acc,
+    };
+    let lst_expected = RemoveLiquidityFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        dst_lst_acc: *lst_actual.dst_lst_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: lst_actual.lst_mint,
+    }
+    .resolve_for_pool(pool)?;
+    remove_liquidity_verify_account_keys(lst_actual, lst_expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_verify_account_privileges(lst_actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts::from(lst_actual),
+        &entry_accounts[REMOVE_LIQUIDITY_PROPORTIONAL_ENTRY_PREFIX_LEN..],
+        lst_index,
+    )?;
+
+    Ok((
+        VerifiedRemoveLiquidityProportionalEntry {
+            lst_index,
+            min_lst_out: *min_lst_out,
+            lst: lst_actual,
+            cpi,
+        },
+        rest,
+    ))
+}
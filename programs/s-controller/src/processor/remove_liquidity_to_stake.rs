@@ -0,0 +1,278 @@
+use s_controller_interface::{
+    remove_liquidity_to_stake_verify_account_keys,
+    remove_liquidity_to_stake_verify_account_privileges, RemoveLiquidityToStakeAccounts,
+    RemoveLiquidityToStakeIxArgs, SControllerError, REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs,
+    PoolConfigAccount, PoolKey, PoolStateAccount, RemoveLiquidityEvent, RemoveLiquidityIxAmts,
+    RemoveLiquidityIxFullArgs, RemoveLiquidityToStakeFreeArgs, SControllerEvent,
+    SwapLiquidityReturnData,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    burn_invoke, mint_supply, token_account_balance,
+    transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{
+        PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi, WithdrawStakeCpi,
+        WithdrawStakeCpiExpectedKeys,
+    },
+    verify::{
+        verify_lp_cpis, verify_lst_output_not_disabled, verify_lsts_synced_for_lp_if_required,
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        VerifyLpCpiAccounts,
+    },
+};
+
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
+
+pub fn process_remove_liquidity_to_stake(
+    accounts: &[AccountInfo],
+    args: RemoveLiquidityToStakeIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        pool,
+        RemoveLiquidityIxFullArgs {
+            lst_index,
+            amts:
+                RemoveLiquidityIxAmts {
+                    lp_token_amount,
+                    min_lst_out,
+                },
+        },
+        withdraw_stake_cpi,
+        lst_cpi,
+        pricing_cpi,
+    ) = verify_remove_liquidity_to_stake(accounts, args)?;
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    verify_lsts_synced_for_lp_if_required(accounts.pool_config, accounts.lst_state_list)?;
+
+    let pool_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_sol_value = calc_lp_tokens_sol_value(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value,
+        },
+        lp_token_amount,
+    )?;
+
+    let lp_tokens_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_redeem(PricingProgramIxArgs {
+            amount: lp_token_amount,
+            sol_value: lp_tokens_sol_value,
+        })?;
+    if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    let to_user_lst_amount = lst_cpi
+        .invoke_sol_to_lst(lp_tokens_sol_value_after_fees)?
+        .get_min();
+    let to_protocol_fees_lst_amount =
+        calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            to_user_lst_amount,
+            lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
+        })?;
+
+    if to_user_lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    if to_user_lst_amount < min_lst_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    burn_invoke(
+        BurnAccounts {
+            mint: accounts.lp_token_mint,
+            burn_from: accounts.src_lp_acc,
+            burn_from_authority: accounts.signer,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+    )?;
+
+    // protocol fees are left in the pool reserves and minted as LP tokens below instead
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+    if !protocol_fees_as_lp {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.lst_token_program,
+                from: accounts.pool_reserves,
+                authority: accounts.pool_state,
+                mint: accounts.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+
+    // The stake pool burns the LST (and transfers its withdrawal fee) directly
+    // from pool_reserves, so it must not take more than the priced amount
+    let pool_reserves_balance_before = token_account_balance(accounts.pool_reserves)?;
+    withdraw_stake_cpi.invoke_signed_withdraw_stake(&pool, to_user_lst_amount)?;
+    let lst_burnt = pool_reserves_balance_before
+        .checked_sub(token_account_balance(accounts.pool_reserves)?)
+        .ok_or(SControllerError::MathError)?;
+    if lst_burnt > to_user_lst_amount {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    SControllerEvent::RemoveLiquidityToStake(RemoveLiquidityEvent {
+        lst_index: index_to_u32(lst_index)?,
+        lp_tokens_burnt: lp_token_amount,
+        lp_tokens_sol_value,
+        lst_out: to_user_lst_amount,
+        protocol_fees: to_protocol_fees_lst_amount,
+        total_sol_value: accounts.pool_state.total_sol_value()?,
+    })
+    .emit()?;
+
+    if protocol_fees_as_lp && to_protocol_fees_lst_amount > 0 {
+        let protocol_fees_sol_value = lst_cpi
+            .invoke_lst_to_sol(to_protocol_fees_lst_amount)?
+            .get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index,
+                protocol_fees: to_protocol_fees_lst_amount,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: lp_token_amount,
+        amount_out: to_user_lst_amount,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value: lp_tokens_sol_value,
+        out_sol_value: lp_tokens_sol_value_after_fees,
+    }
+    .set()
+}
+
+fn verify_remove_liquidity_to_stake<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    RemoveLiquidityToStakeIxArgs {
+        withdraw_stake_accs,
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+        min_lst_out,
+    }: RemoveLiquidityToStakeIxArgs,
+) -> Result<
+    (
+        RemoveLiquidityToStakeAccounts<'a, 'info>,
+        PoolKey,
+        RemoveLiquidityIxFullArgs,
+        WithdrawStakeCpi<'a, 'info>,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+    ),
+    ProgramError,
+> {
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: RemoveLiquidityToStakeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = RemoveLiquidityToStakeFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    remove_liquidity_to_stake_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_to_stake_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let lst_state = lst_state_list[lst_index]; // lst_index checked above
+    verify_lst_output_not_disabled(&lst_state)?;
+
+    let withdraw_stake_accounts_suffix_slice_end: usize = withdraw_stake_accs.into();
+    let withdraw_stake_accounts_suffix_slice = accounts
+        .get(
+            REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN
+                ..REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN
+                    + withdraw_stake_accounts_suffix_slice_end,
+        )
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let withdraw_stake_cpi =
+        WithdrawStakeCpi::from_account_suffix_slice(withdraw_stake_accounts_suffix_slice)?;
+    withdraw_stake_cpi.verify_keys(WithdrawStakeCpiExpectedKeys {
+        user_stake_authority: actual.signer.key,
+        user_transfer_authority: actual.pool_state.key,
+        burn_from: actual.pool_reserves.key,
+        pool_mint: actual.lst_mint.key,
+    })?;
+
+    let accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_TO_STAKE_IX_ACCOUNTS_LEN + withdraw_stake_accounts_suffix_slice_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((
+        actual,
+        pool,
+        RemoveLiquidityIxFullArgs {
+            lst_index,
+            amts: RemoveLiquidityIxAmts {
+                lp_token_amount,
+                min_lst_out,
+            },
+        },
+        withdraw_stake_cpi,
+        lst_cpi,
+        pricing_cpi,
+    ))
+}
@@ -3,9 +3,7 @@ use s_controller_interface::{
     RemoveLstAccounts, RemoveLstIxArgs, SControllerError,
 };
 use s_controller_lib::{
-    index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED, PROTOCOL_FEE_BUMP, PROTOCOL_FEE_SEED},
-    try_lst_state_list, try_pool_state, RemoveLstFreeArgs,
+    index_to_usize, try_lst_state_list, try_pool_state, PoolKey, RemoveLstFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -19,11 +17,11 @@ use solana_program::{
 
 use crate::{
     list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
-    verify::verify_not_rebalancing_and_not_disabled,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated},
 };
 
 pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> ProgramResult {
-    let (accounts, lst_index) = verify_remove_lst(accounts, args)?;
+    let (accounts, pool, lst_index) = verify_remove_lst(accounts, args)?;
     close_token_account_invoke_signed(
         CloseTokenAccountAccounts {
             account_to_close: accounts.protocol_fee_accumulator,
@@ -31,7 +29,7 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
             token_program: accounts.lst_token_program,
             refund_rent_to: accounts.refund_rent_to,
         },
-        &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]],
+        &[pool.protocol_fee_pda().signer_seeds().as_slice()],
     )?;
 
     close_token_account_invoke_signed(
@@ -41,7 +39,7 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
             token_program: accounts.lst_token_program,
             refund_rent_to: accounts.refund_rent_to,
         },
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
     // Gotta put direct account lamport manipuation last after token program CPIs
     // because CPIs' lamport balance checks are broken:
@@ -58,10 +56,11 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
 fn verify_remove_lst<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     RemoveLstIxArgs { lst_index }: RemoveLstIxArgs,
-) -> Result<(RemoveLstAccounts<'a, 'info>, usize), ProgramError> {
+) -> Result<(RemoveLstAccounts<'a, 'info>, PoolKey, usize), ProgramError> {
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: RemoveLstAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = RemoveLstFreeArgs {
         lst_index,
@@ -70,13 +69,14 @@ fn verify_remove_lst<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     remove_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     remove_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
     let lst_state_list_acc_data = actual.lst_state_list.try_borrow_data()?;
@@ -90,5 +90,5 @@ fn verify_remove_lst<'a, 'info>(
         return Err(SControllerError::LstStillHasValue.into());
     }
 
-    Ok((actual, lst_index))
+    Ok((actual, pool, lst_index))
 }
@@ -0,0 +1,56 @@
+use s_controller_interface::{
+    remove_protocol_fee_beneficiary_verify_account_keys,
+    remove_protocol_fee_beneficiary_verify_account_privileges, ProtocolFeeBeneficiaryShare,
+    RemoveProtocolFeeBeneficiaryAccounts, RemoveProtocolFeeBeneficiaryIxArgs,
+};
+use s_controller_lib::{index_to_usize, RemoveProtocolFeeBeneficiaryFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
+    verify::verify_pool_key,
+};
+
+pub fn process_remove_protocol_fee_beneficiary(
+    accounts: &[AccountInfo],
+    args: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> ProgramResult {
+    let (checked, index) = verify_remove_protocol_fee_beneficiary(accounts, args)?;
+
+    remove_from_list_pda::<ProtocolFeeBeneficiaryShare>(
+        RemoveFromListPdaAccounts {
+            list_pda: checked.protocol_fee_beneficiary_list,
+            refund_rent_to: checked.refund_rent_to,
+        },
+        index,
+    )
+}
+
+fn verify_remove_protocol_fee_beneficiary<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    RemoveProtocolFeeBeneficiaryIxArgs { index }: RemoveProtocolFeeBeneficiaryIxArgs,
+) -> Result<(RemoveProtocolFeeBeneficiaryAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: RemoveProtocolFeeBeneficiaryAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+    let index = index_to_usize(index)?;
+
+    let free_args = RemoveProtocolFeeBeneficiaryFreeArgs {
+        index,
+        refund_rent_to: *actual.refund_rent_to.key,
+        pool_state_acc: actual.pool_state,
+        protocol_fee_beneficiary_list: actual.protocol_fee_beneficiary_list,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    remove_protocol_fee_beneficiary_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_protocol_fee_beneficiary_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, index))
+}
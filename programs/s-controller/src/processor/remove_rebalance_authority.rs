@@ -0,0 +1,56 @@
+use s_controller_interface::{
+    remove_rebalance_authority_verify_account_keys,
+    remove_rebalance_authority_verify_account_privileges, RebalanceAuthorityBudget,
+    RemoveRebalanceAuthorityAccounts, RemoveRebalanceAuthorityIxArgs,
+};
+use s_controller_lib::{index_to_usize, RemoveRebalanceAuthorityFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
+    verify::verify_pool_key,
+};
+
+pub fn process_remove_rebalance_authority(
+    accounts: &[AccountInfo],
+    args: RemoveRebalanceAuthorityIxArgs,
+) -> ProgramResult {
+    let (checked, index) = verify_remove_rebalance_authority(accounts, args)?;
+
+    remove_from_list_pda::<RebalanceAuthorityBudget>(
+        RemoveFromListPdaAccounts {
+            list_pda: checked.rebalance_authority_list,
+            refund_rent_to: checked.refund_rent_to,
+        },
+        index,
+    )
+}
+
+fn verify_remove_rebalance_authority<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    RemoveRebalanceAuthorityIxArgs { index }: RemoveRebalanceAuthorityIxArgs,
+) -> Result<(RemoveRebalanceAuthorityAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: RemoveRebalanceAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+    let index = index_to_usize(index)?;
+
+    let free_args = RemoveRebalanceAuthorityFreeArgs {
+        index,
+        refund_rent_to: *actual.refund_rent_to.key,
+        pool_state_acc: actual.pool_state,
+        rebalance_authority_list: actual.rebalance_authority_list,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    remove_rebalance_authority_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_rebalance_authority_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, index))
+}
@@ -1,7 +1,7 @@
 use s_controller_interface::{
     set_admin_verify_account_keys, set_admin_verify_account_privileges, SetAdminAccounts,
 };
-use s_controller_lib::{try_pool_state_mut, SetAdminFreeArgs};
+use s_controller_lib::{try_pending_authorities_mut, PoolKey, SetAdminFreeArgs};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -9,29 +9,47 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
+use crate::{
+    pending_authorities::{
+        create_pending_authorities_if_not_exist, CreatePendingAuthoritiesAccounts,
+    },
+    verify::verify_pool_key,
+};
+
+/// Only proposes the new admin, who then needs to sign AcceptAdmin for the change to take effect
 pub fn process_set_admin(accounts: &[AccountInfo]) -> ProgramResult {
-    let checked = verify_set_admin(accounts)?;
+    let (checked, pool) = verify_set_admin(accounts)?;
+
+    create_pending_authorities_if_not_exist(
+        &pool,
+        CreatePendingAuthoritiesAccounts {
+            pending_authorities: checked.pending_authorities,
+            payer: checked.payer,
+        },
+    )?;
 
-    let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_data)?;
-    pool_state.admin = *checked.new_admin.key;
+    let mut pending_authorities_data = checked.pending_authorities.try_borrow_mut_data()?;
+    let pending_authorities = try_pending_authorities_mut(&mut pending_authorities_data)?;
+    pending_authorities.admin = *checked.new_admin.key;
 
     Ok(())
 }
 
 fn verify_set_admin<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<SetAdminAccounts<'a, 'info>, ProgramError> {
+) -> Result<(SetAdminAccounts<'a, 'info>, PoolKey), ProgramError> {
     let actual: SetAdminAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let expected = SetAdminFreeArgs {
+        payer: *actual.payer.key,
         new_admin: *actual.new_admin.key,
         pool_state: actual.pool_state,
     }
-    .resolve()?;
+    .resolve_for_pool(&pool)?;
 
     set_admin_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     set_admin_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
@@ -0,0 +1,72 @@
+use s_controller_interface::{
+    set_lp_sync_requirement_verify_account_keys, set_lp_sync_requirement_verify_account_privileges,
+    SetLpSyncRequirementAccounts, SetLpSyncRequirementIxArgs,
+};
+use s_controller_lib::{
+    try_pool_config_mut, try_pool_state, PoolKey, SetLpSyncRequirementFreeArgs, U8BoolMut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    pool_config::{create_pool_config_if_not_exist, CreatePoolConfigAccounts},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
+
+pub fn process_set_lp_sync_requirement(
+    accounts: &[AccountInfo],
+    SetLpSyncRequirementIxArgs {
+        lp_requires_synced_lsts,
+        lp_synced_lsts_dust_threshold,
+    }: SetLpSyncRequirementIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_set_lp_sync_requirement(accounts)?;
+
+    create_pool_config_if_not_exist(
+        &pool,
+        CreatePoolConfigAccounts {
+            pool_config: checked.pool_config,
+            payer: checked.payer,
+        },
+    )?;
+
+    let mut pool_config_bytes = checked.pool_config.try_borrow_mut_data()?;
+    let pool_config = try_pool_config_mut(&mut pool_config_bytes)?;
+    let mut flag = U8BoolMut(&mut pool_config.lp_requires_synced_lsts);
+    if lp_requires_synced_lsts {
+        flag.set_true();
+    } else {
+        flag.set_false();
+    }
+    pool_config.lp_synced_lsts_dust_threshold = lp_synced_lsts_dust_threshold;
+
+    Ok(())
+}
+
+fn verify_set_lp_sync_requirement<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<(SetLpSyncRequirementAccounts<'a, 'info>, PoolKey), ProgramError> {
+    let actual: SetLpSyncRequirementAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SetLpSyncRequirementFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    set_lp_sync_requirement_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lp_sync_requirement_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok((actual, pool))
+}
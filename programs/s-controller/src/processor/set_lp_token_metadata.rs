@@ -0,0 +1,230 @@
+use s_controller_interface::{
+    set_lp_token_metadata_verify_account_keys, set_lp_token_metadata_verify_account_privileges,
+    SetLpTokenMetadataAccounts, SetLpTokenMetadataIxArgs,
+};
+use s_controller_lib::{
+    mpl_create_metadata_account_v3_ix_data, mpl_update_metadata_account_v2_ix_data, try_pool_state,
+    LpTokenMetadataProgram, PoolKey, SetLpTokenMetadataFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::{
+    instruction::{initialize, update_field},
+    state::{Field, TokenMetadata},
+};
+use system_program_interface::{transfer_invoke, TransferAccounts, TransferIxArgs};
+
+use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key};
+
+pub fn process_set_lp_token_metadata(
+    accounts: &[AccountInfo],
+    args: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let (checked, pool, metadata_program) = verify_set_lp_token_metadata(accounts)?;
+
+    match metadata_program {
+        LpTokenMetadataProgram::TokenMetadataExtension => {
+            set_token_metadata_extension(checked, &pool, args)
+        }
+        LpTokenMetadataProgram::MplTokenMetadata => set_mpl_metadata(checked, &pool, &args),
+    }
+}
+
+/// Initializes the token metadata extension on the token-2022 LP token mint
+/// if it does not exist yet, else updates the fields that changed.
+///
+/// The mint is topped up from `payer` to remain rent-exempt beforehand,
+/// since token-2022 reallocs it without transferring lamports.
+fn set_token_metadata_extension(
+    accounts: SetLpTokenMetadataAccounts,
+    pool: &PoolKey,
+    SetLpTokenMetadataIxArgs { name, symbol, uri }: SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let lp_token_mint = accounts.lp_token_mint;
+    let mint_len = lp_token_mint.data_len();
+    let existing = {
+        let mint_data = lp_token_mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&mint_data)?
+            .get_variable_len_extension::<TokenMetadata>()
+            .ok()
+    };
+
+    let ixs = match existing {
+        None => {
+            let new_metadata = TokenMetadata {
+                update_authority: Some(pool.pool_state).try_into()?,
+                mint: *lp_token_mint.key,
+                name: name.clone(),
+                symbol: symbol.clone(),
+                uri: uri.clone(),
+                additional_metadata: vec![],
+            };
+            top_up_rent(&accounts, mint_len + new_metadata.tlv_size_of()?)?;
+            vec![initialize(
+                accounts.metadata_program.key,
+                lp_token_mint.key,
+                &pool.pool_state,
+                lp_token_mint.key,
+                &pool.pool_state,
+                name,
+                symbol,
+                uri,
+            )]
+        }
+        Some(mut metadata) => {
+            let existing_tlv_size = metadata.tlv_size_of()?;
+            let mut ixs = Vec::with_capacity(3);
+            let mut max_len = mint_len;
+            for (field, value, current) in [
+                (Field::Name, name, metadata.name.clone()),
+                (Field::Symbol, symbol, metadata.symbol.clone()),
+                (Field::Uri, uri, metadata.uri.clone()),
+            ] {
+                if value == current {
+                    continue;
+                }
+                // each update_field() reallocs the mint, so the mint must be
+                // rent-exempt at the largest of the intermediate sizes
+                metadata.update(field.clone(), value.clone());
+                max_len = max_len.max(mint_len - existing_tlv_size + metadata.tlv_size_of()?);
+                ixs.push(update_field(
+                    accounts.metadata_program.key,
+                    lp_token_mint.key,
+                    &pool.pool_state,
+                    field,
+                    value,
+                ));
+            }
+            top_up_rent(&accounts, max_len)?;
+            ixs
+        }
+    };
+
+    for ix in ixs {
+        invoke_signed(
+            &ix,
+            &[
+                lp_token_mint.clone(),
+                accounts.pool_state.clone(),
+                accounts.metadata_program.clone(),
+            ],
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+    Ok(())
+}
+
+/// Transfers enough lamports from `payer` to the LP token mint
+/// for it to be rent-exempt at `new_len`
+fn top_up_rent(accounts: &SetLpTokenMetadataAccounts, new_len: usize) -> ProgramResult {
+    let lamports_short = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(accounts.lp_token_mint.lamports());
+    if lamports_short > 0 {
+        transfer_invoke(
+            TransferAccounts {
+                from: accounts.payer,
+                to: accounts.lp_token_mint,
+            },
+            TransferIxArgs {
+                lamports: lamports_short,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Creates the Tokenkeg LP token mint's metaplex metadata account
+/// if it does not exist yet, else updates it.
+///
+/// The pool state PDA is both the mint authority and the metadata's update authority.
+fn set_mpl_metadata(
+    accounts: SetLpTokenMetadataAccounts,
+    pool: &PoolKey,
+    args: &SetLpTokenMetadataIxArgs,
+) -> ProgramResult {
+    let metadata_meta = AccountMeta::new(*accounts.metadata.key, false);
+    let pool_state_signer_meta = AccountMeta::new_readonly(pool.pool_state, true);
+    let ix = if accounts.metadata.data_is_empty() {
+        Instruction {
+            program_id: *accounts.metadata_program.key,
+            accounts: vec![
+                metadata_meta,
+                AccountMeta::new_readonly(*accounts.lp_token_mint.key, false),
+                pool_state_signer_meta.clone(),
+                AccountMeta::new(*accounts.payer.key, true),
+                pool_state_signer_meta,
+                AccountMeta::new_readonly(*accounts.system_program.key, false),
+            ],
+            data: mpl_create_metadata_account_v3_ix_data(args)?,
+        }
+    } else {
+        Instruction {
+            program_id: *accounts.metadata_program.key,
+            accounts: vec![metadata_meta, pool_state_signer_meta],
+            data: mpl_update_metadata_account_v2_ix_data(args)?,
+        }
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.metadata.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.metadata_program.clone(),
+        ],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
+    )
+}
+
+fn verify_set_lp_token_metadata<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<
+    (
+        SetLpTokenMetadataAccounts<'a, 'info>,
+        PoolKey,
+        LpTokenMetadataProgram,
+    ),
+    ProgramError,
+> {
+    let actual: SetLpTokenMetadataAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SetLpTokenMetadataFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+        lp_token_mint: actual.lp_token_mint,
+    }
+    .resolve_for_pool(&pool)?;
+
+    set_lp_token_metadata_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lp_token_metadata_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    // lp_token_program checked against lp_token_mint's owner by resolve
+    let metadata_program =
+        LpTokenMetadataProgram::try_from_lp_token_program(actual.lp_token_program.key)?;
+
+    Ok((actual, pool, metadata_program))
+}
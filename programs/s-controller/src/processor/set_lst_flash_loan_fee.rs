@@ -0,0 +1,69 @@
+use s_controller_interface::{
+    set_lst_flash_loan_fee_verify_account_keys, set_lst_flash_loan_fee_verify_account_privileges,
+    SControllerError, SetLstFlashLoanFeeAccounts, SetLstFlashLoanFeeIxArgs,
+};
+use s_controller_lib::{
+    index_to_usize, try_lst_state_list_mut, try_pool_state, SetLstFlashLoanFeeFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::verify::{
+    verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+};
+
+pub fn process_set_lst_flash_loan_fee(
+    accounts: &[AccountInfo],
+    args: SetLstFlashLoanFeeIxArgs,
+) -> ProgramResult {
+    let (accounts, lst_index, flash_loan_fee_bps) = verify_set_lst_flash_loan_fee(accounts, args)?;
+
+    let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
+    let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
+
+    // lst_index checked in verify
+    lst_state_list[lst_index].flash_loan_fee_bps = flash_loan_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_lst_flash_loan_fee<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstFlashLoanFeeIxArgs {
+        index,
+        flash_loan_fee_bps,
+    }: SetLstFlashLoanFeeIxArgs,
+) -> Result<(SetLstFlashLoanFeeAccounts<'me, 'info>, usize, u16), ProgramError> {
+    let lst_index = index_to_usize(index)?;
+
+    let actual: SetLstFlashLoanFeeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = SetLstFlashLoanFeeFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    set_lst_flash_loan_fee_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_lst_flash_loan_fee_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    if flash_loan_fee_bps > BPS_DENOMINATOR {
+        return Err(SControllerError::FeeTooHigh.into());
+    }
+
+    Ok((actual, lst_index, flash_loan_fee_bps))
+}
@@ -0,0 +1,64 @@
+use s_controller_interface::{
+    set_pool_rebalance_authority_budget_verify_account_keys,
+    set_pool_rebalance_authority_budget_verify_account_privileges,
+    SetPoolRebalanceAuthorityBudgetAccounts, SetPoolRebalanceAuthorityBudgetIxArgs,
+};
+use s_controller_lib::{
+    try_pool_config_mut, try_pool_state, PoolKey, SetPoolRebalanceAuthorityBudgetFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    pool_config::{create_pool_config_if_not_exist, CreatePoolConfigAccounts},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
+
+pub fn process_set_pool_rebalance_authority_budget(
+    accounts: &[AccountInfo],
+    SetPoolRebalanceAuthorityBudgetIxArgs { epoch_budget }: SetPoolRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_set_pool_rebalance_authority_budget(accounts)?;
+
+    create_pool_config_if_not_exist(
+        &pool,
+        CreatePoolConfigAccounts {
+            pool_config: checked.pool_config,
+            payer: checked.payer,
+        },
+    )?;
+
+    let mut pool_config_bytes = checked.pool_config.try_borrow_mut_data()?;
+    let pool_config = try_pool_config_mut(&mut pool_config_bytes)?;
+    pool_config.rebalance_authority_epoch_budget = epoch_budget;
+
+    Ok(())
+}
+
+fn verify_set_pool_rebalance_authority_budget<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<(SetPoolRebalanceAuthorityBudgetAccounts<'a, 'info>, PoolKey), ProgramError> {
+    let actual: SetPoolRebalanceAuthorityBudgetAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SetPoolRebalanceAuthorityBudgetFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    set_pool_rebalance_authority_budget_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_pool_rebalance_authority_budget_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    Ok((actual, pool))
+}
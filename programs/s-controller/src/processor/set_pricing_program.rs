@@ -10,7 +10,10 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pricing_program_is_program};
+use crate::verify::{
+    verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pricing_program_is_program,
+    verify_timelock_not_active,
+};
 
 pub fn process_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
     let SetPricingProgramAccounts {
@@ -31,12 +34,13 @@ fn verify_set_pricing_program<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
 ) -> Result<SetPricingProgramAccounts<'me, 'info>, ProgramError> {
     let actual: SetPricingProgramAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = SetPricingProgramFreeArgs {
         new_pricing_program: *actual.new_pricing_program.key,
         pool_state_acc: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     set_pricing_program_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -49,6 +53,7 @@ fn verify_set_pricing_program<'me, 'info>(
     let pool_state = try_pool_state(&pool_state_bytes)?;
 
     verify_not_rebalancing_and_not_disabled(pool_state)?;
+    verify_timelock_not_active(pool_state)?;
 
     Ok(actual)
 }
@@ -11,7 +11,7 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key};
 
 pub fn process_set_protocol_fee(
     accounts: &[AccountInfo],
@@ -46,11 +46,12 @@ fn verify_set_protocol_fee<'a, 'info>(
     }: SetProtocolFeeIxArgs,
 ) -> Result<(SetProtocolFeeAccounts<'a, 'info>, SetProtocolFeeIxArgs), ProgramError> {
     let actual: SetProtocolFeeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = SetProtocolFeeFreeArgs {
         pool_state: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     set_protocol_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     set_protocol_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_protocol_fee_beneficiary_verify_account_keys,
     set_protocol_fee_beneficiary_verify_account_privileges, SetProtocolFeeBeneficiaryAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetProtocolFeeBeneficiaryFreeArgs};
+use s_controller_lib::{
+    try_pending_authorities_mut, try_pool_state, PoolKey, SetProtocolFeeBeneficiaryFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -10,28 +12,45 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{
+    pending_authorities::{
+        create_pending_authorities_if_not_exist, CreatePendingAuthoritiesAccounts,
+    },
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
 
+/// Only proposes the new beneficiary,
+/// who then needs to sign AcceptProtocolFeeBeneficiary for the change to take effect
 pub fn process_set_protocol_fee_beneficiary(accounts: &[AccountInfo]) -> ProgramResult {
-    let checked = verify_set_protocol_fee_beneficiary(accounts)?;
+    let (checked, pool) = verify_set_protocol_fee_beneficiary(accounts)?;
+
+    create_pending_authorities_if_not_exist(
+        &pool,
+        CreatePendingAuthoritiesAccounts {
+            pending_authorities: checked.pending_authorities,
+            payer: checked.payer,
+        },
+    )?;
 
-    let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_data)?;
-    pool_state.protocol_fee_beneficiary = *checked.new_beneficiary.key;
+    let mut pending_authorities_data = checked.pending_authorities.try_borrow_mut_data()?;
+    let pending_authorities = try_pending_authorities_mut(&mut pending_authorities_data)?;
+    pending_authorities.protocol_fee_beneficiary = *checked.new_beneficiary.key;
 
     Ok(())
 }
 
 fn verify_set_protocol_fee_beneficiary<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<SetProtocolFeeBeneficiaryAccounts<'a, 'info>, ProgramError> {
+) -> Result<(SetProtocolFeeBeneficiaryAccounts<'a, 'info>, PoolKey), ProgramError> {
     let actual: SetProtocolFeeBeneficiaryAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let expected = SetProtocolFeeBeneficiaryFreeArgs {
+        payer: *actual.payer.key,
         new_beneficiary: *actual.new_beneficiary.key,
         pool_state: actual.pool_state,
     }
-    .resolve()?;
+    .resolve_for_pool(&pool)?;
 
     set_protocol_fee_beneficiary_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -42,5 +61,5 @@ fn verify_set_protocol_fee_beneficiary<'a, 'info>(
     let pool_state = try_pool_state(&pool_state_bytes)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
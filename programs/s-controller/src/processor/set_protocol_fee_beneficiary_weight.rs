@@ -0,0 +1,58 @@
+use s_controller_interface::{
+    set_protocol_fee_beneficiary_weight_verify_account_keys,
+    set_protocol_fee_beneficiary_weight_verify_account_privileges,
+    SetProtocolFeeBeneficiaryWeightAccounts, SetProtocolFeeBeneficiaryWeightIxArgs,
+};
+use s_controller_lib::{
+    index_to_usize, try_protocol_fee_beneficiary_list_mut, verify_protocol_fee_beneficiary_weights,
+    SetProtocolFeeBeneficiaryWeightFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::verify::verify_pool_key;
+
+pub fn process_set_protocol_fee_beneficiary_weight(
+    accounts: &[AccountInfo],
+    args: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> ProgramResult {
+    let (checked, index) = verify_set_protocol_fee_beneficiary_weight(accounts, args)?;
+
+    let mut list_data = checked
+        .protocol_fee_beneficiary_list
+        .try_borrow_mut_data()?;
+    let list = try_protocol_fee_beneficiary_list_mut(&mut list_data)?;
+    // index checked by resolve() in verify
+    list[index].weight_bps = args.weight_bps;
+
+    verify_protocol_fee_beneficiary_weights(list)?;
+
+    Ok(())
+}
+
+fn verify_set_protocol_fee_beneficiary_weight<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetProtocolFeeBeneficiaryWeightIxArgs { index, .. }: SetProtocolFeeBeneficiaryWeightIxArgs,
+) -> Result<(SetProtocolFeeBeneficiaryWeightAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: SetProtocolFeeBeneficiaryWeightAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+    let index = index_to_usize(index)?;
+
+    let free_args = SetProtocolFeeBeneficiaryWeightFreeArgs {
+        index,
+        pool_state_acc: actual.pool_state,
+        protocol_fee_beneficiary_list: actual.protocol_fee_beneficiary_list,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    set_protocol_fee_beneficiary_weight_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_protocol_fee_beneficiary_weight_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, index))
+}
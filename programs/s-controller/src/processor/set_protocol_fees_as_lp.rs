@@ -0,0 +1,76 @@
+use s_controller_interface::{
+    set_protocol_fees_as_lp_verify_account_keys, set_protocol_fees_as_lp_verify_account_privileges,
+    SControllerError, SetProtocolFeesAsLpAccounts, SetProtocolFeesAsLpIxArgs,
+};
+use s_controller_lib::{
+    try_pool_config_mut, try_pool_state, PoolKey, SetProtocolFeesAsLpFreeArgs, U8BoolMut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    pool_config::{create_pool_config_if_not_exist, CreatePoolConfigAccounts},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
+
+pub fn process_set_protocol_fees_as_lp(
+    accounts: &[AccountInfo],
+    SetProtocolFeesAsLpIxArgs {
+        protocol_fees_as_lp,
+    }: SetProtocolFeesAsLpIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_set_protocol_fees_as_lp(accounts, protocol_fees_as_lp)?;
+
+    create_pool_config_if_not_exist(
+        &pool,
+        CreatePoolConfigAccounts {
+            pool_config: checked.pool_config,
+            payer: checked.payer,
+        },
+    )?;
+
+    let mut pool_config_bytes = checked.pool_config.try_borrow_mut_data()?;
+    let pool_config = try_pool_config_mut(&mut pool_config_bytes)?;
+    let mut flag = U8BoolMut(&mut pool_config.protocol_fees_as_lp);
+    if protocol_fees_as_lp {
+        flag.set_true();
+    } else {
+        flag.set_false();
+    }
+
+    Ok(())
+}
+
+fn verify_set_protocol_fees_as_lp<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    protocol_fees_as_lp: bool,
+) -> Result<(SetProtocolFeesAsLpAccounts<'a, 'info>, PoolKey), ProgramError> {
+    let actual: SetProtocolFeesAsLpAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SetProtocolFeesAsLpFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    set_protocol_fees_as_lp_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_protocol_fees_as_lp_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    // fee-charging instructions would fail to mint to it otherwise
+    if protocol_fees_as_lp && actual.protocol_fee_lp_acc.data_is_empty() {
+        return Err(SControllerError::ProtocolFeeLpAccountNotCreated.into());
+    }
+
+    Ok((actual, pool))
+}
@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_rebalance_authority_verify_account_keys, set_rebalance_authority_verify_account_privileges,
     SControllerError, SetRebalanceAuthorityAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetRebalanceAuthorityFreeArgs};
+use s_controller_lib::{
+    try_pending_authorities_mut, try_pool_state, PoolKey, SetRebalanceAuthorityFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -10,29 +12,46 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{
+    pending_authorities::{
+        create_pending_authorities_if_not_exist, CreatePendingAuthoritiesAccounts,
+    },
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
 
+/// Only proposes the new rebalance authority,
+/// who then needs to sign AcceptRebalanceAuthority for the change to take effect
 pub fn process_set_rebalance_authority(accounts: &[AccountInfo]) -> ProgramResult {
-    let accounts = verify_set_rebalance_authority(accounts)?;
+    let (accounts, pool) = verify_set_rebalance_authority(accounts)?;
+
+    create_pending_authorities_if_not_exist(
+        &pool,
+        CreatePendingAuthoritiesAccounts {
+            pending_authorities: accounts.pending_authorities,
+            payer: accounts.payer,
+        },
+    )?;
 
-    let mut pool_state_bytes = accounts.pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+    let mut pending_authorities_bytes = accounts.pending_authorities.try_borrow_mut_data()?;
+    let pending_authorities = try_pending_authorities_mut(&mut pending_authorities_bytes)?;
 
-    pool_state.rebalance_authority = *accounts.new_rebalance_authority.key;
+    pending_authorities.rebalance_authority = *accounts.new_rebalance_authority.key;
 
     Ok(())
 }
 
 fn verify_set_rebalance_authority<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<SetRebalanceAuthorityAccounts<'a, 'info>, ProgramError> {
+) -> Result<(SetRebalanceAuthorityAccounts<'a, 'info>, PoolKey), ProgramError> {
     let actual: SetRebalanceAuthorityAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let expected = SetRebalanceAuthorityFreeArgs {
+        payer: *actual.payer.key,
         signer: *actual.signer.key,
         new_rebalance_authority: *actual.new_rebalance_authority.key,
     }
-    .resolve();
+    .resolve_for_pool(&pool);
 
     set_rebalance_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -49,5 +68,5 @@ fn verify_set_rebalance_authority<'a, 'info>(
         return Err(SControllerError::UnauthorizedSetRebalanceAuthoritySigner.into());
     }
 
-    Ok(actual)
+    Ok((actual, pool))
 }
@@ -0,0 +1,55 @@
+use s_controller_interface::{
+    set_rebalance_authority_budget_verify_account_keys,
+    set_rebalance_authority_budget_verify_account_privileges, SetRebalanceAuthorityBudgetAccounts,
+    SetRebalanceAuthorityBudgetIxArgs,
+};
+use s_controller_lib::{
+    index_to_usize, try_rebalance_authority_list_mut, SetRebalanceAuthorityBudgetFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::verify::verify_pool_key;
+
+pub fn process_set_rebalance_authority_budget(
+    accounts: &[AccountInfo],
+    args: SetRebalanceAuthorityBudgetIxArgs,
+) -> ProgramResult {
+    let (checked, index) = verify_set_rebalance_authority_budget(accounts, args)?;
+
+    let mut list_data = checked.rebalance_authority_list.try_borrow_mut_data()?;
+    let list = try_rebalance_authority_list_mut(&mut list_data)?;
+    // index checked by resolve() in verify
+    // amount already spent this epoch is kept,
+    // so lowering the budget below it blocks the authority for the rest of the epoch
+    list[index].epoch_budget = args.epoch_budget;
+
+    Ok(())
+}
+
+fn verify_set_rebalance_authority_budget<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetRebalanceAuthorityBudgetIxArgs { index, .. }: SetRebalanceAuthorityBudgetIxArgs,
+) -> Result<(SetRebalanceAuthorityBudgetAccounts<'me, 'info>, usize), ProgramError> {
+    let actual: SetRebalanceAuthorityBudgetAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+    let index = index_to_usize(index)?;
+
+    let free_args = SetRebalanceAuthorityBudgetFreeArgs {
+        index,
+        pool_state_acc: actual.pool_state,
+        rebalance_authority_list: actual.rebalance_authority_list,
+    };
+    let expected = free_args.resolve_for_pool(&pool)?;
+
+    set_rebalance_authority_budget_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_rebalance_authority_budget_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok((actual, index))
+}
@@ -0,0 +1,65 @@
+use s_controller_interface::{
+    set_referral_fee_verify_account_keys, set_referral_fee_verify_account_privileges,
+    SControllerError, SetReferralFeeAccounts, SetReferralFeeIxArgs,
+};
+use s_controller_lib::{try_pool_config_mut, try_pool_state, PoolKey, SetReferralFeeFreeArgs};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    pool_config::{create_pool_config_if_not_exist, CreatePoolConfigAccounts},
+    verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key},
+};
+
+pub fn process_set_referral_fee(
+    accounts: &[AccountInfo],
+    SetReferralFeeIxArgs { new_fee_bps }: SetReferralFeeIxArgs,
+) -> ProgramResult {
+    let (checked, pool) = verify_set_referral_fee(accounts, new_fee_bps)?;
+
+    create_pool_config_if_not_exist(
+        &pool,
+        CreatePoolConfigAccounts {
+            pool_config: checked.pool_config,
+            payer: checked.payer,
+        },
+    )?;
+
+    let mut pool_config_bytes = checked.pool_config.try_borrow_mut_data()?;
+    let pool_config = try_pool_config_mut(&mut pool_config_bytes)?;
+    pool_config.referral_fee_bps = new_fee_bps;
+
+    Ok(())
+}
+
+fn verify_set_referral_fee<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    new_fee_bps: u16,
+) -> Result<(SetReferralFeeAccounts<'a, 'info>, PoolKey), ProgramError> {
+    let actual: SetReferralFeeAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SetReferralFeeFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(&pool)?;
+
+    set_referral_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_referral_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    if new_fee_bps > BPS_DENOMINATOR {
+        return Err(SControllerError::FeeTooHigh.into());
+    }
+
+    Ok((actual, pool))
+}
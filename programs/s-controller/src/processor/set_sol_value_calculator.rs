@@ -15,7 +15,10 @@ use solana_program::{
 
 use crate::{
     cpi::SolValueCalculatorCpi,
-    verify::{verify_not_rebalancing_and_not_disabled, verify_sol_value_calculator_is_program},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_sol_value_calculator_is_program, verify_timelock_not_active,
+    },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
@@ -30,7 +33,10 @@ pub fn process_set_sol_value_calculator(
         let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
         let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
         // lst_index checked in verify
-        lst_state_list[lst_index].sol_value_calculator = *cpi.program.key;
+        let lst_state = &mut lst_state_list[lst_index];
+        lst_state.sol_value_calculator = *cpi.program.key;
+        // rates returned by the old calculator should not trip the circuit breaker
+        lst_state.last_sol_value_rate = 0;
     }
 
     sync_sol_value_unchecked(
@@ -53,6 +59,7 @@ fn verify_set_sol_value_calculator<'a, 'info>(
 > {
     let lst_index = index_to_usize(lst_index)?;
     let actual: SetSolValueCalculatorAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = SetSolValueCalculatorFreeArgs {
         lst_index,
@@ -60,7 +67,7 @@ fn verify_set_sol_value_calculator<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     set_sol_value_calculator_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -69,7 +76,9 @@ fn verify_set_sol_value_calculator<'a, 'info>(
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
+    verify_timelock_not_active(pool_state)?;
 
     let accounts_suffix_slice = accounts
         .get(SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN..)
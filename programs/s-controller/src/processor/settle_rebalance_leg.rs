@@ -0,0 +1,120 @@
+use s_controller_interface::{
+    settle_rebalance_leg_verify_account_keys, settle_rebalance_leg_verify_account_privileges,
+    SControllerError, SettleRebalanceLegAccounts, SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_u32, try_pool_state, try_rebalance_record,
+    try_rebalance_record_unsettled_dst_lst_indexes_mut, PoolStateAccount, SControllerEvent,
+    SettleRebalanceLegEvent, SettleRebalanceLegFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_is_rebalancing, verify_lst_sol_val_calc_cpi, verify_pool_key,
+        VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+pub fn process_settle_rebalance_leg(accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, cpi, dst_lst_index, position) = verify_settle_rebalance_leg(accounts)?;
+
+    sync_sol_value_unchecked(
+        SyncSolValueUncheckedAccounts::from(accounts),
+        cpi,
+        dst_lst_index,
+    )?;
+
+    remove_unsettled_dst_lst_index(accounts.rebalance_record, position)?;
+
+    SControllerEvent::SettleRebalanceLeg(SettleRebalanceLegEvent {
+        dst_lst_index: index_to_u32(dst_lst_index)?,
+        total_sol_value: accounts.pool_state.total_sol_value()?,
+    })
+    .emit()
+}
+
+/// Removes the entry at `position` from the rebalance record's list of unsettled legs.
+///
+/// Order of the list does not matter, so the last entry is moved into `position`
+/// before shrinking the account. No lamports are refunded since the
+/// rebalance record is not rent-exempt and is closed by EndRebalance
+fn remove_unsettled_dst_lst_index(
+    rebalance_record: &AccountInfo,
+    position: usize,
+) -> Result<(), ProgramError> {
+    {
+        let mut rebalance_record_data = rebalance_record.try_borrow_mut_data()?;
+        let unsettled =
+            try_rebalance_record_unsettled_dst_lst_indexes_mut(&mut rebalance_record_data)?;
+        let last = unsettled
+            .len()
+            .checked_sub(1)
+            .ok_or(SControllerError::RebalanceLegNotFound)?;
+        unsettled.swap(position, last);
+    }
+    let new_len = rebalance_record
+        .data_len()
+        .checked_sub(std::mem::size_of::<u32>())
+        .ok_or(SControllerError::MathError)?;
+    rebalance_record.realloc(new_len, false)
+}
+
+/// Returns (accounts, dst lst calculator cpi, dst_lst_index, position on unsettled list)
+fn verify_settle_rebalance_leg<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<
+    (
+        SettleRebalanceLegAccounts<'a, 'info>,
+        SolValueCalculatorCpi<'a, 'info>,
+        usize,
+        usize,
+    ),
+    ProgramError,
+> {
+    let actual: SettleRebalanceLegAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let free_args = SettleRebalanceLegFreeArgs {
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        rebalance_record: actual.rebalance_record,
+        dst_lst_mint: actual.dst_lst_mint,
+    };
+    let (mut expected, dst_lst_index, position) = free_args.resolve_for_pool(&pool)?;
+    // legs can only be settled by the rebalance authority that started the rebalance,
+    // which may be one on the rebalance authority list
+    {
+        let rebalance_record_bytes = actual.rebalance_record.try_borrow_data()?;
+        let rebalance_record = try_rebalance_record(&rebalance_record_bytes)?;
+        expected.rebalance_authority = rebalance_record.rebalance_authority;
+    }
+
+    settle_rebalance_leg_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    settle_rebalance_leg_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_is_rebalancing(pool_state)?;
+
+    let accounts_suffix_slice = accounts
+        .get(SETTLE_REBALANCE_LEG_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let dst_lst_cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        dst_lst_index,
+    )?;
+
+    Ok((actual, dst_lst_cpi, dst_lst_index, position))
+}
@@ -4,10 +4,10 @@ use s_controller_interface::{
     START_REBALANCE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED, REBALANCE_RECORD_BUMP, REBALANCE_RECORD_SEED},
-    try_lst_state_list, try_pool_state, try_pool_state_mut, try_rebalance_record_mut,
-    PoolStateAccount, SrcDstLstIndexes, StartRebalanceFreeArgs, U8BoolMut, REBALANCE_RECORD_SIZE,
+    calc_max_rebalance_sol_value_loss, index_to_usize, try_lst_state_list, try_pool_state,
+    try_pool_state_mut, try_rebalance_record_mut, PoolConfigAccount, PoolKey, PoolStateAccount,
+    SControllerEvent, SrcDstLstIndexes, StartRebalanceEvent, StartRebalanceFreeArgs, U8BoolMut,
+    REBALANCE_RECORD_SIZE,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -32,9 +32,14 @@ use system_program_interface::{
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::SrcDstLstSolValueCalculatorCpis,
+    rebalance_authority_list::{
+        charge_rebalance_authority_budget_for_epoch, ChargeRebalanceAuthorityBudgetAccounts,
+    },
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
-        verify_src_dst_lst_sol_val_calc_cpis, VerifySrcDstLstSolValCalcCpiAccounts,
+        verify_lst_input_not_disabled, verify_lst_output_not_disabled,
+        verify_not_rebalancing_and_not_disabled, verify_pool_key, verify_pool_migrated,
+        verify_rebalance_authority_or_on_list, verify_src_dst_lst_sol_val_calc_cpis,
+        VerifySrcDstLstSolValCalcCpiAccounts,
     },
 };
 
@@ -46,6 +51,7 @@ pub fn process_start_rebalance(
 ) -> ProgramResult {
     let (
         accounts,
+        pool,
         SrcDstLstSolValueCalculatorCpis {
             src_lst: src_lst_cpi,
             dst_lst: dst_lst_cpi,
@@ -76,11 +82,28 @@ pub fn process_start_rebalance(
             mint: accounts.src_lst_mint,
         },
         args.amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
 
+    let sol_value_withdrawn =
+        old_total_sol_value.saturating_sub(accounts.pool_state.total_sol_value()?);
+    charge_rebalance_authority_budget_for_epoch(
+        ChargeRebalanceAuthorityBudgetAccounts {
+            rebalance_authority: accounts.rebalance_authority,
+            rebalance_authority_list: accounts.rebalance_authority_list,
+            pool_config: accounts.pool_config,
+        },
+        sol_value_withdrawn,
+    )?;
+    let max_sol_value_loss = calc_max_rebalance_sol_value_loss(
+        old_total_sol_value,
+        accounts.pool_config.max_rebalance_loss_bps()?,
+        args.max_loss_lamports,
+    )?;
+
+    let rebalance_record_pda = pool.rebalance_record_pda();
     allocate_invoke_signed(
         AllocateAccounts {
             allocate: accounts.rebalance_record,
@@ -88,7 +111,7 @@ pub fn process_start_rebalance(
         AllocateIxArgs {
             space: space_to_u64(REBALANCE_RECORD_SIZE)?,
         },
-        &[&[REBALANCE_RECORD_SEED, &[REBALANCE_RECORD_BUMP]]],
+        &[rebalance_record_pda.signer_seeds().as_slice()],
     )?;
     assign_invoke_signed(
         AssignAccounts {
@@ -97,7 +120,7 @@ pub fn process_start_rebalance(
         AssignIxArgs {
             owner: s_controller_lib::program::ID,
         },
-        &[&[REBALANCE_RECORD_SEED, &[REBALANCE_RECORD_BUMP]]],
+        &[rebalance_record_pda.signer_seeds().as_slice()],
     )?;
     transfer_direct_increment(
         TransferAccounts {
@@ -111,12 +134,21 @@ pub fn process_start_rebalance(
     let rebalance_record = try_rebalance_record_mut(&mut rebalance_record_data)?;
     rebalance_record.dst_lst_index = args.dst_lst_index;
     rebalance_record.old_total_sol_value = old_total_sol_value;
+    rebalance_record.max_sol_value_loss = max_sol_value_loss;
+    rebalance_record.rebalance_authority = *accounts.rebalance_authority.key;
 
     let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_data)?;
     U8BoolMut(&mut pool_state.is_rebalancing).set_true();
 
-    Ok(())
+    SControllerEvent::StartRebalance(StartRebalanceEvent {
+        src_lst_index: args.src_lst_index,
+        dst_lst_index: args.dst_lst_index,
+        src_lst_withdrawn: args.amount,
+        old_total_sol_value,
+        total_sol_value: pool_state.total_sol_value,
+    })
+    .emit()
 }
 
 fn verify_start_rebalance<'a, 'info>(
@@ -128,10 +160,12 @@ fn verify_start_rebalance<'a, 'info>(
         amount: _,
         min_starting_src_lst,
         max_starting_dst_lst,
+        max_loss_lamports: _,
     }: &StartRebalanceIxArgs,
 ) -> Result<
     (
         StartRebalanceAccounts<'a, 'info>,
+        PoolKey,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
         SrcDstLstIndexes,
     ),
@@ -141,6 +175,7 @@ fn verify_start_rebalance<'a, 'info>(
     let dst_lst_index = index_to_usize(*dst_lst_index)?;
 
     let actual: StartRebalanceAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = StartRebalanceFreeArgs {
         withdraw_to: *actual.withdraw_to.key,
@@ -151,7 +186,10 @@ fn verify_start_rebalance<'a, 'info>(
         src_lst_mint: actual.src_lst_mint,
         dst_lst_mint: actual.dst_lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let mut expected = free_args.resolve_for_pool(&pool)?;
+    // rebalance authorities on the list may sign in place of the pool's,
+    // checked with verify_rebalance_authority_or_on_list() below
+    expected.rebalance_authority = *actual.rebalance_authority.key;
 
     start_rebalance_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     start_rebalance_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -165,12 +203,20 @@ fn verify_start_rebalance<'a, 'info>(
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
+    verify_rebalance_authority_or_on_list(
+        *actual.rebalance_authority.key,
+        pool_state,
+        actual.rebalance_authority_list,
+    )?;
 
     let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
     let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
     let dst_lst_state = lst_state_list[dst_lst_index]; // dst_lst_index checked above
     verify_lst_input_not_disabled(&dst_lst_state)?;
+    let src_lst_state = lst_state_list[src_lst_index]; // src_lst_index checked above
+    verify_lst_output_not_disabled(&src_lst_state)?;
 
     let accounts_suffix_slice = accounts
         .get(START_REBALANCE_IX_ACCOUNTS_LEN..)
@@ -188,13 +234,20 @@ fn verify_start_rebalance<'a, 'info>(
         src_dst_lst_indexes,
     )?;
 
-    verify_has_succeeding_end_rebalance_ix(actual.instructions, *actual.dst_lst_mint.key)?;
+    verify_has_succeeding_end_rebalance_ix(
+        actual.instructions,
+        *actual.rebalance_authority.key,
+        pool.pool_state,
+        *actual.dst_lst_mint.key,
+    )?;
 
-    Ok((actual, src_dst_lst_cpis, src_dst_lst_indexes))
+    Ok((actual, pool, src_dst_lst_cpis, src_dst_lst_indexes))
 }
 
 fn verify_has_succeeding_end_rebalance_ix(
     instructions_sysvar: &AccountInfo,
+    rebalance_authority: Pubkey,
+    pool_state: Pubkey,
     dst_lst_mint: Pubkey,
 ) -> Result<(), ProgramError> {
     let mut next_ix_idx: usize = load_current_index_checked(instructions_sysvar)?.into();
@@ -204,16 +257,29 @@ fn verify_has_succeeding_end_rebalance_ix(
             .ok_or(SControllerError::MathError)?;
         let next_ix = load_instruction_at_checked(next_ix_idx, instructions_sysvar)
             .map_err(|_| SControllerError::NoSucceedingEndRebalance)?;
-        if is_end_rebalance_ix(&next_ix, dst_lst_mint) {
+        if is_end_rebalance_ix(&next_ix, rebalance_authority, pool_state, dst_lst_mint) {
             break;
         }
     }
     Ok(())
 }
 
+const END_REBALANCE_IX_REBALANCE_AUTHORITY_INDEX: usize = 0;
+
+const END_REBALANCE_IX_POOL_STATE_INDEX: usize = 1;
+
 const END_REBALANCE_IX_DST_LST_MINT_INDEX: usize = 4;
 
-fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
+/// EndRebalance accepts any signer as its rebalance authority
+/// since it cannot check the rebalance authority list,
+/// so it must be signed by the same rebalance authority as StartRebalance.
+/// It must also end the rebalance of the same pool
+fn is_end_rebalance_ix(
+    ix: &Instruction,
+    rebalance_authority: Pubkey,
+    pool_state: Pubkey,
+    dst_lst_mint: Pubkey,
+) -> bool {
     let discm = match ix.data.first() {
         Some(d) => d,
         None => return false,
@@ -224,6 +290,21 @@ fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
     if ix.program_id != s_controller_lib::program::ID {
         return false;
     }
+    let rebalance_authority_account =
+        match ix.accounts.get(END_REBALANCE_IX_REBALANCE_AUTHORITY_INDEX) {
+            Some(a) => a,
+            None => return false,
+        };
+    if rebalance_authority_account.pubkey != rebalance_authority {
+        return false;
+    }
+    let pool_state_account = match ix.accounts.get(END_REBALANCE_IX_POOL_STATE_INDEX) {
+        Some(a) => a,
+        None => return false,
+    };
+    if pool_state_account.pubkey != pool_state {
+        return false;
+    }
     let dst_lst_mint_account = match ix.accounts.get(END_REBALANCE_IX_DST_LST_MINT_INDEX) {
         Some(a) => a,
         None => return false,
@@ -3,10 +3,10 @@ use s_controller_interface::{
     SwapExactOutAccounts, SwapExactOutIxArgs, SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_swap_protocol_fees, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, CalcSwapProtocolFeesArgs, PoolStateAccount,
-    SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactOutAmounts, SwapFreeArgs,
+    calc_swap_protocol_fees, calc_swap_referral_fees, index_to_u32, index_to_usize,
+    try_lst_state_list, try_pool_config, try_pool_state, CalcSwapProtocolFeesArgs, LstTransferFee,
+    PoolConfigAccount, PoolKey, PoolStateAccount, SControllerEvent, SrcDstLstIndexes,
+    SrcDstLstValueCalcAccs, SwapEvent, SwapExactOutAmounts, SwapFreeArgs, SwapLiquidityReturnData,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -16,23 +16,29 @@ use sanctum_token_lib::{
     transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled, verify_swap_cpis,
-        verify_swap_not_same_lst, VerifySwapCpiAccounts,
+        verify_lst_input_not_disabled, verify_lst_max_share_not_exceeded,
+        verify_lst_output_not_disabled, verify_not_rebalancing_and_not_disabled, verify_pool_key,
+        verify_pool_migrated, verify_swap_cpis, verify_swap_not_same_lst, VerifySwapCpiAccounts,
     },
 };
 
-use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+use super::{
+    mint_protocol_fees_as_lp_unchecked, sync_sol_value_unchecked, MintProtocolFeesAsLpArgs,
+    MintProtocolFeesAsLpUncheckedAccounts, SyncSolValueUncheckedAccounts,
+};
 
 pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs) -> ProgramResult {
     let (
         accounts,
+        pool,
         SwapExactOutAmounts {
             max_amount_in,
             amount,
@@ -57,15 +63,21 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
 
     let start_total_sol_value = accounts.pool_state.total_sol_value()?;
 
-    let out_sol_value = dst_lst_cpi.invoke_lst_to_sol(amount)?.get_max();
+    let epoch = Clock::get()?.epoch;
+    let src_transfer_fee = LstTransferFee::try_from_mint(accounts.src_lst_mint, epoch)?;
+    let dst_transfer_fee = LstTransferFee::try_from_mint(accounts.dst_lst_mint, epoch)?;
+
+    let dst_lst_out = dst_transfer_fee.amt_bef_fee(amount)?;
+    let out_sol_value = dst_lst_cpi.invoke_lst_to_sol(dst_lst_out)?.get_max();
     if out_sol_value == 0 {
         return Err(SControllerError::ZeroValue.into());
     }
     let in_sol_value = pricing_cpi.invoke_price_exact_out(PricingProgramIxArgs {
-        amount,
+        amount: dst_lst_out,
         sol_value: out_sol_value,
     })?;
-    let src_lst_in = src_lst_cpi.invoke_sol_to_lst(in_sol_value)?.get_max();
+    let src_lst_received = src_lst_cpi.invoke_sol_to_lst(in_sol_value)?.get_max();
+    let src_lst_in = src_transfer_fee.amt_bef_fee(src_lst_received)?;
 
     if src_lst_in > max_amount_in {
         return Err(SControllerError::SlippageToleranceExceeded.into());
@@ -78,11 +90,27 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
     let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
         in_sol_value,
         out_sol_value,
-        dst_lst_out: amount,
+        dst_lst_out,
         trading_protocol_fee_bps,
     })?;
 
-    let total_dst_lst_out = amount
+    let referral_fees =
+        if accounts.referrer_dst_lst_acc.key == accounts.protocol_fee_accumulator.key {
+            0
+        } else {
+            let referral_fee_bps = {
+                let pool_config_bytes = accounts.pool_config.try_borrow_data()?;
+                try_pool_config(&pool_config_bytes)?.referral_fee_bps
+            };
+            calc_swap_referral_fees(to_protocol_fees_lst_amount, referral_fee_bps)?
+        };
+    let to_protocol_fee_accumulator_lst_amount = to_protocol_fees_lst_amount
+        .checked_sub(referral_fees)
+        .ok_or(SControllerError::MathError)?;
+
+    let protocol_fees_as_lp = accounts.pool_config.protocol_fees_as_lp()?;
+
+    let total_dst_lst_out = dst_lst_out
         .checked_add(to_protocol_fees_lst_amount)
         .ok_or(SControllerError::MathError)?;
     if total_dst_lst_out > token_account_balance(accounts.dst_pool_reserves)? {
@@ -99,17 +127,33 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
         },
         src_lst_in,
     )?;
-    transfer_checked_decimal_agnostic_invoke_signed(
-        TransferCheckedAccounts {
-            from: accounts.dst_pool_reserves,
-            to: accounts.protocol_fee_accumulator,
-            token_program: accounts.dst_lst_token_program,
-            authority: accounts.pool_state,
-            mint: accounts.dst_lst_mint,
-        },
-        to_protocol_fees_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
-    )?;
+    // protocol fees are left in the pool reserves and minted as LP tokens below instead
+    if !protocol_fees_as_lp {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: accounts.dst_pool_reserves,
+                to: accounts.protocol_fee_accumulator,
+                token_program: accounts.dst_lst_token_program,
+                authority: accounts.pool_state,
+                mint: accounts.dst_lst_mint,
+            },
+            to_protocol_fee_accumulator_lst_amount,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
+    if referral_fees > 0 {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: accounts.dst_pool_reserves,
+                to: accounts.referrer_dst_lst_acc,
+                token_program: accounts.dst_lst_token_program,
+                authority: accounts.pool_state,
+                mint: accounts.dst_lst_mint,
+            },
+            referral_fees,
+            &[pool.pool_state_pda().signer_seeds().as_slice()],
+        )?;
+    }
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
             from: accounts.dst_pool_reserves,
@@ -118,19 +162,61 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             authority: accounts.pool_state,
             mint: accounts.dst_lst_mint,
         },
-        amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        dst_lst_out,
+        &[pool.pool_state_pda().signer_seeds().as_slice()],
     )?;
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
     sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
 
+    verify_lst_max_share_not_exceeded(accounts.pool_state, accounts.lst_state_list, src_lst_index)?;
+
     let end_total_sol_value = accounts.pool_state.total_sol_value()?;
     if end_total_sol_value < start_total_sol_value {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
 
-    Ok(())
+    SControllerEvent::SwapExactOut(SwapEvent {
+        src_lst_index: index_to_u32(src_lst_index)?,
+        dst_lst_index: index_to_u32(dst_lst_index)?,
+        src_lst_in,
+        dst_lst_out,
+        in_sol_value,
+        out_sol_value,
+        protocol_fees: to_protocol_fees_lst_amount,
+        total_sol_value: end_total_sol_value,
+        referral_fees,
+    })
+    .emit()?;
+
+    if protocol_fees_as_lp && to_protocol_fee_accumulator_lst_amount > 0 {
+        let protocol_fees_sol_value = dst_lst_cpi
+            .invoke_lst_to_sol(to_protocol_fee_accumulator_lst_amount)?
+            .get_min();
+        mint_protocol_fees_as_lp_unchecked(
+            &pool,
+            MintProtocolFeesAsLpUncheckedAccounts {
+                lp_token_mint: accounts.lp_token_mint,
+                protocol_fee_lp_acc: accounts.protocol_fee_lp_acc,
+                lp_token_program: accounts.lp_token_program,
+                pool_state: accounts.pool_state,
+            },
+            MintProtocolFeesAsLpArgs {
+                lst_index: dst_lst_index,
+                protocol_fees: to_protocol_fee_accumulator_lst_amount,
+                protocol_fees_sol_value,
+            },
+        )?;
+    }
+
+    SwapLiquidityReturnData {
+        amount_in: src_lst_in,
+        amount_out: dst_lst_out,
+        protocol_fees: to_protocol_fees_lst_amount,
+        in_sol_value,
+        out_sol_value,
+    }
+    .set()
 }
 
 fn verify_swap_exact_out<'a, 'info>(
@@ -146,6 +232,7 @@ fn verify_swap_exact_out<'a, 'info>(
 ) -> Result<
     (
         SwapExactOutAccounts<'a, 'info>,
+        PoolKey,
         SwapExactOutAmounts,
         SrcDstLstIndexes,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
@@ -161,18 +248,21 @@ fn verify_swap_exact_out<'a, 'info>(
     let dst_lst_index = index_to_usize(dst_lst_index)?;
 
     let actual: SwapExactOutAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = SwapFreeArgs {
         signer: *actual.signer.key,
         src_lst_acc: *actual.src_lst_acc.key,
         dst_lst_acc: *actual.dst_lst_acc.key,
+        referrer_dst_lst_acc: Some(*actual.referrer_dst_lst_acc.key),
         src_lst_index,
         dst_lst_index,
         src_lst_mint: actual.src_lst_mint,
         dst_lst_mint: actual.dst_lst_mint,
+        pool_state: actual.pool_state,
         lst_state_list: actual.lst_state_list,
     };
-    let expected = free_args.resolve_exact_out()?;
+    let expected = free_args.resolve_exact_out_for_pool(&pool)?;
 
     swap_exact_out_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     swap_exact_out_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -181,12 +271,15 @@ fn verify_swap_exact_out<'a, 'info>(
 
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
     let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
     let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
     let src_lst_state = lst_state_list[src_lst_index];
     verify_lst_input_not_disabled(&src_lst_state)?;
+    let dst_lst_state = lst_state_list[dst_lst_index];
+    verify_lst_output_not_disabled(&dst_lst_state)?;
 
     let src_dst_lst_indexes = SrcDstLstIndexes {
         src_lst_index,
@@ -209,6 +302,7 @@ fn verify_swap_exact_out<'a, 'info>(
 
     Ok((
         actual,
+        pool,
         SwapExactOutAmounts {
             max_amount_in,
             amount,
@@ -1,17 +1,21 @@
 use s_controller_interface::{
-    sync_sol_value_verify_account_keys, sync_sol_value_verify_account_privileges,
-    SyncSolValueAccounts, SyncSolValueIxArgs, SYNC_SOL_VALUE_IX_ACCOUNTS_LEN,
+    sync_sol_value_verify_account_keys, sync_sol_value_verify_account_privileges, LstState,
+    PoolState, SControllerError, SyncSolValueAccounts, SyncSolValueIxArgs,
+    SYNC_SOL_VALUE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize, sync_sol_value_with_retval, try_lst_state_list_mut, try_pool_state,
-    try_pool_state_mut, SyncSolValueFreeArgs,
+    index_to_usize, sync_sol_value_with_circuit_breaker, try_lst_state_list,
+    try_lst_state_list_mut, try_pool_state, try_pool_state_mut, PoolStateAccount, SControllerEvent,
+    SolValueRateArgs, SolValueRateCircuitBreakerTrippedEvent, SolValueRateTrip, SyncSolValueEvent,
+    SyncSolValueFreeArgs, U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use sanctum_token_lib::token_account_balance;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 use crate::{
@@ -20,19 +24,46 @@ use crate::{
     },
     cpi::SolValueCalculatorCpi,
     verify::{
-        verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled,
-        VerifyLstSolValCalcCpiAccounts,
+        verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled, verify_pool_key,
+        verify_pool_migrated, VerifyLstSolValCalcCpiAccounts,
     },
 };
 
 pub fn process_sync_sol_value(accounts: &[AccountInfo], args: SyncSolValueIxArgs) -> ProgramResult {
     let (accounts, cpi) = verify_sync_sol_value(accounts, &args)?;
     let lst_index: usize = args.lst_index.try_into().unwrap(); // lst_index checked in verify
-    sync_sol_value_unchecked(
+    let old_sol_value = lst_sol_value(accounts.lst_state_list, lst_index)?;
+    let trip = sync_sol_value_or_trip_circuit_breaker_unchecked(
         SyncSolValueUncheckedAccounts::from(accounts),
         cpi,
         lst_index,
-    )
+    )?;
+
+    match trip {
+        Some(SolValueRateTrip {
+            last_sol_value_rate,
+            new_sol_value_rate,
+        }) => SControllerEvent::SolValueRateCircuitBreakerTripped(
+            SolValueRateCircuitBreakerTrippedEvent {
+                lst_index: args.lst_index,
+                last_sol_value_rate,
+                new_sol_value_rate,
+            },
+        ),
+        None => SControllerEvent::SyncSolValue(SyncSolValueEvent {
+            lst_index: args.lst_index,
+            old_sol_value,
+            new_sol_value: lst_sol_value(accounts.lst_state_list, lst_index)?,
+            total_sol_value: accounts.pool_state.total_sol_value()?,
+        }),
+    }
+    .emit()
+}
+
+fn lst_sol_value(lst_state_list: &AccountInfo, lst_index: usize) -> Result<u64, ProgramError> {
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    Ok(lst_state_list[lst_index].sol_value)
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -57,8 +88,60 @@ where
     }
 }
 
-/// SyncSolValue's full subroutine, exported for use by other instruction processors
+/// SyncSolValue's full subroutine, exported for use by other instruction processors.
+///
+/// Fails with [`SControllerError::SolValueRateCircuitBreakerTripped`] if the LST's
+/// circuit breaker trips, since the instruction would otherwise operate on a suspicious SOL value.
+/// The failure reverts the whole transaction, so the LST is not disabled:
+/// only SyncSolValue and SyncSolValueMulti persist the trip, see
+/// [`sync_sol_value_or_trip_circuit_breaker_unchecked`]
 pub fn sync_sol_value_unchecked<'a, 'info>(
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    lst_index: usize,
+) -> Result<(), ProgramError> {
+    let (pool_state, lst_state, trip) = simulate_sync_sol_value_or_trip(accounts, cpi, lst_index)?;
+    if trip.is_some() {
+        return Err(SControllerError::SolValueRateCircuitBreakerTripped.into());
+    }
+    write_sync_sol_value_result(accounts, lst_index, pool_state, lst_state)
+}
+
+/// Same as [`sync_sol_value_unchecked`], but if the LST's circuit breaker trips,
+/// disables input and output for the LST and returns the trip instead of failing.
+/// The LST's `sol_value` is left unchanged on a trip.
+pub fn sync_sol_value_or_trip_circuit_breaker_unchecked<'a, 'info>(
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    lst_index: usize,
+) -> Result<Option<SolValueRateTrip>, ProgramError> {
+    let (pool_state, mut lst_state, trip) =
+        simulate_sync_sol_value_or_trip(accounts, cpi, lst_index)?;
+    if trip.is_some() {
+        U8BoolMut(&mut lst_state.is_input_disabled).set_true();
+        U8BoolMut(&mut lst_state.is_output_disabled).set_true();
+    }
+    write_sync_sol_value_result(accounts, lst_index, pool_state, lst_state)?;
+    Ok(trip)
+}
+
+/// Same as [`sync_sol_value_unchecked`], but applies the sync to copies of
+/// the pool's state and the LST's state instead of writing to the accounts.
+///
+/// Returns the (pool_state, lst_state) the accounts would have after syncing
+pub fn simulate_sync_sol_value_unchecked<'a, 'info>(
+    accounts: SyncSolValueUncheckedAccounts<'a, 'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+    lst_index: usize,
+) -> Result<(PoolState, LstState), ProgramError> {
+    let (pool_state, lst_state, trip) = simulate_sync_sol_value_or_trip(accounts, cpi, lst_index)?;
+    if trip.is_some() {
+        return Err(SControllerError::SolValueRateCircuitBreakerTripped.into());
+    }
+    Ok((pool_state, lst_state))
+}
+
+fn simulate_sync_sol_value_or_trip<'a, 'info>(
     SyncSolValueUncheckedAccounts {
         pool_reserves,
         pool_state,
@@ -66,18 +149,45 @@ pub fn sync_sol_value_unchecked<'a, 'info>(
     }: SyncSolValueUncheckedAccounts<'a, 'info>,
     cpi: SolValueCalculatorCpi<'a, 'info>,
     lst_index: usize,
-) -> Result<(), ProgramError> {
+) -> Result<(PoolState, LstState, Option<SolValueRateTrip>), ProgramError> {
     let lst_balance = token_account_balance(pool_reserves)?;
     let returned_sol_value_range = cpi.invoke_lst_to_sol(lst_balance)?;
 
+    let pool_state_bytes = pool_state.try_borrow_data()?;
+    let mut pool_state = *try_pool_state(&pool_state_bytes)?;
+
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let mut lst_state = lst_state_list[lst_index];
+
+    let trip = sync_sol_value_with_circuit_breaker(
+        &mut pool_state,
+        &mut lst_state,
+        SolValueRateArgs {
+            reserves_balance: lst_balance,
+            sol_value: returned_sol_value_range.get_min(),
+            epoch: Clock::get()?.epoch,
+        },
+    )?;
+
+    Ok((pool_state, lst_state, trip))
+}
+
+fn write_sync_sol_value_result(
+    SyncSolValueUncheckedAccounts {
+        pool_state,
+        lst_state_list,
+        ..
+    }: SyncSolValueUncheckedAccounts,
+    lst_index: usize,
+    new_pool_state: PoolState,
+    new_lst_state: LstState,
+) -> Result<(), ProgramError> {
     let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+    *try_pool_state_mut(&mut pool_state_bytes)? = new_pool_state;
 
     let mut lst_state_list_bytes = lst_state_list.try_borrow_mut_data()?;
-    let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_bytes)?;
-    let lst_state = &mut lst_state_list[lst_index];
-
-    sync_sol_value_with_retval(pool_state, lst_state, returned_sol_value_range.get_min())?;
+    try_lst_state_list_mut(&mut lst_state_list_bytes)?[lst_index] = new_lst_state;
 
     Ok(())
 }
@@ -112,12 +222,15 @@ fn verify_sync_sol_value_base_accounts<'a, 'info>(
     actual: SyncSolValueAccounts<'a, 'info>,
     lst_index: usize,
 ) -> Result<SyncSolValueAccounts<'a, 'info>, ProgramError> {
+    let pool = verify_pool_key(actual.pool_state)?;
+
     let free_args = SyncSolValueFreeArgs {
         lst_index,
+        pool_state: actual.pool_state,
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     sync_sol_value_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     sync_sol_value_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -125,6 +238,7 @@ fn verify_sync_sol_value_base_accounts<'a, 'info>(
     let pool_state_bytes = actual.pool_state.try_borrow_data()?;
     let pool_state = try_pool_state(&pool_state_bytes)?;
 
+    verify_pool_migrated(pool_state)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
     Ok(actual)
@@ -0,0 +1,216 @@
+use s_controller_interface::{
+    sync_sol_value_multi_verify_account_keys, sync_sol_value_multi_verify_account_privileges,
+    sync_sol_value_verify_account_keys, SyncSolValueAccounts, SyncSolValueMultiAccounts,
+    SyncSolValueMultiEntry, SyncSolValueMultiIxArgs, SyncSolValueMultiKeys,
+    SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_u32, index_to_usize, sync_sol_value_with_circuit_breaker, try_lst_state_list_mut,
+    try_pool_state, try_pool_state_mut, PoolKey, SControllerEvent, SolValueRateArgs,
+    SolValueRateCircuitBreakerTrippedEvent, SolValueRateTrip, SyncSolValueEvent,
+    SyncSolValueFreeArgs, U8BoolMut,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::token_account_balance;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    cpi::SolValueCalculatorCpi,
+    verify::{
+        verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled, verify_pool_key,
+        verify_pool_migrated, VerifyLstSolValCalcCpiAccounts,
+    },
+};
+
+/// Number of accounts preceding each entry's SOL value calculator accounts:
+/// lst_mint, pool_reserves
+const SYNC_SOL_VALUE_MULTI_ENTRY_PREFIX_LEN: usize = 2;
+
+pub fn process_sync_sol_value_multi(
+    accounts: &[AccountInfo],
+    args: SyncSolValueMultiIxArgs,
+) -> ProgramResult {
+    let (accounts, entries) = verify_sync_sol_value_multi(accounts, &args)?;
+
+    // CPI all the calculators first so that
+    // pool_state and lst_state_list only need to be rewritten once
+    let returned_sol_values = entries
+        .into_iter()
+        .map(
+            |VerifiedSyncSolValueMultiEntry {
+                 lst_index,
+                 pool_reserves,
+                 cpi,
+             }| {
+                let lst_balance = token_account_balance(pool_reserves)?;
+                let returned_sol_value_range = cpi.invoke_lst_to_sol(lst_balance)?;
+                Ok((lst_index, lst_balance, returned_sol_value_range.get_min()))
+            },
+        )
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+    let epoch = Clock::get()?.epoch;
+
+    let mut pool_state_bytes = accounts.pool_state.try_borrow_mut_data()?;
+    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+
+    let mut lst_state_list_bytes = accounts.lst_state_list.try_borrow_mut_data()?;
+    let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_bytes)?;
+
+    for (lst_index, lst_balance, returned_sol_value) in returned_sol_values {
+        let lst_state = &mut lst_state_list[lst_index];
+        let old_sol_value = lst_state.sol_value;
+        let trip = sync_sol_value_with_circuit_breaker(
+            pool_state,
+            lst_state,
+            SolValueRateArgs {
+                reserves_balance: lst_balance,
+                sol_value: returned_sol_value,
+                epoch,
+            },
+        )?;
+        let lst_index = index_to_u32(lst_index)?;
+        match trip {
+            Some(SolValueRateTrip {
+                last_sol_value_rate,
+                new_sol_value_rate,
+            }) => {
+                U8BoolMut(&mut lst_state.is_input_disabled).set_true();
+                U8BoolMut(&mut lst_state.is_output_disabled).set_true();
+                SControllerEvent::SolValueRateCircuitBreakerTripped(
+                    SolValueRateCircuitBreakerTrippedEvent {
+                        lst_index,
+                        last_sol_value_rate,
+                        new_sol_value_rate,
+                    },
+                )
+            }
+            None => SControllerEvent::SyncSolValue(SyncSolValueEvent {
+                lst_index,
+                old_sol_value,
+                new_sol_value: lst_state.sol_value,
+                total_sol_value: pool_state.total_sol_value,
+            }),
+        }
+        .emit()?;
+    }
+
+    Ok(())
+}
+
+struct VerifiedSyncSolValueMultiEntry<'a, 'info> {
+    lst_index: usize,
+    pool_reserves: &'a AccountInfo<'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+}
+
+fn verify_sync_sol_value_multi<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    SyncSolValueMultiIxArgs { entries }: &SyncSolValueMultiIxArgs,
+) -> Result<
+    (
+        SyncSolValueMultiAccounts<'a, 'info>,
+        Vec<VerifiedSyncSolValueMultiEntry<'a, 'info>>,
+    ),
+    ProgramError,
+> {
+    let actual: SyncSolValueMultiAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
+
+    let expected = SyncSolValueMultiKeys {
+        pool_state: pool.pool_state,
+        lst_state_list: pool.lst_state_list_pda().address,
+    };
+
+    sync_sol_value_multi_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    sync_sol_value_multi_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+
+    verify_pool_migrated(pool_state)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let mut accounts_suffix_slice = accounts
+        .get(SYNC_SOL_VALUE_MULTI_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let verified_entries = entries
+        .iter()
+        .map(|entry| {
+            let (verified, rest) =
+                verify_sync_sol_value_multi_entry(actual, &pool, accounts_suffix_slice, entry)?;
+            accounts_suffix_slice = rest;
+            Ok(verified)
+        })
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+
+    Ok((actual, verified_entries))
+}
+
+/// Returns the verified entry and the remaining accounts suffix slice
+/// following this entry's accounts
+fn verify_sync_sol_value_multi_entry<'a, 'info>(
+    actual: SyncSolValueMultiAccounts<'a, 'info>,
+    pool: &PoolKey,
+    accounts_suffix_slice: &'a [AccountInfo<'info>],
+    SyncSolValueMultiEntry {
+        lst_index,
+        lst_value_calc_accs,
+    }: &SyncSolValueMultiEntry,
+) -> Result<
+    (
+        VerifiedSyncSolValueMultiEntry<'a, 'info>,
+        &'a [AccountInfo<'info>],
+    ),
+    ProgramError,
+> {
+    let lst_index = index_to_usize(*lst_index)?;
+    let entry_accounts_len =
+        SYNC_SOL_VALUE_MULTI_ENTRY_PREFIX_LEN + usize::from(*lst_value_calc_accs);
+    if accounts_suffix_slice.len() < entry_accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (entry_accounts, rest) = accounts_suffix_slice.split_at(entry_accounts_len);
+    let lst_mint = &entry_accounts[0];
+    let pool_reserves = &entry_accounts[1];
+
+    let lst_actual = SyncSolValueAccounts {
+        lst_mint,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        pool_reserves,
+    };
+    let lst_expected = SyncSolValueFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint,
+    }
+    .resolve_for_pool(pool)?;
+    sync_sol_value_verify_account_keys(lst_actual, lst_expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+
+    let cpi = verify_lst_sol_val_calc_cpi(
+        VerifyLstSolValCalcCpiAccounts {
+            lst_state_list: actual.lst_state_list,
+            lst_mint,
+        },
+        &entry_accounts[SYNC_SOL_VALUE_MULTI_ENTRY_PREFIX_LEN..],
+        lst_index,
+    )?;
+
+    Ok((
+        VerifiedSyncSolValueMultiEntry {
+            lst_index,
+            pool_reserves,
+            cpi,
+        },
+        rest,
+    ))
+}
@@ -3,8 +3,8 @@ use s_controller_interface::{
     SControllerError, WithdrawProtocolFeesAccounts, WithdrawProtocolFeesIxArgs,
 };
 use s_controller_lib::{
-    program::{PROTOCOL_FEE_BUMP, PROTOCOL_FEE_SEED},
-    try_pool_state, WithdrawProtocolFeesFreeArgs,
+    try_pool_state, PoolKey, SControllerEvent, WithdrawProtocolFeesEvent,
+    WithdrawProtocolFeesFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -16,13 +16,13 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pool_key};
 
 pub fn process_withdraw_protocol_fees(
     accounts: &[AccountInfo],
     args: WithdrawProtocolFeesIxArgs,
 ) -> ProgramResult {
-    let accounts = verify_withdraw_protocol_fees(accounts)?;
+    let (accounts, pool) = verify_withdraw_protocol_fees(accounts)?;
 
     if args.amount > token_account_balance(accounts.protocol_fee_accumulator)? {
         return Err(SControllerError::NotEnoughFees.into());
@@ -37,22 +37,27 @@ pub fn process_withdraw_protocol_fees(
             mint: accounts.lst_mint,
         },
         args.amount,
-        &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]],
+        &[pool.protocol_fee_pda().signer_seeds().as_slice()],
     )?;
 
-    Ok(())
+    SControllerEvent::WithdrawProtocolFees(WithdrawProtocolFeesEvent {
+        lst_mint: *accounts.lst_mint.key,
+        amount: args.amount,
+    })
+    .emit()
 }
 
 fn verify_withdraw_protocol_fees<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<WithdrawProtocolFeesAccounts<'a, 'info>, ProgramError> {
+) -> Result<(WithdrawProtocolFeesAccounts<'a, 'info>, PoolKey), ProgramError> {
     let actual: WithdrawProtocolFeesAccounts = load_accounts(accounts)?;
+    let pool = verify_pool_key(actual.pool_state)?;
 
     let free_args = WithdrawProtocolFeesFreeArgs {
         pool_state: actual.pool_state,
         withdraw_to: actual.withdraw_to,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(&pool)?;
 
     withdraw_protocol_fees_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -64,5 +69,5 @@ fn verify_withdraw_protocol_fees<'a, 'info>(
 
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
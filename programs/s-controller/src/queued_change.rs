@@ -0,0 +1,54 @@
+use s_controller_interface::{QueuedChange, SControllerError};
+use s_controller_lib::{try_queued_change, try_queued_change_mut, verify_timelock_elapsed};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+
+pub struct CreateQueuedChangeAccounts<'me, 'info> {
+    pub queued_change: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+}
+
+/// Creates a QueuedChange PDA containing `queued_change_data`,
+/// transfering enough lamports from `payer` to make it rent-exempt.
+/// Fails if a change is already queued at the PDA,
+/// it must be executed or cancelled first.
+pub fn create_queued_change(
+    CreateQueuedChangeAccounts {
+        queued_change,
+        payer,
+    }: CreateQueuedChangeAccounts,
+    queued_change_signer_seeds: &[&[&[u8]]],
+    queued_change_data: QueuedChange,
+) -> Result<(), ProgramError> {
+    if !queued_change.data_is_empty() {
+        return Err(SControllerError::ChangeAlreadyQueued.into());
+    }
+    // a singleton account is just a list of len 1
+    extend_list_pda::<QueuedChange>(
+        ExtendListPdaAccounts {
+            list_pda: queued_change,
+            payer,
+        },
+        queued_change_signer_seeds,
+    )?;
+
+    let mut bytes = queued_change.try_borrow_mut_data()?;
+    let dst = try_queued_change_mut(&mut bytes)?;
+    *dst = queued_change_data;
+
+    Ok(())
+}
+
+/// Checks that the QueuedChange PDA's timelock delay has elapsed
+/// as of the current slot, returning a copy of its data
+pub fn verify_queued_change_executable(
+    queued_change: &AccountInfo,
+) -> Result<QueuedChange, ProgramError> {
+    let bytes = queued_change.try_borrow_data()?;
+    let queued_change = try_queued_change(&bytes)?;
+    verify_timelock_elapsed(queued_change, Clock::get()?.slot)?;
+    Ok(*queued_change)
+}
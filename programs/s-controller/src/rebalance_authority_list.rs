@@ -0,0 +1,46 @@
+use s_controller_lib::{
+    charge_pool_rebalance_authority_budget, charge_rebalance_authority_budget,
+    try_find_rebalance_authority, try_pool_config_mut, try_rebalance_authority_list_mut,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, sysvar::Sysvar,
+};
+
+pub struct ChargeRebalanceAuthorityBudgetAccounts<'me, 'info> {
+    pub rebalance_authority: &'me AccountInfo<'info>,
+    pub rebalance_authority_list: &'me AccountInfo<'info>,
+    pub pool_config: &'me AccountInfo<'info>,
+}
+
+/// Charges `sol_value` withdrawn by `rebalance_authority` against its budget for the current epoch.
+///
+/// Authorities on the pool's rebalance authority list are charged against their entry.
+/// The pool's rebalance authority without an entry is charged against the
+/// PoolConfig's rebalance authority budget, which is a no-op if PoolConfig has not been created.
+/// Budgets of [`s_controller_lib::UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET`] are not charged
+/// and budgets of 0 reject any withdrawal on both paths
+pub fn charge_rebalance_authority_budget_for_epoch(
+    ChargeRebalanceAuthorityBudgetAccounts {
+        rebalance_authority,
+        rebalance_authority_list,
+        pool_config,
+    }: ChargeRebalanceAuthorityBudgetAccounts,
+    sol_value: u64,
+) -> Result<(), ProgramError> {
+    let epoch = Clock::get()?.epoch;
+    if !rebalance_authority_list.data_is_empty() {
+        let mut list_data = rebalance_authority_list.try_borrow_mut_data()?;
+        let list = try_rebalance_authority_list_mut(&mut list_data)?;
+        if try_find_rebalance_authority(*rebalance_authority.key, list).is_some() {
+            charge_rebalance_authority_budget(list, *rebalance_authority.key, epoch, sol_value)?;
+            return Ok(());
+        }
+    }
+    if pool_config.data_is_empty() {
+        return Ok(());
+    }
+    let mut pool_config_data = pool_config.try_borrow_mut_data()?;
+    let pool_config = try_pool_config_mut(&mut pool_config_data)?;
+    charge_pool_rebalance_authority_budget(pool_config, epoch, sol_value)?;
+    Ok(())
+}
@@ -2,10 +2,15 @@
 
 use s_controller_interface::{LstState, PoolState, SControllerError};
 use s_controller_lib::{
-    try_disable_pool_authority_list, try_find_element_in_list, SrcDstLstIndexes,
-    SrcDstLstValueCalcAccs, U8Bool,
+    program::POOL_STATE_ID, try_disable_pool_authority_list, try_find_element_in_list,
+    try_lst_state_list, try_pool_config, try_pool_state, try_rebalance_authority_list,
+    verify_lst_max_share, verify_lsts_synced_for_lp, verify_rebalance_authority, PoolKey,
+    SrcDstLstIndexes, SrcDstLstValueCalcAccs, U8Bool, CURRENT_PROGRAM_VERS,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     account_traits::{
@@ -18,6 +23,32 @@ use crate::{
     },
 };
 
+/// Returns the key of the pool that `pool_state_acc` is the pool state of.
+///
+/// Additional pools require a `find_program_address()` here,
+/// and for each of their other PDAs that is subsequently resolved.
+pub fn verify_pool_key(pool_state_acc: &AccountInfo) -> Result<PoolKey, ProgramError> {
+    if *pool_state_acc.key == POOL_STATE_ID {
+        return Ok(PoolKey::DEFAULT);
+    }
+    let pool_state_bytes = pool_state_acc.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    Ok(PoolKey::try_from_pool_state(
+        *pool_state_acc.key,
+        pool_state,
+    )?)
+}
+
+/// Instructions that read or write lst_state_list require it to be in the current layout.
+/// Instructions that only touch pool_state, such as DisablePool and the admin setters,
+/// do not call this so that they still work on pools that have not been migrated yet
+pub const fn verify_pool_migrated(pool_state: &PoolState) -> Result<(), SControllerError> {
+    if pool_state.version != CURRENT_PROGRAM_VERS {
+        return Err(SControllerError::InvalidPoolVersion);
+    }
+    Ok(())
+}
+
 pub const fn verify_not_rebalancing_and_not_disabled(
     pool_state: &PoolState,
 ) -> Result<(), SControllerError> {
@@ -30,6 +61,23 @@ pub const fn verify_not_rebalancing_and_not_disabled(
     Ok(())
 }
 
+pub const fn verify_is_rebalancing(pool_state: &PoolState) -> Result<(), SControllerError> {
+    if U8Bool(pool_state.is_rebalancing).is_true() {
+        Ok(())
+    } else {
+        Err(SControllerError::PoolNotRebalancing)
+    }
+}
+
+/// Instant changes to timelocked pool params are only allowed
+/// while the pool's timelock delay is 0
+pub const fn verify_timelock_not_active(pool_state: &PoolState) -> Result<(), SControllerError> {
+    if pool_state.timelock_delay != 0 {
+        return Err(SControllerError::TimelockActive);
+    }
+    Ok(())
+}
+
 pub const fn verify_lst_input_not_disabled(lst_state: &LstState) -> Result<(), SControllerError> {
     if U8Bool(lst_state.is_input_disabled).is_true() {
         return Err(SControllerError::LstInputDisabled);
@@ -37,6 +85,48 @@ pub const fn verify_lst_input_not_disabled(lst_state: &LstState) -> Result<(), S
     Ok(())
 }
 
+pub const fn verify_lst_output_not_disabled(lst_state: &LstState) -> Result<(), SControllerError> {
+    if U8Bool(lst_state.is_output_disabled).is_true() {
+        return Err(SControllerError::LstOutputDisabled);
+    }
+    Ok(())
+}
+
+/// Should be called after the final SyncSolValue of an instruction
+/// that adds the LST at `lst_index` to the pool's reserves
+pub fn verify_lst_max_share_not_exceeded(
+    pool_state_acc: &AccountInfo,
+    lst_state_list_acc: &AccountInfo,
+    lst_index: usize,
+) -> Result<(), ProgramError> {
+    let pool_state_bytes = pool_state_acc.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    let lst_state_list_bytes = lst_state_list_acc.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let lst_state = lst_state_list
+        .get(lst_index)
+        .ok_or(SControllerError::InvalidLstIndex)?;
+    verify_lst_max_share(lst_state, pool_state.total_sol_value)?;
+    Ok(())
+}
+
+/// Checks that every LST has been synced in the current epoch
+/// if the pool's `lp_requires_synced_lsts` is set
+pub fn verify_lsts_synced_for_lp_if_required(
+    pool_config_acc: &AccountInfo,
+    lst_state_list_acc: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let pool_config_bytes = pool_config_acc.try_borrow_data()?;
+    let pool_config = try_pool_config(&pool_config_bytes)?;
+    if U8Bool(pool_config.lp_requires_synced_lsts).is_false() {
+        return Ok(());
+    }
+    let lst_state_list_bytes = lst_state_list_acc.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    verify_lsts_synced_for_lp(&pool_config, lst_state_list, Clock::get()?.epoch)?;
+    Ok(())
+}
+
 pub fn verify_admin_or_disable_pool_authority(
     signer: Pubkey,
     pool_state: &PoolState,
@@ -52,6 +142,17 @@ pub fn verify_admin_or_disable_pool_authority(
     Ok(())
 }
 
+pub fn verify_rebalance_authority_or_on_list(
+    signer: Pubkey,
+    pool_state: &PoolState,
+    rebalance_authority_list_acc: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let data = rebalance_authority_list_acc.try_borrow_data()?;
+    let list = try_rebalance_authority_list(&data)?;
+    verify_rebalance_authority(pool_state, list, signer)?;
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct VerifyLstSolValCalcCpiAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
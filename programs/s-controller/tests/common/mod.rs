@@ -1,4 +1,8 @@
-use solana_program_test::{processor, ProgramTest};
+use s_controller_lib::{
+    decode_s_controller_events_from_logs, SControllerEvent, SwapLiquidityReturnData,
+};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::transaction::Transaction;
 
 pub trait SControllerProgramTest {
     fn add_s_program(self) -> Self;
@@ -14,3 +18,36 @@ impl SControllerProgramTest for ProgramTest {
         self
     }
 }
+
+/// Processes `tx`, panicking if it fails,
+/// and returns the events emitted by the s-controller program
+pub async fn process_tx_get_s_controller_events(
+    banks_client: &mut BanksClient,
+    tx: Transaction,
+) -> Vec<SControllerEvent> {
+    let res = banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    res.result.unwrap();
+    decode_s_controller_events_from_logs(
+        s_controller_lib::program::ID,
+        &res.metadata.unwrap().log_messages,
+    )
+}
+
+/// Processes `tx`, panicking if it fails,
+/// and returns the amounts set as return data by the s-controller program
+pub async fn process_tx_get_s_controller_return_data(
+    banks_client: &mut BanksClient,
+    tx: Transaction,
+) -> SwapLiquidityReturnData {
+    let res = banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    res.result.unwrap();
+    let return_data = res.metadata.unwrap().return_data.unwrap();
+    SwapLiquidityReturnData::try_from_program_return_data(return_data.program_id, &return_data.data)
+        .unwrap()
+}
@@ -305,7 +305,7 @@ async fn exec_verify_add_liq_success_no_fees(
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[payer, liquidity_provider], *last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let return_data = process_tx_get_s_controller_return_data(banks_client, tx).await;
 
     let lp_token_account = banks_client
         .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
@@ -319,6 +319,10 @@ async fn exec_verify_add_liq_success_no_fees(
         // since LST should be worth >1 SOL
         assert!(lp_token_increase > lst_account_starting_balance);
     }
+    assert_eq!(return_data.amount_in, lst_account_starting_balance);
+    assert_eq!(return_data.amount_out, lp_token_increase);
+    assert_eq!(return_data.protocol_fees, 0);
+    assert_eq!(return_data.in_sol_value, return_data.out_sol_value);
 
     let lst_account = banks_client
         .get_account_unwrapped(lst_account_to_add_from)
@@ -0,0 +1,226 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{PoolState, SControllerError};
+use s_controller_lib::{
+    add_liquidity_multi_ix_full, try_lst_state_list, try_pool_state, AddLiquidityEvent,
+    AddLiquidityMultiByMintsFreeArgs, AddLiquidityMultiLst, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, SControllerEvent,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{mint_supply, token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
+
+const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+const MSOL_TO_ADD: u64 = 1_000_000_000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    liquidity_provider: Keypair,
+    lp_token_mint: Pubkey,
+    src_lst_accs: [Pubkey; 2],
+    dst_lp_acc: Pubkey,
+}
+
+async fn setup() -> Setup {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let src_lst_accs =
+        [(jitosol::ID, JITOSOL_TO_ADD), (msol::ID, MSOL_TO_ADD)].map(|(mint, amount)| {
+            program_test.gen_and_add_token_account(MockTokenAccountArgs {
+                mint,
+                authority: liquidity_provider.pubkey(),
+                amount,
+            })
+        });
+    let dst_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: liquidity_provider.pubkey(),
+        amount: 0,
+    });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    Setup {
+        ctx,
+        liquidity_provider,
+        lp_token_mint,
+        src_lst_accs,
+        dst_lp_acc,
+    }
+}
+
+/// `lst_indexes` indexes into [jitosol, msol]
+async fn add_liquidity_multi_tx(
+    banks_client: &mut BanksClient,
+    setup: &Setup,
+    lst_indexes: &[usize],
+    min_lp_out: u64,
+) -> Transaction {
+    const LST_AMOUNTS: [u64; 2] = [JITOSOL_TO_ADD, MSOL_TO_ADD];
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts: Vec<AccountMeta> = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas().to_vec();
+    let sol_val_calc_accounts = [jito_sol_val_calc_accounts, marinade_sol_val_calc_accounts];
+    let mints = [jitosol::ID, msol::ID];
+    let pricing_program_accounts = mints.map(|pubkey| [AccountMeta::new_readonly(pubkey, false)]);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_keys) = AddLiquidityMultiByMintsFreeArgs {
+        signer: setup.liquidity_provider.pubkey(),
+        dst_lp_acc: setup.dst_lp_acc,
+        pool_state: pool_state_acc,
+        lst_state_list: lst_state_list_acc,
+        lst_mints: &lst_indexes
+            .iter()
+            .map(|i| MintWithTokenProgram {
+                pubkey: mints[*i],
+                token_program: spl_token::ID,
+            })
+            .collect::<Vec<_>>(),
+        src_lst_accs: &lst_indexes
+            .iter()
+            .map(|i| setup.src_lst_accs[*i])
+            .collect::<Vec<_>>(),
+    }
+    .resolve()
+    .unwrap();
+    let lsts: Vec<AddLiquidityMultiLst> = lst_indexes
+        .iter()
+        .zip(lst_keys)
+        .map(|(i, (keys, lst_index, program_ids))| AddLiquidityMultiLst {
+            keys,
+            lst_index,
+            lst_amount: LST_AMOUNTS[*i],
+            extra_accounts: AddRemoveLiquidityExtraAccounts::new(
+                program_ids,
+                AddRemoveLiquidityAccountSuffixes {
+                    lst_calculator_accounts: &sol_val_calc_accounts[*i],
+                    pricing_program_price_lp_accounts: &pricing_program_accounts[*i],
+                },
+            ),
+        })
+        .collect();
+    let ix = add_liquidity_multi_ix_full(keys, min_lp_out, &lsts).unwrap();
+
+    let payer = &setup.ctx.payer;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(
+        &[payer, &setup.liquidity_provider],
+        setup.ctx.last_blockhash,
+    );
+    tx
+}
+
+async fn token_balance(banks_client: &mut BanksClient, addr: Pubkey) -> u64 {
+    token_account_balance(banks_client.get_account_unwrapped(addr).await).unwrap()
+}
+
+#[tokio::test]
+async fn basic_add_liquidity_multi_no_fee() {
+    let setup = setup().await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = add_liquidity_multi_tx(&mut banks_client, &setup, &[0, 1], 0).await;
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+
+    for src_lst_acc in setup.src_lst_accs {
+        assert_eq!(token_balance(&mut banks_client, src_lst_acc).await, 0);
+    }
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let PoolState {
+        total_sol_value, ..
+    } = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(
+        *total_sol_value,
+        lst_state_list.iter().map(|s| s.sol_value).sum::<u64>()
+    );
+
+    // no fees and empty pool: LP tokens minted 1:1 with SOL value added
+    let lp_tokens_minted = token_balance(&mut banks_client, setup.dst_lp_acc).await;
+    assert_eq!(lp_tokens_minted, *total_sol_value);
+    let lp_token_mint_acc = banks_client
+        .get_account_unwrapped(setup.lp_token_mint)
+        .await;
+    assert_eq!(mint_supply(lp_token_mint_acc).unwrap(), lp_tokens_minted);
+
+    assert_eq!(
+        events,
+        [JITOSOL_TO_ADD, MSOL_TO_ADD]
+            .into_iter()
+            .zip(lst_state_list)
+            .enumerate()
+            .map(|(lst_index, (lst_amount, lst_state))| {
+                SControllerEvent::AddLiquidityMulti(AddLiquidityEvent {
+                    lst_index: lst_index.try_into().unwrap(),
+                    lst_amount,
+                    lst_amount_sol_value: lst_state.sol_value,
+                    lp_tokens_minted,
+                    protocol_fees: 0,
+                    total_sol_value: *total_sol_value,
+                })
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn fail_slippage() {
+    let setup = setup().await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = add_liquidity_multi_tx(&mut banks_client, &setup, &[0, 1], u64::MAX).await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
+
+#[tokio::test]
+async fn fail_duplicate_lst() {
+    let setup = setup().await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = add_liquidity_multi_tx(&mut banks_client, &setup, &[1, 1], 0).await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::InvalidAddLiquidityMultiEntries);
+}
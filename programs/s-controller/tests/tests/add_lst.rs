@@ -10,21 +10,30 @@ use s_controller_test_utils::{
     PoolStateProgramTest, DEFAULT_POOL_STATE,
 };
 use sanctum_solana_test_utils::{
-    assert_custom_err, assert_program_error, test_fixtures_dir,
+    assert_custom_err, assert_program_error, est_rent_exempt_lamports, test_fixtures_dir,
     token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
-    ExtendedBanksClient,
+    ExtendedBanksClient, ExtendedProgramTest,
 };
 use solana_program::{
-    hash::Hash, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
-    system_instruction, system_program,
+    hash::Hash, program_error::ProgramError, program_option::COption, program_pack::Pack,
+    pubkey::Pubkey, system_instruction, system_program,
 };
 use solana_program_test::{processor, BanksClient, ProgramTest};
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{
+    account::Account,
     signature::{read_keypair_file, Keypair},
     signer::Signer,
     transaction::Transaction,
 };
+use spl_token_2022::{
+    extension::{
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        ExtensionType, StateWithExtensions, StateWithExtensionsMut,
+    },
+    state::Mint,
+};
 use test_utils::jitosol;
 
 use crate::common::*;
@@ -377,3 +386,134 @@ async fn fail_add_non_exec_sol_val_calc() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_custom_err(err, SControllerError::FaultySolValueCalculator);
 }
+
+#[tokio::test]
+async fn add_token_2022_transfer_fee_lst() {
+    let (program_test, mock_auth_kp) = jito_marinade_add_lst_program_test();
+    let lst_mint = Pubkey::new_unique();
+    let lst_mint_acc = token_2022_lst_mint(&[ExtensionType::TransferFeeConfig], |state| {
+        let transfer_fee = TransferFee {
+            epoch: 0u64.into(),
+            maximum_fee: u64::MAX.into(),
+            transfer_fee_basis_points: 100u16.into(),
+        };
+        let transfer_fee_config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        transfer_fee_config.older_transfer_fee = transfer_fee;
+        transfer_fee_config.newer_transfer_fee = transfer_fee;
+    });
+    let program_test = program_test.add_account_chained(lst_mint, lst_mint_acc.clone());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let tx = add_lst_test_tx(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        KeyedAccount {
+            pubkey: lst_mint,
+            account: lst_mint_acc,
+        },
+    )
+    .await;
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    let (_i, lst_state) = try_find_lst_mint_on_list(lst_mint, lst_state_list).unwrap();
+    assert_eq!(
+        lst_state.sol_value_calculator,
+        spl_calculator_lib::program::ID
+    );
+
+    let (pool_reserves_addr, _bump) = find_pool_reserves_address(FindLstPdaAtaKeys {
+        lst_mint,
+        token_program: spl_token_2022::ID,
+    });
+    let pool_reserves = banks_client.get_account_unwrapped(pool_reserves_addr).await;
+    assert_eq!(pool_reserves.owner, spl_token_2022::ID);
+    let pool_reserves_token_account =
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&pool_reserves.data).unwrap();
+    assert_eq!(pool_reserves_token_account.base.owner, POOL_STATE_ID);
+    assert_eq!(pool_reserves_token_account.base.amount, 0);
+}
+
+#[tokio::test]
+async fn fail_add_token_2022_permanent_delegate_lst() {
+    let (program_test, mock_auth_kp) = jito_marinade_add_lst_program_test();
+    let lst_mint = Pubkey::new_unique();
+    let lst_mint_acc = token_2022_lst_mint(&[ExtensionType::PermanentDelegate], |state| {
+        state
+            .init_extension::<PermanentDelegate>(true)
+            .unwrap()
+            .delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+    });
+    let program_test = program_test.add_account_chained(lst_mint, lst_mint_acc.clone());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let tx = add_lst_test_tx(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        KeyedAccount {
+            pubkey: lst_mint,
+            account: lst_mint_acc,
+        },
+    )
+    .await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::UnsupportedLstMintExtension);
+}
+
+/// An initialized token-2022 LST mint with `extension_types`,
+/// which should be initialized by `init_extensions`
+fn token_2022_lst_mint(
+    extension_types: &[ExtensionType],
+    init_extensions: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extension_types).unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    init_extensions(&mut state);
+    state.base = Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+async fn add_lst_test_tx(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    mock_auth_kp: &Keypair,
+    last_blockhash: Hash,
+    lst_mint: KeyedAccount,
+) -> Transaction {
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let (keys, _bumps) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_account,
+        },
+        lst_mint,
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_lst_ix(keys).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, mock_auth_kp], last_blockhash);
+    tx
+}
@@ -0,0 +1,732 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{
+    add_lst_ix, add_protocol_fee_beneficiary_ix, initialize_ix, withdraw_protocol_fees_ix,
+    AddProtocolFeeBeneficiaryIxArgs, InitializeKeys, ProtocolFeeBeneficiaryShare,
+    WithdrawProtocolFeesIxArgs,
+};
+use s_controller_lib::{
+    add_liquidity_ix_full, calc_protocol_fee_beneficiary_share, distribute_protocol_fees_ix_full,
+    end_rebalance_ix_full, find_pool_reserves_address_with_pool_state_id,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    protocol_fee_beneficiary_atas, remove_liquidity_ix_full, start_rebalance_ix_full,
+    swap_exact_in_ix_by_mint_full_for_pool, try_lst_state_list, try_pool_state,
+    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs, AddLstFreeArgs,
+    AddProtocolFeeBeneficiaryFreeArgs, AddRemoveLiquidityExtraAccounts,
+    DistributeProtocolFeesFreeArgs, EndRebalanceFromStartRebalanceKeys, FindLstPdaAtaKeys,
+    InitializeFreeArgs, PoolKey, RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts,
+    RemoveLiquidityIxFullArgs, SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes,
+    SrcDstLstSolValueCalcAccounts, StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs,
+    StartRebalanceIxLstAmts, SwapByMintsFreeArgs, SwapExactInAmounts, U8Bool,
+    WithdrawProtocolFeesFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test_for_pool, AddSplProgramTest,
+    GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs, LpTokenProgramTest,
+    MockLpMintToInitArgs,
+};
+use sanctum_solana_test_utils::{
+    assert_program_error, test_fixtures_dir,
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedBanksClient,
+};
+use sanctum_token_lib::{
+    mint_supply, token_account_balance, transfer_checked_ix, MintWithTokenProgram,
+    TransferCheckedArgs, TransferCheckedKeys,
+};
+use solana_program::{
+    clock::Clock, instruction::AccountMeta, program_error::ProgramError, program_option::COption,
+    program_pack::Pack, pubkey::Pubkey,
+};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use spl_token::state::Mint;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+/// Returns (program_test, mock_auth_kp, lp_token_mint_addr)
+fn setup() -> (ProgramTest, Keypair, Pubkey) {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let lp_token_mint_addr = Pubkey::new_unique();
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_spl_progs()
+        .add_jito_stake_pool()
+        .add_mock_lp_mint_to_init(MockLpMintToInitArgs {
+            initial_authority: mock_auth_kp.pubkey(),
+            addr: lp_token_mint_addr,
+        });
+    (program_test, mock_auth_kp, lp_token_mint_addr)
+}
+
+#[tokio::test]
+async fn initialize_and_add_lst_to_additional_pool() {
+    let (program_test, mock_auth_kp, lp_token_mint_addr) = setup();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool = PoolKey::new(lp_token_mint_addr);
+    assert_ne!(pool.pool_state, POOL_STATE_ID);
+
+    let ix = initialize_ix(
+        InitializeFreeArgs {
+            payer: payer.pubkey(),
+            lp_token_mint: lp_token_mint_addr,
+        }
+        .resolve_for_pool(&pool),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.lp_token_mint, lp_token_mint_addr);
+    assert_eq!(
+        PoolKey::try_from_pool_state(pool.pool_state, pool_state).unwrap(),
+        pool
+    );
+
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint_addr).await;
+    let lp_token_mint = Mint::unpack(&lp_token_mint_acc.data).unwrap();
+    assert_eq!(lp_token_mint.mint_authority, COption::Some(pool.pool_state));
+    assert_eq!(
+        lp_token_mint.freeze_authority,
+        COption::Some(pool.pool_state)
+    );
+
+    let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
+    let (keys, _bumps) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: KeyedAccount {
+            pubkey: pool.pool_state,
+            account: pool_state_acc,
+        },
+        lst_mint: KeyedAccount {
+            pubkey: jitosol::ID,
+            account: jitosol_mint_acc,
+        },
+    }
+    .resolve_for_pool(&pool)
+    .unwrap();
+    let ix = add_lst_ix(keys).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(pool.lst_state_list_pda().address)
+        .await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    assert_eq!(lst_state_list.len(), 1);
+    assert_eq!(lst_state_list[0].mint, jitosol::ID);
+
+    let pool_reserves_acc = banks_client.get_account_unwrapped(keys.pool_reserves).await;
+    let pool_reserves = spl_token::state::Account::unpack(&pool_reserves_acc.data).unwrap();
+    assert_eq!(pool_reserves.owner, pool.pool_state);
+
+    // default pool untouched
+    assert!(banks_client
+        .get_account(POOL_STATE_ID)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(banks_client
+        .get_account(LST_STATE_LIST_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn fail_initialize_pool_state_of_other_lp_token_mint() {
+    let (program_test, mock_auth_kp, lp_token_mint_addr) = setup();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let other_pool = PoolKey::new(Pubkey::new_unique());
+    let ix = initialize_ix(InitializeKeys {
+        pool_state: other_pool.pool_state,
+        ..InitializeFreeArgs {
+            payer: payer.pubkey(),
+            lp_token_mint: lp_token_mint_addr,
+        }
+        .resolve_for_pool(&PoolKey::new(lp_token_mint_addr))
+    })
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+/// Returns (program_test, pool) of a mocked additional pool with LP token mint
+/// `args.lp_token_mint` and the no-fee pricing program. The default pool does not exist.
+fn jito_marinade_additional_pool_program_test(
+    args: JitoMarinadeProgramTestArgs,
+) -> (ProgramTest, PoolKey) {
+    let pool = PoolKey::new(args.lp_token_mint);
+    let program_test = jito_marinade_no_fee_program_test_for_pool(&pool, args).add_s_program();
+    (program_test, pool)
+}
+
+async fn start_at_jito_stake_pool_last_update_epoch(
+    program_test: ProgramTest,
+) -> ProgramTestContext {
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    ctx
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec()
+}
+
+fn pool_reserves_of(pool: &PoolKey, lst_mint: Pubkey) -> Pubkey {
+    find_pool_reserves_address_with_pool_state_id(
+        pool.pool_state,
+        FindLstPdaAtaKeys {
+            lst_mint,
+            token_program: spl_token::ID,
+        },
+    )
+    .0
+}
+
+async fn token_balance(banks_client: &mut BanksClient, addr: Pubkey) -> u64 {
+    token_account_balance(banks_client.get_account_unwrapped(addr).await).unwrap()
+}
+
+#[tokio::test]
+async fn swap_exact_in_on_additional_pool() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+
+    let (mut program_test, pool) =
+        jito_marinade_additional_pool_program_test(JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES,
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            lp_token_mint: Pubkey::new_unique(),
+            ..Default::default()
+        });
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let start_pool_total_sol_value = try_pool_state(&pool_state_acc.data)
+        .unwrap()
+        .total_sol_value;
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(pool.lst_state_list_pda().address)
+        .await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+
+    let ix = swap_exact_in_ix_by_mint_full_for_pool(
+        &pool,
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+        },
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+        &[
+            AccountMeta::new_readonly(msol::ID, false),
+            AccountMeta::new_readonly(jitosol::ID, false),
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut banks_client, swapper_msol_acc_addr).await,
+        0
+    );
+    let jitosol_received = token_balance(&mut banks_client, swapper_jitosol_acc_addr).await;
+    assert!(jitosol_received > MSOL_TO_SWAP_IN);
+
+    assert_eq!(
+        token_balance(&mut banks_client, pool_reserves_of(&pool, msol::ID)).await,
+        MSOL_STARTING_POOL_RESERVES + MSOL_TO_SWAP_IN
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, pool_reserves_of(&pool, jitosol::ID)).await,
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_received
+    );
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let end_pool_total_sol_value = try_pool_state(&pool_state_acc.data)
+        .unwrap()
+        .total_sol_value;
+    assert!(start_pool_total_sol_value < end_pool_total_sol_value);
+}
+
+#[tokio::test]
+async fn add_remove_liquidity_on_additional_pool() {
+    const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let (mut program_test, pool) =
+        jito_marinade_additional_pool_program_test(JitoMarinadeProgramTestArgs {
+            lp_token_mint,
+            ..Default::default()
+        });
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let extra_accounts = AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id: spl_calculator_lib::program::ID,
+        pricing_program_id: no_fee_pricing_program::ID,
+        lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        pricing_program_price_lp_accounts: &[AccountMeta::new_readonly(jitosol::ID, false)],
+    };
+    let jitosol_mint = MintWithTokenProgram {
+        pubkey: jitosol::ID,
+        token_program: spl_token::ID,
+    };
+    let jitosol_pool_reserves = pool_reserves_of(&pool, jitosol::ID);
+
+    // add
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(pool.lst_state_list_pda().address)
+        .await;
+    let (keys, lst_index, _program_ids) = AddLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lst_acc: liquidity_provider_jitosol_acc_addr,
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: pool_state_acc,
+        lst_state_list: lst_state_list_acc,
+        lst_mint: jitosol_mint,
+    }
+    .resolve_for_pool(&pool)
+    .unwrap();
+    let ix = add_liquidity_ix_full(
+        keys,
+        AddLiquidityIxFullArgs {
+            lst_index,
+            amts: AddLiquidityIxAmts {
+                lst_amount: JITOSOL_TO_ADD,
+                min_lp_out: 0,
+            },
+        },
+        extra_accounts,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lp_tokens_received =
+        token_balance(&mut banks_client, liquidity_provider_lp_token_acc_addr).await;
+    assert!(lp_tokens_received > 0);
+    let lp_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    assert_eq!(mint_supply(lp_mint_acc).unwrap(), lp_tokens_received);
+    assert_eq!(
+        token_balance(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_TO_ADD
+    );
+
+    // remove
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(pool.lst_state_list_pda().address)
+        .await;
+    let (keys, lst_index, _program_ids) = RemoveLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lp_acc: liquidity_provider_lp_token_acc_addr,
+        dst_lst_acc: liquidity_provider_jitosol_acc_addr,
+        pool_state: pool_state_acc,
+        lst_state_list: lst_state_list_acc,
+        lst_mint: jitosol_mint,
+    }
+    .resolve_for_pool(&pool)
+    .unwrap();
+    let ix = remove_liquidity_ix_full(
+        keys,
+        RemoveLiquidityIxFullArgs {
+            lst_index,
+            amts: RemoveLiquidityIxAmts {
+                lp_token_amount: lp_tokens_received,
+                min_lst_out: 0,
+            },
+        },
+        extra_accounts,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut banks_client, liquidity_provider_lp_token_acc_addr).await,
+        0
+    );
+    let lp_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    assert_eq!(mint_supply(lp_mint_acc).unwrap(), 0);
+    let jitosol_returned =
+        token_balance(&mut banks_client, liquidity_provider_jitosol_acc_addr).await;
+    // a few lamports might be left in the pool due to rounding
+    assert!(jitosol_returned > 0);
+    assert_eq!(
+        token_balance(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_TO_ADD - jitosol_returned
+    );
+}
+
+#[tokio::test]
+async fn distribute_withdraw_protocol_fees_on_additional_pool() {
+    const MSOL_ACCUMULATED_FEES: u64 = 10_000_000_007;
+    const BENEFICIARY_WEIGHT_BPS: u16 = 3_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let share = ProtocolFeeBeneficiaryShare {
+        beneficiary: Pubkey::new_unique(),
+        weight_bps: BENEFICIARY_WEIGHT_BPS,
+    };
+    let beneficiary_atas = protocol_fee_beneficiary_atas(&[share], msol::ID, spl_token::ID);
+
+    let (mut program_test, pool) =
+        jito_marinade_additional_pool_program_test(JitoMarinadeProgramTestArgs {
+            msol_protocol_fee_accumulator: MSOL_ACCUMULATED_FEES,
+            lp_token_mint: Pubkey::new_unique(),
+            ..Default::default()
+        });
+    program_test = program_test.add_tokenkeg_account_from_args(
+        beneficiary_atas[0],
+        MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: share.beneficiary,
+            amount: 0,
+        },
+    );
+    let auth_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let ix = add_protocol_fee_beneficiary_ix(
+        AddProtocolFeeBeneficiaryFreeArgs {
+            payer: payer.pubkey(),
+            new_beneficiary: share.beneficiary,
+            pool_state_acc: KeyedAccount {
+                pubkey: pool.pool_state,
+                account: pool_state_acc.clone(),
+            },
+        }
+        .resolve_for_pool(&pool)
+        .unwrap(),
+        AddProtocolFeeBeneficiaryIxArgs {
+            weight_bps: share.weight_bps,
+        },
+    )
+    .unwrap();
+    let keys = DistributeProtocolFeesFreeArgs {
+        lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    }
+    .resolve_for_pool(&pool);
+    let protocol_fee_accumulator = keys.protocol_fee_accumulator;
+    let distribute_ix = distribute_protocol_fees_ix_full(keys, &beneficiary_atas).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix, distribute_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let beneficiary_amount =
+        calc_protocol_fee_beneficiary_share(MSOL_ACCUMULATED_FEES, BENEFICIARY_WEIGHT_BPS).unwrap();
+    assert_eq!(
+        token_balance(&mut banks_client, beneficiary_atas[0]).await,
+        beneficiary_amount
+    );
+    let remainder = MSOL_ACCUMULATED_FEES - beneficiary_amount;
+    assert_eq!(
+        token_balance(&mut banks_client, protocol_fee_accumulator).await,
+        remainder
+    );
+
+    let auth_msol_acc = banks_client.get_account_unwrapped(auth_msol_acc_addr).await;
+    let keys = WithdrawProtocolFeesFreeArgs {
+        pool_state: KeyedAccount {
+            pubkey: pool.pool_state,
+            account: pool_state_acc,
+        },
+        withdraw_to: KeyedAccount {
+            pubkey: auth_msol_acc_addr,
+            account: auth_msol_acc,
+        },
+    }
+    .resolve_for_pool(&pool)
+    .unwrap();
+    assert_eq!(
+        keys.protocol_fee_accumulator,
+        find_protocol_fee_accumulator_address_with_protocol_fee_id(
+            pool.protocol_fee_pda().address,
+            FindLstPdaAtaKeys {
+                lst_mint: msol::ID,
+                token_program: spl_token::ID,
+            },
+        )
+        .0
+    );
+    let ix =
+        withdraw_protocol_fees_ix(keys, WithdrawProtocolFeesIxArgs { amount: remainder }).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut banks_client, auth_msol_acc_addr).await,
+        remainder
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, protocol_fee_accumulator).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn rebalance_on_additional_pool() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 500_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let (mut program_test, pool) =
+        jito_marinade_additional_pool_program_test(JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: JITOSOL_START_SOL_VALUE,
+            msol_sol_value: MSOL_START_SOL_VALUE,
+            jitosol_reserves: JITOSOL_START_SOL_VALUE,
+            msol_reserves: MSOL_START_SOL_VALUE,
+            lp_token_mint: Pubkey::new_unique(),
+            ..Default::default()
+        });
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: MSOL_DONATE_AMT,
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let lst_state_list_addr = pool.lst_state_list_pda().address;
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(lst_state_list_addr)
+        .await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        _program_ids,
+    ) = StartRebalanceByMintsFreeArgs {
+        withdraw_to: withdraw_jitosol_to_addr,
+        lst_state_list: KeyedAccount {
+            pubkey: lst_state_list_addr,
+            account: lst_state_list_acc,
+        },
+        pool_state: KeyedAccount {
+            pubkey: pool.pool_state,
+            account: pool_state_acc,
+        },
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    }
+    .resolve_for_pool(&pool)
+    .unwrap();
+    let end_rebalance_keys = EndRebalanceFromStartRebalanceKeys(&start_rebalance_keys).resolve();
+
+    let start_rebalance_ix = start_rebalance_ix_full(
+        start_rebalance_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts: StartRebalanceIxLstAmts {
+                amount: JITOSOL_WITHDRAW_AMT,
+                min_starting_src_lst: 0,
+                max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: 0,
+            },
+        },
+        SrcDstLstSolValueCalcAccounts {
+            src_lst_calculator_program_id: spl_calculator_lib::program::ID,
+            dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+    )
+    .unwrap();
+    let donate_msol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_msol_from_addr,
+            to: end_rebalance_keys.dst_pool_reserves,
+            authority: mock_auth_kp.pubkey(),
+            mint: msol::ID,
+        },
+        TransferCheckedArgs {
+            amount: MSOL_DONATE_AMT,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let end_rebalance_ix = end_rebalance_ix_full(
+        end_rebalance_keys,
+        &marinade_sol_val_calc_accounts,
+        marinade_calculator_lib::program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(
+        &[start_rebalance_ix, donate_msol_ix, end_rebalance_ix],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_account_unwrapped(pool.pool_state).await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert!(pool_state.total_sol_value >= JITOSOL_START_SOL_VALUE + MSOL_START_SOL_VALUE);
+
+    let lst_state_list_acc = banks_client
+        .get_account_unwrapped(lst_state_list_addr)
+        .await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    for lst_state in lst_state_list {
+        if lst_state.mint == jitosol::ID {
+            assert!(lst_state.sol_value < JITOSOL_START_SOL_VALUE);
+        } else {
+            assert!(lst_state.sol_value > MSOL_START_SOL_VALUE);
+        }
+    }
+    assert_eq!(
+        token_balance(&mut banks_client, withdraw_jitosol_to_addr).await,
+        JITOSOL_WITHDRAW_AMT
+    );
+
+    let rebalance_record = banks_client
+        .get_account(pool.rebalance_record_pda().address)
+        .await
+        .unwrap();
+    assert!(rebalance_record.is_none());
+}
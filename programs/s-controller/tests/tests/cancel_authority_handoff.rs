@@ -0,0 +1,122 @@
+use s_controller_interface::{
+    accept_admin_ix, cancel_authority_handoff_ix, AcceptAdminKeys, CancelAuthorityHandoffIxArgs,
+    PendingAuthorities, SControllerError,
+};
+use s_controller_lib::{
+    program::{PENDING_AUTHORITIES_ID, POOL_STATE_ID},
+    CancelAuthorityHandoffFreeArgs,
+};
+use s_controller_test_utils::{
+    assert_admin, MockPoolState, PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
+};
+use sanctum_solana_test_utils::{assert_custom_err, test_fixtures_dir, IntoAccount};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn cancel_pending_admin() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let pending_admin_kp = Keypair::new();
+    let pending_rebalance_authority_kp = Keypair::new();
+    let pending_authorities = PendingAuthorities {
+        admin: pending_admin_kp.pubkey(),
+        rebalance_authority: pending_rebalance_authority_kp.pubkey(),
+        ..NO_PENDING_AUTHORITIES
+    };
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(pending_authorities);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = cancel_authority_handoff_ix(
+        CancelAuthorityHandoffFreeArgs {
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        CancelAuthorityHandoffIxArgs {
+            cancel_admin: true,
+            cancel_rebalance_authority: false,
+            cancel_protocol_fee_beneficiary: true,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        PendingAuthorities {
+            rebalance_authority: pending_rebalance_authority_kp.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+
+    // cancelled admin can no longer accept
+    let ix = accept_admin_ix(AcceptAdminKeys {
+        pending_admin: pending_admin_kp.pubkey(),
+        pool_state: POOL_STATE_ID,
+        pending_authorities: PENDING_AUTHORITIES_ID,
+    })
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &pending_admin_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::NoPendingAuthority);
+    assert_admin(&mut banks_client, mock_auth_kp.pubkey()).await;
+}
+
+#[tokio::test]
+async fn cancel_nothing_pending() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(NO_PENDING_AUTHORITIES);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = cancel_authority_handoff_ix(
+        CancelAuthorityHandoffFreeArgs {
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        CancelAuthorityHandoffIxArgs {
+            cancel_admin: true,
+            cancel_rebalance_authority: true,
+            cancel_protocol_fee_beneficiary: true,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::NoPendingAuthority);
+}
@@ -0,0 +1,73 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    deposit_stake_ix_by_mint_full, AddRemoveLiquidityAccountSuffixes, DepositStakeByMintFreeArgs,
+    DepositStakeProgramAccounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{assert_custom_err, token::MockTokenAccountArgs};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::SControllerProgramTest;
+
+#[tokio::test]
+async fn fail_unsupported_deposit_stake_program() {
+    let staker = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let stake_account = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let dst_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: staker.pubkey(),
+        amount: 0,
+    });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let ix = deposit_stake_ix_by_mint_full(
+        DepositStakeByMintFreeArgs {
+            signer: staker.pubkey(),
+            dst_lp_acc,
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+            lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        0,
+        0,
+        DepositStakeProgramAccounts {
+            deposit_stake_program_id: Pubkey::new_unique(),
+            deposit_stake_accounts: &[AccountMeta::new(stake_account, false)],
+        },
+        AddRemoveLiquidityAccountSuffixes {
+            lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            pricing_program_price_lp_accounts: &[AccountMeta::new_readonly(msol::ID, false)],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &staker], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::IncorrectDepositStakeProgram);
+}
@@ -0,0 +1,472 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use marinade_keys::msol;
+use s_controller_interface::{add_lst_ix, initialize_ix, SControllerError};
+use s_controller_lib::{
+    flash_borrow_ix_by_mint_full, flash_repay_ix_by_mint_full_for_pool,
+    program::FLASH_LOAN_RECORD_ID, set_lst_flash_loan_fee_ix_by_mint_full, try_pool_state,
+    AddLstFreeArgs, FlashBorrowByMintFreeArgs, FlashLoanEvent, FlashRepayByMintFreeArgs,
+    InitializeFreeArgs, PoolKey, SControllerEvent, SetLstFlashLoanFeeByMintFreeArgs, U8Bool,
+};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
+    GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs, LpTokenProgramTest,
+    LstStateListBanksClient, MockLpMintToInitArgs, MockProtocolFeeBps, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const JITOSOL_TO_BORROW: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn flash_loan_basic_with_fee() {
+    const FLASH_LOAN_FEE_BPS: u16 = 100;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
+    // 1% of JITOSOL_TO_BORROW
+    const EXPECTED_FEE: u64 = 10_000_000;
+    // 10% of EXPECTED_FEE
+    const EXPECTED_PROTOCOL_FEES: u64 = 1_000_000;
+    const BORROWER_STARTING_JITOSOL: u64 = EXPECTED_FEE;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let borrower = Keypair::new();
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program();
+
+    let borrower_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: borrower.pubkey(),
+        amount: BORROWER_STARTING_JITOSOL,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = set_lst_flash_loan_fee_ix_by_mint_full(
+        &SetLstFlashLoanFeeByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        FLASH_LOAN_FEE_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        banks_client
+            .get_lst_state(jitosol::ID)
+            .await
+            .flash_loan_fee_bps,
+        FLASH_LOAN_FEE_BPS
+    );
+
+    let [borrow_ix, repay_ix] = jitosol_flash_loan_ixs(
+        &mut banks_client,
+        borrower.pubkey(),
+        borrower_jitosol_acc_addr,
+        JITOSOL_TO_BORROW,
+    )
+    .await;
+    let jitosol_pool_reserves = borrow_ix.accounts[5].pubkey;
+    let jitosol_protocol_fee_accumulator = repay_ix.accounts[3].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[borrow_ix, repay_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &borrower], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+
+    let borrower_jitosol_acc = banks_client
+        .get_account_unwrapped(borrower_jitosol_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(borrower_jitosol_acc).unwrap(),
+        BORROWER_STARTING_JITOSOL - EXPECTED_FEE
+    );
+    let jitosol_pool_reserves_acc = banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_pool_reserves_acc).unwrap(),
+        JITOSOL_STARTING_POOL_RESERVES + EXPECTED_FEE - EXPECTED_PROTOCOL_FEES
+    );
+    let jitosol_protocol_fee_accumulator_acc = banks_client
+        .get_account_unwrapped(jitosol_protocol_fee_accumulator)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_protocol_fee_accumulator_acc).unwrap(),
+        EXPECTED_PROTOCOL_FEES
+    );
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+
+    let flash_loan_record = banks_client
+        .get_account(FLASH_LOAN_RECORD_ID)
+        .await
+        .unwrap();
+    assert!(flash_loan_record.is_none());
+
+    let [SControllerEvent::FlashLoan(FlashLoanEvent {
+        lst_index,
+        amount,
+        fee,
+        protocol_fees,
+        total_sol_value,
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+    // jitoSOL is index 0
+    assert_eq!(*lst_index, 0);
+    assert_eq!(*amount, JITOSOL_TO_BORROW);
+    assert_eq!(*fee, EXPECTED_FEE);
+    assert_eq!(*protocol_fees, EXPECTED_PROTOCOL_FEES);
+    assert_eq!(*total_sol_value, pool_state.total_sol_value);
+}
+
+#[tokio::test]
+async fn flash_borrow_fail_no_repay() {
+    let borrower = Keypair::new();
+
+    let mut program_test =
+        jito_marinade_no_fee_program_test(no_fee_program_test_args()).add_s_program();
+    let borrower_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: borrower.pubkey(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let [borrow_ix, _repay_ix] = jitosol_flash_loan_ixs(
+        &mut banks_client,
+        borrower.pubkey(),
+        borrower_jitosol_acc_addr,
+        JITOSOL_TO_BORROW,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[borrow_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::NoSucceedingFlashRepay);
+}
+
+#[tokio::test]
+async fn flash_borrow_fail_repay_to_other_pool() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let borrower = Keypair::new();
+    let other_lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(no_fee_program_test_args())
+        .add_s_program()
+        .add_mock_lp_mint_to_init(MockLpMintToInitArgs {
+            initial_authority: mock_auth_kp.pubkey(),
+            addr: other_lp_token_mint,
+        });
+    let borrower_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: borrower.pubkey(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    // set up another pool with jitoSOL
+    let other_pool = PoolKey::new(other_lp_token_mint);
+    let init_ix = initialize_ix(
+        InitializeFreeArgs {
+            payer: payer.pubkey(),
+            lp_token_mint: other_lp_token_mint,
+        }
+        .resolve_for_pool(&other_pool),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[init_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let (add_lst_keys, _bumps) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: KeyedAccount {
+            pubkey: other_pool.pool_state,
+            account: banks_client
+                .get_account_unwrapped(other_pool.pool_state)
+                .await,
+        },
+        lst_mint: KeyedAccount {
+            pubkey: jitosol::ID,
+            account: banks_client.get_account_unwrapped(jitosol::ID).await,
+        },
+    }
+    .resolve_for_pool(&other_pool)
+    .unwrap();
+    let add_lst_ix = add_lst_ix(add_lst_keys).unwrap();
+    let mut tx = Transaction::new_with_payer(&[add_lst_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // borrow jitoSOL from the default pool but repay it to the other pool
+    let [borrow_ix, repay_ix] = jitosol_flash_loan_ixs_repaying_pool(
+        &mut banks_client,
+        borrower.pubkey(),
+        borrower_jitosol_acc_addr,
+        JITOSOL_TO_BORROW,
+        &other_pool,
+    )
+    .await;
+    assert_eq!(repay_ix.accounts[5].pubkey, other_pool.pool_state);
+    assert_eq!(
+        repay_ix.accounts[6].pubkey,
+        other_pool.lst_state_list_pda().address
+    );
+
+    let mut tx = Transaction::new_with_payer(&[borrow_ix, repay_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &borrower], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::NoSucceedingFlashRepay);
+}
+
+#[tokio::test]
+async fn flash_borrow_fail_pool_locked_during_loan() {
+    let borrower = Keypair::new();
+
+    let mut program_test =
+        jito_marinade_no_fee_program_test(no_fee_program_test_args()).add_s_program();
+    let borrower_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: borrower.pubkey(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let [borrow_ix, repay_ix] = jitosol_flash_loan_ixs(
+        &mut banks_client,
+        borrower.pubkey(),
+        borrower_jitosol_acc_addr,
+        JITOSOL_TO_BORROW,
+    )
+    .await;
+
+    // nested flash loan while the first one is outstanding
+    let mut tx = Transaction::new_with_payer(
+        &[borrow_ix.clone(), borrow_ix, repay_ix],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &borrower], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::PoolRebalancing);
+}
+
+#[tokio::test]
+async fn set_flash_loan_fee_over_100_pct_fails() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = jito_marinade_no_fee_program_test(
+        JitoMarinadeProgramTestArgs::default().with_lp_token_mint(Pubkey::new_unique()),
+    )
+    .add_s_program();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_lst_flash_loan_fee_ix_by_mint_full(
+        &SetLstFlashLoanFeeByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        10_001,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::FeeTooHigh);
+}
+
+fn no_fee_program_test_args() -> JitoMarinadeProgramTestArgs {
+    JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES,
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    }
+}
+
+/// Returns [FlashBorrow, FlashRepay] of `amount` jitoSOL
+/// to and from `borrower_jitosol_acc_addr`
+async fn jitosol_flash_loan_ixs(
+    banks_client: &mut BanksClient,
+    borrower: Pubkey,
+    borrower_jitosol_acc_addr: Pubkey,
+    amount: u64,
+) -> [Instruction; 2] {
+    jitosol_flash_loan_ixs_repaying_pool(
+        banks_client,
+        borrower,
+        borrower_jitosol_acc_addr,
+        amount,
+        &PoolKey::DEFAULT,
+    )
+    .await
+}
+
+/// Same as [`jitosol_flash_loan_ixs`], but the FlashRepay is for `repay_pool`
+/// while the FlashBorrow remains for the default pool
+async fn jitosol_flash_loan_ixs_repaying_pool(
+    banks_client: &mut BanksClient,
+    borrower: Pubkey,
+    borrower_jitosol_acc_addr: Pubkey,
+    amount: u64,
+    repay_pool: &PoolKey,
+) -> [Instruction; 2] {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let repay_pool_state_acc = banks_client
+        .get_account_unwrapped(repay_pool.pool_state)
+        .await;
+    let repay_lst_state_list_acc = banks_client
+        .get_account_unwrapped(repay_pool.lst_state_list_pda().address)
+        .await;
+
+    let borrow_ix = flash_borrow_ix_by_mint_full(
+        FlashBorrowByMintFreeArgs {
+            dst_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        amount,
+    )
+    .unwrap();
+    let repay_ix = flash_repay_ix_by_mint_full_for_pool(
+        repay_pool,
+        FlashRepayByMintFreeArgs {
+            signer: borrower,
+            src_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: repay_pool_state_acc,
+            lst_state_list: repay_lst_state_list_acc,
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        &jito_sol_val_calc_accounts,
+    )
+    .unwrap();
+    [borrow_ix, repay_ix]
+}
@@ -72,7 +72,7 @@ async fn initialize_basic() {
             protocol_fee_beneficiary: initial_authority::ID,
             pricing_program: DEFAULT_PRICING_PROGRAM,
             lp_token_mint: lp_token_mint_addr,
-            padding: [0; 1] // dont care
+            timelock_delay: 0,
         }
     );
 
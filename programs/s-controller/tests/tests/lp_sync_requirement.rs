@@ -0,0 +1,149 @@
+use marinade_keys::msol;
+use s_controller_interface::{PoolConfig, SControllerError};
+use s_controller_lib::{
+    add_liquidity_ix_full, AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
+    AddRemoveLiquidityExtraAccounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolConfigProgramTest,
+    PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::BanksClientError;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+
+const MSOL_SOL_VALUE: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn add_liquidity_fails_if_other_lst_not_synced() {
+    let res = exec_add_jitosol_liquidity(0).await;
+    assert_custom_err(res.unwrap_err(), SControllerError::LstNotSyncedThisEpoch);
+}
+
+#[tokio::test]
+async fn add_liquidity_ignores_unsynced_lst_below_dust_threshold() {
+    exec_add_jitosol_liquidity(MSOL_SOL_VALUE + 1)
+        .await
+        .unwrap();
+}
+
+/// Adds jitoSOL liquidity to a pool that requires synced LSTs for LP
+/// while mSOL has never been synced.
+/// jitoSOL is synced by the AddLiquidity instruction itself.
+async fn exec_add_jitosol_liquidity(
+    lp_synced_lsts_dust_threshold: u64,
+) -> Result<(), BanksClientError> {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: MSOL_SOL_VALUE,
+        jitosol_reserves: 0,
+        msol_reserves: MSOL_SOL_VALUE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: MSOL_SOL_VALUE,
+    })
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 0,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 1,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: 0,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold,
+        rebalance_authority_epoch_spent: 0,
+    });
+    let src_lst_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: liquidity_provider.pubkey(),
+        amount: JITOSOL_TO_ADD,
+    });
+    let dst_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: liquidity_provider.pubkey(),
+        amount: 0,
+    });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let mut banks_client = ctx.banks_client;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_index, _program_ids) = AddLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lst_acc,
+        dst_lp_acc,
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: &lst_state_list_account,
+        lst_mint: KeyedAccount {
+            pubkey: jitosol::ID,
+            account: banks_client.get_account_unwrapped(jitosol::ID).await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_liquidity_ix_full(
+        keys,
+        AddLiquidityIxFullArgs {
+            lst_index,
+            amts: AddLiquidityIxAmts {
+                lst_amount: JITOSOL_TO_ADD,
+                min_lp_out: 0,
+            },
+        },
+        AddRemoveLiquidityExtraAccounts {
+            lst_calculator_program_id: spl_calculator_lib::program::ID,
+            pricing_program_id: no_fee_pricing_program::ID,
+            lst_calculator_accounts: &SplLstSolCommonFreeArgsConst {
+                spl_stake_pool: KeyedAccount {
+                    pubkey: jito_stake_pool::ID,
+                    account: jito_stake_pool_acc,
+                },
+            }
+            .resolve_spl_to_account_metas()
+            .unwrap(),
+            pricing_program_price_lp_accounts: &[AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            }],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &liquidity_provider], ctx.last_blockhash);
+    banks_client.process_transaction(tx).await?;
+
+    let msol_last_sync_epoch = banks_client.get_lst_state(msol::ID).await.last_sync_epoch;
+    assert_eq!(msol_last_sync_epoch, 0);
+    assert_eq!(
+        banks_client
+            .get_lst_state(jitosol::ID)
+            .await
+            .last_sync_epoch,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH
+    );
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use s_controller_interface::{
+    migrate_ix, set_protocol_fee_ix, LstState, PoolState, SControllerError, SetProtocolFeeIxArgs,
+};
+use s_controller_lib::{
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    set_lst_max_share_ix_by_mint_full, try_lst_state_list, try_lst_state_list_mut, try_pool_state,
+    MigrateFreeArgs, SetLstMaxShareByMintFreeArgs, SetProtocolFeeFreeArgs, CURRENT_PROGRAM_VERS,
+    LST_STATE_SIZE, LST_STATE_V1_SIZE, MIGRATABLE_PROGRAM_VERS,
+};
+use s_controller_test_utils::{
+    mock_lst_state, LstStateListBanksClient, MockLstStateArgs, MockLstStateRet,
+    PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, est_rent_exempt_lamports, test_fixtures_dir, ExtendedBanksClient,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_program};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    account::Account,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::SControllerProgramTest;
+
+const V1_POOL_STATE: PoolState = PoolState {
+    version: MIGRATABLE_PROGRAM_VERS,
+    ..DEFAULT_POOL_STATE
+};
+
+#[tokio::test]
+async fn migrate_v1_pool() {
+    let mock_auth_kp = mock_auth_kp();
+    let lst_states = mock_lst_states();
+    let program_test = v1_pool_program_test(&lst_states, &mock_auth_kp);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let admin_lamports_before = banks_client
+        .get_account_unwrapped(mock_auth_kp.pubkey())
+        .await
+        .lamports;
+    let lst_state_list_lamports_before = banks_client.get_lst_state_list_acc().await.lamports;
+
+    let ix = migrate_v1_pool_ix(&mut banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    assert_eq!(
+        try_pool_state(&pool_state_acc.data).unwrap().version,
+        CURRENT_PROGRAM_VERS
+    );
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    assert_eq!(
+        lst_state_list_acc.data.len(),
+        lst_states.len() * LST_STATE_SIZE
+    );
+    assert_eq!(
+        try_lst_state_list(&lst_state_list_acc.data).unwrap(),
+        lst_states
+    );
+    let rent = banks_client.get_rent().await.unwrap();
+    assert!(rent.is_exempt(lst_state_list_acc.lamports, lst_state_list_acc.data.len()));
+
+    let admin_lamports_after = banks_client
+        .get_account_unwrapped(mock_auth_kp.pubkey())
+        .await
+        .lamports;
+    assert_eq!(
+        admin_lamports_before - admin_lamports_after,
+        lst_state_list_acc.lamports - lst_state_list_lamports_before
+    );
+}
+
+#[tokio::test]
+async fn migrate_twice_fails() {
+    let mock_auth_kp = mock_auth_kp();
+    let program_test = v1_pool_program_test(&mock_lst_states(), &mock_auth_kp);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = migrate_v1_pool_ix(&mut banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix.clone()], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // different blockhash to avoid duplicate tx
+    let last_blockhash = banks_client
+        .get_new_latest_blockhash(&last_blockhash)
+        .await
+        .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::InvalidPoolVersion);
+}
+
+#[tokio::test]
+async fn unmigrated_pool_accepts_pool_state_instructions() {
+    let mock_auth_kp = mock_auth_kp();
+    let program_test = v1_pool_program_test(&mock_lst_states(), &mock_auth_kp);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_protocol_fee_ix(
+        SetProtocolFeeFreeArgs {
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: banks_client.get_pool_state_acc().await,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetProtocolFeeIxArgs {
+            new_lp_protocol_fee_bps: Some(1),
+            new_trading_protocol_fee_bps: None,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.lp_protocol_fee_bps, 1);
+    assert_eq!(pool_state.version, MIGRATABLE_PROGRAM_VERS);
+}
+
+#[tokio::test]
+async fn unmigrated_pool_rejects_lst_state_list_instructions() {
+    let mock_auth_kp = mock_auth_kp();
+    let lst_states = mock_lst_states();
+    let program_test = v1_pool_program_test(&lst_states, &mock_auth_kp);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    // the lib reads the old lst_state_list layout to resolve the instruction's accounts
+    let ix = set_lst_max_share_ix_by_mint_full(
+        &SetLstMaxShareByMintFreeArgs {
+            lst_mint: lst_states[1].mint,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        1_000,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::InvalidPoolVersion);
+}
+
+fn mock_auth_kp() -> Keypair {
+    read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+        .unwrap()
+}
+
+fn mock_lst_states() -> Vec<LstState> {
+    [1_000_000_000, 2_000_000_000, 0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, sol_value)| {
+            let MockLstStateRet { mut lst_state, .. } = mock_lst_state(MockLstStateArgs {
+                mint: Pubkey::new_unique(),
+                sol_value_calculator: Pubkey::new_unique(),
+                token_program: spl_token::ID,
+                sol_value,
+                reserves_amt: sol_value,
+                protocol_fee_accumulator_amt: 0,
+                is_input_disabled: i == 1,
+                is_output_disabled: i == 2,
+            });
+            lst_state.max_share_bps = 5_000;
+            lst_state.flash_loan_fee_bps = 10;
+            lst_state
+        })
+        .collect()
+}
+
+/// A pool on [`MIGRATABLE_PROGRAM_VERS`] with `lst_states` in the old layout,
+/// and a funded admin to pay for the migration's rent
+fn v1_pool_program_test(lst_states: &[LstState], mock_auth_kp: &Keypair) -> ProgramTest {
+    let mut data = vec![0u8; lst_states.len() * LST_STATE_SIZE];
+    try_lst_state_list_mut(&mut data)
+        .unwrap()
+        .copy_from_slice(lst_states);
+    let v1_data: Vec<u8> = data
+        .chunks_exact(LST_STATE_SIZE)
+        .flat_map(|lst_state| &lst_state[..LST_STATE_V1_SIZE])
+        .copied()
+        .collect();
+
+    let mut program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(V1_POOL_STATE);
+    program_test.add_account(
+        LST_STATE_LIST_ID,
+        Account {
+            lamports: est_rent_exempt_lamports(v1_data.len()),
+            data: v1_data,
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    );
+    program_test.add_account(
+        mock_auth_kp.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: Vec::new(),
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    );
+    program_test
+}
+
+async fn migrate_v1_pool_ix(banks_client: &mut BanksClient) -> Instruction {
+    migrate_ix(
+        MigrateFreeArgs {
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: banks_client.get_pool_state_acc().await,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap()
+}
@@ -1,21 +1,42 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
+mod additional_pool;
+mod cancel_authority_handoff;
+mod deposit_stake;
 mod disable_pool;
 mod enable_disable_lst_input;
+mod enable_disable_lst_output;
 mod enable_pool;
+mod flash_loan;
 mod initialize;
+mod lp_sync_requirement;
+mod migrate;
+mod protocol_fee_beneficiary_list;
+mod protocol_fees_as_lp;
+mod quote;
 mod rebalance;
+mod rebalance_authority_list;
+mod referral_fee;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_proportional;
+mod remove_liquidity_to_stake;
 mod remove_lst;
 mod set_admin;
+mod set_lp_token_metadata;
+mod set_lst_max_share;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
 mod set_rebalance_authority;
 mod set_sol_value_calculator;
+mod sol_value_rate_circuit_breaker;
 mod swap_exact_in;
 mod swap_exact_out;
 mod sync_sol_value;
+mod sync_sol_value_multi;
+mod timelock;
+mod transfer_fee_lst;
 mod withdraw_protocol_fees;
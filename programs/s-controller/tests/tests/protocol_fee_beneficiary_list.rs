@@ -0,0 +1,255 @@
+use marinade_keys::msol;
+use s_controller_interface::{
+    add_protocol_fee_beneficiary_ix, remove_protocol_fee_beneficiary_ix,
+    set_protocol_fee_beneficiary_weight_ix, AddProtocolFeeBeneficiaryIxArgs,
+    ProtocolFeeBeneficiaryShare, SControllerError, SetProtocolFeeBeneficiaryWeightIxArgs,
+};
+use s_controller_lib::{
+    calc_protocol_fee_beneficiary_share, distribute_protocol_fees_ix_full,
+    find_protocol_fee_accumulator_address, program::POOL_STATE_ID, protocol_fee_beneficiary_atas,
+    try_protocol_fee_beneficiary_list, AddProtocolFeeBeneficiaryFreeArgs,
+    DistributeProtocolFeesEvent, DistributeProtocolFeesFreeArgs, FindLstPdaAtaKeys,
+    RemoveProtocolFeeBeneficiaryByPubkeyFreeArgs, SControllerEvent,
+    SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, MockPoolState,
+    PoolStateProgramTest, ProtocolFeeBeneficiaryListBanksClient,
+    ProtocolFeeBeneficiaryListProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir,
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedBanksClient, IntoAccount,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::*;
+
+async fn get_list(banks_client: &mut BanksClient) -> Vec<ProtocolFeeBeneficiaryShare> {
+    let acc = banks_client.get_protocol_fee_beneficiary_list_acc().await;
+    try_protocol_fee_beneficiary_list(&acc.data)
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn add_set_weight_remove() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state = KeyedAccount {
+        pubkey: POOL_STATE_ID,
+        account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+    };
+    let [beneficiary_a, beneficiary_b] = [0; 2].map(|_| Keypair::new().pubkey());
+
+    let signed_tx = |ix| {
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+        tx
+    };
+    let add_ix = |new_beneficiary, weight_bps| {
+        add_protocol_fee_beneficiary_ix(
+            AddProtocolFeeBeneficiaryFreeArgs {
+                payer: payer.pubkey(),
+                new_beneficiary,
+                pool_state_acc: pool_state.clone(),
+            }
+            .resolve()
+            .unwrap(),
+            AddProtocolFeeBeneficiaryIxArgs { weight_bps },
+        )
+        .unwrap()
+    };
+
+    banks_client
+        .process_transaction(signed_tx(add_ix(beneficiary_a, 6_000)))
+        .await
+        .unwrap();
+    banks_client
+        .process_transaction(signed_tx(add_ix(beneficiary_b, 4_000)))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_list(&mut banks_client).await,
+        vec![
+            ProtocolFeeBeneficiaryShare {
+                beneficiary: beneficiary_a,
+                weight_bps: 6_000,
+            },
+            ProtocolFeeBeneficiaryShare {
+                beneficiary: beneficiary_b,
+                weight_bps: 4_000,
+            },
+        ]
+    );
+
+    // duplicate
+    let err = banks_client
+        .process_transaction(signed_tx(add_ix(beneficiary_a, 0)))
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::DuplicateProtocolFeeBeneficiary);
+
+    // total weight > 100%
+    let err = banks_client
+        .process_transaction(signed_tx(add_ix(Keypair::new().pubkey(), 1)))
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::ProtocolFeeBeneficiaryWeightsTooHigh);
+
+    let list_acc = banks_client.get_protocol_fee_beneficiary_list_acc().await;
+    let set_weight_ix = |weight_bps| {
+        let (keys, mut args) = SetProtocolFeeBeneficiaryWeightByPubkeyFreeArgs {
+            beneficiary: beneficiary_a,
+            pool_state_acc: pool_state.account.clone(),
+            protocol_fee_beneficiary_list: list_acc.clone(),
+        }
+        .resolve()
+        .unwrap();
+        args.weight_bps = weight_bps;
+        set_protocol_fee_beneficiary_weight_ix(keys, args).unwrap()
+    };
+    let err = banks_client
+        .process_transaction(signed_tx(set_weight_ix(6_001)))
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::ProtocolFeeBeneficiaryWeightsTooHigh);
+    banks_client
+        .process_transaction(signed_tx(set_weight_ix(5_000)))
+        .await
+        .unwrap();
+    assert_eq!(get_list(&mut banks_client).await[0].weight_bps, 5_000);
+
+    let (keys, args) = RemoveProtocolFeeBeneficiaryByPubkeyFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        beneficiary: beneficiary_a,
+        pool_state_acc: pool_state.account.clone(),
+        protocol_fee_beneficiary_list: list_acc,
+    }
+    .resolve()
+    .unwrap();
+    banks_client
+        .process_transaction(signed_tx(
+            remove_protocol_fee_beneficiary_ix(keys, args).unwrap(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_list(&mut banks_client).await,
+        vec![ProtocolFeeBeneficiaryShare {
+            beneficiary: beneficiary_b,
+            weight_bps: 4_000,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn distribute_protocol_fees_by_weight() {
+    const MSOL_ACCUMULATED_FEES: u64 = 10_000_000_007;
+    const WEIGHTS_BPS: [u16; 2] = [3_000, 5_000];
+
+    let shares = WEIGHTS_BPS.map(|weight_bps| ProtocolFeeBeneficiaryShare {
+        beneficiary: Pubkey::new_unique(),
+        weight_bps,
+    });
+    let beneficiary_atas = protocol_fee_beneficiary_atas(&shares, msol::ID, spl_token::ID);
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: MSOL_ACCUMULATED_FEES,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_protocol_fee_beneficiary_list(&shares)
+    .add_s_program();
+    for (share, ata) in shares.iter().zip(beneficiary_atas.iter()) {
+        program_test = program_test.add_tokenkeg_account_from_args(
+            *ata,
+            MockTokenAccountArgs {
+                mint: msol::ID,
+                authority: share.beneficiary,
+                amount: 0,
+            },
+        );
+    }
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let keys = DistributeProtocolFeesFreeArgs {
+        lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    }
+    .resolve();
+
+    // wrong number of beneficiary token accounts
+    let ix = distribute_protocol_fees_ix_full(keys, &beneficiary_atas[..1]).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(
+        err,
+        SControllerError::IncorrectProtocolFeeBeneficiaryTokenAccounts,
+    );
+
+    let ix = distribute_protocol_fees_ix_full(keys, &beneficiary_atas).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+
+    let expected_amounts = WEIGHTS_BPS.map(|weight_bps| {
+        calc_protocol_fee_beneficiary_share(MSOL_ACCUMULATED_FEES, weight_bps).unwrap()
+    });
+    let expected_events: Vec<_> = shares
+        .iter()
+        .zip(expected_amounts)
+        .map(|(share, amount)| {
+            SControllerEvent::DistributeProtocolFees(DistributeProtocolFeesEvent {
+                lst_mint: msol::ID,
+                beneficiary: share.beneficiary,
+                amount,
+            })
+        })
+        .collect();
+    assert_eq!(events, expected_events);
+
+    for (ata, expected_amount) in beneficiary_atas.iter().zip(expected_amounts) {
+        let acc = banks_client.get_account_unwrapped(*ata).await;
+        assert_eq!(token_account_balance(acc).unwrap(), expected_amount);
+    }
+
+    // remainder stays in the accumulator for the protocol fee beneficiary
+    let (protocol_fee_accumulator, _bump) =
+        find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
+            lst_mint: msol::ID,
+            token_program: spl_token::ID,
+        });
+    let acc = banks_client
+        .get_account_unwrapped(protocol_fee_accumulator)
+        .await;
+    assert_eq!(
+        token_account_balance(acc).unwrap(),
+        MSOL_ACCUMULATED_FEES - expected_amounts.iter().sum::<u64>()
+    );
+}
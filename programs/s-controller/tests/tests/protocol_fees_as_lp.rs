@@ -0,0 +1,793 @@
+use flat_fee_lib::account_resolvers::PriceExactInFreeArgs;
+use flat_fee_test_utils::MockFeeAccountArgs;
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{
+    set_protocol_fees_as_lp_ix, PoolConfig, PoolState, SControllerError, SetProtocolFeesAsLpIxArgs,
+};
+use s_controller_lib::{
+    convert_protocol_fees_to_lp_ix_by_mint_full, find_protocol_fee_lp_address,
+    flash_borrow_ix_by_mint_full, flash_repay_ix_by_mint_full,
+    program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
+    remove_liquidity_proportional_ix_full, set_lst_flash_loan_fee_ix_by_mint_full,
+    swap_exact_in_ix_by_mint_full, try_pool_state, ConvertProtocolFeesToLpByMintFreeArgs,
+    FlashBorrowByMintFreeArgs, FlashLoanEvent, FlashRepayByMintFreeArgs,
+    ProtocolFeesMintedAsLpEvent, RemoveLiquidityProportionalByListFreeArgs,
+    RemoveLiquidityProportionalLst, SControllerEvent, SetLstFlashLoanFeeByMintFreeArgs,
+    SetProtocolFeesAsLpFreeArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
+    SwapEvent, SwapExactInAmounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
+    GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs, LpTokenProgramTest,
+    LstStateListBanksClient, MockPoolState, MockProtocolFeeBps, PoolConfigBanksClient,
+    PoolConfigProgramTest, PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir,
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedBanksClient, IntoAccount,
+};
+use sanctum_token_lib::{mint_supply, token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
+
+const PROTOCOL_FEES_AS_LP_POOL_CONFIG: PoolConfig = PoolConfig {
+    remove_liquidity_proportional_fee_bps: 0,
+    referral_fee_bps: 0,
+    protocol_fees_as_lp: 1,
+    lp_requires_synced_lsts: 0,
+    max_rebalance_loss_bps: 0,
+    rebalance_authority_epoch_budget: 0,
+    rebalance_authority_last_epoch: 0,
+    lp_synced_lsts_dust_threshold: 0,
+    rebalance_authority_epoch_spent: 0,
+};
+
+#[tokio::test]
+async fn set_protocol_fees_as_lp_requires_protocol_fee_lp_acc() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool_state = PoolState {
+        lp_token_mint,
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(pool_state)
+        .add_mock_lp_mint(lp_token_mint, 0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let set_ix = |protocol_fees_as_lp| {
+        set_protocol_fees_as_lp_ix(
+            SetProtocolFeesAsLpFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: KeyedAccount {
+                    pubkey: POOL_STATE_ID,
+                    account: MockPoolState(pool_state).into_account(),
+                },
+            }
+            .resolve()
+            .unwrap(),
+            SetProtocolFeesAsLpIxArgs {
+                protocol_fees_as_lp,
+            },
+        )
+        .unwrap()
+    };
+
+    let mut tx = Transaction::new_with_payer(&[set_ix(true)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::ProtocolFeeLpAccountNotCreated);
+
+    let create_lp_acc_ix = create_associated_token_account_idempotent(
+        &payer.pubkey(),
+        &PROTOCOL_FEE_ID,
+        &lp_token_mint,
+        &spl_token::ID,
+    );
+    assert_eq!(
+        create_lp_acc_ix.accounts[1].pubkey,
+        find_protocol_fee_lp_address(lp_token_mint).0
+    );
+    let mut tx =
+        Transaction::new_with_payer(&[create_lp_acc_ix, set_ix(true)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(banks_client.get_pool_config().await.protocol_fees_as_lp, 1);
+
+    let mut tx = Transaction::new_with_payer(&[set_ix(false)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(banks_client.get_pool_config().await.protocol_fees_as_lp, 0);
+}
+
+#[tokio::test]
+async fn swap_exact_in_mints_protocol_fees_as_lp() {
+    let lp_token_mint = Pubkey::new_unique();
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const LP_TOKEN_STARTING_SUPPLY: u64 = 20_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    const JITOSOL_OUT_FEE_BPS: i16 = 6;
+    const MSOL_IN_FEE_BPS: i16 = 9;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+    let swapper = Keypair::new();
+    let protocol_fee_lp_acc = find_protocol_fee_lp_address(lp_token_mint).0;
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_STARTING_SUPPLY,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: JITOSOL_OUT_FEE_BPS,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: MSOL_IN_FEE_BPS,
+                output_fee_bps: Default::default(),
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program()
+    .add_pool_config(PROTOCOL_FEES_AS_LP_POOL_CONFIG)
+    .add_tokenkeg_account_from_args(
+        protocol_fee_lp_acc,
+        MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: PROTOCOL_FEE_ID,
+            amount: 0,
+        },
+    );
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &PriceExactInFreeArgs {
+            input_lst_mint: msol::ID,
+            output_lst_mint: jitosol::ID,
+        }
+        .resolve_to_account_metas(),
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+    let jitosol_protocol_fee_accumulator = ix.accounts[5].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    let [SControllerEvent::SwapExactIn(SwapEvent {
+        dst_lst_out,
+        protocol_fees,
+        ..
+    }), SControllerEvent::ProtocolFeesMintedAsLp(ProtocolFeesMintedAsLpEvent {
+        lst_index,
+        protocol_fees: protocol_fees_minted_as_lp,
+        protocol_fees_sol_value,
+        lp_tokens_minted,
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+
+    let mut balances = Vec::new();
+    for addr in [
+        swapper_jitosol_acc_addr,
+        jitosol_pool_reserves,
+        jitosol_protocol_fee_accumulator,
+        protocol_fee_lp_acc,
+    ] {
+        let acc = banks_client.get_account_unwrapped(addr).await;
+        balances.push(token_account_balance(acc).unwrap());
+    }
+    let [jitosol_received, jitosol_pool_reserves_balance, protocol_fee_accumulator_balance, protocol_fee_lp_balance] =
+        balances.try_into().unwrap();
+    let lp_token_supply =
+        mint_supply(banks_client.get_account_unwrapped(lp_token_mint).await).unwrap();
+
+    assert_eq!(jitosol_received, *dst_lst_out);
+    assert!(*protocol_fees > 0);
+    assert_eq!(*lst_index, 0);
+    assert_eq!(protocol_fees_minted_as_lp, protocol_fees);
+    assert!(*protocol_fees_sol_value > 0);
+    assert!(*lp_tokens_minted > 0);
+    assert_eq!(protocol_fee_accumulator_balance, 0);
+    assert_eq!(protocol_fee_lp_balance, *lp_tokens_minted);
+    assert_eq!(lp_token_supply, LP_TOKEN_STARTING_SUPPLY + lp_tokens_minted);
+    assert_eq!(
+        jitosol_pool_reserves_balance + jitosol_received,
+        JITOSOL_STARTING_POOL_RESERVES
+    );
+}
+
+const CONVERT_MSOL_RESERVES: u64 = 10_000_000_000;
+const CONVERT_MSOL_ACCUMULATED_FEES: u64 = 1_000_000_000;
+const CONVERT_LP_TOKEN_STARTING_SUPPLY: u64 = 10_000_000_000;
+
+fn convert_protocol_fees_to_lp_program_test(lp_token_mint: Pubkey) -> ProgramTest {
+    jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: CONVERT_MSOL_RESERVES,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: CONVERT_MSOL_ACCUMULATED_FEES,
+        lp_token_mint,
+        lp_token_supply: CONVERT_LP_TOKEN_STARTING_SUPPLY,
+    })
+    .add_tokenkeg_account_from_args(
+        find_protocol_fee_lp_address(lp_token_mint).0,
+        MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: PROTOCOL_FEE_ID,
+            amount: 0,
+        },
+    )
+    .add_s_program()
+}
+
+async fn convert_msol_protocol_fees_to_lp_ix(banks_client: &mut BanksClient) -> Instruction {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let msol_mint_acc = banks_client.get_account_unwrapped(msol::ID).await;
+    convert_protocol_fees_to_lp_ix_by_mint_full(
+        ConvertProtocolFeesToLpByMintFreeArgs {
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+            lst_mint: KeyedAccount {
+                pubkey: msol::ID,
+                account: msol_mint_acc,
+            },
+        },
+        &marinade_sol_val_calc_account_metas(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn convert_protocol_fees_to_lp_not_enabled() {
+    let lp_token_mint = Pubkey::new_unique();
+    let (mut banks_client, payer, last_blockhash) =
+        convert_protocol_fees_to_lp_program_test(lp_token_mint)
+            .start()
+            .await;
+
+    let ix = convert_msol_protocol_fees_to_lp_ix(&mut banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::ProtocolFeesAsLpNotEnabled);
+}
+
+#[tokio::test]
+async fn convert_protocol_fees_to_lp_basic() {
+    let lp_token_mint = Pubkey::new_unique();
+    let protocol_fee_lp_acc = find_protocol_fee_lp_address(lp_token_mint).0;
+    let (mut banks_client, payer, last_blockhash) =
+        convert_protocol_fees_to_lp_program_test(lp_token_mint)
+            .add_pool_config(PROTOCOL_FEES_AS_LP_POOL_CONFIG)
+            .start()
+            .await;
+
+    let ix = convert_msol_protocol_fees_to_lp_ix(&mut banks_client).await;
+    let msol_protocol_fee_accumulator = ix.accounts[1].pubkey;
+    let msol_pool_reserves = ix.accounts[3].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    let [SControllerEvent::ProtocolFeesMintedAsLp(ProtocolFeesMintedAsLpEvent {
+        lst_index,
+        protocol_fees,
+        protocol_fees_sol_value,
+        lp_tokens_minted,
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+
+    assert_eq!(*lst_index, 1);
+    assert_eq!(*protocol_fees, CONVERT_MSOL_ACCUMULATED_FEES);
+    assert!(*protocol_fees_sol_value > 0);
+    assert!(*lp_tokens_minted > 0);
+
+    let mut balances = Vec::new();
+    for addr in [
+        msol_protocol_fee_accumulator,
+        msol_pool_reserves,
+        protocol_fee_lp_acc,
+    ] {
+        let acc = banks_client.get_account_unwrapped(addr).await;
+        balances.push(token_account_balance(acc).unwrap());
+    }
+    let [protocol_fee_accumulator_balance, pool_reserves_balance, protocol_fee_lp_balance] =
+        balances.try_into().unwrap();
+    assert_eq!(protocol_fee_accumulator_balance, 0);
+    assert_eq!(
+        pool_reserves_balance,
+        CONVERT_MSOL_RESERVES + CONVERT_MSOL_ACCUMULATED_FEES
+    );
+    assert_eq!(protocol_fee_lp_balance, *lp_tokens_minted);
+
+    // LP tokens minted must not be worth more than the fees converted
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let total_sol_value = try_pool_state(&pool_state_acc.data)
+        .unwrap()
+        .total_sol_value;
+    let lp_token_supply =
+        mint_supply(banks_client.get_account_unwrapped(lp_token_mint).await).unwrap();
+    assert_eq!(
+        lp_token_supply,
+        CONVERT_LP_TOKEN_STARTING_SUPPLY + lp_tokens_minted
+    );
+    assert!(
+        u128::from(*lp_tokens_minted) * u128::from(total_sol_value)
+            <= u128::from(*protocol_fees_sol_value) * u128::from(lp_token_supply)
+    );
+}
+
+#[tokio::test]
+async fn flash_repay_mints_protocol_fees_as_lp() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const LP_TOKEN_STARTING_SUPPLY: u64 = 20_000_000_000;
+    const JITOSOL_TO_BORROW: u64 = 1_000_000_000;
+    const FLASH_LOAN_FEE_BPS: u16 = 100;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
+    // 1% of JITOSOL_TO_BORROW
+    const EXPECTED_FEE: u64 = 10_000_000;
+    // 10% of EXPECTED_FEE
+    const EXPECTED_PROTOCOL_FEES: u64 = 1_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let borrower = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let protocol_fee_lp_acc = find_protocol_fee_lp_address(lp_token_mint).0;
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_STARTING_SUPPLY,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program()
+    .add_pool_config(PROTOCOL_FEES_AS_LP_POOL_CONFIG)
+    .add_tokenkeg_account_from_args(
+        protocol_fee_lp_acc,
+        MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: PROTOCOL_FEE_ID,
+            amount: 0,
+        },
+    );
+
+    let borrower_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: borrower.pubkey(),
+        amount: EXPECTED_FEE,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = set_lst_flash_loan_fee_ix_by_mint_full(
+        &SetLstFlashLoanFeeByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        FLASH_LOAN_FEE_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let jitosol_mint = MintWithTokenProgram {
+        pubkey: jitosol::ID,
+        token_program: spl_token::ID,
+    };
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let borrow_ix = flash_borrow_ix_by_mint_full(
+        FlashBorrowByMintFreeArgs {
+            dst_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: pool_state_acc.clone(),
+            lst_state_list: lst_state_list_acc.clone(),
+            lst_mint: jitosol_mint,
+        },
+        JITOSOL_TO_BORROW,
+    )
+    .unwrap();
+    let repay_ix = flash_repay_ix_by_mint_full(
+        FlashRepayByMintFreeArgs {
+            signer: borrower.pubkey(),
+            src_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+            lst_mint: jitosol_mint,
+        },
+        &jito_sol_val_calc_accounts,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = borrow_ix.accounts[5].pubkey;
+    let jitosol_protocol_fee_accumulator = repay_ix.accounts[3].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[borrow_ix, repay_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &borrower], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    let [SControllerEvent::FlashLoan(FlashLoanEvent {
+        fee, protocol_fees, ..
+    }), SControllerEvent::ProtocolFeesMintedAsLp(ProtocolFeesMintedAsLpEvent {
+        lst_index,
+        protocol_fees: protocol_fees_minted_as_lp,
+        protocol_fees_sol_value,
+        lp_tokens_minted,
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+
+    assert_eq!(*fee, EXPECTED_FEE);
+    assert_eq!(*protocol_fees, EXPECTED_PROTOCOL_FEES);
+    assert_eq!(*lst_index, 0);
+    assert_eq!(protocol_fees_minted_as_lp, protocol_fees);
+    assert!(*protocol_fees_sol_value > 0);
+    assert!(*lp_tokens_minted > 0);
+
+    let mut balances = Vec::new();
+    for addr in [
+        jitosol_pool_reserves,
+        jitosol_protocol_fee_accumulator,
+        protocol_fee_lp_acc,
+    ] {
+        let acc = banks_client.get_account_unwrapped(addr).await;
+        balances.push(token_account_balance(acc).unwrap());
+    }
+    let [pool_reserves_balance, protocol_fee_accumulator_balance, protocol_fee_lp_balance] =
+        balances.try_into().unwrap();
+    assert_eq!(
+        pool_reserves_balance,
+        JITOSOL_STARTING_POOL_RESERVES + EXPECTED_FEE
+    );
+    assert_eq!(protocol_fee_accumulator_balance, 0);
+    assert_eq!(protocol_fee_lp_balance, *lp_tokens_minted);
+    let lp_token_supply =
+        mint_supply(banks_client.get_account_unwrapped(lp_token_mint).await).unwrap();
+    assert_eq!(lp_token_supply, LP_TOKEN_STARTING_SUPPLY + lp_tokens_minted);
+}
+
+#[tokio::test]
+async fn remove_liquidity_proportional_mints_protocol_fees_as_lp() {
+    const RESERVES_STARTING_BALANCE: u64 = 1_000_000_000;
+    const LP_TOKEN_STARTING_SUPPLY: u64 = 2_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_STARTING_SUPPLY / 4;
+    const REMOVE_LIQUIDITY_PROPORTIONAL_FEE_BPS: u16 = 100;
+    const LP_PROTOCOL_FEE_BPS: u16 = 5_000;
+    // 250_000_000 - 1% = 247_500_000
+    const EXPECTED_LST_OUT: u64 = 247_500_000;
+    // 50% of the 2_500_000 fee
+    const EXPECTED_PROTOCOL_FEES: u64 = 1_250_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let protocol_fee_lp_acc = find_protocol_fee_lp_address(lp_token_mint).0;
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: RESERVES_STARTING_BALANCE, // updated on sync
+            msol_sol_value: RESERVES_STARTING_BALANCE,    // updated on sync
+            jitosol_reserves: RESERVES_STARTING_BALANCE,
+            msol_reserves: RESERVES_STARTING_BALANCE,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_STARTING_SUPPLY,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [jitosol::ID, msol::ID].map(|lst_mint| MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }),
+        MockProtocolFeeBps {
+            trading: Default::default(),
+            lp: LP_PROTOCOL_FEE_BPS,
+        },
+    )
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: REMOVE_LIQUIDITY_PROPORTIONAL_FEE_BPS,
+        ..PROTOCOL_FEES_AS_LP_POOL_CONFIG
+    })
+    .add_tokenkeg_account_from_args(
+        protocol_fee_lp_acc,
+        MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: PROTOCOL_FEE_ID,
+            amount: 0,
+        },
+    );
+    let dst_lst_accs = [jitosol::ID, msol::ID].map(|mint| {
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        })
+    });
+    let src_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: liquidity_provider.pubkey(),
+        amount: LP_TOKEN_STARTING_SUPPLY,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let (keys, lst_keys) = RemoveLiquidityProportionalByListFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lp_acc,
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: banks_client.get_lst_state_list_acc().await,
+        lst_mints: &[jitosol::ID, msol::ID].map(|pubkey| MintWithTokenProgram {
+            pubkey,
+            token_program: spl_token::ID,
+        }),
+        dst_lst_accs: &dst_lst_accs,
+    }
+    .resolve()
+    .unwrap();
+    let ix = remove_liquidity_proportional_ix_full(
+        keys,
+        LP_TOKENS_TO_REMOVE,
+        &[
+            RemoveLiquidityProportionalLst {
+                keys: lst_keys[0],
+                min_lst_out: EXPECTED_LST_OUT,
+                sol_value_calculator_accounts: &jito_sol_val_calc_accounts,
+            },
+            RemoveLiquidityProportionalLst {
+                keys: lst_keys[1],
+                min_lst_out: EXPECTED_LST_OUT,
+                sol_value_calculator_accounts: &marinade_sol_val_calc_accounts,
+            },
+        ],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    let [SControllerEvent::RemoveLiquidityProportional(_), SControllerEvent::RemoveLiquidityProportional(_), SControllerEvent::ProtocolFeesMintedAsLp(jitosol_minted), SControllerEvent::ProtocolFeesMintedAsLp(msol_minted)] =
+        events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+
+    let mut total_lp_tokens_minted = 0;
+    for (
+        expected_lst_index,
+        (
+            ProtocolFeesMintedAsLpEvent {
+                lst_index,
+                protocol_fees,
+                protocol_fees_sol_value,
+                lp_tokens_minted,
+            },
+            lst_keys,
+        ),
+    ) in [jitosol_minted, msol_minted]
+        .into_iter()
+        .zip(lst_keys)
+        .enumerate()
+    {
+        assert_eq!(*lst_index, u32::try_from(expected_lst_index).unwrap());
+        assert_eq!(*protocol_fees, EXPECTED_PROTOCOL_FEES);
+        assert!(*protocol_fees_sol_value > 0);
+        assert!(*lp_tokens_minted > 0);
+        total_lp_tokens_minted += lp_tokens_minted;
+
+        let mut balances = Vec::new();
+        for addr in [
+            lst_keys.dst_lst_acc,
+            lst_keys.pool_reserves,
+            lst_keys.protocol_fee_accumulator,
+        ] {
+            let acc = banks_client.get_account_unwrapped(addr).await;
+            balances.push(token_account_balance(acc).unwrap());
+        }
+        let [lst_received, pool_reserves_balance, protocol_fee_accumulator_balance] =
+            balances.try_into().unwrap();
+        assert_eq!(lst_received, EXPECTED_LST_OUT);
+        assert_eq!(
+            pool_reserves_balance,
+            RESERVES_STARTING_BALANCE - EXPECTED_LST_OUT
+        );
+        assert_eq!(protocol_fee_accumulator_balance, 0);
+    }
+
+    let protocol_fee_lp_balance = token_account_balance(
+        banks_client
+            .get_account_unwrapped(protocol_fee_lp_acc)
+            .await,
+    )
+    .unwrap();
+    assert_eq!(protocol_fee_lp_balance, total_lp_tokens_minted);
+    let lp_token_supply =
+        mint_supply(banks_client.get_account_unwrapped(lp_token_mint).await).unwrap();
+    assert_eq!(
+        lp_token_supply,
+        LP_TOKEN_STARTING_SUPPLY - LP_TOKENS_TO_REMOVE + total_lp_tokens_minted
+    );
+}
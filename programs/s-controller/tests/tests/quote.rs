@@ -0,0 +1,566 @@
+use flat_fee_lib::account_resolvers::{
+    PriceExactInFreeArgs, PriceExactOutFreeArgs, PriceLpTokensToRedeemFreeArgs,
+};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use lido_keys::stsol;
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_lib::{
+    add_liquidity_ix_by_mint_full, quote_add_liquidity_ix_by_mint_full,
+    quote_remove_liquidity_ix_by_mint_full, quote_swap_exact_in_ix_by_mint_full,
+    quote_swap_exact_out_ix_by_mint_full, remove_liquidity_ix_by_mint_full,
+    swap_exact_in_ix_by_mint_full, swap_exact_out_ix_by_mint_full, AddLiquidityByMintFreeArgs,
+    AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes, QuoteLiquidityByMintFreeArgs,
+    QuoteSwapByMintsFreeArgs, RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
+    SwapExactOutAmounts, SwapLiquidityReturnData,
+};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
+    lido_wsol_flat_fee_program_test, GenAndAddTokenAccountProgramTest, JitoMarinadeProgramTestArgs,
+    LidoWsolProgramTestArgs, LstStateListBanksClient, MockProtocolFeeBps, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{token::MockTokenAccountArgs, ExtendedBanksClient};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use spl_token::native_mint;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
+
+use crate::common::*;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const TRADING_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+fn jito_marinade_flat_fee_swap_program_test() -> ProgramTest {
+    jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: 6,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 9,
+                output_fee_bps: Default::default(),
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program()
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec()
+}
+
+/// Processes the quote in a tx of its own and checks that
+/// the pool's accounts were not modified
+async fn process_quote(banks_client: &mut BanksClient, tx: Transaction) -> SwapLiquidityReturnData {
+    let pool_state_before = banks_client.get_pool_state_acc().await;
+    let lst_state_list_before = banks_client.get_lst_state_list_acc().await;
+
+    let quote = process_tx_get_s_controller_return_data(banks_client, tx).await;
+
+    assert_eq!(
+        banks_client.get_pool_state_acc().await.data,
+        pool_state_before.data
+    );
+    assert_eq!(
+        banks_client.get_lst_state_list_acc().await.data,
+        lst_state_list_before.data
+    );
+    quote
+}
+
+#[tokio::test]
+async fn quote_swap_exact_in_matches_swap_exact_in() {
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = jito_marinade_flat_fee_swap_program_test();
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let src_dst_mints = (
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+    );
+    let suffixes = SrcDstLstSolValueCalcAccountSuffixes {
+        src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+    };
+    let pricing_program_accounts = PriceExactInFreeArgs {
+        input_lst_mint: msol::ID,
+        output_lst_mint: jitosol::ID,
+    }
+    .resolve_to_account_metas();
+
+    let quote_ix = quote_swap_exact_in_ix_by_mint_full(
+        QuoteSwapByMintsFreeArgs {
+            src_lst_mint: src_dst_mints.0,
+            dst_lst_mint: src_dst_mints.1,
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_account,
+        },
+        MSOL_TO_SWAP_IN,
+        suffixes,
+        &pricing_program_accounts,
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[quote_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let quote = process_quote(&mut banks_client, tx).await;
+
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: src_dst_mints.0,
+            dst_lst_mint: src_dst_mints.1,
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: quote.amount_out,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        suffixes,
+        &pricing_program_accounts,
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let jitosol_protocol_fee_accumulator = ix.accounts[5].pubkey;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let jitosol_received = token_account_balance(
+        banks_client
+            .get_account_unwrapped(swapper_jitosol_acc_addr)
+            .await,
+    )
+    .unwrap();
+    let protocol_fees = token_account_balance(
+        banks_client
+            .get_account_unwrapped(jitosol_protocol_fee_accumulator)
+            .await,
+    )
+    .unwrap();
+    assert!(protocol_fees > 0);
+    assert_eq!(quote.amount_in, MSOL_TO_SWAP_IN);
+    assert_eq!(quote.amount_out, jitosol_received);
+    assert_eq!(quote.protocol_fees, protocol_fees);
+    assert!(quote.in_sol_value > quote.out_sol_value);
+}
+
+#[tokio::test]
+async fn quote_swap_exact_out_matches_swap_exact_out() {
+    const JITOSOL_TO_SWAP_OUT: u64 = 1_000_000_000;
+    const MAX_MSOL_IN: u64 = 2_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = jito_marinade_flat_fee_swap_program_test();
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MAX_MSOL_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let src_dst_mints = (
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+    );
+    let suffixes = SrcDstLstSolValueCalcAccountSuffixes {
+        src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+    };
+    let pricing_program_accounts = PriceExactOutFreeArgs {
+        input_lst_mint: msol::ID,
+        output_lst_mint: jitosol::ID,
+    }
+    .resolve_to_account_metas();
+
+    let quote_ix = quote_swap_exact_out_ix_by_mint_full(
+        QuoteSwapByMintsFreeArgs {
+            src_lst_mint: src_dst_mints.0,
+            dst_lst_mint: src_dst_mints.1,
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_account,
+        },
+        JITOSOL_TO_SWAP_OUT,
+        suffixes,
+        &pricing_program_accounts,
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[quote_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let quote = process_quote(&mut banks_client, tx).await;
+
+    let ix = swap_exact_out_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: src_dst_mints.0,
+            dst_lst_mint: src_dst_mints.1,
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_account,
+        },
+        SwapExactOutAmounts {
+            max_amount_in: quote.amount_in,
+            amount: JITOSOL_TO_SWAP_OUT,
+        },
+        suffixes,
+        &pricing_program_accounts,
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let jitosol_protocol_fee_accumulator = ix.accounts[5].pubkey;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let msol_left = token_account_balance(
+        banks_client
+            .get_account_unwrapped(swapper_msol_acc_addr)
+            .await,
+    )
+    .unwrap();
+    let protocol_fees = token_account_balance(
+        banks_client
+            .get_account_unwrapped(jitosol_protocol_fee_accumulator)
+            .await,
+    )
+    .unwrap();
+    assert_eq!(quote.amount_in, MAX_MSOL_IN - msol_left);
+    assert_eq!(quote.amount_out, JITOSOL_TO_SWAP_OUT);
+    assert_eq!(quote.protocol_fees, protocol_fees);
+}
+
+#[tokio::test]
+async fn quote_add_liquidity_matches_add_liquidity() {
+    const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: JITOSOL_STARTING_POOL_RESERVES + MSOL_STARTING_POOL_RESERVES,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let lst_mint = MintWithTokenProgram {
+        pubkey: jitosol::ID,
+        token_program: spl_token::ID,
+    };
+    let account_suffixes = AddRemoveLiquidityAccountSuffixes {
+        lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        pricing_program_price_lp_accounts: &[AccountMeta {
+            pubkey: jitosol::ID,
+            is_signer: false,
+            is_writable: false,
+        }],
+    };
+
+    let quote_ix = quote_add_liquidity_ix_by_mint_full(
+        QuoteLiquidityByMintFreeArgs {
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint,
+        },
+        JITOSOL_TO_ADD,
+        account_suffixes,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[quote_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let quote = process_quote(&mut banks_client, tx).await;
+
+    let ix = add_liquidity_ix_by_mint_full(
+        AddLiquidityByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lst_acc: liquidity_provider_jitosol_acc_addr,
+            dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint,
+        },
+        AddLiquidityIxAmts {
+            lst_amount: JITOSOL_TO_ADD,
+            min_lp_out: quote.amount_out,
+        },
+        account_suffixes,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lp_tokens_received = token_account_balance(
+        banks_client
+            .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+            .await,
+    )
+    .unwrap();
+    assert_eq!(quote.amount_in, JITOSOL_TO_ADD);
+    assert_eq!(quote.amount_out, lp_tokens_received);
+    assert_eq!(quote.protocol_fees, 0);
+    assert_eq!(quote.in_sol_value, quote.out_sol_value);
+}
+
+#[tokio::test]
+async fn quote_remove_liquidity_matches_remove_liquidity() {
+    const LP_TOKEN_SUPPLY: u64 = 1_000_000_000;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 2;
+    const WSOL_RESERVES_STARTING_BALANCE: u64 = 10_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = lido_wsol_flat_fee_program_test(
+        LidoWsolProgramTestArgs {
+            wsol_reserves: WSOL_RESERVES_STARTING_BALANCE,
+            stsol_sol_value: 0,
+            stsol_reserves: 0,
+            wsol_protocol_fee_accumulator: 0,
+            stsol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_SUPPLY,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 10,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: native_mint::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: Default::default(),
+                lst_mint: stsol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: Default::default(),
+            lp: 5_000,
+        },
+    )
+    .add_s_program();
+    let liquidity_provider_wsol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: native_mint::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let lst_mint = MintWithTokenProgram {
+        pubkey: native_mint::ID,
+        token_program: spl_token::ID,
+    };
+    let pricing_program_price_lp_accounts = PriceLpTokensToRedeemFreeArgs {
+        output_lst_mint: native_mint::ID,
+    }
+    .resolve_to_account_metas();
+    let account_suffixes = AddRemoveLiquidityAccountSuffixes {
+        lst_calculator_accounts: &WSOL_LST_SOL_COMMON_METAS,
+        pricing_program_price_lp_accounts: &pricing_program_price_lp_accounts,
+    };
+
+    let quote_ix = quote_remove_liquidity_ix_by_mint_full(
+        QuoteLiquidityByMintFreeArgs {
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint,
+        },
+        LP_TOKENS_TO_REMOVE,
+        account_suffixes,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[quote_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let quote = process_quote(&mut banks_client, tx).await;
+
+    let ix = remove_liquidity_ix_by_mint_full(
+        RemoveLiquidityByMintFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            src_lp_acc: liquidity_provider_lp_token_acc_addr,
+            dst_lst_acc: liquidity_provider_wsol_acc_addr,
+            pool_state: &pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint,
+        },
+        RemoveLiquidityIxAmts {
+            lp_token_amount: LP_TOKENS_TO_REMOVE,
+            min_lst_out: quote.amount_out,
+        },
+        account_suffixes,
+    )
+    .unwrap();
+    let protocol_fee_accumulator = ix.accounts[5].pubkey;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let wsol_received = token_account_balance(
+        banks_client
+            .get_account_unwrapped(liquidity_provider_wsol_acc_addr)
+            .await,
+    )
+    .unwrap();
+    let protocol_fees = token_account_balance(
+        banks_client
+            .get_account_unwrapped(protocol_fee_accumulator)
+            .await,
+    )
+    .unwrap();
+    assert!(protocol_fees > 0);
+    assert_eq!(quote.amount_in, LP_TOKENS_TO_REMOVE);
+    assert_eq!(quote.amount_out, wsol_received);
+    assert_eq!(quote.protocol_fees, protocol_fees);
+    assert!(quote.in_sol_value > quote.out_sol_value);
+}
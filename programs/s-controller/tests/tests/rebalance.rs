@@ -1,16 +1,22 @@
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
-use s_controller_interface::SControllerError;
+use s_controller_interface::{PoolConfig, RebalanceAuthorityBudget, SControllerError};
 use s_controller_lib::{
-    end_rebalance_ix_full,
+    add_rebalance_leg_ix_full, end_rebalance_ix_full,
+    find_pool_reserves_address_with_pool_state_id,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    start_rebalance_ix_full, try_lst_state_list, try_pool_state,
-    EndRebalanceFromStartRebalanceKeys, SrcDstLstIndexes, SrcDstLstSolValueCalcAccounts,
-    StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts, U8Bool,
+    settle_rebalance_leg_ix_full, start_rebalance_ix_full, try_lst_state_list, try_pool_state,
+    try_rebalance_authority_list, AddRebalanceLegByMintsFreeArgs, EndRebalanceEvent,
+    EndRebalanceFromStartRebalanceKeys, FindLstPdaAtaKeys, PoolKey, RebalanceSolValueLostEvent,
+    SControllerEvent, SettleRebalanceLegFromAddRebalanceLegKeys, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccounts, StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs,
+    StartRebalanceIxLstAmts, U8Bool, UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
-    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolConfigBanksClient,
+    PoolConfigProgramTest, PoolStateBanksClient, RebalanceAuthorityListBanksClient,
+    RebalanceAuthorityListProgramTest,
 };
 use sanctum_solana_test_utils::{
     assert_custom_err, assert_program_error, test_fixtures_dir, token::MockTokenAccountArgs,
@@ -22,7 +28,7 @@ use sanctum_token_lib::{
 use solana_program::{
     clock::Clock, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
 };
-use solana_program_test::ProgramTestContext;
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{
     account::Account,
@@ -33,7 +39,7 @@ use solana_sdk::{
 use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
 use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::common::SControllerProgramTest;
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
 
 struct CreateRebalanceDonateIxsArgs {
     pub jito_stake_pool_acc: Account,
@@ -46,6 +52,7 @@ struct CreateRebalanceDonateIxsArgs {
     pub msol_donate_amt: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    pub max_loss_lamports: u64,
 }
 
 fn create_rebalance_donate_ixs(
@@ -60,6 +67,7 @@ fn create_rebalance_donate_ixs(
         msol_donate_amt,
         min_starting_src_lst,
         max_starting_dst_lst,
+        max_loss_lamports,
     }: CreateRebalanceDonateIxsArgs,
 ) -> [Instruction; 3] {
     let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
@@ -111,6 +119,7 @@ fn create_rebalance_donate_ixs(
                 amount: jitosol_withdraw_amt,
                 min_starting_src_lst,
                 max_starting_dst_lst,
+                max_loss_lamports,
             },
         },
         SrcDstLstSolValueCalcAccounts {
@@ -208,6 +217,7 @@ async fn rebalance_basic() {
         msol_donate_amt: MSOL_DONATE_AMT,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
     });
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -324,6 +334,7 @@ async fn rebalance_fail_no_end() {
                 amount: 500_000_000,
                 min_starting_src_lst: 0,
                 max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: 0,
             },
         },
         SrcDstLstSolValueCalcAccounts {
@@ -402,6 +413,7 @@ async fn rebalance_fail_unauthorized() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
     });
     // change rebalance authority
     ixs[0].accounts[0].pubkey = unauthorized.pubkey();
@@ -411,7 +423,355 @@ async fn rebalance_fail_unauthorized() {
 
     let err = banks_client.process_transaction(tx).await.unwrap_err();
 
-    assert_program_error(err, ProgramError::InvalidArgument);
+    assert_custom_err(err, SControllerError::UnauthorizedRebalanceAuthority);
+}
+
+/// Rebalances 500_000_000 jitoSOL to mSOL signed by a rebalance authority on the list
+/// with the given budget entry, that may also sign EndRebalance in place of `end_rebalance_signer`
+async fn listed_rebalance_authority_rebalance(
+    budget: RebalanceAuthorityBudget,
+    listed_authority_kp: &Keypair,
+    end_rebalance_signer: Option<&Keypair>,
+) -> (Result<(), BanksClientError>, BanksClient) {
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 1_000_000_000,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_rebalance_authority_list(&[budget]);
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: listed_authority_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: listed_authority_kp.pubkey(),
+        amount: 500_000_000,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let mut ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: listed_authority_kp.pubkey(),
+        jitosol_withdraw_amt: 500_000_000,
+        msol_donate_amt: 500_000_000,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
+    });
+    let end_rebalance_signer = end_rebalance_signer.unwrap_or(listed_authority_kp);
+    ixs[0].accounts[0].pubkey = listed_authority_kp.pubkey();
+    ixs[2].accounts[0].pubkey = end_rebalance_signer.pubkey();
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    if end_rebalance_signer.pubkey() == listed_authority_kp.pubkey() {
+        tx.sign(&[&payer, listed_authority_kp], last_blockhash);
+    } else {
+        tx.sign(
+            &[&payer, listed_authority_kp, end_rebalance_signer],
+            last_blockhash,
+        );
+    }
+
+    let res = banks_client.process_transaction(tx).await;
+    (res, banks_client)
+}
+
+#[tokio::test]
+async fn rebalance_listed_authority_within_budget() {
+    let listed_authority_kp = Keypair::new();
+    let (res, mut banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: 1_000_000_000,
+            last_epoch: 0,
+            epoch_spent: 0,
+        },
+        &listed_authority_kp,
+        None,
+    )
+    .await;
+    res.unwrap();
+
+    let list_acc = banks_client.get_rebalance_authority_list_acc().await;
+    let list = try_rebalance_authority_list(&list_acc.data).unwrap();
+    assert_eq!(list[0].last_epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    // jitoSOL is worth more than 1 SOL
+    assert!(list[0].epoch_spent > 500_000_000);
+    assert!(list[0].epoch_spent <= 1_000_000_000);
+}
+
+#[tokio::test]
+async fn rebalance_listed_authority_budget_reset_on_new_epoch() {
+    let listed_authority_kp = Keypair::new();
+    let (res, mut banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: 1_000_000_000,
+            last_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1,
+            epoch_spent: 1_000_000_000,
+        },
+        &listed_authority_kp,
+        None,
+    )
+    .await;
+    res.unwrap();
+
+    let list_acc = banks_client.get_rebalance_authority_list_acc().await;
+    let list = try_rebalance_authority_list(&list_acc.data).unwrap();
+    assert_eq!(list[0].last_epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    assert!(list[0].epoch_spent < 1_000_000_000);
+}
+
+#[tokio::test]
+async fn rebalance_fail_listed_authority_budget_exceeded() {
+    let listed_authority_kp = Keypair::new();
+    let (res, _banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: 1_000_000_000,
+            last_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+            epoch_spent: 600_000_000,
+        },
+        &listed_authority_kp,
+        None,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::RebalanceBudgetExceeded);
+}
+
+/// Rebalances 500_000_000 jitoSOL to mSOL signed by the pool's rebalance authority
+/// with the given PoolConfig rebalance authority budget fields
+async fn pool_rebalance_authority_rebalance(
+    epoch_budget: u64,
+    last_epoch: u64,
+    epoch_spent: u64,
+) -> (Result<(), BanksClientError>, BanksClient) {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 1_000_000_000,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 0,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 0,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: epoch_budget,
+        rebalance_authority_last_epoch: last_epoch,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: epoch_spent,
+    });
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 500_000_000,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: 500_000_000,
+        msol_donate_amt: 500_000_000,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let res = banks_client.process_transaction(tx).await;
+    (res, banks_client)
+}
+
+#[tokio::test]
+async fn rebalance_pool_authority_within_budget() {
+    let (res, mut banks_client) = pool_rebalance_authority_rebalance(1_000_000_000, 0, 0).await;
+    res.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(
+        pool_config.rebalance_authority_last_epoch,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH
+    );
+    // jitoSOL is worth more than 1 SOL
+    assert!(pool_config.rebalance_authority_epoch_spent > 500_000_000);
+    assert!(pool_config.rebalance_authority_epoch_spent <= 1_000_000_000);
+}
+
+#[tokio::test]
+async fn rebalance_pool_authority_budget_reset_on_new_epoch() {
+    let (res, mut banks_client) = pool_rebalance_authority_rebalance(
+        1_000_000_000,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1,
+        1_000_000_000,
+    )
+    .await;
+    res.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(
+        pool_config.rebalance_authority_last_epoch,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH
+    );
+    assert!(pool_config.rebalance_authority_epoch_spent < 1_000_000_000);
+}
+
+#[tokio::test]
+async fn rebalance_fail_pool_authority_budget_exceeded() {
+    let (res, _banks_client) = pool_rebalance_authority_rebalance(
+        1_000_000_000,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        600_000_000,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::RebalanceBudgetExceeded);
+}
+
+#[tokio::test]
+async fn rebalance_pool_authority_not_rate_limited_with_unlimited_budget() {
+    let (res, mut banks_client) = pool_rebalance_authority_rebalance(
+        UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        0,
+    )
+    .await;
+    res.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(pool_config.rebalance_authority_epoch_spent, 0);
+}
+
+#[tokio::test]
+async fn rebalance_fail_pool_authority_zero_budget() {
+    let (res, _banks_client) =
+        pool_rebalance_authority_rebalance(0, JITO_STAKE_POOL_LAST_UPDATE_EPOCH, 0).await;
+    assert_custom_err(res.unwrap_err(), SControllerError::RebalanceBudgetExceeded);
+}
+
+#[tokio::test]
+async fn rebalance_listed_authority_not_rate_limited_with_unlimited_budget() {
+    let listed_authority_kp = Keypair::new();
+    let (res, mut banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+            last_epoch: 0,
+            epoch_spent: 0,
+        },
+        &listed_authority_kp,
+        None,
+    )
+    .await;
+    res.unwrap();
+
+    let list_acc = banks_client.get_rebalance_authority_list_acc().await;
+    let list = try_rebalance_authority_list(&list_acc.data).unwrap();
+    assert_eq!(list[0].last_epoch, 0);
+    assert_eq!(list[0].epoch_spent, 0);
+}
+
+#[tokio::test]
+async fn rebalance_fail_listed_authority_zero_budget() {
+    let listed_authority_kp = Keypair::new();
+    let (res, _banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: 0,
+            last_epoch: 0,
+            epoch_spent: 0,
+        },
+        &listed_authority_kp,
+        None,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::RebalanceBudgetExceeded);
+}
+
+#[tokio::test]
+async fn rebalance_fail_end_rebalance_signed_by_other_authority() {
+    let listed_authority_kp = Keypair::new();
+    let other_kp = Keypair::new();
+    let (res, _banks_client) = listed_rebalance_authority_rebalance(
+        RebalanceAuthorityBudget {
+            authority: listed_authority_kp.pubkey(),
+            epoch_budget: 1_000_000_000,
+            last_epoch: 0,
+            epoch_spent: 0,
+        },
+        &listed_authority_kp,
+        Some(&other_kp),
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::NoSucceedingEndRebalance);
 }
 
 #[tokio::test]
@@ -466,29 +826,227 @@ async fn rebalance_fail_not_enough_sol_value_returned() {
         .get_account_unwrapped(jito_stake_pool::ID)
         .await;
 
-    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_donate_amt: TINY_MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+}
+
+/// Rebalances 500_000_000 jitoSOL to mSOL, returning only a tiny amount of mSOL
+/// to the pool, in a pool with `max_rebalance_loss_bps`
+async fn bounded_loss_rebalance_tx(
+    max_rebalance_loss_bps: u16,
+    max_loss_lamports: u64,
+) -> (BanksClient, Transaction) {
+    const TINY_MSOL_DONATE_AMT: u64 = 100_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 1_000_000_000,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 0,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 0,
+        max_rebalance_loss_bps,
+        rebalance_authority_epoch_budget: UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: 0,
+    });
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: TINY_MSOL_DONATE_AMT,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: 500_000_000,
+        msol_donate_amt: TINY_MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        max_loss_lamports,
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    (banks_client, tx)
+}
+
+#[tokio::test]
+async fn rebalance_bounded_loss_within_tolerance() {
+    const MAX_LOSS_LAMPORTS: u64 = 1_000_000_000;
+
+    // both LSTs are worth more than 1 SOL so 50% of the pool's total SOL value > MAX_LOSS_LAMPORTS
+    let (mut banks_client, tx) = bounded_loss_rebalance_tx(5_000, MAX_LOSS_LAMPORTS).await;
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+
+    let [SControllerEvent::StartRebalance(_), SControllerEvent::EndRebalance(EndRebalanceEvent {
+        old_total_sol_value,
+        total_sol_value,
+        ..
+    }), SControllerEvent::RebalanceSolValueLost(RebalanceSolValueLostEvent {
+        sol_value_lost,
+        max_sol_value_loss,
+        ..
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+    assert!(*sol_value_lost > 0);
+    assert_eq!(*sol_value_lost, old_total_sol_value - total_sol_value);
+    assert_eq!(*max_sol_value_loss, MAX_LOSS_LAMPORTS);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.total_sol_value, *total_sol_value);
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+}
+
+#[tokio::test]
+async fn rebalance_fail_bounded_loss_exceeds_max_loss_lamports() {
+    let (mut banks_client, tx) = bounded_loss_rebalance_tx(5_000, 100_000_000).await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_fail_bounded_loss_exceeds_max_rebalance_loss_bps() {
+    let (mut banks_client, tx) = bounded_loss_rebalance_tx(100, 1_000_000_000).await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_fail_wrong_end_rebalance_dst_lst_mint() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 1_000_000_000,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 500_000_000,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let mut ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
         jito_stake_pool_acc,
         pool_state_acc,
         lst_state_list_acc,
         withdraw_jitosol_to_addr,
         donate_msol_from_addr,
         donate_msol_authority: mock_auth_kp.pubkey(),
-        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
-        msol_donate_amt: TINY_MSOL_DONATE_AMT,
+        jitosol_withdraw_amt: 500_000_000,
+        msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
     });
+    // change dst_lst_mint of end rebalance ix
+    ixs[2].accounts[4].pubkey = jitosol::ID;
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
     tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
 
     let err = banks_client.process_transaction(tx).await.unwrap_err();
 
-    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+    assert_custom_err(err, SControllerError::NoSucceedingEndRebalance);
 }
 
 #[tokio::test]
-async fn rebalance_fail_wrong_end_rebalance_dst_lst_mint() {
+async fn rebalance_fail_end_rebalance_of_other_pool() {
     let mock_auth_kp =
         read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
             .unwrap();
@@ -544,9 +1102,21 @@ async fn rebalance_fail_wrong_end_rebalance_dst_lst_mint() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        max_loss_lamports: 0,
     });
-    // change dst_lst_mint of end rebalance ix
-    ixs[2].accounts[4].pubkey = jitosol::ID;
+    // change end rebalance ix to end a rebalance of another pool
+    let other_pool = PoolKey::new(Pubkey::new_unique());
+    ixs[2].accounts[1].pubkey = other_pool.pool_state;
+    ixs[2].accounts[2].pubkey = other_pool.lst_state_list_pda().address;
+    ixs[2].accounts[3].pubkey = other_pool.rebalance_record_pda().address;
+    ixs[2].accounts[5].pubkey = find_pool_reserves_address_with_pool_state_id(
+        other_pool.pool_state,
+        FindLstPdaAtaKeys {
+            lst_mint: msol::ID,
+            token_program: spl_token::ID,
+        },
+    )
+    .0;
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
     tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
@@ -623,6 +1193,7 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
             msol_donate_amt: MSOL_DONATE_AMT,
             min_starting_src_lst,
             max_starting_dst_lst,
+            max_loss_lamports: 0,
         });
 
         let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -632,3 +1203,371 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
         assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
     }
 }
+
+struct CreateTwoLegRebalanceIxsArgs {
+    pub jito_stake_pool_acc: Account,
+    pub pool_state_acc: Account,
+    pub lst_state_list_acc: Account,
+    pub withdraw_jitosol_to_addr: Pubkey,
+    pub withdraw_msol_to_addr: Pubkey,
+    pub donate_jitosol_from_addr: Pubkey,
+    pub donate_msol_from_addr: Pubkey,
+    pub donate_authority: Pubkey,
+    pub jitosol_withdraw_amt: u64,
+    pub msol_withdraw_amt: u64,
+    pub include_settle_rebalance_leg: bool,
+}
+
+/// StartRebalance jitoSOL -> mSOL,
+/// AddRebalanceLeg mSOL -> jitoSOL,
+/// donate the withdrawn amounts back,
+/// SettleRebalanceLeg jitoSOL (if `include_settle_rebalance_leg`),
+/// EndRebalance mSOL
+fn create_two_leg_rebalance_ixs(
+    CreateTwoLegRebalanceIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        withdraw_msol_to_addr,
+        donate_jitosol_from_addr,
+        donate_msol_from_addr,
+        donate_authority,
+        jitosol_withdraw_amt,
+        msol_withdraw_amt,
+        include_settle_rebalance_leg,
+    }: CreateTwoLegRebalanceIxsArgs,
+) -> Vec<Instruction> {
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let jitosol_mint = MintWithTokenProgram {
+        pubkey: jitosol::ID,
+        token_program: spl_token::ID,
+    };
+    let msol_mint = MintWithTokenProgram {
+        pubkey: msol::ID,
+        token_program: spl_token::ID,
+    };
+
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index: jitosol_index,
+            dst_lst_index: msol_index,
+        },
+        _program_ids,
+    ) = StartRebalanceByMintsFreeArgs {
+        withdraw_to: withdraw_jitosol_to_addr,
+        lst_state_list: KeyedAccount {
+            pubkey: LST_STATE_LIST_ID,
+            account: lst_state_list_acc.clone(),
+        },
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc.clone(),
+        },
+        src_lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        dst_lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let end_rebalance_keys = EndRebalanceFromStartRebalanceKeys(&start_rebalance_keys).resolve();
+
+    let (add_rebalance_leg_keys, _indexes, _program_ids) = AddRebalanceLegByMintsFreeArgs {
+        withdraw_to: withdraw_msol_to_addr,
+        lst_state_list: KeyedAccount {
+            pubkey: LST_STATE_LIST_ID,
+            account: lst_state_list_acc,
+        },
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc,
+        },
+        src_lst_mint: msol_mint,
+        dst_lst_mint: jitosol_mint,
+    }
+    .resolve()
+    .unwrap();
+    let settle_rebalance_leg_keys =
+        SettleRebalanceLegFromAddRebalanceLegKeys(&add_rebalance_leg_keys).resolve();
+
+    let start_rebalance_ix = start_rebalance_ix_full(
+        start_rebalance_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index: jitosol_index,
+            dst_lst_index: msol_index,
+            lst_amts: StartRebalanceIxLstAmts {
+                amount: jitosol_withdraw_amt,
+                min_starting_src_lst: 0,
+                max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: 0,
+            },
+        },
+        SrcDstLstSolValueCalcAccounts {
+            src_lst_calculator_program_id: spl_calculator_lib::program::ID,
+            dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+    )
+    .unwrap();
+    let add_rebalance_leg_ix = add_rebalance_leg_ix_full(
+        add_rebalance_leg_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index: msol_index,
+            dst_lst_index: jitosol_index,
+            lst_amts: StartRebalanceIxLstAmts {
+                amount: msol_withdraw_amt,
+                min_starting_src_lst: 0,
+                max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: 0,
+            },
+        },
+        SrcDstLstSolValueCalcAccounts {
+            src_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+            dst_lst_calculator_program_id: spl_calculator_lib::program::ID,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+    )
+    .unwrap();
+    let donate_jitosol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_jitosol_from_addr,
+            to: settle_rebalance_leg_keys.dst_pool_reserves,
+            authority: donate_authority,
+            mint: jitosol::ID,
+        },
+        TransferCheckedArgs {
+            amount: jitosol_withdraw_amt,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let settle_rebalance_leg_ix = settle_rebalance_leg_ix_full(
+        settle_rebalance_leg_keys,
+        &jito_sol_val_calc_accounts,
+        spl_calculator_lib::program::ID,
+    )
+    .unwrap();
+    let donate_msol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_msol_from_addr,
+            to: end_rebalance_keys.dst_pool_reserves,
+            authority: donate_authority,
+            mint: msol::ID,
+        },
+        TransferCheckedArgs {
+            amount: msol_withdraw_amt,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let end_rebalance_ix = end_rebalance_ix_full(
+        end_rebalance_keys,
+        &marinade_sol_val_calc_accounts,
+        marinade_calculator_lib::program::ID,
+    )
+    .unwrap();
+
+    let mut ixs = vec![start_rebalance_ix, add_rebalance_leg_ix, donate_jitosol_ix];
+    if include_settle_rebalance_leg {
+        ixs.push(settle_rebalance_leg_ix);
+    }
+    ixs.extend([donate_msol_ix, end_rebalance_ix]);
+    ixs
+}
+
+/// Instructions at `other_authority_ix_indexes` are signed by another rebalance authority
+/// on the rebalance authority list instead of the pool's rebalance authority that started the rebalance.
+///
+/// If `ix_order` is not empty, the instructions of [`create_two_leg_rebalance_ixs`]
+/// are reordered to `ix_order`'s indexes
+async fn two_leg_rebalance_tx(
+    include_settle_rebalance_leg: bool,
+    other_authority_ix_indexes: &[usize],
+    ix_order: &[usize],
+) -> (Transaction, BanksClient) {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_WITHDRAW_AMT: u64 = 250_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let other_authority_kp = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_START_SOL_VALUE,
+        msol_sol_value: MSOL_START_SOL_VALUE,
+        jitosol_reserves: JITOSOL_START_SOL_VALUE,
+        msol_reserves: MSOL_START_SOL_VALUE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_rebalance_authority_list(&[RebalanceAuthorityBudget {
+        authority: other_authority_kp.pubkey(),
+        epoch_budget: UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+        last_epoch: 0,
+        epoch_spent: 0,
+    }]);
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let withdraw_msol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_jitosol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: JITOSOL_WITHDRAW_AMT,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: MSOL_WITHDRAW_AMT,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let mut ixs = create_two_leg_rebalance_ixs(CreateTwoLegRebalanceIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        withdraw_msol_to_addr,
+        donate_jitosol_from_addr,
+        donate_msol_from_addr,
+        donate_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_withdraw_amt: MSOL_WITHDRAW_AMT,
+        include_settle_rebalance_leg,
+    });
+
+    for i in other_authority_ix_indexes {
+        ixs[*i].accounts[0].pubkey = other_authority_kp.pubkey();
+    }
+    if !ix_order.is_empty() {
+        ixs = ix_order.iter().map(|i| ixs[*i].clone()).collect();
+    }
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    if other_authority_ix_indexes.is_empty() {
+        tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    } else {
+        tx.sign(
+            &[&payer, &mock_auth_kp, &other_authority_kp],
+            last_blockhash,
+        );
+    }
+
+    (tx, banks_client)
+}
+
+#[tokio::test]
+async fn rebalance_two_legs() {
+    let (tx, mut banks_client) = two_leg_rebalance_tx(true, &[], &[]).await;
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert!(pool_state.total_sol_value >= 2_000_000_000);
+
+    let rebalance_record = banks_client.get_account(REBALANCE_RECORD_ID).await.unwrap();
+    assert!(rebalance_record.is_none());
+}
+
+#[tokio::test]
+async fn rebalance_two_legs_fail_leg_not_settled() {
+    let (tx, mut banks_client) = two_leg_rebalance_tx(false, &[], &[]).await;
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::RebalanceLegsNotSettled);
+}
+
+#[tokio::test]
+async fn rebalance_two_legs_fail_leg_added_by_other_authority() {
+    // AddRebalanceLeg
+    let (tx, mut banks_client) = two_leg_rebalance_tx(true, &[1], &[]).await;
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn rebalance_two_legs_fail_leg_settled_by_other_authority() {
+    // SettleRebalanceLeg
+    let (tx, mut banks_client) = two_leg_rebalance_tx(true, &[3], &[]).await;
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn rebalance_two_legs_fail_dst_lst_returned_before_leg_added() {
+    // StartRebalance, donate mSOL, AddRebalanceLeg, donate jitoSOL, SettleRebalanceLeg, EndRebalance.
+    // AddRebalanceLeg syncs mSOL again, so the returned mSOL is not counted
+    let (tx, mut banks_client) = two_leg_rebalance_tx(true, &[], &[0, 4, 1, 2, 3, 5]).await;
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_two_legs_fail_leg_settled_before_dst_lst_returned() {
+    // StartRebalance, AddRebalanceLeg, SettleRebalanceLeg, donate jitoSOL, donate mSOL, EndRebalance.
+    // jitoSOL is not synced after it is returned, so it is not counted
+    let (tx, mut banks_client) = two_leg_rebalance_tx(true, &[], &[0, 1, 3, 2, 4, 5]).await;
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolWouldLoseSolValue);
+}
@@ -0,0 +1,189 @@
+use s_controller_interface::{
+    add_rebalance_authority_ix, remove_rebalance_authority_ix,
+    set_pool_rebalance_authority_budget_ix, set_rebalance_authority_budget_ix,
+    AddRebalanceAuthorityIxArgs, RebalanceAuthorityBudget, SControllerError,
+    SetPoolRebalanceAuthorityBudgetIxArgs,
+};
+use s_controller_lib::{
+    program::{POOL_CONFIG_ID, POOL_STATE_ID},
+    try_rebalance_authority_list, AddRebalanceAuthorityFreeArgs,
+    RemoveRebalanceAuthorityByPubkeyFreeArgs, SetPoolRebalanceAuthorityBudgetFreeArgs,
+    SetRebalanceAuthorityBudgetByPubkeyFreeArgs,
+};
+use s_controller_test_utils::{
+    MockPoolState, PoolConfigBanksClient, PoolStateProgramTest, RebalanceAuthorityListBanksClient,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, test_fixtures_dir, IntoAccount};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::*;
+
+async fn get_list(banks_client: &mut BanksClient) -> Vec<RebalanceAuthorityBudget> {
+    let acc = banks_client.get_rebalance_authority_list_acc().await;
+    try_rebalance_authority_list(&acc.data).unwrap().to_vec()
+}
+
+#[tokio::test]
+async fn add_set_budget_remove() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state = KeyedAccount {
+        pubkey: POOL_STATE_ID,
+        account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+    };
+    let [authority_a, authority_b] = [0; 2].map(|_| Keypair::new().pubkey());
+
+    let signed_tx = |ix| {
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+        tx
+    };
+    let add_ix = |new_rebalance_authority, epoch_budget| {
+        add_rebalance_authority_ix(
+            AddRebalanceAuthorityFreeArgs {
+                payer: payer.pubkey(),
+                new_rebalance_authority,
+                pool_state_acc: pool_state.clone(),
+            }
+            .resolve()
+            .unwrap(),
+            AddRebalanceAuthorityIxArgs { epoch_budget },
+        )
+        .unwrap()
+    };
+
+    banks_client
+        .process_transaction(signed_tx(add_ix(authority_a, 1_000_000_000)))
+        .await
+        .unwrap();
+    banks_client
+        .process_transaction(signed_tx(add_ix(authority_b, 2_000_000_000)))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_list(&mut banks_client).await,
+        vec![
+            RebalanceAuthorityBudget {
+                authority: authority_a,
+                epoch_budget: 1_000_000_000,
+                last_epoch: 0,
+                epoch_spent: 0,
+            },
+            RebalanceAuthorityBudget {
+                authority: authority_b,
+                epoch_budget: 2_000_000_000,
+                last_epoch: 0,
+                epoch_spent: 0,
+            },
+        ]
+    );
+
+    // duplicate
+    let err = banks_client
+        .process_transaction(signed_tx(add_ix(authority_a, 0)))
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::DuplicateRebalanceAuthority);
+
+    let list_acc = banks_client.get_rebalance_authority_list_acc().await;
+    let (keys, mut args) = SetRebalanceAuthorityBudgetByPubkeyFreeArgs {
+        rebalance_authority: authority_a,
+        pool_state_acc: pool_state.account.clone(),
+        rebalance_authority_list: list_acc.clone(),
+    }
+    .resolve()
+    .unwrap();
+    assert_eq!(args.epoch_budget, 1_000_000_000);
+    args.epoch_budget = 500_000_000;
+    banks_client
+        .process_transaction(signed_tx(
+            set_rebalance_authority_budget_ix(keys, args).unwrap(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_list(&mut banks_client).await[0].epoch_budget,
+        500_000_000
+    );
+
+    let (keys, args) = RemoveRebalanceAuthorityByPubkeyFreeArgs {
+        refund_rent_to: payer.pubkey(),
+        rebalance_authority: authority_a,
+        pool_state_acc: pool_state.account.clone(),
+        rebalance_authority_list: list_acc,
+    }
+    .resolve()
+    .unwrap();
+    banks_client
+        .process_transaction(signed_tx(
+            remove_rebalance_authority_ix(keys, args).unwrap(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_list(&mut banks_client).await,
+        vec![RebalanceAuthorityBudget {
+            authority: authority_b,
+            epoch_budget: 2_000_000_000,
+            last_epoch: 0,
+            epoch_spent: 0,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn set_pool_rebalance_authority_budget_creates_pool_config() {
+    const EPOCH_BUDGET: u64 = 1_000_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+    assert!(banks_client
+        .get_account(POOL_CONFIG_ID)
+        .await
+        .unwrap()
+        .is_none());
+
+    let ix = set_pool_rebalance_authority_budget_ix(
+        SetPoolRebalanceAuthorityBudgetFreeArgs {
+            payer: payer.pubkey(),
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetPoolRebalanceAuthorityBudgetIxArgs {
+            epoch_budget: EPOCH_BUDGET,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(pool_config.rebalance_authority_epoch_budget, EPOCH_BUDGET);
+    assert_eq!(pool_config.rebalance_authority_last_epoch, 0);
+    assert_eq!(pool_config.rebalance_authority_epoch_spent, 0);
+}
@@ -0,0 +1,276 @@
+use flat_fee_lib::account_resolvers::PriceExactInFreeArgs;
+use flat_fee_test_utils::MockFeeAccountArgs;
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{
+    set_referral_fee_ix, PoolConfig, SControllerError, SetReferralFeeIxArgs,
+};
+use s_controller_lib::{
+    calc_swap_referral_fees, program::POOL_STATE_ID, swap_exact_in_ix_by_mint_full,
+    SControllerEvent, SetReferralFeeFreeArgs, SrcDstLstSolValueCalcAccountSuffixes,
+    SwapByMintsFreeArgs, SwapEvent, SwapExactInAmounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, MockPoolState, MockProtocolFeeBps,
+    PoolConfigBanksClient, PoolConfigProgramTest, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+    IntoAccount,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
+
+#[tokio::test]
+async fn set_referral_fee_creates_pool_config() {
+    const NEW_FEE_BPS: u16 = 2_000;
+    const REMOVE_LIQUIDITY_PROPORTIONAL_FEE_BPS: u16 = 5;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pool_config(PoolConfig {
+            remove_liquidity_proportional_fee_bps: REMOVE_LIQUIDITY_PROPORTIONAL_FEE_BPS,
+            referral_fee_bps: 0,
+            protocol_fees_as_lp: 0,
+            lp_requires_synced_lsts: 0,
+            max_rebalance_loss_bps: 0,
+            rebalance_authority_epoch_budget: 0,
+            rebalance_authority_last_epoch: 0,
+            lp_synced_lsts_dust_threshold: 0,
+            rebalance_authority_epoch_spent: 0,
+        });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let set_fee_ix = |new_fee_bps| {
+        set_referral_fee_ix(
+            SetReferralFeeFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: KeyedAccount {
+                    pubkey: POOL_STATE_ID,
+                    account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+                },
+            }
+            .resolve()
+            .unwrap(),
+            SetReferralFeeIxArgs { new_fee_bps },
+        )
+        .unwrap()
+    };
+
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix(NEW_FEE_BPS)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(pool_config.referral_fee_bps, NEW_FEE_BPS);
+    assert_eq!(
+        pool_config.remove_liquidity_proportional_fee_bps,
+        REMOVE_LIQUIDITY_PROPORTIONAL_FEE_BPS
+    );
+
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix(10_001)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::FeeTooHigh);
+}
+
+#[tokio::test]
+async fn swap_exact_in_pays_referrer() {
+    let lp_token_mint = Pubkey::new_unique();
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    const JITOSOL_OUT_FEE_BPS: i16 = 6;
+    const MSOL_IN_FEE_BPS: i16 = 9;
+    const TRADING_PROTOCOL_FEE_BPS: u16 = 5_000;
+    const REFERRAL_FEE_BPS: u16 = 2_000;
+
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Default::default(),
+            lp_withdrawal_fee_bps: Default::default(),
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: Default::default(),
+                output_fee_bps: JITOSOL_OUT_FEE_BPS,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: MSOL_IN_FEE_BPS,
+                output_fee_bps: Default::default(),
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: TRADING_PROTOCOL_FEE_BPS,
+            lp: Default::default(),
+        },
+    )
+    .add_s_program()
+    .add_pool_config(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 0,
+        referral_fee_bps: REFERRAL_FEE_BPS,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 0,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: 0,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: 0,
+    });
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+    let referrer_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: Pubkey::new_unique(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: Some(referrer_jitosol_acc_addr),
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &PriceExactInFreeArgs {
+            input_lst_mint: msol::ID,
+            output_lst_mint: jitosol::ID,
+        }
+        .resolve_to_account_metas(),
+        flat_fee_lib::program::ID,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+    let jitosol_protocol_fee_accumulator = ix.accounts[5].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    let [SControllerEvent::SwapExactIn(SwapEvent {
+        dst_lst_out,
+        protocol_fees,
+        referral_fees,
+        ..
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+
+    let mut balances = Vec::new();
+    for addr in [
+        swapper_jitosol_acc_addr,
+        jitosol_pool_reserves,
+        jitosol_protocol_fee_accumulator,
+        referrer_jitosol_acc_addr,
+    ] {
+        let acc = banks_client.get_account_unwrapped(addr).await;
+        balances.push(token_account_balance(acc).unwrap());
+    }
+    let [jitosol_received, jitosol_pool_reserves_balance, protocol_fee_accumulator_balance, referrer_balance] =
+        balances.try_into().unwrap();
+
+    assert_eq!(jitosol_received, *dst_lst_out);
+    assert!(*referral_fees > 0);
+    assert_eq!(
+        *referral_fees,
+        calc_swap_referral_fees(*protocol_fees, REFERRAL_FEE_BPS).unwrap()
+    );
+    assert_eq!(referrer_balance, *referral_fees);
+    assert_eq!(
+        protocol_fee_accumulator_balance + referrer_balance,
+        *protocol_fees
+    );
+    assert_eq!(
+        jitosol_pool_reserves_balance + jitosol_received + *protocol_fees,
+        JITOSOL_STARTING_POOL_RESERVES
+    );
+}
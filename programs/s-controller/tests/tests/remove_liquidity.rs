@@ -5,6 +5,7 @@ use s_controller_interface::SControllerError;
 use s_controller_lib::{
     remove_liquidity_ix_full, try_pool_state, AddRemoveLiquidityExtraAccounts,
     RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    SwapLiquidityReturnData,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, lido_wsol_flat_fee_program_test,
@@ -25,7 +26,7 @@ use spl_token::native_mint;
 use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
 
-use crate::common::SControllerProgramTest;
+use crate::common::{process_tx_get_s_controller_return_data, SControllerProgramTest};
 
 #[tokio::test]
 async fn basic_redeem_full_no_fees() {
@@ -258,7 +259,7 @@ async fn basic_redeem_full_flat_fees() {
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer, &liquidity_provider], last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
 
     let aaf = CeilDiv(U64BpsFee::new_unchecked(LP_WITHDRAWAL_FEE_BPS))
         .apply(WSOL_RESERVES_STARTING_BALANCE)
@@ -292,6 +293,17 @@ async fn basic_redeem_full_flat_fees() {
         token_account_balance(protocol_fee_accumulator_account).unwrap(),
         protocol_fees_charged
     );
+
+    assert_eq!(
+        return_data,
+        SwapLiquidityReturnData {
+            amount_in: LP_TOKENS_TO_REMOVE,
+            amount_out: amt_after_fee,
+            protocol_fees: protocol_fees_charged,
+            in_sol_value: WSOL_RESERVES_STARTING_BALANCE,
+            out_sol_value: amt_after_fee,
+        }
+    );
 }
 
 #[tokio::test]
@@ -0,0 +1,368 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{
+    set_remove_liquidity_proportional_fee_ix, PoolConfig, PoolState, SControllerError,
+    SetRemoveLiquidityProportionalFeeIxArgs,
+};
+use s_controller_lib::{
+    find_pool_reserves_address, program::POOL_STATE_ID, remove_liquidity_proportional_ix_full,
+    try_lst_state_list, try_pool_state, FindLstPdaAtaKeys,
+    RemoveLiquidityProportionalByListFreeArgs, RemoveLiquidityProportionalEvent,
+    RemoveLiquidityProportionalLst, SControllerEvent, SetRemoveLiquidityProportionalFeeFreeArgs,
+    UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, MockPoolState, PoolConfigBanksClient,
+    PoolConfigProgramTest, PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+    IntoAccount,
+};
+use sanctum_token_lib::{mint_supply, token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_events, SControllerProgramTest};
+
+const LP_TOKEN_SUPPLY: u64 = 1_000_000_000;
+const RESERVES_STARTING_BALANCE: u64 = 1_000_000_000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    liquidity_provider: Keypair,
+    lp_token_mint: Pubkey,
+    src_lp_acc: Pubkey,
+    dst_lst_accs: [Pubkey; 2],
+}
+
+async fn setup(pool_config: Option<PoolConfig>) -> Setup {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: RESERVES_STARTING_BALANCE,
+        msol_sol_value: RESERVES_STARTING_BALANCE,
+        jitosol_reserves: RESERVES_STARTING_BALANCE,
+        msol_reserves: RESERVES_STARTING_BALANCE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    if let Some(pool_config) = pool_config {
+        program_test = program_test.add_pool_config(pool_config);
+    }
+    let dst_lst_accs = [jitosol::ID, msol::ID].map(|mint| {
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        })
+    });
+    let src_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: liquidity_provider.pubkey(),
+        amount: LP_TOKEN_SUPPLY,
+    });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    Setup {
+        ctx,
+        liquidity_provider,
+        lp_token_mint,
+        src_lp_acc,
+        dst_lst_accs,
+    }
+}
+
+async fn remove_liquidity_proportional_tx(
+    banks_client: &mut BanksClient,
+    setup: &Setup,
+    lp_token_amount: u64,
+    min_lst_outs: [u64; 2],
+) -> Transaction {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts: Vec<AccountMeta> = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_keys) = RemoveLiquidityProportionalByListFreeArgs {
+        signer: setup.liquidity_provider.pubkey(),
+        src_lp_acc: setup.src_lp_acc,
+        pool_state: pool_state_acc,
+        lst_state_list: lst_state_list_acc,
+        lst_mints: &[jitosol::ID, msol::ID].map(|pubkey| MintWithTokenProgram {
+            pubkey,
+            token_program: spl_token::ID,
+        }),
+        dst_lst_accs: &setup.dst_lst_accs,
+    }
+    .resolve()
+    .unwrap();
+    let ix = remove_liquidity_proportional_ix_full(
+        keys,
+        lp_token_amount,
+        &[
+            RemoveLiquidityProportionalLst {
+                keys: lst_keys[0],
+                min_lst_out: min_lst_outs[0],
+                sol_value_calculator_accounts: &jito_sol_val_calc_accounts,
+            },
+            RemoveLiquidityProportionalLst {
+                keys: lst_keys[1],
+                min_lst_out: min_lst_outs[1],
+                sol_value_calculator_accounts: &marinade_sol_val_calc_accounts,
+            },
+        ],
+    )
+    .unwrap();
+
+    let payer = &setup.ctx.payer;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(
+        &[payer, &setup.liquidity_provider],
+        setup.ctx.last_blockhash,
+    );
+    tx
+}
+
+async fn token_balance(banks_client: &mut BanksClient, addr: Pubkey) -> u64 {
+    token_account_balance(banks_client.get_account_unwrapped(addr).await).unwrap()
+}
+
+#[tokio::test]
+async fn basic_no_pool_config() {
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 4;
+    const EXPECTED_LST_OUT: u64 = RESERVES_STARTING_BALANCE / 4;
+
+    let setup = setup(None).await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = remove_liquidity_proportional_tx(
+        &mut banks_client,
+        &setup,
+        LP_TOKENS_TO_REMOVE,
+        [EXPECTED_LST_OUT; 2],
+    )
+    .await;
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+
+    for dst_lst_acc in setup.dst_lst_accs {
+        assert_eq!(
+            token_balance(&mut banks_client, dst_lst_acc).await,
+            EXPECTED_LST_OUT
+        );
+    }
+    assert_eq!(
+        token_balance(&mut banks_client, setup.src_lp_acc).await,
+        LP_TOKEN_SUPPLY - LP_TOKENS_TO_REMOVE
+    );
+    let lp_token_mint_acc = banks_client
+        .get_account_unwrapped(setup.lp_token_mint)
+        .await;
+    assert_eq!(
+        mint_supply(lp_token_mint_acc).unwrap(),
+        LP_TOKEN_SUPPLY - LP_TOKENS_TO_REMOVE
+    );
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let PoolState {
+        total_sol_value, ..
+    } = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(
+        *total_sol_value,
+        lst_state_list.iter().map(|s| s.sol_value).sum::<u64>()
+    );
+
+    assert_eq!(
+        events,
+        (0..2)
+            .map(|lst_index| SControllerEvent::RemoveLiquidityProportional(
+                RemoveLiquidityProportionalEvent {
+                    lst_index,
+                    lp_tokens_burnt: LP_TOKENS_TO_REMOVE,
+                    lst_out: EXPECTED_LST_OUT,
+                    protocol_fees: 0,
+                    total_sol_value: *total_sol_value,
+                }
+            ))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn fee_stays_in_pool() {
+    const FEE_BPS: u16 = 100;
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 4;
+    // 250_000_000 - 1% = 247_500_000
+    const EXPECTED_LST_OUT: u64 = 247_500_000;
+
+    let setup = setup(Some(PoolConfig {
+        remove_liquidity_proportional_fee_bps: FEE_BPS,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 0,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: 0,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: 0,
+    }))
+    .await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = remove_liquidity_proportional_tx(
+        &mut banks_client,
+        &setup,
+        LP_TOKENS_TO_REMOVE,
+        [EXPECTED_LST_OUT; 2],
+    )
+    .await;
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    for (dst_lst_acc, lst_state) in setup.dst_lst_accs.into_iter().zip(lst_state_list) {
+        assert_eq!(
+            token_balance(&mut banks_client, dst_lst_acc).await,
+            EXPECTED_LST_OUT
+        );
+        let pool_reserves = find_pool_reserves_address(FindLstPdaAtaKeys {
+            lst_mint: lst_state.mint,
+            token_program: spl_token::ID,
+        })
+        .0;
+        assert_eq!(
+            token_balance(&mut banks_client, pool_reserves).await,
+            RESERVES_STARTING_BALANCE - EXPECTED_LST_OUT
+        );
+    }
+}
+
+#[tokio::test]
+async fn fail_slippage() {
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 4;
+    const EXPECTED_LST_OUT: u64 = RESERVES_STARTING_BALANCE / 4;
+
+    let setup = setup(None).await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx = remove_liquidity_proportional_tx(
+        &mut banks_client,
+        &setup,
+        LP_TOKENS_TO_REMOVE,
+        [EXPECTED_LST_OUT, EXPECTED_LST_OUT + 1],
+    )
+    .await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
+
+#[tokio::test]
+async fn fail_lsts_not_synced_this_epoch() {
+    const LP_TOKENS_TO_REMOVE: u64 = LP_TOKEN_SUPPLY / 4;
+
+    let setup = setup(Some(PoolConfig {
+        remove_liquidity_proportional_fee_bps: 0,
+        referral_fee_bps: 0,
+        protocol_fees_as_lp: 0,
+        lp_requires_synced_lsts: 1,
+        max_rebalance_loss_bps: 0,
+        rebalance_authority_epoch_budget: 0,
+        rebalance_authority_last_epoch: 0,
+        lp_synced_lsts_dust_threshold: 0,
+        rebalance_authority_epoch_spent: 0,
+    }))
+    .await;
+    let mut banks_client = setup.ctx.banks_client.clone();
+
+    let tx =
+        remove_liquidity_proportional_tx(&mut banks_client, &setup, LP_TOKENS_TO_REMOVE, [0, 0])
+            .await;
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LstNotSyncedThisEpoch);
+}
+
+#[tokio::test]
+async fn set_fee_creates_pool_config() {
+    const NEW_FEE_BPS: u16 = 5;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    assert_eq!(
+        banks_client
+            .get_pool_config()
+            .await
+            .remove_liquidity_proportional_fee_bps,
+        0
+    );
+
+    let set_fee_ix = |new_fee_bps| {
+        set_remove_liquidity_proportional_fee_ix(
+            SetRemoveLiquidityProportionalFeeFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: KeyedAccount {
+                    pubkey: POOL_STATE_ID,
+                    account: MockPoolState(DEFAULT_POOL_STATE).into_account(),
+                },
+            }
+            .resolve()
+            .unwrap(),
+            SetRemoveLiquidityProportionalFeeIxArgs { new_fee_bps },
+        )
+        .unwrap()
+    };
+
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix(NEW_FEE_BPS)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_config = banks_client.get_pool_config().await;
+    assert_eq!(
+        pool_config.remove_liquidity_proportional_fee_bps,
+        NEW_FEE_BPS
+    );
+    // creating the PDA does not rate-limit the pool's rebalance authority
+    assert_eq!(
+        pool_config.rebalance_authority_epoch_budget,
+        UNLIMITED_REBALANCE_AUTHORITY_EPOCH_BUDGET
+    );
+
+    let mut tx = Transaction::new_with_payer(&[set_fee_ix(10_001)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::FeeTooHigh);
+}
@@ -0,0 +1,157 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    remove_liquidity_to_stake_ix_by_mint_full, AddRemoveLiquidityAccountSuffixes,
+    RemoveLiquidityIxAmts, RemoveLiquidityToStakeByMintFreeArgs, WithdrawStakeProgramAccounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{assert_custom_err, token::MockTokenAccountArgs};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, stake, sysvar};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_stake_pool_keys::spl_stake_pool_program;
+
+use crate::common::SControllerProgramTest;
+
+const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+
+/// Withdraw stake accounts with the pinned accounts set to the given keys
+/// and the rest set to random keys
+fn withdraw_stake_accounts(
+    user_stake_authority: Pubkey,
+    user_transfer_authority: Pubkey,
+    burn_from: Pubkey,
+    pool_mint: Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(user_stake_authority, false),
+        AccountMeta::new_readonly(user_transfer_authority, false),
+        AccountMeta::new(burn_from, false),
+        AccountMeta::new(Pubkey::new_unique(), false),
+        AccountMeta::new(pool_mint, false),
+        AccountMeta::new_readonly(sysvar::clock::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(stake::program::ID, false),
+    ]
+}
+
+async fn assert_remove_liquidity_to_stake_fails(
+    withdraw_stake_program_id: Pubkey,
+    withdraw_stake_accounts_fn: impl FnOnce(Pubkey, Pubkey, Pubkey, Pubkey) -> Vec<AccountMeta>,
+    expected_err: SControllerError,
+) {
+    let withdrawer = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: LP_TOKEN_AMOUNT,
+        jitosol_reserves: 0,
+        msol_reserves: LP_TOKEN_AMOUNT,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_AMOUNT,
+    })
+    .add_s_program();
+    let src_lp_acc = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: lp_token_mint,
+        authority: withdrawer.pubkey(),
+        amount: LP_TOKEN_AMOUNT,
+    });
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let free_args = RemoveLiquidityToStakeByMintFreeArgs {
+        signer: withdrawer.pubkey(),
+        src_lp_acc,
+        pool_state: pool_state_acc,
+        lst_state_list: lst_state_list_acc,
+        lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+    };
+    let (keys, _, _) = free_args.clone().resolve().unwrap();
+    let withdraw_stake_accounts = withdraw_stake_accounts_fn(
+        withdrawer.pubkey(),
+        keys.pool_state,
+        keys.pool_reserves,
+        keys.lst_mint,
+    );
+    let ix = remove_liquidity_to_stake_ix_by_mint_full(
+        free_args,
+        RemoveLiquidityIxAmts {
+            lp_token_amount: LP_TOKEN_AMOUNT,
+            min_lst_out: 0,
+        },
+        WithdrawStakeProgramAccounts {
+            withdraw_stake_program_id,
+            withdraw_stake_accounts: &withdraw_stake_accounts,
+        },
+        AddRemoveLiquidityAccountSuffixes {
+            lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            pricing_program_price_lp_accounts: &[AccountMeta::new_readonly(msol::ID, false)],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &withdrawer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, expected_err);
+}
+
+#[tokio::test]
+async fn fail_unsupported_withdraw_stake_program() {
+    assert_remove_liquidity_to_stake_fails(
+        Pubkey::new_unique(),
+        withdraw_stake_accounts,
+        SControllerError::IncorrectWithdrawStakeProgram,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_withdraw_stake_burn_from_not_pool_reserves() {
+    assert_remove_liquidity_to_stake_fails(
+        spl_stake_pool_program::ID,
+        |user_stake_authority, user_transfer_authority, _burn_from, pool_mint| {
+            withdraw_stake_accounts(
+                user_stake_authority,
+                user_transfer_authority,
+                Pubkey::new_unique(),
+                pool_mint,
+            )
+        },
+        SControllerError::IncorrectWithdrawStakeAccounts,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_withdraw_stake_authority_not_signer() {
+    assert_remove_liquidity_to_stake_fails(
+        spl_stake_pool_program::ID,
+        |_user_stake_authority, user_transfer_authority, burn_from, pool_mint| {
+            withdraw_stake_accounts(
+                Pubkey::new_unique(),
+                user_transfer_authority,
+                burn_from,
+                pool_mint,
+            )
+        },
+        SControllerError::IncorrectWithdrawStakeAccounts,
+    )
+    .await;
+}
@@ -1,10 +1,11 @@
-use s_controller_interface::set_admin_ix;
-use s_controller_lib::{program::POOL_STATE_ID, SetAdminFreeArgs};
+use s_controller_interface::{accept_admin_ix, set_admin_ix, PendingAuthorities};
+use s_controller_lib::{program::POOL_STATE_ID, AcceptAuthorityFreeArgs, SetAdminFreeArgs};
 
 use s_controller_test_utils::{
-    assert_admin, PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+    assert_admin, MockPendingAuthorities, PendingAuthoritiesBanksClient, PoolStateBanksClient,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
 };
-use sanctum_solana_test_utils::test_fixtures_dir;
+use sanctum_solana_test_utils::{test_fixtures_dir, IntoAccount};
 use solana_program_test::*;
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{
@@ -28,9 +29,10 @@ async fn basic_set_admin() {
 
     let (mut banks_client, payer, last_blockhash) = program_test.start().await;
 
-    // Change admin
+    // Propose new admin
     let ix = set_admin_ix(
         SetAdminFreeArgs {
+            payer: payer.pubkey(),
             new_admin: new_admin_kp.pubkey(),
             pool_state: KeyedAccount {
                 pubkey: POOL_STATE_ID,
@@ -47,11 +49,44 @@ async fn basic_set_admin() {
 
     banks_client.process_transaction(tx).await.unwrap();
 
+    assert_admin(&mut banks_client, mock_auth_kp.pubkey()).await;
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        PendingAuthorities {
+            admin: new_admin_kp.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+
+    // Accept
+    let ix = accept_admin_ix(
+        AcceptAuthorityFreeArgs {
+            pending_authorities: MockPendingAuthorities(PendingAuthorities {
+                admin: new_admin_kp.pubkey(),
+                ..NO_PENDING_AUTHORITIES
+            })
+            .into_account(),
+        }
+        .resolve_admin()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &new_admin_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
     assert_admin(&mut banks_client, new_admin_kp.pubkey()).await;
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        NO_PENDING_AUTHORITIES
+    );
 
-    // Change admin again
+    // Change admin again, PDA already exists this time
     let ix2 = set_admin_ix(
         SetAdminFreeArgs {
+            payer: payer.pubkey(),
             new_admin: another_new_admin_kp.pubkey(),
             pool_state: KeyedAccount {
                 pubkey: POOL_STATE_ID,
@@ -62,9 +97,24 @@ async fn basic_set_admin() {
         .unwrap(),
     )
     .unwrap();
+    let accept_ix2 = accept_admin_ix(
+        AcceptAuthorityFreeArgs {
+            pending_authorities: MockPendingAuthorities(PendingAuthorities {
+                admin: another_new_admin_kp.pubkey(),
+                ..NO_PENDING_AUTHORITIES
+            })
+            .into_account(),
+        }
+        .resolve_admin()
+        .unwrap(),
+    )
+    .unwrap();
 
-    let mut tx2 = Transaction::new_with_payer(&[ix2], Some(&payer.pubkey()));
-    tx2.sign(&[&payer, &new_admin_kp], last_blockhash);
+    let mut tx2 = Transaction::new_with_payer(&[ix2, accept_ix2], Some(&payer.pubkey()));
+    tx2.sign(
+        &[&payer, &new_admin_kp, &another_new_admin_kp],
+        last_blockhash,
+    );
 
     banks_client.process_transaction(tx2).await.unwrap();
 
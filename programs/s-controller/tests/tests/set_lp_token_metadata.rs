@@ -0,0 +1,415 @@
+use s_controller_interface::{
+    set_lp_token_metadata_ix, PoolState, SControllerError, SetLpTokenMetadataIxArgs,
+};
+use s_controller_lib::{
+    find_mpl_metadata_address, mpl_create_metadata_account_v3_ix_data, mpl_token_metadata_program,
+    program::POOL_STATE_ID, verify_mpl_metadata_lens, SetLpTokenMetadataFreeArgs,
+    MPL_MAX_NAME_LENGTH, MPL_MAX_SYMBOL_LENGTH, MPL_MAX_URI_LENGTH,
+};
+use s_controller_test_utils::{
+    LpTokenProgramTest, MockPoolState, PoolStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, est_rent_exempt_lamports, test_fixtures_dir, ExtendedBanksClient,
+    ExtendedProgramTest, IntoAccount,
+};
+use solana_program::{instruction::Instruction, program_option::COption, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_token_2022::{
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensions, ExtensionType,
+        StateWithExtensions, StateWithExtensionsMut,
+    },
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::common::SControllerProgramTest;
+
+#[tokio::test]
+async fn set_lp_token_metadata_token_2022_initialize_then_update() {
+    let mock_auth_kp = mock_auth_kp();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool_state = PoolState {
+        lp_token_mint,
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(pool_state)
+        .add_account_chained(
+            lp_token_mint,
+            token_2022_lp_mint_with_metadata_pointer(lp_token_mint),
+        );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let initial = SetLpTokenMetadataIxArgs {
+        name: "Infinity".into(),
+        symbol: "INF".into(),
+        uri: "https://example.com/inf.json".into(),
+    };
+    exec_set_lp_token_metadata(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        pool_state,
+        initial.clone(),
+    )
+    .await;
+    assert_lp_token_metadata(&mut banks_client, lp_token_mint, &initial).await;
+
+    let updated = SetLpTokenMetadataIxArgs {
+        uri: "https://example.com/a-much-longer-uri-to-force-a-realloc/inf.json".into(),
+        ..initial
+    };
+    let last_blockhash = banks_client
+        .get_new_latest_blockhash(&last_blockhash)
+        .await
+        .unwrap();
+    exec_set_lp_token_metadata(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        pool_state,
+        updated.clone(),
+    )
+    .await;
+    assert_lp_token_metadata(&mut banks_client, lp_token_mint, &updated).await;
+}
+
+#[tokio::test]
+async fn set_lp_token_metadata_mpl_create_then_update() {
+    let mock_auth_kp = mock_auth_kp();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool_state = PoolState {
+        lp_token_mint,
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_mpl_token_metadata_prog()
+        .add_pool_state(pool_state)
+        .add_mock_lp_mint(lp_token_mint, 0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let initial = SetLpTokenMetadataIxArgs {
+        name: "Infinity".into(),
+        symbol: "INF".into(),
+        uri: "https://example.com/inf.json".into(),
+    };
+    exec_set_lp_token_metadata(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        pool_state,
+        initial.clone(),
+    )
+    .await;
+    assert_mpl_metadata(&mut banks_client, lp_token_mint, &initial).await;
+
+    let updated = SetLpTokenMetadataIxArgs {
+        name: "Sanctum Infinity".into(),
+        uri: "https://example.com/a-much-longer-uri/inf.json".into(),
+        ..initial
+    };
+    let last_blockhash = banks_client
+        .get_new_latest_blockhash(&last_blockhash)
+        .await
+        .unwrap();
+    exec_set_lp_token_metadata(
+        &mut banks_client,
+        &payer,
+        &mock_auth_kp,
+        last_blockhash,
+        pool_state,
+        updated.clone(),
+    )
+    .await;
+    assert_mpl_metadata(&mut banks_client, lp_token_mint, &updated).await;
+}
+
+#[tokio::test]
+async fn set_lp_token_metadata_mpl_fails_name_too_long() {
+    let mock_auth_kp = mock_auth_kp();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool_state = PoolState {
+        lp_token_mint,
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(pool_state)
+        .add_mock_lp_mint(lp_token_mint, 0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    let mut tx = Transaction::new_with_payer(
+        &[set_lp_token_metadata_test_ix(
+            &payer,
+            pool_state,
+            lp_token_mint_acc,
+            SetLpTokenMetadataIxArgs {
+                name: "n".repeat(MPL_MAX_NAME_LENGTH + 1),
+                symbol: "INF".into(),
+                uri: "https://example.com/inf.json".into(),
+            },
+        )],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LpTokenMetadataTooLong);
+}
+
+#[test]
+fn verify_mpl_metadata_lens_bounds() {
+    let max = SetLpTokenMetadataIxArgs {
+        name: "n".repeat(MPL_MAX_NAME_LENGTH),
+        symbol: "s".repeat(MPL_MAX_SYMBOL_LENGTH),
+        uri: "u".repeat(MPL_MAX_URI_LENGTH),
+    };
+    verify_mpl_metadata_lens(&max).unwrap();
+    for too_long in [
+        SetLpTokenMetadataIxArgs {
+            name: "n".repeat(MPL_MAX_NAME_LENGTH + 1),
+            ..max.clone()
+        },
+        SetLpTokenMetadataIxArgs {
+            symbol: "s".repeat(MPL_MAX_SYMBOL_LENGTH + 1),
+            ..max.clone()
+        },
+        SetLpTokenMetadataIxArgs {
+            uri: "u".repeat(MPL_MAX_URI_LENGTH + 1),
+            ..max.clone()
+        },
+    ] {
+        assert_eq!(
+            verify_mpl_metadata_lens(&too_long).unwrap_err(),
+            SControllerError::LpTokenMetadataTooLong
+        );
+        assert_eq!(
+            mpl_create_metadata_account_v3_ix_data(&too_long).unwrap_err(),
+            SControllerError::LpTokenMetadataTooLong
+        );
+    }
+}
+
+#[test]
+fn set_lp_token_metadata_fails_unsupported_lp_token_program() {
+    let lp_token_mint = Pubkey::new_unique();
+    let result = SetLpTokenMetadataFreeArgs {
+        payer: Pubkey::new_unique(),
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: MockPoolState(PoolState {
+                lp_token_mint,
+                ..DEFAULT_POOL_STATE
+            })
+            .into_account(),
+        },
+        lp_token_mint: Account {
+            owner: Pubkey::new_unique(),
+            ..token_2022_lp_mint_with_metadata_pointer(lp_token_mint)
+        },
+    }
+    .resolve();
+    assert_eq!(
+        result.unwrap_err(),
+        SControllerError::UnsupportedLpTokenProgram
+    );
+}
+
+#[tokio::test]
+async fn set_lp_token_metadata_fails_pool_disabled() {
+    let mock_auth_kp = mock_auth_kp();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool_state = PoolState {
+        lp_token_mint,
+        is_disabled: 1,
+        ..DEFAULT_POOL_STATE
+    };
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(pool_state)
+        .add_account_chained(
+            lp_token_mint,
+            token_2022_lp_mint_with_metadata_pointer(lp_token_mint),
+        );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut tx = Transaction::new_with_payer(
+        &[set_lp_token_metadata_test_ix(
+            &payer,
+            pool_state,
+            token_2022_lp_mint_with_metadata_pointer(lp_token_mint),
+            SetLpTokenMetadataIxArgs {
+                name: "Infinity".into(),
+                symbol: "INF".into(),
+                uri: "https://example.com/inf.json".into(),
+            },
+        )],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::PoolDisabled);
+}
+
+fn mock_auth_kp() -> Keypair {
+    read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+        .unwrap()
+}
+
+/// A token-2022 LP token mint with the pool state as mint authority
+/// and its metadata pointer pointing to itself
+fn token_2022_lp_mint_with_metadata_pointer(lp_token_mint: Pubkey) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+        .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let metadata_pointer = state.init_extension::<MetadataPointer>(true).unwrap();
+    metadata_pointer.authority = Some(POOL_STATE_ID).try_into().unwrap();
+    metadata_pointer.metadata_address = Some(lp_token_mint).try_into().unwrap();
+    state.base = Mint {
+        mint_authority: COption::Some(POOL_STATE_ID),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::Some(POOL_STATE_ID),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(len),
+        data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+fn set_lp_token_metadata_test_ix(
+    payer: &Keypair,
+    pool_state: PoolState,
+    lp_token_mint: Account,
+    args: SetLpTokenMetadataIxArgs,
+) -> Instruction {
+    set_lp_token_metadata_ix(
+        SetLpTokenMetadataFreeArgs {
+            payer: payer.pubkey(),
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(pool_state).into_account(),
+            },
+            lp_token_mint,
+        }
+        .resolve()
+        .unwrap(),
+        args,
+    )
+    .unwrap()
+}
+
+async fn exec_set_lp_token_metadata(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    mock_auth_kp: &Keypair,
+    last_blockhash: Hash,
+    pool_state: PoolState,
+    args: SetLpTokenMetadataIxArgs,
+) {
+    let lp_token_mint = banks_client
+        .get_account_unwrapped(pool_state.lp_token_mint)
+        .await;
+    let mut tx = Transaction::new_with_payer(
+        &[set_lp_token_metadata_test_ix(
+            payer,
+            pool_state,
+            lp_token_mint,
+            args,
+        )],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[payer, mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn assert_lp_token_metadata(
+    banks_client: &mut BanksClient,
+    lp_token_mint: Pubkey,
+    SetLpTokenMetadataIxArgs { name, symbol, uri }: &SetLpTokenMetadataIxArgs,
+) {
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    let rent = banks_client.get_rent().await.unwrap();
+    assert!(rent.is_exempt(lp_token_mint_acc.lamports, lp_token_mint_acc.data.len()));
+
+    let mint = StateWithExtensions::<Mint>::unpack(&lp_token_mint_acc.data).unwrap();
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(POOL_STATE_ID)
+    );
+    assert_eq!(metadata.mint, lp_token_mint);
+    assert_eq!(metadata.name, *name);
+    assert_eq!(metadata.symbol, *symbol);
+    assert_eq!(metadata.uri, *uri);
+}
+
+trait MplTokenMetadataProgramTest {
+    /// Program and program data accounts of the metaplex token metadata program,
+    /// cloned from mainnet, see test-fixtures/README.md
+    fn add_mpl_token_metadata_prog(self) -> Self;
+}
+
+impl MplTokenMetadataProgramTest for ProgramTest {
+    fn add_mpl_token_metadata_prog(self) -> Self {
+        self.add_test_fixtures_account("mpl-token-metadata-prog.json")
+            .add_test_fixtures_account("mpl-token-metadata-prog-data.json")
+    }
+}
+
+/// `Key::MetadataV1`
+const MPL_METADATA_V1_KEY: u8 = 4;
+
+async fn assert_mpl_metadata(
+    banks_client: &mut BanksClient,
+    lp_token_mint: Pubkey,
+    SetLpTokenMetadataIxArgs { name, symbol, uri }: &SetLpTokenMetadataIxArgs,
+) {
+    let metadata_acc = banks_client
+        .get_account_unwrapped(find_mpl_metadata_address(lp_token_mint).0)
+        .await;
+    assert_eq!(metadata_acc.owner, mpl_token_metadata_program::ID);
+
+    // Metadata { key, update_authority, mint, data: Data { name, symbol, uri, .. }, .. }
+    let data = metadata_acc.data.as_slice();
+    assert_eq!(data[0], MPL_METADATA_V1_KEY);
+    assert_eq!(Pubkey::try_from(&data[1..33]).unwrap(), POOL_STATE_ID);
+    assert_eq!(Pubkey::try_from(&data[33..65]).unwrap(), lp_token_mint);
+    let mut offset = 65;
+    for expected in [name, symbol, uri] {
+        assert_eq!(read_mpl_string(data, &mut offset), *expected);
+    }
+}
+
+/// Reads a borsh string, trimming the null bytes metaplex pads it with
+fn read_mpl_string(data: &[u8], offset: &mut usize) -> String {
+    let len = u32::from_le_bytes(data[*offset..*offset + 4].try_into().unwrap());
+    let start = *offset + 4;
+    *offset = start + usize::try_from(len).unwrap();
+    std::str::from_utf8(&data[start..*offset])
+        .unwrap()
+        .trim_end_matches('\0')
+        .to_owned()
+}
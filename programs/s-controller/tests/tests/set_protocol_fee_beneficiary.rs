@@ -1,8 +1,15 @@
-use s_controller_interface::{set_protocol_fee_beneficiary_ix, PoolState};
-use s_controller_lib::{program::POOL_STATE_ID, try_pool_state, SetProtocolFeeBeneficiaryFreeArgs};
+use s_controller_interface::{
+    accept_protocol_fee_beneficiary_ix, set_protocol_fee_beneficiary_ix, PendingAuthorities,
+    PoolState,
+};
+use s_controller_lib::{
+    program::POOL_STATE_ID, try_pool_state, AcceptAuthorityFreeArgs,
+    SetProtocolFeeBeneficiaryFreeArgs,
+};
 
 use s_controller_test_utils::{
-    MockPoolState, PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
+    MockPendingAuthorities, MockPoolState, PendingAuthoritiesBanksClient, PoolStateBanksClient,
+    PoolStateProgramTest, DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
 };
 use sanctum_solana_test_utils::{test_fixtures_dir, IntoAccount};
 use solana_program_test::*;
@@ -29,9 +36,10 @@ async fn basic_set_protocol_fee_beneficiary() {
 
     let (mut banks_client, payer, last_blockhash) = program_test.start().await;
 
-    // Change protocol fee beneficiary
+    // Propose new protocol fee beneficiary
     let ix = set_protocol_fee_beneficiary_ix(
         SetProtocolFeeBeneficiaryFreeArgs {
+            payer: payer.pubkey(),
             new_beneficiary: new_beneficiary_kp.pubkey(),
             pool_state: KeyedAccount {
                 pubkey: POOL_STATE_ID,
@@ -48,6 +56,36 @@ async fn basic_set_protocol_fee_beneficiary() {
 
     banks_client.process_transaction(tx).await.unwrap();
 
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(*pool_state, DEFAULT_POOL_STATE);
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        PendingAuthorities {
+            protocol_fee_beneficiary: new_beneficiary_kp.pubkey(),
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+
+    // Accept
+    let ix = accept_protocol_fee_beneficiary_ix(
+        AcceptAuthorityFreeArgs {
+            pending_authorities: MockPendingAuthorities(PendingAuthorities {
+                protocol_fee_beneficiary: new_beneficiary_kp.pubkey(),
+                ..NO_PENDING_AUTHORITIES
+            })
+            .into_account(),
+        }
+        .resolve_protocol_fee_beneficiary()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &new_beneficiary_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
     let pool_state_acc = banks_client.get_pool_state_acc().await;
     let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
     assert_eq!(
@@ -57,10 +95,15 @@ async fn basic_set_protocol_fee_beneficiary() {
             ..*pool_state
         }
     );
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        NO_PENDING_AUTHORITIES
+    );
 
     // Change protocol fee beneficiary again
     let ix2 = set_protocol_fee_beneficiary_ix(
         SetProtocolFeeBeneficiaryFreeArgs {
+            payer: payer.pubkey(),
             new_beneficiary: another_new_beneficiary_kp.pubkey(),
             pool_state: KeyedAccount {
                 pubkey: POOL_STATE_ID,
@@ -71,9 +114,24 @@ async fn basic_set_protocol_fee_beneficiary() {
         .unwrap(),
     )
     .unwrap();
+    let accept_ix2 = accept_protocol_fee_beneficiary_ix(
+        AcceptAuthorityFreeArgs {
+            pending_authorities: MockPendingAuthorities(PendingAuthorities {
+                protocol_fee_beneficiary: another_new_beneficiary_kp.pubkey(),
+                ..NO_PENDING_AUTHORITIES
+            })
+            .into_account(),
+        }
+        .resolve_protocol_fee_beneficiary()
+        .unwrap(),
+    )
+    .unwrap();
 
-    let mut tx2 = Transaction::new_with_payer(&[ix2], Some(&payer.pubkey()));
-    tx2.sign(&[&payer, &new_beneficiary_kp], last_blockhash);
+    let mut tx2 = Transaction::new_with_payer(&[ix2, accept_ix2], Some(&payer.pubkey()));
+    tx2.sign(
+        &[&payer, &new_beneficiary_kp, &another_new_beneficiary_kp],
+        last_blockhash,
+    );
 
     banks_client.process_transaction(tx2).await.unwrap();
 
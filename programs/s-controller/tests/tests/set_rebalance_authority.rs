@@ -1,9 +1,14 @@
-use s_controller_interface::{set_rebalance_authority_ix, SControllerError};
+use s_controller_interface::{
+    accept_rebalance_authority_ix, set_rebalance_authority_ix, PendingAuthorities, SControllerError,
+};
 use s_controller_lib::{
-    KnownAuthoritySetRebalanceAuthorityFreeArgs, SetRebalanceAuthorityFreeArgs,
+    AcceptAuthorityFreeArgs, KnownAuthoritySetRebalanceAuthorityFreeArgs,
+    SetRebalanceAuthorityFreeArgs,
 };
 use s_controller_test_utils::{
-    assert_rebalance_authority, MockPoolState, PoolStateProgramTest, DEFAULT_POOL_STATE,
+    assert_rebalance_authority, MockPendingAuthorities, MockPoolState,
+    PendingAuthoritiesBanksClient, PendingAuthoritiesProgramTest, PoolStateProgramTest,
+    DEFAULT_POOL_STATE, NO_PENDING_AUTHORITIES,
 };
 use sanctum_solana_test_utils::{assert_custom_err, test_fixtures_dir, IntoAccount};
 use solana_program::pubkey::Pubkey;
@@ -30,6 +35,7 @@ async fn admin_set() {
 
     let ix = set_rebalance_authority_ix(
         KnownAuthoritySetRebalanceAuthorityFreeArgs {
+            payer: payer.pubkey(),
             new_rebalance_authority,
             pool_state: MockPoolState(DEFAULT_POOL_STATE).into_account(),
         }
@@ -42,7 +48,14 @@ async fn admin_set() {
     tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
     banks_client.process_transaction(tx).await.unwrap();
 
-    assert_rebalance_authority(&mut banks_client, new_rebalance_authority).await;
+    assert_rebalance_authority(&mut banks_client, DEFAULT_POOL_STATE.rebalance_authority).await;
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        PendingAuthorities {
+            rebalance_authority: new_rebalance_authority,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
 }
 
 #[tokio::test]
@@ -60,6 +73,7 @@ async fn rebalance_authority_set() {
 
     let ix = set_rebalance_authority_ix(
         KnownAuthoritySetRebalanceAuthorityFreeArgs {
+            payer: payer.pubkey(),
             new_rebalance_authority,
             pool_state: MockPoolState(pool_state).into_account(),
         }
@@ -72,7 +86,48 @@ async fn rebalance_authority_set() {
     tx.sign(&[&payer, &current_rebalance_authority], last_blockhash);
     banks_client.process_transaction(tx).await.unwrap();
 
-    assert_rebalance_authority(&mut banks_client, new_rebalance_authority).await;
+    assert_rebalance_authority(&mut banks_client, current_rebalance_authority.pubkey()).await;
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        PendingAuthorities {
+            rebalance_authority: new_rebalance_authority,
+            ..NO_PENDING_AUTHORITIES
+        }
+    );
+}
+
+#[tokio::test]
+async fn pending_rebalance_authority_accept() {
+    let new_rebalance_authority = Keypair::new();
+    let pending_authorities = PendingAuthorities {
+        rebalance_authority: new_rebalance_authority.pubkey(),
+        ..NO_PENDING_AUTHORITIES
+    };
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_pending_authorities(pending_authorities);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = accept_rebalance_authority_ix(
+        AcceptAuthorityFreeArgs {
+            pending_authorities: MockPendingAuthorities(pending_authorities).into_account(),
+        }
+        .resolve_rebalance_authority()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &new_rebalance_authority], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_rebalance_authority(&mut banks_client, new_rebalance_authority.pubkey()).await;
+    assert_eq!(
+        banks_client.get_pending_authorities().await,
+        NO_PENDING_AUTHORITIES
+    );
 }
 
 #[tokio::test]
@@ -86,6 +141,7 @@ async fn unauthorized_signer() {
 
     let ix = set_rebalance_authority_ix(
         SetRebalanceAuthorityFreeArgs {
+            payer: payer.pubkey(),
             new_rebalance_authority,
             signer: payer.pubkey(), // payer is unauthorized
         }
@@ -49,6 +49,7 @@ async fn basic_set_marinade() {
             reserves_amt: MSOL_POOL_RESERVES,
             protocol_fee_accumulator_amt: 0,
             is_input_disabled: false,
+            is_output_disabled: false,
         }],
     )
     .add_s_program();
@@ -171,6 +172,7 @@ async fn fail_set_non_exec_sol_val_calc() {
         reserves_amt: MSOL_POOL_RESERVES,
         protocol_fee_accumulator_amt: 0,
         is_input_disabled: false,
+        is_output_disabled: false,
     }])
     .add_s_program();
 
@@ -0,0 +1,418 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{LstState, SControllerError};
+use s_controller_lib::{
+    program::LST_STATE_LIST_ID, set_lst_circuit_breaker_ix_by_mint_full,
+    swap_exact_in_ix_by_mint_full, sync_sol_value_ix_by_mint_full, try_lst_state_list_mut,
+    SControllerEvent, SetLstCircuitBreakerByMintFreeArgs, SolValueRateCircuitBreakerTrippedEvent,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
+    SyncSolValueByMintFreeArgs, SyncSolValueEvent, U8Bool,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+const JITOSOL_STARTING_SOL_VALUE: u64 = 1_000_000_000;
+
+/// SOL value rate of jitoSOL at JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+/// which is also the SOL value of the 1 jitoSOL pool reserves
+const JITOSOL_SOL_VALUE_RATE: u64 = 1_072_326_756;
+
+const CIRCUIT_BREAKER_BPS: u16 = 100;
+
+#[tokio::test]
+async fn sync_trips_circuit_breaker() {
+    // ~7.2% jump
+    let mut ctx = start_with_sol_value_rates(
+        circuit_breaker_program_test(),
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        },
+    )
+    .await;
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert_eq!(
+        events,
+        [SControllerEvent::SolValueRateCircuitBreakerTripped(
+            SolValueRateCircuitBreakerTrippedEvent {
+                lst_index: 0,
+                last_sol_value_rate: 1_000_000_000,
+                new_sol_value_rate: JITOSOL_SOL_VALUE_RATE,
+            }
+        )]
+    );
+
+    let LstState {
+        is_input_disabled,
+        is_output_disabled,
+        sol_value,
+        last_sol_value_rate,
+        prev_epoch_sol_value_rate,
+        ..
+    } = ctx.banks_client.get_lst_state(jitosol::ID).await;
+    assert!(U8Bool(is_input_disabled).is_true());
+    assert!(U8Bool(is_output_disabled).is_true());
+    assert_eq!(sol_value, JITOSOL_STARTING_SOL_VALUE);
+    // the tripping rate is not recorded so that later syncs keep tripping
+    assert_eq!(last_sol_value_rate, 1_000_000_000);
+    assert_eq!(prev_epoch_sol_value_rate, 1_000_000_000);
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let last_blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert!(matches!(
+        events[..],
+        [SControllerEvent::SolValueRateCircuitBreakerTripped(_)]
+    ));
+}
+
+#[tokio::test]
+async fn sync_within_threshold_does_not_trip() {
+    // ~0.2% jump
+    let mut ctx = start_with_sol_value_rates(
+        circuit_breaker_program_test(),
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_070_000_000,
+            last_sol_value_rate: 1_070_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        },
+    )
+    .await;
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert!(matches!(events[..], [SControllerEvent::SyncSolValue(_)]));
+
+    let LstState {
+        is_input_disabled,
+        is_output_disabled,
+        last_sol_value_rate,
+        last_sol_value_rate_epoch,
+        prev_epoch_sol_value_rate,
+        ..
+    } = ctx.banks_client.get_lst_state(jitosol::ID).await;
+    assert!(U8Bool(is_input_disabled).is_false());
+    assert!(U8Bool(is_output_disabled).is_false());
+    assert_eq!(last_sol_value_rate, JITOSOL_SOL_VALUE_RATE);
+    assert_eq!(last_sol_value_rate_epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    // syncs within the same epoch do not move the baseline rate
+    assert_eq!(prev_epoch_sol_value_rate, 1_070_000_000);
+}
+
+#[tokio::test]
+async fn sync_compares_against_prev_epoch_rate_within_epoch() {
+    // ~0.1% from the last recorded rate, but ~1.2% from the baseline
+    let mut ctx = start_with_sol_value_rates(
+        circuit_breaker_program_test(),
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_060_000_000,
+            last_sol_value_rate: 1_071_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        },
+    )
+    .await;
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert_eq!(
+        events,
+        [SControllerEvent::SolValueRateCircuitBreakerTripped(
+            SolValueRateCircuitBreakerTrippedEvent {
+                lst_index: 0,
+                last_sol_value_rate: 1_060_000_000,
+                new_sol_value_rate: JITOSOL_SOL_VALUE_RATE,
+            }
+        )]
+    );
+}
+
+#[tokio::test]
+async fn sync_across_epochs_trips_circuit_breaker() {
+    // ~7.2% jump from the previous epoch's last recorded rate
+    let mut ctx = start_with_sol_value_rates(
+        circuit_breaker_program_test(),
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1,
+        },
+    )
+    .await;
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert_eq!(
+        events,
+        [SControllerEvent::SolValueRateCircuitBreakerTripped(
+            SolValueRateCircuitBreakerTrippedEvent {
+                lst_index: 0,
+                last_sol_value_rate: 1_000_000_000,
+                new_sol_value_rate: JITOSOL_SOL_VALUE_RATE,
+            }
+        )]
+    );
+
+    let LstState {
+        is_input_disabled,
+        last_sol_value_rate,
+        last_sol_value_rate_epoch,
+        prev_epoch_sol_value_rate,
+        ..
+    } = ctx.banks_client.get_lst_state(jitosol::ID).await;
+    assert!(U8Bool(is_input_disabled).is_true());
+    // the baseline is only rolled forward if the check passes
+    assert_eq!(last_sol_value_rate, 1_000_000_000);
+    assert_eq!(
+        last_sol_value_rate_epoch,
+        JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1
+    );
+    assert_eq!(prev_epoch_sol_value_rate, 1_000_000_000);
+}
+
+#[tokio::test]
+async fn sync_across_epochs_rolls_baseline() {
+    // ~0.2% jump from the previous epoch's last recorded rate
+    let mut ctx = start_with_sol_value_rates(
+        circuit_breaker_program_test(),
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate: 1_070_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1,
+        },
+    )
+    .await;
+
+    let ix = jitosol_sync_sol_value_ix(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let events = process_tx_get_s_controller_events(&mut ctx.banks_client, tx).await;
+    assert_eq!(
+        events,
+        [SControllerEvent::SyncSolValue(SyncSolValueEvent {
+            lst_index: 0,
+            old_sol_value: JITOSOL_STARTING_SOL_VALUE,
+            new_sol_value: JITOSOL_SOL_VALUE_RATE,
+            total_sol_value: JITOSOL_SOL_VALUE_RATE + 1_000_000_000,
+        })]
+    );
+    let LstState {
+        is_input_disabled,
+        last_sol_value_rate,
+        last_sol_value_rate_epoch,
+        prev_epoch_sol_value_rate,
+        ..
+    } = ctx.banks_client.get_lst_state(jitosol::ID).await;
+    assert!(U8Bool(is_input_disabled).is_false());
+    assert_eq!(last_sol_value_rate, JITOSOL_SOL_VALUE_RATE);
+    assert_eq!(last_sol_value_rate_epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    assert_eq!(prev_epoch_sol_value_rate, 1_070_000_000);
+}
+
+#[tokio::test]
+async fn swap_trip_reverts_and_does_not_disable_lst() {
+    const MSOL_TO_SWAP_IN: u64 = 100_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = circuit_breaker_program_test();
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+    // ~7.2% jump
+    let mut ctx = start_with_sol_value_rates(
+        program_test,
+        RecordedSolValueRates {
+            prev_epoch_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate: 1_000_000_000,
+            last_sol_value_rate_epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        },
+    )
+    .await;
+    let lst_state_before = ctx.banks_client.get_lst_state(jitosol::ID).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut ctx.banks_client).await;
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            pool_state: ctx.banks_client.get_pool_state_acc().await,
+            lst_state_list: ctx.banks_client.get_lst_state_list_acc().await,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+        &[
+            AccountMeta::new_readonly(msol::ID, false),
+            AccountMeta::new_readonly(jitosol::ID, false),
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &swapper], ctx.last_blockhash);
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SolValueRateCircuitBreakerTripped);
+
+    // only SyncSolValue and SyncSolValueMulti persist the trip
+    let lst_state_after = ctx.banks_client.get_lst_state(jitosol::ID).await;
+    assert_eq!(lst_state_after, lst_state_before);
+    assert!(U8Bool(lst_state_after.is_input_disabled).is_false());
+    assert!(U8Bool(lst_state_after.is_output_disabled).is_false());
+}
+
+struct RecordedSolValueRates {
+    prev_epoch_sol_value_rate: u64,
+    last_sol_value_rate: u64,
+    last_sol_value_rate_epoch: u64,
+}
+
+fn circuit_breaker_program_test() -> ProgramTest {
+    jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_STARTING_SOL_VALUE,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+}
+
+/// Sets jitoSOL's circuit breaker to [`CIRCUIT_BREAKER_BPS`] and
+/// overwrites its recorded SOL value rates
+async fn start_with_sol_value_rates(
+    program_test: ProgramTest,
+    RecordedSolValueRates {
+        prev_epoch_sol_value_rate,
+        last_sol_value_rate,
+        last_sol_value_rate_epoch,
+    }: RecordedSolValueRates,
+) -> ProgramTestContext {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let mut ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ix = set_lst_circuit_breaker_ix_by_mint_full(
+        &SetLstCircuitBreakerByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: ctx.banks_client.get_pool_state_acc().await,
+            lst_state_list: ctx.banks_client.get_lst_state_list_acc().await,
+        },
+        CIRCUIT_BREAKER_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    tx.sign(&[&ctx.payer, &mock_auth_kp], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut lst_state_list_acc = ctx.banks_client.get_lst_state_list_acc().await;
+    let lst_state = try_lst_state_list_mut(&mut lst_state_list_acc.data)
+        .unwrap()
+        .iter_mut()
+        .find(|s| s.mint == jitosol::ID)
+        .unwrap();
+    assert_eq!(
+        lst_state.sol_value_rate_circuit_breaker_bps,
+        CIRCUIT_BREAKER_BPS
+    );
+    lst_state.prev_epoch_sol_value_rate = prev_epoch_sol_value_rate;
+    lst_state.last_sol_value_rate = last_sol_value_rate;
+    lst_state.last_sol_value_rate_epoch = last_sol_value_rate_epoch;
+    ctx.set_account(&LST_STATE_LIST_ID, &lst_state_list_acc.into());
+
+    ctx
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec()
+}
+
+async fn jitosol_sync_sol_value_ix(banks_client: &mut BanksClient) -> Instruction {
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(banks_client).await;
+    sync_sol_value_ix_by_mint_full(
+        SyncSolValueByMintFreeArgs {
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+            lst_mint: KeyedAccount {
+                pubkey: jitosol::ID,
+                account: banks_client.get_account_unwrapped(jitosol::ID).await,
+            },
+        },
+        &jito_sol_val_calc_accounts,
+    )
+    .unwrap()
+}
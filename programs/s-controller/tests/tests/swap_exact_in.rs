@@ -4,8 +4,9 @@ use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
 use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    swap_exact_in_ix_by_mint_full, try_pool_state, SrcDstLstSolValueCalcAccountSuffixes,
-    SwapByMintsFreeArgs, SwapExactInAmounts,
+    disable_lst_output_ix_by_mint_full, swap_exact_in_ix_by_mint_full, try_pool_state,
+    DisableEnableLstInputByMintFreeArgs, SControllerEvent, SrcDstLstSolValueCalcAccountSuffixes,
+    SwapByMintsFreeArgs, SwapEvent, SwapExactInAmounts,
 };
 use s_controller_test_utils::{
     jito_marinade_flat_fee_program_test, jito_marinade_no_fee_program_test,
@@ -13,20 +14,28 @@ use s_controller_test_utils::{
     MockProtocolFeeBps, PoolStateBanksClient,
 };
 use sanctum_solana_test_utils::{
-    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
 };
 use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
 use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
 use solana_program_test::ProgramTestContext;
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
 use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
 use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
 
-use crate::common::SControllerProgramTest;
+use crate::common::{
+    process_tx_get_s_controller_events, process_tx_get_s_controller_return_data,
+    SControllerProgramTest,
+};
 
 #[tokio::test]
 async fn basic_swap_exact_in_no_fee() {
+    let lp_token_mint = Pubkey::new_unique();
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
@@ -41,7 +50,7 @@ async fn basic_swap_exact_in_no_fee() {
         // dont cares
         jitosol_protocol_fee_accumulator: 0,
         msol_protocol_fee_accumulator: 0,
-        lp_token_mint: Pubkey::new_unique(),
+        lp_token_mint,
         lp_token_supply: 0,
     })
     .add_s_program();
@@ -95,6 +104,7 @@ async fn basic_swap_exact_in_no_fee() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_msol_acc_addr,
             dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
@@ -103,6 +113,7 @@ async fn basic_swap_exact_in_no_fee() {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactInAmounts {
@@ -135,7 +146,7 @@ async fn basic_swap_exact_in_no_fee() {
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer, &swapper], last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
 
     let msol_account = banks_client
         .get_account_unwrapped(swapper_msol_acc_addr)
@@ -168,10 +179,31 @@ async fn basic_swap_exact_in_no_fee() {
         .unwrap()
         .total_sol_value;
     assert!(start_pool_total_sol_value < end_pool_total_sol_value);
+
+    let [SControllerEvent::SwapExactIn(SwapEvent {
+        src_lst_index,
+        dst_lst_index,
+        src_lst_in,
+        dst_lst_out,
+        protocol_fees,
+        total_sol_value,
+        ..
+    })] = events.as_slice()
+    else {
+        panic!("Unexpected events {events:?}");
+    };
+    // jitoSOL is index 0, mSOL is index 1
+    assert_eq!(*src_lst_index, 1);
+    assert_eq!(*dst_lst_index, 0);
+    assert_eq!(*src_lst_in, MSOL_TO_SWAP_IN);
+    assert_eq!(*dst_lst_out, jitosol_received);
+    assert_eq!(*protocol_fees, 0);
+    assert_eq!(*total_sol_value, end_pool_total_sol_value);
 }
 
 #[tokio::test]
 async fn basic_swap_exact_in_flat_fee() {
+    let lp_token_mint = Pubkey::new_unique();
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
@@ -191,7 +223,7 @@ async fn basic_swap_exact_in_flat_fee() {
             // dont cares
             jitosol_protocol_fee_accumulator: 0,
             msol_protocol_fee_accumulator: 0,
-            lp_token_mint: Pubkey::new_unique(),
+            lp_token_mint,
             lp_token_supply: 0,
         },
         flat_fee_interface::ProgramState {
@@ -266,6 +298,7 @@ async fn basic_swap_exact_in_flat_fee() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_msol_acc_addr,
             dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
@@ -274,6 +307,7 @@ async fn basic_swap_exact_in_flat_fee() {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactInAmounts {
@@ -300,7 +334,7 @@ async fn basic_swap_exact_in_flat_fee() {
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer, &swapper], last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
 
     let msol_account = banks_client
         .get_account_unwrapped(swapper_msol_acc_addr)
@@ -335,6 +369,10 @@ async fn basic_swap_exact_in_flat_fee() {
         jitosol_pool_reserves_balance + jitosol_received + protocol_fee_accumulator_balance,
         JITOSOL_STARTING_POOL_RESERVES
     );
+    assert_eq!(return_data.amount_in, MSOL_TO_SWAP_IN);
+    assert_eq!(return_data.amount_out, jitosol_received);
+    assert_eq!(return_data.protocol_fees, protocol_fee_accumulator_balance);
+    assert!(return_data.in_sol_value > return_data.out_sol_value);
 
     // TODO: verify fee percentages and amounts
 
@@ -347,6 +385,7 @@ async fn basic_swap_exact_in_flat_fee() {
 
 #[tokio::test]
 async fn fail_swap_exact_in_same_mint() {
+    let lp_token_mint = Pubkey::new_unique();
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const JITOSOL_TO_SWAP_IN: u64 = 1_000_000_000;
 
@@ -360,7 +399,7 @@ async fn fail_swap_exact_in_same_mint() {
         msol_sol_value: 0,
         jitosol_protocol_fee_accumulator: 0,
         msol_protocol_fee_accumulator: 0,
-        lp_token_mint: Pubkey::new_unique(),
+        lp_token_mint,
         lp_token_supply: 0,
     })
     .add_s_program();
@@ -403,6 +442,7 @@ async fn fail_swap_exact_in_same_mint() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_jitosol_acc_addr,
             dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
@@ -411,6 +451,7 @@ async fn fail_swap_exact_in_same_mint() {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactInAmounts {
@@ -443,3 +484,124 @@ async fn fail_swap_exact_in_same_mint() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_custom_err(err, SControllerError::SwapSameLst);
 }
+
+#[tokio::test]
+async fn fail_swap_exact_in_dst_output_disabled() {
+    let lp_token_mint = Pubkey::new_unique();
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let disable_jitosol_output_ix =
+        disable_lst_output_ix_by_mint_full(&DisableEnableLstInputByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: &lst_state_list_account,
+        })
+        .unwrap();
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let swap_ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &[
+            AccountMeta {
+                pubkey: msol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx =
+        Transaction::new_with_payer(&[disable_jitosol_output_ix, swap_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LstOutputDisabled);
+}
@@ -123,6 +123,7 @@ async fn basic_swap_exact_out_no_fee() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_msol_acc_addr,
             dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
@@ -131,6 +132,7 @@ async fn basic_swap_exact_out_no_fee() {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactOutAmounts {
@@ -200,6 +202,7 @@ async fn basic_swap_exact_out_no_fee() {
 
 #[tokio::test]
 async fn basic_swap_exact_out_flat_fee() {
+    let lp_token_mint = Pubkey::new_unique();
     const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
     const JITOSOL_TO_RECEIVE: u64 = 1_000_000_000;
@@ -222,7 +225,7 @@ async fn basic_swap_exact_out_flat_fee() {
             // dont cares
             jitosol_protocol_fee_accumulator: 0,
             msol_protocol_fee_accumulator: 0,
-            lp_token_mint: Pubkey::new_unique(),
+            lp_token_mint,
             lp_token_supply: 0,
         },
         flat_fee_interface::ProgramState {
@@ -297,6 +300,7 @@ async fn basic_swap_exact_out_flat_fee() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_msol_acc_addr,
             dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
@@ -305,6 +309,7 @@ async fn basic_swap_exact_out_flat_fee() {
                 pubkey: jitosol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactOutAmounts {
@@ -331,7 +336,7 @@ async fn basic_swap_exact_out_flat_fee() {
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer, &swapper], last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
 
     let msol_account = banks_client
         .get_account_unwrapped(swapper_msol_acc_addr)
@@ -367,6 +372,10 @@ async fn basic_swap_exact_out_flat_fee() {
         jitosol_pool_reserves_balance + jitosol_received + protocol_fee_accumulator_balance,
         JITOSOL_STARTING_POOL_RESERVES
     );
+    assert_eq!(return_data.amount_in, msol_paid);
+    assert_eq!(return_data.amount_out, JITOSOL_TO_RECEIVE);
+    assert_eq!(return_data.protocol_fees, protocol_fee_accumulator_balance);
+    assert!(return_data.in_sol_value > return_data.out_sol_value);
 
     // TODO: verify fee percentages and amounts
 
@@ -423,6 +432,7 @@ async fn fail_swap_exact_out_same_mint() {
             signer: swapper.pubkey(),
             src_lst_acc: swapper_msol_acc_addr,
             dst_lst_acc: swapper_msol_acc_addr,
+            referrer_dst_lst_acc: None,
             src_lst_mint: MintWithTokenProgram {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
@@ -431,6 +441,7 @@ async fn fail_swap_exact_out_same_mint() {
                 pubkey: msol::ID,
                 token_program: spl_token::ID,
             },
+            pool_state: banks_client.get_pool_state_acc().await,
             lst_state_list: lst_state_list_account,
         },
         SwapExactOutAmounts {
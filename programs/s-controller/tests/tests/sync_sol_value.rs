@@ -1,6 +1,7 @@
 use s_controller_interface::{LstState, PoolState};
 use s_controller_lib::{
-    sync_sol_value_ix_by_mint_full, try_lst_state_list, try_pool_state, SyncSolValueByMintFreeArgs,
+    sync_sol_value_ix_by_mint_full, try_lst_state_list, try_pool_state, SControllerEvent,
+    SyncSolValueByMintFreeArgs, SyncSolValueEvent,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
@@ -48,6 +49,7 @@ async fn basic() {
     let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
 
     let free_args = SyncSolValueByMintFreeArgs {
+        pool_state: banks_client.get_pool_state_acc().await,
         lst_state_list: lst_state_list_acc,
         lst_mint: KeyedAccount {
             pubkey: jitosol::ID,
@@ -76,7 +78,16 @@ async fn basic() {
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
     tx.sign(&[&payer], last_blockhash);
 
-    banks_client.process_transaction(tx).await.unwrap();
+    let events = process_tx_get_s_controller_events(&mut banks_client, tx).await;
+    assert_eq!(
+        events,
+        [SControllerEvent::SyncSolValue(SyncSolValueEvent {
+            lst_index: 0,
+            old_sol_value: 1_000_000_000,
+            new_sol_value: EXPECTED_NEW_JITOSOL_SOL_VALUE,
+            total_sol_value: EXPECTED_NEW_TOTAL_SOL_VALUE,
+        })]
+    );
 
     let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
     let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
@@ -0,0 +1,174 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_lib::{
+    sync_sol_value_multi_ix_full, try_lst_state_list, try_pool_state,
+    SyncSolValueMultiByMintFreeArgs, SyncSolValueMultiLst,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{assert_custom_err, ExtendedBanksClient};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+const STARTING_SOL_VALUE: u64 = 1_000_000_000;
+
+async fn setup() -> ProgramTestContext {
+    let program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: STARTING_SOL_VALUE,
+        msol_sol_value: STARTING_SOL_VALUE,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    ctx
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec()
+}
+
+#[tokio::test]
+async fn basic_jitosol_msol() {
+    const EXPECTED_NEW_JITOSOL_SOL_VALUE: u64 = 1_072_326_756;
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = setup().await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_keys) = SyncSolValueMultiByMintFreeArgs {
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: lst_state_list_acc,
+        lst_mints: &[jitosol::ID, msol::ID].map(|pubkey| MintWithTokenProgram {
+            pubkey,
+            token_program: spl_token::ID,
+        }),
+    }
+    .resolve()
+    .unwrap();
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let ix = sync_sol_value_multi_ix_full(
+        keys,
+        &[
+            SyncSolValueMultiLst {
+                keys: lst_keys[0],
+                sol_value_calculator_accounts: &jito_sol_val_calc_accounts,
+            },
+            SyncSolValueMultiLst {
+                keys: lst_keys[1],
+                sol_value_calculator_accounts: &marinade_sol_val_calc_accounts,
+            },
+        ],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+    let sol_value_of = |mint: Pubkey| {
+        let LstState { sol_value, .. } = lst_state_list.iter().find(|s| s.mint == mint).unwrap();
+        *sol_value
+    };
+    let jitosol_sol_value = sol_value_of(jitosol::ID);
+    let msol_sol_value = sol_value_of(msol::ID);
+    assert_eq!(jitosol_sol_value, EXPECTED_NEW_JITOSOL_SOL_VALUE);
+    // 1 mSOL is worth more than 1 SOL
+    assert!(msol_sol_value > STARTING_SOL_VALUE);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let PoolState {
+        total_sol_value, ..
+    } = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(*total_sol_value, jitosol_sol_value + msol_sol_value);
+}
+
+#[tokio::test]
+async fn fail_incorrect_sol_value_calculator() {
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = setup().await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, mut lst_keys) = SyncSolValueMultiByMintFreeArgs {
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: lst_state_list_acc,
+        lst_mints: &[jitosol::ID, msol::ID].map(|pubkey| MintWithTokenProgram {
+            pubkey,
+            token_program: spl_token::ID,
+        }),
+    }
+    .resolve()
+    .unwrap();
+    // pass the marinade calculator for jitoSOL
+    lst_keys[0].sol_value_calculator = lst_keys[1].sol_value_calculator;
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let ix = sync_sol_value_multi_ix_full(
+        keys,
+        &[
+            SyncSolValueMultiLst {
+                keys: lst_keys[0],
+                sol_value_calculator_accounts: &marinade_sol_val_calc_accounts,
+            },
+            SyncSolValueMultiLst {
+                keys: lst_keys[1],
+                sol_value_calculator_accounts: &marinade_sol_val_calc_accounts,
+            },
+        ],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::IncorrectSolValueCalculator);
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let PoolState {
+        total_sol_value, ..
+    } = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(*total_sol_value, 2 * STARTING_SOL_VALUE);
+}
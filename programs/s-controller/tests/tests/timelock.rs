@@ -0,0 +1,442 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::{
+    cancel_queued_change_ix, execute_set_pricing_program_ix, execute_set_timelock_delay_ix,
+    queue_set_pricing_program_ix, queue_set_sol_value_calculator_ix, queue_set_timelock_delay_ix,
+    set_pricing_program_ix, PoolState, QueueSetSolValueCalculatorIxArgs,
+    QueueSetTimelockDelayIxArgs, SControllerError,
+};
+use s_controller_lib::{
+    execute_set_sol_value_calculator_ix_by_mint_full, index_to_u32,
+    program::{POOL_STATE_ID, QUEUED_PRICING_PROGRAM_ID, QUEUED_TIMELOCK_DELAY_ID},
+    try_find_lst_mint_on_list, try_lst_state_list, try_pool_state, try_queued_change,
+    CancelQueuedChangeFreeArgs, ExecuteSetPricingProgramFreeArgs,
+    ExecuteSetSolValueCalculatorByMintFreeArgs, ExecuteSetTimelockDelayFreeArgs,
+    QueueSetPricingProgramFreeArgs, QueueSetSolValueCalculatorByMintFreeArgs,
+    QueueSetTimelockDelayFreeArgs, SetPricingProgramFreeArgs, TIMELOCK_DELAY_UNIT_SLOTS,
+};
+use s_controller_test_utils::{
+    assert_pricing_prog_set, jito_marinade_no_fee_program_test,
+    DisablePoolAuthorityListProgramTest, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    LstStateListProgramTest, MockLstStateArgs, PoolStateBanksClient, PoolStateProgramTest,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, test_fixtures_dir};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::SControllerProgramTest;
+
+const TIMELOCK_DELAY: u8 = 2;
+
+fn timelocked_program_test(timelock_delay: u8) -> (ProgramTest, Keypair) {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(PoolState {
+            timelock_delay,
+            ..DEFAULT_POOL_STATE
+        });
+    program_test.add_program(
+        "no_fee_pricing_program",
+        no_fee_pricing_program::ID,
+        processor!(no_fee_pricing_program::process_instruction),
+    );
+    (program_test, mock_auth_kp)
+}
+
+async fn queue_set_pricing_program_ix_full(
+    banks_client: &mut BanksClient,
+    payer: Pubkey,
+) -> Instruction {
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    queue_set_pricing_program_ix(
+        QueueSetPricingProgramFreeArgs {
+            payer,
+            new_pricing_program: no_fee_pricing_program::ID,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+async fn execute_set_pricing_program_ix_full(banks_client: &mut BanksClient) -> Instruction {
+    let queued_change_acc = banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    execute_set_pricing_program_ix(
+        ExecuteSetPricingProgramFreeArgs {
+            queued_change: queued_change_acc,
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+async fn current_slot(banks_client: &mut BanksClient) -> u64 {
+    banks_client.get_sysvar::<Clock>().await.unwrap().slot
+}
+
+#[tokio::test]
+async fn queue_execute_pricing_program_no_delay() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let queue_ix = queue_set_pricing_program_ix_full(&mut banks_client, payer.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let execute_ix = execute_set_pricing_program_ix_full(&mut banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[execute_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_pricing_prog_set(&mut banks_client, no_fee_pricing_program::ID).await;
+    assert!(banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn fail_set_pricing_program_timelock_active() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(TIMELOCK_DELAY);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let ix = set_pricing_program_ix(
+        SetPricingProgramFreeArgs {
+            new_pricing_program: no_fee_pricing_program::ID,
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::TimelockActive);
+}
+
+#[tokio::test]
+async fn execute_pricing_program_only_after_delay() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(TIMELOCK_DELAY);
+    let mut ctx = program_test.start_with_context().await;
+    let payer_pk = ctx.payer.pubkey();
+
+    let queued_at_slot = current_slot(&mut ctx.banks_client).await;
+    let queue_ix = queue_set_pricing_program_ix_full(&mut ctx.banks_client, payer_pk).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer_pk));
+    tx.sign(&[&ctx.payer, &mock_auth_kp], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let queued_change_acc = ctx
+        .banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let earliest_execution_slot = try_queued_change(&queued_change_acc.data)
+        .unwrap()
+        .earliest_execution_slot;
+    assert_eq!(
+        earliest_execution_slot,
+        queued_at_slot + u64::from(TIMELOCK_DELAY) * TIMELOCK_DELAY_UNIT_SLOTS
+    );
+
+    let execute_ix = execute_set_pricing_program_ix_full(&mut ctx.banks_client).await;
+    let mut tx = Transaction::new_with_payer(&[execute_ix.clone()], Some(&payer_pk));
+    tx.sign(&[&ctx.payer], ctx.last_blockhash);
+    let err = ctx.banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::TimelockNotElapsed);
+
+    let clock = ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        slot: earliest_execution_slot,
+        ..clock
+    });
+    let last_blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut tx = Transaction::new_with_payer(&[execute_ix], Some(&payer_pk));
+    tx.sign(&[&ctx.payer], last_blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_pricing_prog_set(&mut ctx.banks_client, no_fee_pricing_program::ID).await;
+}
+
+#[tokio::test]
+async fn fail_queue_twice() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(TIMELOCK_DELAY);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let queue_ix = queue_set_pricing_program_ix_full(&mut banks_client, payer.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // use different payer so that tx is not a duplicate
+    let second_payer = Keypair::new();
+    let queue_ix =
+        queue_set_pricing_program_ix_full(&mut banks_client, second_payer.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp, &second_payer], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::ChangeAlreadyQueued);
+}
+
+#[tokio::test]
+async fn disable_pool_authority_cancel_queued_change() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(TIMELOCK_DELAY);
+    let disable_pool_authority_kp = Keypair::new();
+    let program_test =
+        program_test.add_disable_pool_authority_list(&[disable_pool_authority_kp.pubkey()]);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let queue_ix = queue_set_pricing_program_ix_full(&mut banks_client, payer.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let queued_change_acc = banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let payer_lamports_before = banks_client.get_balance(payer.pubkey()).await.unwrap();
+
+    let ix = cancel_queued_change_ix(
+        CancelQueuedChangeFreeArgs {
+            signer: disable_pool_authority_kp.pubkey(),
+            queued_change: queued_change_acc,
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &disable_pool_authority_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .is_none());
+    // rent refunded to payer exceeds the tx fee
+    let payer_lamports_after = banks_client.get_balance(payer.pubkey()).await.unwrap();
+    assert!(payer_lamports_after > payer_lamports_before);
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_ne!(pool_state.pricing_program, no_fee_pricing_program::ID);
+}
+
+#[tokio::test]
+async fn fail_cancel_unauthorized() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(TIMELOCK_DELAY);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let queue_ix = queue_set_pricing_program_ix_full(&mut banks_client, payer.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let rando_kp = Keypair::new();
+    let queued_change_acc = banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let ix = cancel_queued_change_ix(
+        CancelQueuedChangeFreeArgs {
+            signer: rando_kp.pubkey(),
+            queued_change: queued_change_acc,
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &rando_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::InvalidDisablePoolAuthority);
+    assert!(banks_client
+        .get_account(QUEUED_PRICING_PROGRAM_ID)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn queue_execute_timelock_delay() {
+    let (program_test, mock_auth_kp) = timelocked_program_test(0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let queue_ix = queue_set_timelock_delay_ix(
+        QueueSetTimelockDelayFreeArgs {
+            payer: payer.pubkey(),
+            pool_state_acc: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        QueueSetTimelockDelayIxArgs {
+            timelock_delay: TIMELOCK_DELAY,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let queued_change_acc = banks_client
+        .get_account(QUEUED_TIMELOCK_DELAY_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let execute_ix = execute_set_timelock_delay_ix(
+        ExecuteSetTimelockDelayFreeArgs {
+            queued_change: queued_change_acc,
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[execute_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(pool_state.timelock_delay, TIMELOCK_DELAY);
+    assert!(banks_client
+        .get_account(QUEUED_TIMELOCK_DELAY_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn queue_execute_sol_value_calculator() {
+    const MSOL_POOL_RESERVES: u64 = 1_000_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        // these are overriden below
+        msol_reserves: MSOL_POOL_RESERVES,
+        msol_sol_value: MSOL_POOL_RESERVES,
+        // dont cares
+        jitosol_reserves: 0,
+        jitosol_sol_value: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_mock_lst_states(
+        // set mSOL initial calculator to a broken pubkey
+        &[MockLstStateArgs {
+            mint: msol::ID,
+            sol_value_calculator: Pubkey::new_unique(),
+            token_program: spl_token::ID,
+            sol_value: MSOL_POOL_RESERVES,
+            reserves_amt: MSOL_POOL_RESERVES,
+            protocol_fee_accumulator_amt: 0,
+            is_input_disabled: false,
+            is_output_disabled: false,
+        }],
+    )
+    .add_s_program();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (queue_keys, lst_index) = QueueSetSolValueCalculatorByMintFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint: msol::ID,
+        new_sol_value_calculator: marinade_calculator_lib::program::ID,
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: banks_client.get_lst_state_list_acc().await,
+    }
+    .resolve()
+    .unwrap();
+    let queued_change_addr = queue_keys.queued_change;
+    let queue_ix = queue_set_sol_value_calculator_ix(
+        queue_keys,
+        QueueSetSolValueCalculatorIxArgs {
+            lst_index: index_to_u32(lst_index).unwrap(),
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[queue_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let queued_change_acc = banks_client
+        .get_account(queued_change_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    let execute_ix = execute_set_sol_value_calculator_ix_by_mint_full(
+        &ExecuteSetSolValueCalculatorByMintFreeArgs {
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+            lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            queued_change: queued_change_acc,
+        },
+        &marinade_sol_val_calc_account_metas(),
+        marinade_calculator_lib::program::ID,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[execute_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let lst_state_list = try_lst_state_list(&lst_state_list_account.data).unwrap();
+    let (_i, lst_state) = try_find_lst_mint_on_list(msol::ID, lst_state_list).unwrap();
+    assert_eq!(
+        lst_state.sol_value_calculator,
+        marinade_calculator_lib::program::ID
+    );
+    // should have increased to true rate after sync
+    assert!(lst_state.sol_value > MSOL_POOL_RESERVES);
+    assert!(banks_client
+        .get_account(queued_change_addr)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,909 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    add_liquidity_ix_full, end_rebalance_ix_full, flash_borrow_ix_by_mint_full,
+    flash_repay_ix_by_mint_full,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
+    quote_swap_exact_in_ix_by_mint_full, remove_liquidity_ix_full, start_rebalance_ix_full,
+    swap_exact_in_ix_by_mint_full, swap_exact_out_ix_by_mint_full, try_pool_state,
+    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
+    AddRemoveLiquidityExtraAccounts, EndRebalanceFromStartRebalanceKeys, FlashBorrowByMintFreeArgs,
+    FlashRepayByMintFreeArgs, LstTransferFee, QuoteSwapByMintsFreeArgs,
+    RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    SrcDstLstIndexes, SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
+    StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts,
+    SwapByMintsFreeArgs, SwapExactInAmounts, SwapExactOutAmounts, U8Bool,
+};
+use s_controller_test_utils::{
+    jito_marinade_transfer_fee_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_ix, MintWithTokenProgram, TransferCheckedArgs,
+    TransferCheckedKeys,
+};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use spl_token_2022::extension::transfer_fee::TransferFee;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::{process_tx_get_s_controller_return_data, SControllerProgramTest};
+
+// The jitoSOL mint is replaced by a token-2022 mint that charges
+// JITOSOL_TRANSFER_FEE_BPS on every transfer.
+// Pricing program and protocol fees are all zero so that
+// all differences in amounts are due to the transfer fee.
+//
+// The s-controller program also fails any of these instructions
+// if the pool would lose SOL value, so successful txs also mean
+// that SOL values were computed on the amounts actually received
+
+const JITOSOL_TRANSFER_FEE_BPS: u16 = 100;
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const LP_TOKEN_SUPPLY: u64 = JITOSOL_STARTING_POOL_RESERVES + MSOL_STARTING_POOL_RESERVES;
+
+const JITOSOL_MINT: MintWithTokenProgram = MintWithTokenProgram {
+    pubkey: jitosol::ID,
+    token_program: spl_token_2022::ID,
+};
+const MSOL_MINT: MintWithTokenProgram = MintWithTokenProgram {
+    pubkey: msol::ID,
+    token_program: spl_token::ID,
+};
+
+fn jitosol_mint_transfer_fee() -> TransferFee {
+    TransferFee {
+        epoch: 0u64.into(),
+        maximum_fee: u64::MAX.into(),
+        transfer_fee_basis_points: JITOSOL_TRANSFER_FEE_BPS.into(),
+    }
+}
+
+fn jitosol_transfer_fee() -> LstTransferFee {
+    LstTransferFee(Some(jitosol_mint_transfer_fee()))
+}
+
+fn transfer_fee_program_test(lp_token_mint: Pubkey) -> ProgramTest {
+    jito_marinade_transfer_fee_no_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+            msol_reserves: MSOL_STARTING_POOL_RESERVES,
+            jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+            msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: LP_TOKEN_SUPPLY,
+        },
+        jitosol_mint_transfer_fee(),
+    )
+    .add_s_program()
+}
+
+async fn start_at_jito_stake_pool_last_update_epoch(
+    program_test: ProgramTest,
+) -> ProgramTestContext {
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    ctx
+}
+
+async fn jito_sol_val_calc_accounts(banks_client: &mut BanksClient) -> Vec<AccountMeta> {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap()
+    .to_vec()
+}
+
+fn no_fee_pricing_accounts(src_lst_mint: Pubkey, dst_lst_mint: Pubkey) -> [AccountMeta; 2] {
+    [src_lst_mint, dst_lst_mint].map(|pubkey| AccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable: false,
+    })
+}
+
+async fn balance_of(banks_client: &mut BanksClient, token_acc: Pubkey) -> u64 {
+    token_account_balance(banks_client.get_account_unwrapped(token_acc).await).unwrap()
+}
+
+#[tokio::test]
+async fn swap_exact_in_transfer_fee_src_lst() {
+    const JITOSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let swapper_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: swapper.pubkey(),
+            amount: JITOSOL_TO_SWAP_IN,
+        });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_jitosol_acc_addr,
+            dst_lst_acc: swapper_msol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: JITOSOL_MINT,
+            dst_lst_mint: MSOL_MINT,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 1,
+            amount: JITOSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+        },
+        &no_fee_pricing_accounts(jitosol::ID, msol::ID),
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = ix.accounts[10].pubkey;
+    let msol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    let jitosol_received = jitosol_transfer_fee()
+        .amt_after_fee(JITOSOL_TO_SWAP_IN)
+        .unwrap();
+    assert!(jitosol_received < JITOSOL_TO_SWAP_IN);
+    assert_eq!(return_data.amount_in, JITOSOL_TO_SWAP_IN);
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_acc_addr).await,
+        0
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES + jitosol_received
+    );
+
+    let msol_received = balance_of(&mut banks_client, swapper_msol_acc_addr).await;
+    assert_eq!(msol_received, return_data.amount_out);
+    // mSOL worth more than jitoSOL
+    assert!(msol_received < jitosol_received);
+    assert_eq!(
+        balance_of(&mut banks_client, msol_pool_reserves).await,
+        MSOL_STARTING_POOL_RESERVES - msol_received
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_in_transfer_fee_dst_lst() {
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+    let swapper_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MSOL_MINT,
+            dst_lst_mint: JITOSOL_MINT,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 1,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+        &no_fee_pricing_accounts(msol::ID, jitosol::ID),
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let msol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    assert_eq!(return_data.amount_in, MSOL_TO_SWAP_IN);
+    assert_eq!(
+        balance_of(&mut banks_client, msol_pool_reserves).await,
+        MSOL_STARTING_POOL_RESERVES + MSOL_TO_SWAP_IN
+    );
+
+    let jitosol_out = return_data.amount_out;
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_out
+    );
+    let jitosol_received = balance_of(&mut banks_client, swapper_jitosol_acc_addr).await;
+    assert_eq!(
+        jitosol_received,
+        jitosol_transfer_fee().amt_after_fee(jitosol_out).unwrap()
+    );
+    assert!(jitosol_received < jitosol_out);
+}
+
+#[tokio::test]
+async fn swap_exact_in_transfer_fee_dst_lst_min_amount_out() {
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+    let swapper_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let suffixes = SrcDstLstSolValueCalcAccountSuffixes {
+        src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+    };
+    let pricing_program_accounts = no_fee_pricing_accounts(msol::ID, jitosol::ID);
+
+    let quote_ix = quote_swap_exact_in_ix_by_mint_full(
+        QuoteSwapByMintsFreeArgs {
+            src_lst_mint: MSOL_MINT,
+            dst_lst_mint: JITOSOL_MINT,
+            pool_state: &pool_state_acc,
+            lst_state_list: &lst_state_list_acc,
+        },
+        MSOL_TO_SWAP_IN,
+        suffixes,
+        &pricing_program_accounts,
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[quote_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+    let quote = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+    // amount_out is the amount leaving the pool, before the transfer fee
+    let jitosol_received = jitosol_transfer_fee()
+        .amt_after_fee(quote.amount_out)
+        .unwrap();
+    assert!(jitosol_received < quote.amount_out);
+
+    let swap_ix = |min_amount_out| {
+        swap_exact_in_ix_by_mint_full(
+            SwapByMintsFreeArgs {
+                signer: swapper.pubkey(),
+                src_lst_acc: swapper_msol_acc_addr,
+                dst_lst_acc: swapper_jitosol_acc_addr,
+                referrer_dst_lst_acc: None,
+                src_lst_mint: MSOL_MINT,
+                dst_lst_mint: JITOSOL_MINT,
+                pool_state: &pool_state_acc,
+                lst_state_list: &lst_state_list_acc,
+            },
+            SwapExactInAmounts {
+                min_amount_out,
+                amount: MSOL_TO_SWAP_IN,
+            },
+            suffixes,
+            &pricing_program_accounts,
+            no_fee_pricing_program::ID,
+        )
+        .unwrap()
+    };
+
+    // min_amount_out is checked against the amount the swapper receives
+    let mut tx =
+        Transaction::new_with_payer(&[swap_ix(jitosol_received + 1)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+
+    let mut tx = Transaction::new_with_payer(&[swap_ix(jitosol_received)], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_acc_addr).await,
+        jitosol_received
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_out_transfer_fee_src_lst() {
+    const MSOL_TO_SWAP_OUT: u64 = 1_000_000_000;
+    const SWAPPER_STARTING_JITOSOL: u64 = 2_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let swapper_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: swapper.pubkey(),
+            amount: SWAPPER_STARTING_JITOSOL,
+        });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let ix = swap_exact_out_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_jitosol_acc_addr,
+            dst_lst_acc: swapper_msol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: JITOSOL_MINT,
+            dst_lst_mint: MSOL_MINT,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        SwapExactOutAmounts {
+            max_amount_in: SWAPPER_STARTING_JITOSOL,
+            amount: MSOL_TO_SWAP_OUT,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+        },
+        &no_fee_pricing_accounts(jitosol::ID, msol::ID),
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = ix.accounts[10].pubkey;
+    let msol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    assert_eq!(return_data.amount_out, MSOL_TO_SWAP_OUT);
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_msol_acc_addr).await,
+        MSOL_TO_SWAP_OUT
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, msol_pool_reserves).await,
+        MSOL_STARTING_POOL_RESERVES - MSOL_TO_SWAP_OUT
+    );
+
+    let jitosol_in = return_data.amount_in;
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_acc_addr).await,
+        SWAPPER_STARTING_JITOSOL - jitosol_in
+    );
+    let jitosol_received = jitosol_transfer_fee().amt_after_fee(jitosol_in).unwrap();
+    assert!(jitosol_received < jitosol_in);
+    // mSOL worth more than jitoSOL
+    assert!(jitosol_received > MSOL_TO_SWAP_OUT);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES + jitosol_received
+    );
+}
+
+#[tokio::test]
+async fn swap_exact_out_transfer_fee_dst_lst() {
+    const JITOSOL_TO_SWAP_OUT: u64 = 1_000_000_000;
+    const SWAPPER_STARTING_MSOL: u64 = 2_000_000_000;
+
+    let swapper = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: SWAPPER_STARTING_MSOL,
+    });
+    let swapper_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: swapper.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let ix = swap_exact_out_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            referrer_dst_lst_acc: None,
+            src_lst_mint: MSOL_MINT,
+            dst_lst_mint: JITOSOL_MINT,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        SwapExactOutAmounts {
+            max_amount_in: SWAPPER_STARTING_MSOL,
+            amount: JITOSOL_TO_SWAP_OUT,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_account_metas(),
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+        &no_fee_pricing_accounts(msol::ID, jitosol::ID),
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    let msol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    // the swapper receives exactly the requested amount after the transfer fee
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_jitosol_acc_addr).await,
+        JITOSOL_TO_SWAP_OUT
+    );
+    let jitosol_out = jitosol_transfer_fee()
+        .amt_bef_fee(JITOSOL_TO_SWAP_OUT)
+        .unwrap();
+    assert!(jitosol_out > JITOSOL_TO_SWAP_OUT);
+    assert_eq!(return_data.amount_out, jitosol_out);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_out
+    );
+
+    let msol_in = return_data.amount_in;
+    assert_eq!(
+        balance_of(&mut banks_client, swapper_msol_acc_addr).await,
+        SWAPPER_STARTING_MSOL - msol_in
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, msol_pool_reserves).await,
+        MSOL_STARTING_POOL_RESERVES + msol_in
+    );
+}
+
+#[tokio::test]
+async fn add_liquidity_transfer_fee_lst() {
+    const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let mut program_test = transfer_fee_program_test(lp_token_mint);
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_index, _program_ids) = AddLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lst_acc: liquidity_provider_jitosol_acc_addr,
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: &lst_state_list_acc,
+        lst_mint: KeyedAccount {
+            pubkey: jitosol::ID,
+            account: banks_client.get_account_unwrapped(jitosol::ID).await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let jitosol_pool_reserves = keys.pool_reserves;
+    let ix = add_liquidity_ix_full(
+        keys,
+        AddLiquidityIxFullArgs {
+            lst_index,
+            amts: AddLiquidityIxAmts {
+                lst_amount: JITOSOL_TO_ADD,
+                min_lp_out: 1,
+            },
+        },
+        AddRemoveLiquidityExtraAccounts {
+            lst_calculator_program_id: spl_calculator_lib::program::ID,
+            pricing_program_id: no_fee_pricing_program::ID,
+            lst_calculator_accounts: &jito_sol_val_calc_accounts(&mut banks_client).await,
+            pricing_program_price_lp_accounts: &[AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            }],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    assert_eq!(return_data.amount_in, JITOSOL_TO_ADD);
+    assert_eq!(
+        balance_of(&mut banks_client, liquidity_provider_jitosol_acc_addr).await,
+        0
+    );
+    let jitosol_received = jitosol_transfer_fee()
+        .amt_after_fee(JITOSOL_TO_ADD)
+        .unwrap();
+    assert!(jitosol_received < JITOSOL_TO_ADD);
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES + jitosol_received
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, liquidity_provider_lp_token_acc_addr).await,
+        return_data.amount_out
+    );
+}
+
+#[tokio::test]
+async fn remove_liquidity_transfer_fee_lst() {
+    const LP_TOKENS_TO_REMOVE: u64 = 1_000_000_000;
+
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let mut program_test = transfer_fee_program_test(lp_token_mint);
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let (keys, lst_index, _program_ids) = RemoveLiquidityByMintFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        src_lp_acc: liquidity_provider_lp_token_acc_addr,
+        dst_lst_acc: liquidity_provider_jitosol_acc_addr,
+        pool_state: banks_client.get_pool_state_acc().await,
+        lst_state_list: &lst_state_list_acc,
+        lst_mint: JITOSOL_MINT,
+    }
+    .resolve()
+    .unwrap();
+    let jitosol_pool_reserves = keys.pool_reserves;
+    let ix = remove_liquidity_ix_full(
+        keys,
+        RemoveLiquidityIxFullArgs {
+            lst_index,
+            amts: RemoveLiquidityIxAmts {
+                lp_token_amount: LP_TOKENS_TO_REMOVE,
+                min_lst_out: 1,
+            },
+        },
+        AddRemoveLiquidityExtraAccounts {
+            lst_calculator_program_id: spl_calculator_lib::program::ID,
+            pricing_program_id: no_fee_pricing_program::ID,
+            lst_calculator_accounts: &jito_sol_val_calc_accounts(&mut banks_client).await,
+            pricing_program_price_lp_accounts: &[AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            }],
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+    let return_data = process_tx_get_s_controller_return_data(&mut banks_client, tx).await;
+
+    assert_eq!(return_data.amount_in, LP_TOKENS_TO_REMOVE);
+    assert_eq!(
+        balance_of(&mut banks_client, liquidity_provider_lp_token_acc_addr).await,
+        0
+    );
+    let jitosol_out = return_data.amount_out;
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_out
+    );
+    let jitosol_received = balance_of(&mut banks_client, liquidity_provider_jitosol_acc_addr).await;
+    assert_eq!(
+        jitosol_received,
+        jitosol_transfer_fee().amt_after_fee(jitosol_out).unwrap()
+    );
+    assert!(jitosol_received < jitosol_out);
+}
+
+#[tokio::test]
+async fn rebalance_transfer_fee_src_lst() {
+    const JITOSOL_WITHDRAW_AMT: u64 = 1_000_000_000;
+    // mSOL worth more than jitoSOL
+    const MSOL_DONATE_AMT: u64 = JITOSOL_WITHDRAW_AMT;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let withdraw_jitosol_to_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: mock_auth_kp.pubkey(),
+            amount: 0,
+        });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: MSOL_DONATE_AMT,
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let (
+        start_rebalance_keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        _program_ids,
+    ) = StartRebalanceByMintsFreeArgs {
+        withdraw_to: withdraw_jitosol_to_addr,
+        lst_state_list: KeyedAccount {
+            pubkey: LST_STATE_LIST_ID,
+            account: lst_state_list_acc,
+        },
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc,
+        },
+        src_lst_mint: JITOSOL_MINT,
+        dst_lst_mint: MSOL_MINT,
+    }
+    .resolve()
+    .unwrap();
+    let jitosol_pool_reserves = start_rebalance_keys.src_pool_reserves;
+    let end_rebalance_keys = EndRebalanceFromStartRebalanceKeys(&start_rebalance_keys).resolve();
+    let msol_pool_reserves = end_rebalance_keys.dst_pool_reserves;
+
+    let jito_sol_val_calc_accounts = jito_sol_val_calc_accounts(&mut banks_client).await;
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let start_rebalance_ix = start_rebalance_ix_full(
+        start_rebalance_keys,
+        StartRebalanceIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            lst_amts: StartRebalanceIxLstAmts {
+                amount: JITOSOL_WITHDRAW_AMT,
+                min_starting_src_lst: 0,
+                max_starting_dst_lst: u64::MAX,
+                max_loss_lamports: 0,
+            },
+        },
+        SrcDstLstSolValueCalcAccounts {
+            src_lst_calculator_program_id: spl_calculator_lib::program::ID,
+            dst_lst_calculator_program_id: marinade_calculator_lib::program::ID,
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+    )
+    .unwrap();
+    let donate_msol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_msol_from_addr,
+            to: msol_pool_reserves,
+            authority: mock_auth_kp.pubkey(),
+            mint: msol::ID,
+        },
+        TransferCheckedArgs {
+            amount: MSOL_DONATE_AMT,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let end_rebalance_ix = end_rebalance_ix_full(
+        end_rebalance_keys,
+        &marinade_sol_val_calc_accounts,
+        marinade_calculator_lib::program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(
+        &[start_rebalance_ix, donate_msol_ix, end_rebalance_ix],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES - JITOSOL_WITHDRAW_AMT
+    );
+    let jitosol_received = balance_of(&mut banks_client, withdraw_jitosol_to_addr).await;
+    assert_eq!(
+        jitosol_received,
+        jitosol_transfer_fee()
+            .amt_after_fee(JITOSOL_WITHDRAW_AMT)
+            .unwrap()
+    );
+    assert!(jitosol_received < JITOSOL_WITHDRAW_AMT);
+    assert_eq!(
+        balance_of(&mut banks_client, msol_pool_reserves).await,
+        MSOL_STARTING_POOL_RESERVES + MSOL_DONATE_AMT
+    );
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    let rebalance_record = banks_client.get_account(REBALANCE_RECORD_ID).await.unwrap();
+    assert!(rebalance_record.is_none());
+}
+
+#[tokio::test]
+async fn flash_loan_transfer_fee_lst() {
+    const JITOSOL_TO_BORROW: u64 = 1_000_000_000;
+
+    let transfer_fee = jitosol_transfer_fee();
+    let jitosol_borrowed_received = transfer_fee.amt_after_fee(JITOSOL_TO_BORROW).unwrap();
+    // the borrower pays the transfer fee on the repayment so that the reserves are made whole
+    let jitosol_to_repay = transfer_fee.amt_bef_fee(JITOSOL_TO_BORROW).unwrap();
+    assert!(jitosol_borrowed_received < JITOSOL_TO_BORROW);
+    assert!(jitosol_to_repay > JITOSOL_TO_BORROW);
+    let borrower_starting_jitosol = jitosol_to_repay - jitosol_borrowed_received;
+
+    let borrower = Keypair::new();
+    let mut program_test = transfer_fee_program_test(Pubkey::new_unique());
+    let borrower_jitosol_acc_addr =
+        program_test.gen_and_add_transfer_fee_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: borrower.pubkey(),
+            amount: borrower_starting_jitosol,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = start_at_jito_stake_pool_last_update_epoch(program_test).await;
+
+    let borrow_ix = flash_borrow_ix_by_mint_full(
+        FlashBorrowByMintFreeArgs {
+            dst_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+            lst_mint: JITOSOL_MINT,
+        },
+        JITOSOL_TO_BORROW,
+    )
+    .unwrap();
+    let jitosol_pool_reserves = borrow_ix.accounts[5].pubkey;
+    let repay_ix = flash_repay_ix_by_mint_full(
+        FlashRepayByMintFreeArgs {
+            signer: borrower.pubkey(),
+            src_lst_acc: borrower_jitosol_acc_addr,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+            lst_mint: JITOSOL_MINT,
+        },
+        &jito_sol_val_calc_accounts(&mut banks_client).await,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[borrow_ix, repay_ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &borrower], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        balance_of(&mut banks_client, borrower_jitosol_acc_addr).await,
+        0
+    );
+    assert_eq!(
+        balance_of(&mut banks_client, jitosol_pool_reserves).await,
+        JITOSOL_STARTING_POOL_RESERVES
+    );
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+}